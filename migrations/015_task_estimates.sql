@@ -0,0 +1,8 @@
+-- Task effort estimates and recorded actuals for velocity reporting.
+-- estimate_secs is supplied at submission (`--estimate`); duration_secs is
+-- recorded when the task transitions to Complete (completed_at - started_at).
+
+ALTER TABLE tasks ADD COLUMN estimate_secs INTEGER;
+ALTER TABLE tasks ADD COLUMN duration_secs INTEGER;
+
+CREATE INDEX IF NOT EXISTS idx_tasks_status_completed_at ON tasks(status, completed_at);
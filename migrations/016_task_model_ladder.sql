@@ -0,0 +1,4 @@
+-- Current rung of the agent type's model escalation ladder for a task.
+-- NULL when the task's agent type has no configured ladder.
+
+ALTER TABLE tasks ADD COLUMN model_ladder_position INTEGER;
@@ -0,0 +1,4 @@
+-- Per-task substrate parameter overrides (max turns, temperature, max tokens,
+-- stop sequences) as JSON. NULL when the task uses the agent defaults.
+
+ALTER TABLE tasks ADD COLUMN execution_params TEXT;
@@ -0,0 +1,4 @@
+-- Lineage link for reruns: the completed task this task re-executes.
+-- NULL for tasks that are not reruns.
+
+ALTER TABLE tasks ADD COLUMN supersedes TEXT;
@@ -0,0 +1,5 @@
+-- Overseers that must pass once after a direct-mode agent completes
+-- (`task submit --verify`), as a JSON array of names. NULL when the task
+-- declares no verification gate.
+
+ALTER TABLE tasks ADD COLUMN required_overseers TEXT;
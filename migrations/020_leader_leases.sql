@@ -0,0 +1,10 @@
+-- Leadership leases shared by swarm processes running against one database.
+-- A process holds a lease until `expires_at` and renews it while alive; once
+-- it lapses, any process may take it over.
+
+CREATE TABLE IF NOT EXISTS leader_leases (
+    name       TEXT PRIMARY KEY,
+    holder     TEXT NOT NULL,
+    -- Unix epoch milliseconds
+    expires_at INTEGER NOT NULL
+);
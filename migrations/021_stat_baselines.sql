@@ -0,0 +1,12 @@
+-- Baselines marked by `swarm stats --reset`. Each row snapshots the lifetime
+-- activity counters at reset time; "this session" stats are the current
+-- counters less those of the latest baseline.
+
+CREATE TABLE IF NOT EXISTS stat_baselines (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    reset_at        TEXT NOT NULL,
+    tasks_completed INTEGER NOT NULL DEFAULT 0,
+    tasks_failed    INTEGER NOT NULL DEFAULT 0,
+    executions      INTEGER NOT NULL DEFAULT 0,
+    tokens_used     INTEGER NOT NULL DEFAULT 0
+);
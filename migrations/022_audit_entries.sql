@@ -0,0 +1,14 @@
+-- Structured audit log entries written by AuditLogService when a pool is
+-- attached and `persist_to_db` is set. The full entry is kept as JSON;
+-- timestamp/level/category are broken out for filtering.
+
+CREATE TABLE IF NOT EXISTS audit_entries (
+    id        TEXT PRIMARY KEY,
+    timestamp TEXT NOT NULL,
+    level     TEXT NOT NULL,
+    category  TEXT NOT NULL,
+    entry     TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_entries_timestamp ON audit_entries(timestamp);
+CREATE INDEX IF NOT EXISTS idx_audit_entries_category ON audit_entries(category, timestamp);
@@ -0,0 +1,10 @@
+-- Operator controls for a running swarm. `swarm pause`/`swarm resume` write
+-- here from the CLI process; the orchestrator loop reads the row every tick.
+
+CREATE TABLE IF NOT EXISTS swarm_control (
+    id         INTEGER PRIMARY KEY CHECK (id = 1),
+    paused     INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+INSERT OR IGNORE INTO swarm_control (id, paused) VALUES (1, 0);
@@ -0,0 +1,8 @@
+-- Persist memory embeddings alongside the model that produced them, so
+-- vectors from different models are never compared and a model switch can
+-- be re-embedded incrementally (rows whose model differs are still stale).
+-- Vectors are stored as little-endian f32 bytes.
+ALTER TABLE memories ADD COLUMN embedding BLOB;
+ALTER TABLE memories ADD COLUMN embedding_model TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_memories_embedding_model ON memories(embedding_model);
@@ -0,0 +1,27 @@
+-- Full-text index over task titles and descriptions, kept in sync with
+-- `tasks` by triggers. `SqliteTaskRepository::search_by_text` falls back to
+-- a LIKE scan when this table is absent (SQLite built without FTS5).
+CREATE VIRTUAL TABLE IF NOT EXISTS tasks_fts USING fts5(
+    task_id UNINDEXED,
+    title,
+    description
+);
+
+INSERT INTO tasks_fts (task_id, title, description)
+SELECT id, title, COALESCE(description, '') FROM tasks;
+
+CREATE TRIGGER IF NOT EXISTS tasks_fts_insert AFTER INSERT ON tasks BEGIN
+    INSERT INTO tasks_fts (task_id, title, description)
+    VALUES (new.id, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS tasks_fts_update AFTER UPDATE OF title, description ON tasks
+WHEN old.title IS NOT new.title OR old.description IS NOT new.description BEGIN
+    DELETE FROM tasks_fts WHERE task_id = old.id;
+    INSERT INTO tasks_fts (task_id, title, description)
+    VALUES (new.id, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS tasks_fts_delete AFTER DELETE ON tasks BEGIN
+    DELETE FROM tasks_fts WHERE task_id = old.id;
+END;
@@ -0,0 +1,4 @@
+-- Capabilities an agent template declares on its agent card, stored as a
+-- JSON array of strings so `find_by_capability` can query them with
+-- json_each. Existing templates start with none declared.
+ALTER TABLE agent_templates ADD COLUMN capabilities TEXT NOT NULL DEFAULT '[]';
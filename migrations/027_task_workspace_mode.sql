@@ -0,0 +1,4 @@
+-- Where a task's agent runs: 'auto' (infer from workflow and agent role),
+-- 'ephemeral' (repo root, no worktree) or 'worktree'. Existing tasks keep
+-- the previous inferred behaviour.
+ALTER TABLE tasks ADD COLUMN workspace_mode TEXT NOT NULL DEFAULT 'auto';
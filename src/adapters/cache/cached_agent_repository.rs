@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use moka::future::Cache;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 use uuid::Uuid;
 
@@ -20,6 +21,18 @@ const TEMPLATE_CACHE_TTL_SECS: u64 = 60;
 /// Maximum number of cached template entries.
 const TEMPLATE_CACHE_MAX_CAPACITY: u64 = 100;
 
+/// Hit/miss counters and size of the template cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// `get_template_by_name` calls answered from the cache.
+    pub hits: u64,
+    /// `get_template_by_name` calls that went to the inner repository.
+    pub misses: u64,
+    /// Cached templates. Moka updates this lazily, so it may briefly count
+    /// entries that were just invalidated or expired.
+    pub entries: u64,
+}
+
 /// Cached agent repository decorator.
 ///
 /// Wraps any `AgentRepository` implementation with a moka-based cache
@@ -28,6 +41,8 @@ pub struct CachedAgentRepository<A: AgentRepository> {
     inner: Arc<A>,
     /// Cache keyed by template name -> AgentTemplate.
     template_by_name: Cache<String, Arc<AgentTemplate>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
 
 impl<A: AgentRepository> CachedAgentRepository<A> {
@@ -46,11 +61,25 @@ impl<A: AgentRepository> CachedAgentRepository<A> {
         Self {
             inner,
             template_by_name,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Current hit/miss counts and cache size.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.template_by_name.entry_count(),
         }
     }
 
     /// Invalidate all cached templates.
-    fn invalidate_all(&self) {
+    ///
+    /// Call after writing templates behind the cache's back, or after a bulk
+    /// change such as `AgentService::seed_baseline_agents*`.
+    pub fn invalidate_all(&self) {
         self.template_by_name.invalidate_all();
     }
 
@@ -78,8 +107,10 @@ impl<A: AgentRepository + 'static> AgentRepository for CachedAgentRepository<A>
     async fn get_template_by_name(&self, name: &str) -> DomainResult<Option<AgentTemplate>> {
         // Check cache first
         if let Some(cached) = self.template_by_name.get(name).await {
+            self.hits.fetch_add(1, Ordering::Relaxed);
             return Ok(Some((*cached).clone()));
         }
+        self.misses.fetch_add(1, Ordering::Relaxed);
 
         // Cache miss - fetch from inner
         let result = self.inner.get_template_by_name(name).await?;
@@ -129,6 +160,10 @@ impl<A: AgentRepository + 'static> AgentRepository for CachedAgentRepository<A>
         self.inner.get_active_templates().await
     }
 
+    async fn find_by_capability(&self, capability: &str) -> DomainResult<Vec<AgentTemplate>> {
+        self.inner.find_by_capability(capability).await
+    }
+
     // Instance operations - not cached (too volatile)
 
     async fn create_instance(&self, instance: &AgentInstance) -> DomainResult<()> {
@@ -166,6 +201,7 @@ impl<A: AgentRepository + 'static> AgentRepository for CachedAgentRepository<A>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapters::sqlite::{SqliteAgentRepository, create_migrated_test_pool};
 
     // Basic compile-time verification that CachedAgentRepository<A>
     // implements AgentRepository when A does. Actual integration tests
@@ -179,4 +215,42 @@ mod tests {
 
         assert_eq!(cache.entry_count(), 0);
     }
+
+    #[tokio::test]
+    async fn test_stats_and_invalidate_all() {
+        let inner = Arc::new(SqliteAgentRepository::new(
+            create_migrated_test_pool().await.unwrap(),
+        ));
+        let repo = CachedAgentRepository::new(inner.clone());
+        repo.create_template(&AgentTemplate::new("coder", AgentTier::Worker))
+            .await
+            .unwrap();
+
+        repo.get_template_by_name("coder").await.unwrap().unwrap();
+        repo.get_template_by_name("coder").await.unwrap().unwrap();
+        repo.template_by_name.run_pending_tasks().await;
+        assert_eq!(
+            repo.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                entries: 1,
+            }
+        );
+
+        // A write that bypasses the cache is only seen after invalidating.
+        let mut template = inner.get_template_by_name("coder").await.unwrap().unwrap();
+        template.max_turns = 40;
+        inner.update_template(&template).await.unwrap();
+        let cached = repo.get_template_by_name("coder").await.unwrap().unwrap();
+        assert_eq!(cached.max_turns, 25);
+
+        repo.invalidate_all();
+        repo.template_by_name.run_pending_tasks().await;
+        assert_eq!(repo.stats().entries, 0);
+        let fresh = repo.get_template_by_name("coder").await.unwrap().unwrap();
+        assert_eq!(fresh.max_turns, 40);
+        let stats = repo.stats();
+        assert_eq!((stats.hits, stats.misses), (2, 2));
+    }
 }
@@ -5,4 +5,4 @@
 
 pub mod cached_agent_repository;
 
-pub use cached_agent_repository::CachedAgentRepository;
+pub use cached_agent_repository::{CacheStats, CachedAgentRepository};
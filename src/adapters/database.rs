@@ -0,0 +1,246 @@
+//! Backend-neutral persistence seam.
+//!
+//! Repository adapters historically talked to `SqlitePool` directly. This
+//! module holds the pieces that do not depend on a particular database:
+//! the row-parsing helpers shared by every repository, a small dynamic value
+//! model (`DbValue` / `DbRow`), and the `Database` trait that a backend
+//! implements to run parameterized queries. SQLite implements it via
+//! [`SqliteDatabase`](crate::adapters::sqlite::SqliteDatabase); other
+//! backends (e.g. a Postgres event store) implement the same trait and reuse
+//! the helpers here instead of duplicating them.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::{DomainError, DomainResult};
+
+/// Parse a UUID string from a row field.
+pub fn parse_uuid(s: &str) -> DomainResult<Uuid> {
+    Uuid::parse_str(s).map_err(|e| DomainError::SerializationError(e.to_string()))
+}
+
+/// Parse an optional UUID string from a row field.
+pub fn parse_optional_uuid(s: Option<String>) -> DomainResult<Option<Uuid>> {
+    s.map(|s| Uuid::parse_str(&s))
+        .transpose()
+        .map_err(|e| DomainError::SerializationError(e.to_string()))
+}
+
+/// Parse an RFC3339 datetime string from a row field.
+pub fn parse_datetime(s: &str) -> DomainResult<DateTime<Utc>> {
+    chrono::DateTime::parse_from_rfc3339(s)
+        .map_err(|e| DomainError::SerializationError(e.to_string()))
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Parse an optional RFC3339 datetime string from a row field.
+pub fn parse_optional_datetime(s: Option<String>) -> DomainResult<Option<DateTime<Utc>>> {
+    s.map(|s| chrono::DateTime::parse_from_rfc3339(&s).map(|d| d.with_timezone(&Utc)))
+        .transpose()
+        .map_err(|e| DomainError::SerializationError(e.to_string()))
+}
+
+/// Parse a JSON string from a row field, falling back to the type's default.
+pub fn parse_json_or_default<T: serde::de::DeserializeOwned + Default>(
+    s: Option<String>,
+) -> DomainResult<T> {
+    s.map(|s| serde_json::from_str(&s))
+        .transpose()
+        .map_err(|e| DomainError::SerializationError(e.to_string()))
+        .map(|opt| opt.unwrap_or_default())
+}
+
+/// Convert a Vec of DB rows into domain objects, skipping (and warning about)
+/// any row that fails to deserialize. Intended for list-style queries where a
+/// single corrupt row should not poison the entire result set. Single-row
+/// getters should use the normal `try_into` path so callers see the error.
+pub fn rows_into_lossy<R, T>(rows: Vec<R>, source: &'static str) -> Vec<T>
+where
+    R: TryInto<T, Error = DomainError>,
+{
+    rows.into_iter()
+        .filter_map(|r| match r.try_into() {
+            Ok(v) => Some(v),
+            Err(e) => {
+                tracing::warn!(
+                    source = source,
+                    error = %e,
+                    "skipping corrupt row during list query"
+                );
+                None
+            }
+        })
+        .collect()
+}
+
+/// Which database engine a `Database` talks to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    Sqlite,
+    Postgres,
+}
+
+impl DatabaseBackend {
+    /// Bind placeholder for the 1-based parameter `index` in this backend's
+    /// SQL dialect (`?` for SQLite, `$n` for Postgres).
+    pub fn placeholder(&self, index: usize) -> String {
+        match self {
+            Self::Sqlite => "?".to_string(),
+            Self::Postgres => format!("${index}"),
+        }
+    }
+}
+
+/// A single bound parameter or column value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DbValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+impl From<&str> for DbValue {
+    fn from(v: &str) -> Self {
+        Self::Text(v.to_string())
+    }
+}
+
+impl From<String> for DbValue {
+    fn from(v: String) -> Self {
+        Self::Text(v)
+    }
+}
+
+impl From<i64> for DbValue {
+    fn from(v: i64) -> Self {
+        Self::Integer(v)
+    }
+}
+
+impl From<f64> for DbValue {
+    fn from(v: f64) -> Self {
+        Self::Real(v)
+    }
+}
+
+impl From<bool> for DbValue {
+    fn from(v: bool) -> Self {
+        Self::Integer(v as i64)
+    }
+}
+
+impl From<Uuid> for DbValue {
+    fn from(v: Uuid) -> Self {
+        Self::Text(v.to_string())
+    }
+}
+
+impl From<DateTime<Utc>> for DbValue {
+    fn from(v: DateTime<Utc>) -> Self {
+        Self::Text(v.to_rfc3339())
+    }
+}
+
+impl<T: Into<DbValue>> From<Option<T>> for DbValue {
+    fn from(v: Option<T>) -> Self {
+        v.map_or(Self::Null, Into::into)
+    }
+}
+
+/// A result row keyed by column name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DbRow {
+    columns: Vec<(String, DbValue)>,
+}
+
+impl DbRow {
+    pub fn new(columns: Vec<(String, DbValue)>) -> Self {
+        Self { columns }
+    }
+
+    /// Raw value of `column`, if present.
+    pub fn get(&self, column: &str) -> Option<&DbValue> {
+        self.columns
+            .iter()
+            .find(|(name, _)| name == column)
+            .map(|(_, v)| v)
+    }
+
+    fn require(&self, column: &str) -> DomainResult<&DbValue> {
+        self.get(column)
+            .ok_or_else(|| DomainError::DatabaseError(format!("missing column '{column}'")))
+    }
+
+    /// Nullable text column.
+    pub fn opt_text(&self, column: &str) -> DomainResult<Option<String>> {
+        match self.require(column)? {
+            DbValue::Null => Ok(None),
+            DbValue::Text(s) => Ok(Some(s.clone())),
+            other => Err(DomainError::SerializationError(format!(
+                "column '{column}' is not text: {other:?}"
+            ))),
+        }
+    }
+
+    /// Non-null text column.
+    pub fn text(&self, column: &str) -> DomainResult<String> {
+        self.opt_text(column)?
+            .ok_or_else(|| DomainError::SerializationError(format!("column '{column}' is null")))
+    }
+
+    /// Nullable integer column.
+    pub fn opt_int(&self, column: &str) -> DomainResult<Option<i64>> {
+        match self.require(column)? {
+            DbValue::Null => Ok(None),
+            DbValue::Integer(i) => Ok(Some(*i)),
+            other => Err(DomainError::SerializationError(format!(
+                "column '{column}' is not an integer: {other:?}"
+            ))),
+        }
+    }
+
+    /// Non-null integer column.
+    pub fn int(&self, column: &str) -> DomainResult<i64> {
+        self.opt_int(column)?
+            .ok_or_else(|| DomainError::SerializationError(format!("column '{column}' is null")))
+    }
+
+    /// UUID stored as text.
+    pub fn uuid(&self, column: &str) -> DomainResult<Uuid> {
+        parse_uuid(&self.text(column)?)
+    }
+
+    /// RFC3339 datetime stored as text.
+    pub fn datetime(&self, column: &str) -> DomainResult<DateTime<Utc>> {
+        parse_datetime(&self.text(column)?)
+    }
+
+    /// Nullable RFC3339 datetime stored as text.
+    pub fn opt_datetime(&self, column: &str) -> DomainResult<Option<DateTime<Utc>>> {
+        parse_optional_datetime(self.opt_text(column)?)
+    }
+}
+
+/// Narrow query interface a persistence backend implements.
+///
+/// SQL passed to these methods must use the backend's placeholder syntax;
+/// see [`DatabaseBackend::placeholder`].
+#[async_trait]
+pub trait Database: Send + Sync {
+    /// The engine behind this handle.
+    fn backend(&self) -> DatabaseBackend;
+
+    /// Run a statement and return the number of affected rows.
+    async fn execute(&self, sql: &str, params: &[DbValue]) -> DomainResult<u64>;
+
+    /// Run a query and return every row.
+    async fn fetch_all(&self, sql: &str, params: &[DbValue]) -> DomainResult<Vec<DbRow>>;
+
+    /// Run a query and return the first row, if any.
+    async fn fetch_optional(&self, sql: &str, params: &[DbValue]) -> DomainResult<Option<DbRow>> {
+        Ok(self.fetch_all(sql, params).await?.into_iter().next())
+    }
+}
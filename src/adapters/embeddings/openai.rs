@@ -139,6 +139,10 @@ impl EmbeddingProvider for OpenAiEmbeddingProvider {
         self.config.dimension
     }
 
+    fn model_id(&self) -> String {
+        format!("openai/{}@{}", self.config.model, self.config.dimension)
+    }
+
     async fn embed(&self, text: &str) -> DomainResult<Vec<f32>> {
         let results = self.call_embeddings_api(vec![text.to_string()]).await?;
         results
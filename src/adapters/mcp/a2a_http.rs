@@ -12,6 +12,7 @@
 //! * [`agent`]         — `agent/card`, `agent/skills`
 //! * [`federation`]    — `federation/*` (10 methods)
 //! * [`rest`]          — REST endpoints + `.well-known/agent.json` + delegation queue
+//!   + federation status callbacks
 
 mod agent;
 mod dispatch;
@@ -19,6 +20,8 @@ mod federation;
 mod rest;
 mod tasks;
 
+pub(crate) use rest::FEDERATION_CALLBACK_PATH;
+
 use axum::{
     Router,
     extract::State,
@@ -47,6 +50,8 @@ use axum::http::Request;
 use axum::middleware::Next;
 use axum::response::Response;
 
+use crate::adapters::mcp::auth::{HttpAuth, with_auth};
+use crate::adapters::mcp::tls::build_server_config;
 use crate::domain::models::a2a::{A2AAgentCard, A2AMessage};
 
 /// A2A-specific JSON-RPC error codes.
@@ -111,6 +116,20 @@ pub enum A2ATaskState {
     Canceled,
 }
 
+impl From<crate::domain::models::a2a_protocol::A2ATaskState> for A2ATaskState {
+    fn from(state: crate::domain::models::a2a_protocol::A2ATaskState) -> Self {
+        use crate::domain::models::a2a_protocol::A2ATaskState as Wire;
+        match state {
+            Wire::Submitted => Self::Submitted,
+            Wire::Working => Self::Working,
+            Wire::InputRequired | Wire::AuthRequired => Self::InputRequired,
+            Wire::Completed => Self::Completed,
+            Wire::Failed | Wire::Rejected => Self::Failed,
+            Wire::Canceled => Self::Canceled,
+        }
+    }
+}
+
 impl std::fmt::Display for A2ATaskState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -221,6 +240,8 @@ pub struct TaskSendParams {
     pub message: A2AProtocolMessage,
     #[serde(default)]
     pub metadata: Option<Value>,
+    #[serde(default, rename = "pushNotificationConfig")]
+    pub push_notification_config: Option<PushNotificationConfig>,
 }
 
 /// Task get parameters.
@@ -251,7 +272,7 @@ pub struct PushNotificationConfig {
     pub url: String,
     #[serde(default)]
     pub headers: HashMap<String, String>,
-    #[serde(rename = "authToken")]
+    #[serde(rename = "authToken", alias = "token")]
     pub auth_token: Option<String>,
 }
 
@@ -407,6 +428,10 @@ pub struct A2AHttpConfig {
     /// When set, all `federation/*` JSON-RPC methods require a valid JWT
     /// in the `Authorization: Bearer <token>` header.
     pub federation_jwt_secret: Option<Vec<u8>>,
+    /// Authentication required on every non-health endpoint. A `Bearer`
+    /// scheme shares the `Authorization` header with federation JWTs, so
+    /// prefer `Hmac` when both are enabled.
+    pub auth: HttpAuth,
 }
 
 /// TLS configuration for the federation gateway (mTLS).
@@ -448,6 +473,7 @@ impl Default for A2AHttpConfig {
             max_stream_duration_s: 3600,
             federation_tls: None,
             federation_jwt_secret: None,
+            auth: HttpAuth::default(),
         }
     }
 }
@@ -488,7 +514,7 @@ impl InMemoryTask {
             metadata: params.metadata.clone(),
             created_at: now,
             updated_at: now,
-            push_config: None,
+            push_config: params.push_notification_config.clone(),
         }
     }
 
@@ -651,6 +677,11 @@ impl A2AHttpGateway {
                 "/api/v1/delegations/{delegation_id}/ack",
                 post(rest::acknowledge_delegation),
             )
+            // Status callbacks from cerebrates for tasks we delegated
+            .route(
+                rest::FEDERATION_CALLBACK_PATH,
+                post(rest::handle_federation_callback),
+            )
             .with_state(state.clone());
 
         // Apply JWT middleware for federation endpoint authentication
@@ -659,6 +690,7 @@ impl A2AHttpGateway {
             jwt_state,
             federation_jwt_middleware,
         ));
+        let app = with_auth(app, &self.config.auth);
 
         if self.config.enable_cors {
             app.layer(
@@ -764,54 +796,11 @@ impl A2AHttpGateway {
 fn build_rustls_server_config(
     tls_config: &FederationTlsGatewayConfig,
 ) -> Result<Arc<rustls::ServerConfig>, Box<dyn std::error::Error + Send + Sync>> {
-    use rustls::pki_types::{CertificateDer, PrivateKeyDer};
-    use std::io::BufReader;
-
-    // Load server certificate chain
-    let cert_file = std::fs::File::open(&tls_config.cert_path)
-        .map_err(|e| format!("Failed to open cert file '{}': {}", tls_config.cert_path, e))?;
-    let certs: Vec<CertificateDer<'static>> = rustls_pemfile::certs(&mut BufReader::new(cert_file))
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| format!("Failed to parse certificates: {}", e))?;
-
-    // Load server private key
-    let key_file = std::fs::File::open(&tls_config.key_path)
-        .map_err(|e| format!("Failed to open key file '{}': {}", tls_config.key_path, e))?;
-    let key: PrivateKeyDer<'static> = rustls_pemfile::private_key(&mut BufReader::new(key_file))
-        .map_err(|e| format!("Failed to parse private key: {}", e))?
-        .ok_or("No private key found in key file")?;
-
-    let config = if let Some(ref ca_path) = tls_config.ca_path {
-        // mTLS: require client certificates verified against the provided CA
-        let ca_file = std::fs::File::open(ca_path)
-            .map_err(|e| format!("Failed to open CA file '{}': {}", ca_path, e))?;
-        let ca_certs: Vec<CertificateDer<'static>> =
-            rustls_pemfile::certs(&mut BufReader::new(ca_file))
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| format!("Failed to parse CA certificates: {}", e))?;
-
-        let mut root_store = rustls::RootCertStore::empty();
-        for cert in ca_certs {
-            root_store.add(cert)?;
-        }
-
-        let client_verifier = rustls::server::WebPkiClientVerifier::builder(Arc::new(root_store))
-            .build()
-            .map_err(|e| format!("Failed to build client verifier: {}", e))?;
-
-        rustls::ServerConfig::builder()
-            .with_client_cert_verifier(client_verifier)
-            .with_single_cert(certs, key)
-            .map_err(|e| format!("Failed to build TLS config: {}", e))?
-    } else {
-        // TLS only (no client cert required)
-        rustls::ServerConfig::builder()
-            .with_no_client_auth()
-            .with_single_cert(certs, key)
-            .map_err(|e| format!("Failed to build TLS config: {}", e))?
-    };
-
-    Ok(Arc::new(config))
+    build_server_config(
+        &tls_config.cert_path,
+        &tls_config.key_path,
+        tls_config.ca_path.as_deref(),
+    )
 }
 
 // ============================================================================
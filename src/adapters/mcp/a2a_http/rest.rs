@@ -6,10 +6,11 @@
 //! - `/agents/*` REST agent discovery
 //! - `/tasks/*` REST task CRUD + SSE streaming
 //! - `/api/v1/delegations/*` delegation queue used by the orchestrator
+//! - `/api/v1/federation/callbacks` status pushes from cerebrates
 
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     response::{
         Json,
         sse::{Event, KeepAlive, Sse},
@@ -25,12 +26,19 @@ use std::time::Duration;
 use uuid::Uuid;
 
 use crate::domain::models::a2a::A2AAgentCard;
+use crate::services::federation::CallbackError;
 
+use super::tasks::{NOTIFICATION_TOKEN_HEADER, notify_push};
 use super::{
     A2ASkill, A2AState, A2ATask, A2ATaskState, A2ATaskStatus, ErrorResponse, InMemoryTask,
     PendingDelegation, TaskSendParams,
 };
 
+/// Route cerebrates POST task status transitions to. Exempt from the
+/// gateway's HTTP auth: callers authenticate with the federation
+/// callback secret instead.
+pub(crate) const FEDERATION_CALLBACK_PATH: &str = "/api/v1/federation/callbacks";
+
 pub(super) async fn health_check() -> &'static str {
     "OK"
 }
@@ -65,7 +73,7 @@ pub(super) async fn handle_well_known_agent_card(
         }),
         capabilities: A2ACapabilities {
             streaming: true,
-            push_notifications: false,
+            push_notifications: state.config.enable_push_notifications,
             state_transition_history: false,
         },
         skills: vec![A2ASkill {
@@ -279,6 +287,7 @@ pub(super) async fn cancel_task(
 
     task.state = A2ATaskState::Canceled;
     task.updated_at = Utc::now();
+    notify_push(&state, task);
 
     Ok(Json(A2ATask {
         id: task.id.clone(),
@@ -378,3 +387,64 @@ pub(super) async fn stream_task(
     Ok(Sse::new(stream).keep_alive(KeepAlive::new().interval(heartbeat_interval)))
 }
 
+/// Handle POST /api/v1/federation/callbacks — a cerebrate reporting a
+/// status transition for a task we delegated to it.
+///
+/// The `X-A2A-Notification-Token` header must match
+/// `federation.callback_secret`. On success the new state is mirrored onto
+/// any local gateway task tracking the same federation task.
+pub(super) async fn handle_federation_callback(
+    State(state): State<Arc<A2AState>>,
+    headers: HeaderMap,
+    Json(task): Json<crate::domain::models::a2a_protocol::A2ATask>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<ErrorResponse>)> {
+    let Some(ref fed) = state.federation_service else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "Federation is not enabled".to_string(),
+                code: "FEDERATION_DISABLED".to_string(),
+            }),
+        ));
+    };
+
+    let token = headers
+        .get(NOTIFICATION_TOKEN_HEADER)
+        .and_then(|v| v.to_str().ok());
+    let status = fed.handle_task_callback(token, &task).await.map_err(|e| {
+        let (code, label) = match e {
+            CallbackError::Disabled => (StatusCode::NOT_FOUND, "CALLBACKS_DISABLED"),
+            CallbackError::Unauthorized => (StatusCode::UNAUTHORIZED, "INVALID_CALLBACK_TOKEN"),
+            CallbackError::UnknownTask(_) => (StatusCode::NOT_FOUND, "TASK_NOT_FOUND"),
+        };
+        tracing::warn!(remote_task_id = %task.id, error = %e, "Rejected federation status callback");
+        (
+            code,
+            Json(ErrorResponse {
+                error: e.to_string(),
+                code: label.to_string(),
+            }),
+        )
+    })?;
+
+    // Mirror onto gateway tasks that front this delegation (created by
+    // tasks/sendSubscribe with `abathur:federation.task_id` metadata).
+    let local_id = status.task_id.to_string();
+    let mut tasks = state.tasks.write().await;
+    for local in tasks.values_mut().filter(|t| {
+        t.metadata
+            .as_ref()
+            .and_then(|m| m.get("abathur:federation"))
+            .and_then(|f| f.get("task_id"))
+            .and_then(|v| v.as_str())
+            == Some(local_id.as_str())
+    }) {
+        local.state = status.state.into();
+        local.updated_at = Utc::now();
+    }
+
+    Ok(Json(json!({
+        "taskId": local_id,
+        "state": status.state.as_str(),
+    })))
+}
@@ -1,5 +1,6 @@
 //! `tasks/*` JSON-RPC handlers — send, get, cancel, sendSubscribe (SSE),
-//! pushNotificationConfig.
+//! pushNotificationConfig — plus [`notify_push`], which delivers status
+//! transitions to a task's registered push-notification URL.
 //!
 //! Also hosts `handle_federation_routing` (the `tasks/send` fast path that
 //! recognises `abathur:federation` metadata and routes into
@@ -67,6 +68,7 @@ pub(super) async fn handle_tasks_send(
             existing.history.push(params.message);
             existing.state = A2ATaskState::Working;
             existing.updated_at = Utc::now();
+            notify_push(&state, existing);
             existing.clone()
         } else {
             tasks.insert(task_id.clone(), new_task.clone());
@@ -563,6 +565,7 @@ pub(super) async fn handle_tasks_send_subscribe(
             existing.history.push(params.message.clone());
             existing.state = A2ATaskState::Working;
             existing.updated_at = Utc::now();
+            notify_push(&state, existing);
         } else {
             tasks.insert(task_id.clone(), new_task.clone());
         }
@@ -603,6 +606,7 @@ pub(super) async fn handle_tasks_send_subscribe(
                             }
                         }));
                         t.updated_at = Utc::now();
+                        notify_push(&state_for_spawn, t);
                     }
                 }
                 Err(e) => {
@@ -613,6 +617,7 @@ pub(super) async fn handle_tasks_send_subscribe(
                             "error": format!("Federation delegation failed: {}", e),
                         }));
                         t.updated_at = Utc::now();
+                        notify_push(&state_for_spawn, t);
                     }
                 }
             }
@@ -803,6 +808,7 @@ pub(super) async fn handle_tasks_cancel(
 
     task.state = A2ATaskState::Canceled;
     task.updated_at = Utc::now();
+    notify_push(&state, task);
 
     let response = A2ATask {
         id: task.id.clone(),
@@ -873,3 +879,39 @@ pub(super) async fn handle_push_notification_config(
         json!({"taskId": params.id, "configured": true}),
     ))
 }
+
+/// Header carrying the subscriber's token on outbound push notifications.
+pub(super) const NOTIFICATION_TOKEN_HEADER: &str = "X-A2A-Notification-Token";
+
+/// POST `task`'s current status to its registered push-notification URL.
+///
+/// Fire-and-forget: delivery failures are logged, and the subscriber is
+/// expected to fall back to polling `tasks/get`.
+pub(super) fn notify_push(state: &A2AState, task: &InMemoryTask) {
+    if !state.config.enable_push_notifications {
+        return;
+    }
+    let Some(config) = task.push_config.clone() else {
+        return;
+    };
+    let mut body = task.to_a2a_task();
+    body.history = None;
+
+    tokio::spawn(async move {
+        let mut req = reqwest::Client::new().post(&config.url).json(&body);
+        for (name, value) in &config.headers {
+            req = req.header(name, value);
+        }
+        if let Some(ref token) = config.auth_token {
+            req = req.header(NOTIFICATION_TOKEN_HEADER, token);
+        }
+        if let Err(e) = req.send().await.and_then(|r| r.error_for_status()) {
+            tracing::warn!(
+                task_id = %body.id,
+                url = %config.url,
+                error = %e,
+                "Push notification delivery failed"
+            );
+        }
+    });
+}
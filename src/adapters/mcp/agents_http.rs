@@ -14,10 +14,11 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::net::TcpListener;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 
+use crate::adapters::mcp::auth::{HttpAuth, with_auth};
+use crate::adapters::mcp::tls::{TlsConfig, serve_router};
 use crate::domain::models::agent::{AgentConstraint, AgentTier, ToolCapability};
 use crate::domain::ports::AgentRepository;
 use crate::services::AgentService;
@@ -31,6 +32,10 @@ pub struct AgentsHttpConfig {
     pub port: u16,
     /// Whether to enable CORS.
     pub enable_cors: bool,
+    /// Authentication required on every non-health endpoint.
+    pub auth: HttpAuth,
+    /// Serve HTTPS with this certificate and key instead of plain HTTP.
+    pub tls: Option<TlsConfig>,
 }
 
 impl Default for AgentsHttpConfig {
@@ -39,6 +44,8 @@ impl Default for AgentsHttpConfig {
             host: "127.0.0.1".to_string(),
             port: 9102,
             enable_cors: true,
+            auth: HttpAuth::default(),
+            tls: None,
         }
     }
 }
@@ -150,6 +157,7 @@ impl<A: AgentRepository + Clone + Send + Sync + 'static> AgentsHttpServer<A> {
             .route("/api/v1/agents/{name}", delete(disable_agent::<A>))
             .route("/health", get(health_check))
             .with_state(state);
+        let app = with_auth(app, &self.config.auth);
 
         if self.config.enable_cors {
             app.layer(
@@ -167,13 +175,17 @@ impl<A: AgentRepository + Clone + Send + Sync + 'static> AgentsHttpServer<A> {
     /// Start the server.
     pub async fn serve(self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let addr: SocketAddr = format!("{}:{}", self.config.host, self.config.port).parse()?;
+        let tls = self.config.tls.clone();
         let router = self.build_router();
 
-        tracing::info!("MCP Agents HTTP server listening on {}", addr);
-
-        let listener = TcpListener::bind(addr).await?;
-        axum::serve(listener, router).await?;
-        Ok(())
+        serve_router(
+            "MCP Agents HTTP server",
+            addr,
+            router,
+            tls.as_ref(),
+            std::future::pending(),
+        )
+        .await
     }
 
     /// Start the server with a shutdown signal.
@@ -185,15 +197,17 @@ impl<A: AgentRepository + Clone + Send + Sync + 'static> AgentsHttpServer<A> {
         F: std::future::Future<Output = ()> + Send + 'static,
     {
         let addr: SocketAddr = format!("{}:{}", self.config.host, self.config.port).parse()?;
+        let tls = self.config.tls.clone();
         let router = self.build_router();
 
-        tracing::info!("MCP Agents HTTP server listening on {}", addr);
-
-        let listener = TcpListener::bind(addr).await?;
-        axum::serve(listener, router)
-            .with_graceful_shutdown(shutdown)
-            .await?;
-        Ok(())
+        serve_router(
+            "MCP Agents HTTP server",
+            addr,
+            router,
+            tls.as_ref(),
+            shutdown,
+        )
+        .await
     }
 }
 
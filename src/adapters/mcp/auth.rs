@@ -0,0 +1,328 @@
+//! Pluggable request authentication for the MCP/HTTP servers.
+//!
+//! Every HTTP server (memory, tasks, agents, events, A2A) carries an
+//! [`HttpAuth`] in its config and wraps its router with [`with_auth`].
+//! The default scheme is [`HttpAuth::AllowAll`], which preserves the
+//! historical unauthenticated behavior for servers bound to localhost.
+//!
+//! Supported schemes:
+//! - `bearer`: `Authorization: Bearer <token>` must match the shared secret.
+//! - `hmac`: `X-Abathur-Timestamp` (unix seconds) plus
+//!   `X-Abathur-Signature: sha256=<hex>` over
+//!   `"{timestamp}.{METHOD}.{path_and_query}.{body}"` keyed by the secret.
+//!
+//! Mutual TLS is configured separately on the A2A gateway via
+//! [`FederationTlsGatewayConfig`](super::FederationTlsGatewayConfig), since
+//! client verification happens during the TLS handshake rather than here.
+//!
+//! `/health` is always exempt so readiness probes keep working, as are
+//! routes that check their own shared secret (federation callbacks and the
+//! tasks webhook).
+
+use axum::{
+    Router,
+    body::Body,
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::Arc;
+
+use crate::services::config::{HttpAuthConfig, HttpAuthScheme};
+
+/// Header carrying the HMAC request timestamp (unix seconds).
+pub const HMAC_TIMESTAMP_HEADER: &str = "x-abathur-timestamp";
+/// Header carrying the HMAC request signature (`sha256=<hex>`).
+pub const HMAC_SIGNATURE_HEADER: &str = "x-abathur-signature";
+/// Maximum accepted clock skew for HMAC-signed requests.
+pub const HMAC_MAX_SKEW_SECS: i64 = 300;
+/// Largest request body buffered for HMAC verification.
+const MAX_SIGNED_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// Authentication scheme enforced on inbound HTTP requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum HttpAuth {
+    /// Accept every request (localhost-only deployments).
+    #[default]
+    AllowAll,
+    /// Require `Authorization: Bearer <token>`.
+    Bearer { token: String },
+    /// Require an HMAC-SHA256 signature over the request.
+    Hmac { secret: String },
+}
+
+impl HttpAuth {
+    /// Build from the `[http_auth]` section of `abathur.toml`.
+    ///
+    /// `Config::load_http_auth` rejects non-`allow_all` schemes without a
+    /// secret; a missing secret here falls back to `AllowAll` with a warning.
+    pub fn from_config(config: &HttpAuthConfig) -> Self {
+        let secret = config.secret.clone().filter(|s| !s.is_empty());
+        match (config.scheme, secret) {
+            (HttpAuthScheme::AllowAll, _) => Self::AllowAll,
+            (HttpAuthScheme::Bearer, Some(token)) => Self::Bearer { token },
+            (HttpAuthScheme::Hmac, Some(secret)) => Self::Hmac { secret },
+            (scheme, None) => {
+                tracing::warn!(
+                    ?scheme,
+                    "http_auth scheme has no secret; allowing all requests"
+                );
+                Self::AllowAll
+            }
+        }
+    }
+
+    /// Short scheme name for logs and status output.
+    pub fn scheme_name(&self) -> &'static str {
+        match self {
+            Self::AllowAll => "allow_all",
+            Self::Bearer { .. } => "bearer",
+            Self::Hmac { .. } => "hmac",
+        }
+    }
+
+    /// Check a request's headers (and, for HMAC, its body) against this scheme.
+    pub fn verify(
+        &self,
+        method: &str,
+        path_and_query: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> bool {
+        match self {
+            Self::AllowAll => true,
+            Self::Bearer { token } => headers
+                .get("authorization")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.strip_prefix("Bearer "))
+                .is_some_and(|presented| constant_time_eq(presented.as_bytes(), token.as_bytes())),
+            Self::Hmac { secret } => {
+                let header = |name| headers.get(name).and_then(|v| v.to_str().ok());
+                let (Some(timestamp), Some(signature)) =
+                    (header(HMAC_TIMESTAMP_HEADER), header(HMAC_SIGNATURE_HEADER))
+                else {
+                    return false;
+                };
+                let Ok(ts) = timestamp.parse::<i64>() else {
+                    return false;
+                };
+                if (chrono::Utc::now().timestamp() - ts).abs() > HMAC_MAX_SKEW_SECS {
+                    return false;
+                }
+                let expected = sign_hmac(secret, timestamp, method, path_and_query, body);
+                constant_time_eq(signature.as_bytes(), expected.as_bytes())
+            }
+        }
+    }
+}
+
+/// Compute the `sha256=<hex>` signature a client must send under [`HttpAuth::Hmac`].
+pub fn sign_hmac(
+    secret: &str,
+    timestamp: &str,
+    method: &str,
+    path_and_query: &str,
+    body: &[u8],
+) -> String {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    type HmacSha256 = Hmac<Sha256>;
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(format!("{}.{}.{}.", timestamp, method, path_and_query).as_bytes());
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Wrap `router` with the auth middleware unless `auth` is `AllowAll`.
+///
+/// Apply before CORS so preflight requests are answered without credentials.
+pub fn with_auth(router: Router, auth: &HttpAuth) -> Router {
+    if *auth == HttpAuth::AllowAll {
+        return router;
+    }
+    router.layer(axum::middleware::from_fn_with_state(
+        Arc::new(auth.clone()),
+        require_auth,
+    ))
+}
+
+async fn require_auth(
+    State(auth): State<Arc<HttpAuth>>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    // Federation status callbacks carry their own shared-secret token,
+    // checked by the handler; cerebrates do not hold our HTTP credentials.
+    // The same goes for webhook callers, which present the webhook secret.
+    let path = req.uri().path();
+    if path == "/health"
+        || path == crate::adapters::mcp::a2a_http::FEDERATION_CALLBACK_PATH
+        || path.starts_with(crate::adapters::mcp::tasks_http::WEBHOOK_PATH_PREFIX)
+    {
+        return Ok(next.run(req).await);
+    }
+
+    let method = req.method().to_string();
+    let path_and_query = req
+        .uri()
+        .path_and_query()
+        .map(|pq| pq.as_str().to_string())
+        .unwrap_or_default();
+
+    let req = match auth.as_ref() {
+        HttpAuth::Hmac { .. } => {
+            let (parts, body) = req.into_parts();
+            let bytes = axum::body::to_bytes(body, MAX_SIGNED_BODY_BYTES)
+                .await
+                .map_err(|_| StatusCode::PAYLOAD_TOO_LARGE)?;
+            if !auth.verify(&method, &path_and_query, &parts.headers, &bytes) {
+                return Err(unauthorized(&auth, &path_and_query));
+            }
+            Request::from_parts(parts, Body::from(bytes))
+        }
+        _ => {
+            if !auth.verify(&method, &path_and_query, req.headers(), &[]) {
+                return Err(unauthorized(&auth, &path_and_query));
+            }
+            req
+        }
+    };
+
+    Ok(next.run(req).await)
+}
+
+fn unauthorized(auth: &HttpAuth, path: &str) -> StatusCode {
+    tracing::warn!(
+        scheme = auth.scheme_name(),
+        path,
+        "Rejected unauthenticated HTTP request"
+    );
+    StatusCode::UNAUTHORIZED
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::{get, post};
+    use tower::ServiceExt;
+
+    fn app(auth: &HttpAuth) -> Router {
+        let router = Router::new()
+            .route("/api/v1/things", post(|body: String| async move { body }))
+            .route("/health", get(|| async { "OK" }));
+        with_auth(router, auth)
+    }
+
+    async fn status(router: Router, req: Request<Body>) -> StatusCode {
+        router.oneshot(req).await.unwrap().status()
+    }
+
+    fn post_req(headers: &[(&str, &str)], body: &str) -> Request<Body> {
+        let mut builder = Request::post("/api/v1/things");
+        for (k, v) in headers {
+            builder = builder.header(*k, *v);
+        }
+        builder.body(Body::from(body.to_string())).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_bearer_rejects_unauthenticated_request() {
+        let auth = HttpAuth::Bearer {
+            token: "s3cret".to_string(),
+        };
+
+        assert_eq!(
+            status(app(&auth), post_req(&[], "{}")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status(
+                app(&auth),
+                post_req(&[("authorization", "Bearer wrong")], "{}")
+            )
+            .await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status(
+                app(&auth),
+                post_req(&[("authorization", "Bearer s3cret")], "{}")
+            )
+            .await,
+            StatusCode::OK
+        );
+        let health = Request::get("/health").body(Body::empty()).unwrap();
+        assert_eq!(status(app(&auth), health).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_allow_all_accepts_everything() {
+        assert_eq!(
+            status(app(&HttpAuth::AllowAll), post_req(&[], "{}")).await,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hmac_verifies_signature_over_body() {
+        let auth = HttpAuth::Hmac {
+            secret: "k".to_string(),
+        };
+        let ts = chrono::Utc::now().timestamp().to_string();
+        let sig = sign_hmac("k", &ts, "POST", "/api/v1/things", b"{\"a\":1}");
+        let headers = [
+            (HMAC_TIMESTAMP_HEADER, ts.as_str()),
+            (HMAC_SIGNATURE_HEADER, sig.as_str()),
+        ];
+
+        assert_eq!(
+            status(app(&auth), post_req(&headers, "{\"a\":1}")).await,
+            StatusCode::OK
+        );
+        // Tampered body no longer matches the signature.
+        assert_eq!(
+            status(app(&auth), post_req(&headers, "{\"a\":2}")).await,
+            StatusCode::UNAUTHORIZED
+        );
+
+        let stale = (chrono::Utc::now().timestamp() - HMAC_MAX_SKEW_SECS - 10).to_string();
+        let stale_sig = sign_hmac("k", &stale, "POST", "/api/v1/things", b"{}");
+        let stale_headers = [
+            (HMAC_TIMESTAMP_HEADER, stale.as_str()),
+            (HMAC_SIGNATURE_HEADER, stale_sig.as_str()),
+        ];
+        assert_eq!(
+            status(app(&auth), post_req(&stale_headers, "{}")).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[test]
+    fn test_from_config_requires_secret() {
+        let cfg = HttpAuthConfig {
+            scheme: HttpAuthScheme::Bearer,
+            secret: None,
+            ..Default::default()
+        };
+        assert_eq!(HttpAuth::from_config(&cfg), HttpAuth::AllowAll);
+
+        let cfg = HttpAuthConfig {
+            scheme: HttpAuthScheme::Hmac,
+            secret: Some("x".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            HttpAuth::from_config(&cfg),
+            HttpAuth::Hmac {
+                secret: "x".to_string()
+            }
+        );
+    }
+}
@@ -28,6 +28,7 @@ use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 use uuid::Uuid;
 
+use crate::adapters::mcp::auth::{HttpAuth, with_auth};
 use crate::services::event_bus::{EventBus, EventCategory, SequenceNumber, UnifiedEvent};
 use crate::services::event_store::{EventQuery, EventStore};
 
@@ -46,6 +47,8 @@ pub struct EventsHttpConfig {
     pub max_history_limit: u32,
     /// Default page size for history queries.
     pub default_page_size: u32,
+    /// Authentication required on every non-health endpoint.
+    pub auth: HttpAuth,
 }
 
 impl Default for EventsHttpConfig {
@@ -57,6 +60,7 @@ impl Default for EventsHttpConfig {
             heartbeat_interval_ms: 30000,
             max_history_limit: 1000,
             default_page_size: 100,
+            auth: HttpAuth::default(),
         }
     }
 }
@@ -112,8 +116,8 @@ impl EventsHttpServer {
             .route("/api/v1/webhooks/{id}", delete(delete_webhook))
             .route("/api/v1/webhooks/{id}/test", post(test_webhook))
             .route("/health", get(health_check))
-            .with_state(self.state.clone())
-            .layer(TraceLayer::new_for_http());
+            .with_state(self.state.clone());
+        router = with_auth(router, &self.state.config.auth).layer(TraceLayer::new_for_http());
 
         if self.state.config.enable_cors {
             router = router.layer(
@@ -212,10 +216,19 @@ struct HistoryQuery {
     correlation_id: Option<Uuid>,
     category: Option<String>,
     limit: Option<u32>,
-    offset: Option<u32>,
+    /// `next_cursor` from the previous page.
+    cursor: Option<u64>,
     order: Option<String>,
 }
 
+/// One page of history; pass `next_cursor` back as `?cursor=` for the next.
+#[derive(Debug, Serialize)]
+struct HistoryResponse {
+    events: Vec<UnifiedEvent>,
+    /// Absent on the last page.
+    next_cursor: Option<u64>,
+}
+
 /// SSE stream of all events with `Last-Event-ID` replay support.
 ///
 /// If the client sends a `Last-Event-ID` header (standard SSE reconnection),
@@ -803,11 +816,15 @@ async fn replay_events(
     Ok(Json(events))
 }
 
-/// Query historical events with filters.
+/// Query historical events with filters, one page at a time.
+///
+/// Newest first unless `order=asc`. Pages are cursor-based, so events
+/// recorded while a client pages through history neither repeat nor go
+/// missing.
 async fn query_history(
     State(state): State<Arc<EventsState>>,
     Query(params): Query<HistoryQuery>,
-) -> Result<Json<Vec<UnifiedEvent>>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<HistoryResponse>, (StatusCode, Json<ErrorResponse>)> {
     let store = state.event_store.as_ref().ok_or_else(|| {
         (
             StatusCode::NOT_IMPLEMENTED,
@@ -823,7 +840,7 @@ async fn query_history(
         .unwrap_or(state.config.default_page_size)
         .min(state.config.max_history_limit);
 
-    let mut query = EventQuery::new().limit(limit);
+    let mut query = EventQuery::new();
 
     if let Some(since) = params.since_sequence {
         query = query.since_sequence(SequenceNumber(since));
@@ -845,26 +862,28 @@ async fn query_history(
     {
         query = query.category(category);
     }
-    if let Some(offset) = params.offset {
-        query = query.offset(offset);
-    }
-
     query = match params.order.as_deref() {
         Some("asc") | Some("ascending") => query.ascending(),
         _ => query.descending(),
     };
 
-    let events = store.query(query).await.map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: e.to_string(),
-                code: "QUERY_ERROR".to_string(),
-            }),
-        )
-    })?;
+    let (events, next_cursor) = store
+        .query_page(query, params.cursor.map(SequenceNumber), limit)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                    code: "QUERY_ERROR".to_string(),
+                }),
+            )
+        })?;
 
-    Ok(Json(events))
+    Ok(Json(HistoryResponse {
+        events,
+        next_cursor: next_cursor.map(|seq| seq.0),
+    }))
 }
 
 /// Get event store statistics.
@@ -963,4 +982,59 @@ mod tests {
         let state = EventsState::new(bus.clone(), None, EventsHttpConfig::default());
         assert!(state.event_store.is_none());
     }
+
+    #[tokio::test]
+    async fn test_history_pages_with_cursor() {
+        use crate::services::event_bus::{EventId, EventPayload, EventSeverity};
+        use crate::services::event_store::InMemoryEventStore;
+
+        let store = Arc::new(InMemoryEventStore::new());
+        for seq in 1..=5 {
+            store
+                .append(&UnifiedEvent {
+                    id: EventId::new(),
+                    sequence: SequenceNumber(seq),
+                    timestamp: chrono::Utc::now(),
+                    severity: EventSeverity::Info,
+                    category: EventCategory::Orchestrator,
+                    goal_id: None,
+                    task_id: None,
+                    correlation_id: None,
+                    source_process_id: None,
+                    payload: EventPayload::OrchestratorStarted,
+                })
+                .await
+                .unwrap();
+        }
+        let state = Arc::new(EventsState::new(
+            Arc::new(EventBus::new(EventBusConfig::default())),
+            Some(store),
+            EventsHttpConfig::default(),
+        ));
+
+        let mut cursor = None;
+        let mut pages = Vec::new();
+        loop {
+            let params = HistoryQuery {
+                since_sequence: None,
+                until_sequence: None,
+                goal_id: None,
+                task_id: None,
+                correlation_id: None,
+                category: None,
+                limit: Some(2),
+                cursor,
+                order: None,
+            };
+            let Json(page) = query_history(State(state.clone()), Query(params))
+                .await
+                .unwrap();
+            pages.push(page.events.iter().map(|e| e.sequence.0).collect::<Vec<_>>());
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(pages, vec![vec![5, 4], vec![3, 2], vec![1]]);
+    }
 }
@@ -2,12 +2,17 @@
 //!
 //! Extracted from `a2a_http.rs`. The client posts JSON-RPC `tasks/send`
 //! requests to trusted peer swarms, applies per-peer rate limiting, and
-//! parses the structured `A2ATask` reply.
+//! parses the structured `A2ATask` reply. `delegate_with_retry` adds
+//! exponential backoff and a per-peer circuit breaker so a dead peer is
+//! skipped instead of timed out against on every delegation.
 
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use serde_json::json;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+use thiserror::Error;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
@@ -15,12 +20,107 @@ use crate::adapters::mcp::a2a_http::{A2ATask, JsonRpcResponse};
 use crate::domain::models::a2a::A2AAgentCard;
 use crate::services::{A2AFederationConfig, TrustedSwarmConfig};
 
+/// Backoff schedule for [`FederationClient::delegate_with_retry`].
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Upper bound on any single delay.
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after each retry.
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay to wait after failed attempt number `attempt` (1-based).
+    pub fn backoff_for(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.powi(attempt.saturating_sub(1) as i32);
+        self.initial_backoff.mul_f64(factor).min(self.max_backoff)
+    }
+}
+
+/// Why a delegation through [`FederationClient::delegate_with_retry`] failed.
+#[derive(Debug, Error)]
+pub enum DelegationError {
+    /// The peer's circuit is open; it was not contacted. Callers should
+    /// escalate rather than wait on it.
+    #[error("Peer {peer_id} circuit open after {consecutive_failures} consecutive failures")]
+    CircuitOpen {
+        peer_id: String,
+        consecutive_failures: u32,
+    },
+    /// Every attempt failed to reach the peer or got a server error.
+    #[error("Peer {peer_id} unreachable after {attempts} attempts: {last_error}")]
+    Unreachable {
+        peer_id: String,
+        attempts: u32,
+        last_error: String,
+    },
+    /// The request was refused without a transport failure (unknown peer,
+    /// rate limit, JSON-RPC error); retrying would not help.
+    #[error("{0}")]
+    Rejected(String),
+}
+
+/// Reachability of one peer as seen by [`FederationClient`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PeerHealth {
+    /// Transport failures since the last success.
+    pub consecutive_failures: u32,
+    /// While set and in the future, delegations skip this peer.
+    pub open_until: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub last_success_at: Option<DateTime<Utc>>,
+}
+
+impl PeerHealth {
+    /// Whether the circuit is open at `now`.
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        self.open_until.is_some_and(|until| now < until)
+    }
+}
+
+/// Outcome of a single `tasks/send` attempt.
+enum SendError {
+    /// Transport failure or 5xx; counts against the peer's circuit.
+    Transient(String),
+    /// The peer answered but refused, or the request never left.
+    Rejected(String),
+}
+
+impl SendError {
+    fn into_message(self) -> String {
+        match self {
+            Self::Transient(e) | Self::Rejected(e) => e,
+        }
+    }
+}
+
 /// Client for delegating tasks to trusted peer swarms via A2A protocol.
 pub struct FederationClient {
     config: A2AFederationConfig,
     http_client: reqwest::Client,
     /// Per-peer request counters for rate limiting (peer_id -> count in current window).
     request_counts: Arc<RwLock<HashMap<String, u32>>>,
+    /// Per-peer circuit breaker state.
+    peer_health: Arc<RwLock<HashMap<String, PeerHealth>>>,
+    /// Consecutive transport failures that open a peer's circuit.
+    failure_threshold: u32,
+    /// How long an open circuit stays open before one probe is allowed.
+    open_duration: Duration,
 }
 
 impl FederationClient {
@@ -34,9 +134,25 @@ impl FederationClient {
             config,
             http_client,
             request_counts: Arc::new(RwLock::new(HashMap::new())),
+            peer_health: Arc::new(RwLock::new(HashMap::new())),
+            failure_threshold: 3,
+            open_duration: Duration::from_secs(300),
         }
     }
 
+    /// Open a peer's circuit after `failure_threshold` consecutive transport
+    /// failures, for `open_duration`.
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, open_duration: Duration) -> Self {
+        self.failure_threshold = failure_threshold.max(1);
+        self.open_duration = open_duration;
+        self
+    }
+
+    /// Circuit state of every peer contacted through `delegate_with_retry`.
+    pub async fn peer_status(&self) -> HashMap<String, PeerHealth> {
+        self.peer_health.read().await.clone()
+    }
+
     /// List available trusted peers that are active.
     pub fn list_available_peers(&self) -> Vec<&TrustedSwarmConfig> {
         self.config
@@ -83,7 +199,81 @@ impl FederationClient {
 
     /// Delegate a task to a trusted peer swarm.
     pub async fn delegate_task(&self, peer_id: &str, message: &str) -> Result<A2ATask, String> {
-        let peer = self.find_peer(peer_id)?;
+        self.send_task(peer_id, message)
+            .await
+            .map_err(SendError::into_message)
+    }
+
+    /// Delegate a task, retrying transport failures with backoff and
+    /// tracking the peer's circuit. Fails fast with
+    /// [`DelegationError::CircuitOpen`] while the peer's circuit is open.
+    pub async fn delegate_with_retry(
+        &self,
+        peer_id: &str,
+        message: &str,
+        policy: &RetryPolicy,
+    ) -> Result<A2ATask, DelegationError> {
+        let max_attempts = policy.max_attempts.max(1);
+        let mut attempt = 0;
+        loop {
+            if let Some(health) = self.peer_health.read().await.get(peer_id)
+                && health.is_open(Utc::now())
+            {
+                return Err(DelegationError::CircuitOpen {
+                    peer_id: peer_id.to_string(),
+                    consecutive_failures: health.consecutive_failures,
+                });
+            }
+
+            attempt += 1;
+            let error = match self.send_task(peer_id, message).await {
+                Ok(task) => {
+                    let mut health = self.peer_health.write().await;
+                    let entry = health.entry(peer_id.to_string()).or_default();
+                    entry.consecutive_failures = 0;
+                    entry.open_until = None;
+                    entry.last_success_at = Some(Utc::now());
+                    return Ok(task);
+                }
+                Err(SendError::Rejected(e)) => return Err(DelegationError::Rejected(e)),
+                Err(SendError::Transient(e)) => e,
+            };
+
+            let opened = self.record_transport_failure(peer_id, &error).await;
+            if opened || attempt >= max_attempts {
+                return Err(DelegationError::Unreachable {
+                    peer_id: peer_id.to_string(),
+                    attempts: attempt,
+                    last_error: error,
+                });
+            }
+            tokio::time::sleep(policy.backoff_for(attempt)).await;
+        }
+    }
+
+    /// Count a transport failure against `peer_id`; returns whether this
+    /// failure opened (or re-opened) its circuit.
+    async fn record_transport_failure(&self, peer_id: &str, error: &str) -> bool {
+        let mut health = self.peer_health.write().await;
+        let entry = health.entry(peer_id.to_string()).or_default();
+        entry.consecutive_failures += 1;
+        entry.last_error = Some(error.to_string());
+        if entry.consecutive_failures < self.failure_threshold {
+            return false;
+        }
+        let open_for = chrono::Duration::from_std(self.open_duration).unwrap_or_default();
+        entry.open_until = Some(Utc::now() + open_for);
+        tracing::warn!(
+            peer_id,
+            consecutive_failures = entry.consecutive_failures,
+            "federation peer circuit opened: {}",
+            error
+        );
+        true
+    }
+
+    async fn send_task(&self, peer_id: &str, message: &str) -> Result<A2ATask, SendError> {
+        let peer = self.find_peer(peer_id).map_err(SendError::Rejected)?;
 
         // Rate limiting
         {
@@ -93,7 +283,10 @@ impl FederationClient {
                 .rate_limit_override
                 .unwrap_or(self.config.rate_limit_per_swarm);
             if *count >= limit {
-                return Err(format!("Rate limit exceeded for peer {}", peer_id));
+                return Err(SendError::Rejected(format!(
+                    "Rate limit exceeded for peer {}",
+                    peer_id
+                )));
             }
             *count += 1;
         }
@@ -132,39 +325,41 @@ impl FederationClient {
             request = request.header("Authorization", format!("Bearer {}", token));
         }
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| format!("Failed to send task to peer {}: {}", peer_id, e))?;
+        let response = request.send().await.map_err(|e| {
+            SendError::Transient(format!("Failed to send task to peer {}: {}", peer_id, e))
+        })?;
 
-        if !response.status().is_success() {
-            return Err(format!(
-                "Peer {} returned status {}",
-                peer_id,
-                response.status()
-            ));
+        let status = response.status();
+        if !status.is_success() {
+            let message = format!("Peer {} returned status {}", peer_id, status);
+            return Err(if status.is_server_error() {
+                SendError::Transient(message)
+            } else {
+                SendError::Rejected(message)
+            });
         }
 
-        let rpc_response: JsonRpcResponse = response
-            .json()
-            .await
-            .map_err(|e| format!("Failed to parse response from peer {}: {}", peer_id, e))?;
+        let rpc_response: JsonRpcResponse = response.json().await.map_err(|e| {
+            SendError::Transient(format!(
+                "Failed to parse response from peer {}: {}",
+                peer_id, e
+            ))
+        })?;
 
         if let Some(error) = rpc_response.error {
-            return Err(format!(
+            return Err(SendError::Rejected(format!(
                 "Peer {} error: {} ({})",
                 peer_id, error.message, error.code
-            ));
+            )));
         }
 
         let result = rpc_response
             .result
-            .ok_or_else(|| format!("Peer {} returned no result", peer_id))?;
+            .ok_or_else(|| SendError::Rejected(format!("Peer {} returned no result", peer_id)))?;
 
-        let task: A2ATask = serde_json::from_value(result)
-            .map_err(|e| format!("Failed to parse task from peer {}: {}", peer_id, e))?;
-
-        Ok(task)
+        serde_json::from_value(result).map_err(|e| {
+            SendError::Rejected(format!("Failed to parse task from peer {}: {}", peer_id, e))
+        })
     }
 
     /// Reset rate limit counters (should be called periodically).
@@ -180,3 +375,180 @@ impl FederationClient {
             .ok_or_else(|| format!("Peer {} not found or inactive", peer_id))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// What the fake peer does with each connection, in order; the last
+    /// behavior repeats.
+    #[derive(Clone, Copy)]
+    enum Reply {
+        /// Read the request and never answer.
+        Hang,
+        ServerError,
+        Ok,
+    }
+
+    async fn fake_peer(replies: Vec<Reply>) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = hits.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let n = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let reply = replies[n.min(replies.len() - 1)];
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 8192];
+                    let _ = socket.read(&mut buf).await;
+                    let (status, body) = match reply {
+                        Reply::Hang => {
+                            std::future::pending::<()>().await;
+                            unreachable!()
+                        }
+                        Reply::ServerError => ("503 Service Unavailable", "{}".to_string()),
+                        Reply::Ok => (
+                            "200 OK",
+                            json!({
+                                "jsonrpc": "2.0",
+                                "id": "1",
+                                "result": {
+                                    "id": "remote-1",
+                                    "sessionId": "s",
+                                    "status": {"state": "submitted"}
+                                }
+                            })
+                            .to_string(),
+                        ),
+                    };
+                    let response = format!(
+                        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        (format!("http://{}", addr), hits)
+    }
+
+    fn client(endpoint: String) -> FederationClient {
+        FederationClient::new(A2AFederationConfig {
+            trusted_swarms: vec![TrustedSwarmConfig {
+                id: "peer".to_string(),
+                name: "Peer".to_string(),
+                endpoint,
+                active: true,
+                ..Default::default()
+            }],
+            external_request_timeout_secs: 1,
+            ..Default::default()
+        })
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+            multiplier: 2.0,
+        }
+    }
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff_for(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff_for(2), Duration::from_secs(1));
+        assert_eq!(policy.backoff_for(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff_for(10), Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_retries_server_errors_until_success() {
+        let (endpoint, hits) =
+            fake_peer(vec![Reply::ServerError, Reply::ServerError, Reply::Ok]).await;
+        let client = client(endpoint);
+
+        let task = client
+            .delegate_with_retry("peer", "do it", &fast_policy(3))
+            .await
+            .unwrap();
+        assert_eq!(task.id, "remote-1");
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        let health = &client.peer_status().await["peer"];
+        assert_eq!(health.consecutive_failures, 0);
+        assert!(health.last_success_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_timeouts_open_circuit_and_fail_fast() {
+        let (endpoint, hits) = fake_peer(vec![Reply::Hang]).await;
+        let client = client(endpoint).with_circuit_breaker(2, Duration::from_secs(60));
+
+        let err = client
+            .delegate_with_retry("peer", "do it", &fast_policy(5))
+            .await
+            .unwrap_err();
+        // The circuit opens on the second timeout, cutting the retries short.
+        assert!(matches!(
+            err,
+            DelegationError::Unreachable { attempts: 2, .. }
+        ));
+        assert!(client.peer_status().await["peer"].is_open(Utc::now()));
+
+        let err = client
+            .delegate_with_retry("peer", "do it", &fast_policy(5))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            DelegationError::CircuitOpen {
+                consecutive_failures: 2,
+                ..
+            }
+        ));
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_probe_closes_circuit_on_success() {
+        let (endpoint, _) = fake_peer(vec![Reply::ServerError, Reply::Ok]).await;
+        let client = client(endpoint).with_circuit_breaker(1, Duration::ZERO);
+
+        let err = client
+            .delegate_with_retry("peer", "do it", &fast_policy(3))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            DelegationError::Unreachable { attempts: 1, .. }
+        ));
+
+        // The open window has already lapsed, so the next call probes.
+        client
+            .delegate_with_retry("peer", "do it", &fast_policy(1))
+            .await
+            .unwrap();
+        assert!(!client.peer_status().await["peer"].is_open(Utc::now()));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_peer_is_rejected_without_touching_health() {
+        let client = client("http://127.0.0.1:9".to_string());
+        let err = client
+            .delegate_with_retry("nobody", "do it", &fast_policy(3))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DelegationError::Rejected(_)));
+        assert!(client.peer_status().await.is_empty());
+    }
+}
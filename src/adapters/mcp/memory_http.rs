@@ -13,12 +13,15 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::net::TcpListener;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 use uuid::Uuid;
 
-use crate::domain::models::{AccessorId, Memory, MemoryQuery, MemoryTier, MemoryType};
+use crate::adapters::mcp::auth::{HttpAuth, with_auth};
+use crate::adapters::mcp::tls::{TlsConfig, serve_router};
+use crate::domain::models::{
+    AccessorId, Memory, MemoryMetadata, MemoryQuery, MemoryTier, MemoryType,
+};
 use crate::domain::ports::MemoryRepository;
 use crate::services::MemoryService;
 use crate::services::command_bus::{
@@ -35,6 +38,10 @@ pub struct MemoryHttpConfig {
     pub port: u16,
     /// Whether to enable CORS.
     pub enable_cors: bool,
+    /// Authentication required on every non-health endpoint.
+    pub auth: HttpAuth,
+    /// Serve HTTPS with this certificate and key instead of plain HTTP.
+    pub tls: Option<TlsConfig>,
 }
 
 impl Default for MemoryHttpConfig {
@@ -43,6 +50,8 @@ impl Default for MemoryHttpConfig {
             host: "127.0.0.1".to_string(),
             port: 9100,
             enable_cors: true,
+            auth: HttpAuth::default(),
+            tls: None,
         }
     }
 }
@@ -58,6 +67,9 @@ pub struct StoreMemoryRequest {
     pub tier: Option<String>,
     #[serde(default)]
     pub memory_type: Option<String>,
+    /// Minimum decay factor (0.0-1.0), overriding the tier's floor.
+    #[serde(default)]
+    pub decay_floor: Option<f32>,
 }
 
 /// Request to update a memory.
@@ -218,6 +230,7 @@ impl<M: MemoryRepository + Clone + Send + Sync + 'static> MemoryHttpServer<M> {
             // Health check
             .route("/health", get(health_check))
             .with_state(state);
+        let app = with_auth(app, &self.config.auth);
 
         if self.config.enable_cors {
             app.layer(
@@ -235,13 +248,17 @@ impl<M: MemoryRepository + Clone + Send + Sync + 'static> MemoryHttpServer<M> {
     /// Start the server.
     pub async fn serve(self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let addr: SocketAddr = format!("{}:{}", self.config.host, self.config.port).parse()?;
+        let tls = self.config.tls.clone();
         let router = self.build_router();
 
-        tracing::info!("MCP Memory HTTP server listening on {}", addr);
-
-        let listener = TcpListener::bind(addr).await?;
-        axum::serve(listener, router).await?;
-        Ok(())
+        serve_router(
+            "MCP Memory HTTP server",
+            addr,
+            router,
+            tls.as_ref(),
+            std::future::pending(),
+        )
+        .await
     }
 
     /// Start the server with a shutdown signal.
@@ -253,15 +270,17 @@ impl<M: MemoryRepository + Clone + Send + Sync + 'static> MemoryHttpServer<M> {
         F: std::future::Future<Output = ()> + Send + 'static,
     {
         let addr: SocketAddr = format!("{}:{}", self.config.host, self.config.port).parse()?;
+        let tls = self.config.tls.clone();
         let router = self.build_router();
 
-        tracing::info!("MCP Memory HTTP server listening on {}", addr);
-
-        let listener = TcpListener::bind(addr).await?;
-        axum::serve(listener, router)
-            .with_graceful_shutdown(shutdown)
-            .await?;
-        Ok(())
+        serve_router(
+            "MCP Memory HTTP server",
+            addr,
+            router,
+            tls.as_ref(),
+            shutdown,
+        )
+        .await
     }
 }
 
@@ -336,7 +355,10 @@ async fn store_memory<M: MemoryRepository + Clone + Send + Sync + 'static>(
         namespace,
         tier,
         memory_type,
-        metadata: None,
+        metadata: req.decay_floor.map(|floor| MemoryMetadata {
+            decay_floor: Some(floor),
+            ..Default::default()
+        }),
     });
     let envelope = CommandEnvelope::new(CommandSource::Mcp("memory-http".into()), cmd);
 
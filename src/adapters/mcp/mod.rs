@@ -8,19 +8,23 @@
 
 pub mod a2a_http;
 pub mod agents_http;
+pub mod auth;
 pub mod events_http;
 pub mod federation_client;
 pub mod memory_http;
 pub mod stdio_server;
 pub mod tasks_http;
+pub mod tls;
 
 pub use a2a_http::{
     A2AHttpConfig, A2AHttpGateway, A2AState, A2ATaskState, FederationTlsGatewayConfig,
     create_federation_jwt,
 };
-pub use federation_client::FederationClient;
+pub use federation_client::{DelegationError, FederationClient, PeerHealth, RetryPolicy};
 pub use agents_http::{AgentsHttpConfig, AgentsHttpServer};
+pub use auth::HttpAuth;
 pub use events_http::{EventsHttpConfig, EventsHttpServer, EventsState};
 pub use memory_http::{MemoryHttpConfig, MemoryHttpServer};
 pub use stdio_server::StdioServer;
 pub use tasks_http::{TasksHttpConfig, TasksHttpServer};
+pub use tls::TlsConfig;
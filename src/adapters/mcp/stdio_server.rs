@@ -12,8 +12,8 @@ use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use uuid::Uuid;
 
 use crate::domain::models::{
-    AccessorId, ExecutionMode, GoalStatus, MemoryTier, MemoryType, TaskPriority, TaskSource,
-    TaskStatus, TaskType,
+    AccessorId, ExecutionMode, GoalStatus, MemoryMetadata, MemoryTier, MemoryType, TaskContext,
+    TaskPriority, TaskSource, TaskStatus, TaskType,
 };
 use crate::domain::ports::AgentRepository;
 use crate::domain::ports::{
@@ -193,6 +193,11 @@ where
                             "items": { "type": "string" },
                             "description": "UUIDs of tasks that must complete before this one starts. Use this to create task pipelines (e.g., implement before test, test before review)."
                         },
+                        "inject_dependency_results": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Subset of depends_on whose final output and artifacts are added to this task's prompt once they complete. Use this when this task consumes another task's output (e.g., a design the implementation must follow)."
+                        },
                         "priority": { "type": "string", "enum": ["low", "normal", "high", "critical"], "description": "Task priority. Higher priority tasks are picked up first. Default: normal." },
                         "task_type": { "type": "string", "enum": ["standard", "verification", "research", "review"], "description": "Type of task. Default: standard. Use 'verification' for intent verification tasks." },
                         "execution_mode": { "type": "string", "enum": ["direct", "convergent"], "description": "Execution mode override. 'convergent' uses iterative refinement with intent verification — recommended for implementation tasks. If omitted, the system selects automatically via heuristic." }
@@ -264,7 +269,7 @@ where
             serde_json::json!({"name":"agent_list","description":"List all available agent templates in the Abathur swarm. Call this before creating new agents to check if a suitable one already exists.","inputSchema":{"type":"object","properties":{}}}),
             serde_json::json!({"name":"agent_get","description":"Get full details of an agent template by name, including its system prompt, tools, constraints, and version.","inputSchema":{"type":"object","properties":{"name":{"type":"string","description":"Agent template name (e.g., 'rust-implementer')"}},"required":["name"]}}),
             serde_json::json!({"name":"memory_search","description":"Search the Abathur swarm's shared memory by keyword query. Use this before planning to find similar past tasks, known failure patterns, architectural decisions, and reusable context.","inputSchema":{"type":"object","properties":{"query":{"type":"string","description":"Search query — keywords or phrases to match against stored memories"},"namespace":{"type":"string","description":"Optional namespace filter to scope search"},"limit":{"type":"integer","description":"Maximum results to return (default: 20)"}},"required":["query"]}}),
-            serde_json::json!({"name":"memory_store","description":"Store a memory in the Abathur swarm for future reference by yourself and other agents.","inputSchema":{"type":"object","properties":{"key":{"type":"string","description":"Unique key for this memory"},"content":{"type":"string","description":"The memory content"},"namespace":{"type":"string","description":"Namespace to organize memories (default: 'default')."},"memory_type":{"type":"string","enum":["fact","code","decision","error","pattern","reference","context"],"description":"Type of memory. Default: fact."},"tier":{"type":"string","enum":["working","episodic","semantic"],"description":"Memory tier. Default: working."}},"required":["key","content"]}}),
+            serde_json::json!({"name":"memory_store","description":"Store a memory in the Abathur swarm for future reference by yourself and other agents.","inputSchema":{"type":"object","properties":{"key":{"type":"string","description":"Unique key for this memory"},"content":{"type":"string","description":"The memory content"},"namespace":{"type":"string","description":"Namespace to organize memories (default: 'default')."},"memory_type":{"type":"string","enum":["fact","code","decision","error","pattern","reference","context"],"description":"Type of memory. Default: fact."},"tier":{"type":"string","enum":["working","episodic","semantic"],"description":"Memory tier. Default: working."},"decay_floor":{"type":"number","minimum":0,"maximum":1,"description":"Minimum decay factor for this memory, overriding its tier's floor. A floored memory is never pruned for decay."}},"required":["key","content"]}}),
             serde_json::json!({"name":"memory_get","description":"Retrieve a specific memory by its UUID.","inputSchema":{"type":"object","properties":{"id":{"type":"string","description":"Memory UUID (returned by memory_search or memory_store)"}},"required":["id"]}}),
             serde_json::json!({"name":"goals_list","description":"List active goals in the Abathur swarm.","inputSchema":{"type":"object","properties":{}}}),
             serde_json::json!({"name":"task_assign","description":"Assign an agent_type to a Ready task without claiming it. Use this to assign a specialist agent to a workflow phase subtask so the scheduler picks it up.","inputSchema":{"type":"object","properties":{"task_id":{"type":"string","description":"UUID of the task to assign"},"agent_type":{"type":"string","description":"Name of the agent template to assign"}},"required":["task_id","agent_type"]}}),
@@ -369,6 +374,22 @@ where
             })
             .unwrap_or_default();
 
+        let injected: Vec<Uuid> = args
+            .get("inject_dependency_results")
+            .and_then(|d| d.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().and_then(|s| Uuid::parse_str(s).ok()))
+                    .filter(|id| depends_on.contains(id))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let context = (!injected.is_empty()).then(|| {
+            let mut ctx = TaskContext::default();
+            ctx.set_injected_dependencies(&injected);
+            ctx
+        });
+
         // Auto-populate parent_id from --task-id context
         let parent_id = self.task_id;
 
@@ -445,12 +466,16 @@ where
             priority,
             agent_type,
             depends_on,
-            context: Box::new(None),
+            context: Box::new(context),
             idempotency_key,
             source: TaskSource::Human,
             deadline: None,
             task_type,
             execution_mode,
+            estimate_secs: None,
+            execution_params: None,
+            required_overseers: Vec::new(),
+            workspace_mode: None,
         });
         let envelope = CommandEnvelope::new(CommandSource::Mcp("stdio".into()), cmd);
 
@@ -872,6 +897,10 @@ where
             .and_then(|t| t.as_str())
             .and_then(MemoryTier::parse)
             .unwrap_or(MemoryTier::Working);
+        let decay_floor = args
+            .get("decay_floor")
+            .and_then(|f| f.as_f64())
+            .map(|f| f as f32);
 
         let cmd = DomainCommand::Memory(MemoryCommand::Store {
             key,
@@ -879,7 +908,10 @@ where
             namespace,
             tier,
             memory_type,
-            metadata: None,
+            metadata: decay_floor.map(|floor| MemoryMetadata {
+                decay_floor: Some(floor),
+                ..Default::default()
+            }),
         });
         let envelope = CommandEnvelope::new(CommandSource::Mcp("stdio".into()), cmd);
 
@@ -6,18 +6,19 @@
 use axum::{
     Router,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     response::Json,
     routing::{get, post},
 };
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::net::TcpListener;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 use uuid::Uuid;
 
+use crate::adapters::mcp::auth::{HttpAuth, constant_time_eq, with_auth};
+use crate::adapters::mcp::tls::{TlsConfig, serve_router};
 use crate::domain::models::{Task, TaskPriority, TaskSource, TaskStatus};
 use crate::domain::ports::TaskRepository;
 use crate::services::TaskService;
@@ -26,6 +27,12 @@ use crate::services::command_bus::{
 };
 use crate::services::guardrails::{GuardrailResult, Guardrails};
 
+/// Path prefix of the webhook routes. These skip [`HttpAuth`] and are
+/// checked against [`TasksHttpConfig::webhook_secret`] instead.
+pub const WEBHOOK_PATH_PREFIX: &str = "/api/v1/webhooks/";
+/// Header carrying the webhook shared secret.
+pub const WEBHOOK_SECRET_HEADER: &str = "x-abathur-webhook-secret";
+
 /// Configuration for the tasks HTTP server.
 #[derive(Debug, Clone)]
 pub struct TasksHttpConfig {
@@ -35,6 +42,13 @@ pub struct TasksHttpConfig {
     pub port: u16,
     /// Whether to enable CORS.
     pub enable_cors: bool,
+    /// Authentication required on every non-health endpoint.
+    pub auth: HttpAuth,
+    /// Serve HTTPS with this certificate and key instead of plain HTTP.
+    pub tls: Option<TlsConfig>,
+    /// Shared secret for `POST /api/v1/webhooks/{source}/tasks`. The route
+    /// is only mounted when this is set.
+    pub webhook_secret: Option<String>,
 }
 
 impl Default for TasksHttpConfig {
@@ -43,6 +57,9 @@ impl Default for TasksHttpConfig {
             host: "127.0.0.1".to_string(),
             port: 9101,
             enable_cors: true,
+            auth: HttpAuth::default(),
+            tls: None,
+            webhook_secret: None,
         }
     }
 }
@@ -65,6 +82,27 @@ pub struct SubmitTaskRequest {
     pub idempotency_key: Option<String>,
 }
 
+/// Task pushed by an external system (CI, monitoring) to the webhook route.
+#[derive(Debug, Deserialize)]
+pub struct WebhookTaskRequest {
+    pub title: String,
+    pub description: String,
+    #[serde(default)]
+    pub priority: Option<String>,
+    #[serde(default)]
+    pub agent_type: Option<String>,
+    /// Caller-side delivery ID; redeliveries with the same key return the
+    /// original task instead of creating a new one.
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+}
+
+/// Response to a webhook task submission.
+#[derive(Debug, Serialize)]
+pub struct WebhookTaskResponse {
+    pub id: Uuid,
+}
+
 /// Request to complete a task.
 #[derive(Debug, Deserialize)]
 pub struct CompleteTaskRequest {
@@ -168,6 +206,7 @@ struct AppState<T: TaskRepository> {
     service: TaskService<T>,
     command_bus: Arc<CommandBus>,
     guardrails: Option<Arc<Guardrails>>,
+    webhook_secret: Option<String>,
 }
 
 /// Tasks HTTP Server.
@@ -204,9 +243,10 @@ impl<T: TaskRepository + Clone + Send + Sync + 'static> TasksHttpServer<T> {
             service: self.service,
             command_bus: self.command_bus,
             guardrails: self.guardrails,
+            webhook_secret: self.config.webhook_secret.clone(),
         });
 
-        let app = Router::new()
+        let mut app = Router::new()
             // Task CRUD operations
             .route("/api/v1/tasks", get(list_tasks::<T>))
             .route("/api/v1/tasks", post(submit_task::<T>))
@@ -221,8 +261,14 @@ impl<T: TaskRepository + Clone + Send + Sync + 'static> TasksHttpServer<T> {
             // Statistics
             .route("/api/v1/tasks/stats", get(get_stats::<T>))
             // Health check
-            .route("/health", get(health_check))
-            .with_state(state);
+            .route("/health", get(health_check));
+        if self.config.webhook_secret.is_some() {
+            app = app.route(
+                &format!("{WEBHOOK_PATH_PREFIX}{{source}}/tasks"),
+                post(webhook_task::<T>),
+            );
+        }
+        let app = with_auth(app.with_state(state), &self.config.auth);
 
         if self.config.enable_cors {
             app.layer(
@@ -240,13 +286,17 @@ impl<T: TaskRepository + Clone + Send + Sync + 'static> TasksHttpServer<T> {
     /// Start the server.
     pub async fn serve(self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let addr: SocketAddr = format!("{}:{}", self.config.host, self.config.port).parse()?;
+        let tls = self.config.tls.clone();
         let router = self.build_router();
 
-        tracing::info!("MCP Tasks HTTP server listening on {}", addr);
-
-        let listener = TcpListener::bind(addr).await?;
-        axum::serve(listener, router).await?;
-        Ok(())
+        serve_router(
+            "MCP Tasks HTTP server",
+            addr,
+            router,
+            tls.as_ref(),
+            std::future::pending(),
+        )
+        .await
     }
 
     /// Start the server with a shutdown signal.
@@ -258,15 +308,17 @@ impl<T: TaskRepository + Clone + Send + Sync + 'static> TasksHttpServer<T> {
         F: std::future::Future<Output = ()> + Send + 'static,
     {
         let addr: SocketAddr = format!("{}:{}", self.config.host, self.config.port).parse()?;
+        let tls = self.config.tls.clone();
         let router = self.build_router();
 
-        tracing::info!("MCP Tasks HTTP server listening on {}", addr);
-
-        let listener = TcpListener::bind(addr).await?;
-        axum::serve(listener, router)
-            .with_graceful_shutdown(shutdown)
-            .await?;
-        Ok(())
+        serve_router(
+            "MCP Tasks HTTP server",
+            addr,
+            router,
+            tls.as_ref(),
+            shutdown,
+        )
+        .await
     }
 }
 
@@ -310,24 +362,7 @@ async fn submit_task<T: TaskRepository + Clone + Send + Sync + 'static>(
     State(state): State<Arc<AppState<T>>>,
     Json(req): Json<SubmitTaskRequest>,
 ) -> Result<(StatusCode, Json<TaskResponse>), (StatusCode, Json<ErrorResponse>)> {
-    // Pre-flight guardrails check: reject task creation if limits are exceeded.
-    if let Some(guardrails) = &state.guardrails {
-        match guardrails.check_task_creation().await {
-            GuardrailResult::Blocked(reason) => {
-                return Err((
-                    StatusCode::BAD_REQUEST,
-                    Json(ErrorResponse {
-                        error: reason,
-                        code: "GUARDRAIL_BLOCKED".to_string(),
-                    }),
-                ));
-            }
-            GuardrailResult::Warning(msg) => {
-                tracing::warn!("Guardrail warning on task submission: {}", msg);
-            }
-            GuardrailResult::Allowed => {}
-        }
-    }
+    check_guardrails(&state).await?;
 
     let priority = req
         .priority
@@ -348,6 +383,10 @@ async fn submit_task<T: TaskRepository + Clone + Send + Sync + 'static>(
         deadline: None,
         task_type: None,
         execution_mode: None,
+        estimate_secs: None,
+        execution_params: None,
+        required_overseers: Vec::new(),
+        workspace_mode: None,
     });
     let envelope = CommandEnvelope::new(CommandSource::Mcp("tasks-http".into()), cmd);
 
@@ -370,6 +409,134 @@ async fn submit_task<T: TaskRepository + Clone + Send + Sync + 'static>(
     }
 }
 
+async fn webhook_task<T: TaskRepository + Clone + Send + Sync + 'static>(
+    State(state): State<Arc<AppState<T>>>,
+    Path(source): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<WebhookTaskRequest>,
+) -> Result<(StatusCode, Json<WebhookTaskResponse>), (StatusCode, Json<ErrorResponse>)> {
+    let presented = headers
+        .get(WEBHOOK_SECRET_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    let authorized = state
+        .webhook_secret
+        .as_deref()
+        .is_some_and(|secret| constant_time_eq(presented.as_bytes(), secret.as_bytes()));
+    if !authorized {
+        tracing::warn!(source = %source, "Rejected webhook with invalid secret");
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(ErrorResponse {
+                error: "Missing or invalid webhook secret".to_string(),
+                code: "UNAUTHORIZED".to_string(),
+            }),
+        ));
+    }
+
+    let invalid = |error: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error,
+                code: "INVALID_REQUEST".to_string(),
+            }),
+        )
+    };
+    if source.is_empty()
+        || !source
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(invalid(format!(
+            "Invalid webhook source '{}': use letters, digits, '-' or '_'",
+            source
+        )));
+    }
+    if req.title.trim().is_empty() {
+        return Err(invalid("title must not be empty".to_string()));
+    }
+    if req.description.trim().is_empty() {
+        return Err(invalid("description must not be empty".to_string()));
+    }
+    let priority = match req.priority.as_deref() {
+        None => TaskPriority::Normal,
+        Some(p) => {
+            TaskPriority::parse(p).ok_or_else(|| invalid(format!("Unknown priority '{}'", p)))?
+        }
+    };
+
+    check_guardrails(&state).await?;
+
+    let cmd = DomainCommand::Task(TaskCommand::Submit {
+        title: Some(req.title.trim().to_string()),
+        description: req.description,
+        parent_id: None,
+        priority,
+        agent_type: req.agent_type.filter(|a| !a.trim().is_empty()),
+        depends_on: Vec::new(),
+        context: Box::new(None),
+        idempotency_key: req
+            .idempotency_key
+            .map(|key| format!("webhook:{}:{}", source, key)),
+        source: TaskSource::Webhook {
+            source_name: source.clone(),
+        },
+        deadline: None,
+        task_type: None,
+        execution_mode: None,
+        estimate_secs: None,
+        execution_params: None,
+        required_overseers: Vec::new(),
+        workspace_mode: None,
+    });
+    let envelope = CommandEnvelope::new(CommandSource::Webhook(source.clone()), cmd);
+
+    match state.command_bus.dispatch(envelope).await {
+        Ok(CommandResult::Task(task)) => Ok((
+            StatusCode::CREATED,
+            Json(WebhookTaskResponse { id: task.id }),
+        )),
+        Ok(_) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: "Unexpected command result type".to_string(),
+                code: "INTERNAL_ERROR".to_string(),
+            }),
+        )),
+        Err(e) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+                code: "SUBMIT_ERROR".to_string(),
+            }),
+        )),
+    }
+}
+
+/// Pre-flight guardrails check: reject task creation if limits are exceeded.
+async fn check_guardrails<T: TaskRepository>(
+    state: &AppState<T>,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let Some(guardrails) = &state.guardrails else {
+        return Ok(());
+    };
+    match guardrails.check_task_creation().await {
+        GuardrailResult::Blocked(reason) | GuardrailResult::RateLimited(reason) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: reason,
+                code: "GUARDRAIL_BLOCKED".to_string(),
+            }),
+        )),
+        GuardrailResult::Warning(msg) => {
+            tracing::warn!("Guardrail warning on task submission: {}", msg);
+            Ok(())
+        }
+        GuardrailResult::Allowed => Ok(()),
+    }
+}
+
 async fn get_task<T: TaskRepository + Clone + Send + Sync + 'static>(
     State(state): State<Arc<AppState<T>>>,
     Path(id): Path<Uuid>,
@@ -556,6 +723,106 @@ async fn get_stats<T: TaskRepository + Clone + Send + Sync + 'static>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    use crate::adapters::sqlite::SqliteTaskRepository;
+    use crate::adapters::sqlite::test_support::{setup_goal_repo, setup_task_repo};
+    use crate::domain::ports::NullMemoryRepository;
+    use crate::services::memory_maintenance_service::MemoryMaintenanceService;
+    use crate::services::{EventBus, EventBusConfig, GoalService, MemoryService};
+
+    async fn webhook_router() -> (Router, Arc<SqliteTaskRepository>) {
+        let repo = setup_task_repo().await;
+        let memory_service = Arc::new(MemoryService::new(Arc::new(NullMemoryRepository::new())));
+        let command_bus = Arc::new(CommandBus::new(
+            Arc::new(TaskService::new(repo.clone())),
+            Arc::new(GoalService::new(setup_goal_repo().await)),
+            Arc::new(MemoryMaintenanceService::from_memory_service(
+                memory_service,
+            )),
+            Arc::new(EventBus::new(EventBusConfig {
+                persist_events: false,
+                ..Default::default()
+            })),
+        ));
+        let config = TasksHttpConfig {
+            auth: HttpAuth::Bearer {
+                token: "api-token".to_string(),
+            },
+            webhook_secret: Some("hook-secret".to_string()),
+            ..Default::default()
+        };
+        let server = TasksHttpServer::new(TaskService::new(repo.clone()), command_bus, config);
+        (server.build_router(), repo)
+    }
+
+    fn webhook_request(secret: Option<&str>, body: serde_json::Value) -> Request<Body> {
+        let mut builder =
+            Request::post("/api/v1/webhooks/ci/tasks").header("content-type", "application/json");
+        if let Some(secret) = secret {
+            builder = builder.header(WEBHOOK_SECRET_HEADER, secret);
+        }
+        builder.body(Body::from(body.to_string())).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_webhook_creates_task() {
+        let (router, repo) = webhook_router().await;
+        let body = serde_json::json!({
+            "title": "Fix failing build",
+            "description": "CI run 42 failed on main",
+            "priority": "high",
+            "agent_type": "developer",
+        });
+
+        let response = router
+            .oneshot(webhook_request(Some("hook-secret"), body))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        let id: Uuid = created["id"].as_str().unwrap().parse().unwrap();
+
+        let task = repo.get(id).await.unwrap().expect("task persisted");
+        assert_eq!(task.title, "Fix failing build");
+        assert_eq!(task.priority, TaskPriority::High);
+        assert_eq!(task.agent_type.as_deref(), Some("developer"));
+        assert_eq!(
+            task.source,
+            TaskSource::Webhook {
+                source_name: "ci".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_webhook_rejects_bad_secret_and_invalid_body() {
+        let (router, _repo) = webhook_router().await;
+        let body = serde_json::json!({"title": "t", "description": "d"});
+
+        for secret in [None, Some("wrong")] {
+            let response = router
+                .clone()
+                .oneshot(webhook_request(secret, body.clone()))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        let response = router
+            .oneshot(webhook_request(
+                Some("hook-secret"),
+                serde_json::json!({"title": " ", "description": "d", "priority": "urgent"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 
     #[test]
     fn test_config_default() {
@@ -0,0 +1,167 @@
+//! TLS for the MCP HTTP servers.
+//!
+//! A server with a [`TlsConfig`] serves HTTPS via `axum_server` + rustls.
+//! The certificate and key are loaded before the port is bound, so a missing
+//! or unreadable file fails startup instead of falling back to plain HTTP.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::Router;
+use tokio::net::TcpListener;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// PEM-encoded certificate chain and private key an HTTP server presents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// Path to the PEM-encoded server certificate chain.
+    pub cert_path: String,
+    /// Path to the PEM-encoded server private key.
+    pub key_path: String,
+}
+
+impl TlsConfig {
+    pub fn new(cert_path: impl Into<String>, key_path: impl Into<String>) -> Self {
+        Self {
+            cert_path: cert_path.into(),
+            key_path: key_path.into(),
+        }
+    }
+
+    /// Read and validate the certificate and key.
+    pub fn load(&self) -> Result<Arc<rustls::ServerConfig>, BoxError> {
+        build_server_config(&self.cert_path, &self.key_path, None)
+    }
+}
+
+/// Serve `router` on `addr` until `shutdown` resolves, over HTTPS when `tls`
+/// is set. `server` names the server in log lines.
+pub(crate) async fn serve_router<F>(
+    server: &str,
+    addr: SocketAddr,
+    router: Router,
+    tls: Option<&TlsConfig>,
+    shutdown: F,
+) -> Result<(), BoxError>
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    let Some(tls) = tls else {
+        tracing::info!("{} listening on {}", server, addr);
+        let listener = TcpListener::bind(addr).await?;
+        axum::serve(listener, router)
+            .with_graceful_shutdown(shutdown)
+            .await?;
+        return Ok(());
+    };
+
+    let rustls_config = tls.load()?;
+    tracing::info!("{} listening on {} (TLS)", server, addr);
+
+    let handle = axum_server::Handle::new();
+    let handle_clone = handle.clone();
+    tokio::spawn(async move {
+        shutdown.await;
+        handle_clone.graceful_shutdown(None);
+    });
+    axum_server::bind_rustls(
+        addr,
+        axum_server::tls_rustls::RustlsConfig::from_config(rustls_config),
+    )
+    .handle(handle)
+    .serve(router.into_make_service())
+    .await?;
+    Ok(())
+}
+
+/// Build a `rustls::ServerConfig` from PEM files.
+///
+/// When `ca_path` is provided, enables mutual TLS (mTLS) by requiring
+/// client certificates signed by the given CA.
+pub(crate) fn build_server_config(
+    cert_path: &str,
+    key_path: &str,
+    ca_path: Option<&str>,
+) -> Result<Arc<rustls::ServerConfig>, BoxError> {
+    use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+    use std::io::BufReader;
+
+    // Load server certificate chain
+    let cert_file = std::fs::File::open(cert_path)
+        .map_err(|e| format!("Failed to open cert file '{}': {}", cert_path, e))?;
+    let certs: Vec<CertificateDer<'static>> = rustls_pemfile::certs(&mut BufReader::new(cert_file))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to parse certificates in '{}': {}", cert_path, e))?;
+    if certs.is_empty() {
+        return Err(format!("No certificates found in cert file '{}'", cert_path).into());
+    }
+
+    // Load server private key
+    let key_file = std::fs::File::open(key_path)
+        .map_err(|e| format!("Failed to open key file '{}': {}", key_path, e))?;
+    let key: PrivateKeyDer<'static> = rustls_pemfile::private_key(&mut BufReader::new(key_file))
+        .map_err(|e| format!("Failed to parse private key in '{}': {}", key_path, e))?
+        .ok_or_else(|| format!("No private key found in key file '{}'", key_path))?;
+
+    let config = if let Some(ca_path) = ca_path {
+        // mTLS: require client certificates verified against the provided CA
+        let ca_file = std::fs::File::open(ca_path)
+            .map_err(|e| format!("Failed to open CA file '{}': {}", ca_path, e))?;
+        let ca_certs: Vec<CertificateDer<'static>> =
+            rustls_pemfile::certs(&mut BufReader::new(ca_file))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Failed to parse CA certificates: {}", e))?;
+
+        let mut root_store = rustls::RootCertStore::empty();
+        for cert in ca_certs {
+            root_store.add(cert)?;
+        }
+
+        let client_verifier = rustls::server::WebPkiClientVerifier::builder(Arc::new(root_store))
+            .build()
+            .map_err(|e| format!("Failed to build client verifier: {}", e))?;
+
+        rustls::ServerConfig::builder()
+            .with_client_cert_verifier(client_verifier)
+            .with_single_cert(certs, key)
+            .map_err(|e| format!("Failed to build TLS config: {}", e))?
+    } else {
+        // TLS only (no client cert required)
+        rustls::ServerConfig::builder()
+            .with_no_client_auth()
+            .with_single_cert(certs, key)
+            .map_err(|e| format!("Failed to build TLS config: {}", e))?
+    };
+
+    Ok(Arc::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_names_the_missing_or_invalid_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cert = dir.path().join("cert.pem");
+        let key = dir.path().join("key.pem");
+
+        let err = TlsConfig::new(cert.to_str().unwrap(), key.to_str().unwrap())
+            .load()
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("cert file") && err.contains("cert.pem"),
+            "{}",
+            err
+        );
+
+        std::fs::write(&cert, "not a certificate").unwrap();
+        let err = TlsConfig::new(cert.to_str().unwrap(), key.to_str().unwrap())
+            .load()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("No certificates found"), "{}", err);
+    }
+}
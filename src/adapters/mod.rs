@@ -2,6 +2,7 @@
 
 pub mod a2a;
 pub mod cache;
+pub mod database;
 pub mod embeddings;
 pub mod mcp;
 pub mod plugins;
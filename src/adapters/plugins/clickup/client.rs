@@ -2,14 +2,17 @@
 //!
 //! Wraps the ClickUp REST API v2, providing typed methods for the
 //! operations used by the ingestion and egress adapters. Includes
-//! a token-bucket rate limiter to stay within the 100 req/min API limit.
+//! a token-bucket rate limiter to stay within the 100 req/min API limit,
+//! and retries requests that ClickUp still rejects with HTTP 429.
 
+use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use tokio::sync::Mutex;
 
+use crate::adapters::plugins::rate_limit::{RateLimitConfig, TokenBucketRateLimiter};
 use crate::domain::errors::{DomainError, DomainResult};
 
 use super::models::{ClickUpCommentRequest, ClickUpTaskResponse, ClickUpTasksResponse};
@@ -17,64 +20,84 @@ use super::models::{ClickUpCommentRequest, ClickUpTaskResponse, ClickUpTasksResp
 /// Base URL for the ClickUp API v2.
 const CLICKUP_API_BASE: &str = "https://api.clickup.com/api/v2";
 
-/// Token-bucket rate limiter.
+/// How [`ClickUpClient`] retries requests rejected with HTTP 429.
 ///
-/// Allows up to `capacity` requests per `window`. When the bucket is
-/// exhausted, [`acquire`](RateLimiter::acquire) sleeps until a token
-/// becomes available.
-#[derive(Debug)]
-pub struct RateLimiter {
-    /// Maximum tokens in the bucket.
-    capacity: u32,
-    /// Current available tokens.
-    tokens: u32,
-    /// Duration of the refill window.
-    window: Duration,
-    /// When the current window started.
-    window_start: Instant,
+/// The wait before each retry comes from the response's `Retry-After`
+/// header when present, otherwise from exponential backoff starting at
+/// `base_delay`. Either way it is capped at `max_delay`. Once
+/// `max_retries` retries are used up, the 429 is returned as an error.
+///
+/// Read from the adapter manifest config:
+///
+/// ```toml
+/// [config]
+/// max_retries = 3
+/// retry_base_delay_ms = 1000
+/// retry_max_delay_ms = 60000
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; zero disables retrying.
+    pub max_retries: u32,
+    /// Backoff before the first retry when there is no `Retry-After` header.
+    pub base_delay: Duration,
+    /// Upper bound on any single wait.
+    pub max_delay: Duration,
 }
 
-impl RateLimiter {
-    /// Create a new rate limiter with the given capacity and window.
-    pub fn new(capacity: u32, window: Duration) -> Self {
+impl Default for RetryPolicy {
+    fn default() -> Self {
         Self {
-            capacity,
-            tokens: capacity,
-            window,
-            window_start: Instant::now(),
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
         }
     }
+}
 
-    /// Acquire a single token, sleeping if necessary.
-    ///
-    /// If the current window has elapsed, the bucket is refilled.
-    /// If no tokens are available, this method sleeps until the
-    /// window resets.
-    pub async fn acquire(&mut self) {
-        let elapsed = self.window_start.elapsed();
-        if elapsed >= self.window {
-            // Refill the bucket and start a new window.
-            self.tokens = self.capacity;
-            self.window_start = Instant::now();
+impl RetryPolicy {
+    /// Build a policy from the adapter manifest config, using the default
+    /// for any key that is absent or not a non-negative integer.
+    pub fn from_config(config: &HashMap<String, serde_json::Value>) -> Self {
+        let defaults = Self::default();
+        let get = |key: &str| config.get(key).and_then(|v| v.as_u64());
+        Self {
+            max_retries: get("max_retries")
+                .map(|n| n.min(u32::MAX as u64) as u32)
+                .unwrap_or(defaults.max_retries),
+            base_delay: get("retry_base_delay_ms")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.base_delay),
+            max_delay: get("retry_max_delay_ms")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.max_delay),
         }
+    }
 
-        if self.tokens > 0 {
-            self.tokens -= 1;
-        } else {
-            // Sleep until the window resets.
-            let remaining = self.window.saturating_sub(elapsed);
-            tracing::warn!(
-                sleep_ms = remaining.as_millis() as u64,
-                "ClickUp rate limit reached, sleeping"
-            );
-            tokio::time::sleep(remaining).await;
-            // After sleeping, refill and consume one token.
-            self.tokens = self.capacity - 1;
-            self.window_start = Instant::now();
-        }
+    /// Wait before retry number `attempt` (zero-based).
+    ///
+    /// `retry_after` is the server-requested delay, if any.
+    fn delay_for(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let delay = retry_after
+            .unwrap_or_else(|| self.base_delay.saturating_mul(2u32.saturating_pow(attempt)));
+        delay.min(self.max_delay)
     }
 }
 
+/// Parse a `Retry-After` header value.
+///
+/// Accepts both forms allowed by RFC 9110: a number of seconds, or an
+/// HTTP date (a date in the past yields a zero delay).
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = at.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
 /// HTTP client for the ClickUp REST API v2.
 ///
 /// All methods return [`DomainResult`] and map HTTP / network errors
@@ -86,20 +109,50 @@ pub struct ClickUpClient {
     /// ClickUp personal API token.
     api_key: String,
     /// Shared rate limiter.
-    rate_limiter: Arc<Mutex<RateLimiter>>,
+    rate_limiter: Arc<Mutex<TokenBucketRateLimiter>>,
+    /// API base URL; overridable so tests can point at a local server.
+    base_url: String,
+    /// Retry behavior for HTTP 429 responses.
+    retry_policy: RetryPolicy,
 }
 
 impl ClickUpClient {
     /// Create a new client with the given API key.
     pub fn new(api_key: String) -> Self {
-        let rate_limiter = RateLimiter::new(100, Duration::from_secs(60));
         Self {
             http: Client::new(),
             api_key,
-            rate_limiter: Arc::new(Mutex::new(rate_limiter)),
+            rate_limiter: Arc::new(Mutex::new(TokenBucketRateLimiter::new(
+                Self::default_rate_limit(),
+            ))),
+            base_url: CLICKUP_API_BASE.to_string(),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    /// ClickUp allows 100 requests per minute per token.
+    pub fn default_rate_limit() -> RateLimitConfig {
+        RateLimitConfig::new(100, Duration::from_secs(60))
+    }
+
+    /// Replace the client-side rate limit.
+    pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limiter = Arc::new(Mutex::new(TokenBucketRateLimiter::new(config)));
+        self
+    }
+
+    /// Replace the retry policy for HTTP 429 responses.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Send requests to `base_url` instead of the public ClickUp API.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
     /// Create a client by reading the `CLICKUP_API_KEY` environment variable.
     ///
     /// Returns `Err` if the variable is not set or is empty.
@@ -112,19 +165,66 @@ impl ClickUpClient {
         Ok(Self::new(api_key))
     }
 
-    /// Acquire a rate-limit token and build an authorized request.
-    async fn rate_limited_request(
-        &self,
-        method: reqwest::Method,
-        url: &str,
-    ) -> reqwest::RequestBuilder {
-        self.rate_limiter.lock().await.acquire().await;
+    /// Build an authorized request.
+    fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
         self.http
             .request(method, url)
             .header("Authorization", &self.api_key)
             .header("Content-Type", "application/json")
     }
 
+    /// Send `request`, acquiring a rate-limit token before every attempt
+    /// and retrying HTTP 429 responses according to the [`RetryPolicy`].
+    ///
+    /// Returns the final response, which is still a 429 if the retries ran
+    /// out; callers turn any non-success status into an error.
+    async fn send(
+        &self,
+        op: &str,
+        request: reqwest::RequestBuilder,
+    ) -> DomainResult<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let req = request
+                .try_clone()
+                .ok_or_else(|| DomainError::ExternalServiceError {
+                    service: "clickup".to_string(),
+                    reason: format!("{op} request body cannot be retried"),
+                })?;
+            self.rate_limiter.lock().await.acquire().await;
+            let resp = req
+                .send()
+                .await
+                .map_err(|e| DomainError::ExternalServiceError {
+                    service: "clickup".to_string(),
+                    reason: format!("{op} request failed: {e}"),
+                })?;
+
+            if resp.status() != StatusCode::TOO_MANY_REQUESTS
+                || attempt >= self.retry_policy.max_retries
+            {
+                return Ok(resp);
+            }
+
+            let retry_after = resp
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(parse_retry_after);
+            let delay = self.retry_policy.delay_for(attempt, retry_after);
+            attempt += 1;
+            tracing::warn!(
+                op,
+                attempt,
+                max_retries = self.retry_policy.max_retries,
+                retry_after_header = retry_after.is_some(),
+                sleep_ms = delay.as_millis() as u64,
+                "ClickUp returned 429, retrying"
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
     /// Fetch tasks from a ClickUp list.
     ///
     /// If `updated_after_ms` is provided, only tasks updated after that
@@ -137,19 +237,13 @@ impl ClickUpClient {
         let url = match updated_after_ms {
             Some(ts) => format!(
                 "{}/list/{}/task?date_updated_gt={}",
-                CLICKUP_API_BASE, list_id, ts
+                self.base_url, list_id, ts
             ),
-            None => format!("{}/list/{}/task", CLICKUP_API_BASE, list_id),
+            None => format!("{}/list/{}/task", self.base_url, list_id),
         };
-        let req = self.rate_limited_request(reqwest::Method::GET, &url).await;
-
-        let resp = req
-            .send()
-            .await
-            .map_err(|e| DomainError::ExternalServiceError {
-                service: "clickup".to_string(),
-                reason: format!("get_tasks request failed: {e}"),
-            })?;
+        let resp = self
+            .send("get_tasks", self.request(reqwest::Method::GET, &url))
+            .await?;
 
         if !resp.status().is_success() {
             let status = resp.status();
@@ -168,21 +262,35 @@ impl ClickUpClient {
             })
     }
 
+    /// Check that the API key is accepted by fetching the authorized user.
+    pub async fn check_auth(&self) -> DomainResult<()> {
+        let url = format!("{}/user", self.base_url);
+        let resp = self
+            .send("check_auth", self.request(reqwest::Method::GET, &url))
+            .await?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_else(|e| format!("<body read failed: {e}>"));
+            return Err(DomainError::ExternalServiceError {
+                service: "clickup".to_string(),
+                reason: format!("check_auth returned {status}: {body}"),
+            });
+        }
+        Ok(())
+    }
+
     /// Update the status of a ClickUp task.
     pub async fn update_task_status(&self, task_id: &str, status: &str) -> DomainResult<()> {
-        let url = format!("{}/task/{}", CLICKUP_API_BASE, task_id);
+        let url = format!("{}/task/{}", self.base_url, task_id);
         let body = serde_json::json!({ "status": status });
 
         let resp = self
-            .rate_limited_request(reqwest::Method::PUT, &url)
-            .await
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| DomainError::ExternalServiceError {
-                service: "clickup".to_string(),
-                reason: format!("update_task_status request failed: {e}"),
-            })?;
+            .send(
+                "update_task_status",
+                self.request(reqwest::Method::PUT, &url).json(&body),
+            )
+            .await?;
 
         if !resp.status().is_success() {
             let status_code = resp.status();
@@ -198,21 +306,17 @@ impl ClickUpClient {
 
     /// Post a comment on a ClickUp task.
     pub async fn post_comment(&self, task_id: &str, comment: &str) -> DomainResult<()> {
-        let url = format!("{}/task/{}/comment", CLICKUP_API_BASE, task_id);
+        let url = format!("{}/task/{}/comment", self.base_url, task_id);
         let body = ClickUpCommentRequest {
             comment_text: comment.to_string(),
         };
 
         let resp = self
-            .rate_limited_request(reqwest::Method::POST, &url)
-            .await
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| DomainError::ExternalServiceError {
-                service: "clickup".to_string(),
-                reason: format!("post_comment request failed: {e}"),
-            })?;
+            .send(
+                "post_comment",
+                self.request(reqwest::Method::POST, &url).json(&body),
+            )
+            .await?;
 
         if !resp.status().is_success() {
             let status = resp.status();
@@ -235,22 +339,18 @@ impl ClickUpClient {
         name: &str,
         description: &str,
     ) -> DomainResult<ClickUpTaskResponse> {
-        let url = format!("{}/list/{}/task", CLICKUP_API_BASE, list_id);
+        let url = format!("{}/list/{}/task", self.base_url, list_id);
         let body = serde_json::json!({
             "name": name,
             "description": description,
         });
 
         let resp = self
-            .rate_limited_request(reqwest::Method::POST, &url)
-            .await
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| DomainError::ExternalServiceError {
-                service: "clickup".to_string(),
-                reason: format!("create_task request failed: {e}"),
-            })?;
+            .send(
+                "create_task",
+                self.request(reqwest::Method::POST, &url).json(&body),
+            )
+            .await?;
 
         if !resp.status().is_success() {
             let status = resp.status();
@@ -272,23 +372,10 @@ impl ClickUpClient {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_rate_limiter_creation() {
-        let rl = RateLimiter::new(100, Duration::from_secs(60));
-        assert_eq!(rl.capacity, 100);
-        assert_eq!(rl.tokens, 100);
-    }
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Instant;
 
-    #[tokio::test]
-    async fn test_rate_limiter_acquire_decrements_tokens() {
-        let mut rl = RateLimiter::new(5, Duration::from_secs(60));
-        rl.acquire().await;
-        assert_eq!(rl.tokens, 4);
-        rl.acquire().await;
-        assert_eq!(rl.tokens, 3);
-    }
+    use super::*;
 
     #[test]
     fn test_client_from_env_missing() {
@@ -304,5 +391,138 @@ mod tests {
     fn test_client_new() {
         let client = ClickUpClient::new("test-key".to_string());
         assert_eq!(client.api_key, "test-key");
+        assert_eq!(client.base_url, CLICKUP_API_BASE);
+        assert_eq!(client.retry_policy, RetryPolicy::default());
+    }
+
+    #[test]
+    fn test_retry_policy_from_config() {
+        let mut config = HashMap::new();
+        config.insert("max_retries".to_string(), serde_json::json!(5));
+        config.insert("retry_base_delay_ms".to_string(), serde_json::json!(250));
+        config.insert("retry_max_delay_ms".to_string(), serde_json::json!("soon"));
+
+        let policy = RetryPolicy::from_config(&config);
+        assert_eq!(policy.max_retries, 5);
+        assert_eq!(policy.base_delay, Duration::from_millis(250));
+        // Invalid values fall back to the default.
+        assert_eq!(policy.max_delay, RetryPolicy::default().max_delay);
+    }
+
+    #[test]
+    fn test_retry_policy_delay() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(10),
+        };
+        assert_eq!(policy.delay_for(0, None), Duration::from_secs(1));
+        assert_eq!(policy.delay_for(2, None), Duration::from_secs(4));
+        assert_eq!(policy.delay_for(5, None), Duration::from_secs(10));
+        assert_eq!(
+            policy.delay_for(0, Some(Duration::from_secs(7))),
+            Duration::from_secs(7)
+        );
+        assert_eq!(
+            policy.delay_for(0, Some(Duration::from_secs(120))),
+            Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after(" 3 "), Some(Duration::from_secs(3)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("later"), None);
+    }
+
+    /// Serve `responses` in order, one per connection, and count the
+    /// requests received. The last response repeats once the list runs out.
+    async fn serve_responses(responses: Vec<&'static str>) -> (String, Arc<AtomicUsize>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                while !String::from_utf8_lossy(&request).contains("\r\n\r\n") {
+                    let read = socket.read(&mut buf).await.unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..read]);
+                }
+                let head = responses[n.min(responses.len() - 1)];
+                let response =
+                    format!("{head}\r\ncontent-length: 2\r\nconnection: close\r\n\r\n{{}}");
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.ok();
+            }
+        });
+        (format!("http://{addr}"), hits)
+    }
+
+    fn retrying_client(base_url: &str, base_delay: Duration) -> ClickUpClient {
+        ClickUpClient::new("test-key".to_string())
+            .with_base_url(base_url)
+            .with_retry_policy(RetryPolicy {
+                max_retries: 2,
+                base_delay,
+                max_delay: Duration::from_secs(60),
+            })
+    }
+
+    #[tokio::test]
+    async fn test_429_with_retry_after_header_is_retried() {
+        let (base_url, hits) = serve_responses(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nretry-after: 0",
+            "HTTP/1.1 200 OK",
+        ])
+        .await;
+        // The header's zero delay wins over the long backoff.
+        let client = retrying_client(&base_url, Duration::from_secs(30));
+
+        let start = Instant::now();
+        client.check_auth().await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_429_without_header_backs_off_exponentially() {
+        let (base_url, hits) = serve_responses(vec![
+            "HTTP/1.1 429 Too Many Requests",
+            "HTTP/1.1 429 Too Many Requests",
+            "HTTP/1.1 200 OK",
+        ])
+        .await;
+        let client = retrying_client(&base_url, Duration::from_millis(20));
+
+        let start = Instant::now();
+        client.check_auth().await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        // 20ms then 40ms of backoff.
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[tokio::test]
+    async fn test_persistent_429_surfaces_as_error() {
+        let (base_url, hits) =
+            serve_responses(vec!["HTTP/1.1 429 Too Many Requests\r\nretry-after: 0"]).await;
+        let client = retrying_client(&base_url, Duration::from_millis(1));
+
+        let err = client.get_tasks("list-1", None).await.unwrap_err();
+        assert!(err.to_string().contains("429"), "got: {err}");
+        // The first attempt plus two retries.
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
     }
 }
@@ -43,6 +43,10 @@ impl EgressAdapter for ClickUpEgressAdapter {
         &self.manifest
     }
 
+    async fn health_check(&self) -> DomainResult<()> {
+        self.client.check_auth().await
+    }
+
     async fn execute(&self, action: &EgressAction) -> DomainResult<EgressResult> {
         match action {
             EgressAction::UpdateStatus {
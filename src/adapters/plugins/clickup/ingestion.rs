@@ -104,6 +104,10 @@ impl IngestionAdapter for ClickUpIngestionAdapter {
         &self.manifest
     }
 
+    async fn health_check(&self) -> DomainResult<()> {
+        self.client.check_auth().await
+    }
+
     async fn poll(&self, last_poll: Option<DateTime<Utc>>) -> DomainResult<Vec<IngestionItem>> {
         let list_id =
             self.list_id()
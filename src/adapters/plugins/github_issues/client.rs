@@ -6,79 +6,22 @@
 //! authenticated API limit.
 
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use reqwest::Client;
 use tokio::sync::Mutex;
 
+use crate::adapters::plugins::rate_limit::{RateLimitConfig, TokenBucketRateLimiter};
 use crate::domain::errors::{DomainError, DomainResult};
 
 use super::models::{
-    GitHubCommentRequest, GitHubCreateIssueRequest, GitHubCreateIssueResponse, GitHubIssue,
+    GitHubAddLabelsRequest, GitHubCommentRequest, GitHubCreateIssueRequest, GitHubCreateIssueResponse, GitHubIssue,
     GitHubIssueUpdateRequest, GitHubPullRequestDetail,
 };
 
 /// Base URL for the GitHub REST API v3.
 const GITHUB_API_BASE: &str = "https://api.github.com";
 
-/// Token-bucket rate limiter.
-///
-/// Allows up to `capacity` requests per `window`. When the bucket is
-/// exhausted, [`acquire`](RateLimiter::acquire) sleeps until the window
-/// resets and a token becomes available.
-#[derive(Debug)]
-pub struct RateLimiter {
-    /// Maximum tokens in the bucket.
-    capacity: u32,
-    /// Current available tokens.
-    tokens: u32,
-    /// Duration of the refill window.
-    window: Duration,
-    /// When the current window started.
-    window_start: Instant,
-}
-
-impl RateLimiter {
-    /// Create a new rate limiter with the given capacity and window.
-    pub fn new(capacity: u32, window: Duration) -> Self {
-        Self {
-            capacity,
-            tokens: capacity,
-            window,
-            window_start: Instant::now(),
-        }
-    }
-
-    /// Acquire a single token, sleeping if necessary.
-    ///
-    /// If the current window has elapsed, the bucket is refilled.
-    /// If no tokens are available, this method sleeps until the
-    /// window resets.
-    pub async fn acquire(&mut self) {
-        let elapsed = self.window_start.elapsed();
-        if elapsed >= self.window {
-            // Refill the bucket and start a new window.
-            self.tokens = self.capacity;
-            self.window_start = Instant::now();
-        }
-
-        if self.tokens > 0 {
-            self.tokens -= 1;
-        } else {
-            // Sleep until the window resets.
-            let remaining = self.window.saturating_sub(elapsed);
-            tracing::warn!(
-                sleep_ms = remaining.as_millis() as u64,
-                "GitHub rate limit reached, sleeping"
-            );
-            tokio::time::sleep(remaining).await;
-            // After sleeping, refill and consume one token.
-            self.tokens = self.capacity - 1;
-            self.window_start = Instant::now();
-        }
-    }
-}
-
 /// HTTP client for the GitHub REST API v3.
 ///
 /// All methods return [`DomainResult`] and map HTTP / network errors
@@ -90,21 +33,32 @@ pub struct GitHubClient {
     /// GitHub personal access token or fine-grained token.
     token: String,
     /// Shared rate limiter (5 000 req/hr for authenticated requests).
-    rate_limiter: Arc<Mutex<RateLimiter>>,
+    rate_limiter: Arc<Mutex<TokenBucketRateLimiter>>,
 }
 
 impl GitHubClient {
     /// Create a new client with the given token.
     pub fn new(token: String) -> Self {
-        // GitHub allows 5 000 authenticated requests per hour.
-        let rate_limiter = RateLimiter::new(5_000, Duration::from_secs(3_600));
         Self {
             http: Client::new(),
             token,
-            rate_limiter: Arc::new(Mutex::new(rate_limiter)),
+            rate_limiter: Arc::new(Mutex::new(TokenBucketRateLimiter::new(
+                Self::default_rate_limit(),
+            ))),
         }
     }
 
+    /// GitHub allows 5 000 authenticated requests per hour.
+    pub fn default_rate_limit() -> RateLimitConfig {
+        RateLimitConfig::new(5_000, Duration::from_secs(3_600))
+    }
+
+    /// Replace the client-side rate limit.
+    pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limiter = Arc::new(Mutex::new(TokenBucketRateLimiter::new(config)));
+        self
+    }
+
     /// Create a client by reading the `ABATHUR_GITHUB_TOKEN` environment variable.
     ///
     /// Returns `Err` if the variable is not set or is empty.
@@ -278,6 +232,98 @@ impl GitHubClient {
         Ok(())
     }
 
+    /// Add labels to an issue, keeping any it already has.
+    pub async fn add_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        labels: Vec<String>,
+    ) -> DomainResult<()> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/labels",
+            GITHUB_API_BASE, owner, repo, issue_number
+        );
+        let body = GitHubAddLabelsRequest { labels };
+
+        let resp = self
+            .rate_limited_request(reqwest::Method::POST, &url)
+            .await
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| DomainError::ExternalServiceError {
+                service: "github".to_string(),
+                reason: format!("add_labels request failed: {e}"),
+            })?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body_text = resp
+                .text()
+                .await
+                .unwrap_or_else(|e| format!("<body read failed: {e}>"));
+            return Err(DomainError::ExternalServiceError {
+                service: "github".to_string(),
+                reason: format!("add_labels returned {status}: {body_text}"),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Remove a label from an issue.
+    ///
+    /// A label the issue does not carry (HTTP 404) is not an error.
+    pub async fn remove_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        label: &str,
+    ) -> DomainResult<()> {
+        // Push the label as a path segment so names with spaces or slashes
+        // are percent-encoded.
+        let mut url = reqwest::Url::parse(&format!(
+            "{}/repos/{}/{}/issues/{}/labels",
+            GITHUB_API_BASE, owner, repo, issue_number
+        ))
+        .map_err(|e| DomainError::ExternalServiceError {
+            service: "github".to_string(),
+            reason: format!("remove_label URL invalid: {e}"),
+        })?;
+        url.path_segments_mut()
+            .map_err(|_| DomainError::ExternalServiceError {
+                service: "github".to_string(),
+                reason: "remove_label URL cannot have path segments".to_string(),
+            })?
+            .push(label);
+
+        let resp = self
+            .rate_limited_request(reqwest::Method::DELETE, url.as_str())
+            .await
+            .send()
+            .await
+            .map_err(|e| DomainError::ExternalServiceError {
+                service: "github".to_string(),
+                reason: format!("remove_label request failed: {e}"),
+            })?;
+
+        if !resp.status().is_success() && resp.status() != reqwest::StatusCode::NOT_FOUND {
+            let status = resp.status();
+            let body_text = resp
+                .text()
+                .await
+                .unwrap_or_else(|e| format!("<body read failed: {e}>"));
+            return Err(DomainError::ExternalServiceError {
+                service: "github".to_string(),
+                reason: format!("remove_label returned {status}: {body_text}"),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Post a comment on an issue.
     pub async fn post_comment(
         &self,
@@ -379,6 +425,31 @@ impl GitHubClient {
             .header("User-Agent", "abathur-swarm")
     }
 
+    /// Check that the token is accepted by fetching the authenticated user.
+    pub async fn check_auth(&self) -> DomainResult<()> {
+        let url = format!("{}/user", GITHUB_API_BASE);
+
+        let resp = self
+            .rate_limited_request(reqwest::Method::GET, &url)
+            .await
+            .send()
+            .await
+            .map_err(|e| DomainError::ExternalServiceError {
+                service: "github".to_string(),
+                reason: format!("check_auth failed: {e}"),
+            })?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_else(|e| format!("<body read failed: {e}>"));
+            return Err(DomainError::ExternalServiceError {
+                service: "github".to_string(),
+                reason: format!("check_auth returned {status}: {body}"),
+            });
+        }
+        Ok(())
+    }
+
     /// Fetch full pull request details from the pulls endpoint.
     ///
     /// The issues endpoint only returns a stub; this hydrates the full PR
@@ -527,22 +598,6 @@ pub(crate) static GITHUB_TOKEN_ENV_LOCK: std::sync::LazyLock<std::sync::Mutex<()
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_rate_limiter_creation() {
-        let rl = RateLimiter::new(5_000, Duration::from_secs(3_600));
-        assert_eq!(rl.capacity, 5_000);
-        assert_eq!(rl.tokens, 5_000);
-    }
-
-    #[tokio::test]
-    async fn test_rate_limiter_acquire_decrements_tokens() {
-        let mut rl = RateLimiter::new(5, Duration::from_secs(60));
-        rl.acquire().await;
-        assert_eq!(rl.tokens, 4);
-        rl.acquire().await;
-        assert_eq!(rl.tokens, 3);
-    }
-
     #[test]
     fn test_client_from_env_missing() {
         // Serialize access to ABATHUR_GITHUB_TOKEN across tests to prevent races.
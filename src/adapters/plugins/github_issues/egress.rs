@@ -14,12 +14,15 @@ use crate::domain::models::adapter::{AdapterManifest, EgressAction, EgressResult
 use crate::domain::ports::adapter::EgressAdapter;
 
 use super::client::GitHubClient;
+use super::models::StatusLabelSets;
 
 /// Adapter that pushes actions to GitHub Issues (close/reopen, comments, creation).
 ///
 /// Configuration is read from the [`AdapterManifest::config`] map:
 /// - `owner` (required): repository owner (user or organisation).
 /// - `repo` (required): repository name.
+/// - `in_progress_labels`, `done_labels` (optional): comma-separated label
+///   sets kept in step with status updates; see [`StatusLabelSets`].
 #[derive(Debug)]
 pub struct GitHubEgressAdapter {
     /// The adapter manifest describing capabilities and config.
@@ -44,6 +47,28 @@ impl GitHubEgressAdapter {
         self.manifest.config.get("repo").and_then(|v| v.as_str())
     }
 
+    /// Read the `in_progress_labels` and `done_labels` sets from config.
+    fn status_labels(&self) -> StatusLabelSets {
+        let read = |key: &str| -> Vec<String> {
+            self.manifest
+                .config
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|raw| {
+                    raw.split(',')
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        StatusLabelSets {
+            in_progress: read("in_progress_labels"),
+            done: read("done_labels"),
+        }
+    }
+
     /// Parse an issue number from an `external_id` string.
     ///
     /// Accepts plain numeric strings only (e.g., `"42"`).
@@ -94,6 +119,10 @@ impl EgressAdapter for GitHubEgressAdapter {
         &self.manifest
     }
 
+    async fn health_check(&self) -> DomainResult<()> {
+        self.client.check_auth().await
+    }
+
     async fn execute(&self, action: &EgressAction) -> DomainResult<EgressResult> {
         // Resolve owner/repo once and return early with a clear error if missing.
         let owner = self.owner().ok_or_else(|| DomainError::ConfigError {
@@ -125,6 +154,26 @@ impl EgressAdapter for GitHubEgressAdapter {
                     .update_issue_state(owner, repo, issue_number, github_state)
                     .await?;
 
+                let status_labels = self.status_labels();
+                if !status_labels.is_empty() {
+                    let (add, remove) = status_labels.changes_for(new_status, github_state);
+                    for label in remove {
+                        self.client
+                            .remove_label(owner, repo, issue_number, label)
+                            .await?;
+                    }
+                    if !add.is_empty() {
+                        self.client
+                            .add_labels(
+                                owner,
+                                repo,
+                                issue_number,
+                                add.into_iter().map(str::to_string).collect(),
+                            )
+                            .await?;
+                    }
+                }
+
                 Ok(EgressResult::ok_with_id(external_id))
             }
 
@@ -276,6 +325,28 @@ mod tests {
         .with_config("repo", serde_json::json!("my-repo"))
     }
 
+    // ── status_labels config ────────────────────────────────────────────────
+
+    #[test]
+    fn test_status_labels_from_config() {
+        let manifest = test_manifest()
+            .with_config("in_progress_labels", serde_json::json!("in progress, wip"))
+            .with_config("done_labels", serde_json::json!(""));
+        let client = Arc::new(GitHubClient::new("test-token".to_string()));
+        let adapter = GitHubEgressAdapter::new(manifest, client);
+
+        let sets = adapter.status_labels();
+        assert_eq!(sets.in_progress, vec!["in progress", "wip"]);
+        assert!(sets.done.is_empty());
+    }
+
+    #[test]
+    fn test_status_labels_absent() {
+        let client = Arc::new(GitHubClient::new("test-token".to_string()));
+        let adapter = GitHubEgressAdapter::new(test_manifest(), client);
+        assert!(adapter.status_labels().is_empty());
+    }
+
     // ── parse_issue_number ──────────────────────────────────────────────────
 
     #[test]
@@ -19,7 +19,7 @@ use crate::domain::models::adapter::{AdapterManifest, IngestionItem, IngestionIt
 use crate::domain::ports::adapter::IngestionAdapter;
 
 use super::client::GitHubClient;
-use super::models::{GitHubIssue, GitHubPullRequestDetail};
+use super::models::{GitHubIssue, GitHubPullRequestDetail, LabelPriorityMap};
 
 /// Adapter that ingests issues from a GitHub repository.
 ///
@@ -30,6 +30,9 @@ use super::models::{GitHubIssue, GitHubPullRequestDetail};
 ///   `"all"`. Defaults to `"open"`.
 /// - `filter_labels` (optional): comma-separated list of label names; when
 ///   set, only issues that carry at least one matching label are ingested.
+/// - `label_priority_map` (optional): label name to priority rules; see
+///   [`LabelPriorityMap`]. When absent, priority keywords in label names
+///   are used instead.
 #[derive(Debug)]
 pub struct GitHubIngestionAdapter {
     /// The adapter manifest describing capabilities and config.
@@ -169,6 +172,35 @@ impl GitHubIngestionAdapter {
             .unwrap_or(100_000)
     }
 
+    /// Read the optional `label_priority_map` rules from config.
+    fn label_priority_map(&self) -> LabelPriorityMap {
+        self.manifest
+            .config
+            .get("label_priority_map")
+            .map(LabelPriorityMap::from_config)
+            .unwrap_or_default()
+    }
+
+    /// Choose the priority for an issue.
+    ///
+    /// With a configured `priority_map`, the highest mapped label wins and
+    /// an issue with no mapped label is [`TaskPriority::Normal`]. Without
+    /// one, falls back to [`extract_priority`](Self::extract_priority).
+    fn issue_priority(
+        issue: &GitHubIssue,
+        priority_map: &LabelPriorityMap,
+    ) -> Option<TaskPriority> {
+        if priority_map.is_empty() {
+            Self::extract_priority(issue)
+        } else {
+            Some(
+                priority_map
+                    .priority_for(&issue.labels)
+                    .unwrap_or(TaskPriority::Normal),
+            )
+        }
+    }
+
     /// Map a GitHub issue's labels to a [`TaskPriority`].
     ///
     /// Recognises labels whose names contain priority keywords
@@ -199,14 +231,14 @@ impl GitHubIngestionAdapter {
     }
 
     /// Convert a [`GitHubIssue`] to an [`IngestionItem`] with `IngestionItemKind::Issue`.
-    fn to_ingestion_item(issue: &GitHubIssue) -> IngestionItem {
+    fn to_ingestion_item(issue: &GitHubIssue, priority_map: &LabelPriorityMap) -> IngestionItem {
         let external_id = issue.number.to_string();
         let description = issue.body.clone().unwrap_or_default();
 
         let mut item = IngestionItem::new(&external_id, &issue.title, description)
             .with_item_kind(IngestionItemKind::Issue);
 
-        if let Some(priority) = Self::extract_priority(issue) {
+        if let Some(priority) = Self::issue_priority(issue, priority_map) {
             item = item.with_priority(priority);
         }
 
@@ -285,6 +317,10 @@ impl IngestionAdapter for GitHubIngestionAdapter {
         &self.manifest
     }
 
+    async fn health_check(&self) -> DomainResult<()> {
+        self.client.check_auth().await
+    }
+
     async fn poll(&self, last_poll: Option<DateTime<Utc>>) -> DomainResult<Vec<IngestionItem>> {
         let owner =
             self.owner()
@@ -320,6 +356,7 @@ impl IngestionAdapter for GitHubIngestionAdapter {
         let pr_base_filter = self.pr_base_filter();
         let pr_ignore_authors = self.pr_ignore_authors();
         let max_diff_chars = self.max_diff_chars();
+        let priority_map = self.label_priority_map();
 
         let mut items: Vec<IngestionItem> = Vec::new();
 
@@ -411,7 +448,7 @@ impl IngestionAdapter for GitHubIngestionAdapter {
                     continue;
                 }
 
-                items.push(Self::to_ingestion_item(gh_item));
+                items.push(Self::to_ingestion_item(gh_item, &priority_map));
             }
         }
 
@@ -530,12 +567,33 @@ mod tests {
         assert_eq!(GitHubIngestionAdapter::extract_priority(&issue), None);
     }
 
+    #[test]
+    fn test_label_priority_map_overrides_keywords() {
+        let manifest = test_manifest().with_config(
+            "label_priority_map",
+            serde_json::json!({ "P0": "critical", "P2": "normal", "P3": "low" }),
+        );
+        let client = Arc::new(GitHubClient::new("test-token".to_string()));
+        let adapter = GitHubIngestionAdapter::new(manifest, client);
+        let map = adapter.label_priority_map();
+
+        // Highest mapped label wins; keyword labels are not consulted.
+        let issue = make_github_issue(8, "Outage", vec!["P3", "priority: high", "P0"]);
+        let item = GitHubIngestionAdapter::to_ingestion_item(&issue, &map);
+        assert_eq!(item.priority, Some(TaskPriority::Critical));
+
+        // No mapped label defaults to Normal.
+        let issue = make_github_issue(9, "Question", vec!["priority: high"]);
+        let item = GitHubIngestionAdapter::to_ingestion_item(&issue, &map);
+        assert_eq!(item.priority, Some(TaskPriority::Normal));
+    }
+
     // ── to_ingestion_item ───────────────────────────────────────────────────
 
     #[test]
     fn test_to_ingestion_item_full() {
         let issue = make_github_issue(42, "Fix login bug", vec!["bug", "priority: high"]);
-        let item = GitHubIngestionAdapter::to_ingestion_item(&issue);
+        let item = GitHubIngestionAdapter::to_ingestion_item(&issue, &LabelPriorityMap::default());
 
         assert_eq!(item.external_id, "42");
         assert_eq!(item.title, "Fix login bug");
@@ -551,7 +609,7 @@ mod tests {
     fn test_to_ingestion_item_minimal() {
         let mut issue = make_github_issue(1, "Minimal", vec![]);
         issue.body = None;
-        let item = GitHubIngestionAdapter::to_ingestion_item(&issue);
+        let item = GitHubIngestionAdapter::to_ingestion_item(&issue, &LabelPriorityMap::default());
 
         assert_eq!(item.external_id, "1");
         assert_eq!(item.description, "");
@@ -630,7 +688,9 @@ mod tests {
                     true
                 }
             })
-            .map(GitHubIngestionAdapter::to_ingestion_item)
+            .map(|issue| {
+                GitHubIngestionAdapter::to_ingestion_item(issue, &LabelPriorityMap::default())
+            })
             .collect();
 
         assert_eq!(items.len(), 1, "Only the non-PR issue should be included");
@@ -645,7 +705,7 @@ mod tests {
     #[test]
     fn test_to_ingestion_item_sets_issue_kind() {
         let issue = make_github_issue(42, "Fix login bug", vec!["bug"]);
-        let item = GitHubIngestionAdapter::to_ingestion_item(&issue);
+        let item = GitHubIngestionAdapter::to_ingestion_item(&issue, &LabelPriorityMap::default());
         assert_eq!(item.item_kind, Some(IngestionItemKind::Issue));
     }
 
@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::domain::models::TaskPriority;
+
 /// An issue returned by the GitHub API.
 ///
 /// Note: issues and pull requests share the same endpoint. Pull requests
@@ -153,6 +155,134 @@ pub struct GitHubIssueUpdateRequest {
     pub state: String,
 }
 
+/// Request body for adding labels to an issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubAddLabelsRequest {
+    /// Label names to add; existing labels are kept.
+    pub labels: Vec<String>,
+}
+
+/// Label-to-priority rules read from the `label_priority_map` adapter config.
+///
+/// The config is either a table of label name to priority name:
+///
+/// ```toml
+/// [config.label_priority_map]
+/// P0 = "critical"
+/// urgent = "critical"
+/// P3 = "low"
+/// ```
+///
+/// or the same pairs as a comma-separated string (`"P0=critical, P3=low"`).
+/// Label names match case-insensitively. Entries with an unknown priority
+/// name are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelPriorityMap {
+    /// Lowercased label name and the priority it maps to.
+    rules: Vec<(String, TaskPriority)>,
+}
+
+impl LabelPriorityMap {
+    /// Parse the map from its config value.
+    pub fn from_config(value: &serde_json::Value) -> Self {
+        let pairs: Vec<(String, String)> = match value {
+            serde_json::Value::Object(table) => table
+                .iter()
+                .filter_map(|(label, priority)| {
+                    priority.as_str().map(|p| (label.clone(), p.to_string()))
+                })
+                .collect(),
+            serde_json::Value::String(raw) => raw
+                .split(',')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(label, priority)| (label.to_string(), priority.to_string()))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let rules = pairs
+            .into_iter()
+            .filter_map(|(label, priority)| {
+                let label = label.trim().to_lowercase();
+                let priority = TaskPriority::parse(priority.trim());
+                if priority.is_none() {
+                    tracing::warn!(
+                        label = %label,
+                        "Ignoring label_priority_map entry with unknown priority"
+                    );
+                }
+                priority.filter(|_| !label.is_empty()).map(|p| (label, p))
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Whether no rules are configured.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// The highest priority mapped from any of `labels`, or `None` when no
+    /// label has a rule.
+    pub fn priority_for(&self, labels: &[GitHubLabel]) -> Option<TaskPriority> {
+        labels
+            .iter()
+            .filter_map(|label| {
+                let name = label.name.to_lowercase();
+                self.rules
+                    .iter()
+                    .find(|(rule, _)| *rule == name)
+                    .map(|(_, priority)| *priority)
+            })
+            .max()
+    }
+}
+
+/// Labels that mirror a task's lifecycle on its GitHub issue, read from the
+/// comma-separated `in_progress_labels` and `done_labels` adapter config.
+///
+/// On a status update the labels for the new status are added and the other
+/// set is removed, so an issue never carries both.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusLabelSets {
+    /// Labels applied while the task is being worked on.
+    pub in_progress: Vec<String>,
+    /// Labels applied once the issue is closed.
+    pub done: Vec<String>,
+}
+
+impl StatusLabelSets {
+    /// Whether neither set is configured.
+    pub fn is_empty(&self) -> bool {
+        self.in_progress.is_empty() && self.done.is_empty()
+    }
+
+    /// Split the label sets into `(add, remove)` for an issue moving to
+    /// `new_status` with resulting GitHub `state`.
+    ///
+    /// Closed issues get the done labels. Open issues get the in-progress
+    /// labels when `new_status` names an in-progress state (`"in progress"`,
+    /// `"in_progress"`, `"running"`), and otherwise lose both sets.
+    pub fn changes_for(&self, new_status: &str, state: &str) -> (Vec<&str>, Vec<&str>) {
+        let normalized = new_status.trim().to_lowercase().replace(['_', '-'], " ");
+        let (add, remove): (&[String], Vec<&String>) = if state == "closed" {
+            (&self.done, self.in_progress.iter().collect())
+        } else if matches!(normalized.as_str(), "in progress" | "running") {
+            (&self.in_progress, self.done.iter().collect())
+        } else {
+            (&[], self.in_progress.iter().chain(&self.done).collect())
+        };
+        (
+            add.iter().map(String::as_str).collect(),
+            remove
+                .into_iter()
+                .filter(|label| !add.contains(label))
+                .map(String::as_str)
+                .collect(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,4 +439,69 @@ mod tests {
         assert_eq!(resp.number, 7);
         assert_eq!(resp.html_url, "https://github.com/org/repo/issues/7");
     }
+
+    fn labels(names: &[&str]) -> Vec<GitHubLabel> {
+        names
+            .iter()
+            .map(|n| GitHubLabel {
+                name: n.to_string(),
+                color: "ffffff".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_label_priority_map_highest_match_wins() {
+        let map = LabelPriorityMap::from_config(&serde_json::json!({
+            "P0": "critical",
+            "urgent": "critical",
+            "P1": "high",
+            "P3": "low",
+        }));
+
+        assert_eq!(
+            map.priority_for(&labels(&["p3", "bug", "P1"])),
+            Some(TaskPriority::High)
+        );
+        assert_eq!(
+            map.priority_for(&labels(&["P3", "Urgent"])),
+            Some(TaskPriority::Critical)
+        );
+    }
+
+    #[test]
+    fn test_label_priority_map_no_match() {
+        let map = LabelPriorityMap::from_config(&serde_json::json!("P0=critical, P3=low"));
+        assert!(!map.is_empty());
+        assert_eq!(map.priority_for(&labels(&["bug", "help wanted"])), None);
+        assert_eq!(map.priority_for(&[]), None);
+    }
+
+    #[test]
+    fn test_label_priority_map_ignores_unknown_priorities() {
+        let map = LabelPriorityMap::from_config(&serde_json::json!({ "P0": "asap" }));
+        assert!(map.is_empty());
+        assert!(LabelPriorityMap::from_config(&serde_json::json!(42)).is_empty());
+    }
+
+    #[test]
+    fn test_status_label_changes() {
+        let sets = StatusLabelSets {
+            in_progress: vec!["in progress".to_string()],
+            done: vec!["done".to_string()],
+        };
+
+        assert_eq!(
+            sets.changes_for("in_progress", "open"),
+            (vec!["in progress"], vec!["done"])
+        );
+        assert_eq!(
+            sets.changes_for("completed", "closed"),
+            (vec!["done"], vec!["in progress"])
+        );
+        assert_eq!(
+            sets.changes_for("open", "open"),
+            (vec![], vec!["in progress", "done"])
+        );
+    }
 }
@@ -7,6 +7,7 @@
 
 pub mod clickup;
 pub mod github_issues;
+pub mod rate_limit;
 
 use std::sync::Arc;
 
@@ -15,7 +16,7 @@ use crate::domain::models::adapter::{
 };
 use crate::domain::ports::adapter::{EgressAdapter, IngestionAdapter};
 
-use self::clickup::client::ClickUpClient;
+use self::clickup::client::{ClickUpClient, RetryPolicy};
 use self::clickup::egress::ClickUpEgressAdapter;
 use self::clickup::ingestion::ClickUpIngestionAdapter;
 
@@ -23,6 +24,8 @@ use self::github_issues::client::GitHubClient;
 use self::github_issues::egress::GitHubEgressAdapter;
 use self::github_issues::ingestion::GitHubIngestionAdapter;
 
+use self::rate_limit::RateLimitConfig;
+
 /// Metadata for an adapter that the binary knows how to scaffold.
 #[derive(Debug, Clone)]
 pub struct KnownAdapter {
@@ -110,7 +113,14 @@ pub fn create_native_adapter(
 > {
     match manifest.name.as_str() {
         "clickup" => {
-            let client = Arc::new(ClickUpClient::from_env()?);
+            let client = Arc::new(
+                ClickUpClient::from_env()?
+                    .with_retry_policy(RetryPolicy::from_config(&manifest.config))
+                    .with_rate_limit(RateLimitConfig::from_config(
+                        &manifest.config,
+                        ClickUpClient::default_rate_limit(),
+                    )),
+            );
 
             let ingestion: Option<Box<dyn IngestionAdapter>> =
                 if manifest.direction.supports_ingestion() {
@@ -134,7 +144,9 @@ pub fn create_native_adapter(
             Ok((ingestion, egress))
         }
         "github-issues" => {
-            let client = Arc::new(GitHubClient::from_env()?);
+            let client = Arc::new(GitHubClient::from_env()?.with_rate_limit(
+                RateLimitConfig::from_config(&manifest.config, GitHubClient::default_rate_limit()),
+            ));
 
             let ingestion: Option<Box<dyn IngestionAdapter>> =
                 if manifest.direction.supports_ingestion() {
@@ -0,0 +1,271 @@
+//! Token-bucket rate limiting shared by the native adapter HTTP clients.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Sustained rate and burst allowance for a [`TokenBucketRateLimiter`].
+///
+/// Tokens refill continuously at `rate` per `window`, and the bucket holds at
+/// most `burst_capacity` of them, so idle time banks up to that many requests
+/// for a later burst. Each client has its own defaults; the adapter manifest
+/// config can override them:
+///
+/// ```toml
+/// [config]
+/// rate_limit = 100
+/// rate_limit_window_secs = 60
+/// rate_limit_burst = 150
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// Tokens added per `window`.
+    pub rate: u32,
+    /// Period over which `rate` tokens are added.
+    pub window: Duration,
+    /// Most tokens the bucket can hold; defaults to `rate`.
+    pub burst_capacity: u32,
+}
+
+impl RateLimitConfig {
+    /// `rate` tokens per `window`, with a burst capacity equal to `rate`.
+    pub fn new(rate: u32, window: Duration) -> Self {
+        Self {
+            rate,
+            window,
+            burst_capacity: rate,
+        }
+    }
+
+    /// Let the bucket bank up to `burst_capacity` tokens.
+    pub fn with_burst_capacity(mut self, burst_capacity: u32) -> Self {
+        self.burst_capacity = burst_capacity;
+        self
+    }
+
+    /// Apply overrides from the adapter manifest config on top of `defaults`,
+    /// keeping the default for any key that is absent or not a positive
+    /// integer (numbers and numeric strings are both accepted). Without
+    /// `rate_limit_burst`, an overridden rate also resets the burst capacity
+    /// to match it.
+    pub fn from_config(config: &HashMap<String, serde_json::Value>, defaults: Self) -> Self {
+        let get = |key: &str| {
+            config
+                .get(key)
+                .and_then(|v| {
+                    v.as_u64()
+                        .or_else(|| v.as_str().and_then(|s| s.trim().parse().ok()))
+                })
+                .filter(|&n| n > 0)
+        };
+        let rate = get("rate_limit")
+            .map(|n| n.min(u32::MAX as u64) as u32)
+            .unwrap_or(defaults.rate);
+        let window = get("rate_limit_window_secs")
+            .map(Duration::from_secs)
+            .unwrap_or(defaults.window);
+        let burst_capacity = get("rate_limit_burst")
+            .map(|n| n.min(u32::MAX as u64) as u32)
+            .unwrap_or(if rate == defaults.rate {
+                defaults.burst_capacity
+            } else {
+                rate
+            });
+        Self {
+            rate,
+            window,
+            burst_capacity,
+        }
+    }
+
+    /// Tokens added per second.
+    fn tokens_per_sec(&self) -> f64 {
+        self.rate as f64 / self.window.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+}
+
+/// Token-bucket rate limiter.
+///
+/// Starts full. [`try_acquire`](Self::try_acquire) takes tokens only when
+/// the bucket holds enough of them; [`acquire`](Self::acquire) sleeps until
+/// it does.
+#[derive(Debug)]
+pub struct TokenBucketRateLimiter {
+    config: RateLimitConfig,
+    /// Tokens available as of `last_refill`, never above `burst_capacity`.
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucketRateLimiter {
+    /// Create a full bucket. A zero rate or capacity is raised to one so the
+    /// limiter can always make progress.
+    pub fn new(mut config: RateLimitConfig) -> Self {
+        config.rate = config.rate.max(1);
+        config.burst_capacity = config.burst_capacity.max(1);
+        Self {
+            tokens: config.burst_capacity as f64,
+            config,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// The limiter's configuration.
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    /// Whole tokens available right now.
+    pub fn available(&mut self) -> u32 {
+        self.refill(Instant::now());
+        self.tokens.floor() as u32
+    }
+
+    /// Take `n` tokens if the bucket holds them. Never grants more than the
+    /// bucket holds, so a request larger than `burst_capacity` always fails.
+    pub fn try_acquire(&mut self, n: u32) -> bool {
+        self.try_acquire_at(n, Instant::now())
+    }
+
+    /// Take one token, sleeping until it is available.
+    pub async fn acquire(&mut self) {
+        self.acquire_n(1).await
+    }
+
+    /// Take `n` tokens, sleeping until they are available. A request larger
+    /// than the burst capacity is capped to it, so it waits for a full
+    /// bucket rather than forever.
+    pub async fn acquire_n(&mut self, n: u32) {
+        let n = n.min(self.config.burst_capacity);
+        loop {
+            let now = Instant::now();
+            if self.try_acquire_at(n, now) {
+                return;
+            }
+            let wait = self.wait_for(n);
+            tracing::warn!(
+                sleep_ms = wait.as_millis() as u64,
+                tokens = n,
+                "Rate limit reached, sleeping"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn try_acquire_at(&mut self, n: u32, now: Instant) -> bool {
+        self.refill(now);
+        let n = n as f64;
+        if self.tokens >= n {
+            self.tokens -= n;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Add the tokens earned since the last refill, capped at capacity.
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.last_refill = now;
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.config.tokens_per_sec())
+            .min(self.config.burst_capacity as f64);
+    }
+
+    /// Time until `n` tokens will have accumulated.
+    fn wait_for(&self, n: u32) -> Duration {
+        let missing = (n as f64 - self.tokens).max(0.0);
+        Duration::from_secs_f64(missing / self.config.tokens_per_sec())
+            .max(Duration::from_millis(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(rate: u32, window: Duration, burst: u32) -> (TokenBucketRateLimiter, Instant) {
+        let mut limiter = TokenBucketRateLimiter::new(
+            RateLimitConfig::new(rate, window).with_burst_capacity(burst),
+        );
+        let start = Instant::now();
+        limiter.last_refill = start;
+        (limiter, start)
+    }
+
+    #[test]
+    fn test_idle_then_burst_up_to_capacity_then_sustained_rate() {
+        // 10 tokens/sec sustained, bursts of up to 30.
+        let (mut rl, start) = limiter(10, Duration::from_secs(1), 30);
+        assert!(rl.try_acquire_at(30, start));
+        assert!(!rl.try_acquire_at(1, start));
+
+        // A long idle period banks no more than the burst capacity.
+        let later = start + Duration::from_secs(60);
+        for _ in 0..30 {
+            assert!(rl.try_acquire_at(1, later));
+        }
+        assert!(!rl.try_acquire_at(1, later));
+
+        // Afterwards requests are admitted at the sustained rate only.
+        let tick = later + Duration::from_millis(100);
+        assert!(rl.try_acquire_at(1, tick));
+        assert!(!rl.try_acquire_at(1, tick));
+        let second = later + Duration::from_secs(1);
+        assert!(rl.try_acquire_at(9, second));
+        assert!(!rl.try_acquire_at(1, second));
+    }
+
+    #[test]
+    fn test_try_acquire_multiple_tokens_never_over_grants() {
+        let (mut rl, start) = limiter(5, Duration::from_secs(1), 8);
+        assert!(!rl.try_acquire_at(9, start));
+        assert!(rl.try_acquire_at(6, start));
+        // Two left; a three-token request must wait, not go negative.
+        assert!(!rl.try_acquire_at(3, start));
+        assert!(rl.try_acquire_at(2, start));
+        // 0.4s at 5/sec earns exactly two tokens.
+        let later = start + Duration::from_millis(400);
+        assert!(!rl.try_acquire_at(3, later));
+        assert!(rl.try_acquire_at(2, later));
+    }
+
+    #[test]
+    fn test_burst_below_rate_caps_refill() {
+        let (mut rl, start) = limiter(100, Duration::from_secs(60), 10);
+        let later = start + Duration::from_secs(3_600);
+        assert_eq!(rl.tokens, 10.0);
+        rl.refill(later);
+        assert_eq!(rl.tokens, 10.0);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_refill() {
+        let mut rl = TokenBucketRateLimiter::new(RateLimitConfig::new(100, Duration::from_secs(1)));
+        assert!(rl.try_acquire(100));
+        let started = Instant::now();
+        rl.acquire_n(2).await;
+        assert!(started.elapsed() >= Duration::from_millis(15));
+        assert_eq!(rl.available(), 0);
+    }
+
+    #[test]
+    fn test_from_config_overrides_defaults() {
+        let defaults = RateLimitConfig::new(100, Duration::from_secs(60)).with_burst_capacity(120);
+        assert_eq!(
+            RateLimitConfig::from_config(&HashMap::new(), defaults.clone()),
+            defaults
+        );
+
+        let config = HashMap::from([
+            ("rate_limit".to_string(), serde_json::json!(50)),
+            ("rate_limit_window_secs".to_string(), serde_json::json!(10)),
+            ("rate_limit_burst".to_string(), serde_json::json!(0)),
+        ]);
+        let parsed = RateLimitConfig::from_config(&config, defaults.clone());
+        assert_eq!(parsed, RateLimitConfig::new(50, Duration::from_secs(10)));
+
+        let config = HashMap::from([("rate_limit_burst".to_string(), serde_json::json!("500"))]);
+        let parsed = RateLimitConfig::from_config(&config, defaults);
+        assert_eq!(parsed.burst_capacity, 500);
+        assert_eq!(parsed.rate, 100);
+    }
+}
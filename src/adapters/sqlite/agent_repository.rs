@@ -29,11 +29,13 @@ impl AgentRepository for SqliteAgentRepository {
         let tools_json = serde_json::to_string(&template.tools)?;
         let constraints_json = serde_json::to_string(&template.constraints)?;
         let handoff_json = serde_json::to_string(&template.agent_card.handoff_targets)?;
+        let capabilities_json = serde_json::to_string(&template.agent_card.capabilities)?;
 
         sqlx::query(
             r#"INSERT INTO agent_templates (id, name, description, tier, version, system_prompt,
-               tools, constraints, handoff_targets, max_turns, read_only, is_active, created_at, updated_at)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#
+               tools, constraints, handoff_targets, capabilities, max_turns, read_only, is_active,
+               created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
         )
         .bind(template.id.to_string())
         .bind(&template.name)
@@ -44,9 +46,14 @@ impl AgentRepository for SqliteAgentRepository {
         .bind(&tools_json)
         .bind(&constraints_json)
         .bind(&handoff_json)
+        .bind(&capabilities_json)
         .bind(template.max_turns as i32)
         .bind(template.read_only as i32)
-        .bind(if template.status == AgentStatus::Active { 1i32 } else { 0i32 })
+        .bind(if template.status == AgentStatus::Active {
+            1i32
+        } else {
+            0i32
+        })
         .bind(template.created_at.to_rfc3339())
         .bind(template.updated_at.to_rfc3339())
         .execute(&self.pool)
@@ -94,10 +101,11 @@ impl AgentRepository for SqliteAgentRepository {
         let tools_json = serde_json::to_string(&template.tools)?;
         let constraints_json = serde_json::to_string(&template.constraints)?;
         let handoff_json = serde_json::to_string(&template.agent_card.handoff_targets)?;
+        let capabilities_json = serde_json::to_string(&template.agent_card.capabilities)?;
 
         let result = sqlx::query(
             r#"UPDATE agent_templates SET name = ?, description = ?, tier = ?, version = ?,
-               system_prompt = ?, tools = ?, constraints = ?, handoff_targets = ?,
+               system_prompt = ?, tools = ?, constraints = ?, handoff_targets = ?, capabilities = ?,
                max_turns = ?, read_only = ?, is_active = ?, updated_at = ?
                WHERE id = ?"#,
         )
@@ -109,6 +117,7 @@ impl AgentRepository for SqliteAgentRepository {
         .bind(&tools_json)
         .bind(&constraints_json)
         .bind(&handoff_json)
+        .bind(&capabilities_json)
         .bind(template.max_turns as i32)
         .bind(template.read_only as i32)
         .bind(if template.status == AgentStatus::Active {
@@ -192,6 +201,31 @@ impl AgentRepository for SqliteAgentRepository {
         .await
     }
 
+    async fn find_by_capability(&self, capability: &str) -> DomainResult<Vec<AgentTemplate>> {
+        let rows: Vec<TemplateRow> = sqlx::query_as(
+            r#"SELECT * FROM agent_templates t
+               WHERE t.is_active = 1 AND (
+                   EXISTS (SELECT 1 FROM json_each(t.capabilities) c
+                           WHERE lower(c.value) = lower(?1))
+                   OR EXISTS (SELECT 1 FROM json_each(t.tools) tool
+                              WHERE lower(json_extract(tool.value, '$.name')) = lower(?1)))
+               ORDER BY t.name, t.version DESC"#,
+        )
+        .bind(capability)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut templates: Vec<AgentTemplate> = Vec::new();
+        for row in rows {
+            // Rows arrive newest version first within each name.
+            if templates.last().is_some_and(|t| t.name == row.name) {
+                continue;
+            }
+            templates.push(row.try_into()?);
+        }
+        Ok(templates)
+    }
+
     // Instance operations
 
     async fn create_instance(&self, instance: &AgentInstance) -> DomainResult<()> {
@@ -307,6 +341,7 @@ struct TemplateRow {
     tools: Option<String>,
     constraints: Option<String>,
     handoff_targets: Option<String>,
+    capabilities: Option<String>,
     max_turns: i32,
     read_only: Option<i32>,
     is_active: i32,
@@ -327,6 +362,7 @@ impl TryFrom<TemplateRow> for AgentTemplate {
         let tools: Vec<ToolCapability> = super::parse_json_or_default(row.tools)?;
         let constraints: Vec<AgentConstraint> = super::parse_json_or_default(row.constraints)?;
         let handoff_targets: Vec<String> = super::parse_json_or_default(row.handoff_targets)?;
+        let capabilities: Vec<String> = super::parse_json_or_default(row.capabilities)?;
 
         let created_at = super::parse_datetime(&row.created_at)?;
         let updated_at = super::parse_datetime(&row.updated_at)?;
@@ -347,6 +383,7 @@ impl TryFrom<TemplateRow> for AgentTemplate {
             tools,
             constraints,
             agent_card: AgentCard {
+                capabilities,
                 handoff_targets,
                 ..Default::default()
             },
@@ -459,6 +496,42 @@ mod tests {
         assert_eq!(architects.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_find_by_capability() {
+        let (repo, pool) = setup_test_repo().await;
+
+        let reviewer = AgentTemplate::new("reviewer", AgentTier::Specialist)
+            .with_capability("code-review")
+            .with_capability("security-audit");
+        let linter = AgentTemplate::new("linter", AgentTier::Worker)
+            .with_tool(ToolCapability::new("Code-Review", "Review diffs"));
+        let writer = AgentTemplate::new("writer", AgentTier::Worker).with_capability("docs");
+        let mut retired =
+            AgentTemplate::new("old-reviewer", AgentTier::Worker).with_capability("code-review");
+        retired.status = AgentStatus::Disabled;
+        for template in [&reviewer, &linter, &writer, &retired] {
+            repo.create_template(template).await.unwrap();
+        }
+        // A newer version of the reviewer; only the latest is returned.
+        let mut reviewer_v2 = reviewer.clone();
+        reviewer_v2.id = Uuid::new_v4();
+        reviewer_v2.version = 2;
+        repo.create_template(&reviewer_v2).await.unwrap();
+
+        let found = repo.find_by_capability("code-review").await.unwrap();
+        let names: Vec<_> = found.iter().map(|t| (t.name.as_str(), t.version)).collect();
+        assert_eq!(names, vec![("linter", 1), ("reviewer", 2)]);
+        assert_eq!(
+            found[1].agent_card.capabilities,
+            vec!["code-review", "security-audit"]
+        );
+
+        let found = repo.find_by_capability("SECURITY-AUDIT").await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "reviewer");
+        assert!(repo.find_by_capability("deploy").await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_instance_lifecycle() {
         let (repo, pool) = setup_test_repo().await;
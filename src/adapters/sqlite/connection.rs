@@ -17,6 +17,8 @@ pub enum ConnectionError {
     DirectoryCreationFailed(#[source] std::io::Error),
     #[error("Connection failed: {0}")]
     ConnectionFailed(#[source] sqlx::Error),
+    #[error("Maintenance query failed: {0}")]
+    MaintenanceFailed(#[source] sqlx::Error),
 }
 
 #[derive(Debug, Clone)]
@@ -142,3 +144,191 @@ pub async fn verify_connection(pool: &SqlitePool) -> Result<(), ConnectionError>
         .map_err(ConnectionError::ConnectionFailed)?;
     Ok(())
 }
+
+/// Row count and on-disk footprint of a single table.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TableStats {
+    pub name: String,
+    pub rows: i64,
+    /// Bytes of pages owned by the table and its indexes, when the SQLite
+    /// build exposes the `dbstat` virtual table.
+    pub bytes: Option<u64>,
+}
+
+/// Size report for a database file and its write-ahead log.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DatabaseStats {
+    pub file_size: u64,
+    pub wal_size: u64,
+    pub page_size: i64,
+    pub page_count: i64,
+    pub freelist_count: i64,
+    /// Tables ordered largest first (by bytes, then rows).
+    pub tables: Vec<TableStats>,
+}
+
+impl DatabaseStats {
+    /// Bytes a `VACUUM` could hand back to the filesystem.
+    pub fn reclaimable_bytes(&self) -> u64 {
+        (self.freelist_count.max(0) * self.page_size.max(0)) as u64
+    }
+}
+
+/// Size of the file at `path` plus its `-wal` sidecar, zero when absent.
+pub fn database_file_sizes(path: &Path) -> (u64, u64) {
+    let size = |p: &Path| std::fs::metadata(p).map(|m| m.len()).unwrap_or(0);
+    let mut wal = path.as_os_str().to_owned();
+    wal.push("-wal");
+    (size(path), size(Path::new(&wal)))
+}
+
+/// Collect row counts per table, page usage, and file sizes for the
+/// database at `path`.
+pub async fn database_stats(
+    pool: &SqlitePool,
+    path: &Path,
+) -> Result<DatabaseStats, ConnectionError> {
+    let pragma = |name: &'static str| async move {
+        sqlx::query_scalar::<_, i64>(&format!("PRAGMA {name}"))
+            .fetch_one(pool)
+            .await
+            .map_err(ConnectionError::MaintenanceFailed)
+    };
+    let page_size = pragma("page_size").await?;
+    let page_count = pragma("page_count").await?;
+    let freelist_count = pragma("freelist_count").await?;
+
+    let names: Vec<String> = sqlx::query_scalar(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(ConnectionError::MaintenanceFailed)?;
+
+    // `dbstat` is a compile-time option; fall back to row counts without it.
+    let sizes: Option<std::collections::HashMap<String, i64>> = sqlx::query_as(
+        "SELECT COALESCE(m.tbl_name, s.name), SUM(s.pgsize)
+         FROM dbstat s LEFT JOIN sqlite_master m ON m.name = s.name
+         GROUP BY 1",
+    )
+    .fetch_all(pool)
+    .await
+    .ok()
+    .map(|rows: Vec<(String, i64)>| rows.into_iter().collect());
+
+    let mut tables = Vec::with_capacity(names.len());
+    for name in names {
+        let rows: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM \"{}\"",
+            name.replace('"', "\"\"")
+        ))
+        .fetch_one(pool)
+        .await
+        .map_err(ConnectionError::MaintenanceFailed)?;
+        let bytes = sizes
+            .as_ref()
+            .map(|sizes| sizes.get(&name).copied().unwrap_or(0).max(0) as u64);
+        tables.push(TableStats { name, rows, bytes });
+    }
+    tables.sort_by(|a, b| b.bytes.cmp(&a.bytes).then(b.rows.cmp(&a.rows)));
+
+    let (file_size, wal_size) = database_file_sizes(path);
+    Ok(DatabaseStats {
+        file_size,
+        wal_size,
+        page_size,
+        page_count,
+        freelist_count,
+        tables,
+    })
+}
+
+/// Rebuild the database file to reclaim free pages, then truncate the WAL.
+///
+/// `VACUUM` holds the write lock for its whole run, so callers must make
+/// sure no long-lived process (the swarm daemon) is using the database.
+/// Use a single-connection pool: the truncating checkpoint cannot complete
+/// while another connection holds a read snapshot.
+pub async fn vacuum(pool: &SqlitePool) -> Result<(), ConnectionError> {
+    sqlx::query("VACUUM")
+        .execute(pool)
+        .await
+        .map_err(ConnectionError::MaintenanceFailed)?;
+    sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+        .execute(pool)
+        .await
+        .map_err(ConnectionError::MaintenanceFailed)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_stats_and_vacuum_reclaim_pruned_rows() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("abathur.db");
+        let pool = create_pool(
+            &format!("sqlite:{}", path.display()),
+            Some(PoolConfig {
+                max_connections: 1,
+                ..PoolConfig::default()
+            }),
+        )
+        .await
+        .unwrap();
+
+        sqlx::query("CREATE TABLE events (id INTEGER PRIMARY KEY, payload TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE tasks (id INTEGER PRIMARY KEY)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 500)
+             INSERT INTO events (payload) SELECT printf('%.1000c', 'x') FROM n",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO tasks DEFAULT VALUES")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let stats = database_stats(&pool, &path).await.unwrap();
+        assert_eq!(stats.tables[0].name, "events");
+        assert_eq!(stats.tables[0].rows, 500);
+        assert_eq!(stats.tables[1].rows, 1);
+        assert!(stats.file_size + stats.wal_size > 0);
+
+        sqlx::query("DELETE FROM events WHERE id > 10")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let pruned = database_stats(&pool, &path).await.unwrap();
+        assert_eq!(
+            pruned
+                .tables
+                .iter()
+                .find(|t| t.name == "events")
+                .unwrap()
+                .rows,
+            10
+        );
+        assert!(pruned.reclaimable_bytes() > 0);
+
+        vacuum(&pool).await.unwrap();
+        let vacuumed = database_stats(&pool, &path).await.unwrap();
+        assert_eq!(vacuumed.freelist_count, 0);
+        assert_eq!(vacuumed.wal_size, 0);
+        assert!(vacuumed.file_size < pruned.file_size);
+    }
+}
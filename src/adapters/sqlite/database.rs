@@ -0,0 +1,161 @@
+//! SQLite implementation of the backend-neutral `Database` trait.
+
+use async_trait::async_trait;
+use sqlx::sqlite::{SqliteArguments, SqliteRow};
+use sqlx::{Column, Row, SqlitePool, TypeInfo, ValueRef};
+
+use crate::adapters::database::{Database, DatabaseBackend, DbRow, DbValue};
+use crate::domain::errors::{DomainError, DomainResult};
+
+/// `Database` handle over a SQLite pool.
+///
+/// Shares the pool with the concrete `Sqlite*Repository` types, so rows
+/// written through either path are visible to the other.
+#[derive(Clone)]
+pub struct SqliteDatabase {
+    pool: SqlitePool,
+}
+
+impl SqliteDatabase {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// The underlying pool, for repositories that still use sqlx directly.
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+}
+
+fn bind_params<'q>(
+    sql: &'q str,
+    params: &'q [DbValue],
+) -> sqlx::query::Query<'q, sqlx::Sqlite, SqliteArguments<'q>> {
+    params
+        .iter()
+        .fold(sqlx::query(sql), |query, param| match param {
+            DbValue::Null => query.bind(None::<String>),
+            DbValue::Integer(i) => query.bind(*i),
+            DbValue::Real(f) => query.bind(*f),
+            DbValue::Text(s) => query.bind(s.as_str()),
+            DbValue::Blob(b) => query.bind(b.as_slice()),
+        })
+}
+
+fn row_to_db_row(row: &SqliteRow) -> DomainResult<DbRow> {
+    let mut columns = Vec::with_capacity(row.columns().len());
+    for column in row.columns() {
+        let i = column.ordinal();
+        let raw = row.try_get_raw(i)?;
+        let value = if raw.is_null() {
+            DbValue::Null
+        } else {
+            match raw.type_info().name() {
+                "INTEGER" | "BOOLEAN" => DbValue::Integer(row.try_get(i)?),
+                "REAL" => DbValue::Real(row.try_get(i)?),
+                "BLOB" => DbValue::Blob(row.try_get(i)?),
+                "TEXT" | "DATETIME" | "DATE" | "TIME" => DbValue::Text(row.try_get(i)?),
+                other => {
+                    return Err(DomainError::DatabaseError(format!(
+                        "unsupported SQLite type '{other}' in column '{}'",
+                        column.name()
+                    )));
+                }
+            }
+        };
+        columns.push((column.name().to_string(), value));
+    }
+    Ok(DbRow::new(columns))
+}
+
+#[async_trait]
+impl Database for SqliteDatabase {
+    fn backend(&self) -> DatabaseBackend {
+        DatabaseBackend::Sqlite
+    }
+
+    async fn execute(&self, sql: &str, params: &[DbValue]) -> DomainResult<u64> {
+        let result = bind_params(sql, params).execute(&self.pool).await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn fetch_all(&self, sql: &str, params: &[DbValue]) -> DomainResult<Vec<DbRow>> {
+        let rows = bind_params(sql, params).fetch_all(&self.pool).await?;
+        rows.iter().map(row_to_db_row).collect()
+    }
+
+    async fn fetch_optional(&self, sql: &str, params: &[DbValue]) -> DomainResult<Option<DbRow>> {
+        let row = bind_params(sql, params).fetch_optional(&self.pool).await?;
+        row.as_ref().map(row_to_db_row).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::test_support::setup_pool;
+    use crate::adapters::sqlite::{SqliteQuietWindowRepository, SqliteTaskRepository};
+    use crate::domain::models::Task;
+    use crate::domain::models::quiet_window::QuietWindow;
+    use crate::domain::ports::TaskRepository;
+    use crate::domain::ports::quiet_window_repository::QuietWindowRepository;
+
+    #[tokio::test]
+    async fn test_sqlite_repos_round_trip_through_database_seam() {
+        let pool = setup_pool().await;
+        let db: Box<dyn Database> = Box::new(SqliteDatabase::new(pool.clone()));
+        assert_eq!(db.backend(), DatabaseBackend::Sqlite);
+
+        // Written by the concrete repository, read through the seam.
+        let task_repo = SqliteTaskRepository::new(pool.clone());
+        let task = Task::new("Seam task");
+        task_repo.create(&task).await.unwrap();
+
+        let row = db
+            .fetch_optional(
+                "SELECT id, title, status, retry_count, created_at, started_at FROM tasks WHERE id = ?",
+                &[task.id.into()],
+            )
+            .await
+            .unwrap()
+            .expect("task row");
+        assert_eq!(row.uuid("id").unwrap(), task.id);
+        assert_eq!(row.text("title").unwrap(), task.title);
+        assert_eq!(row.text("status").unwrap(), task.status.as_str());
+        assert_eq!(row.int("retry_count").unwrap(), task.retry_count as i64);
+        assert_eq!(
+            row.datetime("created_at").unwrap().timestamp(),
+            task.created_at.timestamp()
+        );
+        assert_eq!(row.opt_datetime("started_at").unwrap(), None);
+
+        // Written through the seam, read by the concrete repository.
+        let window = QuietWindow::new("nightly", "", "0 22 * * *", "0 6 * * *", "UTC");
+        let affected = db
+            .execute(
+                "INSERT INTO quiet_windows (id, name, description, start_cron, end_cron, timezone, status, created_at, updated_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                &[
+                    window.id.into(),
+                    window.name.as_str().into(),
+                    window.description.as_str().into(),
+                    window.start_cron.as_str().into(),
+                    window.end_cron.as_str().into(),
+                    window.timezone.as_str().into(),
+                    window.status.as_str().into(),
+                    window.created_at.into(),
+                    window.updated_at.into(),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(affected, 1);
+
+        let window_repo = SqliteQuietWindowRepository::new(pool);
+        let loaded = window_repo.get(window.id).await.unwrap().expect("window");
+        assert_eq!(loaded.name, window.name);
+        assert_eq!(loaded.start_cron, window.start_cron);
+        assert_eq!(loaded.status, window.status);
+        assert_eq!(loaded.created_at.timestamp(), window.created_at.timestamp());
+    }
+}
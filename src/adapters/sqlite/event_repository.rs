@@ -11,7 +11,7 @@ use crate::services::event_bus::{
 };
 use crate::services::event_store::{
     CircuitBreakerRecord, DeadLetterEntry, EventQuery, EventStore, EventStoreError,
-    EventStoreStats, WebhookSubscription,
+    EventStoreStats, EventVolume, WebhookSubscription,
 };
 
 /// SQLite-backed event repository.
@@ -231,6 +231,48 @@ impl EventStore for SqliteEventRepository {
         Ok(result.0 as u64)
     }
 
+    async fn volume_since(
+        &self,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<EventVolume, EventStoreError> {
+        // Counted in SQL; the payload's serde tag is its variant name.
+        let rows: Vec<(String, Option<String>, i64)> = sqlx::query_as(
+            "SELECT category, json_extract(payload, '$.type'), COUNT(*) FROM events
+             WHERE ? IS NULL OR timestamp >= ?
+             GROUP BY 1, 2",
+        )
+        .bind(since.map(|t| t.to_rfc3339()))
+        .bind(since.map(|t| t.to_rfc3339()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::QueryError(e.to_string()))?;
+
+        let mut total_events = 0;
+        let mut by_category: Vec<(EventCategory, u64)> = Vec::new();
+        let mut by_payload_type = std::collections::BTreeMap::new();
+        for (category, payload_type, count) in rows {
+            let count = count as u64;
+            total_events += count;
+            let category = Self::string_to_category(&category);
+            match by_category.iter_mut().find(|(c, _)| *c == category) {
+                Some((_, n)) => *n += count,
+                None => by_category.push((category, count)),
+            }
+            *by_payload_type
+                .entry(payload_type.unwrap_or_else(|| "unknown".to_string()))
+                .or_insert(0) += count;
+        }
+        by_category.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        let mut by_payload_type: Vec<_> = by_payload_type.into_iter().collect();
+        by_payload_type.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+
+        Ok(EventVolume {
+            total_events,
+            by_category,
+            by_payload_type,
+        })
+    }
+
     async fn prune_older_than(&self, duration: Duration) -> Result<u64, EventStoreError> {
         let cutoff = Utc::now() - chrono::Duration::from_std(duration).unwrap_or_default();
         let cutoff_str = cutoff.to_rfc3339();
@@ -256,6 +298,16 @@ impl EventStore for SqliteEventRepository {
         Ok(result.and_then(|(maybe_seq,)| maybe_seq.map(|seq| SequenceNumber(seq as u64))))
     }
 
+    async fn watermark_handler_names(&self) -> Result<Vec<String>, EventStoreError> {
+        let rows: Vec<(String,)> =
+            sqlx::query_as("SELECT handler_name FROM handler_watermarks ORDER BY handler_name")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| EventStoreError::QueryError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|(name,)| name).collect())
+    }
+
     async fn get_watermark(
         &self,
         handler_name: &str,
@@ -996,6 +1048,39 @@ mod tests {
         assert_eq!(all.len(), 3);
     }
 
+    #[tokio::test]
+    async fn test_sqlite_volume_since_counts_in_sql() {
+        let pool = setup_test_db().await;
+        let store = SqliteEventRepository::new(pool, None);
+
+        let mut old = make_test_event(0);
+        old.timestamp = Utc::now() - chrono::Duration::hours(2);
+        store.append(&old).await.unwrap();
+        store.append(&make_test_event(1)).await.unwrap();
+        let mut stopped = make_test_event(2);
+        stopped.category = EventCategory::Orchestrator;
+        stopped.payload = EventPayload::OrchestratorStopped;
+        store.append(&stopped).await.unwrap();
+
+        let all = store.volume_since(None).await.unwrap();
+        assert_eq!(all.total_events, 3);
+        assert_eq!(
+            all.by_category,
+            vec![(EventCategory::Task, 2), (EventCategory::Orchestrator, 1)]
+        );
+        assert_eq!(
+            all.by_payload_type[0],
+            ("OrchestratorStarted".to_string(), 2)
+        );
+
+        let recent = store
+            .volume_since(Some(Utc::now() - chrono::Duration::hours(1)))
+            .await
+            .unwrap();
+        assert_eq!(recent.total_events, 2);
+        assert_eq!(recent.by_payload_type.len(), 2);
+    }
+
     #[tokio::test]
     async fn test_sqlite_event_store_count_and_latest() {
         let pool = setup_test_db().await;
@@ -1028,4 +1113,36 @@ mod tests {
             .unwrap();
         assert_eq!(since.len(), 5);
     }
+
+    #[tokio::test]
+    async fn test_sqlite_event_store_query_page() {
+        let pool = setup_test_db().await;
+        let store = SqliteEventRepository::new(pool, None);
+
+        for i in 0..25 {
+            store.append(&make_test_event(i)).await.unwrap();
+        }
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        let mut pages = 0;
+        loop {
+            let (page, next) = store
+                .query_page(EventQuery::new().ascending(), cursor, 10)
+                .await
+                .unwrap();
+            pages += 1;
+            seen.extend(page.iter().map(|e| e.sequence.0));
+            if pages == 1 {
+                // Written mid-walk; must show up exactly once, at the end.
+                store.append(&make_test_event(25)).await.unwrap();
+            }
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        assert_eq!(pages, 3);
+        assert_eq!(seen, (0..26).collect::<Vec<_>>());
+    }
 }
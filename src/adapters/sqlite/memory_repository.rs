@@ -8,7 +8,7 @@ use uuid::Uuid;
 
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::models::{
-    AccessorId, Memory, MemoryMetadata, MemoryQuery, MemoryTier, MemoryType,
+    AccessorId, Memory, MemoryMetadata, MemoryQuery, MemoryTier, MemoryType, NamespaceSummary,
 };
 use crate::domain::ports::MemoryRepository;
 
@@ -31,8 +31,9 @@ impl MemoryRepository for SqliteMemoryRepository {
 
         let store_q = sqlx::query(
             r#"INSERT INTO memories (id, namespace, key, content, value, memory_type, tier, metadata,
-               access_count, version, created_at, updated_at, last_accessed_at, expires_at, distinct_accessors)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#
+               access_count, version, created_at, updated_at, last_accessed_at, expires_at, distinct_accessors,
+               embedding, embedding_model)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#
         )
         .bind(memory.id.to_string())
         .bind(&memory.namespace)
@@ -48,7 +49,9 @@ impl MemoryRepository for SqliteMemoryRepository {
         .bind(memory.updated_at.to_rfc3339())
         .bind(memory.last_accessed.to_rfc3339())
         .bind(memory.expires_at.map(|t| t.to_rfc3339()))
-        .bind(&accessors_json);
+        .bind(&accessors_json)
+        .bind(memory.embedding.as_deref().map(encode_embedding))
+        .bind(&memory.embedding_model);
         exec_tx!(&self.pool, store_q, execute)?;
 
         // Update FTS index
@@ -90,7 +93,7 @@ impl MemoryRepository for SqliteMemoryRepository {
             r#"UPDATE memories SET namespace = ?, key = ?, content = ?, value = ?,
                memory_type = ?, tier = ?, metadata = ?, access_count = ?,
                version = ?, updated_at = ?, last_accessed_at = ?, expires_at = ?,
-               distinct_accessors = ?
+               distinct_accessors = ?, embedding = ?, embedding_model = ?
                WHERE id = ?"#,
         )
         .bind(&memory.namespace)
@@ -106,6 +109,8 @@ impl MemoryRepository for SqliteMemoryRepository {
         .bind(memory.last_accessed.to_rfc3339())
         .bind(memory.expires_at.map(|t| t.to_rfc3339()))
         .bind(&accessors_json)
+        .bind(memory.embedding.as_deref().map(encode_embedding))
+        .bind(&memory.embedding_model)
         .bind(memory.id.to_string());
         let result = exec_tx!(&self.pool, update_q, execute)?;
 
@@ -353,6 +358,100 @@ impl MemoryRepository for SqliteMemoryRepository {
         }
         Ok(counts)
     }
+
+    async fn list_namespaces(&self) -> DomainResult<Vec<NamespaceSummary>> {
+        let rows: Vec<(String, i64, i64, i64, i64, i64, Option<String>)> = sqlx::query_as(
+            r#"SELECT namespace,
+                      COUNT(*),
+                      COALESCE(SUM(LENGTH(CAST(content AS BLOB))), 0),
+                      SUM(CASE WHEN tier = 'working' THEN 1 ELSE 0 END),
+                      SUM(CASE WHEN tier = 'episodic' THEN 1 ELSE 0 END),
+                      SUM(CASE WHEN tier = 'semantic' THEN 1 ELSE 0 END),
+                      MAX(updated_at)
+               FROM memories
+               GROUP BY namespace
+               ORDER BY namespace"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(
+                |(namespace, count, total_bytes, working, episodic, semantic, last_updated)| {
+                    Ok(NamespaceSummary {
+                        namespace,
+                        count: count as u64,
+                        total_bytes: total_bytes as u64,
+                        working: working as u64,
+                        episodic: episodic as u64,
+                        semantic: semantic as u64,
+                        last_updated: super::parse_optional_datetime(last_updated)?,
+                    })
+                },
+            )
+            .collect()
+    }
+
+    async fn count_stale_embeddings(&self, model: &str) -> DomainResult<u64> {
+        let (count,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM memories WHERE embedding_model IS NOT ?")
+                .bind(model)
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(count as u64)
+    }
+
+    async fn list_stale_embeddings(&self, model: &str, limit: usize) -> DomainResult<Vec<Memory>> {
+        let rows: Vec<MemoryRow> = sqlx::query_as(
+            "SELECT * FROM memories WHERE embedding_model IS NOT ? ORDER BY id LIMIT ?",
+        )
+        .bind(model)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    async fn store_embeddings(
+        &self,
+        model: &str,
+        embeddings: &[(Uuid, Vec<f32>)],
+    ) -> DomainResult<()> {
+        // One transaction per batch: a crash leaves every row either fully on
+        // the old model or fully on the new one, never a half-written vector.
+        let mut tx = self.pool.begin().await?;
+        for (id, vector) in embeddings {
+            sqlx::query("UPDATE memories SET embedding = ?, embedding_model = ? WHERE id = ?")
+                .bind(encode_embedding(vector))
+                .bind(model)
+                .bind(id.to_string())
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+/// Encode an embedding as little-endian `f32` bytes for the `embedding` column.
+fn encode_embedding(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+/// Inverse of [`encode_embedding`].
+fn decode_embedding(id: Uuid, bytes: &[u8]) -> DomainResult<Vec<f32>> {
+    if !bytes.len().is_multiple_of(4) {
+        return Err(DomainError::SerializationError(format!(
+            "embedding for memory {} is {} bytes, not a whole number of f32s",
+            id,
+            bytes.len()
+        )));
+    }
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect())
 }
 
 /// Sanitize a search query for use with SQLite FTS5.
@@ -367,7 +466,7 @@ impl MemoryRepository for SqliteMemoryRepository {
 ///
 /// Returns an empty string if the input is empty or whitespace-only, which the caller
 /// should treat as "no results" rather than issuing a MATCH query.
-fn sanitize_fts5_query(query: &str) -> String {
+pub(super) fn sanitize_fts5_query(query: &str) -> String {
     let terms: Vec<String> = query
         .split_whitespace()
         .map(|term| {
@@ -398,6 +497,8 @@ struct MemoryRow {
     expires_at: Option<String>,
     /// JSON array of AccessorId values, e.g. `[{"kind":"Task","id":"..."},...]`
     distinct_accessors: Option<String>,
+    embedding: Option<Vec<u8>>,
+    embedding_model: Option<String>,
 }
 
 impl TryFrom<MemoryRow> for Memory {
@@ -444,6 +545,12 @@ impl TryFrom<MemoryRow> for Memory {
             None => std::collections::HashSet::new(),
         };
 
+        let embedding = row
+            .embedding
+            .as_deref()
+            .map(|bytes| decode_embedding(id, bytes))
+            .transpose()?;
+
         Ok(Memory {
             id,
             key: row.key,
@@ -458,7 +565,8 @@ impl TryFrom<MemoryRow> for Memory {
             updated_at,
             expires_at,
             version: row.version as u64,
-            embedding: None,
+            embedding,
+            embedding_model: row.embedding_model,
             distinct_accessors,
         })
     }
@@ -534,6 +642,40 @@ mod tests {
         assert_eq!(*counts.get(&MemoryTier::Semantic).unwrap_or(&0), 1);
     }
 
+    #[tokio::test]
+    async fn test_list_namespaces_aggregates_per_namespace() {
+        let repo = setup_test_repo().await;
+
+        let w1 = Memory::working("w1", "12345").with_namespace("alpha");
+        let e1 = Memory::episodic("e1", "1234567890").with_namespace("alpha");
+        let mut s1 = Memory::semantic("s1", "héllo").with_namespace("alpha");
+        s1.updated_at = chrono::Utc::now() + chrono::Duration::hours(1);
+        let b1 = Memory::working("b1", "abc").with_namespace("beta");
+        for m in [&w1, &e1, &s1, &b1] {
+            repo.store(m).await.unwrap();
+        }
+
+        let namespaces = repo.list_namespaces().await.unwrap();
+        assert_eq!(namespaces.len(), 2);
+
+        let alpha = &namespaces[0];
+        assert_eq!(alpha.namespace, "alpha");
+        assert_eq!(alpha.count, 3);
+        // Sizes are in bytes: "héllo" is 6 bytes in UTF-8.
+        assert_eq!(alpha.total_bytes, 5 + 10 + 6);
+        assert_eq!((alpha.working, alpha.episodic, alpha.semantic), (1, 1, 1));
+        assert_eq!(
+            alpha.last_updated.map(|t| t.timestamp()),
+            Some(s1.updated_at.timestamp())
+        );
+
+        let beta = &namespaces[1];
+        assert_eq!(beta.namespace, "beta");
+        assert_eq!(beta.count, 1);
+        assert_eq!(beta.total_bytes, 3);
+        assert_eq!((beta.working, beta.episodic, beta.semantic), (1, 0, 0));
+    }
+
     // ---- sanitize_fts5_query unit tests ----
 
     #[test]
@@ -757,4 +899,28 @@ mod tests {
             "mixed reserved + normal term search should not crash"
         );
     }
+
+    #[tokio::test]
+    async fn test_store_embeddings_stamps_model_and_clears_staleness() {
+        let repo = setup_test_repo().await;
+        let a = Memory::working("a", "alpha").with_namespace("test");
+        let b = Memory::working("b", "beta").with_namespace("test");
+        repo.store(&a).await.unwrap();
+        repo.store(&b).await.unwrap();
+        assert_eq!(repo.count_stale_embeddings("m@2").await.unwrap(), 2);
+
+        repo.store_embeddings("m@2", &[(a.id, vec![0.5, -1.25])])
+            .await
+            .unwrap();
+
+        let loaded = repo.get(a.id).await.unwrap().unwrap();
+        assert_eq!(loaded.embedding, Some(vec![0.5, -1.25]));
+        assert_eq!(loaded.embedding_model.as_deref(), Some("m@2"));
+
+        let stale = repo.list_stale_embeddings("m@2", 10).await.unwrap();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].id, b.id);
+        // Under a different model everything is stale again.
+        assert_eq!(repo.count_stale_embeddings("other@2").await.unwrap(), 2);
+    }
 }
@@ -22,6 +22,48 @@ pub struct Migration {
     pub sql: String,
 }
 
+/// A migration recorded in `schema_migrations`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub description: Option<String>,
+    pub applied_at: String,
+}
+
+/// A migration that [`Migrator::run_embedded_migrations`] would apply.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct PendingMigration {
+    pub version: i64,
+    pub description: String,
+}
+
+/// Applied and pending migrations of a database, both ordered by version.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct MigrationStatus {
+    pub current_version: i64,
+    pub applied: Vec<AppliedMigration>,
+    pub pending: Vec<PendingMigration>,
+}
+
+impl MigrationStatus {
+    /// Status of a database with no migrations applied yet.
+    pub fn unmigrated(migrations: &[Migration]) -> Self {
+        let mut pending: Vec<PendingMigration> = migrations
+            .iter()
+            .map(|m| PendingMigration {
+                version: m.version,
+                description: m.description.clone(),
+            })
+            .collect();
+        pending.sort_by_key(|m| m.version);
+        Self {
+            current_version: 0,
+            applied: Vec::new(),
+            pending,
+        }
+    }
+}
+
 pub struct Migrator {
     pool: SqlitePool,
 }
@@ -53,6 +95,50 @@ impl Migrator {
         Ok(pending.len())
     }
 
+    /// Report which of `migrations` are applied and which would run.
+    ///
+    /// Read-only: a database without a `schema_migrations` table is reported
+    /// as version 0 with every migration pending.
+    pub async fn status(
+        &self,
+        migrations: &[Migration],
+    ) -> Result<MigrationStatus, MigrationError> {
+        let applied = if self.has_migrations_table().await? {
+            sqlx::query_as::<_, (i64, Option<String>, String)>(
+                "SELECT version, description, applied_at FROM schema_migrations ORDER BY version",
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(MigrationError::VersionCheckError)?
+            .into_iter()
+            .map(|(version, description, applied_at)| AppliedMigration {
+                version,
+                description,
+                applied_at,
+            })
+            .collect()
+        } else {
+            Vec::new()
+        };
+
+        let current_version = applied.iter().map(|m| m.version).max().unwrap_or(0);
+        let mut status = MigrationStatus::unmigrated(migrations);
+        status.pending.retain(|m| m.version > current_version);
+        status.current_version = current_version;
+        status.applied = applied;
+        Ok(status)
+    }
+
+    async fn has_migrations_table(&self) -> Result<bool, MigrationError> {
+        let row: Option<(String,)> = sqlx::query_as(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'schema_migrations'",
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(MigrationError::VersionCheckError)?;
+        Ok(row.is_some())
+    }
+
     async fn ensure_migrations_table(&self) -> Result<(), MigrationError> {
         sqlx::query(
             "CREATE TABLE IF NOT EXISTS schema_migrations (
@@ -174,5 +260,102 @@ pub fn all_embedded_migrations() -> Vec<Migration> {
             description: "Quiet windows for cost-control scheduling".to_string(),
             sql: include_str!("../../../migrations/014_quiet_windows.sql").to_string(),
         },
+        Migration {
+            version: 15,
+            description: "Task effort estimates and recorded durations".to_string(),
+            sql: include_str!("../../../migrations/015_task_estimates.sql").to_string(),
+        },
+        Migration {
+            version: 16,
+            description: "Task model escalation ladder position".to_string(),
+            sql: include_str!("../../../migrations/016_task_model_ladder.sql").to_string(),
+        },
+        Migration {
+            version: 17,
+            description: "Task substrate parameter overrides".to_string(),
+            sql: include_str!("../../../migrations/017_task_execution_params.sql").to_string(),
+        },
+        Migration {
+            version: 18,
+            description: "Task rerun lineage".to_string(),
+            sql: include_str!("../../../migrations/018_task_supersedes.sql").to_string(),
+        },
+        Migration {
+            version: 19,
+            description: "Task verification gate overseers".to_string(),
+            sql: include_str!("../../../migrations/019_task_required_overseers.sql").to_string(),
+        },
+        Migration {
+            version: 20,
+            description: "Leader election leases".to_string(),
+            sql: include_str!("../../../migrations/020_leader_leases.sql").to_string(),
+        },
+        Migration {
+            version: 21,
+            description: "Swarm stats baselines".to_string(),
+            sql: include_str!("../../../migrations/021_stat_baselines.sql").to_string(),
+        },
+        Migration {
+            version: 22,
+            description: "Persisted audit log entries".to_string(),
+            sql: include_str!("../../../migrations/022_audit_entries.sql").to_string(),
+        },
+        Migration {
+            version: 23,
+            description: "Swarm control flags".to_string(),
+            sql: include_str!("../../../migrations/023_swarm_control.sql").to_string(),
+        },
+        Migration {
+            version: 24,
+            description: "Memory embeddings".to_string(),
+            sql: include_str!("../../../migrations/024_memory_embeddings.sql").to_string(),
+        },
+        Migration {
+            version: 25,
+            description: "Full-text search over tasks".to_string(),
+            sql: include_str!("../../../migrations/025_task_search.sql").to_string(),
+        },
+        Migration {
+            version: 26,
+            description: "Agent template capabilities".to_string(),
+            sql: include_str!("../../../migrations/026_agent_capabilities.sql").to_string(),
+        },
+        Migration {
+            version: 27,
+            description: "Task workspace mode".to_string(),
+            sql: include_str!("../../../migrations/027_task_workspace_mode.sql").to_string(),
+        },
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::create_test_pool;
+
+    #[tokio::test]
+    async fn test_status_reports_pending_until_migrated() {
+        let migrator = Migrator::new(create_test_pool().await.unwrap());
+        let migrations = all_embedded_migrations();
+
+        let before = migrator.status(&migrations).await.unwrap();
+        assert_eq!(before.current_version, 0);
+        assert!(before.applied.is_empty());
+        assert_eq!(before.pending.len(), migrations.len());
+        assert_eq!(before.pending[0].version, 1);
+
+        let applied = migrator
+            .run_embedded_migrations(migrations.clone())
+            .await
+            .unwrap();
+        assert_eq!(applied, migrations.len());
+
+        let after = migrator.status(&migrations).await.unwrap();
+        assert!(after.pending.is_empty());
+        assert_eq!(after.applied.len(), migrations.len());
+        assert_eq!(
+            after.current_version,
+            migrations.last().map(|m| m.version).unwrap()
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! SQLite implementation of the StatsRepository port.
+//!
+//! Task counters come from the `tasks` table and token usage from the agent
+//! runs recorded in `template_executions`.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::models::{StatBaseline, SwarmCounters};
+use crate::domain::ports::StatsRepository;
+
+/// SQLite-backed swarm stats repository.
+#[derive(Clone)]
+pub struct SqliteStatsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteStatsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Count tasks that reached a terminal state, and agent runs recorded, at
+    /// or after `since` (or ever, when `None`).
+    ///
+    /// Timestamps are stored as RFC 3339 UTC strings, so they compare
+    /// lexically.
+    async fn count_activity(&self, since: Option<DateTime<Utc>>) -> DomainResult<SwarmCounters> {
+        let since = since.map(|t| t.to_rfc3339());
+
+        let (tasks_completed, tasks_failed): (i64, i64) = sqlx::query_as(
+            r#"SELECT COUNT(CASE WHEN status = 'complete' THEN 1 END),
+                      COUNT(CASE WHEN status = 'failed' THEN 1 END)
+               FROM tasks
+               WHERE ?1 IS NULL OR COALESCE(completed_at, updated_at) >= ?1"#,
+        )
+        .bind(&since)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let (executions, tokens_used): (i64, i64) = sqlx::query_as(
+            r#"SELECT COUNT(*), COALESCE(SUM(tokens_used), 0)
+               FROM template_executions
+               WHERE ?1 IS NULL OR executed_at >= ?1"#,
+        )
+        .bind(&since)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(SwarmCounters {
+            tasks_completed: tasks_completed as u64,
+            tasks_failed: tasks_failed as u64,
+            executions: executions as u64,
+            tokens_used: tokens_used.max(0) as u64,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct BaselineRow {
+    reset_at: String,
+    tasks_completed: i64,
+    tasks_failed: i64,
+    executions: i64,
+    tokens_used: i64,
+}
+
+impl TryFrom<BaselineRow> for StatBaseline {
+    type Error = DomainError;
+
+    fn try_from(row: BaselineRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            reset_at: super::parse_datetime(&row.reset_at)?,
+            counters: SwarmCounters {
+                tasks_completed: row.tasks_completed.max(0) as u64,
+                tasks_failed: row.tasks_failed.max(0) as u64,
+                executions: row.executions.max(0) as u64,
+                tokens_used: row.tokens_used.max(0) as u64,
+            },
+        })
+    }
+}
+
+#[async_trait]
+impl StatsRepository for SqliteStatsRepository {
+    async fn counters(&self) -> DomainResult<SwarmCounters> {
+        self.count_activity(None).await
+    }
+
+    async fn counters_since(&self, since: DateTime<Utc>) -> DomainResult<SwarmCounters> {
+        self.count_activity(Some(since)).await
+    }
+
+    async fn latest_baseline(&self) -> DomainResult<Option<StatBaseline>> {
+        let row: Option<BaselineRow> = sqlx::query_as(
+            r#"SELECT reset_at, tasks_completed, tasks_failed, executions, tokens_used
+               FROM stat_baselines ORDER BY id DESC LIMIT 1"#,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(StatBaseline::try_from).transpose()
+    }
+
+    async fn reset_baseline(&self) -> DomainResult<StatBaseline> {
+        let baseline = StatBaseline {
+            reset_at: Utc::now(),
+            counters: self.counters().await?,
+        };
+
+        sqlx::query(
+            r#"INSERT INTO stat_baselines
+               (reset_at, tasks_completed, tasks_failed, executions, tokens_used)
+               VALUES (?, ?, ?, ?, ?)"#,
+        )
+        .bind(baseline.reset_at.to_rfc3339())
+        .bind(baseline.counters.tasks_completed as i64)
+        .bind(baseline.counters.tasks_failed as i64)
+        .bind(baseline.counters.executions as i64)
+        .bind(baseline.counters.tokens_used as i64)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(baseline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::create_migrated_test_pool;
+
+    /// Record a task that ended in `status` now, plus the agent run behind it.
+    async fn record_run(pool: &SqlitePool, status: &str, tokens: i64) -> String {
+        let task_id = uuid::Uuid::new_v4().to_string();
+        let now = Utc::now().to_rfc3339();
+        sqlx::query(
+            r#"INSERT INTO tasks (id, title, status, updated_at, completed_at)
+               VALUES (?, 'test task', ?, ?, ?)"#,
+        )
+        .bind(&task_id)
+        .bind(status)
+        .bind(&now)
+        .bind(&now)
+        .execute(pool)
+        .await
+        .unwrap();
+        record_execution(pool, &task_id, tokens).await;
+        task_id
+    }
+
+    async fn record_execution(pool: &SqlitePool, task_id: &str, tokens: i64) {
+        sqlx::query(
+            r#"INSERT INTO template_executions
+               (id, task_id, template_name, template_version, outcome,
+                executed_at, turns_used, tokens_used)
+               VALUES (?, ?, 'coder', 1, 'success', ?, 3, ?)"#,
+        )
+        .bind(uuid::Uuid::new_v4().to_string())
+        .bind(task_id)
+        .bind(Utc::now().to_rfc3339())
+        .bind(tokens)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_session_after_reset_counts_only_later_activity() {
+        let pool = create_migrated_test_pool().await.unwrap();
+        let repo = SqliteStatsRepository::new(pool.clone());
+        assert_eq!(repo.latest_baseline().await.unwrap(), None);
+
+        record_run(&pool, "complete", 1000).await;
+        let retried = record_run(&pool, "failed", 500).await;
+
+        let baseline = repo.reset_baseline().await.unwrap();
+        assert_eq!(repo.latest_baseline().await.unwrap(), Some(baseline.clone()));
+
+        // The task that failed before the reset is retried and completes, and
+        // a fresh task fails. Subtracting status totals would report no
+        // failures this session.
+        let now = Utc::now().to_rfc3339();
+        sqlx::query(
+            "UPDATE tasks SET status = 'complete', updated_at = ?, completed_at = ? WHERE id = ?",
+        )
+        .bind(&now)
+        .bind(&now)
+        .bind(&retried)
+        .execute(&pool)
+        .await
+        .unwrap();
+        record_execution(&pool, &retried, 200).await;
+        record_run(&pool, "failed", 50).await;
+
+        let all_time = repo.counters().await.unwrap();
+        assert_eq!(all_time.tokens_used, 1750);
+        assert_eq!(
+            repo.counters_since(baseline.reset_at).await.unwrap(),
+            SwarmCounters {
+                tasks_completed: 1,
+                tasks_failed: 1,
+                executions: 2,
+                tokens_used: 250,
+            }
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! SQLite implementation of the SwarmControlRepository port.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::ports::SwarmControlRepository;
+
+/// SQLite-backed swarm control repository.
+#[derive(Clone)]
+pub struct SqliteSwarmControlRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSwarmControlRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SwarmControlRepository for SqliteSwarmControlRepository {
+    async fn is_paused(&self) -> DomainResult<bool> {
+        let paused: Option<i64> =
+            sqlx::query_scalar("SELECT paused FROM swarm_control WHERE id = 1")
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(paused.unwrap_or(0) != 0)
+    }
+
+    async fn set_paused(&self, paused: bool) -> DomainResult<()> {
+        sqlx::query(
+            r#"INSERT INTO swarm_control (id, paused, updated_at) VALUES (1, ?, datetime('now'))
+               ON CONFLICT(id) DO UPDATE SET
+                   paused = excluded.paused,
+                   updated_at = excluded.updated_at"#,
+        )
+        .bind(paused)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::create_migrated_test_pool;
+
+    #[tokio::test]
+    async fn test_pause_flag_round_trips() {
+        let repo = SqliteSwarmControlRepository::new(create_migrated_test_pool().await.unwrap());
+
+        assert!(!repo.is_paused().await.unwrap());
+        repo.set_paused(true).await.unwrap();
+        assert!(repo.is_paused().await.unwrap());
+        // Setting the same value again is harmless.
+        repo.set_paused(true).await.unwrap();
+        assert!(repo.is_paused().await.unwrap());
+        repo.set_paused(false).await.unwrap();
+        assert!(!repo.is_paused().await.unwrap());
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::exec_tx;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 /// Emit a warning when a serialized context JSON blob exceeds this size.
 /// This is a signal that the hints cap may not be functioning or that
@@ -9,12 +10,16 @@ use async_trait::async_trait;
 const JSON_SIZE_WARN_BYTES: usize = 64 * 1024;
 use sqlx::SqlitePool;
 use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use super::tx_context;
+
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::models::{
-    ArtifactRef, ExecutionMode, RoutingHints, Task, TaskContext, TaskPriority, TaskSource,
-    TaskStatus, TaskType,
+    ArtifactRef, ExecutionMode, ReadyTaskKey, RoutingHints, Task, TaskContext, TaskPriority,
+    TaskSource, TaskStatus, TaskType, TaskVelocity, WorkspaceMode,
 };
 use crate::domain::ports::{TaskFilter, TaskRepository};
 
@@ -44,13 +49,23 @@ impl TaskRepository for SqliteTaskRepository {
         }
         let (source_type, source_ref) = serialize_task_source(&task.source);
         let execution_mode_json = serde_json::to_string(&task.execution_mode)?;
+        let execution_params_json = task
+            .execution_params
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+        let required_overseers_json = (!task.required_overseers.is_empty())
+            .then(|| serde_json::to_string(&task.required_overseers))
+            .transpose()?;
 
         let create_q = sqlx::query(
             r#"INSERT INTO tasks (id, parent_id, title, description, status, priority,
                agent_type, routing, artifacts, context, retry_count, max_retries, worktree_path,
                idempotency_key, source_type, source_ref, version, created_at, updated_at, started_at, completed_at, deadline,
-               execution_mode, trajectory_id, task_type)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#
+               execution_mode, trajectory_id, task_type, estimate_secs, duration_secs,
+               model_ladder_position, execution_params, supersedes, required_overseers,
+               workspace_mode)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#
         )
         .bind(task.id.to_string())
         .bind(task.parent_id.map(|id| id.to_string()))
@@ -76,7 +91,14 @@ impl TaskRepository for SqliteTaskRepository {
         .bind(task.deadline.map(|t| t.to_rfc3339()))
         .bind(&execution_mode_json)
         .bind(task.trajectory_id.map(|id| id.to_string()))
-        .bind(task.task_type.as_str());
+        .bind(task.task_type.as_str())
+        .bind(task.estimate_secs.map(|s| s as i64))
+        .bind(task.duration_secs.map(|s| s as i64))
+        .bind(task.model_ladder_position.map(|p| p as i64))
+        .bind(&execution_params_json)
+        .bind(task.supersedes.map(|id| id.to_string()))
+        .bind(&required_overseers_json)
+        .bind(task.workspace_mode.as_str());
         exec_tx!(&self.pool, create_q, execute)?;
 
         // Add dependencies
@@ -87,6 +109,40 @@ impl TaskRepository for SqliteTaskRepository {
         Ok(())
     }
 
+    async fn bulk_insert(&self, tasks: &[Task]) -> DomainResult<()> {
+        let insert_all = async {
+            // Parents and dependencies may appear later in the batch.
+            exec_tx!(
+                &self.pool,
+                sqlx::query("PRAGMA defer_foreign_keys = ON"),
+                execute
+            )?;
+            for task in tasks {
+                self.create(task).await?;
+            }
+            Ok::<(), DomainError>(())
+        };
+
+        // Join a caller's transaction if there is one.
+        if tx_context::try_get_tx().is_some() {
+            return insert_all.await;
+        }
+
+        let shared: tx_context::SharedTx = Arc::new(Mutex::new(self.pool.begin().await?));
+        let result = tx_context::run_in_tx_scope(shared.clone(), insert_all).await;
+        let tx = tx_context::take_inner_tx(shared).await.map_err(|_| {
+            DomainError::DatabaseError("bulk insert transaction still in use".to_string())
+        })?;
+        match result {
+            Ok(()) => tx.commit().await?,
+            Err(e) => {
+                tx.rollback().await?;
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
     async fn get(&self, id: Uuid) -> DomainResult<Option<Task>> {
         let get_q = sqlx::query_as("SELECT * FROM tasks WHERE id = ?").bind(id.to_string());
         let row: Option<TaskRow> = exec_tx!(&self.pool, get_q, fetch_optional)?;
@@ -114,6 +170,14 @@ impl TaskRepository for SqliteTaskRepository {
         }
         let (source_type, source_ref) = serialize_task_source(&task.source);
         let execution_mode_json = serde_json::to_string(&task.execution_mode)?;
+        let execution_params_json = task
+            .execution_params
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+        let required_overseers_json = (!task.required_overseers.is_empty())
+            .then(|| serde_json::to_string(&task.required_overseers))
+            .transpose()?;
 
         let update_q = sqlx::query(
             r#"UPDATE tasks SET parent_id = ?, title = ?, description = ?,
@@ -121,7 +185,10 @@ impl TaskRepository for SqliteTaskRepository {
                context = ?, retry_count = ?, max_retries = ?, worktree_path = ?,
                source_type = ?, source_ref = ?,
                version = ?, updated_at = ?, started_at = ?, completed_at = ?, deadline = ?,
-               execution_mode = ?, trajectory_id = ?, task_type = ?
+               execution_mode = ?, trajectory_id = ?, task_type = ?,
+               estimate_secs = ?, duration_secs = ?, model_ladder_position = ?,
+               execution_params = ?, supersedes = ?, required_overseers = ?,
+               workspace_mode = ?
                WHERE id = ? AND version = ?"#,
         )
         .bind(task.parent_id.map(|id| id.to_string()))
@@ -146,6 +213,13 @@ impl TaskRepository for SqliteTaskRepository {
         .bind(&execution_mode_json)
         .bind(task.trajectory_id.map(|id| id.to_string()))
         .bind(task.task_type.as_str())
+        .bind(task.estimate_secs.map(|s| s as i64))
+        .bind(task.duration_secs.map(|s| s as i64))
+        .bind(task.model_ladder_position.map(|p| p as i64))
+        .bind(&execution_params_json)
+        .bind(task.supersedes.map(|id| id.to_string()))
+        .bind(&required_overseers_json)
+        .bind(task.workspace_mode.as_str())
         .bind(task.id.to_string())
         .bind(task.loaded_version.get() as i64);
         let result = exec_tx!(&self.pool, update_q, execute)?;
@@ -189,34 +263,69 @@ impl TaskRepository for SqliteTaskRepository {
     async fn list(&self, filter: TaskFilter) -> DomainResult<Vec<Task>> {
         let mut query = String::from("SELECT * FROM tasks WHERE 1=1");
         let mut bindings: Vec<String> = Vec::new();
+        push_filter_clauses(&filter, &mut query, &mut bindings);
 
-        if let Some(status) = &filter.status {
-            query.push_str(" AND status = ?");
-            bindings.push(status.as_str().to_string());
-        }
-        if let Some(priority) = &filter.priority {
-            query.push_str(" AND priority = ?");
-            bindings.push(priority.as_str().to_string());
-        }
-        if let Some(parent_id) = &filter.parent_id {
-            query.push_str(" AND parent_id = ?");
-            bindings.push(parent_id.to_string());
+        query.push_str(" ORDER BY created_at DESC");
+
+        if let Some(n) = filter.limit {
+            query.push_str(&format!(" LIMIT {}", n));
         }
-        if let Some(agent_type) = &filter.agent_type {
-            query.push_str(" AND agent_type = ?");
-            bindings.push(agent_type.clone());
+
+        let mut q = sqlx::query_as::<_, TaskRow>(&query);
+        for binding in &bindings {
+            q = q.bind(binding);
         }
-        if let Some(task_type) = &filter.task_type {
-            query.push_str(" AND task_type = ?");
-            bindings.push(task_type.as_str().to_string());
+
+        let rows: Vec<TaskRow> = q.fetch_all(&self.pool).await?;
+        let mut tasks: Vec<Task> = super::rows_into_lossy(rows, "tasks.list");
+        for task in tasks.iter_mut() {
+            self.load_dependencies(task).await?;
         }
-        if let Some(created_before) = &filter.created_before {
-            query.push_str(" AND created_at < ?");
-            bindings.push(created_before.to_rfc3339());
+        Ok(tasks)
+    }
+
+    async fn search_by_text(&self, query: &str, filter: TaskFilter) -> DomainResult<Vec<Task>> {
+        let terms: Vec<&str> = query.split_whitespace().collect();
+        if terms.is_empty() {
+            return Ok(Vec::new());
         }
 
-        query.push_str(" ORDER BY created_at DESC");
+        let mut bindings: Vec<String> = Vec::new();
+        // Ranking bindings come after the filter bindings in the statement.
+        let mut rank_bindings: Vec<String> = Vec::new();
+        let (mut query, order_by) = if self.has_fts_index().await? {
+            bindings.push(super::memory_repository::sanitize_fts5_query(query));
+            (
+                String::from(
+                    "SELECT tasks.* FROM tasks_fts JOIN tasks ON tasks.id = tasks_fts.task_id \
+                     WHERE tasks_fts MATCH ?",
+                ),
+                // Title hits outweigh description hits.
+                String::from(" ORDER BY bm25(tasks_fts, 0.0, 4.0, 1.0), created_at DESC"),
+            )
+        } else {
+            // Without FTS5 every term must appear in the title or description,
+            // and tasks with more of the terms in their title rank first.
+            let mut query = String::from("SELECT * FROM tasks WHERE 1=1");
+            for term in &terms {
+                let pattern = like_pattern(term);
+                query.push_str(
+                    " AND (title LIKE ? ESCAPE '\\' OR COALESCE(description, '') LIKE ? ESCAPE '\\')",
+                );
+                bindings.push(pattern.clone());
+                bindings.push(pattern.clone());
+                rank_bindings.push(pattern);
+            }
+            let title_hits = vec!["(title LIKE ? ESCAPE '\\')"; terms.len()].join(" + ");
+            (
+                query,
+                format!(" ORDER BY {title_hits} DESC, created_at DESC"),
+            )
+        };
 
+        push_filter_clauses(&filter, &mut query, &mut bindings);
+        query.push_str(&order_by);
+        bindings.extend(rank_bindings);
         if let Some(n) = filter.limit {
             query.push_str(&format!(" LIMIT {}", n));
         }
@@ -227,7 +336,7 @@ impl TaskRepository for SqliteTaskRepository {
         }
 
         let rows: Vec<TaskRow> = q.fetch_all(&self.pool).await?;
-        let mut tasks: Vec<Task> = super::rows_into_lossy(rows, "tasks.list");
+        let mut tasks: Vec<Task> = super::rows_into_lossy(rows, "tasks.search_by_text");
         for task in tasks.iter_mut() {
             self.load_dependencies(task).await?;
         }
@@ -272,6 +381,32 @@ impl TaskRepository for SqliteTaskRepository {
         Ok(tasks)
     }
 
+    async fn ready_task_keys(&self) -> DomainResult<Vec<ReadyTaskKey>> {
+        let rows: Vec<(String, String, String)> = sqlx::query_as(
+            r#"SELECT id, priority, created_at FROM tasks WHERE status = 'ready'
+               ORDER BY CASE priority
+                   WHEN 'critical' THEN 1
+                   WHEN 'high' THEN 2
+                   WHEN 'normal' THEN 3
+                   WHEN 'low' THEN 4
+               END, created_at"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|(id, priority, created_at)| {
+                Ok(ReadyTaskKey {
+                    id: super::parse_uuid(&id)?,
+                    priority: TaskPriority::parse(&priority).ok_or_else(|| {
+                        DomainError::SerializationError(format!("Invalid priority: {}", priority))
+                    })?,
+                    created_at: super::parse_datetime(&created_at)?,
+                })
+            })
+            .collect()
+    }
+
     async fn get_by_agent(&self, agent_type: &str) -> DomainResult<Vec<Task>> {
         self.list(TaskFilter {
             agent_type: Some(agent_type.to_string()),
@@ -367,6 +502,17 @@ impl TaskRepository for SqliteTaskRepository {
         }
     }
 
+    async fn count_by_idempotency_prefix(&self, prefix: &str) -> DomainResult<u64> {
+        // substr() comparison avoids LIKE wildcard escaping for arbitrary prefixes.
+        let count_q = sqlx::query_as(
+            "SELECT COUNT(*) FROM tasks WHERE substr(idempotency_key, 1, length(?)) = ?",
+        )
+        .bind(prefix)
+        .bind(prefix);
+        let (count,): (i64,) = exec_tx!(&self.pool, count_q, fetch_one)?;
+        Ok(count as u64)
+    }
+
     async fn list_by_source(&self, source_type: &str) -> DomainResult<Vec<Task>> {
         let rows: Vec<TaskRow> =
             sqlx::query_as("SELECT * FROM tasks WHERE source_type = ? ORDER BY created_at DESC")
@@ -398,6 +544,38 @@ impl TaskRepository for SqliteTaskRepository {
         Ok(counts)
     }
 
+    async fn velocity_since(&self, since: DateTime<Utc>) -> DomainResult<TaskVelocity> {
+        let velocity_q = sqlx::query_as(
+            r#"SELECT COUNT(*),
+                      COUNT(estimate_secs - duration_secs),
+                      AVG(duration_secs),
+                      AVG(CASE WHEN duration_secs IS NOT NULL THEN estimate_secs END),
+                      AVG(ABS(duration_secs - estimate_secs)),
+                      AVG(duration_secs - estimate_secs)
+               FROM tasks
+               WHERE status = 'complete' AND completed_at >= ?"#,
+        )
+        .bind(since.to_rfc3339());
+        let (completed, estimated, avg_actual, avg_estimate, mean_abs_error, mean_error): (
+            i64,
+            i64,
+            Option<f64>,
+            Option<f64>,
+            Option<f64>,
+            Option<f64>,
+        ) = exec_tx!(&self.pool, velocity_q, fetch_one)?;
+
+        Ok(TaskVelocity {
+            window_secs: (Utc::now() - since).num_seconds().max(0) as u64,
+            completed: completed as u64,
+            estimated: estimated as u64,
+            avg_actual_secs: avg_actual,
+            avg_estimate_secs: avg_estimate,
+            mean_abs_error_secs: mean_abs_error,
+            mean_error_secs: mean_error,
+        })
+    }
+
     async fn claim_task_atomic(
         &self,
         task_id: Uuid,
@@ -424,6 +602,50 @@ impl TaskRepository for SqliteTaskRepository {
         self.get(task_id).await
     }
 
+    async fn claim_next_ready(
+        &self,
+        candidates: &[Uuid],
+        agent_type: &str,
+    ) -> DomainResult<Option<Task>> {
+        if candidates.is_empty() {
+            return Ok(None);
+        }
+        // The subquery and the update run as one statement, so exactly one
+        // concurrent caller transitions the chosen row; `status = 'ready'` is
+        // repeated on the outer UPDATE as a belt-and-braces guard.
+        let placeholders = vec!["?"; candidates.len()].join(", ");
+        let rank = "WHEN ? THEN ? ".repeat(candidates.len());
+        let sql = format!(
+            r#"UPDATE tasks
+               SET status = 'running', agent_type = ?, version = version + 1,
+                   updated_at = ?, started_at = ?
+               WHERE status = 'ready' AND id = (
+                   SELECT id FROM tasks
+                   WHERE status = 'ready' AND id IN ({placeholders})
+                   ORDER BY CASE id {rank}END
+                   LIMIT 1
+               )
+               RETURNING id"#
+        );
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut query = sqlx::query_as::<_, (String,)>(&sql)
+            .bind(agent_type)
+            .bind(&now)
+            .bind(&now);
+        for id in candidates {
+            query = query.bind(id.to_string());
+        }
+        for (position, id) in candidates.iter().enumerate() {
+            query = query.bind(id.to_string()).bind(position as i64);
+        }
+        let Some((id,)) = query.fetch_optional(&self.pool).await? else {
+            return Ok(None);
+        };
+
+        self.get(super::parse_uuid(&id)?).await
+    }
+
     async fn get_parent_id(&self, task_id: Uuid) -> DomainResult<Option<Uuid>> {
         let row: Option<(Option<String>,)> =
             sqlx::query_as("SELECT parent_id FROM tasks WHERE id = ?")
@@ -494,7 +716,7 @@ impl SqliteTaskRepository {
     /// If `task_id` is reachable from `depends_on`, then the proposed edge would
     /// close a loop.
     async fn would_create_cycle(&self, task_id: Uuid, depends_on: Uuid) -> DomainResult<bool> {
-        let row: Option<(i64,)> = sqlx::query_as(
+        let cycle_q = sqlx::query_as(
             r#"WITH RECURSIVE reachable(id) AS (
                 SELECT ?1
                 UNION
@@ -505,10 +727,20 @@ impl SqliteTaskRepository {
             SELECT 1 FROM reachable WHERE id = ?2 LIMIT 1"#,
         )
         .bind(depends_on.to_string())
-        .bind(task_id.to_string())
+        .bind(task_id.to_string());
+        let row: Option<(i64,)> = exec_tx!(&self.pool, cycle_q, fetch_optional)?;
+
+        Ok(row.is_some())
+    }
+
+    /// Whether the `tasks_fts` full-text index exists. It is missing when
+    /// the linked SQLite was built without FTS5.
+    async fn has_fts_index(&self) -> DomainResult<bool> {
+        let row: Option<(i64,)> = sqlx::query_as(
+            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'tasks_fts'",
+        )
         .fetch_optional(&self.pool)
         .await?;
-
         Ok(row.is_some())
     }
 
@@ -554,6 +786,13 @@ struct TaskRow {
     execution_mode: Option<String>,
     trajectory_id: Option<String>,
     task_type: Option<String>,
+    estimate_secs: Option<i64>,
+    duration_secs: Option<i64>,
+    model_ladder_position: Option<i64>,
+    execution_params: Option<String>,
+    supersedes: Option<String>,
+    required_overseers: Option<String>,
+    workspace_mode: Option<String>,
 }
 
 impl TryFrom<TaskRow> for Task {
@@ -591,11 +830,26 @@ impl TryFrom<TaskRow> for Task {
             None => ExecutionMode::default(),
         };
         let trajectory_id = super::parse_optional_uuid(row.trajectory_id)?;
+        let supersedes = super::parse_optional_uuid(row.supersedes)?;
+        let required_overseers = super::parse_json_or_default(row.required_overseers)?;
         let task_type = row
             .task_type
             .as_deref()
             .and_then(TaskType::parse)
             .unwrap_or_default();
+        let workspace_mode = row
+            .workspace_mode
+            .as_deref()
+            .and_then(WorkspaceMode::parse)
+            .unwrap_or_default();
+        let execution_params = row
+            .execution_params
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| {
+                DomainError::SerializationError(format!("Invalid execution_params: {}", e))
+            })?;
 
         Ok(Task {
             id,
@@ -623,12 +877,74 @@ impl TryFrom<TaskRow> for Task {
             execution_mode,
             trajectory_id,
             task_type,
+            estimate_secs: row.estimate_secs.map(|s| s as u64),
+            duration_secs: row.duration_secs.map(|s| s as u64),
+            model_ladder_position: row.model_ladder_position.map(|p| p as u32),
+            execution_params,
+            supersedes,
+            required_overseers,
+            workspace_mode,
             loaded_version: crate::domain::models::VersionTag::new(row.version as u64),
         })
     }
 }
 
 /// Serialize a TaskSource into (source_type, source_ref) for DB storage.
+/// Append an ` AND ...` clause to `query` for each field set in `filter`,
+/// pushing the matching values onto `bindings`. `limit` is not applied.
+fn push_filter_clauses(filter: &TaskFilter, query: &mut String, bindings: &mut Vec<String>) {
+    if let Some(status) = &filter.status {
+        query.push_str(" AND status = ?");
+        bindings.push(status.as_str().to_string());
+    }
+    if let Some(priority) = &filter.priority {
+        query.push_str(" AND priority = ?");
+        bindings.push(priority.as_str().to_string());
+    }
+    if let Some((min, max)) = filter.priority_range {
+        let in_range: Vec<&str> = TaskPriority::ALL
+            .iter()
+            .filter(|p| (min..=max).contains(*p))
+            .map(|p| p.as_str())
+            .collect();
+        if in_range.is_empty() {
+            query.push_str(" AND 0");
+        } else {
+            query.push_str(&format!(
+                " AND priority IN ({})",
+                vec!["?"; in_range.len()].join(", ")
+            ));
+            bindings.extend(in_range.into_iter().map(String::from));
+        }
+    }
+    if let Some(parent_id) = &filter.parent_id {
+        query.push_str(" AND parent_id = ?");
+        bindings.push(parent_id.to_string());
+    }
+    if let Some(agent_type) = &filter.agent_type {
+        query.push_str(" AND agent_type = ?");
+        bindings.push(agent_type.clone());
+    }
+    if let Some(task_type) = &filter.task_type {
+        query.push_str(" AND task_type = ?");
+        bindings.push(task_type.as_str().to_string());
+    }
+    if let Some(created_before) = &filter.created_before {
+        query.push_str(" AND created_at < ?");
+        bindings.push(created_before.to_rfc3339());
+    }
+}
+
+/// A `LIKE` pattern matching `term` anywhere, with `%`, `_`, and the `\`
+/// escape character itself escaped.
+fn like_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
 fn serialize_task_source(source: &TaskSource) -> (String, Option<String>) {
     match source {
         TaskSource::Human => ("human".to_string(), None),
@@ -636,6 +952,7 @@ fn serialize_task_source(source: &TaskSource) -> (String, Option<String>) {
         TaskSource::SubtaskOf(uuid) => ("subtask".to_string(), Some(uuid.to_string())),
         TaskSource::Schedule(uuid) => ("schedule".to_string(), Some(uuid.to_string())),
         TaskSource::Adapter(name) => ("adapter".to_string(), Some(name.clone())),
+        TaskSource::Webhook { source_name } => ("webhook".to_string(), Some(source_name.clone())),
     }
 }
 
@@ -667,6 +984,10 @@ fn deserialize_task_source(
             let name = source_ref.unwrap_or("unknown").to_string();
             Ok(TaskSource::Adapter(name))
         }
+        Some("webhook") => {
+            let source_name = source_ref.unwrap_or("unknown").to_string();
+            Ok(TaskSource::Webhook { source_name })
+        }
         // Legacy: goal_evaluation rows in DB are treated as Human
         Some("goal_evaluation") => Ok(TaskSource::Human),
         Some(other) => Err(DomainError::SerializationError(format!(
@@ -698,6 +1019,52 @@ mod tests {
         assert_eq!(retrieved.unwrap().title, "Test Task");
     }
 
+    #[tokio::test]
+    async fn test_execution_params_round_trip() {
+        use crate::domain::models::ExecutionParameters;
+
+        let repo = setup_test_repo().await;
+        let params = ExecutionParameters {
+            max_turns: Some(80),
+            temperature: Some(0.2),
+            ..Default::default()
+        };
+        let task = Task::new("Long refactor").with_execution_params(params.clone());
+        repo.create(&task).await.unwrap();
+
+        let loaded = repo.get(task.id).await.unwrap().unwrap();
+        assert_eq!(loaded.execution_params, Some(params));
+
+        let plain = Task::new("Defaults");
+        repo.create(&plain).await.unwrap();
+        assert!(
+            repo.get(plain.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .execution_params
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_workspace_mode_round_trip() {
+        let repo = setup_test_repo().await;
+        let mut task = Task::new("Summarise").with_workspace_mode(WorkspaceMode::Ephemeral);
+        repo.create(&task).await.unwrap();
+        assert_eq!(
+            repo.get(task.id).await.unwrap().unwrap().workspace_mode,
+            WorkspaceMode::Ephemeral
+        );
+
+        task.workspace_mode = WorkspaceMode::Worktree;
+        repo.update(&task).await.unwrap();
+        assert_eq!(
+            repo.get(task.id).await.unwrap().unwrap().workspace_mode,
+            WorkspaceMode::Worktree
+        );
+    }
+
     #[tokio::test]
     async fn test_task_dependencies() {
         let repo = setup_test_repo().await;
@@ -734,6 +1101,90 @@ mod tests {
         assert_eq!(ready[0].title, "Ready High"); // Higher priority first
     }
 
+    #[tokio::test]
+    async fn test_bulk_insert_accepts_forward_references_and_is_atomic() {
+        let repo = setup_test_repo().await;
+
+        // Child and dependent come before the tasks they reference.
+        let parent = Task::with_title("Parent", "Desc");
+        let upstream = Task::with_title("Upstream", "Desc");
+        let child = Task::with_title("Child", "Desc")
+            .with_parent(parent.id)
+            .with_dependency(upstream.id);
+        repo.bulk_insert(&[child.clone(), parent.clone(), upstream.clone()])
+            .await
+            .unwrap();
+
+        let loaded = repo.get(child.id).await.unwrap().unwrap();
+        assert_eq!(loaded.parent_id, Some(parent.id));
+        assert_eq!(loaded.depends_on, vec![upstream.id]);
+
+        // A duplicate ID late in the batch rolls back the rows before it.
+        let fresh = Task::with_title("Fresh", "Desc");
+        let err = repo.bulk_insert(&[fresh.clone(), parent.clone()]).await;
+        assert!(err.is_err());
+        assert!(repo.get(fresh.id).await.unwrap().is_none());
+
+        // A reference to a task that exists nowhere fails the whole batch.
+        let orphan = Task::with_title("Orphan", "Desc").with_dependency(Uuid::new_v4());
+        assert!(repo.bulk_insert(std::slice::from_ref(&orphan)).await.is_err());
+        assert!(repo.get(orphan.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_priority_range_is_inclusive_and_composes_with_status() {
+        let repo = setup_test_repo().await;
+
+        for priority in TaskPriority::ALL {
+            let mut task = Task::with_title(priority.as_str(), "Desc").with_priority(priority);
+            task.status = TaskStatus::Ready;
+            repo.create(&task).await.unwrap();
+        }
+        let blocked = Task::with_title("blocked high", "Desc").with_priority(TaskPriority::High);
+        repo.create(&blocked).await.unwrap();
+
+        let titles = |tasks: Vec<Task>| {
+            let mut titles: Vec<String> = tasks.into_iter().map(|t| t.title).collect();
+            titles.sort();
+            titles
+        };
+
+        // Both bounds are inclusive.
+        let in_range = repo
+            .list(TaskFilter {
+                status: Some(TaskStatus::Ready),
+                priority_range: Some((TaskPriority::Normal, TaskPriority::High)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(titles(in_range), vec!["high", "normal"]);
+
+        // A single-value range matches exactly that priority; without the
+        // status filter the non-ready task is included too.
+        let exact = repo
+            .list(TaskFilter {
+                priority_range: Some((TaskPriority::High, TaskPriority::High)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(titles(exact), vec!["blocked high", "high"]);
+
+        // An inverted range matches nothing.
+        let inverted = repo
+            .list(TaskFilter {
+                priority_range: Some((TaskPriority::Critical, TaskPriority::Low)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(inverted.is_empty());
+
+        let unfiltered = repo.list(TaskFilter::default()).await.unwrap();
+        assert_eq!(unfiltered.len(), 5);
+    }
+
     #[tokio::test]
     async fn test_claim_task_atomic_success() {
         let repo = setup_test_repo().await;
@@ -779,6 +1230,96 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    async fn test_claim_task_atomic_concurrent_claimers_get_one_task() {
+        let repo = setup_test_repo().await;
+
+        let mut task = Task::with_title("Only one", "Desc");
+        task.status = TaskStatus::Ready;
+        repo.create(&task).await.unwrap();
+
+        let (a, b) = (repo.clone(), repo.clone());
+        let id = task.id;
+        let first = tokio::spawn(async move { a.claim_task_atomic(id, "coder").await.unwrap() });
+        let second = tokio::spawn(async move { b.claim_task_atomic(id, "coder").await.unwrap() });
+        let results = [first.await.unwrap(), second.await.unwrap()];
+
+        let winners: Vec<&Task> = results.iter().flatten().collect();
+        assert_eq!(winners.len(), 1, "exactly one claimer should win");
+        assert_eq!(winners[0].id, task.id);
+        assert_eq!(
+            repo.get(task.id).await.unwrap().unwrap().status,
+            TaskStatus::Running
+        );
+    }
+
+    #[tokio::test]
+    async fn test_claim_next_ready_takes_first_candidate_still_ready() {
+        let repo = setup_test_repo().await;
+
+        let mut tasks = Vec::new();
+        for title in ["taken", "next", "later"] {
+            let mut task = Task::with_title(title, "Desc");
+            task.status = TaskStatus::Ready;
+            repo.create(&task).await.unwrap();
+            tasks.push(task.id);
+        }
+        // Not a candidate, so never claimed however it ranks.
+        let mut other = Task::with_title("other", "Desc").with_priority(TaskPriority::Critical);
+        other.status = TaskStatus::Ready;
+        repo.create(&other).await.unwrap();
+        repo.claim_task_atomic(tasks[0], "coder").await.unwrap();
+
+        let claimed = repo
+            .claim_next_ready(&tasks, "reviewer")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(claimed.id, tasks[1]);
+        assert_eq!(claimed.status, TaskStatus::Running);
+        assert_eq!(claimed.agent_type.as_deref(), Some("reviewer"));
+        assert!(claimed.started_at.is_some());
+
+        let claimed = repo
+            .claim_next_ready(&tasks, "coder")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(claimed.id, tasks[2]);
+        assert!(
+            repo.claim_next_ready(&tasks, "coder")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(repo.claim_next_ready(&[], "coder").await.unwrap().is_none());
+        assert_eq!(
+            repo.get(other.id).await.unwrap().unwrap().status,
+            TaskStatus::Ready
+        );
+    }
+
+    #[tokio::test]
+    async fn test_claim_next_ready_concurrent_claimers_get_one_task() {
+        let repo = setup_test_repo().await;
+
+        let mut task = Task::with_title("Only one", "Desc");
+        task.status = TaskStatus::Ready;
+        repo.create(&task).await.unwrap();
+
+        let (a, b) = (repo.clone(), repo.clone());
+        let candidates = [task.id];
+        let first =
+            tokio::spawn(async move { a.claim_next_ready(&candidates, "coder").await.unwrap() });
+        let second =
+            tokio::spawn(async move { b.claim_next_ready(&candidates, "coder").await.unwrap() });
+        let results = [first.await.unwrap(), second.await.unwrap()];
+
+        let winners: Vec<&Task> = results.iter().flatten().collect();
+        assert_eq!(winners.len(), 1, "exactly one claimer should win");
+        assert_eq!(winners[0].id, task.id);
+    }
+
     #[tokio::test]
     async fn test_update_with_correct_version_succeeds() {
         let repo = setup_test_repo().await;
@@ -977,6 +1518,67 @@ mod tests {
         assert_eq!(claimed.version, original_version + 1);
     }
 
+    #[tokio::test]
+    async fn test_count_by_idempotency_prefix() {
+        let repo = setup_test_repo().await;
+        for key in ["loop:a:1", "loop:a:2", "loop:b:1"] {
+            repo.create(&Task::new("keyed").with_idempotency_key(key))
+                .await
+                .unwrap();
+        }
+        repo.create(&Task::new("unkeyed")).await.unwrap();
+
+        assert_eq!(
+            repo.count_by_idempotency_prefix("loop:a:").await.unwrap(),
+            2
+        );
+        assert_eq!(repo.count_by_idempotency_prefix("loop:").await.unwrap(), 3);
+        assert_eq!(repo.count_by_idempotency_prefix("other").await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_velocity_since() {
+        let repo = setup_test_repo().await;
+        let now = Utc::now();
+        // (estimate, actual): errors of +600 and -200 seconds.
+        for (estimate, duration) in [(Some(1200), 1800), (Some(1000), 800), (None, 400)] {
+            let mut task = Task::new("done");
+            task.status = TaskStatus::Complete;
+            task.completed_at = Some(now);
+            task.estimate_secs = estimate;
+            task.duration_secs = Some(duration);
+            repo.create(&task).await.unwrap();
+        }
+        // Outside the window and not complete: both excluded.
+        let mut old = Task::new("old");
+        old.status = TaskStatus::Complete;
+        old.completed_at = Some(now - chrono::Duration::days(30));
+        old.duration_secs = Some(99_999);
+        repo.create(&old).await.unwrap();
+        repo.create(&Task::new("pending").with_estimate_secs(60))
+            .await
+            .unwrap();
+
+        let v = repo
+            .velocity_since(now - chrono::Duration::days(7))
+            .await
+            .unwrap();
+        assert_eq!(v.completed, 3);
+        assert_eq!(v.estimated, 2);
+        assert_eq!(v.avg_actual_secs, Some(1000.0));
+        assert_eq!(v.avg_estimate_secs, Some(1100.0));
+        assert_eq!(v.mean_abs_error_secs, Some(400.0));
+        assert_eq!(v.mean_error_secs, Some(200.0));
+        assert!((v.throughput_per_day() - 3.0 / 7.0).abs() < 0.01);
+
+        let empty = repo
+            .velocity_since(now + chrono::Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(empty.completed, 0);
+        assert_eq!(empty.avg_actual_secs, None);
+    }
+
     #[tokio::test]
     async fn test_calculate_depth_root_task() {
         let repo = setup_test_repo().await;
@@ -1074,4 +1676,116 @@ mod tests {
         // Leaf has no descendants
         assert_eq!(repo.count_descendants(grandchild.id).await.unwrap(), 0);
     }
+
+    fn ids(tasks: &[Task]) -> Vec<Uuid> {
+        tasks.iter().map(|t| t.id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_search_by_text_tracks_description_changes() {
+        let repo = setup_test_repo().await;
+        let mut task = Task::with_title("Tidy schema", "Rename the columns");
+        repo.create(&task).await.unwrap();
+
+        let filter = TaskFilter::default();
+        assert!(
+            repo.search_by_text("migration", filter.clone())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        task.description = "Write the migration that renames the columns".to_string();
+        repo.update(&task).await.unwrap();
+        let found = repo
+            .search_by_text("migration", filter.clone())
+            .await
+            .unwrap();
+        assert_eq!(ids(&found), vec![task.id]);
+
+        task.description = "Nothing to see".to_string();
+        repo.update(&task).await.unwrap();
+        assert!(
+            repo.search_by_text("migration", filter.clone())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        repo.delete(task.id).await.unwrap();
+        assert!(
+            repo.search_by_text("tidy", filter)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_by_text_ranks_and_filters() {
+        let repo = setup_test_repo().await;
+        let in_description = Task::with_title("Schema work", "Add a migration for goals");
+        let in_title = Task::with_title("Migration for tasks", "Add columns");
+        let mut other_status = Task::with_title("Migration cleanup", "Drop old tables");
+        other_status.status = TaskStatus::Complete;
+        for task in [&in_description, &in_title, &other_status] {
+            repo.create(task).await.unwrap();
+        }
+
+        let filter = TaskFilter {
+            status: Some(TaskStatus::Pending),
+            ..Default::default()
+        };
+        let found = repo.search_by_text("migration", filter).await.unwrap();
+        assert_eq!(ids(&found), vec![in_title.id, in_description.id]);
+
+        // Every term must match.
+        let found = repo
+            .search_by_text("migration goals", TaskFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(ids(&found), vec![in_description.id]);
+        assert!(
+            repo.search_by_text("  ", TaskFilter::default())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_by_text_falls_back_to_like_without_fts() {
+        let repo = setup_test_repo().await;
+        sqlx::raw_sql(
+            "DROP TRIGGER tasks_fts_insert; DROP TRIGGER tasks_fts_update; \
+             DROP TRIGGER tasks_fts_delete; DROP TABLE tasks_fts;",
+        )
+        .execute(&repo.pool)
+        .await
+        .unwrap();
+
+        let in_description = Task::with_title("Schema work", "Add a 100% migration");
+        let in_title = Task::with_title("Migration for tasks", "Add columns");
+        repo.create(&in_description).await.unwrap();
+        repo.create(&in_title).await.unwrap();
+
+        let found = repo
+            .search_by_text("MIGRATION", TaskFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(ids(&found), vec![in_title.id, in_description.id]);
+
+        // LIKE wildcards in the query are matched literally.
+        let found = repo
+            .search_by_text("100%", TaskFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(ids(&found), vec![in_description.id]);
+        assert!(
+            repo.search_by_text("1_0", TaskFilter::default())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
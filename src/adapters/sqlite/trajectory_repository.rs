@@ -173,6 +173,18 @@ impl TrajectoryRepository for SqliteTrajectoryRepository {
         rows.into_iter().map(|r| r.try_into()).collect()
     }
 
+    async fn get_active(&self) -> DomainResult<Vec<Trajectory>> {
+        let rows: Vec<TrajectoryRow> = sqlx::query_as(
+            r#"SELECT * FROM convergence_trajectories
+               WHERE phase NOT IN ('"converged"', '"exhausted"', '"trapped"')
+               ORDER BY updated_at DESC"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
     async fn get_successful_strategies(
         &self,
         attractor_type: &AttractorType,
@@ -628,6 +640,38 @@ mod tests {
         assert_eq!(results.len(), 3);
     }
 
+    #[tokio::test]
+    async fn test_get_active_excludes_terminal_phases() {
+        let (repo, pool) = setup_test_repo().await;
+
+        let mut iterating = test_trajectory();
+        iterating.phase = ConvergencePhase::Iterating;
+        let mut coordinating = test_trajectory();
+        coordinating.phase = ConvergencePhase::Coordinating {
+            children: vec![Uuid::new_v4()],
+        };
+        let mut converged = test_trajectory();
+        converged.phase = ConvergencePhase::Converged;
+        let mut trapped = test_trajectory();
+        trapped.phase = ConvergencePhase::Trapped;
+
+        for t in [&iterating, &coordinating, &converged, &trapped] {
+            save_with_task(&repo, &pool, t).await;
+        }
+
+        let mut ids: Vec<Uuid> = repo
+            .get_active()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        ids.sort();
+        let mut expected = vec![iterating.id, coordinating.id];
+        expected.sort();
+        assert_eq!(ids, expected);
+    }
+
     #[tokio::test]
     async fn test_delete() {
         let (repo, pool) = setup_test_repo().await;
@@ -93,6 +93,26 @@ impl WorktreeRepository for SqliteWorktreeRepository {
         Ok(())
     }
 
+    async fn reassign_completed(
+        &self,
+        id: Uuid,
+        from_task: Uuid,
+        to_task: Uuid,
+    ) -> DomainResult<bool> {
+        let result = sqlx::query(
+            r#"UPDATE worktrees SET task_id = ?, status = 'active', completed_at = NULL, updated_at = ?
+               WHERE id = ? AND task_id = ? AND status IN ('completed', 'merged')"#,
+        )
+        .bind(to_task.to_string())
+        .bind(chrono::Utc::now().to_rfc3339())
+        .bind(id.to_string())
+        .bind(from_task.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
     async fn delete(&self, id: Uuid) -> DomainResult<()> {
         let result = sqlx::query("DELETE FROM worktrees WHERE id = ?")
             .bind(id.to_string())
@@ -250,4 +270,26 @@ mod tests {
         let active = repo.list_active().await.unwrap();
         assert_eq!(active.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_reassign_completed_is_claimed_once() {
+        let (repo, pool) = setup_test_repo().await;
+        let (from, first, second) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        for id in [from, first, second] {
+            insert_test_task(&pool, id).await;
+        }
+
+        let mut wt = Worktree::new(from, "/tmp/wt4", "branch4", "main");
+        wt.complete();
+        repo.create(&wt).await.unwrap();
+
+        assert!(repo.reassign_completed(wt.id, from, first).await.unwrap());
+        assert!(!repo.reassign_completed(wt.id, from, second).await.unwrap());
+
+        let reused = repo.get_by_task(first).await.unwrap().unwrap();
+        assert_eq!(reused.id, wt.id);
+        assert_eq!(reused.status, WorktreeStatus::Active);
+        assert!(reused.completed_at.is_none());
+        assert!(repo.get_by_task(second).await.unwrap().is_none());
+    }
 }
@@ -4,6 +4,7 @@
 //! to the Claude Code CLI substrate.
 
 use async_trait::async_trait;
+use futures::StreamExt;
 use reqwest::{Client, header};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -12,7 +13,9 @@ use tokio::sync::{RwLock, mpsc};
 use uuid::Uuid;
 
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::models::{SessionStatus, SubstrateOutput, SubstrateRequest, SubstrateSession};
+use crate::domain::models::{
+    SessionStatus, SessionUsage, SubstrateOutput, SubstrateRequest, SubstrateSession,
+};
 use crate::domain::ports::Substrate;
 
 /// Configuration for the Anthropic API substrate.
@@ -163,12 +166,19 @@ pub struct MessagesRequest {
     pub stream: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stop_sequences: Vec<String>,
 }
 
 /// Usage information from the API.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// Streamed `message_delta` events carry only the fields that changed, so
+/// every count defaults to zero.
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct Usage {
+    #[serde(default)]
     pub input_tokens: u64,
+    #[serde(default)]
     pub output_tokens: u64,
     #[serde(default)]
     pub cache_read_input_tokens: u64,
@@ -243,7 +253,171 @@ pub struct ApiError {
     pub message: String,
 }
 
+/// Running totals for one Messages API response, folded from stream events.
+#[derive(Debug, Default)]
+struct StreamAccumulator {
+    text: String,
+    input_tokens: u64,
+    output_tokens: u64,
+    cache_read_tokens: u64,
+    cache_write_tokens: u64,
+    stop_reason: Option<String>,
+    /// Set when the API reports an error mid-stream or the stream is cut short.
+    error: Option<String>,
+    finished: bool,
+}
+
+impl StreamAccumulator {
+    /// Fold one stream event into the totals, returning the output it produces.
+    fn apply(&mut self, event: StreamEvent) -> Vec<SubstrateOutput> {
+        match event {
+            StreamEvent::MessageStart { message } => {
+                self.record_usage(&message.usage);
+                vec![]
+            }
+            StreamEvent::ContentBlockStart {
+                content_block: ContentBlock::ToolUse { id, name, .. },
+                ..
+            } => vec![SubstrateOutput::ToolStart { name, id }],
+            StreamEvent::ContentBlockStart {
+                content_block: ContentBlock::Text { text },
+                ..
+            }
+            | StreamEvent::ContentBlockDelta {
+                delta: DeltaBlock { text, .. },
+                ..
+            } if !text.is_empty() => {
+                self.text.push_str(&text);
+                vec![SubstrateOutput::AssistantText { content: text }]
+            }
+            StreamEvent::MessageDelta { delta, usage } => {
+                // Usage on message_delta is cumulative; input counts are only
+                // present on newer API versions.
+                self.record_usage(&usage);
+                self.stop_reason = delta.stop_reason;
+                let mut out = vec![SubstrateOutput::TurnComplete {
+                    turn_number: 1,
+                    input_tokens: self.input_tokens,
+                    output_tokens: self.output_tokens,
+                }];
+                out.extend(self.truncation_notice());
+                out
+            }
+            StreamEvent::MessageStop => {
+                self.finished = true;
+                vec![SubstrateOutput::SessionComplete {
+                    result: self.text.clone(),
+                    usage: Some(self.usage()),
+                }]
+            }
+            StreamEvent::Error { error } => {
+                let message = format!("{}: {}", error.error_type, error.message);
+                self.error = Some(message.clone());
+                self.finished = true;
+                vec![SubstrateOutput::Error { message }]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Fold a complete (non-streamed) response, producing the same output a
+    /// stream of it would.
+    fn apply_response(&mut self, response: MessagesResponse) -> Vec<SubstrateOutput> {
+        let mut out = Vec::new();
+        for block in response.content {
+            match block {
+                ContentBlock::Text { text } => {
+                    if !self.text.is_empty() {
+                        self.text.push('\n');
+                    }
+                    self.text.push_str(&text);
+                    out.push(SubstrateOutput::AssistantText { content: text });
+                }
+                ContentBlock::ToolUse { id, name, .. } => {
+                    out.push(SubstrateOutput::ToolStart { name, id });
+                }
+                ContentBlock::ToolResult { .. } => {}
+            }
+        }
+        self.record_usage(&response.usage);
+        self.stop_reason = response.stop_reason;
+        self.finished = true;
+        out.push(SubstrateOutput::TurnComplete {
+            turn_number: 1,
+            input_tokens: self.input_tokens,
+            output_tokens: self.output_tokens,
+        });
+        out.extend(self.truncation_notice());
+        out.push(SubstrateOutput::SessionComplete {
+            result: self.text.clone(),
+            usage: Some(self.usage()),
+        });
+        out
+    }
+
+    /// A status line when the model was cut off by `max_tokens`.
+    fn truncation_notice(&self) -> Option<SubstrateOutput> {
+        (self.stop_reason.as_deref() == Some("max_tokens")).then(|| SubstrateOutput::Status {
+            message: "Response stopped at max_tokens; output may be truncated".to_string(),
+        })
+    }
+
+    fn record_usage(&mut self, usage: &Usage) {
+        self.input_tokens = self.input_tokens.max(usage.input_tokens);
+        self.output_tokens = self.output_tokens.max(usage.output_tokens);
+        self.cache_read_tokens = self.cache_read_tokens.max(usage.cache_read_input_tokens);
+        self.cache_write_tokens = self
+            .cache_write_tokens
+            .max(usage.cache_creation_input_tokens);
+    }
+
+    /// Totals so far, as carried on `SessionComplete`.
+    fn usage(&self) -> SessionUsage {
+        SessionUsage {
+            input_tokens: self.input_tokens,
+            output_tokens: self.output_tokens,
+            cache_read_tokens: self.cache_read_tokens,
+            cache_write_tokens: self.cache_write_tokens,
+            cost_cents: None,
+        }
+    }
+
+    /// Record the totals on `session` and settle its final status.
+    fn finish(self, session: &mut SubstrateSession) {
+        session.input_tokens = self.input_tokens;
+        session.output_tokens = self.output_tokens;
+        session.cache_read_tokens = self.cache_read_tokens;
+        session.cache_write_tokens = self.cache_write_tokens;
+        session.turns_completed = 1;
+        match self.error {
+            Some(error) => session.fail(error),
+            None => session.complete(self.text),
+        }
+    }
+}
+
+/// Split complete lines off the front of an SSE byte buffer, leaving any
+/// trailing partial line (or partial UTF-8 sequence) for the next chunk.
+fn drain_sse_lines(buffer: &mut Vec<u8>) -> Vec<String> {
+    let mut lines = Vec::new();
+    while let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
+        let line: Vec<u8> = buffer.drain(..=pos).collect();
+        lines.push(String::from_utf8_lossy(&line).trim_end().to_string());
+    }
+    lines
+}
+
+/// Send `output` if anyone is listening. Returns false once the receiver is
+/// gone, which callers treat as cancellation.
+async fn forward(output: Option<&mpsc::Sender<SubstrateOutput>>, event: SubstrateOutput) -> bool {
+    match output {
+        Some(tx) => tx.send(event).await.is_ok(),
+        None => true,
+    }
+}
+
 /// Anthropic API substrate.
+#[derive(Clone)]
 pub struct AnthropicApiSubstrate {
     config: AnthropicApiConfig,
     client: Client,
@@ -305,11 +479,12 @@ impl AnthropicApiSubstrate {
 
         MessagesRequest {
             model,
-            max_tokens: self.config.max_tokens,
+            max_tokens: request.config.max_tokens.unwrap_or(self.config.max_tokens),
             system,
             messages,
             stream: self.config.stream,
-            temperature: None,
+            temperature: request.config.temperature,
+            stop_sequences: request.config.stop_sequences.clone(),
         }
     }
 
@@ -327,8 +502,75 @@ impl AnthropicApiSubstrate {
         serde_json::from_str(json_str).ok()
     }
 
-    /// Execute a non-streaming request.
-    async fn execute_sync(&self, request: &SubstrateRequest) -> DomainResult<(String, Usage)> {
+    /// Create a session for `request` and register it.
+    async fn start_session(&self, request: &SubstrateRequest) -> SubstrateSession {
+        let mut session = SubstrateSession::new(
+            request.task_id,
+            &request.agent_template,
+            request.config.clone(),
+        );
+        session.start(None);
+
+        let mut sessions = self.sessions.write().await;
+        sessions.insert(session.id, session.clone());
+        session
+    }
+
+    /// Run a session to completion, forwarding output as it arrives.
+    async fn run_session(
+        &self,
+        request: SubstrateRequest,
+        output: Option<mpsc::Sender<SubstrateOutput>>,
+    ) -> DomainResult<SubstrateSession> {
+        let mut session = self.start_session(&request).await;
+        self.drive_session(&mut session, &request, output.as_ref())
+            .await;
+        Ok(session)
+    }
+
+    /// Send the request, stream the response, and settle `session`.
+    async fn drive_session(
+        &self,
+        session: &mut SubstrateSession,
+        request: &SubstrateRequest,
+        output: Option<&mpsc::Sender<SubstrateOutput>>,
+    ) {
+        match self.send_messages(session.id, request, output).await {
+            Ok(acc) => acc.finish(session),
+            Err(e) => {
+                forward(
+                    output,
+                    SubstrateOutput::Error {
+                        message: e.to_string(),
+                    },
+                )
+                .await;
+                session.fail(e.to_string());
+            }
+        }
+
+        let mut sessions = self.sessions.write().await;
+        if let Some(stored) = sessions.get(&session.id)
+            && stored.status == SessionStatus::Terminated
+        {
+            session.status = SessionStatus::Terminated;
+            session.ended_at = stored.ended_at;
+        }
+        sessions.insert(session.id, session.clone());
+    }
+
+    /// POST the request to the Messages API and fold the response, streamed
+    /// or not, into an accumulator, forwarding output as it is produced.
+    ///
+    /// Transport and HTTP failures are returned as errors; errors the API
+    /// reports mid-stream are recorded on the accumulator. Reading stops early
+    /// if the session is terminated or the output receiver is dropped.
+    async fn send_messages(
+        &self,
+        session_id: Uuid,
+        request: &SubstrateRequest,
+        output: Option<&mpsc::Sender<SubstrateOutput>>,
+    ) -> DomainResult<StreamAccumulator> {
         let api_key = self
             .config
             .get_api_key()
@@ -337,8 +579,7 @@ impl AnthropicApiSubstrate {
                 reason: "ANTHROPIC_API_KEY not set".to_string(),
             })?;
 
-        let mut api_request = self.build_request(request);
-        api_request.stream = false;
+        let api_request = self.build_request(request);
 
         let response = self
             .client
@@ -360,23 +601,64 @@ impl AnthropicApiSubstrate {
             )));
         }
 
-        let result: MessagesResponse = response
-            .json()
-            .await
-            .map_err(|e| DomainError::SubstrateError(format!("Failed to parse response: {}", e)))?;
+        let mut acc = StreamAccumulator::default();
 
-        // Extract text from content blocks
-        let text = result
-            .content
-            .iter()
-            .filter_map(|block| match block {
-                ContentBlock::Text { text } => Some(text.as_str()),
-                _ => None,
-            })
-            .collect::<Vec<_>>()
-            .join("\n");
+        if !api_request.stream {
+            let result: MessagesResponse = response.json().await.map_err(|e| {
+                DomainError::SubstrateError(format!("Failed to parse response: {}", e))
+            })?;
+            for event in acc.apply_response(result) {
+                if !forward(output, event).await {
+                    break;
+                }
+            }
+            return Ok(acc);
+        }
 
-        Ok((text, result.usage))
+        let mut body = response.bytes_stream();
+        let mut buffer = Vec::new();
+        'stream: while let Some(chunk) = body.next().await {
+            let chunk = chunk
+                .map_err(|e| DomainError::SubstrateError(format!("Stream interrupted: {}", e)))?;
+            buffer.extend_from_slice(&chunk);
+
+            for line in drain_sse_lines(&mut buffer) {
+                let Some(event) = Self::parse_sse_event(&line) else {
+                    continue;
+                };
+                for out in acc.apply(event) {
+                    if !forward(output, out).await {
+                        tracing::debug!(
+                            "substrate channel closed mid-stream; caller likely cancelled"
+                        );
+                        acc.error = Some("Output receiver closed mid-stream".to_string());
+                        break 'stream;
+                    }
+                }
+            }
+
+            if acc.finished {
+                break;
+            }
+            if !self.is_running(session_id).await.unwrap_or(false) {
+                tracing::debug!(%session_id, "session terminated mid-stream");
+                break;
+            }
+        }
+
+        if !acc.finished && acc.error.is_none() {
+            let message = "Stream ended before message_stop".to_string();
+            forward(
+                output,
+                SubstrateOutput::Error {
+                    message: message.clone(),
+                },
+            )
+            .await;
+            acc.error = Some(message);
+        }
+
+        Ok(acc)
     }
 }
 
@@ -392,271 +674,35 @@ impl Substrate for AnthropicApiSubstrate {
     }
 
     async fn execute(&self, request: SubstrateRequest) -> DomainResult<SubstrateSession> {
-        // Create session
-        let mut session = SubstrateSession::new(
-            request.task_id,
-            &request.agent_template,
-            request.config.clone(),
-        );
-        session.start(None);
-
-        // Store session
-        {
-            let mut sessions = self.sessions.write().await;
-            sessions.insert(session.id, session.clone());
-        }
-
-        // Execute request
-        match self.execute_sync(&request).await {
-            Ok((text, usage)) => {
-                session.input_tokens = usage.input_tokens;
-                session.output_tokens = usage.output_tokens;
-                session.cache_read_tokens = usage.cache_read_input_tokens;
-                session.cache_write_tokens = usage.cache_creation_input_tokens;
-                session.turns_completed = 1;
-                session.complete(&text);
-            }
-            Err(e) => {
-                session.fail(e.to_string());
-            }
-        }
-
-        // Update session store
-        {
-            let mut sessions = self.sessions.write().await;
-            sessions.insert(session.id, session.clone());
-        }
+        self.run_session(request, None).await
+    }
 
-        Ok(session)
+    async fn execute_with_output(
+        &self,
+        request: SubstrateRequest,
+        output: mpsc::Sender<SubstrateOutput>,
+    ) -> DomainResult<SubstrateSession> {
+        self.run_session(request, Some(output)).await
     }
 
     async fn execute_streaming(
         &self,
         request: SubstrateRequest,
     ) -> DomainResult<(mpsc::Receiver<SubstrateOutput>, SubstrateSession)> {
-        let api_key = self
-            .config
-            .get_api_key()
-            .ok_or_else(|| DomainError::ConfigError {
+        if self.config.get_api_key().is_none() {
+            return Err(DomainError::ConfigError {
                 key: "ANTHROPIC_API_KEY".to_string(),
                 reason: "ANTHROPIC_API_KEY not set".to_string(),
-            })?;
-
-        // Create session
-        let mut session = SubstrateSession::new(
-            request.task_id,
-            &request.agent_template,
-            request.config.clone(),
-        );
-        session.start(None);
-
-        // Store session
-        {
-            let mut sessions = self.sessions.write().await;
-            sessions.insert(session.id, session.clone());
+            });
         }
 
-        let api_request = self.build_request(&request);
-
-        // Create output channel
+        let session = self.start_session(&request).await;
         let (tx, rx) = mpsc::channel(100);
 
-        let client = self.client.clone();
-        let base_url = self.config.base_url.clone();
-        let api_version = self.config.api_version.clone();
-        let session_id = session.id;
-        let sessions_clone = self.sessions.clone();
-
-        // Spawn streaming task
-        // Note: For simplicity, we fetch the full response and simulate streaming.
-        // A full SSE implementation would require the `stream` feature in reqwest.
+        let this = self.clone();
+        let mut running = session.clone();
         tokio::spawn(async move {
-            let response = client
-                .post(format!("{}/v1/messages", base_url))
-                .header(header::CONTENT_TYPE, "application/json")
-                .header("x-api-key", &api_key)
-                .header("anthropic-version", &api_version)
-                .json(&api_request)
-                .send()
-                .await;
-
-            let response = match response {
-                Ok(r) => r,
-                Err(e) => {
-                    if let Err(se) = tx
-                        .send(SubstrateOutput::Error {
-                            message: format!("Request failed: {}", e),
-                        })
-                        .await
-                    {
-                        tracing::warn!(error = ?se, "substrate channel closed before fatal error delivered");
-                    }
-                    return;
-                }
-            };
-
-            if !response.status().is_success() {
-                let status = response.status();
-                let body = response.text().await.unwrap_or_default();
-                if let Err(se) = tx
-                    .send(SubstrateOutput::Error {
-                        message: format!("API error {}: {}", status, body),
-                    })
-                    .await
-                {
-                    tracing::warn!(error = ?se, "substrate channel closed before fatal error delivered");
-                }
-                return;
-            }
-
-            // Read full response body
-            let body = match response.text().await {
-                Ok(b) => b,
-                Err(e) => {
-                    if let Err(se) = tx
-                        .send(SubstrateOutput::Error {
-                            message: format!("Failed to read response: {}", e),
-                        })
-                        .await
-                    {
-                        tracing::warn!(error = ?se, "substrate channel closed before fatal error delivered");
-                    }
-                    return;
-                }
-            };
-
-            let mut all_text = String::new();
-            let mut total_input = 0u64;
-            let mut total_output = 0u64;
-
-            // Process SSE lines
-            'sse: for line in body.lines() {
-                if let Some(event) = Self::parse_sse_event(line) {
-                    match event {
-                        StreamEvent::ContentBlockDelta { delta, .. } => {
-                            if !delta.text.is_empty() {
-                                all_text.push_str(&delta.text);
-                                if let Err(e) = tx
-                                    .send(SubstrateOutput::AssistantText {
-                                        content: delta.text,
-                                    })
-                                    .await
-                                {
-                                    tracing::debug!(error = ?e, "substrate channel closed mid-stream; caller likely cancelled");
-                                    break 'sse;
-                                }
-                            }
-                        }
-                        StreamEvent::MessageStart { message } => {
-                            total_input = message.usage.input_tokens;
-                        }
-                        StreamEvent::MessageDelta { usage, .. } => {
-                            total_output = usage.output_tokens;
-                            if let Err(e) = tx
-                                .send(SubstrateOutput::TurnComplete {
-                                    turn_number: 1,
-                                    input_tokens: total_input,
-                                    output_tokens: total_output,
-                                })
-                                .await
-                            {
-                                tracing::warn!(error = ?e, "substrate channel closed before terminal event delivered");
-                                break 'sse;
-                            }
-                        }
-                        StreamEvent::MessageStop => {
-                            if let Err(e) = tx
-                                .send(SubstrateOutput::SessionComplete {
-                                    result: all_text.clone(),
-                                })
-                                .await
-                            {
-                                tracing::warn!(error = ?e, "substrate channel closed before terminal event delivered");
-                                break 'sse;
-                            }
-                        }
-                        StreamEvent::Error { error } => {
-                            if let Err(e) = tx
-                                .send(SubstrateOutput::Error {
-                                    message: error.message,
-                                })
-                                .await
-                            {
-                                tracing::warn!(error = ?e, "substrate channel closed before terminal event delivered");
-                                break 'sse;
-                            }
-                        }
-                        StreamEvent::ContentBlockStart {
-                            content_block: ContentBlock::ToolUse { id, name, .. },
-                            ..
-                        } => {
-                            if let Err(e) = tx.send(SubstrateOutput::ToolStart { name, id }).await {
-                                tracing::debug!(error = ?e, "substrate channel closed mid-stream; caller likely cancelled");
-                                break 'sse;
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-            }
-
-            // If we didn't get streaming events, try parsing as a regular JSON response
-            if all_text.is_empty()
-                && let Ok(result) = serde_json::from_str::<MessagesResponse>(&body)
-            {
-                'fallback: {
-                    for block in &result.content {
-                        if let ContentBlock::Text { text } = block {
-                            all_text.push_str(text);
-                            if let Err(e) = tx
-                                .send(SubstrateOutput::AssistantText {
-                                    content: text.clone(),
-                                })
-                                .await
-                            {
-                                tracing::debug!(error = ?e, "substrate channel closed mid-stream; caller likely cancelled");
-                                break 'fallback;
-                            }
-                        }
-                    }
-                    total_input = result.usage.input_tokens;
-                    total_output = result.usage.output_tokens;
-
-                    if let Err(e) = tx
-                        .send(SubstrateOutput::TurnComplete {
-                            turn_number: 1,
-                            input_tokens: total_input,
-                            output_tokens: total_output,
-                        })
-                        .await
-                    {
-                        tracing::warn!(error = ?e, "substrate channel closed before terminal event delivered");
-                        break 'fallback;
-                    }
-
-                    if let Err(e) = tx
-                        .send(SubstrateOutput::SessionComplete {
-                            result: all_text.clone(),
-                        })
-                        .await
-                    {
-                        tracing::warn!(error = ?e, "substrate channel closed before terminal event delivered");
-                    }
-                }
-            }
-
-            // Update session
-            {
-                let mut sessions = sessions_clone.write().await;
-                if let Some(session) = sessions.get_mut(&session_id) {
-                    session.input_tokens = total_input;
-                    session.output_tokens = total_output;
-                    session.turns_completed = 1;
-                    if session.status == SessionStatus::Active {
-                        session.complete(&all_text);
-                    }
-                }
-            }
+            this.drive_session(&mut running, &request, Some(&tx)).await;
         });
 
         Ok((rx, session))
@@ -693,8 +739,8 @@ impl Substrate for AnthropicApiSubstrate {
     }
 
     async fn terminate(&self, session_id: Uuid) -> DomainResult<()> {
-        // For API substrate, we just mark the session as terminated
-        // (we can't actually cancel an in-flight HTTP request easily)
+        // Mark the session as terminated; a streaming read notices between
+        // chunks and stops.
         let mut sessions = self.sessions.write().await;
         if let Some(session) = sessions.get_mut(&session_id) {
             session.terminate();
@@ -785,4 +831,189 @@ mod tests {
         let event = AnthropicApiSubstrate::parse_sse_event(line);
         assert!(event.is_none());
     }
+
+    /// Events in the order the API streams them, usage split across
+    /// message_start and message_delta as the real API does.
+    fn sse_lines(events: &[&str]) -> String {
+        events
+            .iter()
+            .map(|e| format!("event: x\ndata: {}\n\n", e))
+            .collect()
+    }
+
+    const STREAM: &[&str] = &[
+        r#"{"type":"message_start","message":{"id":"msg_1","model":"m","usage":{"input_tokens":12,"output_tokens":1,"cache_read_input_tokens":8}}}"#,
+        r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+        r#"{"type":"ping"}"#,
+        r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hel"}}"#,
+        r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"lo é"}}"#,
+        r#"{"type":"content_block_stop","index":0}"#,
+        r#"{"type":"message_delta","delta":{"stop_reason":"max_tokens"},"usage":{"output_tokens":5}}"#,
+        r#"{"type":"message_stop"}"#,
+    ];
+
+    fn fold(events: &[&str]) -> (StreamAccumulator, Vec<SubstrateOutput>) {
+        let mut acc = StreamAccumulator::default();
+        let mut out = Vec::new();
+        for event in events {
+            let event = AnthropicApiSubstrate::parse_sse_event(&format!("data: {}", event))
+                .expect("event should parse");
+            out.extend(acc.apply(event));
+        }
+        (acc, out)
+    }
+
+    #[test]
+    fn test_accumulator_folds_text_usage_and_stop_reason() {
+        let (acc, out) = fold(STREAM);
+
+        assert_eq!(acc.text, "Hello é");
+        assert_eq!(acc.input_tokens, 12);
+        assert_eq!(acc.output_tokens, 5);
+        assert_eq!(acc.cache_read_tokens, 8);
+        assert_eq!(acc.stop_reason.as_deref(), Some("max_tokens"));
+        assert!(acc.finished && acc.error.is_none());
+
+        let texts: Vec<_> = out
+            .iter()
+            .filter_map(|o| match o {
+                SubstrateOutput::AssistantText { content } => Some(content.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(texts, ["Hel", "lo é"]);
+        assert!(out.iter().any(|o| matches!(
+            o,
+            SubstrateOutput::TurnComplete {
+                input_tokens: 12,
+                output_tokens: 5,
+                ..
+            }
+        )));
+        assert!(
+            out.iter()
+                .any(|o| matches!(o, SubstrateOutput::Status { .. }))
+        );
+        assert!(matches!(
+            out.last(),
+            Some(SubstrateOutput::SessionComplete { result, .. }) if result == "Hello é"
+        ));
+    }
+
+    #[test]
+    fn test_accumulator_records_mid_stream_error() {
+        let (acc, out) = fold(&[
+            STREAM[0],
+            STREAM[3],
+            r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#,
+        ]);
+
+        assert!(acc.finished);
+        assert_eq!(acc.error.as_deref(), Some("overloaded_error: Overloaded"));
+        assert!(matches!(out.last(), Some(SubstrateOutput::Error { .. })));
+
+        let mut session = SubstrateSession::new(Uuid::new_v4(), "agent", Default::default());
+        acc.finish(&mut session);
+        assert_eq!(session.status, SessionStatus::Failed);
+        assert_eq!(session.input_tokens, 12);
+    }
+
+    #[test]
+    fn test_drain_sse_lines_keeps_partial_line() {
+        let text = "data: caf\u{e9}\ndata: next";
+        let bytes = text.as_bytes();
+        // Split inside the two-byte 'é' so the first chunk ends mid-character.
+        let split = text.find('\u{e9}').unwrap() + 1;
+        let mut buffer = bytes[..split].to_vec();
+        assert!(drain_sse_lines(&mut buffer).is_empty());
+
+        buffer.extend_from_slice(&bytes[split..]);
+        assert_eq!(drain_sse_lines(&mut buffer), ["data: caf\u{e9}"]);
+        assert_eq!(buffer, b"data: next");
+    }
+
+    /// Serve one canned SSE response, written in small pieces so events
+    /// straddle chunk boundaries.
+    async fn serve_sse_once(body: String) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            // Read the headers and the JSON body before answering.
+            loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let len = text[..end]
+                        .lines()
+                        .find_map(|l| {
+                            l.to_ascii_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|v| v.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= end + 4 + len {
+                        break;
+                    }
+                }
+            }
+            socket
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\nconnection: close\r\n\r\n",
+                )
+                .await
+                .unwrap();
+            for piece in body.as_bytes().chunks(37) {
+                socket.write_all(piece).await.unwrap();
+                socket.flush().await.unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_output_streams_incrementally() {
+        let base_url = serve_sse_once(sse_lines(STREAM)).await;
+        let mut config = AnthropicApiConfig::default().with_api_key("test");
+        config.base_url = base_url;
+        let substrate = AnthropicApiSubstrate::new(config).unwrap();
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let request = SubstrateRequest::new(Uuid::new_v4(), "agent", "", "Hi");
+        let session = substrate.execute_with_output(request, tx).await.unwrap();
+
+        assert_eq!(session.status, SessionStatus::Completed);
+        assert_eq!(session.result.as_deref(), Some("Hello é"));
+        assert_eq!(session.input_tokens, 12);
+        assert_eq!(session.output_tokens, 5);
+        assert_eq!(session.cache_read_tokens, 8);
+
+        let mut texts = 0;
+        while let Ok(out) = rx.try_recv() {
+            if matches!(out, SubstrateOutput::AssistantText { .. }) {
+                texts += 1;
+            }
+        }
+        assert_eq!(texts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_truncated_stream_fails_session() {
+        let base_url = serve_sse_once(sse_lines(&STREAM[..5])).await;
+        let mut config = AnthropicApiConfig::default().with_api_key("test");
+        config.base_url = base_url;
+        let substrate = AnthropicApiSubstrate::new(config).unwrap();
+
+        let request = SubstrateRequest::new(Uuid::new_v4(), "agent", "", "Hi");
+        let session = substrate.execute(request).await.unwrap();
+
+        assert_eq!(session.status, SessionStatus::Failed);
+        assert!(session.error.unwrap().contains("message_stop"));
+    }
 }
@@ -13,7 +13,9 @@ use tokio::sync::{RwLock, mpsc};
 use uuid::Uuid;
 
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::models::{SessionStatus, SubstrateOutput, SubstrateRequest, SubstrateSession};
+use crate::domain::models::{
+    SessionStatus, SessionUsage, SubstrateOutput, SubstrateRequest, SubstrateSession,
+};
 use crate::domain::ports::Substrate;
 
 /// Log tee for a single agent task. Wraps the log file with a failed-flag so that
@@ -337,27 +339,6 @@ impl ClaudeCodeSubstrate {
                 let is_max_turns = subtype == "error_max_turns";
                 let is_other_error = is_error_flag || subtype.starts_with("error");
 
-                // Extract final usage if present
-                if let Some(usage) = json.get("usage") {
-                    let input = usage
-                        .get("input_tokens")
-                        .and_then(|t| t.as_u64())
-                        .unwrap_or(0);
-                    let output = usage
-                        .get("output_tokens")
-                        .and_then(|t| t.as_u64())
-                        .unwrap_or(0);
-
-                    // Return usage first, then session complete will follow
-                    if input > 0 || output > 0 {
-                        return Some(SubstrateOutput::TurnComplete {
-                            turn_number: 0,
-                            input_tokens: input,
-                            output_tokens: output,
-                        });
-                    }
-                }
-
                 if is_max_turns {
                     Some(SubstrateOutput::MaxTurnsExceeded { result })
                 } else if is_other_error {
@@ -374,7 +355,12 @@ impl ClaudeCodeSubstrate {
                         message: format!("{}: {}{}", subtype_label, result, turns_suffix),
                     })
                 } else {
-                    Some(SubstrateOutput::SessionComplete { result })
+                    // The result event carries the session totals, so they
+                    // travel with the completion rather than as another turn.
+                    Some(SubstrateOutput::SessionComplete {
+                        result,
+                        usage: Some(Self::result_usage(&json)),
+                    })
                 }
             }
 
@@ -405,6 +391,27 @@ impl ClaudeCodeSubstrate {
         }
     }
 
+    /// Session totals from a stream-json `result` event.
+    fn result_usage(json: &serde_json::Value) -> SessionUsage {
+        let usage = json.get("usage");
+        let tokens = |key: &str| {
+            usage
+                .and_then(|u| u.get(key))
+                .and_then(|t| t.as_u64())
+                .unwrap_or(0)
+        };
+        SessionUsage {
+            input_tokens: tokens("input_tokens"),
+            output_tokens: tokens("output_tokens"),
+            cache_read_tokens: tokens("cache_read_input_tokens"),
+            cache_write_tokens: tokens("cache_creation_input_tokens"),
+            cost_cents: json
+                .get("cost_usd")
+                .and_then(|c| c.as_f64())
+                .map(|cost| cost * 100.0),
+        }
+    }
+
     /// Parse a line of output (handles both JSON and plain text).
     fn parse_output_line(line: &str) -> Option<SubstrateOutput> {
         let trimmed = line.trim();
@@ -459,29 +466,14 @@ impl ClaudeCodeSubstrate {
 
         (input_tokens, output_tokens, cache_read, cache_write)
     }
-}
 
-#[async_trait]
-impl Substrate for ClaudeCodeSubstrate {
-    fn name(&self) -> &'static str {
-        "claude_code"
-    }
-
-    async fn is_available(&self) -> DomainResult<bool> {
-        let output = Command::new(&self.config.binary_path)
-            .arg("--version")
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()
-            .await;
-
-        match output {
-            Ok(out) => Ok(out.status.success()),
-            Err(_) => Ok(false),
-        }
-    }
-
-    async fn execute(&self, request: SubstrateRequest) -> DomainResult<SubstrateSession> {
+    /// Run a session to completion, forwarding assistant text and tool
+    /// activity to `output` as each stream-json line is parsed.
+    async fn run_session(
+        &self,
+        request: SubstrateRequest,
+        output: Option<mpsc::Sender<SubstrateOutput>>,
+    ) -> DomainResult<SubstrateSession> {
         let args = self.build_args(&request, "stream-json");
         let working_dir = request
             .config
@@ -587,6 +579,16 @@ impl Substrate for ClaudeCodeSubstrate {
             }
 
             if let Some(parsed) = Self::parse_output_line(&line) {
+                if let Some(ref tx) = output
+                    && matches!(
+                        parsed,
+                        SubstrateOutput::AssistantText { .. }
+                            | SubstrateOutput::ToolStart { .. }
+                            | SubstrateOutput::ToolResult { .. }
+                    )
+                {
+                    let _ = tx.send(parsed.clone()).await;
+                }
                 match parsed {
                     SubstrateOutput::TurnComplete {
                         input_tokens,
@@ -631,31 +633,20 @@ impl Substrate for ClaudeCodeSubstrate {
         // Extract result from the stream's final "result" event, which contains
         // the result text, usage stats, cost, and turn count.
         let result_text = if let Some(ref json) = result_json {
-            if let Some(usage) = json.get("usage") {
-                total_input_tokens = usage
-                    .get("input_tokens")
-                    .and_then(|t| t.as_u64())
-                    .unwrap_or(total_input_tokens);
-                total_output_tokens = usage
-                    .get("output_tokens")
-                    .and_then(|t| t.as_u64())
-                    .unwrap_or(total_output_tokens);
-                session.cache_read_tokens = usage
-                    .get("cache_read_input_tokens")
-                    .and_then(|t| t.as_u64())
-                    .unwrap_or(0);
-                session.cache_write_tokens = usage
-                    .get("cache_creation_input_tokens")
-                    .and_then(|t| t.as_u64())
-                    .unwrap_or(0);
+            if json.get("usage").is_some() {
+                let usage = Self::result_usage(json);
+                total_input_tokens = usage.input_tokens;
+                total_output_tokens = usage.output_tokens;
+                session.cache_read_tokens = usage.cache_read_tokens;
+                session.cache_write_tokens = usage.cache_write_tokens;
             }
 
             if let Some(num_turns) = json.get("num_turns").and_then(|n| n.as_u64()) {
                 turns = num_turns as u32;
             }
 
-            if let Some(cost) = json.get("cost_usd").and_then(|c| c.as_f64()) {
-                session.cost_cents = Some(cost * 100.0);
+            if let Some(cost) = Self::result_usage(json).cost_cents {
+                session.cost_cents = Some(cost);
             }
 
             json.get("result")
@@ -713,6 +704,39 @@ impl Substrate for ClaudeCodeSubstrate {
 
         Ok(session)
     }
+}
+
+#[async_trait]
+impl Substrate for ClaudeCodeSubstrate {
+    fn name(&self) -> &'static str {
+        "claude_code"
+    }
+
+    async fn is_available(&self) -> DomainResult<bool> {
+        let output = Command::new(&self.config.binary_path)
+            .arg("--version")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await;
+
+        match output {
+            Ok(out) => Ok(out.status.success()),
+            Err(_) => Ok(false),
+        }
+    }
+
+    async fn execute(&self, request: SubstrateRequest) -> DomainResult<SubstrateSession> {
+        self.run_session(request, None).await
+    }
+
+    async fn execute_with_output(
+        &self,
+        request: SubstrateRequest,
+        output: mpsc::Sender<SubstrateOutput>,
+    ) -> DomainResult<SubstrateSession> {
+        self.run_session(request, Some(output)).await
+    }
 
     async fn execute_streaming(
         &self,
@@ -804,6 +828,7 @@ impl Substrate for ClaudeCodeSubstrate {
             let mut total_input = 0u64;
             let mut total_output = 0u64;
             let mut max_turns_exceeded = false;
+            let mut completion: Option<(String, Option<SessionUsage>)> = None;
 
             // Read stdout
             while let Ok(Some(line)) = stdout_lines.next_line().await {
@@ -828,6 +853,9 @@ impl Substrate for ClaudeCodeSubstrate {
                         SubstrateOutput::MaxTurnsExceeded { .. } => {
                             max_turns_exceeded = true;
                         }
+                        SubstrateOutput::SessionComplete { result, usage } => {
+                            completion = Some((result.clone(), usage.clone()));
+                        }
                         _ => {}
                     }
                     if tx.send(output).await.is_err() {
@@ -877,6 +905,15 @@ impl Substrate for ClaudeCodeSubstrate {
                                 {
                                     tracing::warn!(error = ?e, "substrate channel closed before terminal event delivered");
                                 }
+                            } else if let Some((result, usage)) = completion {
+                                // The result event already went out on the
+                                // channel; mirror it onto the stored session.
+                                if let Some(usage) = usage {
+                                    session.record_usage(&usage);
+                                }
+                                if session.status == SessionStatus::Active {
+                                    session.complete(result);
+                                }
                             } else {
                                 if session.status == SessionStatus::Active {
                                     session.complete(all_output.trim());
@@ -884,6 +921,7 @@ impl Substrate for ClaudeCodeSubstrate {
                                 if let Err(e) = tx
                                     .send(SubstrateOutput::SessionComplete {
                                         result: "Completed successfully".to_string(),
+                                        usage: None,
                                     })
                                     .await
                                 {
@@ -1133,10 +1171,27 @@ mod tests {
         let line = r#"{"type":"result","result":"Task completed successfully","cost_usd":0.3,"num_turns":5}"#;
         let output = ClaudeCodeSubstrate::parse_stream_json(line);
         assert!(
-            matches!(output, Some(SubstrateOutput::SessionComplete { result }) if result == "Task completed successfully")
+            matches!(output, Some(SubstrateOutput::SessionComplete { result, .. }) if result == "Task completed successfully")
         );
     }
 
+    #[test]
+    fn test_parse_stream_json_result_carries_usage() {
+        let line = r#"{"type":"result","result":"done","cost_usd":0.25,"usage":{"input_tokens":1200,"output_tokens":300,"cache_read_input_tokens":800,"cache_creation_input_tokens":40}}"#;
+        match ClaudeCodeSubstrate::parse_stream_json(line) {
+            Some(SubstrateOutput::SessionComplete { result, usage }) => {
+                assert_eq!(result, "done");
+                let usage = usage.expect("usage");
+                assert_eq!(usage.input_tokens, 1200);
+                assert_eq!(usage.output_tokens, 300);
+                assert_eq!(usage.cache_read_tokens, 800);
+                assert_eq!(usage.cache_write_tokens, 40);
+                assert_eq!(usage.cost_cents, Some(25.0));
+            }
+            other => panic!("expected SessionComplete, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_parse_stream_json_result_is_error_flag() {
         // A `result` event carrying is_error:true (no max_turns subtype) must not
@@ -1,13 +1,14 @@
 //! Mock substrate for testing.
 
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::{RwLock, mpsc};
 use uuid::Uuid;
 
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::models::{SubstrateOutput, SubstrateRequest, SubstrateSession};
+use crate::domain::models::{SessionUsage, SubstrateOutput, SubstrateRequest, SubstrateSession};
 use crate::domain::ports::Substrate;
 
 /// Mock response configuration.
@@ -25,6 +26,8 @@ pub struct MockResponse {
     pub input_tokens_per_turn: u64,
     /// Output tokens per turn
     pub output_tokens_per_turn: u64,
+    /// Stream `output` and then stall without ever finishing the session
+    pub stall_after_output: bool,
 }
 
 impl Default for MockResponse {
@@ -36,6 +39,7 @@ impl Default for MockResponse {
             turns: 1,
             input_tokens_per_turn: 100,
             output_tokens_per_turn: 50,
+            stall_after_output: false,
         }
     }
 }
@@ -55,6 +59,36 @@ impl MockResponse {
             ..Default::default()
         }
     }
+
+    /// Stream `output` as assistant text, then hang until the caller gives up.
+    pub fn partial_then_stall(output: impl Into<String>) -> Self {
+        Self {
+            output: output.into(),
+            stall_after_output: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Computes a response from the request, e.g. by matching on the prompt.
+type MockResponseFn = Arc<dyn Fn(&SubstrateRequest) -> MockResponse + Send + Sync>;
+
+/// What a scripted mock does once every scripted response has been used.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScriptExhausted {
+    /// Fail the call, so a test notices unexpected extra executions.
+    #[default]
+    Error,
+    /// Keep returning the last scripted response.
+    RepeatLast,
+}
+
+/// Responses handed out in order, one per execution, regardless of task.
+#[derive(Default)]
+struct ResponseScript {
+    remaining: VecDeque<MockResponse>,
+    last: Option<MockResponse>,
+    served: usize,
 }
 
 /// Mock substrate for testing.
@@ -62,15 +96,18 @@ pub struct MockSubstrate {
     sessions: Arc<RwLock<HashMap<Uuid, SubstrateSession>>>,
     default_response: MockResponse,
     response_overrides: Arc<RwLock<HashMap<Uuid, MockResponse>>>,
+    response_queues: Arc<RwLock<HashMap<Uuid, VecDeque<MockResponse>>>>,
+    script: Option<Arc<RwLock<ResponseScript>>>,
+    on_exhausted: ScriptExhausted,
+    response_fn: Option<MockResponseFn>,
+    name: &'static str,
+    available: bool,
+    availability_delay: Duration,
 }
 
 impl MockSubstrate {
     pub fn new() -> Self {
-        Self {
-            sessions: Arc::new(RwLock::new(HashMap::new())),
-            default_response: MockResponse::default(),
-            response_overrides: Arc::new(RwLock::new(HashMap::new())),
-        }
+        Self::with_default_response(MockResponse::default())
     }
 
     pub fn with_default_response(response: MockResponse) -> Self {
@@ -78,55 +115,121 @@ impl MockSubstrate {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             default_response: response,
             response_overrides: Arc::new(RwLock::new(HashMap::new())),
+            response_queues: Arc::new(RwLock::new(HashMap::new())),
+            script: None,
+            on_exhausted: ScriptExhausted::default(),
+            response_fn: None,
+            name: "mock",
+            available: true,
+            availability_delay: Duration::ZERO,
         }
     }
 
-    /// Set a specific response for a task ID.
-    pub async fn set_response_for_task(&self, task_id: Uuid, response: MockResponse) {
-        let mut overrides = self.response_overrides.write().await;
-        overrides.insert(task_id, response);
+    /// Serve `responses` in order, one per execution across all tasks. Once
+    /// they run out, calls fail unless [`Self::on_exhausted`] says otherwise.
+    pub fn with_responses(responses: impl IntoIterator<Item = MockResponse>) -> Self {
+        let mut substrate = Self::new();
+        substrate.script = Some(Arc::new(RwLock::new(ResponseScript {
+            remaining: responses.into_iter().collect(),
+            ..Default::default()
+        })));
+        substrate
     }
 
-    /// Get the response for a task.
-    async fn get_response(&self, task_id: Uuid) -> MockResponse {
-        let overrides = self.response_overrides.read().await;
-        overrides
-            .get(&task_id)
-            .cloned()
-            .unwrap_or_else(|| self.default_response.clone())
+    /// Compute each response from its request, e.g. to answer reviewers and
+    /// implementers differently.
+    pub fn with_response_fn(
+        f: impl Fn(&SubstrateRequest) -> MockResponse + Send + Sync + 'static,
+    ) -> Self {
+        let mut substrate = Self::new();
+        substrate.response_fn = Some(Arc::new(f));
+        substrate
     }
 
-    /// Get all completed sessions.
-    pub async fn get_all_sessions(&self) -> Vec<SubstrateSession> {
-        let sessions = self.sessions.read().await;
-        sessions.values().cloned().collect()
+    /// Choose what a scripted mock does after its last response.
+    pub fn on_exhausted(mut self, behavior: ScriptExhausted) -> Self {
+        self.on_exhausted = behavior;
+        self
     }
 
-    /// Clear all sessions.
-    pub async fn clear(&self) {
-        let mut sessions = self.sessions.write().await;
-        sessions.clear();
+    /// Report `name` instead of `"mock"`, e.g. to tell several mocks apart.
+    pub fn with_name(mut self, name: &'static str) -> Self {
+        self.name = name;
+        self
     }
-}
 
-impl Default for MockSubstrate {
-    fn default() -> Self {
-        Self::new()
+    /// Set what availability checks report, after waiting `delay`.
+    pub fn with_availability(mut self, available: bool, delay: Duration) -> Self {
+        self.available = available;
+        self.availability_delay = delay;
+        self
     }
-}
 
-#[async_trait]
-impl Substrate for MockSubstrate {
-    fn name(&self) -> &'static str {
-        "mock"
+    /// Set a specific response for a task ID.
+    pub async fn set_response_for_task(&self, task_id: Uuid, response: MockResponse) {
+        let mut overrides = self.response_overrides.write().await;
+        overrides.insert(task_id, response);
     }
 
-    async fn is_available(&self) -> DomainResult<bool> {
-        Ok(true)
+    /// Queue responses for successive executions of a task (e.g. across
+    /// retries). Once the queue is drained, the task-specific or default
+    /// response applies again.
+    pub async fn queue_responses_for_task(
+        &self,
+        task_id: Uuid,
+        responses: impl IntoIterator<Item = MockResponse>,
+    ) {
+        let mut queues = self.response_queues.write().await;
+        queues.entry(task_id).or_default().extend(responses);
     }
 
-    async fn execute(&self, request: SubstrateRequest) -> DomainResult<SubstrateSession> {
-        let response = self.get_response(request.task_id).await;
+    /// Get the response for a request. Task-specific queues and overrides
+    /// win over the response function and script, which win over the default.
+    async fn get_response(&self, request: &SubstrateRequest) -> DomainResult<MockResponse> {
+        let task_id = request.task_id;
+        if let Some(response) = self
+            .response_queues
+            .write()
+            .await
+            .get_mut(&task_id)
+            .and_then(VecDeque::pop_front)
+        {
+            return Ok(response);
+        }
+        if let Some(response) = self.response_overrides.read().await.get(&task_id) {
+            return Ok(response.clone());
+        }
+        if let Some(ref f) = self.response_fn {
+            return Ok(f(request));
+        }
+        let Some(ref script) = self.script else {
+            return Ok(self.default_response.clone());
+        };
+
+        let mut script = script.write().await;
+        if let Some(response) = script.remaining.pop_front() {
+            script.served += 1;
+            script.last = Some(response.clone());
+            return Ok(response);
+        }
+        match (self.on_exhausted, &script.last) {
+            (ScriptExhausted::RepeatLast, Some(last)) => Ok(last.clone()),
+            _ => Err(DomainError::SubstrateError(format!(
+                "MockSubstrate script exhausted after {} responses",
+                script.served
+            ))),
+        }
+    }
+
+    /// Simulate a session that plays out `response`.
+    async fn run_session(
+        &self,
+        request: SubstrateRequest,
+        response: MockResponse,
+    ) -> DomainResult<SubstrateSession> {
+        if response.stall_after_output {
+            return std::future::pending().await;
+        }
         let mut session =
             SubstrateSession::new(request.task_id, &request.agent_template, request.config);
 
@@ -158,11 +261,65 @@ impl Substrate for MockSubstrate {
         Ok(session)
     }
 
+    /// Get all completed sessions.
+    pub async fn get_all_sessions(&self) -> Vec<SubstrateSession> {
+        let sessions = self.sessions.read().await;
+        sessions.values().cloned().collect()
+    }
+
+    /// Clear all sessions.
+    pub async fn clear(&self) {
+        let mut sessions = self.sessions.write().await;
+        sessions.clear();
+    }
+}
+
+impl Default for MockSubstrate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Substrate for MockSubstrate {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn is_available(&self) -> DomainResult<bool> {
+        if !self.availability_delay.is_zero() {
+            tokio::time::sleep(self.availability_delay).await;
+        }
+        Ok(self.available)
+    }
+
+    async fn execute(&self, request: SubstrateRequest) -> DomainResult<SubstrateSession> {
+        let response = self.get_response(&request).await?;
+        self.run_session(request, response).await
+    }
+
+    async fn execute_with_output(
+        &self,
+        request: SubstrateRequest,
+        output: mpsc::Sender<SubstrateOutput>,
+    ) -> DomainResult<SubstrateSession> {
+        let response = self.get_response(&request).await?;
+        if !response.fail {
+            let _ = output
+                .send(SubstrateOutput::AssistantText {
+                    content: response.output.clone(),
+                })
+                .await;
+        }
+        drop(output);
+        self.run_session(request, response).await
+    }
+
     async fn execute_streaming(
         &self,
         request: SubstrateRequest,
     ) -> DomainResult<(mpsc::Receiver<SubstrateOutput>, SubstrateSession)> {
-        let response = self.get_response(request.task_id).await;
+        let response = self.get_response(&request).await?;
         let mut session =
             SubstrateSession::new(request.task_id, &request.agent_template, request.config);
         session.start(None);
@@ -184,7 +341,15 @@ impl Substrate for MockSubstrate {
             }
 
             // Send result
-            if response_clone.fail {
+            if response_clone.stall_after_output {
+                let _ = tx
+                    .send(SubstrateOutput::AssistantText {
+                        content: response_clone.output,
+                    })
+                    .await;
+                // Hold the channel open until the receiver is dropped.
+                tx.closed().await;
+            } else if response_clone.fail {
                 let _ = tx
                     .send(SubstrateOutput::Error {
                         message: response_clone
@@ -201,6 +366,13 @@ impl Substrate for MockSubstrate {
                 let _ = tx
                     .send(SubstrateOutput::SessionComplete {
                         result: response_clone.output,
+                        usage: Some(SessionUsage {
+                            input_tokens: response_clone.input_tokens_per_turn
+                                * u64::from(response_clone.turns),
+                            output_tokens: response_clone.output_tokens_per_turn
+                                * u64::from(response_clone.turns),
+                            ..Default::default()
+                        }),
                     })
                     .await;
             }
@@ -248,6 +420,16 @@ impl Substrate for MockSubstrate {
         Ok(())
     }
 
+    async fn terminate_by_task_id(&self, task_id: Uuid) -> DomainResult<()> {
+        let mut sessions = self.sessions.write().await;
+        for session in sessions.values_mut() {
+            if session.task_id == task_id && !session.status.is_terminal() {
+                session.terminate();
+            }
+        }
+        Ok(())
+    }
+
     async fn get_session(&self, session_id: Uuid) -> DomainResult<Option<SubstrateSession>> {
         let sessions = self.sessions.read().await;
         Ok(sessions.get(&session_id).cloned())
@@ -328,4 +510,75 @@ mod tests {
 
         assert!(!events.is_empty());
     }
+
+    fn request() -> SubstrateRequest {
+        SubstrateRequest::new(Uuid::new_v4(), "test-agent", "System prompt", "User prompt")
+    }
+
+    #[tokio::test]
+    async fn test_mock_scripted_responses_in_order() {
+        let substrate = MockSubstrate::with_responses([
+            MockResponse::failure("review rejected"),
+            MockResponse::success("fixed"),
+        ]);
+
+        let first = substrate.execute(request()).await.unwrap();
+        assert_eq!(first.status, SessionStatus::Failed);
+        assert_eq!(first.error.as_deref(), Some("review rejected"));
+
+        // The script is shared across tasks and entry points.
+        let (mut rx, _) = substrate.execute_streaming(request()).await.unwrap();
+        let mut result = None;
+        while let Some(event) = rx.recv().await {
+            if let SubstrateOutput::SessionComplete { result: r, .. } = event {
+                result = Some(r);
+            }
+        }
+        assert_eq!(result.as_deref(), Some("fixed"));
+
+        let err = substrate.execute(request()).await.unwrap_err();
+        assert!(err.to_string().contains("exhausted after 2 responses"));
+    }
+
+    #[tokio::test]
+    async fn test_mock_scripted_responses_repeat_last() {
+        let substrate = MockSubstrate::with_responses([MockResponse::success("only")])
+            .on_exhausted(ScriptExhausted::RepeatLast);
+
+        for _ in 0..3 {
+            let session = substrate.execute(request()).await.unwrap();
+            assert_eq!(session.result.as_deref(), Some("only"));
+        }
+
+        // An empty script has nothing to repeat.
+        let empty = MockSubstrate::with_responses([]).on_exhausted(ScriptExhausted::RepeatLast);
+        assert!(empty.execute(request()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mock_response_fn_matches_prompt() {
+        let substrate = MockSubstrate::with_response_fn(|req| {
+            if req.user_prompt.contains("review") {
+                MockResponse::success("LGTM")
+            } else {
+                MockResponse::success("implemented")
+            }
+        });
+
+        let mut review = request();
+        review.user_prompt = "Please review the diff".to_string();
+        let session = substrate.execute(review).await.unwrap();
+        assert_eq!(session.result.as_deref(), Some("LGTM"));
+
+        let session = substrate.execute(request()).await.unwrap();
+        assert_eq!(session.result.as_deref(), Some("implemented"));
+
+        // Task-specific overrides still take precedence.
+        let pinned = request();
+        substrate
+            .set_response_for_task(pinned.task_id, MockResponse::failure("pinned"))
+            .await;
+        let session = substrate.execute(pinned).await.unwrap();
+        assert_eq!(session.status, SessionStatus::Failed);
+    }
 }
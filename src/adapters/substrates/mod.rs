@@ -8,4 +8,4 @@ pub mod registry;
 pub use anthropic_api::{AnthropicApiConfig, AnthropicApiSubstrate};
 pub use claude_code::ClaudeCodeSubstrate;
 pub use mock::MockSubstrate;
-pub use registry::SubstrateRegistry;
+pub use registry::{HealthStatus, RoutingPolicy, SubstrateRegistry};
@@ -1,16 +1,71 @@
 //! Substrate registry and factory.
 
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
 use crate::domain::models::SubstrateType;
 use crate::domain::ports::{Substrate, SubstrateFactory};
+use crate::services::{CircuitBreakerService, CircuitScope};
 
 use super::anthropic_api::{AnthropicApiConfig, AnthropicApiSubstrate};
 use super::claude_code::{ClaudeCodeConfig, ClaudeCodeSubstrate};
 use super::mock::MockSubstrate;
 
+/// Result of a substrate's availability check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// The substrate reported itself available.
+    Healthy,
+    /// The substrate reported itself unavailable (e.g. no binary or API key).
+    Unavailable,
+    /// The check itself failed.
+    Error(String),
+    /// The check did not finish within the registry's health-check timeout.
+    TimedOut,
+}
+
+impl HealthStatus {
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, Self::Healthy)
+    }
+}
+
+/// How [`SubstrateRegistry::select`] spreads spawns over the registered
+/// substrates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingPolicy {
+    /// Pick substrates in proportion to their weights, interleaved rather
+    /// than in bursts (smooth weighted round-robin).
+    WeightedRoundRobin,
+    /// Pick the substrate that was picked longest ago; ones never picked go
+    /// first, in registration order.
+    LeastRecentlyUsed,
+}
+
+/// Bookkeeping behind [`SubstrateRegistry::select`], keyed by substrate name.
+#[derive(Debug, Default)]
+struct SelectionState {
+    /// Smooth weighted round-robin running weights.
+    current: HashMap<String, i64>,
+    /// Selection sequence number each substrate was last picked at.
+    last_used: HashMap<String, u64>,
+    sequence: u64,
+}
+
 /// Registry of available substrates.
 pub struct SubstrateRegistry {
     claude_code_config: Option<ClaudeCodeConfig>,
     anthropic_api_config: Option<AnthropicApiConfig>,
+    /// Substrates registered alongside the built-in ones.
+    substrates: Vec<Arc<dyn Substrate>>,
+    health_check_timeout: Duration,
+    /// Selection weights by substrate name; unlisted substrates weigh 1.
+    weights: HashMap<String, u32>,
+    selection: Mutex<SelectionState>,
 }
 
 impl SubstrateRegistry {
@@ -18,6 +73,10 @@ impl SubstrateRegistry {
         Self {
             claude_code_config: Some(ClaudeCodeConfig::default()),
             anthropic_api_config: Some(AnthropicApiConfig::default()),
+            substrates: Vec::new(),
+            health_check_timeout: Duration::from_secs(10),
+            weights: HashMap::new(),
+            selection: Mutex::default(),
         }
     }
 
@@ -31,6 +90,82 @@ impl SubstrateRegistry {
         self
     }
 
+    /// Register an additional substrate to include in health checks.
+    pub fn with_substrate(mut self, substrate: Arc<dyn Substrate>) -> Self {
+        self.substrates.push(substrate);
+        self
+    }
+
+    /// Weight `name` for [`Self::select`]. A weight of zero keeps the
+    /// substrate out of selection entirely.
+    pub fn with_weight(mut self, name: impl Into<String>, weight: u32) -> Self {
+        self.weights.insert(name.into(), weight);
+        self
+    }
+
+    /// How long each substrate's availability check may take before it
+    /// counts as [`HealthStatus::TimedOut`].
+    pub fn with_health_check_timeout(mut self, timeout: Duration) -> Self {
+        self.health_check_timeout = timeout;
+        self
+    }
+
+    /// Check every configured built-in substrate and every registered one
+    /// concurrently, keyed by substrate name.
+    ///
+    /// Unlike [`Self::create_by_type`], an Anthropic API substrate that cannot
+    /// be constructed is reported as an error rather than replaced by Claude
+    /// Code. The mock substrate is only checked if registered.
+    pub async fn health_check_all(&self) -> HashMap<String, HealthStatus> {
+        let mut statuses = HashMap::new();
+        let mut candidates = self.substrates.clone();
+        if let Some(config) = &self.claude_code_config {
+            candidates.push(Arc::new(ClaudeCodeSubstrate::new(config.clone())));
+        }
+        if let Some(config) = &self.anthropic_api_config {
+            match AnthropicApiSubstrate::new(config.clone()) {
+                Ok(substrate) => candidates.push(Arc::new(substrate)),
+                Err(e) => {
+                    statuses.insert(
+                        SubstrateType::AnthropicApi.as_str().to_string(),
+                        HealthStatus::Error(e.to_string()),
+                    );
+                }
+            }
+        }
+
+        let checks = candidates.iter().map(|substrate| async move {
+            let status =
+                match tokio::time::timeout(self.health_check_timeout, substrate.is_available())
+                    .await
+                {
+                    Ok(Ok(true)) => HealthStatus::Healthy,
+                    Ok(Ok(false)) => HealthStatus::Unavailable,
+                    Ok(Err(e)) => HealthStatus::Error(e.to_string()),
+                    Err(_) => HealthStatus::TimedOut,
+                };
+            (substrate.name().to_string(), status)
+        });
+        statuses.extend(futures::future::join_all(checks).await);
+        statuses
+    }
+
+    /// Whether at least one substrate passes its health check.
+    pub async fn any_healthy(&self) -> bool {
+        self.health_check_all()
+            .await
+            .values()
+            .any(HealthStatus::is_healthy)
+    }
+
+    /// Whether every substrate passes its health check.
+    pub async fn all_healthy(&self) -> bool {
+        self.health_check_all()
+            .await
+            .values()
+            .all(HealthStatus::is_healthy)
+    }
+
     /// Create a substrate by type.
     pub fn create_by_type(&self, substrate_type: SubstrateType) -> Box<dyn Substrate> {
         match substrate_type {
@@ -62,6 +197,99 @@ impl SubstrateRegistry {
     pub fn mock_substrate() -> Box<dyn Substrate> {
         Box::new(MockSubstrate::new())
     }
+
+    /// Pick the first of `candidates` whose substrate circuit is not open.
+    ///
+    /// `candidates` is the primary followed by its configured failovers, in
+    /// preference order. Returns `None` when every circuit is open.
+    pub async fn select_healthy(
+        candidates: &[Arc<dyn Substrate>],
+        circuit_breaker: &CircuitBreakerService,
+    ) -> Option<Arc<dyn Substrate>> {
+        for substrate in candidates {
+            let check = circuit_breaker
+                .check(CircuitScope::substrate(substrate.name()))
+                .await;
+            if check.is_allowed() {
+                return Some(substrate.clone());
+            }
+        }
+        None
+    }
+
+    /// Pick one of the registered substrates under `policy`.
+    ///
+    /// Substrates with a weight of zero or an open circuit are skipped, and
+    /// a skipped substrate does not build up credit while it is out. Only the
+    /// picked substrate's circuit is checked, so candidates that lose the
+    /// pick keep their half-open probe slots. Returns `None` when nothing is
+    /// eligible.
+    pub async fn select(
+        &self,
+        policy: RoutingPolicy,
+        circuit_breaker: &CircuitBreakerService,
+    ) -> Option<Arc<dyn Substrate>> {
+        let mut eligible = Vec::new();
+        for substrate in &self.substrates {
+            let weight = self.weights.get(substrate.name()).copied().unwrap_or(1);
+            if weight == 0 {
+                continue;
+            }
+            if circuit_breaker
+                .would_allow(&CircuitScope::substrate(substrate.name()))
+                .await
+            {
+                eligible.push((substrate, i64::from(weight)));
+            }
+        }
+
+        while let Some(picked) = self.pick(policy, &eligible) {
+            let check = circuit_breaker
+                .check(CircuitScope::substrate(picked.name()))
+                .await;
+            if check.is_allowed() {
+                return Some(picked);
+            }
+            // A concurrent caller took its last probe slot; pick again.
+            eligible.retain(|(substrate, _)| substrate.name() != picked.name());
+        }
+        None
+    }
+
+    fn pick(
+        &self,
+        policy: RoutingPolicy,
+        eligible: &[(&Arc<dyn Substrate>, i64)],
+    ) -> Option<Arc<dyn Substrate>> {
+        let mut state = self.selection.lock().unwrap_or_else(|e| e.into_inner());
+        let picked = match policy {
+            RoutingPolicy::WeightedRoundRobin => {
+                let total: i64 = eligible.iter().map(|(_, w)| w).sum();
+                let mut best: Option<(&Arc<dyn Substrate>, i64)> = None;
+                for (substrate, weight) in eligible {
+                    let current = state
+                        .current
+                        .entry(substrate.name().to_string())
+                        .or_default();
+                    *current += weight;
+                    if best.is_none_or(|(_, b)| *current > b) {
+                        best = Some((substrate, *current));
+                    }
+                }
+                let (picked, _) = best?;
+                *state.current.get_mut(picked.name())? -= total;
+                picked
+            }
+            RoutingPolicy::LeastRecentlyUsed => eligible
+                .iter()
+                .map(|(substrate, _)| *substrate)
+                .min_by_key(|s| state.last_used.get(s.name()).copied().unwrap_or(0))?,
+        };
+        state.sequence += 1;
+        let sequence = state.sequence;
+        state.last_used.insert(picked.name().to_string(), sequence);
+        Some(picked.clone())
+    }
 }
 
 impl Default for SubstrateRegistry {
@@ -117,4 +345,241 @@ mod tests {
         assert!(types.contains(&"claude_code"));
         assert!(types.contains(&"mock"));
     }
+
+    #[tokio::test]
+    async fn test_select_healthy_fails_over_when_primary_circuit_open() {
+        use crate::domain::models::{SessionStatus, SubstrateRequest};
+        use crate::services::CircuitBreakerConfig;
+        use uuid::Uuid;
+
+        let registry = SubstrateRegistry::new();
+        let candidates: Vec<Arc<dyn Substrate>> = vec![
+            Arc::from(registry.create_by_type(SubstrateType::ClaudeCode)),
+            Arc::from(registry.create_by_type(SubstrateType::Mock)),
+        ];
+        let breaker = CircuitBreakerService::new(CircuitBreakerConfig::sensitive());
+
+        let selected = SubstrateRegistry::select_healthy(&candidates, &breaker)
+            .await
+            .unwrap();
+        assert_eq!(selected.name(), "claude_code");
+
+        for _ in 0..3 {
+            breaker
+                .record_failure(CircuitScope::substrate("claude_code"), "spawn failed")
+                .await;
+        }
+
+        let selected = SubstrateRegistry::select_healthy(&candidates, &breaker)
+            .await
+            .unwrap();
+        assert_eq!(selected.name(), "mock");
+        let session = selected
+            .execute(SubstrateRequest::new(Uuid::new_v4(), "coder", "system", "task"))
+            .await
+            .unwrap();
+        assert_eq!(session.status, SessionStatus::Completed);
+
+        for _ in 0..3 {
+            breaker
+                .record_failure(CircuitScope::substrate("mock"), "spawn failed")
+                .await;
+        }
+        assert!(
+            SubstrateRegistry::select_healthy(&candidates, &breaker)
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_check_all_reports_mixed_substrates() {
+        let timeout = Duration::from_millis(50);
+        let registry = SubstrateRegistry {
+            claude_code_config: Some(ClaudeCodeConfig {
+                binary_path: "/nonexistent/claude".to_string(),
+                ..Default::default()
+            }),
+            anthropic_api_config: None,
+            substrates: Vec::new(),
+            health_check_timeout: timeout,
+            weights: HashMap::new(),
+            selection: Mutex::default(),
+        }
+        .with_substrate(Arc::new(MockSubstrate::new()))
+        .with_substrate(Arc::new(
+            MockSubstrate::new()
+                .with_name("offline")
+                .with_availability(false, Duration::ZERO),
+        ))
+        .with_substrate(Arc::new(
+            MockSubstrate::new()
+                .with_name("hung")
+                .with_availability(true, Duration::from_secs(5)),
+        ));
+
+        let started = std::time::Instant::now();
+        let statuses = registry.health_check_all().await;
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(statuses.len(), 4);
+        assert_eq!(statuses["mock"], HealthStatus::Healthy);
+        assert_eq!(statuses["offline"], HealthStatus::Unavailable);
+        assert_eq!(statuses["hung"], HealthStatus::TimedOut);
+        assert_eq!(statuses["claude_code"], HealthStatus::Unavailable);
+
+        assert!(registry.any_healthy().await);
+        assert!(!registry.all_healthy().await);
+
+        let unreachable = SubstrateRegistry {
+            claude_code_config: None,
+            anthropic_api_config: None,
+            substrates: Vec::new(),
+            health_check_timeout: timeout,
+            weights: HashMap::new(),
+            selection: Mutex::default(),
+        }
+        .with_substrate(Arc::new(
+            MockSubstrate::new().with_availability(false, Duration::ZERO),
+        ));
+        assert!(!unreachable.any_healthy().await);
+    }
+
+    fn pool(weights: &[(&'static str, u32)]) -> SubstrateRegistry {
+        weights
+            .iter()
+            .fold(SubstrateRegistry::new(), |registry, (name, weight)| {
+                registry
+                    .with_substrate(Arc::new(MockSubstrate::new().with_name(name)))
+                    .with_weight(*name, *weight)
+            })
+    }
+
+    async fn pick_counts(
+        registry: &SubstrateRegistry,
+        policy: RoutingPolicy,
+        breaker: &CircuitBreakerService,
+        picks: usize,
+    ) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for _ in 0..picks {
+            let substrate = registry.select(policy, breaker).await.unwrap();
+            *counts.entry(substrate.name().to_string()).or_default() += 1;
+        }
+        counts
+    }
+
+    #[tokio::test]
+    async fn test_select_weighted_round_robin_distribution() {
+        use crate::services::CircuitBreakerConfig;
+
+        let registry = pool(&[("api", 3), ("cli", 1), ("off", 0)]);
+        let breaker = CircuitBreakerService::new(CircuitBreakerConfig::sensitive());
+
+        let counts = pick_counts(&registry, RoutingPolicy::WeightedRoundRobin, &breaker, 400).await;
+        assert_eq!(counts["api"], 300);
+        assert_eq!(counts["cli"], 100);
+        assert!(!counts.contains_key("off"));
+
+        // Picks are interleaved, not bursts of the heavier backend.
+        let mut sequence = Vec::new();
+        for _ in 0..8 {
+            let picked = registry
+                .select(RoutingPolicy::WeightedRoundRobin, &breaker)
+                .await
+                .unwrap();
+            sequence.push(picked.name().to_string());
+        }
+        assert_eq!(
+            sequence,
+            ["api", "api", "cli", "api", "api", "api", "cli", "api"]
+        );
+
+        // An open circuit takes a backend out until it recovers.
+        for _ in 0..3 {
+            breaker
+                .record_failure(CircuitScope::substrate("api"), "spawn failed")
+                .await;
+        }
+        let counts = pick_counts(&registry, RoutingPolicy::WeightedRoundRobin, &breaker, 50).await;
+        assert_eq!(counts["cli"], 50);
+
+        for _ in 0..3 {
+            breaker
+                .record_failure(CircuitScope::substrate("cli"), "spawn failed")
+                .await;
+        }
+        assert!(
+            registry
+                .select(RoutingPolicy::WeightedRoundRobin, &breaker)
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_spends_a_probe_only_on_the_picked_substrate() {
+        use crate::services::{CircuitBreakerConfig, CircuitState};
+
+        let registry = pool(&[("a", 1), ("b", 1)]);
+        let breaker = CircuitBreakerService::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_timeout: chrono::Duration::milliseconds(200),
+            half_open_max_probes: 2,
+            ..CircuitBreakerConfig::sensitive()
+        });
+        for name in ["a", "b"] {
+            breaker
+                .record_failure(CircuitScope::substrate(name), "spawn failed")
+                .await;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        // One probe each moves both to half-open with one probe left.
+        for name in ["a", "b"] {
+            let scope = CircuitScope::substrate(name);
+            assert!(breaker.check(scope.clone()).await.is_allowed());
+            assert_eq!(
+                breaker.get_state(&scope).await,
+                Some(CircuitState::HalfOpen)
+            );
+        }
+
+        let picked = registry
+            .select(RoutingPolicy::WeightedRoundRobin, &breaker)
+            .await
+            .unwrap();
+        let other = if picked.name() == "a" { "b" } else { "a" };
+        assert!(
+            breaker
+                .check(CircuitScope::substrate(other))
+                .await
+                .is_allowed()
+        );
+        assert!(
+            breaker
+                .check(CircuitScope::substrate(picked.name()))
+                .await
+                .is_blocked()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_least_recently_used_rotates() {
+        let registry = pool(&[("a", 5), ("b", 1), ("c", 1), ("off", 0)]);
+        let breaker = CircuitBreakerService::with_defaults();
+
+        let mut sequence = Vec::new();
+        for _ in 0..6 {
+            let picked = registry
+                .select(RoutingPolicy::LeastRecentlyUsed, &breaker)
+                .await
+                .unwrap();
+            sequence.push(picked.name().to_string());
+        }
+        // Weights only matter for exclusion; every live backend takes turns.
+        assert_eq!(sequence, ["a", "b", "c", "a", "b", "c"]);
+
+        let counts = pick_counts(&registry, RoutingPolicy::LeastRecentlyUsed, &breaker, 300).await;
+        assert_eq!(counts.len(), 3);
+        assert!(counts.values().all(|&n| n == 100));
+    }
 }
@@ -6,7 +6,8 @@ use std::sync::Arc;
 use sqlx::SqlitePool;
 
 use crate::adapters::sqlite::{
-    SqliteMemoryRepository, SqliteTaskRepository, goal_repository::SqliteGoalRepository,
+    SqliteMemoryRepository, SqliteMergeRequestRepository, SqliteTaskRepository,
+    SqliteWorktreeRepository, goal_repository::SqliteGoalRepository,
 };
 use crate::services::command_bus::{
     CommandBus, CommandEnvelope, CommandError, CommandResult, CommandSource, DomainCommand,
@@ -30,11 +31,20 @@ impl CliCommandDispatcher {
         let goal_repo = Arc::new(SqliteGoalRepository::new(pool.clone()));
         let memory_repo = Arc::new(SqliteMemoryRepository::new(pool.clone()));
 
-        let task_service = Arc::new(TaskService::new(task_repo));
+        let task_service = Arc::new(
+            TaskService::new(task_repo)
+                .with_worktree_repo(Arc::new(SqliteWorktreeRepository::new(pool.clone())))
+                .with_merge_request_repo(Arc::new(SqliteMergeRequestRepository::new(pool.clone()))),
+        );
         let goal_service = Arc::new(GoalService::new(goal_repo));
-        let memory_service = Arc::new(MemoryService::new(memory_repo));
-        let maintenance_service =
-            Arc::new(MemoryMaintenanceService::from_memory_service(memory_service));
+        let memory_config = crate::services::config::Config::load()
+            .unwrap_or_default()
+            .memory;
+        let memory_service =
+            Arc::new(MemoryService::new(memory_repo).with_memory_config(&memory_config));
+        let maintenance_service = Arc::new(MemoryMaintenanceService::from_memory_service(
+            memory_service,
+        ));
 
         let outbox_repo = Arc::new(crate::adapters::sqlite::SqliteOutboxRepository::new(
             pool.clone(),
@@ -48,6 +58,11 @@ impl CliCommandDispatcher {
         Self { command_bus }
     }
 
+    /// The underlying bus, for services that dispatch their own envelopes.
+    pub fn command_bus(&self) -> &Arc<CommandBus> {
+        &self.command_bus
+    }
+
     /// Dispatch a domain command with `CommandSource::Human`.
     pub async fn dispatch(&self, cmd: DomainCommand) -> Result<CommandResult, CommandError> {
         let envelope = CommandEnvelope::new(CommandSource::Human, cmd);
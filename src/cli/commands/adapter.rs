@@ -11,11 +11,15 @@ use tokio::fs;
 
 use crate::adapters::plugins::{KNOWN_ADAPTERS, KnownAdapter, find_known_adapter};
 use crate::cli::display::{
-    CommandOutput, DetailView, list_table, output, render_list, truncate_ellipsis,
+    CommandOutput, DetailView, colorize_status, list_table, output, relative_time, render_list,
+    truncate_ellipsis,
 };
 use crate::domain::models::adapter::{AdapterManifest, EgressAction, EgressResult};
+use crate::services::adapter_health::{AdapterStatus, check_adapter_statuses};
 use crate::services::adapter_loader::find_missing_env_vars;
+use crate::services::adapter_registry::AdapterRegistry;
 use crate::services::config::AdapterConfig;
+use crate::services::event_bus::{EventCategory, UnifiedEvent};
 
 #[derive(Args, Debug)]
 pub struct AdapterArgs {
@@ -54,6 +58,8 @@ pub enum AdapterCommands {
         /// Adapter name (omit to check all enabled adapters)
         name: Option<String>,
     },
+    /// Report live connectivity, last successful poll, and pending egress per adapter
+    Status,
     /// Publish an egress action through an adapter (e.g., update status, post comment)
     Publish {
         /// Adapter name (e.g., "github-issues", "clickup")
@@ -249,6 +255,42 @@ impl CommandOutput for AdapterDoctorOutput {
     }
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct AdapterStatusOutput {
+    pub adapters: Vec<AdapterStatus>,
+    pub all_healthy: bool,
+}
+
+impl CommandOutput for AdapterStatusOutput {
+    fn to_human(&self) -> String {
+        if self.adapters.is_empty() {
+            return "No adapters loaded.".to_string();
+        }
+
+        let mut table = list_table(&["Name", "Health", "Last Poll", "Pending Egress", "Error"]);
+
+        for a in &self.adapters {
+            let health = if a.healthy { "healthy" } else { "unhealthy" };
+            table.add_row(vec![
+                a.name.clone(),
+                colorize_status(health).to_string(),
+                a.last_successful_poll
+                    .as_ref()
+                    .map(relative_time)
+                    .unwrap_or_else(|| "-".to_string()),
+                a.pending_egress.to_string(),
+                truncate_ellipsis(a.error.as_deref().unwrap_or("-"), 60),
+            ]);
+        }
+
+        render_list("adapter", table, self.adapters.len())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct AdapterPublishOutput {
     pub success: bool,
@@ -302,6 +344,7 @@ pub async fn execute(args: AdapterArgs, json_mode: bool) -> Result<()> {
         AdapterCommands::Doctor { name } => {
             cmd_doctor(&adapters_dir, name.as_deref(), json_mode).await
         }
+        AdapterCommands::Status => cmd_status(&adapters_dir, json_mode).await,
         AdapterCommands::Publish { adapter, action } => {
             cmd_publish(&adapters_dir, &adapter, &action, json_mode).await
         }
@@ -343,6 +386,37 @@ fn env_var_status(known: &KnownAdapter) -> String {
     }
 }
 
+/// Load every enabled adapter under `adapters_dir` into a registry.
+async fn load_registry(adapters_dir: &Path) -> AdapterRegistry {
+    let loaded_adapters = crate::services::adapter_loader::load_adapters(
+        adapters_dir.parent().unwrap_or(adapters_dir),
+    )
+    .await;
+    let prompt_content = crate::services::adapter_loader::collect_prompt_content(&loaded_adapters);
+    AdapterRegistry::from_loaded(loaded_adapters, prompt_content)
+}
+
+/// Recent adapter events from the project database, or none if the project
+/// has no database yet.
+async fn recent_adapter_events() -> Result<Vec<UnifiedEvent>> {
+    use crate::adapters::sqlite::{SqliteEventRepository, initialize_default_database};
+    use crate::services::event_store::{EventQuery, EventStore};
+
+    if !Path::new(".abathur/abathur.db").exists() {
+        return Ok(Vec::new());
+    }
+    let pool = initialize_default_database()
+        .await
+        .context("Failed to initialize database")?;
+    let store =
+        SqliteEventRepository::new(pool, crate::services::crypto::load_encryptor_from_env());
+    let query = EventQuery::new()
+        .category(EventCategory::Adapter)
+        .limit(1000)
+        .descending();
+    Ok(store.query(query).await?)
+}
+
 /// Read and parse an adapter.toml manifest from disk.
 fn read_manifest(adapters_dir: &Path, name: &str) -> Result<AdapterManifest> {
     let manifest_path = adapters_dir.join(name).join("adapter.toml");
@@ -645,6 +719,27 @@ async fn cmd_doctor(adapters_dir: &Path, name: Option<&str>, json_mode: bool) ->
     Ok(())
 }
 
+async fn cmd_status(adapters_dir: &Path, json_mode: bool) -> Result<()> {
+    let registry = load_registry(adapters_dir).await;
+    if !registry.has_adapters() {
+        bail!("No enabled adapters found. Enable one with: abathur adapter enable <name>");
+    }
+
+    let events = recent_adapter_events().await?;
+    let out = status_output(&registry, &events).await;
+    output(&out, json_mode);
+    Ok(())
+}
+
+async fn status_output(registry: &AdapterRegistry, events: &[UnifiedEvent]) -> AdapterStatusOutput {
+    let adapters = check_adapter_statuses(registry, events).await;
+    let all_healthy = adapters.iter().all(|a| a.healthy);
+    AdapterStatusOutput {
+        adapters,
+        all_healthy,
+    }
+}
+
 async fn cmd_publish(
     adapters_dir: &Path,
     adapter_name: &str,
@@ -655,16 +750,7 @@ async fn cmd_publish(
     let action: EgressAction = serde_json::from_str(action_json)
         .with_context(|| "Failed to parse action JSON. Expected format: {\"action\":\"update_status\",\"external_id\":\"...\",\"new_status\":\"...\"}".to_string())?;
 
-    // Load the adapter registry
-    let loaded_adapters = crate::services::adapter_loader::load_adapters(
-        adapters_dir.parent().unwrap_or(adapters_dir),
-    )
-    .await;
-    let prompt_content = crate::services::adapter_loader::collect_prompt_content(&loaded_adapters);
-    let registry = crate::services::adapter_registry::AdapterRegistry::from_loaded(
-        loaded_adapters,
-        prompt_content,
-    );
+    let registry = load_registry(adapters_dir).await;
 
     // Look up the egress adapter
     let egress = registry.get_egress(adapter_name).ok_or_else(|| {
@@ -858,6 +944,31 @@ fn run_doctor_checks(adapters_dir: &Path, name: &str) -> Vec<DoctorCheck> {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_status_reports_unhealthy_adapter() {
+        let registry =
+            crate::services::adapter_health::registry_with_unhealthy_adapter("expired-tracker");
+        let out = status_output(&registry, &[]).await;
+
+        assert!(!out.all_healthy);
+        let human = out.to_human();
+        let row = human
+            .lines()
+            .find(|l| l.contains("expired-tracker"))
+            .expect("unhealthy adapter listed");
+        assert!(row.contains("unhealthy"), "got: {}", row);
+
+        let json = out.to_json();
+        let entry = json["adapters"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|a| a["name"] == "expired-tracker")
+            .unwrap();
+        assert_eq!(entry["healthy"], false);
+        assert!(entry["error"].as_str().unwrap().contains("Bad credentials"));
+    }
+
     #[test]
     fn test_known_adapters_not_empty() {
         assert!(!KNOWN_ADAPTERS.is_empty());
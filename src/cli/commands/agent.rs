@@ -8,8 +8,8 @@ use uuid::Uuid;
 
 use crate::adapters::sqlite::{SqliteAgentRepository, initialize_default_database};
 use crate::cli::display::{
-    CommandOutput, DetailView, action_success, colorize_status, colorize_tier, list_table, output,
-    render_list, truncate_ellipsis,
+    CommandOutput, DetailView, Tone, action_success, colorize_status, colorize_tier, list_table,
+    output, paint, render_list, truncate_ellipsis,
 };
 use crate::cli::id_resolver::resolve_task_id;
 use crate::domain::models::a2a::{A2AAgentCard, A2AMessage, MessageType};
@@ -401,9 +401,9 @@ impl CommandOutput for GatewayStatusOutput {
     fn to_human(&self) -> String {
         use colored::Colorize;
         let status_str = if self.running {
-            "RUNNING".green().bold().to_string()
+            paint("RUNNING", Tone::Success).bold().to_string()
         } else {
-            "NOT RUNNING".red().bold().to_string()
+            paint("NOT RUNNING", Tone::Error).bold().to_string()
         };
 
         let mut view = DetailView::new("A2A Gateway Status")
@@ -0,0 +1,135 @@
+//! Implementation of the `abathur audit` command.
+//!
+//! Reads back the structured audit log persisted by the swarm: state changes
+//! and autonomous decisions, filterable by category, level, entity and time.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use clap::{Args, Subcommand};
+
+use crate::adapters::sqlite::initialize_default_database;
+use crate::cli::display::{
+    CommandOutput, list_table, output, parse_duration, render_list, truncate_ellipsis,
+};
+use crate::cli::id_resolver::resolve_task_id;
+use crate::services::audit_log::{
+    AuditCategory, AuditEntry, AuditFilter, AuditLevel, AuditLogService,
+};
+
+#[derive(Args, Debug)]
+pub struct AuditArgs {
+    #[command(subcommand)]
+    pub command: AuditCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AuditCommands {
+    /// List audit log entries, newest first
+    List {
+        /// Filter by category (task, goal, agent, memory, execution, system, security, config)
+        #[arg(short, long)]
+        category: Option<String>,
+        /// Minimum level (debug, info, decision, warning, error, critical)
+        #[arg(long)]
+        level: Option<String>,
+        /// Only entries newer than this (e.g., "1h", "7d")
+        #[arg(long)]
+        since: Option<String>,
+        /// Only entries older than this (e.g., "30m", "1d")
+        #[arg(long)]
+        until: Option<String>,
+        /// Only entries about this task (ID or prefix)
+        #[arg(long)]
+        task: Option<String>,
+        /// Maximum number of entries to show
+        #[arg(short, long, default_value = "50")]
+        limit: usize,
+    },
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct AuditListOutput {
+    pub entries: Vec<AuditEntry>,
+    pub total: usize,
+}
+
+impl CommandOutput for AuditListOutput {
+    fn to_human(&self) -> String {
+        if self.entries.is_empty() {
+            return "No audit events found.".to_string();
+        }
+
+        let mut table = list_table(&["Time", "Level", "Category", "Action", "Actor", "Message"]);
+
+        for e in &self.entries {
+            table.add_row(vec![
+                e.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                e.level.as_str().to_string(),
+                e.category.as_str().to_string(),
+                e.action.as_str().to_string(),
+                truncate_ellipsis(&e.actor.to_string(), 24),
+                truncate_ellipsis(&e.message, 50),
+            ]);
+        }
+
+        render_list("audit event", table, self.total)
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+pub async fn execute(args: AuditArgs, json_mode: bool) -> Result<()> {
+    let pool = initialize_default_database()
+        .await
+        .context("Failed to initialize database. Run 'abathur init' first.")?;
+
+    match args.command {
+        AuditCommands::List {
+            category,
+            level,
+            since,
+            until,
+            task,
+            limit,
+        } => {
+            let mut filter = AuditFilter::new().with_limit(limit);
+
+            if let Some(cat) = category {
+                let parsed = AuditCategory::parse_str(&cat)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown audit category '{}'", cat))?;
+                filter = filter.with_category(parsed);
+            }
+            if let Some(lvl) = level {
+                let parsed = AuditLevel::parse_str(&lvl)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown audit level '{}'", lvl))?;
+                filter = filter.with_min_level(parsed);
+            }
+
+            let now = Utc::now();
+            if let Some(s) = since {
+                filter.from = Some(now - parse_duration(&s)?);
+            }
+            if let Some(u) = until {
+                filter.to = Some(now - parse_duration(&u)?);
+            }
+            if let Some(t) = task {
+                filter = filter.with_entity(resolve_task_id(&pool, &t).await?);
+            }
+
+            let entries = AuditLogService::with_defaults()
+                .with_pool(pool)
+                .query(filter)
+                .await;
+
+            let out = AuditListOutput {
+                total: entries.len(),
+                entries,
+            };
+            output(&out, json_mode);
+        }
+    }
+
+    Ok(())
+}
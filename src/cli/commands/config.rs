@@ -0,0 +1,128 @@
+//! Implementation of the `abathur config` command.
+//!
+//! Currently provides `config migrate`, which upgrades an `abathur.toml`
+//! written for an older schema version to the current one, reporting each
+//! key it renamed or filled in.
+
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use std::path::{Path, PathBuf};
+
+use crate::cli::display::{CommandOutput, action_success, output};
+use crate::services::config_migration::{ConfigChange, migrate_str};
+
+#[derive(Args, Debug)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// Upgrade a config file to the current schema version
+    Migrate {
+        /// Config file to upgrade (defaults to the global --config path)
+        path: Option<PathBuf>,
+        /// Report the changes without writing the file
+        #[arg(long)]
+        dry_run: bool,
+        /// Write the upgraded config here instead of overwriting the input
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConfigMigrateOutput {
+    pub path: String,
+    pub written_to: Option<String>,
+    pub backup: Option<String>,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub changes: Vec<ConfigChange>,
+}
+
+impl CommandOutput for ConfigMigrateOutput {
+    fn to_human(&self) -> String {
+        if self.from_version == self.to_version && self.changes.is_empty() {
+            return action_success(&format!(
+                "{} is already at config version {}",
+                self.path, self.to_version
+            ));
+        }
+
+        let mut lines = vec![format!(
+            "Config version {} -> {} ({} change{})",
+            self.from_version,
+            self.to_version,
+            self.changes.len(),
+            if self.changes.len() == 1 { "" } else { "s" }
+        )];
+        for change in &self.changes {
+            lines.push(format!("  - {}", change));
+        }
+        match &self.written_to {
+            Some(dest) => {
+                lines.push(action_success(&format!("Wrote upgraded config to {}", dest)));
+                if let Some(backup) = &self.backup {
+                    lines.push(format!("Original saved as {}", backup));
+                }
+            }
+            None => lines.push("Dry run: no files written".to_string()),
+        }
+        lines.join("\n")
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+pub async fn execute(args: ConfigArgs, config_path: &Path, json_mode: bool) -> Result<()> {
+    match args.command {
+        ConfigCommands::Migrate {
+            path,
+            dry_run,
+            output: dest,
+        } => {
+            let path = path.unwrap_or_else(|| config_path.to_path_buf());
+            let result = migrate_file(&path, dest.as_deref(), dry_run)?;
+            output(&result, json_mode);
+        }
+    }
+    Ok(())
+}
+
+/// Migrate the config at `path`, writing to `dest` (or back over `path`,
+/// keeping a `.bak` copy of the original) unless `dry_run` is set.
+fn migrate_file(path: &Path, dest: Option<&Path>, dry_run: bool) -> Result<ConfigMigrateOutput> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file {}", path.display()))?;
+    let (upgraded, report) = migrate_str(&content)
+        .with_context(|| format!("Failed to migrate {}", path.display()))?;
+
+    let mut written_to = None;
+    let mut backup = None;
+    if !dry_run && (!report.is_noop() || dest.is_some()) {
+        let target = dest.unwrap_or(path);
+        if dest.is_none() {
+            let backup_path = PathBuf::from(format!("{}.bak", path.display()));
+            std::fs::copy(path, &backup_path).with_context(|| {
+                format!("Failed to back up config to {}", backup_path.display())
+            })?;
+            backup = Some(backup_path.display().to_string());
+        }
+        std::fs::write(target, &upgraded)
+            .with_context(|| format!("Failed to write config to {}", target.display()))?;
+        written_to = Some(target.display().to_string());
+    }
+
+    Ok(ConfigMigrateOutput {
+        path: path.display().to_string(),
+        written_to,
+        backup,
+        from_version: report.from_version,
+        to_version: report.to_version,
+        changes: report.changes,
+    })
+}
@@ -0,0 +1,309 @@
+//! Convergence loop CLI commands.
+//!
+//! `abathur loop status` lists the convergence loops that are still running,
+//! read from the persisted trajectories; `--follow` then tails
+//! `ConvergenceIteration` events from the event store as they arrive.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+
+use crate::adapters::sqlite::{
+    SqliteEventRepository, SqliteTrajectoryRepository, initialize_default_database,
+};
+use crate::cli::display::{
+    CommandOutput, list_table, output, relative_time, render_list, short_id,
+};
+use crate::domain::models::{ConvergencePhase, Trajectory};
+use crate::domain::ports::TrajectoryRepository;
+use crate::services::event_bus::{EventCategory, EventPayload, SequenceNumber};
+use crate::services::event_store::{EventQuery, EventStore};
+
+/// How often `loop status --follow` polls the event store.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Args, Debug)]
+pub struct LoopArgs {
+    #[command(subcommand)]
+    pub command: LoopCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum LoopCommands {
+    /// Show active convergence loops
+    Status {
+        /// Keep running and print each convergence iteration as it completes
+        #[arg(short, long)]
+        follow: bool,
+    },
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct LoopStatusEntry {
+    pub trajectory_id: String,
+    pub task_id: String,
+    pub phase: String,
+    pub iteration: usize,
+    /// Convergence level (0.0–1.0) of the most recent measured observation.
+    pub quality_score: Option<f64>,
+    /// Overseers whose latest signals block convergence.
+    pub blocking_overseers: Vec<String>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<&Trajectory> for LoopStatusEntry {
+    fn from(t: &Trajectory) -> Self {
+        let phase = match &t.phase {
+            ConvergencePhase::Preparing => "preparing",
+            ConvergencePhase::Iterating => "iterating",
+            ConvergencePhase::Coordinating { .. } => "coordinating",
+            ConvergencePhase::Converged => "converged",
+            ConvergencePhase::Exhausted => "exhausted",
+            ConvergencePhase::Trapped => "trapped",
+        };
+        Self {
+            trajectory_id: t.id.to_string(),
+            task_id: t.task_id.to_string(),
+            phase: phase.to_string(),
+            iteration: t.observations.len(),
+            quality_score: t
+                .observations
+                .iter()
+                .rev()
+                .find_map(|o| o.metrics.as_ref().map(|m| m.convergence_level)),
+            blocking_overseers: t
+                .latest_overseer_signals()
+                .map(|s| s.failing_overseers(t.lint_baseline))
+                .unwrap_or_default(),
+            updated_at: t.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct LoopStatusOutput {
+    pub loops: Vec<LoopStatusEntry>,
+    pub total: usize,
+}
+
+impl CommandOutput for LoopStatusOutput {
+    fn to_human(&self) -> String {
+        if self.loops.is_empty() {
+            return "No active convergence loops.".to_string();
+        }
+
+        let mut table = list_table(&[
+            "Task",
+            "Phase",
+            "Iteration",
+            "Quality",
+            "Blocked By",
+            "Updated",
+        ]);
+
+        for l in &self.loops {
+            table.add_row(vec![
+                short_id(&l.task_id).to_string(),
+                l.phase.clone(),
+                l.iteration.to_string(),
+                l.quality_score
+                    .map(|q| format!("{:.2}", q))
+                    .unwrap_or_else(|| "-".to_string()),
+                if l.blocking_overseers.is_empty() {
+                    "-".to_string()
+                } else {
+                    l.blocking_overseers.join(", ")
+                },
+                relative_time(&l.updated_at),
+            ]);
+        }
+
+        render_list("loop", table, self.total)
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+pub async fn execute(args: LoopArgs, json_mode: bool) -> Result<()> {
+    let pool = initialize_default_database()
+        .await
+        .context("Failed to initialize database. Run 'abathur init' first.")?;
+
+    match args.command {
+        LoopCommands::Status { follow } => {
+            let trajectories = SqliteTrajectoryRepository::new(pool.clone());
+            output(&status_output(&trajectories).await?, json_mode);
+
+            if follow {
+                let store = Arc::new(SqliteEventRepository::new(
+                    pool,
+                    crate::services::crypto::load_encryptor_from_env(),
+                ));
+                follow_iterations(store.as_ref(), json_mode).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn status_output(repo: &dyn TrajectoryRepository) -> Result<LoopStatusOutput> {
+    let trajectories = repo
+        .get_active()
+        .await
+        .context("Failed to load active trajectories")?;
+    let loops: Vec<LoopStatusEntry> = trajectories.iter().map(LoopStatusEntry::from).collect();
+    Ok(LoopStatusOutput {
+        total: loops.len(),
+        loops,
+    })
+}
+
+/// Print convergence iterations and terminations as they land in the event
+/// store, until Ctrl+C.
+async fn follow_iterations(store: &dyn EventStore, json_mode: bool) -> Result<()> {
+    let mut after = store
+        .latest_sequence()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to get latest sequence: {}", e))?
+        .map(|s| s.0)
+        .unwrap_or(0);
+
+    if !json_mode {
+        println!("\nFollowing convergence iterations (Ctrl+C to stop)...");
+    }
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            _ = tokio::time::sleep(FOLLOW_POLL_INTERVAL) => {}
+        }
+
+        let events = store
+            .query(
+                EventQuery::new()
+                    .since_sequence(SequenceNumber(after + 1))
+                    .category(EventCategory::Convergence)
+                    .ascending(),
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to query events: {}", e))?;
+
+        for event in events {
+            after = after.max(event.sequence.0);
+            match &event.payload {
+                EventPayload::ConvergenceIteration(p) => {
+                    if json_mode {
+                        println!("{}", serde_json::to_string(&event).unwrap_or_default());
+                    } else {
+                        println!(
+                            "{} task {} iteration {}: {} level {:.2} (delta {:+.3}), {}, {:.0}% budget left",
+                            event.timestamp.format("%H:%M:%S"),
+                            short_id(&p.task_id.to_string()),
+                            p.iteration,
+                            p.strategy,
+                            p.convergence_level,
+                            p.convergence_delta,
+                            p.attractor_type,
+                            p.budget_remaining_fraction * 100.0
+                        );
+                    }
+                }
+                EventPayload::ConvergenceTerminated(p) => {
+                    if json_mode {
+                        println!("{}", serde_json::to_string(&event).unwrap_or_default());
+                    } else {
+                        println!(
+                            "{} task {} finished: {} after {} iterations (level {:.2})",
+                            event.timestamp.format("%H:%M:%S"),
+                            short_id(&p.task_id.to_string()),
+                            p.outcome,
+                            p.total_iterations,
+                            p.final_convergence_level
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::{create_migrated_test_pool, insert_test_task};
+    use crate::domain::models::{
+        ArtifactReference, BuildResult, ConvergenceBudget, ConvergencePolicy, Observation,
+        ObservationMetrics, OverseerSignals, SpecificationEvolution, SpecificationSnapshot,
+        StrategyKind,
+    };
+    use uuid::Uuid;
+
+    fn observation(sequence: u32, level: f64, build_ok: bool) -> Observation {
+        let mut signals = OverseerSignals::empty();
+        signals.build_result = Some(BuildResult {
+            success: build_ok,
+            error_count: if build_ok { 0 } else { 2 },
+            errors: vec![],
+        });
+        let obs = Observation::new(
+            sequence,
+            ArtifactReference::new("/tmp/worktree", "abc123"),
+            signals,
+            StrategyKind::RetryWithFeedback,
+            1000,
+            500,
+        );
+        obs.with_metrics(ObservationMetrics {
+            convergence_level: level,
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_status_lists_in_progress_loop_with_iteration_count() {
+        let pool = create_migrated_test_pool().await.unwrap();
+        let repo = SqliteTrajectoryRepository::new(pool.clone());
+
+        let mut active = Trajectory::new(
+            Uuid::new_v4(),
+            None,
+            SpecificationEvolution::new(SpecificationSnapshot::new("spec".into())),
+            ConvergenceBudget::default(),
+            ConvergencePolicy::default(),
+        );
+        active.phase = ConvergencePhase::Iterating;
+        active.observations = vec![
+            observation(0, 0.2, false),
+            observation(1, 0.4, false),
+            observation(2, 0.55, false),
+        ];
+
+        let mut done = active.clone();
+        done.id = Uuid::new_v4();
+        done.task_id = Uuid::new_v4();
+        done.phase = ConvergencePhase::Converged;
+
+        for t in [&active, &done] {
+            insert_test_task(&pool, t.task_id).await;
+            repo.save(t).await.unwrap();
+        }
+
+        let out = status_output(&repo).await.unwrap();
+        assert_eq!(out.total, 1, "terminal loops are not listed");
+        let entry = &out.loops[0];
+        assert_eq!(entry.task_id, active.task_id.to_string());
+        assert_eq!(entry.iteration, 3);
+        assert_eq!(entry.quality_score, Some(0.55));
+        assert_eq!(entry.blocking_overseers, ["build"]);
+
+        let human = out.to_human();
+        assert!(human.contains(short_id(&active.task_id.to_string())));
+        assert!(human.contains("iterating"));
+    }
+}
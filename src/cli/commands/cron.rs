@@ -126,7 +126,7 @@ fn slugify_prompt(prompt: &str) -> String {
 
 /// Compute the next fire time for a cron expression and format it as a
 /// human-readable relative time string.
-fn next_fire_description(expression: &str) -> Option<String> {
+pub(crate) fn next_fire_description(expression: &str) -> Option<String> {
     let normalized = normalize_cron_expression(expression);
     let schedule = cron::Schedule::from_str(&normalized).ok()?;
     let next = schedule.upcoming(Utc).next()?;
@@ -225,7 +225,12 @@ pub async fn execute(args: CronArgs, json_mode: bool) -> Result<()> {
             }
 
             let priority = TaskPriority::parse(&priority).unwrap_or(TaskPriority::Normal);
-            let overlap_policy = OverlapPolicy::parse(&overlap).unwrap_or(OverlapPolicy::Skip);
+            let overlap_policy = OverlapPolicy::parse(&overlap).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid overlap policy: {} (expected skip, allow or cancel_previous)",
+                    overlap
+                )
+            })?;
 
             // Use the prompt as both title (truncated) and description
             let task_title = if prompt.len() > 60 {
@@ -0,0 +1,341 @@
+//! Implementation of the `abathur db` command.
+//!
+//! Surfaces the state of the project database's embedded schema migrations:
+//! `db status` lists applied and pending migrations, and `db migrate` applies
+//! the pending ones (or, with `--dry-run`, lists what would apply). `db stats`
+//! reports file, WAL, and per-table sizes, and `db vacuum` reclaims the space
+//! left behind by pruned rows.
+
+use anyhow::{Context, Result, bail};
+use clap::{Args, Subcommand};
+use std::path::Path;
+
+use crate::adapters::sqlite::{
+    AppliedMigration, DEFAULT_DATABASE_PATH, DatabaseStats, MigrationStatus, Migrator,
+    PendingMigration, PoolConfig, all_embedded_migrations, create_pool, database_file_sizes,
+    database_stats, vacuum,
+};
+use crate::cli::commands::swarm::check_existing_swarm;
+use crate::cli::display::{
+    CommandOutput, action_success, format_bytes, list_table, output, section_header,
+};
+
+#[derive(Args, Debug)]
+pub struct DbArgs {
+    #[command(subcommand)]
+    pub command: DbCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DbCommands {
+    /// Show applied and pending schema migrations
+    Status {
+        /// Only list migrations newer than this schema version
+        #[arg(long)]
+        since_version: Option<i64>,
+    },
+    /// Apply pending schema migrations
+    Migrate {
+        /// List the migrations that would apply without applying them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Show database file, WAL, and per-table sizes
+    Stats,
+    /// Rebuild the database file and truncate the WAL to reclaim space
+    Vacuum,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DbStatusOutput {
+    pub database: String,
+    pub current_version: i64,
+    pub latest_version: i64,
+    pub applied: Vec<AppliedMigration>,
+    pub pending: Vec<PendingMigration>,
+}
+
+impl DbStatusOutput {
+    fn new(database: &str, status: MigrationStatus, since_version: Option<i64>) -> Self {
+        let since = since_version.unwrap_or(i64::MIN);
+        let latest_version = status
+            .pending
+            .last()
+            .map(|m| m.version)
+            .unwrap_or(status.current_version);
+        Self {
+            database: database.to_string(),
+            current_version: status.current_version,
+            latest_version,
+            applied: status
+                .applied
+                .into_iter()
+                .filter(|m| m.version > since)
+                .collect(),
+            pending: status
+                .pending
+                .into_iter()
+                .filter(|m| m.version > since)
+                .collect(),
+        }
+    }
+}
+
+impl CommandOutput for DbStatusOutput {
+    fn to_human(&self) -> String {
+        let mut lines = vec![format!(
+            "{}: schema version {} of {}",
+            self.database, self.current_version, self.latest_version
+        )];
+        if !self.applied.is_empty() {
+            let mut table = list_table(&["version", "applied at", "description"]);
+            for m in &self.applied {
+                table.add_row(vec![
+                    m.version.to_string(),
+                    m.applied_at.clone(),
+                    m.description.clone().unwrap_or_default(),
+                ]);
+            }
+            lines.push(format!("\nApplied:\n{}", table));
+        }
+        if self.pending.is_empty() {
+            lines.push(action_success("No pending migrations"));
+        } else {
+            lines.push(format!("\nPending:\n{}", pending_table(&self.pending)));
+        }
+        lines.join("\n")
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DbMigrateOutput {
+    pub database: String,
+    pub dry_run: bool,
+    pub from_version: i64,
+    pub migrations: Vec<PendingMigration>,
+}
+
+impl CommandOutput for DbMigrateOutput {
+    fn to_human(&self) -> String {
+        if self.migrations.is_empty() {
+            return action_success(&format!(
+                "{} is up to date at schema version {}",
+                self.database, self.from_version
+            ));
+        }
+        let verb = if self.dry_run {
+            "Would apply"
+        } else {
+            "Applied"
+        };
+        let mut lines = vec![
+            format!(
+                "{} {} migration{} to {} (from version {}):",
+                verb,
+                self.migrations.len(),
+                if self.migrations.len() == 1 { "" } else { "s" },
+                self.database,
+                self.from_version
+            ),
+            pending_table(&self.migrations).to_string(),
+        ];
+        if self.dry_run {
+            lines.push("Dry run: no migrations applied".to_string());
+        }
+        lines.join("\n")
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DbStatsOutput {
+    pub database: String,
+    #[serde(flatten)]
+    pub stats: DatabaseStats,
+}
+
+impl CommandOutput for DbStatsOutput {
+    fn to_human(&self) -> String {
+        let stats = &self.stats;
+        let mut lines = vec![
+            section_header(&self.database),
+            format!(
+                "File: {}   WAL: {}   Reclaimable: {}",
+                format_bytes(stats.file_size),
+                format_bytes(stats.wal_size),
+                format_bytes(stats.reclaimable_bytes())
+            ),
+        ];
+        let mut table = list_table(&["table", "rows", "size"]);
+        for t in &stats.tables {
+            table.add_row(vec![
+                t.name.clone(),
+                t.rows.to_string(),
+                t.bytes.map(format_bytes).unwrap_or_else(|| "-".to_string()),
+            ]);
+        }
+        lines.push(format!("\nTables (largest first):\n{}", table));
+        lines.join("\n")
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DbVacuumOutput {
+    pub database: String,
+    pub size_before: u64,
+    pub size_after: u64,
+    pub reclaimed: u64,
+}
+
+impl DbVacuumOutput {
+    fn new(database: &str, size_before: u64, size_after: u64) -> Self {
+        Self {
+            database: database.to_string(),
+            size_before,
+            size_after,
+            reclaimed: size_before.saturating_sub(size_after),
+        }
+    }
+}
+
+impl CommandOutput for DbVacuumOutput {
+    fn to_human(&self) -> String {
+        action_success(&format!(
+            "Vacuumed {}: {} -> {} (reclaimed {})",
+            self.database,
+            format_bytes(self.size_before),
+            format_bytes(self.size_after),
+            format_bytes(self.reclaimed)
+        ))
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+fn pending_table(migrations: &[PendingMigration]) -> comfy_table::Table {
+    let mut table = list_table(&["version", "description"]);
+    for m in migrations {
+        table.add_row(vec![m.version.to_string(), m.description.clone()]);
+    }
+    table
+}
+
+pub async fn execute(args: DbArgs, json_mode: bool) -> Result<()> {
+    let path = Path::new(DEFAULT_DATABASE_PATH);
+    match args.command {
+        DbCommands::Status { since_version } => {
+            // Report without creating the database when it doesn't exist yet.
+            let status = if path.exists() {
+                migrator(path)
+                    .await?
+                    .status(&all_embedded_migrations())
+                    .await?
+            } else {
+                MigrationStatus::unmigrated(&all_embedded_migrations())
+            };
+            output(
+                &DbStatusOutput::new(DEFAULT_DATABASE_PATH, status, since_version),
+                json_mode,
+            );
+        }
+        DbCommands::Migrate { dry_run } => {
+            let migrations = all_embedded_migrations();
+            let status = if dry_run && !path.exists() {
+                MigrationStatus::unmigrated(&migrations)
+            } else {
+                let migrator = migrator(path).await?;
+                let status = migrator.status(&migrations).await?;
+                if !dry_run {
+                    migrator.run_embedded_migrations(migrations).await?;
+                }
+                status
+            };
+            output(
+                &DbMigrateOutput {
+                    database: DEFAULT_DATABASE_PATH.to_string(),
+                    dry_run,
+                    from_version: status.current_version,
+                    migrations: status.pending,
+                },
+                json_mode,
+            );
+        }
+        DbCommands::Stats => {
+            if !path.exists() {
+                bail!(
+                    "No database at {}; run 'abathur init' first",
+                    DEFAULT_DATABASE_PATH
+                );
+            }
+            let pool = open_pool(path, None).await?;
+            let stats = database_stats(&pool, path).await?;
+            output(
+                &DbStatsOutput {
+                    database: DEFAULT_DATABASE_PATH.to_string(),
+                    stats,
+                },
+                json_mode,
+            );
+        }
+        DbCommands::Vacuum => {
+            if !path.exists() {
+                bail!(
+                    "No database at {}; run 'abathur init' first",
+                    DEFAULT_DATABASE_PATH
+                );
+            }
+            // VACUUM takes the write lock for its whole run; a live swarm
+            // would stall on every write until it finishes.
+            if let Some(pid) = check_existing_swarm() {
+                bail!(
+                    "Swarm is running (PID {}) and holds the database; stop it with 'abathur swarm stop' before vacuuming",
+                    pid
+                );
+            }
+            let (file_before, wal_before) = database_file_sizes(path);
+            let pool = open_pool(
+                path,
+                Some(PoolConfig {
+                    max_connections: 1,
+                    ..PoolConfig::default()
+                }),
+            )
+            .await?;
+            vacuum(&pool).await?;
+            pool.close().await;
+            let (file_after, wal_after) = database_file_sizes(path);
+            output(
+                &DbVacuumOutput::new(
+                    DEFAULT_DATABASE_PATH,
+                    file_before + wal_before,
+                    file_after + wal_after,
+                ),
+                json_mode,
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn migrator(path: &Path) -> Result<Migrator> {
+    Ok(Migrator::new(open_pool(path, None).await?))
+}
+
+async fn open_pool(path: &Path, config: Option<PoolConfig>) -> Result<sqlx::SqlitePool> {
+    create_pool(&format!("sqlite:{}", path.display()), config)
+        .await
+        .context("Failed to open database")
+}
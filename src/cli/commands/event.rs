@@ -10,7 +10,7 @@ use crate::cli::display::{
     truncate_ellipsis,
 };
 use crate::cli::id_resolver::resolve_dlq_id;
-use crate::services::event_store::{EventQuery, EventStore};
+use crate::services::event_store::{EventQuery, EventStore, handler_lags};
 
 #[derive(Args, Debug)]
 pub struct EventArgs {
@@ -20,8 +20,12 @@ pub struct EventArgs {
 
 #[derive(Subcommand, Debug)]
 pub enum EventCommands {
-    /// Show event store statistics
-    Stats,
+    /// Show event store statistics and event volumes by category
+    Stats {
+        /// Only count events newer than this (e.g., "1h", "7d"); default all
+        #[arg(long)]
+        since: Option<String>,
+    },
     /// Detect sequence gaps in the event store
     Gaps {
         /// How far back to scan (number of events)
@@ -80,6 +84,26 @@ pub struct EventStatsOutput {
     pub latest_sequence: Option<u64>,
     pub oldest_event: Option<String>,
     pub newest_event: Option<String>,
+    /// Start of the counting window (None = all events).
+    pub since: Option<String>,
+    pub window_events: u64,
+    pub by_category: Vec<EventCount>,
+    pub by_payload_type: Vec<EventCount>,
+    pub handler_lag: Vec<HandlerLagEntry>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventCount {
+    pub name: String,
+    pub count: u64,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct HandlerLagEntry {
+    pub handler: String,
+    pub watermark: u64,
+    pub lag: u64,
+    pub behind: bool,
 }
 
 impl CommandOutput for EventStatsOutput {
@@ -101,6 +125,32 @@ impl CommandOutput for EventStatsOutput {
             view = view.field("Newest", newest);
         }
 
+        let window = match self.since {
+            Some(ref since) => format!("since {} ({} events)", since, self.window_events),
+            None => format!("all ({} events)", self.window_events),
+        };
+        view = view.field("Window", &window);
+        if !self.by_category.is_empty() {
+            view = view.section("By Category");
+            for c in &self.by_category {
+                view = view.item(&format!("{:<14} {}", c.name, c.count));
+            }
+            view = view.section("By Payload Type");
+            for p in &self.by_payload_type {
+                view = view.item(&format!("{:<32} {}", p.name, p.count));
+            }
+        }
+        if !self.handler_lag.is_empty() {
+            view = view.section("Handler Lag");
+            for h in &self.handler_lag {
+                let marker = if h.behind { "  (behind)" } else { "" };
+                view = view.item(&format!(
+                    "{:<32} seq {} lag {}{}",
+                    h.handler, h.watermark, h.lag, marker
+                ));
+            }
+        }
+
         view.render()
     }
 
@@ -264,17 +314,67 @@ pub async fn execute(args: EventArgs, json_mode: bool) -> Result<()> {
     ));
 
     match args.command {
-        EventCommands::Stats => {
+        EventCommands::Stats { since } => {
             let stats = store
                 .stats()
                 .await
                 .map_err(|e| anyhow::anyhow!("Failed to get stats: {}", e))?;
 
+            let since_time = since
+                .as_deref()
+                .map(|s| {
+                    let duration = parse_std_duration(s)?;
+                    Ok::<_, anyhow::Error>(
+                        chrono::Utc::now() - chrono::Duration::from_std(duration)?,
+                    )
+                })
+                .transpose()?;
+            let volume = store
+                .volume_since(since_time)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to count events: {}", e))?;
+
+            let handler_names = store
+                .watermark_handler_names()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to list watermarks: {}", e))?;
+            let latest_seq = stats.latest_sequence.map(|s| s.0).unwrap_or(0);
+            let lags = handler_lags(store.as_ref(), latest_seq, &handler_names)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to compute handler lag: {}", e))?;
+
             let out = EventStatsOutput {
                 total_events: stats.total_events,
                 latest_sequence: stats.latest_sequence.map(|s| s.0),
                 oldest_event: stats.oldest_event.map(|t| t.to_rfc3339()),
                 newest_event: stats.newest_event.map(|t| t.to_rfc3339()),
+                since: since_time.map(|t| t.to_rfc3339()),
+                window_events: volume.total_events,
+                by_category: volume
+                    .by_category
+                    .into_iter()
+                    .map(|(c, count)| EventCount {
+                        name: c.to_string(),
+                        count,
+                    })
+                    .collect(),
+                by_payload_type: volume
+                    .by_payload_type
+                    .into_iter()
+                    .map(|(p, count)| EventCount {
+                        name: p.to_string(),
+                        count,
+                    })
+                    .collect(),
+                handler_lag: lags
+                    .into_iter()
+                    .map(|l| HandlerLagEntry {
+                        behind: l.is_behind(),
+                        handler: l.handler,
+                        watermark: l.watermark,
+                        lag: l.lag,
+                    })
+                    .collect(),
             };
             output(&out, json_mode);
         }
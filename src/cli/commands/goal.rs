@@ -11,6 +11,7 @@ use crate::cli::display::{
     output, relative_time_str, render_list, short_id, truncate_ellipsis,
 };
 use crate::cli::id_resolver::resolve_goal_id;
+use crate::domain::errors::DomainError;
 use crate::domain::models::{Goal, GoalConstraint, GoalPriority, GoalStatus};
 use crate::domain::ports::GoalFilter;
 use crate::services::GoalService;
@@ -348,7 +349,7 @@ pub async fn execute(args: GoalArgs, json_mode: bool) -> Result<()> {
             let goal = service
                 .get_goal(uuid)
                 .await?
-                .ok_or_else(|| anyhow::anyhow!("Goal not found: {}", id))?;
+                .ok_or(DomainError::GoalNotFound(uuid))?;
 
             let constraints = goal
                 .constraints
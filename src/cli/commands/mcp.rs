@@ -8,8 +8,8 @@ use clap::{Args, Subcommand};
 use std::sync::Arc;
 
 use crate::adapters::mcp::{
-    A2AHttpConfig, A2AHttpGateway, AgentsHttpConfig, AgentsHttpServer, MemoryHttpConfig,
-    MemoryHttpServer, TasksHttpConfig, TasksHttpServer,
+    A2AHttpConfig, A2AHttpGateway, AgentsHttpConfig, AgentsHttpServer, HttpAuth, MemoryHttpConfig,
+    MemoryHttpServer, TasksHttpConfig, TasksHttpServer, TlsConfig,
 };
 use crate::adapters::sqlite::{
     Migrator, SqliteAgentRepository, SqliteGoalRepository, SqliteMemoryRepository,
@@ -44,7 +44,10 @@ impl McpServices {
         let task_repo = Arc::new(SqliteTaskRepository::new(pool.clone()));
         let goal_repo = Arc::new(SqliteGoalRepository::new(pool.clone()));
 
-        let memory_service = MemoryService::new(memory_repo);
+        let memory_config = crate::services::config::Config::load()
+            .unwrap_or_default()
+            .memory;
+        let memory_service = MemoryService::new(memory_repo).with_memory_config(&memory_config);
         let task_service = TaskService::new(task_repo);
         let goal_service = GoalService::new(goal_repo);
 
@@ -127,6 +130,14 @@ pub enum McpCommand {
         /// Disable CORS
         #[arg(long)]
         no_cors: bool,
+
+        /// PEM certificate chain to serve HTTPS with (requires --tls-key)
+        #[arg(long, requires = "tls_key")]
+        tls_cert: Option<String>,
+
+        /// PEM private key for --tls-cert
+        #[arg(long, requires = "tls_cert")]
+        tls_key: Option<String>,
     },
     /// Start the Tasks HTTP server
     TasksHttp {
@@ -141,6 +152,14 @@ pub enum McpCommand {
         /// Disable CORS
         #[arg(long)]
         no_cors: bool,
+
+        /// PEM certificate chain to serve HTTPS with (requires --tls-key)
+        #[arg(long, requires = "tls_key")]
+        tls_cert: Option<String>,
+
+        /// PEM private key for --tls-cert
+        #[arg(long, requires = "tls_cert")]
+        tls_key: Option<String>,
     },
     /// Start the Agents HTTP server
     AgentsHttp {
@@ -155,6 +174,14 @@ pub enum McpCommand {
         /// Disable CORS
         #[arg(long)]
         no_cors: bool,
+
+        /// PEM certificate chain to serve HTTPS with (requires --tls-key)
+        #[arg(long, requires = "tls_key")]
+        tls_cert: Option<String>,
+
+        /// PEM private key for --tls-cert
+        #[arg(long, requires = "tls_cert")]
+        tls_key: Option<String>,
     },
     /// Start the A2A HTTP gateway
     A2aHttp {
@@ -232,17 +259,32 @@ pub async fn execute(args: McpArgs, json_mode: bool) -> Result<()> {
             host,
             port,
             no_cors,
-        } => start_memory_http(host, port, !no_cors, json_mode).await,
+            tls_cert,
+            tls_key,
+        } => {
+            let tls = tls_config(tls_cert, tls_key)?;
+            start_memory_http(host, port, !no_cors, tls, json_mode).await
+        }
         McpCommand::TasksHttp {
             host,
             port,
             no_cors,
-        } => start_tasks_http(host, port, !no_cors, json_mode).await,
+            tls_cert,
+            tls_key,
+        } => {
+            let tls = tls_config(tls_cert, tls_key)?;
+            start_tasks_http(host, port, !no_cors, tls, json_mode).await
+        }
         McpCommand::AgentsHttp {
             host,
             port,
             no_cors,
-        } => start_agents_http(host, port, !no_cors, json_mode).await,
+            tls_cert,
+            tls_key,
+        } => {
+            let tls = tls_config(tls_cert, tls_key)?;
+            start_agents_http(host, port, !no_cors, tls, json_mode).await
+        }
         McpCommand::A2aHttp {
             host,
             port,
@@ -263,6 +305,7 @@ pub async fn execute(args: McpArgs, json_mode: bool) -> Result<()> {
                     max_stream_duration_s: max_stream_secs,
                     federation_tls: None,
                     federation_jwt_secret: None,
+                    auth: http_auth()?,
                 },
                 json_mode,
             )
@@ -294,10 +337,36 @@ pub async fn execute(args: McpArgs, json_mode: bool) -> Result<()> {
     }
 }
 
+/// Resolve the HTTP auth scheme from `abathur.toml` / env, failing closed.
+fn http_auth() -> Result<HttpAuth> {
+    Ok(HttpAuth::from_config(
+        &crate::services::config::Config::load_http_auth()?,
+    ))
+}
+
+/// Validate the `--tls-cert`/`--tls-key` pair up front so a bad certificate
+/// fails startup instead of the server falling back to plain HTTP.
+fn tls_config(cert: Option<String>, key: Option<String>) -> Result<Option<TlsConfig>> {
+    let (Some(cert), Some(key)) = (cert, key) else {
+        return Ok(None);
+    };
+    let tls = TlsConfig::new(cert, key);
+    tls.load()
+        .map_err(|e| anyhow::anyhow!("Invalid TLS configuration: {}", e))?;
+    Ok(Some(tls))
+}
+
+fn webhook_secret() -> Result<Option<String>> {
+    Ok(crate::services::config::Config::load_http_auth()?
+        .webhook_secret
+        .filter(|s| !s.is_empty()))
+}
+
 async fn start_memory_http(
     host: String,
     port: u16,
     enable_cors: bool,
+    tls: Option<TlsConfig>,
     json_mode: bool,
 ) -> Result<()> {
     let services = McpServices::init("abathur.db").await?;
@@ -307,6 +376,8 @@ async fn start_memory_http(
         host: host.clone(),
         port,
         enable_cors,
+        auth: http_auth()?,
+        tls: tls.clone(),
     };
 
     if json_mode {
@@ -315,7 +386,8 @@ async fn start_memory_http(
             "status": "starting",
             "host": host,
             "port": port,
-            "cors": enable_cors
+            "cors": enable_cors,
+            "tls": tls.is_some()
         });
         println!("{}", serde_json::to_string_pretty(&output)?);
     } else {
@@ -326,6 +398,9 @@ async fn start_memory_http(
             "   CORS: {}",
             if enable_cors { "enabled" } else { "disabled" }
         );
+        if let Some(tls) = &tls {
+            println!("   TLS:  {}", tls.cert_path);
+        }
         println!();
     }
 
@@ -339,6 +414,7 @@ async fn start_tasks_http(
     host: String,
     port: u16,
     enable_cors: bool,
+    tls: Option<TlsConfig>,
     json_mode: bool,
 ) -> Result<()> {
     let services = McpServices::init("abathur.db").await?;
@@ -348,6 +424,9 @@ async fn start_tasks_http(
         host: host.clone(),
         port,
         enable_cors,
+        auth: http_auth()?,
+        webhook_secret: webhook_secret()?,
+        tls: tls.clone(),
     };
 
     if json_mode {
@@ -356,7 +435,8 @@ async fn start_tasks_http(
             "status": "starting",
             "host": host,
             "port": port,
-            "cors": enable_cors
+            "cors": enable_cors,
+            "tls": tls.is_some()
         });
         println!("{}", serde_json::to_string_pretty(&output)?);
     } else {
@@ -367,6 +447,9 @@ async fn start_tasks_http(
             "   CORS: {}",
             if enable_cors { "enabled" } else { "disabled" }
         );
+        if let Some(tls) = &tls {
+            println!("   TLS:  {}", tls.cert_path);
+        }
         println!();
     }
 
@@ -380,6 +463,7 @@ async fn start_agents_http(
     host: String,
     port: u16,
     enable_cors: bool,
+    tls: Option<TlsConfig>,
     json_mode: bool,
 ) -> Result<()> {
     let services = McpServices::init("abathur.db").await?;
@@ -390,6 +474,8 @@ async fn start_agents_http(
         host: host.clone(),
         port,
         enable_cors,
+        auth: http_auth()?,
+        tls: tls.clone(),
     };
 
     if json_mode {
@@ -398,7 +484,8 @@ async fn start_agents_http(
             "status": "starting",
             "host": host,
             "port": port,
-            "cors": enable_cors
+            "cors": enable_cors,
+            "tls": tls.is_some()
         });
         println!("{}", serde_json::to_string_pretty(&output)?);
     } else {
@@ -409,6 +496,9 @@ async fn start_agents_http(
             "   CORS: {}",
             if enable_cors { "enabled" } else { "disabled" }
         );
+        if let Some(tls) = &tls {
+            println!("   TLS:  {}", tls.cert_path);
+        }
         println!();
     }
 
@@ -481,6 +571,7 @@ async fn start_all(
     json_mode: bool,
 ) -> Result<()> {
     let services = McpServices::init("abathur.db").await?;
+    let auth = http_auth()?;
 
     if json_mode {
         let output = serde_json::json!({
@@ -515,6 +606,8 @@ async fn start_all(
         host: host.clone(),
         port: memory_port,
         enable_cors: true,
+        auth: auth.clone(),
+        tls: None,
     };
     let memory_server = MemoryHttpServer::new(memory_service, command_bus.clone(), memory_config);
 
@@ -522,6 +615,9 @@ async fn start_all(
         host: host.clone(),
         port: tasks_port,
         enable_cors: true,
+        auth: auth.clone(),
+        webhook_secret: webhook_secret()?,
+        tls: None,
     };
     let tasks_server = TasksHttpServer::new(task_service, command_bus, tasks_config);
 
@@ -529,6 +625,8 @@ async fn start_all(
         host: host.clone(),
         port: agents_port,
         enable_cors: true,
+        auth: auth.clone(),
+        tls: None,
     };
     let agents_server = AgentsHttpServer::new(agent_service, agents_config);
 
@@ -542,6 +640,7 @@ async fn start_all(
         max_stream_duration_s: 3600,
         federation_tls: None,
         federation_jwt_secret: None,
+        auth,
     };
     let a2a_gateway = A2AHttpGateway::new(a2a_config);
     register_default_agents(&a2a_gateway).await;
@@ -4,16 +4,22 @@ use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
 use std::sync::Arc;
 
+use crate::adapters::embeddings::{OpenAiEmbeddingConfig, OpenAiEmbeddingProvider};
 use crate::adapters::sqlite::{SqliteMemoryRepository, initialize_default_database};
 use crate::cli::command_dispatcher::CliCommandDispatcher;
 use crate::cli::display::{
-    CommandOutput, DetailView, action_success, colorize_memory_tier, list_table, output,
-    relative_time_str, render_list, short_id, truncate_ellipsis,
+    CommandOutput, DetailView, action_success, colorize_memory_tier, format_bytes, list_table,
+    output, relative_time, relative_time_opt, relative_time_str, render_list, short_id,
+    truncate_ellipsis,
 };
 use crate::cli::id_resolver::resolve_memory_id;
-use crate::domain::models::{AccessorId, Memory, MemoryQuery, MemoryTier, MemoryType};
-use crate::services::MemoryService;
+use crate::domain::models::{
+    AccessorId, HybridSearchResult, Memory, MemoryMetadata, MemoryQuery, MemoryTier, MemoryType,
+    NamespaceSummary,
+};
 use crate::services::command_bus::{CommandResult, DomainCommand, MemoryCommand};
+use crate::services::event_bus::EventPayload;
+use crate::services::{EmbeddingService, MaintenancePlan, MemoryMaintenanceService, MemoryService};
 
 #[derive(Args, Debug)]
 pub struct MemoryArgs {
@@ -39,6 +45,10 @@ pub enum MemoryCommands {
         /// Type (fact, code, decision, error, pattern, reference, context)
         #[arg(long, default_value = "fact")]
         memory_type: String,
+        /// Minimum decay factor (0.0-1.0) for this memory, overriding its
+        /// tier's floor; a floored memory is never pruned for decay
+        #[arg(long)]
+        decay_floor: Option<f32>,
     },
     /// Show a memory by ID or key
     #[command(visible_alias = "recall")]
@@ -52,13 +62,21 @@ pub enum MemoryCommands {
     /// Search memories
     Search {
         /// Search query
-        query: String,
+        #[arg(required_unless_present = "query_flag")]
+        query: Option<String>,
+        /// Search query (alternative to the positional argument)
+        #[arg(long = "query", conflicts_with = "query")]
+        query_flag: Option<String>,
         /// Namespace filter
         #[arg(short, long)]
         namespace: Option<String>,
         /// Maximum results
         #[arg(short, long, default_value = "10")]
         limit: usize,
+        /// Rank by a blend of keyword (0.0) and embedding (1.0) similarity.
+        /// Uses OpenAI embeddings when OPENAI_API_KEY is set, else keyword-only.
+        #[arg(long)]
+        alpha: Option<f64>,
     },
     /// List memories
     List {
@@ -96,13 +114,29 @@ pub enum MemoryCommands {
         id: String,
     },
     /// Run maintenance (prune expired and decayed)
+    #[command(visible_alias = "gc")]
     Prune {
         /// Only prune expired (skip decay check)
         #[arg(long)]
         expired_only: bool,
+        /// Preview what would be pruned, promoted, and resolved without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Execute maintenance (the default; spell it out in scripts next to --dry-run)
+        #[arg(long, conflicts_with = "dry_run")]
+        apply: bool,
     },
     /// Show memory statistics
     Stats,
+    /// List namespaces with entry counts, size, and tier breakdown
+    Namespaces,
+    /// Re-embed memories whose stored vectors came from a different
+    /// embedding model (resumable; requires OPENAI_API_KEY)
+    Reembed {
+        /// Memories embedded and written per batch
+        #[arg(long, default_value_t = 100)]
+        batch_size: usize,
+    },
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -164,6 +198,60 @@ impl CommandOutput for MemoryListOutput {
     }
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct HybridSearchRow {
+    #[serde(flatten)]
+    pub memory: MemoryOutput,
+    pub score: f32,
+    pub lexical_score: f32,
+    pub vector_score: Option<f32>,
+}
+
+impl From<&HybridSearchResult> for HybridSearchRow {
+    fn from(result: &HybridSearchResult) -> Self {
+        Self {
+            memory: MemoryOutput::from(&result.memory),
+            score: result.score,
+            lexical_score: result.lexical_score,
+            vector_score: result.vector_score,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct HybridSearchOutput {
+    pub results: Vec<HybridSearchRow>,
+    pub total: usize,
+}
+
+impl CommandOutput for HybridSearchOutput {
+    fn to_human(&self) -> String {
+        if self.results.is_empty() {
+            return "No memories found.".to_string();
+        }
+
+        let mut table = list_table(&["ID", "Key", "Namespace", "Score", "Lexical", "Vector"]);
+
+        for row in &self.results {
+            table.add_row(vec![
+                short_id(&row.memory.id).to_string(),
+                truncate_ellipsis(&row.memory.key, 40),
+                truncate_ellipsis(&row.memory.namespace, 15),
+                format!("{:.2}", row.score),
+                format!("{:.2}", row.lexical_score),
+                row.vector_score
+                    .map_or_else(|| "-".to_string(), |v| format!("{v:.2}")),
+            ]);
+        }
+
+        render_list("memory", table, self.total)
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct MemoryDetailOutput {
     pub memory: MemoryOutput,
@@ -279,20 +367,136 @@ impl CommandOutput for MemoryStatsOutput {
     }
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct ReembedOutput {
+    pub model: String,
+    pub total: u64,
+    pub reembedded: u64,
+    pub batches: u64,
+}
+
+impl CommandOutput for ReembedOutput {
+    fn to_human(&self) -> String {
+        if self.total == 0 {
+            return format!("All memories are already embedded with {}.", self.model);
+        }
+        action_success(&format!(
+            "Re-embedded {} of {} memories with {} ({} batches)",
+            self.reembedded, self.total, self.model, self.batches
+        ))
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct NamespaceListOutput {
+    pub namespaces: Vec<NamespaceSummary>,
+    pub total: usize,
+}
+
+impl CommandOutput for NamespaceListOutput {
+    fn to_human(&self) -> String {
+        if self.namespaces.is_empty() {
+            return "No namespaces found.".to_string();
+        }
+
+        let mut table = list_table(&[
+            "Namespace",
+            "Entries",
+            "Size",
+            "Working",
+            "Episodic",
+            "Semantic",
+            "Updated",
+        ]);
+
+        for ns in &self.namespaces {
+            table.add_row(vec![
+                truncate_ellipsis(&ns.namespace, 30),
+                ns.count.to_string(),
+                format_bytes(ns.total_bytes),
+                ns.working.to_string(),
+                ns.episodic.to_string(),
+                ns.semantic.to_string(),
+                relative_time_opt(ns.last_updated.as_ref()),
+            ]);
+        }
+
+        render_list("namespace", table, self.total)
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct PruneOutput {
     pub expired_pruned: u64,
     pub decayed_pruned: u64,
     pub promoted: u64,
     pub conflicts_resolved: u64,
+    pub dry_run: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub candidates: Vec<PruneCandidate>,
+}
+
+/// A memory a dry run would act on.
+#[derive(Debug, serde::Serialize)]
+pub struct PruneCandidate {
+    /// One of `expire`, `decay`, `promote`, `resolve_conflict`.
+    pub action: String,
+    pub id: String,
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Why the memory was selected, e.g. its expiry or decay score.
+    pub reason: String,
+}
+
+impl PruneCandidate {
+    fn new(action: &str, mem: &Memory, reason: String) -> Self {
+        Self {
+            action: action.to_string(),
+            id: mem.id.to_string(),
+            key: mem.key.clone(),
+            namespace: Some(mem.namespace.clone()),
+            reason,
+        }
+    }
 }
 
 impl CommandOutput for PruneOutput {
     fn to_human(&self) -> String {
-        action_success(&format!(
-            "Maintenance complete: {} expired, {} decayed, {} promoted, {} conflicts resolved",
+        if !self.dry_run {
+            return action_success(&format!(
+                "Maintenance complete: {} expired, {} decayed, {} promoted, {} conflicts resolved",
+                self.expired_pruned, self.decayed_pruned, self.promoted, self.conflicts_resolved
+            ));
+        }
+
+        let summary = format!(
+            "Would run maintenance (dry run): {} expired, {} decayed, {} promoted, {} conflicts resolved",
             self.expired_pruned, self.decayed_pruned, self.promoted, self.conflicts_resolved
-        ))
+        );
+        if self.candidates.is_empty() {
+            return summary;
+        }
+
+        let mut table = list_table(&["Action", "ID", "Namespace", "Key", "Reason"]);
+        for c in &self.candidates {
+            table.add_row(vec![
+                c.action.clone(),
+                short_id(&c.id).to_string(),
+                c.namespace.clone().unwrap_or_else(|| "-".to_string()),
+                truncate_ellipsis(&c.key, 40),
+                c.reason.clone(),
+            ]);
+        }
+        format!("{}\n{}", summary, table)
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -307,8 +511,11 @@ pub async fn execute(args: MemoryArgs, json_mode: bool) -> Result<()> {
 
     let repo = Arc::new(SqliteMemoryRepository::new(pool.clone()));
     let event_bus = crate::cli::event_helpers::create_persistent_event_bus(pool.clone()).await;
-    let service = MemoryService::new(repo);
-    let dispatcher = CliCommandDispatcher::new(pool.clone(), event_bus);
+    let memory_config = crate::services::config::Config::load()
+        .unwrap_or_default()
+        .memory;
+    let service = MemoryService::new(repo).with_memory_config(&memory_config);
+    let dispatcher = CliCommandDispatcher::new(pool.clone(), event_bus.clone());
 
     match args.command {
         MemoryCommands::Create {
@@ -317,6 +524,7 @@ pub async fn execute(args: MemoryArgs, json_mode: bool) -> Result<()> {
             namespace,
             tier,
             memory_type,
+            decay_floor,
         } => {
             let tier = MemoryTier::parse(&tier)
                 .ok_or_else(|| anyhow::anyhow!("Invalid tier: {}", tier))?;
@@ -329,7 +537,10 @@ pub async fn execute(args: MemoryArgs, json_mode: bool) -> Result<()> {
                 namespace,
                 tier,
                 memory_type: mtype,
-                metadata: None,
+                metadata: decay_floor.map(|floor| MemoryMetadata {
+                    decay_floor: Some(floor),
+                    ..Default::default()
+                }),
             });
 
             let result = dispatcher
@@ -403,9 +614,36 @@ pub async fn execute(args: MemoryArgs, json_mode: bool) -> Result<()> {
 
         MemoryCommands::Search {
             query,
+            query_flag,
             namespace,
             limit,
+            alpha,
         } => {
+            let query = query.or(query_flag).unwrap_or_default();
+            if let Some(alpha) = alpha {
+                if !(0.0..=1.0).contains(&alpha) {
+                    anyhow::bail!("--alpha must be between 0.0 and 1.0");
+                }
+                let service = match std::env::var("OPENAI_API_KEY") {
+                    Ok(_) => service.with_embeddings(Arc::new(EmbeddingService::with_defaults(
+                        Arc::new(OpenAiEmbeddingProvider::new(
+                            OpenAiEmbeddingConfig::default(),
+                        )),
+                    ))),
+                    Err(_) => service,
+                };
+                let results = service
+                    .search_hybrid(&query, namespace.as_deref(), alpha, limit)
+                    .await?;
+
+                let out = HybridSearchOutput {
+                    total: results.len(),
+                    results: results.iter().map(HybridSearchRow::from).collect(),
+                };
+                output(&out, json_mode);
+                return Ok(());
+            }
+
             let memories = service.search(&query, namespace.as_deref(), limit).await?;
 
             let out = MemoryListOutput {
@@ -494,7 +732,75 @@ pub async fn execute(args: MemoryArgs, json_mode: bool) -> Result<()> {
             output(&out, json_mode);
         }
 
-        MemoryCommands::Prune { expired_only } => {
+        MemoryCommands::Prune {
+            expired_only,
+            dry_run: true,
+            ..
+        } => {
+            let maintenance = MemoryMaintenanceService::from_memory_service(Arc::new(service));
+            let mut plan = maintenance.maintenance_plan().await?;
+            if expired_only {
+                plan = MaintenancePlan {
+                    expired: plan.expired,
+                    ..Default::default()
+                };
+            }
+            let report = plan.report();
+
+            let mut candidates: Vec<PruneCandidate> = Vec::new();
+            let policies = maintenance.memory_service().namespace_policies();
+            for m in &plan.expired {
+                let reason = if policies.contains_key(&m.namespace) {
+                    format!(
+                        "namespace ttl exceeded, created {}",
+                        relative_time(&m.created_at)
+                    )
+                } else {
+                    format!("ttl expired {}", relative_time_opt(m.expires_at.as_ref()))
+                };
+                candidates.push(PruneCandidate::new("expire", m, reason));
+            }
+            for m in &plan.decayed {
+                let reason = format!(
+                    "decay score {:.3} below threshold",
+                    maintenance.memory_service().decay_factor(m)
+                );
+                candidates.push(PruneCandidate::new("decay", m, reason));
+            }
+            for m in &plan.promoted {
+                let reason = format!(
+                    "{} accesses by {} accessors",
+                    m.access_count,
+                    m.distinct_accessor_count()
+                );
+                candidates.push(PruneCandidate::new("promote", m, reason));
+            }
+            for conflict in plan.conflicts.iter().filter(|c| c.is_auto_resolvable()) {
+                candidates.push(PruneCandidate {
+                    action: "resolve_conflict".to_string(),
+                    id: conflict.memory_a.to_string(),
+                    key: conflict.key.clone(),
+                    namespace: None,
+                    reason: format!(
+                        "{:.0}% similar to {}",
+                        conflict.similarity * 100.0,
+                        short_id(&conflict.memory_b.to_string())
+                    ),
+                });
+            }
+
+            let out = PruneOutput {
+                expired_pruned: report.expired_pruned,
+                decayed_pruned: report.decayed_pruned,
+                promoted: report.promoted,
+                conflicts_resolved: report.conflicts_resolved,
+                dry_run: true,
+                candidates,
+            };
+            output(&out, json_mode);
+        }
+
+        MemoryCommands::Prune { expired_only, .. } => {
             let cmd = if expired_only {
                 DomainCommand::Memory(MemoryCommand::PruneExpired)
             } else {
@@ -522,6 +828,8 @@ pub async fn execute(args: MemoryArgs, json_mode: bool) -> Result<()> {
                 decayed_pruned: report.decayed_pruned,
                 promoted: report.promoted,
                 conflicts_resolved: report.conflicts_resolved,
+                dry_run: false,
+                candidates: Vec::new(),
             };
             output(&out, json_mode);
         }
@@ -537,6 +845,48 @@ pub async fn execute(args: MemoryArgs, json_mode: bool) -> Result<()> {
             };
             output(&out, json_mode);
         }
+
+        MemoryCommands::Namespaces => {
+            let namespaces = service.list_namespaces().await?;
+            let out = NamespaceListOutput {
+                total: namespaces.len(),
+                namespaces,
+            };
+            output(&out, json_mode);
+        }
+
+        MemoryCommands::Reembed { batch_size } => {
+            if std::env::var("OPENAI_API_KEY").is_err() {
+                anyhow::bail!("OPENAI_API_KEY is not set; no embedding model to re-embed with");
+            }
+            let embeddings = EmbeddingService::with_defaults(Arc::new(
+                OpenAiEmbeddingProvider::new(OpenAiEmbeddingConfig::default()),
+            ));
+            if !json_mode {
+                let mut progress = event_bus.subscribe();
+                tokio::spawn(async move {
+                    while let Ok(event) = progress.recv().await {
+                        if let EventPayload::MemoryReembedProgress {
+                            processed, total, ..
+                        } = event.payload
+                        {
+                            eprintln!("Re-embedded {}/{} memories", processed, total);
+                        }
+                    }
+                });
+            }
+            let report = service
+                .reembed_all(&embeddings, batch_size, &event_bus)
+                .await?;
+
+            let out = ReembedOutput {
+                model: report.model,
+                total: report.total,
+                reembedded: report.reembedded,
+                batches: report.batches,
+            };
+            output(&out, json_mode);
+        }
     }
 
     Ok(())
@@ -605,4 +955,35 @@ mod tests {
             _ => panic!("Expected List command"),
         }
     }
+
+    #[test]
+    fn parse_reembed_batch_size() {
+        let cli = Cli::parse_from(["memory", "reembed"]);
+        assert!(matches!(
+            cli.command,
+            MemoryCommands::Reembed { batch_size: 100 }
+        ));
+
+        let cli = Cli::parse_from(["memory", "reembed", "--batch-size", "8"]);
+        assert!(matches!(
+            cli.command,
+            MemoryCommands::Reembed { batch_size: 8 }
+        ));
+    }
+
+    #[test]
+    fn parse_prune_apply_conflicts_with_dry_run() {
+        let cli = Cli::parse_from(["memory", "prune", "--apply"]);
+        assert!(matches!(
+            cli.command,
+            MemoryCommands::Prune {
+                apply: true,
+                dry_run: false,
+                ..
+            }
+        ));
+
+        let err = Cli::try_parse_from(["memory", "prune", "--apply", "--dry-run"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
 }
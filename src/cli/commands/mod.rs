@@ -2,7 +2,11 @@
 
 pub mod adapter;
 pub mod agent;
+pub mod audit;
+pub mod config;
+pub mod convergence_loop;
 pub mod cron;
+pub mod db;
 pub mod event;
 pub mod goal;
 pub mod init;
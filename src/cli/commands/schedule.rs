@@ -11,6 +11,7 @@ use crate::cli::display::{
     CommandOutput, DetailView, action_failure, action_success, colorize_status, list_table, output,
     relative_time_str, render_list, short_id, truncate_ellipsis,
 };
+use crate::cli::exit_code::CliError;
 use crate::cli::id_resolver::resolve_schedule_id;
 use crate::domain::models::TaskPriority;
 use crate::domain::models::task_schedule::*;
@@ -352,7 +353,12 @@ pub async fn execute(args: ScheduleArgs, json_mode: bool) -> Result<()> {
             };
 
             let priority = TaskPriority::parse(&priority).unwrap_or(TaskPriority::Normal);
-            let overlap_policy = OverlapPolicy::parse(&overlap).unwrap_or(OverlapPolicy::Skip);
+            let overlap_policy = OverlapPolicy::parse(&overlap).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid overlap policy: {} (expected skip, allow or cancel_previous)",
+                    overlap
+                )
+            })?;
 
             let mut schedule = TaskSchedule::new(
                 name,
@@ -503,5 +509,5 @@ async fn find_schedule(
         return Ok(schedule);
     }
 
-    anyhow::bail!("Task schedule not found: {}", id_or_name)
+    Err(CliError::not_found("task schedule", id_or_name).into())
 }
@@ -13,6 +13,7 @@ use crate::adapters::sqlite::{
     SqliteAgentRepository, SqliteGoalRepository, SqliteMemoryRepository, SqliteTaskRepository,
     SqliteTrajectoryRepository, SqliteWorktreeRepository,
 };
+use crate::domain::errors::DomainError;
 use crate::domain::models::goal_federation::{ContractSignal, ConvergenceContract};
 use crate::domain::models::swarm_dag::{SwarmDag, SwarmDagNode, SwarmDagNodeState};
 use crate::services::overseers::{
@@ -184,14 +185,87 @@ pub enum SwarmCommand {
     },
     /// Stop the running swarm orchestrator
     Stop,
+    /// Pause the swarm: no new tasks are dispatched until resumed
+    Pause,
+    /// Resume a paused swarm
+    Resume,
     /// Show current swarm status
-    Status,
+    #[command(after_help = "\
+Examples:
+  abathur swarm status
+  abathur swarm status --watch
+  abathur swarm status --watch --interval 10
+")]
+    Status {
+        /// Redraw task counts, active agents, and token rate until Ctrl-C
+        #[arg(long)]
+        watch: bool,
+        /// Seconds between refreshes with --watch
+        #[arg(
+            long,
+            default_value = "2",
+            requires = "watch",
+            value_parser = clap::value_parser!(u64).range(1..)
+        )]
+        interval: u64,
+    },
     /// List active goals and tasks
     Active,
+    /// Preview what a drain would wait on: running tasks and work left unstarted
+    DrainReport,
+    /// Report task throughput and estimate accuracy over a recent window
+    Velocity {
+        /// Reporting window (e.g. "24h", "7d", "2w")
+        #[arg(long, default_value = "7d")]
+        window: String,
+    },
+    /// Show activity for this session and all time
+    Stats {
+        /// Start a new session: later stats count only activity from now on
+        #[arg(long)]
+        reset: bool,
+    },
+    /// Export active goals, their tasks, and dependency edges as one graph
+    ExportGraph {
+        /// Output format
+        #[arg(long, value_enum, default_value = "dot")]
+        format: GraphFormat,
+        /// Only include this goal (ID or prefix) and the tasks in its domains
+        #[arg(long)]
+        goal: Option<String>,
+    },
+    /// Force-reset a stuck task to a runnable state, killing its agent
+    RestartTask {
+        /// Task ID (UUID or prefix)
+        id: String,
+        /// State to reset the task to
+        #[arg(long, value_enum, default_value = "ready")]
+        to: RestartTarget,
+        /// Reason recorded with the restart
+        #[arg(long, default_value = "manual restart")]
+        reason: String,
+    },
+    /// Check swarm state for problems (pending migrations, missing indexes,
+    /// dead Running tasks, orphaned worktrees, dependency cycles, orphaned
+    /// workflow subtasks)
+    Doctor {
+        /// Repair what is found; each repair is idempotent
+        #[arg(long)]
+        fix: bool,
+        /// With --fix, also apply repairs that can discard work (removing
+        /// orphaned worktrees with uncommitted changes, failing orphaned
+        /// subtasks no live task can adopt)
+        #[arg(long, short, requires = "fix")]
+        yes: bool,
+    },
     /// Show swarm configuration
     Config,
     /// Run a single tick (process one cycle)
-    Tick,
+    Tick {
+        /// Report why each Ready task would or would not spawn, without ticking
+        #[arg(long)]
+        explain: bool,
+    },
     /// List pending human escalations
     Escalations,
     /// Respond to a human escalation
@@ -239,6 +313,33 @@ pub enum SwarmCommand {
     },
 }
 
+/// Output format for `swarm export-graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum GraphFormat {
+    /// Graphviz DOT with one cluster per goal
+    Dot,
+    /// Goals, tasks, and edges as a JSON document
+    Json,
+}
+
+/// Target state for `swarm restart-task`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RestartTarget {
+    /// Eligible for immediate dispatch
+    Ready,
+    /// Re-checked against dependencies before dispatch
+    Pending,
+}
+
+impl From<RestartTarget> for crate::domain::models::TaskStatus {
+    fn from(target: RestartTarget) -> Self {
+        match target {
+            RestartTarget::Ready => Self::Ready,
+            RestartTarget::Pending => Self::Pending,
+        }
+    }
+}
+
 /// Subcommands for swarm DAG management.
 #[derive(Subcommand, Debug)]
 pub enum DagCommand {
@@ -290,10 +391,27 @@ pub async fn execute(args: SwarmArgs, json_mode: bool) -> Result<()> {
             .await
         }
         SwarmCommand::Stop => stop_swarm(json_mode).await,
-        SwarmCommand::Status => show_status(json_mode).await,
+        SwarmCommand::Pause => set_paused(true, json_mode).await,
+        SwarmCommand::Resume => set_paused(false, json_mode).await,
+        SwarmCommand::Status { watch: false, .. } => show_status(json_mode).await,
+        SwarmCommand::Status {
+            watch: true,
+            interval,
+        } => watch_status(std::time::Duration::from_secs(interval), json_mode).await,
         SwarmCommand::Active => show_active(json_mode).await,
+        SwarmCommand::DrainReport => show_drain_report(json_mode).await,
+        SwarmCommand::Velocity { window } => show_velocity(&window, json_mode).await,
+        SwarmCommand::Stats { reset } => show_stats(reset, json_mode).await,
+        SwarmCommand::ExportGraph { format, goal } => {
+            let format = if json_mode { GraphFormat::Json } else { format };
+            export_graph(format, goal.as_deref()).await
+        }
+        SwarmCommand::RestartTask { id, to, reason } => {
+            restart_task(&id, to, &reason, json_mode).await
+        }
+        SwarmCommand::Doctor { fix, yes } => run_doctor(fix, yes, json_mode).await,
         SwarmCommand::Config => show_config(json_mode).await,
-        SwarmCommand::Tick => run_tick(json_mode).await,
+        SwarmCommand::Tick { explain } => run_tick(explain, json_mode).await,
         SwarmCommand::Escalations => show_escalations(json_mode).await,
         SwarmCommand::Respond {
             id,
@@ -369,7 +487,7 @@ fn remove_pid_file() -> Result<()> {
 }
 
 /// Check if swarm is already running
-fn check_existing_swarm() -> Option<u32> {
+pub(crate) fn check_existing_swarm() -> Option<u32> {
     read_pid_file().and_then(|pid| {
         if is_process_running(pid) {
             Some(pid)
@@ -648,6 +766,56 @@ async fn stop_swarm(json_mode: bool) -> Result<()> {
     Ok(())
 }
 
+/// Set the persisted pause flag. A running orchestrator picks it up on its
+/// next loop iteration; a stopped swarm starts in the requested state.
+async fn set_paused(paused: bool, json_mode: bool) -> Result<()> {
+    use crate::adapters::sqlite::{SqliteSwarmControlRepository, initialize_default_database};
+    use crate::domain::ports::SwarmControlRepository;
+
+    let pool = initialize_default_database().await?;
+    let control = SqliteSwarmControlRepository::new(pool);
+    let was_paused = control.is_paused().await?;
+    control.set_paused(paused).await?;
+
+    let swarm_pid = check_existing_swarm();
+    let status = match (swarm_pid, paused) {
+        (None, _) => "not_running",
+        (Some(_), true) => "paused",
+        (Some(_), false) => "running",
+    };
+    let message = match (swarm_pid, paused) {
+        (Some(_), true) => "No new tasks will be dispatched; running agents finish their work",
+        (Some(_), false) => "Task dispatch resumes on the next orchestrator tick",
+        (None, true) => "No swarm is currently running; it will start paused",
+        (None, false) => "No swarm is currently running; it will start unpaused",
+    };
+
+    if json_mode {
+        let mut output = serde_json::json!({
+            "status": status,
+            "paused": paused,
+            "changed": was_paused != paused,
+            "message": message
+        });
+        if let Some(pid) = swarm_pid {
+            output["pid"] = serde_json::json!(pid);
+        }
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        let verb = if paused { "paused" } else { "resumed" };
+        match swarm_pid {
+            Some(pid) if was_paused == paused => {
+                println!("Swarm already {} (PID: {})", verb, pid)
+            }
+            Some(pid) => println!("Swarm {} (PID: {})", verb, pid),
+            None => {}
+        }
+        println!("{}", message);
+    }
+
+    Ok(())
+}
+
 // reason: see start_swarm above; this is the foreground sibling and shares
 // the same args one-for-one. A struct would be reconstructed from the same
 // clap-derived locals.
@@ -667,6 +835,7 @@ async fn run_swarm_foreground(
     use crate::adapters::sqlite::{Migrator, all_embedded_migrations, create_pool};
     use crate::adapters::substrates::SubstrateRegistry;
     use crate::domain::models::{ExecutionMode, SubstrateType};
+    use crate::domain::ports::SubstrateFactory;
     use crate::services::McpServerConfig;
 
     // Write PID file for foreground mode too (so status works)
@@ -696,12 +865,21 @@ async fn run_swarm_foreground(
     let agent_repo = Arc::new(SqliteAgentRepository::new(pool.clone()));
     let memory_repo = Arc::new(SqliteMemoryRepository::new(pool.clone()));
 
+    // Load application config (abathur.toml) for workflow and polling settings
+    let app_config = match crate::services::config::Config::load() {
+        Ok(c) => c,
+        Err(e) => {
+            tracing::warn!("Failed to load abathur.toml, using defaults: {}", e);
+            crate::services::config::Config::default()
+        }
+    };
+
     // Start MCP servers if requested
     let mcp_server_handles = if with_mcp_servers {
         if !json_mode {
             println!("Starting MCP servers...");
         }
-        Some(start_mcp_servers(pool.clone(), &mcp_urls, json_mode).await?)
+        Some(start_mcp_servers(pool.clone(), &mcp_urls, &app_config.memory, json_mode).await?)
     } else {
         None
     };
@@ -738,15 +916,6 @@ async fn run_swarm_foreground(
         }
     };
 
-    // Load application config (abathur.toml) for workflow and polling settings
-    let app_config = match crate::services::config::Config::load() {
-        Ok(c) => c,
-        Err(e) => {
-            tracing::warn!("Failed to load abathur.toml, using defaults: {}", e);
-            crate::services::config::Config::default()
-        }
-    };
-
     // Resolve the workflow template for this swarm. An explicit --workflow flag
     // wins; otherwise fall back to `config.default_workflow`. Both must resolve
     // against inline workflows or YAML files in `workflows_dir` — there are no
@@ -775,6 +944,7 @@ async fn run_swarm_foreground(
 
     let config = SwarmConfig {
         max_agents,
+        agent_slot_weights: app_config.limits.agent_slot_weights.clone(),
         mcp_servers: mcp_server_config,
         default_execution_mode: execution_mode,
         workflow_template,
@@ -788,6 +958,20 @@ async fn run_swarm_foreground(
         },
         overmind_max_turns: Some(app_config.overmind.max_turns),
         fetch_on_sync: app_config.worktrees.fetch_on_sync,
+        reuse_chained_worktrees: app_config.worktrees.reuse_for_chains,
+        force_worktree_cleanup: app_config.worktrees.force_cleanup,
+        exploration_epsilon: app_config.scheduling.exploration_epsilon,
+        priority_aging_coefficient: app_config.scheduling.priority_aging_coefficient,
+        model_escalation: app_config.model_escalation.clone(),
+        retry_backoff: app_config.retry_backoff.clone(),
+        memory_retrieval: app_config.memory_retrieval.clone(),
+        memory: app_config.memory.clone(),
+        task_routing: app_config.task_routing.clone(),
+        max_context_tokens: app_config
+            .substrates
+            .iter()
+            .filter_map(|(name, s)| Some((name.clone(), s.max_context_tokens?)))
+            .collect(),
         ..Default::default()
     };
 
@@ -810,15 +994,28 @@ async fn run_swarm_foreground(
         .with_store(event_store.clone()),
     );
 
+    // Singleton handlers (pruning, reconciliation) only run in whichever
+    // swarm process holds the leader lease on this database.
+    let leader_election = Arc::new(crate::services::LeaderElection::new(
+        Arc::new(crate::adapters::sqlite::SqliteLeaderLeaseRepository::new(
+            pool.clone(),
+        )),
+        event_bus.process_id().to_string(),
+    ));
+
     // Create EventReactor and EventScheduler.
     // Built-in handlers and schedules are registered by the orchestrator
     // in its run() method via register_builtin_handlers/register_builtin_schedules.
     let reactor = Arc::new(
         crate::services::EventReactor::new(
             event_bus.clone(),
-            crate::services::ReactorConfig::default(),
+            crate::services::ReactorConfig {
+                replay_page_size: config.polling.event_replay_page_size,
+                ..Default::default()
+            },
         )
-        .with_store(event_store),
+        .with_store(event_store)
+        .with_leader_election(leader_election),
     );
 
     let scheduler = Arc::new(
@@ -844,9 +1041,53 @@ async fn run_swarm_foreground(
         crate::services::adapter_registry::AdapterRegistry::from_loaded(
             loaded_adapters,
             prompt_content,
+        )
+        .with_ingestion_settings(
+            crate::services::adapter_registry::IngestionSettings::from_config(&app_config.adapters),
         ),
     );
 
+    // Alternatives to run tasks on while the primary's circuit breaker is open.
+    let failover_substrates: Vec<Arc<dyn crate::domain::ports::Substrate>> = if dry_run {
+        Vec::new()
+    } else {
+        app_config
+            .substrates
+            .get(substrate.name())
+            .map(|s| s.failover.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|name| registry.create(name).map(Arc::from))
+            .collect()
+    };
+
+    // Substrates to spread spawns over when `substrate_routing` is set: the
+    // primary (weight 1 unless configured) plus every weighted alternative.
+    let substrate_routing = match app_config.substrate_routing {
+        Some(policy) if !dry_run => {
+            let primary_weight = app_config
+                .substrates
+                .get(substrate.name())
+                .and_then(|s| s.weight)
+                .unwrap_or(1);
+            let mut pool = SubstrateRegistry::new()
+                .with_substrate(substrate.clone())
+                .with_weight(substrate.name(), primary_weight);
+            for (name, settings) in &app_config.substrates {
+                if let Some(weight) = settings.weight
+                    && name != substrate.name()
+                    && let Some(alternative) = registry.create(name)
+                {
+                    pool = pool
+                        .with_substrate(Arc::from(alternative))
+                        .with_weight(name.as_str(), weight);
+                }
+            }
+            Some((pool, policy))
+        }
+        _ => None,
+    };
+
     let orchestrator = SwarmOrchestrator::new(
         goal_repo.clone(),
         task_repo,
@@ -864,7 +1105,19 @@ async fn run_swarm_foreground(
     .with_trajectory_repo(trajectory_repo)
     .with_overseer_cluster(overseer_cluster)
     .with_pool(pool.clone())
-    .with_adapter_registry(adapter_registry);
+    .with_adapter_registry(adapter_registry)
+    .with_failover_substrates(failover_substrates)
+    .with_guardrails(crate::services::GuardrailsConfig {
+        max_agent_spawns_per_minute: app_config.limits.max_agent_spawns_per_minute,
+        max_agent_spawns_per_hour: app_config.limits.max_agent_spawns_per_hour,
+        per_type_limits: app_config.limits.max_agents_per_type.clone(),
+        ..Default::default()
+    });
+
+    let orchestrator = match substrate_routing {
+        Some((pool, policy)) => orchestrator.with_substrate_routing(pool, policy),
+        None => orchestrator,
+    };
 
     // Wire up budget-aware scheduling using thresholds from abathur.toml [budget] section
     let orchestrator = {
@@ -879,6 +1132,28 @@ async fn run_swarm_foreground(
         orchestrator.with_budget_tracker(tracker)
     };
 
+    // Wire up agent result caching from [result_cache] config
+    let orchestrator = if app_config.result_cache.enabled {
+        orchestrator.with_result_cache(Arc::new(crate::services::result_cache::ResultCache::new(
+            &app_config.result_cache,
+        )))
+    } else {
+        orchestrator
+    };
+
+    // Wire up escalation notifications from [notifications] config
+    let orchestrator = if app_config.notifications.escalation_routes.is_empty()
+        && app_config.notifications.sinks.is_empty()
+    {
+        orchestrator
+    } else {
+        orchestrator.with_escalation_notifier(Arc::new(
+            crate::services::escalation_notifier::EscalationNotifier::from_config(
+                &app_config.notifications,
+            ),
+        ))
+    };
+
     // Wire up quiet-window scheduling from [scheduling] + [[quiet_windows]] config
     let orchestrator = if app_config.scheduling.quiet_hours_enabled {
         let qw_repo = Arc::new(crate::adapters::sqlite::SqliteQuietWindowRepository::new(
@@ -1736,16 +2011,20 @@ struct McpServerHandles {
 async fn start_mcp_servers(
     pool: sqlx::SqlitePool,
     urls: &McpServerUrls,
+    memory_config: &crate::services::config::MemoryConfig,
     json_mode: bool,
 ) -> Result<McpServerHandles> {
     use crate::adapters::mcp::{
-        A2AHttpConfig, A2AHttpGateway, EventsHttpConfig, EventsHttpServer, MemoryHttpConfig,
-        MemoryHttpServer, TasksHttpConfig, TasksHttpServer,
+        A2AHttpConfig, A2AHttpGateway, EventsHttpConfig, EventsHttpServer, HttpAuth,
+        MemoryHttpConfig, MemoryHttpServer, TasksHttpConfig, TasksHttpServer,
     };
     use crate::adapters::sqlite::SqliteEventRepository;
     use crate::services::command_bus::CommandBus;
     use crate::services::{EventBus, EventBusConfig, GoalService, MemoryService, TaskService};
 
+    let http_auth = crate::services::config::Config::load_http_auth()?;
+    let auth = HttpAuth::from_config(&http_auth);
+
     let mut handles = McpServerHandles {
         memory_handle: None,
         tasks_handle: None,
@@ -1756,7 +2035,7 @@ async fn start_mcp_servers(
 
     // Create shared CommandBus for MCP servers
     let memory_repo = Arc::new(SqliteMemoryRepository::new(pool.clone()));
-    let memory_service = MemoryService::new(memory_repo);
+    let memory_service = MemoryService::new(memory_repo).with_memory_config(memory_config);
     let maintenance_service = Arc::new(
         crate::services::memory_maintenance_service::MemoryMaintenanceService::from_memory_service(
             Arc::new(memory_service.clone()),
@@ -1789,6 +2068,7 @@ async fn start_mcp_servers(
         let port = extract_port(url).unwrap_or(9100);
         let config = MemoryHttpConfig {
             port,
+            auth: auth.clone(),
             ..Default::default()
         };
         let server = MemoryHttpServer::new(memory_service.clone(), command_bus.clone(), config);
@@ -1809,6 +2089,8 @@ async fn start_mcp_servers(
         let port = extract_port(url).unwrap_or(9101);
         let config = TasksHttpConfig {
             port,
+            auth: auth.clone(),
+            webhook_secret: http_auth.webhook_secret.clone().filter(|s| !s.is_empty()),
             ..Default::default()
         };
         let server = TasksHttpServer::new(task_service, command_bus, config);
@@ -1829,6 +2111,7 @@ async fn start_mcp_servers(
         let port = extract_port(url).unwrap_or(8080);
         let config = A2AHttpConfig {
             port,
+            auth: auth.clone(),
             ..Default::default()
         };
         let gateway = A2AHttpGateway::new(config);
@@ -1859,6 +2142,7 @@ async fn start_mcp_servers(
             Arc::new(EventBus::new(EventBusConfig::default()).with_store(event_store.clone()));
         let config = EventsHttpConfig {
             port,
+            auth: auth.clone(),
             ..Default::default()
         };
         let server = EventsHttpServer::new(event_bus, Some(event_store), config);
@@ -1966,7 +2250,19 @@ async fn show_status(json_mode: bool) -> Result<()> {
         .map(|t| t.len())
         .unwrap_or(0);
 
-    let status = if swarm_running { "running" } else { "stopped" };
+    let paused = {
+        use crate::adapters::sqlite::SqliteSwarmControlRepository;
+        use crate::domain::ports::SwarmControlRepository;
+        SqliteSwarmControlRepository::new(pool.clone())
+            .is_paused()
+            .await
+            .unwrap_or(false)
+    };
+    let status = match (swarm_running, paused) {
+        (true, true) => "paused",
+        (true, false) => "running",
+        (false, _) => "stopped",
+    };
 
     // Load federation config if available
     let federation_info = {
@@ -2001,6 +2297,7 @@ async fn show_status(json_mode: bool) -> Result<()> {
     if json_mode {
         let mut output = serde_json::json!({
             "status": status,
+            "paused": paused,
             "active_goals": active_goals,
             "pending_tasks": pending_tasks,
             "running_tasks": running_tasks,
@@ -2034,7 +2331,11 @@ async fn show_status(json_mode: bool) -> Result<()> {
         println!("Swarm Status");
         println!("============");
         if swarm_running {
-            println!("Orchestrator:     RUNNING (PID: {})", swarm_pid.unwrap());
+            println!(
+                "Orchestrator:     {} (PID: {})",
+                status.to_uppercase(),
+                swarm_pid.unwrap()
+            );
         } else {
             println!("Orchestrator:     STOPPED");
         }
@@ -2068,6 +2369,196 @@ async fn show_status(json_mode: bool) -> Result<()> {
     Ok(())
 }
 
+/// A StatusUpdate older than this is not trusted as live orchestrator state.
+const WATCH_STATUS_EVENT_MAX_AGE: chrono::Duration = chrono::Duration::minutes(2);
+
+/// Window over which `swarm status --watch` averages the token rate.
+const WATCH_TOKEN_RATE_WINDOW: std::time::Duration = std::time::Duration::from_secs(3600);
+
+/// One refresh of `swarm status --watch`.
+#[derive(Debug, serde::Serialize)]
+struct WatchSnapshot {
+    at: chrono::DateTime<chrono::Utc>,
+    /// `"orchestrator"` when read from the running swarm's latest
+    /// StatusUpdate event, `"database"` when counted from the task table.
+    source: &'static str,
+    active_goals: usize,
+    pending_tasks: usize,
+    ready_tasks: usize,
+    running_tasks: usize,
+    completed_tasks: usize,
+    failed_tasks: usize,
+    /// Only known when the orchestrator is reporting.
+    active_agents: Option<usize>,
+    tokens_per_hour: Option<u64>,
+}
+
+/// Token usage samples for a rolling tokens-per-hour rate.
+#[derive(Debug, Default)]
+struct TokenRate {
+    samples: std::collections::VecDeque<(std::time::Instant, u64)>,
+}
+
+impl TokenRate {
+    /// Record the cumulative token count at `at` and return the hourly rate
+    /// over the retained window, or `None` until two samples are a second
+    /// apart.
+    fn record(&mut self, at: std::time::Instant, tokens_used: u64) -> Option<u64> {
+        // A lower total means the counters were reset; start over.
+        if self.samples.back().is_some_and(|&(_, t)| t > tokens_used) {
+            self.samples.clear();
+        }
+        self.samples.push_back((at, tokens_used));
+        while self
+            .samples
+            .front()
+            .is_some_and(|&(t, _)| at.duration_since(t) > WATCH_TOKEN_RATE_WINDOW)
+        {
+            self.samples.pop_front();
+        }
+
+        let &(first_at, first_tokens) = self.samples.front()?;
+        let elapsed = at.duration_since(first_at).as_secs_f64();
+        if elapsed < 1.0 {
+            return None;
+        }
+        Some(((tokens_used - first_tokens) as f64 * 3600.0 / elapsed).round() as u64)
+    }
+}
+
+/// Redraw swarm counters every `interval` until Ctrl-C.
+///
+/// While an orchestrator is running, counts come from the StatusUpdate
+/// events it persists to the event store, which also carry the active agent
+/// count. Otherwise tasks are counted directly from the database. The token
+/// rate comes from the persisted usage counters in both cases. In JSON mode
+/// each refresh is printed as one line.
+async fn watch_status(interval: std::time::Duration, json_mode: bool) -> Result<()> {
+    use std::io::{IsTerminal, Write};
+
+    use crate::adapters::sqlite::{SqliteEventRepository, SqliteStatsRepository, create_pool};
+    use crate::domain::models::{GoalStatus, TaskStatus};
+    use crate::domain::ports::{GoalFilter, GoalRepository, StatsRepository, TaskRepository};
+    use crate::services::event_bus::{EventCategory, EventPayload};
+    use crate::services::event_store::{EventQuery, EventStore};
+
+    let pool = create_pool("sqlite:.abathur/abathur.db", None).await?;
+    let task_repo = SqliteTaskRepository::new(pool.clone());
+    let goal_repo = SqliteGoalRepository::new(pool.clone());
+    let stats_repo = SqliteStatsRepository::new(pool.clone());
+    let event_store =
+        SqliteEventRepository::new(pool, crate::services::crypto::load_encryptor_from_env());
+
+    let redraw = !json_mode && std::io::stdout().is_terminal();
+    let mut token_rate = TokenRate::default();
+
+    loop {
+        let now = chrono::Utc::now();
+        let live_stats = if check_existing_swarm().is_some() {
+            event_store
+                .query(
+                    EventQuery::new()
+                        .category(EventCategory::Orchestrator)
+                        .since_time(now - WATCH_STATUS_EVENT_MAX_AGE)
+                        .limit(100),
+                )
+                .await?
+                .into_iter()
+                .find_map(|event| match event.payload {
+                    EventPayload::StatusUpdate(stats) => Some(stats),
+                    _ => None,
+                })
+        } else {
+            None
+        };
+        let tokens_per_hour = token_rate.record(
+            std::time::Instant::now(),
+            stats_repo.counters().await?.tokens_used,
+        );
+
+        let snapshot = match live_stats {
+            Some(stats) => WatchSnapshot {
+                at: now,
+                source: "orchestrator",
+                active_goals: stats.active_goals,
+                pending_tasks: stats.pending_tasks,
+                ready_tasks: stats.ready_tasks,
+                running_tasks: stats.running_tasks,
+                completed_tasks: stats.completed_tasks,
+                failed_tasks: stats.failed_tasks,
+                active_agents: Some(stats.active_agents),
+                tokens_per_hour,
+            },
+            None => {
+                let counts = task_repo.count_by_status().await?;
+                let count = |status| counts.get(&status).copied().unwrap_or(0) as usize;
+                let active_goals = goal_repo
+                    .list(GoalFilter {
+                        status: Some(GoalStatus::Active),
+                        ..Default::default()
+                    })
+                    .await?
+                    .len();
+                WatchSnapshot {
+                    at: now,
+                    source: "database",
+                    active_goals,
+                    pending_tasks: count(TaskStatus::Pending),
+                    ready_tasks: count(TaskStatus::Ready),
+                    running_tasks: count(TaskStatus::Running),
+                    completed_tasks: count(TaskStatus::Complete),
+                    failed_tasks: count(TaskStatus::Failed),
+                    active_agents: None,
+                    tokens_per_hour,
+                }
+            }
+        };
+
+        if json_mode {
+            println!("{}", serde_json::to_string(&snapshot)?);
+        } else {
+            if redraw {
+                // Clear the screen and home the cursor so the view updates in place.
+                print!("\x1b[2J\x1b[H");
+            }
+            let dash = || "-".to_string();
+            println!(
+                "Swarm Status ({}, every {}s, Ctrl-C to exit)",
+                snapshot.at.format("%H:%M:%S"),
+                interval.as_secs()
+            );
+            println!("============");
+            println!("Source:           {}", snapshot.source);
+            println!("Active goals:     {}", snapshot.active_goals);
+            println!(
+                "Active agents:    {}",
+                snapshot.active_agents.map_or_else(dash, |n| n.to_string())
+            );
+            println!(
+                "Tokens/hour:      {}",
+                snapshot
+                    .tokens_per_hour
+                    .map_or_else(dash, |n| n.to_string())
+            );
+            println!();
+            println!("Pending:          {}", snapshot.pending_tasks);
+            println!("Ready:            {}", snapshot.ready_tasks);
+            println!("Running:          {}", snapshot.running_tasks);
+            println!("Complete:         {}", snapshot.completed_tasks);
+            println!("Failed:           {}", snapshot.failed_tasks);
+            if !redraw {
+                println!();
+            }
+        }
+        std::io::stdout().flush()?;
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+}
+
 async fn show_active(json_mode: bool) -> Result<()> {
     use crate::adapters::sqlite::create_pool;
     use crate::domain::models::{GoalStatus, TaskStatus};
@@ -2122,6 +2613,520 @@ async fn show_active(json_mode: bool) -> Result<()> {
     Ok(())
 }
 
+/// A running task a drain would wait for.
+#[derive(Debug, serde::Serialize)]
+struct DrainTask {
+    id: Uuid,
+    title: String,
+    agent_type: Option<String>,
+    elapsed_secs: u64,
+    estimate_secs: Option<u64>,
+    /// Expected time left; zero once a task has overrun its estimate.
+    remaining_secs: Option<u64>,
+    /// `"estimate"` when derived from the task's own estimate,
+    /// `"agent_average"` when from the agent type's mean duration.
+    remaining_source: Option<&'static str>,
+}
+
+/// What stopping the swarm now would wait on and leave behind.
+#[derive(Debug, serde::Serialize)]
+struct DrainReport {
+    swarm_running: bool,
+    running: Vec<DrainTask>,
+    /// Ready tasks a drain will not start.
+    ready_not_started: usize,
+    /// Tasks still waiting on dependencies.
+    pending: usize,
+    /// Longest expected remaining time among running tasks: a lower bound
+    /// for a drain timeout that lets them all finish.
+    longest_remaining_secs: Option<u64>,
+    /// Running tasks with neither an estimate nor agent history to go on.
+    unestimated: usize,
+}
+
+impl DrainReport {
+    fn build(
+        swarm_running: bool,
+        running: &[crate::domain::models::Task],
+        ready_not_started: usize,
+        pending: usize,
+        agent_metrics: &[crate::services::task_service::AgentTypeMetrics],
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        let mut tasks: Vec<DrainTask> = running
+            .iter()
+            .map(|task| {
+                let elapsed_secs = task
+                    .started_at
+                    .map_or(0, |s| (now - s).num_seconds().max(0) as u64);
+                let agent_avg = agent_metrics
+                    .iter()
+                    .find(|m| Some(m.agent_type.as_str()) == task.agent_type.as_deref())
+                    .and_then(|m| m.avg_duration_secs);
+                let (expected, remaining_source) = match (task.estimate_secs, agent_avg) {
+                    (Some(est), _) => (Some(est), Some("estimate")),
+                    (None, Some(avg)) => (Some(avg as u64), Some("agent_average")),
+                    (None, None) => (None, None),
+                };
+                DrainTask {
+                    id: task.id,
+                    title: task.title.clone(),
+                    agent_type: task.agent_type.clone(),
+                    elapsed_secs,
+                    estimate_secs: task.estimate_secs,
+                    remaining_secs: expected.map(|e| e.saturating_sub(elapsed_secs)),
+                    remaining_source,
+                }
+            })
+            .collect();
+        // Longest-running first: those are the ones a drain waits on.
+        tasks.sort_by_key(|t| std::cmp::Reverse(t.elapsed_secs));
+
+        Self {
+            swarm_running,
+            longest_remaining_secs: tasks.iter().filter_map(|t| t.remaining_secs).max(),
+            unestimated: tasks.iter().filter(|t| t.remaining_secs.is_none()).count(),
+            running: tasks,
+            ready_not_started,
+            pending,
+        }
+    }
+}
+
+async fn show_drain_report(json_mode: bool) -> Result<()> {
+    use crate::adapters::sqlite::create_pool;
+    use crate::cli::display::{format_secs, truncate_ellipsis};
+    use crate::domain::models::TaskStatus;
+    use crate::domain::ports::TaskRepository;
+    use crate::services::task_service::TaskService;
+
+    let pool = create_pool("sqlite:.abathur/abathur.db", None).await?;
+    let task_repo = Arc::new(SqliteTaskRepository::new(pool));
+    let running = task_repo.list_by_status(TaskStatus::Running).await?;
+    let ready = task_repo.list_by_status(TaskStatus::Ready).await?.len();
+    let pending = task_repo.list_by_status(TaskStatus::Pending).await?.len();
+    let agent_metrics = TaskService::new(task_repo)
+        .get_agent_type_metrics(None, None)
+        .await?;
+
+    let report = DrainReport::build(
+        check_existing_swarm().is_some(),
+        &running,
+        ready,
+        pending,
+        &agent_metrics,
+        chrono::Utc::now(),
+    );
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    let secs = |v: Option<u64>| v.map_or_else(|| "-".to_string(), format_secs);
+    println!(
+        "Drain Report (orchestrator {})",
+        if report.swarm_running {
+            "RUNNING"
+        } else {
+            "STOPPED"
+        }
+    );
+    println!("\nRunning Tasks ({}):", report.running.len());
+    for task in &report.running {
+        println!(
+            "  {} {:<40} {:<16} elapsed {:>7}  remaining {:>7}{}",
+            &task.id.to_string()[..8],
+            truncate_ellipsis(&task.title, 40),
+            task.agent_type.as_deref().unwrap_or("-"),
+            format_secs(task.elapsed_secs),
+            secs(task.remaining_secs),
+            match task.remaining_source {
+                Some("agent_average") => " (agent avg)",
+                _ => "",
+            }
+        );
+    }
+    println!(
+        "\nReady tasks that won't start: {}",
+        report.ready_not_started
+    );
+    println!("Pending tasks:                {}", report.pending);
+    println!(
+        "Longest expected remaining:   {}",
+        secs(report.longest_remaining_secs)
+    );
+    if report.unestimated > 0 {
+        println!(
+            "  ({} running task(s) have no estimate or agent history)",
+            report.unestimated
+        );
+    }
+
+    Ok(())
+}
+
+async fn show_velocity(window: &str, json_mode: bool) -> Result<()> {
+    use crate::adapters::sqlite::create_pool;
+    use crate::cli::display::{format_secs, parse_duration};
+    use crate::domain::ports::TaskRepository;
+
+    let since = chrono::Utc::now() - parse_duration(window)?;
+    let pool = create_pool("sqlite:.abathur/abathur.db", None).await?;
+    let task_repo = SqliteTaskRepository::new(pool);
+    let velocity = task_repo.velocity_since(since).await?;
+
+    if json_mode {
+        let mut output = serde_json::to_value(&velocity)?;
+        output["window"] = serde_json::json!(window);
+        output["throughput_per_day"] = serde_json::json!(velocity.throughput_per_day());
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        let secs = |v: Option<f64>| v.map_or_else(|| "-".to_string(), |s| format_secs(s as u64));
+        println!("Velocity (last {})", window);
+        println!("  Completed:        {}", velocity.completed);
+        println!(
+            "  Throughput:       {:.1} tasks/day",
+            velocity.throughput_per_day()
+        );
+        println!("  Avg duration:     {}", secs(velocity.avg_actual_secs));
+        println!("\nEstimates ({} tasks):", velocity.estimated);
+        println!("  Avg estimate:     {}", secs(velocity.avg_estimate_secs));
+        println!("  Mean abs error:   {}", secs(velocity.mean_abs_error_secs));
+        match velocity.mean_error_secs {
+            Some(e) if e >= 0.0 => {
+                println!("  Bias:             +{} (overrun)", format_secs(e as u64))
+            }
+            Some(e) => println!(
+                "  Bias:             -{} (underrun)",
+                format_secs((-e) as u64)
+            ),
+            None => println!("  Bias:             -"),
+        }
+    }
+
+    Ok(())
+}
+
+async fn show_stats(reset: bool, json_mode: bool) -> Result<()> {
+    use crate::adapters::sqlite::{SqliteStatsRepository, create_pool};
+    use crate::domain::models::SwarmCounters;
+    use crate::domain::ports::StatsRepository;
+
+    let pool = create_pool("sqlite:.abathur/abathur.db", None).await?;
+    let stats_repo = SqliteStatsRepository::new(pool);
+
+    let all_time = stats_repo.counters().await?;
+    let baseline = if reset {
+        Some(stats_repo.reset_baseline().await?)
+    } else {
+        stats_repo.latest_baseline().await?
+    };
+    let session = match &baseline {
+        Some(b) => stats_repo.counters_since(b.reset_at).await?,
+        None => all_time,
+    };
+    let session_start = baseline.as_ref().map(|b| b.reset_at);
+
+    if json_mode {
+        let output = serde_json::json!({
+            "reset": reset,
+            "session_start": session_start,
+            "session": session,
+            "all_time": all_time,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        if reset {
+            println!("Stats baseline reset; a new session starts now.\n");
+        }
+        let print_counters = |c: &SwarmCounters| {
+            println!("  Tasks completed:  {}", c.tasks_completed);
+            println!("  Tasks failed:     {}", c.tasks_failed);
+            println!("  Agent runs:       {}", c.executions);
+            println!("  Tokens used:      {}", c.tokens_used);
+        };
+        match session_start {
+            Some(start) => println!(
+                "This session (since {})",
+                start.format("%Y-%m-%d %H:%M:%S UTC")
+            ),
+            None => println!("This session (no baseline; same as all time)"),
+        }
+        print_counters(&session);
+        println!("\nAll time");
+        print_counters(&all_time);
+    }
+
+    Ok(())
+}
+
+/// Fill colors cycled across goal clusters in DOT output.
+const GOAL_COLORS: &[&str] = &[
+    "lightgoldenrod1",
+    "lightblue",
+    "palegreen",
+    "lightpink",
+    "plum",
+    "lightsalmon",
+];
+
+/// A goal node in the exported swarm graph.
+#[derive(Debug, serde::Serialize)]
+struct GraphGoal {
+    id: Uuid,
+    name: String,
+    priority: String,
+    domains: Vec<String>,
+    /// Tasks whose inferred domains this goal applies to.
+    task_ids: Vec<Uuid>,
+}
+
+/// A task node in the exported swarm graph.
+#[derive(Debug, serde::Serialize)]
+struct GraphTask {
+    id: Uuid,
+    title: String,
+    status: String,
+    agent_type: Option<String>,
+    /// Goal whose cluster the task is drawn in, if any.
+    cluster_goal_id: Option<Uuid>,
+}
+
+/// A dependency edge: `from` must complete before `to` can run.
+#[derive(Debug, serde::Serialize)]
+struct GraphEdge {
+    from: Uuid,
+    to: Uuid,
+}
+
+/// The active goal→task→dependency structure of the swarm.
+#[derive(Debug, serde::Serialize)]
+struct SwarmGraph {
+    goals: Vec<GraphGoal>,
+    tasks: Vec<GraphTask>,
+    edges: Vec<GraphEdge>,
+}
+
+impl SwarmGraph {
+    /// Relate goals to tasks by inferred domain and collect dependency edges.
+    ///
+    /// A goal with no applicability domains applies to every task. A task is
+    /// drawn in the cluster of its explicit `goal_id` when that goal is
+    /// present, otherwise in the first goal that applies to it. When
+    /// `only_goal_tasks` is set, tasks no goal applies to are left out.
+    fn build(
+        goals: &[crate::domain::models::Goal],
+        tasks: Vec<crate::domain::models::Task>,
+        only_goal_tasks: bool,
+    ) -> Self {
+        use crate::services::GoalContextService;
+
+        let mut graph_goals: Vec<GraphGoal> = goals
+            .iter()
+            .map(|g| GraphGoal {
+                id: g.id,
+                name: g.name.clone(),
+                priority: g.priority.as_str().to_string(),
+                domains: g.applicability_domains.clone(),
+                task_ids: Vec::new(),
+            })
+            .collect();
+
+        let mut graph_tasks = Vec::new();
+        for task in &tasks {
+            let domains = GoalContextService::<SqliteGoalRepository>::infer_task_domains(task);
+            let matching: Vec<usize> = goals
+                .iter()
+                .enumerate()
+                .filter(|(_, g)| {
+                    g.applicability_domains.is_empty()
+                        || g.applicability_domains.iter().any(|d| domains.contains(d))
+                })
+                .map(|(i, _)| i)
+                .collect();
+            if only_goal_tasks && matching.is_empty() {
+                continue;
+            }
+            for &i in &matching {
+                graph_goals[i].task_ids.push(task.id);
+            }
+            let cluster_goal_id = task
+                .goal_id()
+                .filter(|id| goals.iter().any(|g| g.id == *id))
+                .or_else(|| matching.first().map(|&i| goals[i].id));
+            graph_tasks.push(GraphTask {
+                id: task.id,
+                title: task.title.clone(),
+                status: task.status.as_str().to_string(),
+                agent_type: task.agent_type.clone(),
+                cluster_goal_id,
+            });
+        }
+
+        let included: std::collections::HashSet<Uuid> = graph_tasks.iter().map(|t| t.id).collect();
+        let edges = tasks
+            .iter()
+            .filter(|t| included.contains(&t.id))
+            .flat_map(|t| {
+                t.depends_on
+                    .iter()
+                    .filter(|dep| included.contains(dep))
+                    .map(|&dep| GraphEdge {
+                        from: dep,
+                        to: t.id,
+                    })
+            })
+            .collect();
+
+        Self {
+            goals: graph_goals,
+            tasks: graph_tasks,
+            edges,
+        }
+    }
+
+    /// Render as Graphviz DOT with one filled cluster per goal.
+    fn to_dot(&self) -> String {
+        use std::fmt::Write;
+
+        fn esc(s: &str) -> String {
+            s.replace('\\', "\\\\").replace('"', "\\\"")
+        }
+        fn task_node(out: &mut String, indent: &str, task: &GraphTask) {
+            let _ = writeln!(
+                out,
+                "{}\"{}\" [label=\"{}\\n[{}]\"];",
+                indent,
+                task.id,
+                esc(&task.title),
+                task.status
+            );
+        }
+
+        let mut out =
+            String::from("digraph swarm {\n  rankdir=LR;\n  node [shape=box, style=rounded];\n");
+        for (i, goal) in self.goals.iter().enumerate() {
+            let color = GOAL_COLORS[i % GOAL_COLORS.len()];
+            let _ = writeln!(out, "  subgraph \"cluster_{}\" {{", goal.id);
+            let _ = writeln!(
+                out,
+                "    label=\"{} ({})\";\n    style=filled;\n    color={};",
+                esc(&goal.name),
+                goal.priority,
+                color
+            );
+            let _ = writeln!(
+                out,
+                "    \"{}\" [label=\"{}\", shape=ellipse, style=\"filled,bold\", fillcolor=white];",
+                goal.id,
+                esc(&goal.name)
+            );
+            for task in self
+                .tasks
+                .iter()
+                .filter(|t| t.cluster_goal_id == Some(goal.id))
+            {
+                task_node(&mut out, "    ", task);
+            }
+            out.push_str("  }\n");
+        }
+        for task in self.tasks.iter().filter(|t| t.cluster_goal_id.is_none()) {
+            task_node(&mut out, "  ", task);
+        }
+        for edge in &self.edges {
+            let _ = writeln!(out, "  \"{}\" -> \"{}\";", edge.from, edge.to);
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+async fn export_graph(format: GraphFormat, goal: Option<&str>) -> Result<()> {
+    use crate::adapters::sqlite::create_pool;
+    use crate::cli::id_resolver::resolve_goal_id;
+    use crate::domain::models::GoalStatus;
+    use crate::domain::ports::{GoalFilter, GoalRepository, TaskFilter, TaskRepository};
+
+    let pool = create_pool("sqlite:.abathur/abathur.db", None).await?;
+    let goal_repo = SqliteGoalRepository::new(pool.clone());
+    let task_repo = SqliteTaskRepository::new(pool.clone());
+
+    let goals = match goal {
+        Some(prefix) => {
+            let id = resolve_goal_id(&pool, prefix).await?;
+            let goal = goal_repo
+                .get(id)
+                .await?
+                .ok_or(DomainError::GoalNotFound(id))?;
+            vec![goal]
+        }
+        None => {
+            goal_repo
+                .list(GoalFilter {
+                    status: Some(GoalStatus::Active),
+                    ..Default::default()
+                })
+                .await?
+        }
+    };
+    let tasks: Vec<_> = task_repo
+        .list(TaskFilter::default())
+        .await?
+        .into_iter()
+        .filter(|t| !t.is_terminal())
+        .collect();
+
+    let graph = SwarmGraph::build(&goals, tasks, goal.is_some());
+    match format {
+        GraphFormat::Dot => print!("{}", graph.to_dot()),
+        GraphFormat::Json => println!("{}", serde_json::to_string_pretty(&graph)?),
+    }
+    Ok(())
+}
+
+async fn run_doctor(fix: bool, yes: bool, json_mode: bool) -> Result<()> {
+    use crate::adapters::sqlite::create_pool;
+    use crate::services::swarm_doctor::{RepairOutcome, SwarmDoctor};
+
+    let pool = create_pool("sqlite:.abathur/abathur.db", None).await?;
+    let doctor = SwarmDoctor::new(pool, check_existing_swarm().is_some());
+    let findings = if fix {
+        doctor.fix(yes).await?
+    } else {
+        doctor.diagnose().await?
+    };
+
+    if json_mode {
+        let output = serde_json::json!({
+            "fix": fix,
+            "findings": findings,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if findings.is_empty() {
+        println!("No problems found.");
+        return Ok(());
+    }
+    for f in &findings {
+        let marker = match f.outcome {
+            RepairOutcome::Found => "found",
+            RepairOutcome::Fixed => "fixed",
+            RepairOutcome::Skipped => "skipped",
+            RepairOutcome::Failed => "FAILED",
+        };
+        println!("[{:<7}] {:<20} {}", marker, f.check.as_str(), f.detail);
+    }
+    if !fix {
+        println!("\nRun `abathur swarm doctor --fix` to repair.");
+    } else if findings.iter().any(|f| f.outcome == RepairOutcome::Skipped) {
+        println!("\nSkipped repairs can discard work; rerun with --fix --yes to apply them.");
+    }
+    Ok(())
+}
+
 async fn show_config(json_mode: bool) -> Result<()> {
     let config = SwarmConfig::default();
 
@@ -2209,6 +3214,49 @@ async fn show_escalations(json_mode: bool) -> Result<()> {
     Ok(())
 }
 
+/// Reset a wedged task through the command bus. The resulting
+/// `TaskRestarted` event is persisted, so a running swarm picks it up and
+/// kills the task's agent process.
+async fn restart_task(id: &str, to: RestartTarget, reason: &str, json_mode: bool) -> Result<()> {
+    use crate::adapters::sqlite::create_pool;
+    use crate::cli::command_dispatcher::CliCommandDispatcher;
+    use crate::cli::commands::task::{TaskActionOutput, TaskOutput};
+    use crate::cli::display::output;
+    use crate::cli::id_resolver::resolve_task_id;
+    use crate::services::command_bus::{CommandResult, DomainCommand, TaskCommand};
+
+    let pool = create_pool("sqlite:.abathur/abathur.db", None).await?;
+    let task_id = resolve_task_id(&pool, id).await?;
+    let event_bus = crate::cli::event_helpers::create_persistent_event_bus(pool.clone()).await;
+    let dispatcher = CliCommandDispatcher::new(pool, event_bus);
+
+    let result = dispatcher
+        .dispatch(DomainCommand::Task(TaskCommand::Restart {
+            task_id,
+            to: to.into(),
+            reason: reason.to_string(),
+        }))
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let CommandResult::Task(task) = result else {
+        anyhow::bail!("Unexpected command result");
+    };
+
+    let out = TaskActionOutput {
+        success: true,
+        message: format!(
+            "Restarted task {} -> {} (retry {}): {}",
+            task.id,
+            task.status.as_str(),
+            task.retry_count,
+            reason
+        ),
+        task: Some(TaskOutput::from(&task)),
+    };
+    output(&out, json_mode);
+    Ok(())
+}
+
 async fn respond_to_escalation(
     id: &str,
     decision: &str,
@@ -2284,7 +3332,7 @@ async fn respond_to_escalation(
     Ok(())
 }
 
-async fn run_tick(json_mode: bool) -> Result<()> {
+async fn run_tick(explain: bool, json_mode: bool) -> Result<()> {
     let config = SwarmConfig {
         use_worktrees: false, // Disable worktrees for tick command
         ..SwarmConfig::default()
@@ -2292,6 +3340,10 @@ async fn run_tick(json_mode: bool) -> Result<()> {
 
     let orchestrator = build_cli_orchestrator(config).await?;
 
+    if explain {
+        return explain_spawn_decisions(&orchestrator, json_mode).await;
+    }
+
     let stats = orchestrator.tick().await?;
 
     if json_mode {
@@ -2317,6 +3369,37 @@ async fn run_tick(json_mode: bool) -> Result<()> {
     Ok(())
 }
 
+/// Print the gate each Ready task is blocked on (or that it would spawn).
+async fn explain_spawn_decisions(orchestrator: &CliOrchestrator, json_mode: bool) -> Result<()> {
+    let decisions = orchestrator.explain_ready_tasks(100).await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&decisions)?);
+        return Ok(());
+    }
+
+    if decisions.is_empty() {
+        println!("No ready tasks.");
+        return Ok(());
+    }
+
+    for d in &decisions {
+        let agent = d.agent_type.as_deref().unwrap_or("-");
+        match d.blocked_by {
+            None => println!("{}  spawn   agent={}", d.task_id, agent),
+            Some(gate) => println!(
+                "{}  blocked {}  agent={}  {}",
+                d.task_id,
+                serde_json::to_value(gate)?.as_str().unwrap_or("unknown"),
+                agent,
+                d.reason
+            ),
+        }
+    }
+
+    Ok(())
+}
+
 // ============================================================================
 // Federation CLI Commands
 // ============================================================================
@@ -2847,3 +3930,46 @@ fn print_dag_nodes(dag: &crate::domain::models::swarm_dag::SwarmDag) {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn token_rate_needs_two_samples() {
+        let mut rate = TokenRate::default();
+        let start = Instant::now();
+        assert_eq!(rate.record(start, 1_000), None);
+        assert_eq!(rate.record(start + Duration::from_millis(500), 1_500), None);
+        assert_eq!(
+            rate.record(start + Duration::from_secs(60), 11_000),
+            Some(600_000)
+        );
+    }
+
+    #[test]
+    fn token_rate_restarts_after_counter_reset() {
+        let mut rate = TokenRate::default();
+        let start = Instant::now();
+        rate.record(start, 50_000);
+        assert_eq!(rate.record(start + Duration::from_secs(10), 100), None);
+        assert_eq!(
+            rate.record(start + Duration::from_secs(46), 1_100),
+            Some(100_000)
+        );
+    }
+
+    #[test]
+    fn token_rate_drops_samples_outside_window() {
+        let mut rate = TokenRate::default();
+        let start = Instant::now();
+        rate.record(start, 0);
+        rate.record(start + Duration::from_secs(1800), 1_000_000);
+        // The first sample falls out of the hour window.
+        assert_eq!(
+            rate.record(start + Duration::from_secs(5400), 2_000_000),
+            Some(1_000_000)
+        );
+    }
+}
@@ -2,19 +2,41 @@
 
 use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
+use futures::future::{BoxFuture, FutureExt};
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
 
-use crate::adapters::sqlite::{SqliteTaskRepository, initialize_default_database};
+use crate::adapters::sqlite::{
+    SqliteEventRepository, SqliteTaskRepository, SqliteWorktreeRepository,
+    initialize_default_database,
+};
 use crate::cli::command_dispatcher::CliCommandDispatcher;
 use crate::cli::display::{
-    CommandOutput, DetailView, action_success, colorize_priority, colorize_status, list_table,
-    output, relative_time_str, render_list, short_id, truncate_ellipsis,
+    CommandOutput, DetailView, Tone, action_success, colorize_priority, colorize_status,
+    format_secs, list_table, output, paint, relative_time_str, render_list, short_id,
+    truncate_ellipsis,
 };
 use crate::cli::id_resolver::{resolve_goal_id, resolve_task_id};
-use crate::domain::models::{Task, TaskContext, TaskPriority, TaskSource, TaskStatus, TaskType};
-use crate::domain::ports::TaskFilter;
+use crate::domain::errors::DomainError;
+use crate::domain::models::{
+    ExecutionParameters, Task, TaskContext, TaskPriority, TaskSource, TaskStatus, TaskType,
+    WorkspaceMode,
+};
+use crate::domain::ports::{TaskFilter, TaskRepository, WorktreeRepository};
 use crate::services::TaskService;
 use crate::services::command_bus::{CommandResult, DomainCommand, TaskCommand};
+use crate::services::dag_restructure::{
+    DagRestructureService, RestructureApplied, RestructurePlan, TaskRef,
+};
+use crate::services::event_bus::{EventCategory, EventPayload, SequenceNumber, UnifiedEvent};
+use crate::services::event_store::{EventQuery, EventStore};
+use crate::services::overseers::canonical_overseer_name;
+use crate::services::task_service::AgentTypeMetrics;
+
+/// How often `task logs --follow` polls the event store.
+const LOGS_FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 /// CLI-local priority enum — maps to `TaskPriority` after clap parsing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
@@ -36,6 +58,24 @@ impl From<CliPriority> for TaskPriority {
     }
 }
 
+/// CLI-local workspace mode — maps to `WorkspaceMode` after clap parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CliWorkspaceMode {
+    Auto,
+    Ephemeral,
+    Worktree,
+}
+
+impl From<CliWorkspaceMode> for WorkspaceMode {
+    fn from(m: CliWorkspaceMode) -> Self {
+        match m {
+            CliWorkspaceMode::Auto => WorkspaceMode::Auto,
+            CliWorkspaceMode::Ephemeral => WorkspaceMode::Ephemeral,
+            CliWorkspaceMode::Worktree => WorkspaceMode::Worktree,
+        }
+    }
+}
+
 #[derive(Args, Debug)]
 pub struct TaskArgs {
     #[command(subcommand)]
@@ -89,6 +129,32 @@ Examples:
         /// Associate with a goal (UUID or prefix)
         #[arg(long)]
         goal: Option<String>,
+        /// Effort estimate (e.g. "30m", "2h", "1d")
+        #[arg(long)]
+        estimate: Option<String>,
+        /// Override the agent's turn budget for this task
+        #[arg(long)]
+        max_turns: Option<u32>,
+        /// Override the sampling temperature for this task (0.0-1.0)
+        #[arg(long)]
+        temperature: Option<f32>,
+        /// Overseers that must pass once the agent completes, e.g.
+        /// "compilation,tests"; a failure marks the task Failed for retry
+        #[arg(long, value_delimiter = ',')]
+        verify: Vec<String>,
+        /// JSON Schema the agent's final output must match, inline or as a
+        /// path to a .json file; mismatches are retried with a corrective hint
+        #[arg(long)]
+        output_schema: Option<String>,
+        /// Allow a read-only agent to reuse the result of an identical
+        /// earlier task when the repository is unchanged (needs
+        /// [result_cache] enabled)
+        #[arg(long)]
+        cacheable: bool,
+        /// Where the agent runs: "ephemeral" in the repo root, "worktree" in
+        /// a dedicated git worktree, or "auto" to infer from the agent role
+        #[arg(long, value_enum)]
+        workspace: Option<CliWorkspaceMode>,
     },
     /// List tasks
     List {
@@ -98,6 +164,12 @@ Examples:
         /// Filter by priority
         #[arg(short, long)]
         priority: Option<String>,
+        /// Only show tasks at or above this priority
+        #[arg(long, value_enum)]
+        min_priority: Option<CliPriority>,
+        /// Only show tasks at or below this priority
+        #[arg(long, value_enum)]
+        max_priority: Option<CliPriority>,
         /// Filter by agent type
         #[arg(short, long)]
         agent: Option<String>,
@@ -110,6 +182,10 @@ Examples:
         /// Show only ready tasks
         #[arg(long)]
         ready: bool,
+        /// Only show tasks whose title or description matches these words,
+        /// best match first
+        #[arg(long, conflicts_with = "ready")]
+        search: Option<String>,
         /// Maximum number of results
         #[arg(short, long, default_value = "50")]
         limit: usize,
@@ -119,6 +195,34 @@ Examples:
         /// Task ID
         id: String,
     },
+    /// Show the tasks a task is blocked by and the tasks it blocks, as a tree
+    #[command(after_help = "\
+Examples:
+  abathur task depends 3f2a
+  abathur task depends 3f2a --depth 2
+  abathur task depends 3f2a --json
+")]
+    Depends {
+        /// Task ID (UUID or prefix)
+        id: String,
+        /// Maximum levels (at least 1) to follow in each direction (unlimited by default)
+        #[arg(long)]
+        depth: Option<usize>,
+    },
+    /// Plan DAG restructuring for permanently failed tasks under a root task
+    #[command(after_help = "\
+Examples:
+  abathur task restructure 3f2a --dry-run
+  abathur task restructure 3f2a
+  abathur task restructure 3f2a --dry-run --json
+")]
+    Restructure {
+        /// Root task ID (UUID or prefix); its subtasks and their dependents are considered
+        root: String,
+        /// Show the planned changes without applying them
+        #[arg(long)]
+        dry_run: bool,
+    },
     /// Cancel a task
     Cancel {
         /// Task ID
@@ -129,8 +233,33 @@ Examples:
         /// Task ID
         id: String,
     },
+    /// Re-run a completed task as a new linked task (e.g. to verify it)
+    Rerun {
+        /// Task ID of the completed task
+        id: String,
+        /// Start from a fresh worktree instead of the original task's worktree
+        #[arg(long)]
+        fresh: bool,
+    },
+    /// Show an agent's output for a task (replays the stored transcript)
+    Logs {
+        /// Task ID (UUID or prefix)
+        id: String,
+        /// Keep printing new output while the task runs
+        #[arg(short, long)]
+        follow: bool,
+    },
     /// Show task status summary
     Status,
+    /// Show success, failure and retry rates per agent type
+    Metrics {
+        /// Only report this agent type
+        #[arg(long)]
+        agent_type: Option<String>,
+        /// Only count tasks finished within this window (e.g. 24h, 7d)
+        #[arg(long)]
+        since: Option<String>,
+    },
     /// Force-transition a task to a new status (bypasses state machine checks)
     #[command(after_help = "\
 Examples:
@@ -148,6 +277,23 @@ Examples:
         #[arg(long)]
         reason: String,
     },
+    /// Edit a task's context: set custom keys or add hints (e.g. to unstick an agent)
+    #[command(after_help = "\
+Examples:
+  abathur task update abc123 --set-context workflow_phase='\"review\"'
+  abathur task update abc123 --set-context max_files=5 --set-context flags='[\"a\"]'
+  abathur task update abc123 --add-hint \"the fixture lives in tests/data\"
+")]
+    Update {
+        /// Task ID (UUID or prefix)
+        id: String,
+        /// Set a custom context key to a JSON value (repeatable); other keys are kept
+        #[arg(long = "set-context", value_name = "KEY=JSON", value_parser = parse_context_entry)]
+        set_context: Vec<(String, serde_json::Value)>,
+        /// Append a hint to the task context (repeatable)
+        #[arg(long = "add-hint", value_name = "TEXT")]
+        add_hint: Vec<String>,
+    },
     /// Unstick a task that is stuck in an intermediate state (e.g. Validating deadlock)
     #[command(after_help = "\
 Examples:
@@ -200,6 +346,78 @@ Examples:
         #[arg(short, long)]
         limit: Option<usize>,
     },
+    /// Export every task (with dependencies and context) as JSON
+    #[command(after_help = "\
+Examples:
+  abathur task export --output tasks.json
+  abathur task export > tasks.json
+")]
+    Export {
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Import tasks from a file written by `task export`
+    #[command(after_help = "\
+Examples:
+  abathur task import --input tasks.json
+
+Tasks whose IDs already exist are imported under new IDs, and references
+to them inside the file follow. A task depending on one that is neither in
+the file nor in the database is imported as blocked.
+")]
+    Import {
+        /// Input file produced by `task export`
+        #[arg(short, long)]
+        input: String,
+    },
+    /// Manage recurring task submissions
+    #[command(after_help = "\
+Examples:
+  abathur task schedule add --cron \"0 9 * * 1\" --template dependency-updater
+  abathur task schedule list
+  abathur task schedule remove dependency-updater-schedule
+")]
+    Schedule {
+        #[command(subcommand)]
+        command: TaskScheduleCommands,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TaskScheduleCommands {
+    /// Submit a fresh task for an agent template on a cron schedule
+    Add {
+        /// Cron expression (5-field: min hour dom month dow)
+        #[arg(long)]
+        cron: String,
+        /// Agent template each fired task is assigned to
+        #[arg(long)]
+        template: String,
+        /// Task prompt (defaults to the template's description)
+        #[arg(long)]
+        prompt: Option<String>,
+        /// Schedule name (defaults to "<template>-schedule")
+        #[arg(long)]
+        name: Option<String>,
+        /// Priority for created tasks
+        #[arg(short, long, default_value = "normal")]
+        priority: CliPriority,
+        /// Overlap policy (skip, allow, cancel_previous)
+        #[arg(long, default_value = "skip")]
+        overlap: String,
+    },
+    /// List recurring task schedules
+    List {
+        /// Filter by status (active, paused, completed)
+        #[arg(long)]
+        status: Option<String>,
+    },
+    /// Remove a recurring task schedule
+    Remove {
+        /// Schedule ID or name
+        id_or_name: String,
+    },
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -272,6 +490,14 @@ pub struct TaskDetailOutput {
     pub created_at: String,
     pub started_at: Option<String>,
     pub completed_at: Option<String>,
+    pub estimate_secs: Option<u64>,
+    pub duration_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_params: Option<ExecutionParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supersedes: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub required_overseers: Vec<String>,
     pub context_custom: std::collections::HashMap<String, serde_json::Value>,
 }
 
@@ -286,6 +512,10 @@ impl CommandOutput for TaskDetailOutput {
             )
             .field("Type", &self.task.task_type)
             .field_opt("Agent", self.task.agent_type.as_deref())
+            .field_opt(
+                "Substrate",
+                self.context_custom.get("substrate").and_then(|v| v.as_str()),
+            )
             .field("Source", "human")
             .section("Description");
 
@@ -340,6 +570,13 @@ impl CommandOutput for TaskDetailOutput {
             }
         }
 
+        if let Some(serde_json::Value::String(partial)) = self.context_custom.get("partial_output")
+        {
+            view = view
+                .section("Partial Output")
+                .item(&truncate_ellipsis(partial, 500));
+        }
+
         view = view
             .section("Timing")
             .field(
@@ -368,6 +605,27 @@ impl CommandOutput for TaskDetailOutput {
             )
             .field("Retries", &self.task.retry_count.to_string());
 
+        if let Some(est) = self.estimate_secs {
+            view = view.field("Estimate", &format_secs(est));
+        }
+        if let Some(dur) = self.duration_secs {
+            view = view.field("Duration", &format_secs(dur));
+        }
+        if let Some(params) = &self.execution_params {
+            if let Some(turns) = params.max_turns {
+                view = view.field("Max turns", &turns.to_string());
+            }
+            if let Some(temp) = params.temperature {
+                view = view.field("Temperature", &temp.to_string());
+            }
+        }
+
+        if let Some(original) = &self.supersedes {
+            view = view.field("Supersedes", original);
+        }
+        if !self.required_overseers.is_empty() {
+            view = view.field("Verify", &self.required_overseers.join(", "));
+        }
         if let Some(path) = &self.worktree_path {
             view = view.field("Worktree", path);
         }
@@ -380,6 +638,265 @@ impl CommandOutput for TaskDetailOutput {
     }
 }
 
+/// One task in a `task depends` tree. Upstream nodes only fill `blocked_by`
+/// and downstream nodes only fill `blocks`; the root fills both.
+#[derive(Debug, serde::Serialize)]
+pub struct DependencyNode {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub blocked_by: Vec<DependencyNode>,
+    pub blocks: Vec<DependencyNode>,
+    /// Already shown earlier in the tree (a cycle or shared dependency), so not expanded again.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub repeated: bool,
+    /// Has further links that `--depth` cut off.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+impl From<&Task> for DependencyNode {
+    fn from(task: &Task) -> Self {
+        Self {
+            id: task.id.to_string(),
+            title: task.title.clone(),
+            status: task.status.as_str().to_string(),
+            blocked_by: Vec::new(),
+            blocks: Vec::new(),
+            repeated: false,
+            truncated: false,
+        }
+    }
+}
+
+impl DependencyNode {
+    fn tree_lines(&self, upstream: bool, depth: usize, lines: &mut Vec<String>) {
+        let children = if upstream {
+            &self.blocked_by
+        } else {
+            &self.blocks
+        };
+        for child in children {
+            let marker = if child.repeated {
+                " (see above)"
+            } else if child.truncated {
+                " …"
+            } else {
+                ""
+            };
+            lines.push(format!(
+                "{}└─ {} {} {}{}",
+                "   ".repeat(depth),
+                short_id(&child.id),
+                colorize_status(&child.status),
+                truncate_ellipsis(&child.title, 60),
+                marker
+            ));
+            child.tree_lines(upstream, depth + 1, lines);
+        }
+    }
+}
+
+impl CommandOutput for DependencyNode {
+    fn to_human(&self) -> String {
+        let mut view = DetailView::new(&self.title)
+            .field("ID", &self.id)
+            .field("Status", &colorize_status(&self.status).to_string());
+
+        for (header, upstream, children) in [
+            ("Blocked by", true, &self.blocked_by),
+            ("Blocks", false, &self.blocks),
+        ] {
+            view = view.section(&format!("{} ({})", header, children.len()));
+            if children.is_empty() {
+                view = view.item("(none)");
+            }
+            let mut lines = Vec::new();
+            self.tree_lines(upstream, 0, &mut lines);
+            for line in &lines {
+                view = view.item(line);
+            }
+        }
+
+        view.render()
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct TaskRestructureOutput {
+    pub root: String,
+    pub dry_run: bool,
+    pub plan: RestructurePlan,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied: Option<RestructureApplied>,
+}
+
+impl TaskRestructureOutput {
+    fn task_ref(&self, task_ref: &TaskRef) -> String {
+        match task_ref {
+            TaskRef::Existing(id) => short_id(&id.to_string()).to_string(),
+            TaskRef::Planned(key) => self
+                .plan
+                .new_tasks
+                .iter()
+                .find(|t| &t.key == key)
+                .map(|t| format!("\"{}\"", truncate_ellipsis(&t.title, 40)))
+                .unwrap_or_else(|| key.clone()),
+        }
+    }
+}
+
+impl CommandOutput for TaskRestructureOutput {
+    fn to_human(&self) -> String {
+        if self.plan.decisions.is_empty() {
+            return action_success(&format!(
+                "No permanently failed tasks to restructure under {}",
+                short_id(&self.root)
+            ));
+        }
+        let mut lines = vec![format!(
+            "Restructure plan for {}{}:",
+            short_id(&self.root),
+            if self.dry_run { " (dry run)" } else { "" }
+        )];
+        for d in &self.plan.decisions {
+            lines.push(format!(
+                "  {}: {}",
+                short_id(&d.task_id.to_string()),
+                d.decision.kind()
+            ));
+        }
+        for t in &self.plan.new_tasks {
+            lines.push(
+                paint(
+                    &format!(
+                        "+ task \"{}\" [{}, {}]",
+                        t.title,
+                        t.agent_type.as_deref().unwrap_or("any agent"),
+                        t.priority.as_str()
+                    ),
+                    Tone::Success,
+                )
+                .to_string(),
+            );
+        }
+        for e in &self.plan.edges_added {
+            lines.push(
+                paint(
+                    &format!(
+                        "+ edge {} -> {}",
+                        self.task_ref(&e.task),
+                        self.task_ref(&e.depends_on)
+                    ),
+                    Tone::Success,
+                )
+                .to_string(),
+            );
+        }
+        for e in &self.plan.edges_removed {
+            lines.push(
+                paint(
+                    &format!(
+                        "- edge {} -> {}",
+                        self.task_ref(&e.task),
+                        self.task_ref(&e.depends_on)
+                    ),
+                    Tone::Error,
+                )
+                .to_string(),
+            );
+        }
+        for u in &self.plan.description_updates {
+            lines.push(
+                paint(
+                    &format!(
+                        "~ {}: append restructure note{}",
+                        short_id(&u.task_id.to_string()),
+                        u.agent_type
+                            .as_ref()
+                            .map(|a| format!(", reassign to {}", a))
+                            .unwrap_or_default()
+                    ),
+                    Tone::Attention,
+                )
+                .to_string(),
+            );
+        }
+        for t in &self.plan.transitions {
+            lines.push(
+                paint(
+                    &format!(
+                        "~ {}: {} -> {} ({})",
+                        short_id(&t.task_id.to_string()),
+                        t.from.as_str(),
+                        t.to.as_str(),
+                        t.reason
+                    ),
+                    Tone::Attention,
+                )
+                .to_string(),
+            );
+        }
+        match &self.applied {
+            Some(a) if a.is_noop() => {
+                lines.push(action_success("Already applied; nothing changed"))
+            }
+            Some(a) => lines.push(action_success(&format!(
+                "Applied: {} task(s) created, +{}/-{} edge(s), {} transition(s), {} description update(s)",
+                a.tasks_created, a.edges_added, a.edges_removed, a.transitions, a.descriptions_updated
+            ))),
+            None if self.dry_run => lines.push("Dry run: no changes applied".to_string()),
+            None => {}
+        }
+        lines.join("\n")
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct TaskImportOutput {
+    pub imported: usize,
+    /// Original ID -> new ID for tasks whose ID was already taken.
+    pub remapped: std::collections::BTreeMap<String, String>,
+    /// Tasks imported as blocked because a dependency was missing.
+    pub blocked: Vec<String>,
+}
+
+impl CommandOutput for TaskImportOutput {
+    fn to_human(&self) -> String {
+        let mut lines = vec![action_success(&format!("Imported {} tasks", self.imported))];
+        if !self.remapped.is_empty() {
+            lines.push(format!(
+                "  {} task(s) got new IDs because theirs were taken",
+                self.remapped.len()
+            ));
+        }
+        if !self.blocked.is_empty() {
+            lines.push(format!(
+                "  {} task(s) blocked on dependencies missing from the file: {}",
+                self.blocked.len(),
+                self.blocked
+                    .iter()
+                    .map(|id| short_id(id))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct TaskActionOutput {
     pub success: bool,
@@ -444,12 +961,12 @@ impl CommandOutput for TaskStatusOutput {
             };
             let bar = "\u{2588}".repeat(bar_len);
             let colored_bar = match *label {
-                "complete" => bar.green().to_string(),
-                "failed" => bar.red().to_string(),
-                "running" => bar.yellow().to_string(),
-                "blocked" => bar.cyan().to_string(),
-                "pending" | "ready" => bar.blue().to_string(),
-                _ => bar.dimmed().to_string(),
+                "complete" => paint(&bar, Tone::Success).to_string(),
+                "failed" => paint(&bar, Tone::Error).to_string(),
+                "running" => paint(&bar, Tone::Progress).to_string(),
+                "blocked" => paint(&bar, Tone::Attention).to_string(),
+                "pending" | "ready" => paint(&bar, Tone::Info).to_string(),
+                _ => paint(&bar, Tone::Muted).to_string(),
             };
             lines.push(format!(
                 "  {:<12} {:>4}  {}",
@@ -472,6 +989,46 @@ impl CommandOutput for TaskStatusOutput {
     }
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct TaskMetricsOutput {
+    pub agents: Vec<AgentTypeMetrics>,
+    pub total: usize,
+}
+
+impl CommandOutput for TaskMetricsOutput {
+    fn to_human(&self) -> String {
+        let mut table = list_table(&[
+            "Agent Type",
+            "Total",
+            "Completed",
+            "Failed",
+            "Avg Retries",
+            "Avg Duration",
+            "Success",
+        ]);
+
+        for m in &self.agents {
+            table.add_row(vec![
+                m.agent_type.clone(),
+                m.total.to_string(),
+                m.completed.to_string(),
+                m.failed.to_string(),
+                format!("{:.2}", m.avg_retries),
+                m.avg_duration_secs
+                    .map(|d| format_secs(d.round() as u64))
+                    .unwrap_or_else(|| "-".to_string()),
+                format!("{:.0}%", m.success_rate * 100.0),
+            ]);
+        }
+
+        render_list("agent type", table, self.total)
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct TaskPruneOutput {
     pub pruned_count: usize,
@@ -510,13 +1067,13 @@ impl CommandOutput for TaskPruneOutput {
         ));
 
         for id in &self.pruned_ids {
-            lines.push(format!("  {} {}", "✓".green(), short_id(id)));
+            lines.push(format!("  {} {}", paint("✓", Tone::Success), short_id(id)));
         }
 
         if !self.skipped.is_empty() {
             lines.push(format!(
                 "\n{} {} task(s):",
-                "Skipped".yellow(),
+                paint("Skipped", Tone::Progress),
                 self.skipped.len()
             ));
             for s in &self.skipped {
@@ -546,7 +1103,7 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
 
     let task_repo = Arc::new(SqliteTaskRepository::new(pool.clone()));
     let event_bus = crate::cli::event_helpers::create_persistent_event_bus(pool.clone()).await;
-    let service = TaskService::new(task_repo);
+    let service = TaskService::new(task_repo.clone());
     let dispatcher = CliCommandDispatcher::new(pool.clone(), event_bus);
 
     match args.command {
@@ -562,6 +1119,13 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
             idempotency_key,
             deadline,
             goal,
+            estimate,
+            max_turns,
+            temperature,
+            verify,
+            output_schema,
+            cacheable,
+            workspace,
         } => {
             let prompt = match (prompt, file) {
                 (Some(p), None) => p,
@@ -608,6 +1172,24 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
                     serde_json::Value::String(gid.to_string()),
                 );
             }
+            if let Some(ref schema) = output_schema {
+                let text = if schema.trim_start().starts_with('{') {
+                    schema.clone()
+                } else {
+                    std::fs::read_to_string(schema).map_err(|e| {
+                        anyhow::anyhow!("failed to read output schema '{}': {}", schema, e)
+                    })?
+                };
+                let schema: serde_json::Value = serde_json::from_str(&text)
+                    .map_err(|e| anyhow::anyhow!("invalid --output-schema JSON: {}", e))?;
+                crate::services::output_schema::check_schema(&schema)
+                    .map_err(|e| anyhow::anyhow!("invalid --output-schema: {}", e))?;
+                ctx.custom.insert("output_schema".to_string(), schema);
+            }
+            if cacheable {
+                ctx.custom
+                    .insert("cacheable".to_string(), serde_json::Value::Bool(true));
+            }
             let context = Box::new(Some(ctx));
 
             let deadline = deadline
@@ -616,6 +1198,33 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
                 .map_err(|e| anyhow::anyhow!("Invalid deadline: {}", e))?
                 .map(|d| d.with_timezone(&chrono::Utc));
 
+            let estimate_secs = estimate
+                .map(|e| crate::cli::display::parse_duration(&e))
+                .transpose()
+                .map_err(|e| anyhow::anyhow!("Invalid estimate: {}", e))?
+                .map(|d| d.num_seconds().max(0) as u64);
+
+            let execution_params = ExecutionParameters {
+                max_turns,
+                temperature,
+                ..Default::default()
+            };
+            let execution_params =
+                (!execution_params.is_empty()).then(|| Box::new(execution_params));
+
+            let mut required_overseers: Vec<String> = Vec::new();
+            for name in &verify {
+                let canonical = canonical_overseer_name(name).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "unknown overseer '{}' in --verify (expected compilation, type-check, build, lint, security-scan, tests or acceptance-test)",
+                        name
+                    )
+                })?;
+                if !required_overseers.iter().any(|n| n == canonical) {
+                    required_overseers.push(canonical.to_string());
+                }
+            }
+
             let cmd = DomainCommand::Task(TaskCommand::Submit {
                 title,
                 description: prompt,
@@ -629,6 +1238,10 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
                 deadline,
                 task_type: None,
                 execution_mode: None,
+                estimate_secs,
+                execution_params,
+                required_overseers,
+                workspace_mode: workspace.map(WorkspaceMode::from),
             });
 
             let result = dispatcher
@@ -656,9 +1269,12 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
         TaskCommands::List {
             status,
             priority,
+            min_priority,
+            max_priority,
             agent,
             task_type,
             ready,
+            search,
             parent,
             limit,
         } => {
@@ -673,13 +1289,23 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
                 let filter = TaskFilter {
                     status: status.as_ref().and_then(|s| TaskStatus::parse(s)),
                     priority: priority.as_ref().and_then(|p| TaskPriority::parse(p)),
+                    priority_range: match (min_priority, max_priority) {
+                        (None, None) => None,
+                        (min, max) => Some((
+                            min.map_or(TaskPriority::Low, TaskPriority::from),
+                            max.map_or(TaskPriority::Critical, TaskPriority::from),
+                        )),
+                    },
                     agent_type: agent,
                     parent_id,
                     task_type: task_type.as_ref().and_then(|t| TaskType::parse(t)),
                     limit: Some(limit),
                     created_before: None,
                 };
-                service.list_tasks(filter).await?
+                match search {
+                    Some(ref query) => service.search_tasks(query, filter).await?,
+                    None => service.list_tasks(filter).await?,
+                }
             };
 
             let out = TaskListOutput {
@@ -694,7 +1320,7 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
             let task = service
                 .get_task(uuid)
                 .await?
-                .ok_or_else(|| anyhow::anyhow!("Task not found: {}", id))?;
+                .ok_or(DomainError::TaskNotFound(uuid))?;
 
             let out = TaskDetailOutput {
                 task: TaskOutput::from(&task),
@@ -704,11 +1330,95 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
                 created_at: task.created_at.to_rfc3339(),
                 started_at: task.started_at.map(|t| t.to_rfc3339()),
                 completed_at: task.completed_at.map(|t| t.to_rfc3339()),
+                estimate_secs: task.estimate_secs,
+                duration_secs: task.duration_secs,
+                execution_params: task.execution_params.clone(),
+                supersedes: task.supersedes.map(|id| id.to_string()),
+                required_overseers: task.required_overseers.clone(),
                 context_custom: task.context.custom.clone(),
             };
             output(&out, json_mode);
         }
 
+        TaskCommands::Depends { id, depth } => {
+            if depth == Some(0) {
+                anyhow::bail!("--depth must be at least 1");
+            }
+            let uuid = resolve_task_id(&pool, &id).await?;
+            let task = service
+                .get_task(uuid)
+                .await?
+                .ok_or(DomainError::TaskNotFound(uuid))?;
+
+            let tree = dependency_tree(task_repo.as_ref(), &task, depth).await?;
+            output(&tree, json_mode);
+        }
+
+        TaskCommands::Restructure { root, dry_run } => {
+            let uuid = resolve_task_id(&pool, &root).await?;
+            let root_task = service
+                .get_task(uuid)
+                .await?
+                .ok_or(DomainError::TaskNotFound(uuid))?;
+            let tasks = restructure_task_set(task_repo.as_ref(), root_task).await?;
+
+            let mut restructure = DagRestructureService::with_defaults();
+            let plan = restructure.plan(&tasks).await?;
+            let applied = if dry_run || plan.is_empty() {
+                None
+            } else {
+                Some(
+                    restructure
+                        .apply(&plan, task_repo.as_ref(), dispatcher.command_bus())
+                        .await?,
+                )
+            };
+            output(
+                &TaskRestructureOutput {
+                    root: uuid.to_string(),
+                    dry_run,
+                    plan,
+                    applied,
+                },
+                json_mode,
+            );
+        }
+
+        TaskCommands::Export {
+            output: output_file,
+        } => {
+            let tasks = task_repo.list(TaskFilter::default()).await?;
+            let content =
+                serde_json::to_string_pretty(&tasks).context("Failed to serialize tasks")?;
+
+            if let Some(path) = output_file {
+                std::fs::write(&path, &content).context(format!("Failed to write to {}", path))?;
+                let out = TaskActionOutput {
+                    success: true,
+                    message: format!("Exported {} tasks to {}", tasks.len(), path),
+                    task: None,
+                };
+                output(&out, json_mode);
+            } else {
+                println!("{}", content);
+            }
+        }
+
+        TaskCommands::Import { input } => {
+            let content =
+                std::fs::read_to_string(&input).context(format!("Failed to read {}", input))?;
+            let tasks: Vec<Task> = serde_json::from_str(&content)
+                .context(format!("{} is not a task export", input))?;
+
+            let (tasks, mut report) = prepare_import(task_repo.as_ref(), tasks).await?;
+            task_repo
+                .bulk_insert(&tasks)
+                .await
+                .context("Import failed; no tasks were written")?;
+            report.imported = tasks.len();
+            output(&report, json_mode);
+        }
+
         TaskCommands::Cancel { id } => {
             let uuid = resolve_task_id(&pool, &id).await?;
 
@@ -758,13 +1468,50 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
             output(&out, json_mode);
         }
 
+        TaskCommands::Rerun { id, fresh } => {
+            let uuid = resolve_task_id(&pool, &id).await?;
+
+            let cmd = DomainCommand::Task(TaskCommand::Rerun {
+                task_id: uuid,
+                fresh,
+            });
+
+            let result = dispatcher
+                .dispatch(cmd)
+                .await
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+            let task = match result {
+                CommandResult::Task(t) => t,
+                _ => anyhow::bail!("Unexpected command result"),
+            };
+
+            // The service hands over the original's worktree when it can;
+            // report whichever one the rerun ended up with.
+            let reused_worktree = SqliteWorktreeRepository::new(pool.clone())
+                .get_by_task(task.id)
+                .await?
+                .map(|wt| wt.path);
+
+            let mut message = format!("Task rerun: {} (supersedes {})", task.id, uuid);
+            if let Some(path) = reused_worktree {
+                message.push_str(&format!(", reusing worktree {}", path));
+            }
+            let out = TaskActionOutput {
+                success: true,
+                message,
+                task: Some(TaskOutput::from(&task)),
+            };
+            output(&out, json_mode);
+        }
+
         TaskCommands::Delete { id, force } => {
             let uuid = resolve_task_id(&pool, &id).await?;
 
             let task = service
                 .get_task(uuid)
                 .await?
-                .ok_or_else(|| anyhow::anyhow!("Task not found: {}", id))?;
+                .ok_or(DomainError::TaskNotFound(uuid))?;
 
             if !task.is_terminal() && !force {
                 anyhow::bail!(
@@ -893,13 +1640,43 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
             output(&out, json_mode);
         }
 
+        TaskCommands::Update {
+            id,
+            set_context,
+            add_hint,
+        } => {
+            if set_context.is_empty() && add_hint.is_empty() {
+                anyhow::bail!("nothing to update: pass --set-context and/or --add-hint");
+            }
+            let uuid = resolve_task_id(&pool, &id).await?;
+            let keys: Vec<String> = set_context.iter().map(|(k, _)| k.clone()).collect();
+            let hints = add_hint.len();
+            let task = service
+                .merge_task_context(uuid, set_context, add_hint)
+                .await?;
+
+            let mut changes = Vec::new();
+            if !keys.is_empty() {
+                changes.push(format!("set {}", keys.join(", ")));
+            }
+            if hints > 0 {
+                changes.push(format!("added {} hint(s)", hints));
+            }
+            let out = TaskActionOutput {
+                success: true,
+                message: format!("Updated task {} context: {}", task.id, changes.join("; ")),
+                task: Some(TaskOutput::from(&task)),
+            };
+            output(&out, json_mode);
+        }
+
         TaskCommands::Unstick { id, strategy } => {
             let uuid = resolve_task_id(&pool, &id).await?;
 
             let task = service
                 .get_task(uuid)
                 .await?
-                .ok_or_else(|| anyhow::anyhow!("Task not found: {}", id))?;
+                .ok_or(DomainError::TaskNotFound(uuid))?;
 
             let (new_status, reason) = match strategy.as_str() {
                 "fail" => (
@@ -958,6 +1735,47 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
             output(&out, json_mode);
         }
 
+        TaskCommands::Schedule { command } => {
+            execute_schedule(command, &pool, json_mode).await?;
+        }
+
+        TaskCommands::Logs { id, follow } => {
+            let uuid = resolve_task_id(&pool, &id).await?;
+            let store = SqliteEventRepository::new(
+                pool.clone(),
+                crate::services::crypto::load_encryptor_from_env(),
+            );
+            let (chunks, mut after) = task_output_since(&store, uuid, None).await?;
+            if chunks.is_empty() && !json_mode {
+                println!(
+                    "No output recorded for task {}.",
+                    short_id(&uuid.to_string())
+                );
+            }
+            print_output_chunks(&chunks, json_mode);
+
+            if follow {
+                loop {
+                    let finished = service
+                        .get_task(uuid)
+                        .await?
+                        .is_none_or(|t| t.status.is_terminal());
+                    if !finished {
+                        tokio::select! {
+                            _ = tokio::signal::ctrl_c() => break,
+                            _ = tokio::time::sleep(LOGS_FOLLOW_POLL_INTERVAL) => {}
+                        }
+                    }
+                    let (chunks, latest) = task_output_since(&store, uuid, after).await?;
+                    after = latest.or(after);
+                    print_output_chunks(&chunks, json_mode);
+                    if finished {
+                        break;
+                    }
+                }
+            }
+        }
+
         TaskCommands::Status => {
             let counts = service.get_status_counts().await?;
 
@@ -981,7 +1799,533 @@ pub async fn execute(args: TaskArgs, json_mode: bool) -> Result<()> {
             };
             output(&out, json_mode);
         }
+
+        TaskCommands::Metrics { agent_type, since } => {
+            let since = since
+                .as_deref()
+                .map(crate::cli::display::parse_duration)
+                .transpose()?
+                .map(|d| chrono::Utc::now() - d);
+            let agents = service
+                .get_agent_type_metrics(agent_type.as_deref(), since)
+                .await?;
+
+            let out = TaskMetricsOutput {
+                total: agents.len(),
+                agents,
+            };
+            output(&out, json_mode);
+        }
     }
 
     Ok(())
 }
+
+/// Parse a `--set-context` entry of the form `key=<json>`.
+fn parse_context_entry(entry: &str) -> std::result::Result<(String, serde_json::Value), String> {
+    let (key, value) = entry
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=JSON, got '{}'", entry))?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(format!("missing key in '{}'", entry));
+    }
+    let value = serde_json::from_str(value)
+        .map_err(|e| format!("value for '{}' is not valid JSON ({}): {}", key, e, value))?;
+    Ok((key.to_string(), value))
+}
+
+/// Walk `task`'s dependency links in one direction (`upstream` follows
+/// `get_dependencies`, otherwise `get_dependents`). Tasks already in `visited`
+/// are listed as repeated instead of expanded, which also stops cycles; nodes
+/// at `max_depth` are marked truncated if they have further links.
+fn dependency_subtree<'a>(
+    repo: &'a dyn TaskRepository,
+    task: &'a Task,
+    upstream: bool,
+    depth: usize,
+    max_depth: Option<usize>,
+    visited: &'a mut HashSet<Uuid>,
+) -> BoxFuture<'a, Result<DependencyNode>> {
+    async move {
+        let mut node = DependencyNode::from(task);
+        let linked = if upstream {
+            repo.get_dependencies(task.id).await?
+        } else {
+            repo.get_dependents(task.id).await?
+        };
+        if max_depth.is_some_and(|max| depth >= max) {
+            node.truncated = !linked.is_empty();
+            return Ok(node);
+        }
+
+        let mut children = Vec::with_capacity(linked.len());
+        for next in &linked {
+            if visited.insert(next.id) {
+                children.push(
+                    dependency_subtree(repo, next, upstream, depth + 1, max_depth, visited).await?,
+                );
+            } else {
+                let mut repeated = DependencyNode::from(next);
+                repeated.repeated = true;
+                children.push(repeated);
+            }
+        }
+        if upstream {
+            node.blocked_by = children;
+        } else {
+            node.blocks = children;
+        }
+        Ok(node)
+    }
+    .boxed()
+}
+
+/// Full upstream (`blocked_by`) and downstream (`blocks`) tree around `task`.
+async fn dependency_tree(
+    repo: &dyn TaskRepository,
+    task: &Task,
+    max_depth: Option<usize>,
+) -> Result<DependencyNode> {
+    let mut visited = HashSet::from([task.id]);
+    let mut root = dependency_subtree(repo, task, true, 0, max_depth, &mut visited).await?;
+
+    let mut visited = HashSet::from([task.id]);
+    let downstream = dependency_subtree(repo, task, false, 0, max_depth, &mut visited).await?;
+    root.blocks = downstream.blocks;
+    root.truncated |= downstream.truncated;
+    Ok(root)
+}
+
+/// `root`, its subtasks at every level, and the direct dependents of each,
+/// which a restructure may rewire.
+async fn restructure_task_set(repo: &dyn TaskRepository, root: Task) -> Result<Vec<Task>> {
+    let mut seen = HashSet::from([root.id]);
+    let mut queue = vec![root.id];
+    let mut tasks = vec![root];
+    while let Some(id) = queue.pop() {
+        for child in repo.get_subtasks(id).await? {
+            if seen.insert(child.id) {
+                queue.push(child.id);
+                tasks.push(child);
+            }
+        }
+    }
+    let mut dependents = Vec::new();
+    for task in &tasks {
+        for dependent in repo.get_dependents(task.id).await? {
+            if seen.insert(dependent.id) {
+                dependents.push(dependent);
+            }
+        }
+    }
+    tasks.extend(dependents);
+    Ok(tasks)
+}
+
+/// Make exported tasks safe to insert into this database.
+async fn prepare_import<R: TaskRepository + ?Sized>(
+    repo: &R,
+    mut tasks: Vec<Task>,
+) -> Result<(Vec<Task>, TaskImportOutput)> {
+    let mut report = TaskImportOutput::default();
+
+    // Tasks whose ID is taken get a fresh one; references inside the batch
+    // follow the new IDs.
+    let mut id_map = std::collections::HashMap::new();
+    for task in &tasks {
+        let new_id = if repo.get(task.id).await?.is_some() {
+            let new_id = Uuid::new_v4();
+            report
+                .remapped
+                .insert(task.id.to_string(), new_id.to_string());
+            new_id
+        } else {
+            task.id
+        };
+        id_map.insert(task.id, new_id);
+    }
+
+    for task in &mut tasks {
+        let original_id = task.id;
+        task.id = id_map[&original_id];
+
+        if let Some(parent_id) = task.parent_id {
+            task.parent_id = match id_map.get(&parent_id) {
+                Some(&mapped) => Some(mapped),
+                None if repo.get(parent_id).await?.is_some() => Some(parent_id),
+                None => {
+                    tracing::warn!(
+                        task_id = %original_id,
+                        parent_id = %parent_id,
+                        "imported task's parent is missing; importing it without a parent"
+                    );
+                    None
+                }
+            };
+        }
+
+        // A dependency found neither in the batch nor in the database is
+        // dropped, and an unfinished task that loses one is imported Blocked.
+        let mut depends_on = Vec::with_capacity(task.depends_on.len());
+        let mut missing_dependency = false;
+        for dep_id in std::mem::take(&mut task.depends_on) {
+            if let Some(&mapped) = id_map.get(&dep_id) {
+                depends_on.push(mapped);
+            } else if repo.get(dep_id).await?.is_some() {
+                depends_on.push(dep_id);
+            } else {
+                tracing::warn!(
+                    task_id = %original_id,
+                    depends_on = %dep_id,
+                    "imported task depends on a task missing from the export; importing it as blocked"
+                );
+                missing_dependency = true;
+            }
+        }
+        task.depends_on = depends_on;
+        if missing_dependency && !task.status.is_terminal() {
+            task.status = TaskStatus::Blocked;
+            report.blocked.push(task.id.to_string());
+        }
+
+        if let Some(supersedes) = task.supersedes {
+            task.supersedes = Some(id_map.get(&supersedes).copied().unwrap_or(supersedes));
+        }
+
+        // Clear idempotency keys that are already in use.
+        if let Some(key) = &task.idempotency_key
+            && repo.get_by_idempotency_key(key).await?.is_some()
+        {
+            task.idempotency_key = None;
+        }
+    }
+
+    Ok((tasks, report))
+}
+
+/// `AgentOutputChunk` events recorded for `task_id` after sequence `after`
+/// (all of them when `None`), oldest first, with the highest sequence seen.
+async fn task_output_since(
+    store: &dyn EventStore,
+    task_id: Uuid,
+    after: Option<u64>,
+) -> Result<(Vec<UnifiedEvent>, Option<u64>)> {
+    let mut query = EventQuery::new()
+        .task_id(task_id)
+        .category(EventCategory::Task)
+        .ascending();
+    if let Some(after) = after {
+        query = query.since_sequence(SequenceNumber(after + 1));
+    }
+    let events = store
+        .query(query)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to query events: {}", e))?;
+    let latest = events.iter().map(|e| e.sequence.0).max();
+    let chunks = events
+        .into_iter()
+        .filter(|e| matches!(e.payload, EventPayload::AgentOutputChunk { .. }))
+        .collect();
+    Ok((chunks, latest))
+}
+
+fn print_output_chunks(chunks: &[UnifiedEvent], json_mode: bool) {
+    for event in chunks {
+        if json_mode {
+            println!("{}", serde_json::to_string(event).unwrap_or_default());
+        } else if let Some(line) = format_output_chunk(event) {
+            println!("{}", line);
+        }
+    }
+}
+
+fn format_output_chunk(event: &UnifiedEvent) -> Option<String> {
+    let EventPayload::AgentOutputChunk { kind, content, .. } = &event.payload else {
+        return None;
+    };
+    let time = event.timestamp.format("%H:%M:%S");
+    Some(match kind.as_str() {
+        "tool_start" => format!("{} {} {}", time, paint("tool:", Tone::Muted), content),
+        "tool_result" => format!(
+            "{} {} {}",
+            time,
+            paint("result:", Tone::Muted),
+            truncate_ellipsis(content.trim(), 200)
+        ),
+        "tool_error" => format!(
+            "{} {} {}",
+            time,
+            paint("tool error:", Tone::Error),
+            truncate_ellipsis(content.trim(), 200)
+        ),
+        _ => format!("{} {}", time, content.trim_end()),
+    })
+}
+
+async fn execute_schedule(
+    command: TaskScheduleCommands,
+    pool: &sqlx::SqlitePool,
+    json_mode: bool,
+) -> Result<()> {
+    use crate::adapters::sqlite::{SqliteAgentRepository, SqliteTaskScheduleRepository};
+    use crate::cli::commands::cron::{CronCreateOutput, next_fire_description};
+    use crate::cli::commands::schedule::{self, ScheduleArgs, ScheduleCommands};
+    use crate::domain::models::task_schedule::{OverlapPolicy, TaskSchedule, TaskScheduleType};
+    use crate::domain::ports::{AgentRepository, TaskScheduleRepository};
+    use crate::services::task_schedule_service::TaskScheduleService;
+    use crate::services::trigger_rules::{normalize_cron_expression, validate_cron_expression};
+
+    match command {
+        TaskScheduleCommands::Add {
+            cron,
+            template,
+            prompt,
+            name,
+            priority,
+            overlap,
+        } => {
+            validate_cron_expression(&cron).map_err(|e| anyhow::anyhow!(e))?;
+
+            let agent_repo = SqliteAgentRepository::new(pool.clone());
+            let agent = agent_repo
+                .get_template_by_name(&template)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Agent template '{}' not found", template))?;
+
+            let repo = Arc::new(SqliteTaskScheduleRepository::new(pool.clone()));
+            let schedule_name = name.unwrap_or_else(|| format!("{}-schedule", template));
+            if repo.get_by_name(&schedule_name).await?.is_some() {
+                anyhow::bail!(
+                    "A schedule named '{}' already exists. Use --name to specify a different name.",
+                    schedule_name
+                );
+            }
+
+            let prompt = prompt.unwrap_or_else(|| agent.description.clone());
+            let overlap_policy = OverlapPolicy::parse(&overlap).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid overlap policy: {} (expected skip, allow or cancel_previous)",
+                    overlap
+                )
+            })?;
+            let sched = TaskSchedule::new(
+                schedule_name,
+                format!("Recurring '{}' task: {}", template, cron),
+                TaskScheduleType::Cron {
+                    expression: normalize_cron_expression(&cron),
+                },
+                truncate_ellipsis(&prompt, 60),
+                prompt,
+            )
+            .with_priority(priority.into())
+            .with_agent_type(template)
+            .with_overlap_policy(overlap_policy);
+
+            let sched = TaskScheduleService::new(repo)
+                .create_schedule(sched)
+                .await?;
+            let out = CronCreateOutput {
+                success: true,
+                id: sched.id.to_string(),
+                name: sched.name.clone(),
+                expression: cron.clone(),
+                next_fire: next_fire_description(&cron),
+                message: format!("Created recurring task schedule '{}'", sched.name),
+            };
+            output(&out, json_mode);
+        }
+
+        TaskScheduleCommands::List { status } => {
+            let args = ScheduleArgs {
+                command: ScheduleCommands::List { status },
+            };
+            schedule::execute(args, json_mode).await?;
+        }
+
+        TaskScheduleCommands::Remove { id_or_name } => {
+            let args = ScheduleArgs {
+                command: ScheduleCommands::Delete { id_or_name },
+            };
+            schedule::execute(args, json_mode).await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::create_migrated_test_pool;
+    use crate::services::event_bus::{EventBus, EventBusConfig, EventSeverity};
+    use crate::services::event_factory::task_event;
+    use crate::services::event_store::InMemoryEventStore;
+
+    async fn publish_chunk(bus: &EventBus, task_id: Uuid, kind: &str, content: &str) {
+        bus.publish(task_event(
+            EventSeverity::Debug,
+            None,
+            task_id,
+            EventPayload::AgentOutputChunk {
+                task_id,
+                agent_type: "coder".to_string(),
+                kind: kind.to_string(),
+                content: content.to_string(),
+            },
+        ))
+        .await;
+    }
+
+    fn contents(chunks: &[UnifiedEvent]) -> Vec<&str> {
+        chunks
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::AgentOutputChunk { content, .. } => Some(content.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_logs_replay_completed_transcript_and_follow_new_chunks() {
+        let store = Arc::new(InMemoryEventStore::new());
+        let bus = EventBus::new(EventBusConfig::default()).with_store(store.clone());
+
+        // A finished task: its whole transcript replays in order, without
+        // other tasks' output or non-output task events.
+        let done = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        publish_chunk(&bus, done, "text", "Reading the module").await;
+        publish_chunk(&bus, other, "text", "unrelated").await;
+        publish_chunk(&bus, done, "tool_start", "Edit").await;
+        bus.publish(task_event(
+            EventSeverity::Info,
+            None,
+            done,
+            EventPayload::TaskCompleted {
+                task_id: done,
+                tokens_used: 10,
+            },
+        ))
+        .await;
+
+        let (chunks, _) = task_output_since(store.as_ref(), done, None).await.unwrap();
+        assert_eq!(contents(&chunks), ["Reading the module", "Edit"]);
+        assert!(format_output_chunk(&chunks[1]).unwrap().contains("Edit"));
+
+        // A running task: following from the last seen sequence yields only
+        // the chunks published since.
+        let running = Uuid::new_v4();
+        publish_chunk(&bus, running, "text", "first").await;
+        let (chunks, after) = task_output_since(store.as_ref(), running, None)
+            .await
+            .unwrap();
+        assert_eq!(contents(&chunks), ["first"]);
+
+        publish_chunk(&bus, running, "text", "second").await;
+        publish_chunk(&bus, running, "tool_result", "ok").await;
+        let (chunks, latest) = task_output_since(store.as_ref(), running, after)
+            .await
+            .unwrap();
+        assert_eq!(contents(&chunks), ["second", "ok"]);
+
+        let (chunks, _) = task_output_since(store.as_ref(), running, latest)
+            .await
+            .unwrap();
+        assert!(chunks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dependency_tree_follows_both_directions_and_stops_cycles() {
+        let pool = create_migrated_test_pool().await.unwrap();
+        let repo = SqliteTaskRepository::new(pool.clone());
+
+        // schema <- api <- ui, plus a legacy cycle ui -> schema.
+        let schema = Task::with_title("Design schema", "Desc");
+        let api = Task::with_title("Build API", "Desc").with_dependency(schema.id);
+        let ui = Task::with_title("Build UI", "Desc").with_dependency(api.id);
+        for task in [&schema, &api, &ui] {
+            repo.create(task).await.unwrap();
+        }
+        sqlx::query("INSERT INTO task_dependencies (task_id, depends_on_id) VALUES (?, ?)")
+            .bind(schema.id.to_string())
+            .bind(ui.id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let tree = dependency_tree(&repo, &api, None).await.unwrap();
+        assert_eq!(tree.blocked_by.len(), 1);
+        let upstream = &tree.blocked_by[0];
+        assert_eq!(upstream.id, schema.id.to_string());
+        let ui_node = &upstream.blocked_by[0];
+        assert_eq!(ui_node.id, ui.id.to_string());
+        // The cycle leads back to the root, which is listed but not expanded.
+        assert_eq!(ui_node.blocked_by[0].id, api.id.to_string());
+        assert!(ui_node.blocked_by[0].repeated);
+        assert!(ui_node.blocked_by[0].blocked_by.is_empty());
+        assert_eq!(tree.blocks.len(), 1);
+        assert_eq!(tree.blocks[0].id, ui.id.to_string());
+
+        let json = tree.to_json();
+        assert_eq!(json["blocked_by"][0]["title"], "Design schema");
+        assert_eq!(json["blocks"][0]["title"], "Build UI");
+
+        let shallow = dependency_tree(&repo, &api, Some(1)).await.unwrap();
+        assert!(shallow.blocked_by[0].truncated);
+        assert!(shallow.blocked_by[0].blocked_by.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_remaps_taken_ids_and_blocks_on_missing_dependencies() {
+        let pool = create_migrated_test_pool().await.unwrap();
+        let repo = SqliteTaskRepository::new(pool);
+
+        let schema = Task::with_title("Design schema", "Desc").with_idempotency_key("schema");
+        let api = Task::with_title("Build API", "Desc").with_dependency(schema.id);
+        let lost = Uuid::new_v4();
+        let ui = Task::with_title("Build UI", "Desc")
+            .with_dependency(api.id)
+            .with_dependency(lost);
+        // `schema` is already in this database, so it must get a new ID.
+        repo.create(&schema).await.unwrap();
+
+        let exported = serde_json::to_string(&vec![&schema, &api, &ui]).unwrap();
+        let tasks: Vec<Task> = serde_json::from_str(&exported).unwrap();
+        let (tasks, report) = prepare_import(&repo, tasks).await.unwrap();
+        repo.bulk_insert(&tasks).await.unwrap();
+
+        let new_schema_id: Uuid = report.remapped[&schema.id.to_string()].parse().unwrap();
+        assert_ne!(new_schema_id, schema.id);
+        let imported_schema = repo.get(new_schema_id).await.unwrap().unwrap();
+        assert_eq!(imported_schema.title, "Design schema");
+        assert_eq!(imported_schema.idempotency_key, None);
+
+        // Edges inside the file follow the remapped ID.
+        let imported_api = repo.get(api.id).await.unwrap().unwrap();
+        assert_eq!(imported_api.depends_on, vec![new_schema_id]);
+        assert_eq!(imported_api.status, api.status);
+
+        // The dangling edge is dropped and the task is held back.
+        let imported_ui = repo.get(ui.id).await.unwrap().unwrap();
+        assert_eq!(imported_ui.depends_on, vec![api.id]);
+        assert_eq!(imported_ui.status, TaskStatus::Blocked);
+        assert_eq!(report.blocked, vec![ui.id.to_string()]);
+    }
+
+    #[test]
+    fn test_parse_context_entry_requires_key_and_json_value() {
+        assert_eq!(
+            parse_context_entry("workflow_phase=\"review\"").unwrap(),
+            ("workflow_phase".to_string(), serde_json::json!("review"))
+        );
+        assert_eq!(
+            parse_context_entry("limits={\"files\":5}").unwrap().1,
+            serde_json::json!({"files": 5})
+        );
+        assert!(parse_context_entry("workflow_phase=review").is_err());
+        assert!(parse_context_entry("=1").is_err());
+        assert!(parse_context_entry("no_value").is_err());
+    }
+}
@@ -11,9 +11,10 @@ use crate::cli::display::{
     CommandOutput, DetailView, action_failure, action_success, list_table, output,
     relative_time_str, render_list, short_id, truncate_ellipsis,
 };
+use crate::cli::exit_code::CliError;
 use crate::cli::id_resolver::resolve_trigger_rule_id;
 use crate::domain::ports::TriggerRuleRepository;
-use crate::services::event_bus::EventCategory;
+use crate::services::event_bus::{EventCategory, EventPayload, EventSeverity};
 use crate::services::trigger_rules::{
     SerializableDomainCommand, SerializableEventFilter, TriggerAction, TriggerCondition,
     TriggerRule, normalize_cron_expression, validate_cron_expression,
@@ -117,6 +118,8 @@ pub enum TriggerCommands {
     },
     /// Seed built-in trigger rules into the database
     Seed,
+    /// Ask a running swarm to reload trigger rules from the database
+    Reload,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -495,6 +498,37 @@ pub async fn execute(args: TriggerArgs, json_mode: bool) -> Result<()> {
             };
             output(&out, json_mode);
         }
+
+        TriggerCommands::Reload => {
+            // Validate here too so a bad rule is reported to the user rather
+            // than only in the swarm's log.
+            let rules = repo.list().await?;
+            let errors: Vec<String> = rules.iter().filter_map(|r| r.validate().err()).collect();
+            if !errors.is_empty() {
+                anyhow::bail!("Invalid trigger rules: {}", errors.join("; "));
+            }
+
+            let event_bus =
+                crate::cli::event_helpers::create_persistent_event_bus(pool.clone()).await;
+            event_bus
+                .publish(crate::services::event_factory::make_event(
+                    EventSeverity::Info,
+                    EventCategory::Orchestrator,
+                    None,
+                    None,
+                    EventPayload::TriggerRulesReloadRequested,
+                ))
+                .await;
+
+            let out = TriggerActionOutput {
+                success: true,
+                message: format!(
+                    "Requested reload of {} trigger rule(s); a running swarm picks it up on its next event poll",
+                    rules.len()
+                ),
+            };
+            output(&out, json_mode);
+        }
     }
 
     Ok(())
@@ -539,5 +573,5 @@ async fn find_rule(
         return Ok(rule);
     }
 
-    anyhow::bail!("Trigger rule not found: {}", id_or_name)
+    Err(CliError::not_found("trigger rule", id_or_name).into())
 }
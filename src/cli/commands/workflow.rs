@@ -6,6 +6,7 @@ use clap::{Args, Subcommand};
 use crate::cli::display::{
     CommandOutput, DetailView, list_table, output, render_list, truncate_ellipsis,
 };
+use crate::cli::exit_code::CliError;
 use crate::domain::models::workflow_template::WorkflowTemplate;
 use crate::services::config::Config;
 
@@ -221,7 +222,7 @@ fn show_workflow(name: &str, json_mode: bool) -> Result<()> {
 
     let wf = config
         .resolve_workflow(name)
-        .ok_or_else(|| anyhow::anyhow!("Workflow '{}' not found", name))?;
+        .ok_or_else(|| CliError::not_found("workflow", name))?;
 
     let phases: Vec<PhaseDetail> = wf
         .phases
@@ -328,7 +329,7 @@ fn export_workflow(name: &str, output_dir: Option<&str>, json_mode: bool) -> Res
 
     let wf = config
         .resolve_workflow(name)
-        .ok_or_else(|| anyhow::anyhow!("Workflow '{}' not found", name))?;
+        .ok_or_else(|| CliError::not_found("workflow", name))?;
 
     let path = write_workflow_yaml(&wf, dir)?;
     let out = ExportOutput {
@@ -1,66 +1,185 @@
 //! Status, priority, and tier color mapping for CLI output.
 //!
-//! All coloring respects `NO_COLOR` env var automatically via the `colored` crate.
+//! Colors are drawn from the active [`Theme`] palette. Call [`configure`] once
+//! at startup: it disables coloring for `--no-color`, the `NO_COLOR` env var,
+//! or when stdout is not a TTY (unless `CLICOLOR_FORCE` is set).
 
-use colored::Colorize;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use colored::{ColoredString, Colorize};
+
+/// Color palette used for CLI output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Theme {
+    /// Standard ANSI palette.
+    #[default]
+    Default,
+    /// Bright, bold colors for low-contrast terminals.
+    Highcontrast,
+    /// No hues; emphasis via bold/dim/underline only.
+    Monochrome,
+}
+
+static ACTIVE_THEME: AtomicU8 = AtomicU8::new(0);
+
+impl Theme {
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::Default => 0,
+            Self::Highcontrast => 1,
+            Self::Monochrome => 2,
+        }
+    }
+
+    fn from_u8(v: u8) -> Self {
+        match v {
+            1 => Self::Highcontrast,
+            2 => Self::Monochrome,
+            _ => Self::Default,
+        }
+    }
+}
+
+/// Set the palette used by all colorize helpers.
+pub fn set_theme(theme: Theme) {
+    ACTIVE_THEME.store(theme.to_u8(), Ordering::Relaxed);
+}
+
+/// The palette currently in use.
+pub fn theme() -> Theme {
+    Theme::from_u8(ACTIVE_THEME.load(Ordering::Relaxed))
+}
+
+/// Apply `--no-color` / `--theme` and the environment to global color state.
+pub fn configure(no_color: bool, theme: Theme) {
+    set_theme(theme);
+    let env_disabled = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let forced = std::env::var_os("CLICOLOR_FORCE").is_some_and(|v| v != "0");
+    if no_color || env_disabled || (!forced && !std::io::stdout().is_terminal()) {
+        colored::control::set_override(false);
+    }
+}
+
+/// Semantic tone of a piece of output, mapped to a color by the active theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tone {
+    /// Completed / healthy.
+    Success,
+    /// In progress.
+    Progress,
+    /// Queued / informational.
+    Info,
+    /// Waiting on something else.
+    Attention,
+    /// Failed / unhealthy.
+    Error,
+    /// Elevated but not failed (e.g. high priority).
+    Warning,
+    /// Highlighted category (e.g. architect tier).
+    Accent,
+    /// De-emphasized.
+    Muted,
+    /// No emphasis.
+    Plain,
+}
+
+/// Render `text` in the given tone using the active theme.
+pub fn paint(text: &str, tone: Tone) -> ColoredString {
+    paint_with(theme(), text, tone)
+}
+
+/// Render `text` in the given tone using an explicit theme.
+pub fn paint_with(theme: Theme, text: &str, tone: Tone) -> ColoredString {
+    match theme {
+        Theme::Default => match tone {
+            Tone::Success => text.green(),
+            Tone::Progress => text.yellow(),
+            Tone::Info => text.blue(),
+            Tone::Attention => text.cyan(),
+            Tone::Error => text.red(),
+            Tone::Warning => text.red(),
+            Tone::Accent => text.magenta(),
+            Tone::Muted => text.dimmed(),
+            Tone::Plain => text.white(),
+        },
+        Theme::Highcontrast => match tone {
+            Tone::Success => text.bright_green().bold(),
+            Tone::Progress => text.bright_yellow().bold(),
+            Tone::Info => text.bright_cyan(),
+            Tone::Attention => text.bright_magenta(),
+            Tone::Error => text.bright_red().bold(),
+            Tone::Warning => text.bright_yellow(),
+            Tone::Accent => text.bright_magenta().bold(),
+            Tone::Muted => text.white(),
+            Tone::Plain => text.bright_white(),
+        },
+        Theme::Monochrome => match tone {
+            Tone::Success | Tone::Error | Tone::Accent => text.bold(),
+            Tone::Progress | Tone::Warning => text.underline(),
+            Tone::Muted => text.dimmed(),
+            Tone::Info | Tone::Attention | Tone::Plain => text.normal(),
+        },
+    }
+}
 
 /// Returns a colored string for any status value.
 ///
-/// Color scheme:
-/// - Green:  complete, active
-/// - Yellow: running, validating
-/// - Blue:   pending, ready
-/// - Cyan:   blocked
-/// - Red:    failed
-/// - Dim:    canceled, retired, deprecated, disabled
-/// - White:  unknown/default
-pub fn colorize_status(status: &str) -> colored::ColoredString {
+/// Tone scheme:
+/// - Success (bold): complete, active
+/// - Progress:       running, validating
+/// - Info:           pending, ready
+/// - Attention:      blocked
+/// - Error (bold):   failed
+/// - Muted:          paused, canceled, retired, deprecated, disabled
+/// - Plain:          unknown/default
+pub fn colorize_status(status: &str) -> ColoredString {
     match status.to_lowercase().as_str() {
-        "complete" | "completed" | "active" => status.green().bold(),
-        "running" | "validating" => status.yellow(),
-        "pending" | "ready" => status.blue(),
-        "paused" => status.yellow().dimmed(),
-        "blocked" => status.cyan(),
-        "failed" => status.red().bold(),
-        "canceled" | "cancelled" | "retired" | "deprecated" | "disabled" => status.dimmed(),
-        _ => status.white(),
+        "complete" | "completed" | "active" => paint(status, Tone::Success).bold(),
+        "running" | "validating" => paint(status, Tone::Progress),
+        "pending" | "ready" => paint(status, Tone::Info),
+        "paused" => paint(status, Tone::Progress).dimmed(),
+        "blocked" => paint(status, Tone::Attention),
+        "failed" => paint(status, Tone::Error).bold(),
+        "canceled" | "cancelled" | "retired" | "deprecated" | "disabled" => {
+            paint(status, Tone::Muted)
+        }
+        _ => paint(status, Tone::Plain),
     }
 }
 
 /// Returns a colored string for priority values.
 ///
-/// Critical = red bold, High = red, Normal = white, Low = dim.
-pub fn colorize_priority(priority: &str) -> colored::ColoredString {
+/// Critical = error bold, High = warning, Normal = plain, Low = muted.
+pub fn colorize_priority(priority: &str) -> ColoredString {
     match priority.to_lowercase().as_str() {
-        "critical" => priority.red().bold(),
-        "high" => priority.red(),
-        "normal" => priority.white(),
-        "low" => priority.dimmed(),
-        _ => priority.white(),
+        "critical" => paint(priority, Tone::Error).bold(),
+        "high" => paint(priority, Tone::Warning),
+        "low" => paint(priority, Tone::Muted),
+        _ => paint(priority, Tone::Plain),
     }
 }
 
 /// Returns a colored string for agent tier values.
 ///
-/// Architect = magenta bold, Specialist = cyan, Worker = white.
-pub fn colorize_tier(tier: &str) -> colored::ColoredString {
+/// Architect = accent bold, Specialist = attention, Worker = plain.
+pub fn colorize_tier(tier: &str) -> ColoredString {
     match tier.to_lowercase().as_str() {
-        "architect" => tier.magenta().bold(),
-        "specialist" => tier.cyan(),
-        "worker" => tier.white(),
-        _ => tier.white(),
+        "architect" => paint(tier, Tone::Accent).bold(),
+        "specialist" => paint(tier, Tone::Attention),
+        _ => paint(tier, Tone::Plain),
     }
 }
 
 /// Returns a colored string for memory tier values.
 ///
-/// Semantic = green, Episodic = yellow, Working = dim.
-pub fn colorize_memory_tier(tier: &str) -> colored::ColoredString {
+/// Semantic = success bold, Episodic = progress, Working = muted.
+pub fn colorize_memory_tier(tier: &str) -> ColoredString {
     match tier.to_lowercase().as_str() {
-        "semantic" => tier.green().bold(),
-        "episodic" => tier.yellow(),
-        "working" => tier.dimmed(),
-        _ => tier.white(),
+        "semantic" => paint(tier, Tone::Success).bold(),
+        "episodic" => paint(tier, Tone::Progress),
+        "working" => paint(tier, Tone::Muted),
+        _ => paint(tier, Tone::Plain),
     }
 }
 
@@ -73,3 +192,24 @@ pub fn label(name: &str) -> String {
 pub fn section_header(title: &str) -> String {
     format!("\n{}", title.bold().underline())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_round_trips_through_atomic() {
+        for t in [Theme::Default, Theme::Highcontrast, Theme::Monochrome] {
+            assert_eq!(Theme::from_u8(t.to_u8()), t);
+        }
+    }
+
+    #[test]
+    fn test_monochrome_palette_has_no_hues() {
+        for tone in [Tone::Success, Tone::Error, Tone::Info, Tone::Accent] {
+            let styled = paint_with(Theme::Monochrome, "x", tone);
+            assert!(styled.fgcolor.is_none(), "{:?} should have no color", tone);
+        }
+        assert!(paint_with(Theme::Default, "x", Tone::Error).fgcolor.is_some());
+    }
+}
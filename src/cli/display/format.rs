@@ -56,6 +56,34 @@ pub fn count_label(n: usize, singular: &str, plural: &str) -> String {
     }
 }
 
+/// Format a number of seconds compactly: "45s", "12m", "1h 30m", "2d 4h".
+pub fn format_secs(secs: u64) -> String {
+    let (d, h, m) = (secs / 86_400, (secs % 86_400) / 3_600, (secs % 3_600) / 60);
+    match (d, h, m) {
+        (0, 0, 0) => format!("{}s", secs),
+        (0, 0, m) => format!("{}m", m),
+        (0, h, 0) => format!("{}h", h),
+        (0, h, m) => format!("{}h {}m", h, m),
+        (d, 0, _) => format!("{}d", d),
+        (d, h, _) => format!("{}d {}h", d, h),
+    }
+}
+
+/// Format a byte count compactly: "512 B", "4.0 KiB", "1.5 MiB".
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
 /// Parse a human-friendly duration string like "7d", "24h", "1w", "30m" into a
 /// `chrono::Duration`.
 pub fn parse_duration(s: &str) -> anyhow::Result<chrono::Duration> {
@@ -115,6 +143,22 @@ pub fn parse_std_duration(s: &str) -> anyhow::Result<std::time::Duration> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_secs() {
+        assert_eq!(format_secs(45), "45s");
+        assert_eq!(format_secs(720), "12m");
+        assert_eq!(format_secs(5_400), "1h 30m");
+        assert_eq!(format_secs(7_200), "2h");
+        assert_eq!(format_secs(187_200), "2d 4h");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(4_096), "4.0 KiB");
+        assert_eq!(format_bytes(1_572_864), "1.5 MiB");
+    }
+
     #[test]
     fn test_parse_duration_days() {
         let d = parse_duration("7d").unwrap();
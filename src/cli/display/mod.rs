@@ -36,11 +36,11 @@ pub fn output<T: CommandOutput>(result: &T, json_mode: bool) {
 /// Render a success action result.
 pub fn action_success(message: &str) -> String {
     use colored::Colorize;
-    format!("{} {}", "\u{2713}".green().bold(), message)
+    format!("{} {}", paint("\u{2713}", Tone::Success).bold(), message)
 }
 
 /// Render a failure action result.
 pub fn action_failure(message: &str) -> String {
     use colored::Colorize;
-    format!("{} {}", "\u{2717}".red().bold(), message)
+    format!("{} {}", paint("\u{2717}", Tone::Error).bold(), message)
 }
@@ -0,0 +1,125 @@
+//! Process exit codes for scripting against the CLI.
+//!
+//! | Code | Meaning                                        |
+//! |------|------------------------------------------------|
+//! | 0    | Success                                        |
+//! | 1    | Any other error                                |
+//! | 2    | A task (or the work it drove) failed           |
+//! | 3    | The referenced entity does not exist           |
+//! | 4    | Configuration is missing or invalid            |
+//! | 5    | Database could not be opened or queried        |
+//!
+//! Handlers keep returning `anyhow::Error`; [`ExitCode::for_error`] walks the
+//! error chain for a typed cause ([`DomainError`], [`ConfigError`],
+//! `sqlx::Error`, or [`CliError`]) and picks the code centrally.
+
+use crate::domain::errors::DomainError;
+use crate::services::config::ConfigError;
+
+/// Documented exit codes. See the module docs for the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    Success = 0,
+    Error = 1,
+    TaskFailed = 2,
+    NotFound = 3,
+    Config = 4,
+    Database = 5,
+}
+
+impl ExitCode {
+    /// Numeric process exit status.
+    pub fn code(self) -> i32 {
+        self as i32
+    }
+
+    /// Map an error to its exit code using the first typed cause in its chain.
+    pub fn for_error(err: &anyhow::Error) -> Self {
+        err.chain().find_map(Self::for_cause).unwrap_or(Self::Error)
+    }
+
+    fn for_cause(cause: &(dyn std::error::Error + 'static)) -> Option<Self> {
+        if let Some(e) = cause.downcast_ref::<CliError>() {
+            return Some(match e {
+                CliError::NotFound { .. } => Self::NotFound,
+            });
+        }
+        if let Some(e) = cause.downcast_ref::<DomainError>() {
+            return Self::for_domain_error(e);
+        }
+        if cause.downcast_ref::<ConfigError>().is_some() {
+            return Some(Self::Config);
+        }
+        if let Some(e) = cause.downcast_ref::<sqlx::Error>() {
+            return Some(match e {
+                sqlx::Error::RowNotFound => Self::NotFound,
+                _ => Self::Database,
+            });
+        }
+        None
+    }
+
+    fn for_domain_error(err: &DomainError) -> Option<Self> {
+        match err {
+            DomainError::GoalNotFound(_)
+            | DomainError::TaskNotFound(_)
+            | DomainError::AgentNotFound(_)
+            | DomainError::MemoryNotFound(_)
+            | DomainError::TaskScheduleNotFound(_) => Some(Self::NotFound),
+            DomainError::ConfigError { .. } => Some(Self::Config),
+            DomainError::DatabaseError(_) => Some(Self::Database),
+            DomainError::ExecutionFailed(_)
+            | DomainError::SubstrateError(_)
+            | DomainError::TimeoutError { .. } => Some(Self::TaskFailed),
+            _ => None,
+        }
+    }
+}
+
+/// CLI-originated failures that carry a specific exit code but have no
+/// [`DomainError`] counterpart (e.g. unresolved ID prefixes).
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error("No {entity} found matching '{id}'")]
+    NotFound { entity: String, id: String },
+}
+
+impl CliError {
+    pub fn not_found(entity: impl Into<String>, id: impl Into<String>) -> Self {
+        Self::NotFound {
+            entity: entity.into(),
+            id: id.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_domain_errors_map_through_context() {
+        let err = Err::<(), _>(DomainError::TaskNotFound(Uuid::nil()))
+            .context("loading task")
+            .unwrap_err();
+        assert_eq!(ExitCode::for_error(&err), ExitCode::NotFound);
+
+        let err = anyhow::Error::new(DomainError::DatabaseError("locked".into()));
+        assert_eq!(ExitCode::for_error(&err), ExitCode::Database);
+
+        let err = anyhow::Error::new(ConfigError::FileNotFound("abathur.toml".into()));
+        assert_eq!(ExitCode::for_error(&err), ExitCode::Config);
+    }
+
+    #[test]
+    fn test_untyped_errors_use_generic_code() {
+        let err = anyhow::anyhow!("something went wrong");
+        assert_eq!(ExitCode::for_error(&err), ExitCode::Error);
+        assert_eq!(
+            ExitCode::for_error(&CliError::not_found("task", "abc").into()),
+            ExitCode::NotFound
+        );
+    }
+}
@@ -7,6 +7,8 @@ use anyhow::{Result, bail};
 use sqlx::SqlitePool;
 use uuid::Uuid;
 
+use crate::cli::exit_code::CliError;
+
 /// Resolve a task ID prefix to a full UUID.
 pub async fn resolve_task_id(pool: &SqlitePool, prefix: &str) -> Result<Uuid> {
     resolve_prefix(pool, prefix, "task", TASK_QUERY).await
@@ -91,7 +93,7 @@ async fn resolve_prefix_raw(
     let rows: Vec<(String,)> = sqlx::query_as(query).bind(&pattern).fetch_all(pool).await?;
 
     match rows.len() {
-        0 => bail!("No {} found matching '{}'", entity, prefix),
+        0 => Err(CliError::not_found(entity, prefix).into()),
         1 => Ok(rows[0].0.clone()),
         n => {
             let mut msg = format!("Ambiguous prefix '{}': matches {} {}s:", prefix, n, entity);
@@ -4,6 +4,7 @@ pub mod command_dispatcher;
 pub mod commands;
 pub mod display;
 pub mod event_helpers;
+pub mod exit_code;
 pub mod id_resolver;
 mod output;
 
@@ -24,6 +25,14 @@ pub struct Cli {
     #[arg(long, global = true, default_value = "abathur.toml")]
     pub config: PathBuf,
 
+    /// Disable colored output (also honored via the NO_COLOR env var)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Color palette for human-readable output
+    #[arg(long, global = true, value_enum, default_value_t = display::Theme::Default)]
+    pub theme: display::Theme,
+
     /// Verbosity level (-v, -vv, -vvv)
     #[arg(short, long, action = clap::ArgAction::Count, global = true)]
     pub verbose: u8,
@@ -56,12 +65,20 @@ pub enum Commands {
     Schedule(commands::schedule::ScheduleArgs),
     /// Query and inspect the event store
     Event(commands::event::EventArgs),
+    /// Query the audit log of state changes and decisions
+    Audit(commands::audit::AuditArgs),
     /// Manage workflow templates
     Workflow(commands::workflow::WorkflowArgs),
     /// Manage adapter plugins
     Adapter(commands::adapter::AdapterArgs),
     /// Quick cron schedule management
     Cron(commands::cron::CronArgs),
+    /// Inspect running convergence loops
+    Loop(commands::convergence_loop::LoopArgs),
+    /// Inspect and upgrade the configuration file
+    Config(commands::config::ConfigArgs),
+    /// Inspect and apply database schema migrations
+    Db(commands::db::DbArgs),
     /// Generate shell completions
     Completions {
         /// Shell to generate completions for
@@ -75,10 +92,37 @@ pub fn print_completions(shell: Shell) {
     clap_complete::generate(shell, &mut cmd, "abathur", &mut std::io::stdout());
 }
 
+/// Report `err` on stderr and exit with the code from [`exit_code::ExitCode::for_error`].
+/// Report every configuration problem found by
+/// [`Config::validate_all`](crate::services::config::Config::validate_all)
+/// and exit with [`exit_code::ExitCode::Config`].
+pub fn handle_config_errors(
+    errors: &[crate::services::config::ConfigError],
+    json_mode: bool,
+) -> ! {
+    let code = exit_code::ExitCode::Config.code();
+    if json_mode {
+        let output = serde_json::json!({
+            "error": true,
+            "exit_code": code,
+            "message": "invalid configuration",
+            "errors": errors.iter().map(|e| e.to_string()).collect::<Vec<_>>()
+        });
+        eprintln!("{}", serde_json::to_string_pretty(&output).unwrap());
+    } else {
+        for err in errors {
+            eprintln!("{}", display::action_failure(&err.to_string()));
+        }
+    }
+    std::process::exit(code);
+}
+
 pub fn handle_error(err: anyhow::Error, json_mode: bool) -> ! {
+    let code = exit_code::ExitCode::for_error(&err).code();
     if json_mode {
         let output = serde_json::json!({
             "error": true,
+            "exit_code": code,
             "message": err.to_string(),
             "chain": err.chain().skip(1).map(|e| e.to_string()).collect::<Vec<_>>()
         });
@@ -89,5 +133,5 @@ pub fn handle_error(err: anyhow::Error, json_mode: bool) -> ! {
             eprintln!("  Caused by: {}", cause);
         }
     }
-    std::process::exit(1);
+    std::process::exit(code);
 }
@@ -87,6 +87,11 @@ pub enum DomainError {
     /// illegal phase transition).
     #[error("Workflow '{workflow}' error: {reason}")]
     WorkflowError { workflow: String, reason: String },
+
+    /// A task failed a pre-submission policy check. `rule` names the
+    /// validator that rejected it.
+    #[error("Task rejected by '{rule}': {reason}")]
+    SubmissionRejected { rule: String, reason: String },
 }
 
 pub type DomainResult<T> = Result<T, DomainError>;
@@ -124,7 +129,7 @@ impl DomainError {
     ///   DatabaseError, ValidationFailed, SerializationError, ExecutionFailed,
     ///   SubstrateError, NotImplemented, InvalidStateTransition (`reason`),
     ///   ConfigError (`reason`), WorkflowError (`reason`),
-    ///   ExternalServiceError (`reason`).
+    ///   ExternalServiceError (`reason`), SubmissionRejected (`reason`).
     ///
     /// Variants that lossy-wrap into ValidationFailed:
     ///   GoalNotFound, TaskNotFound, DependencyCycle, AgentNotFound,
@@ -159,6 +164,10 @@ impl DomainError {
                 service,
                 reason: format!("{ctx}: {reason}"),
             },
+            Self::SubmissionRejected { rule, reason } => Self::SubmissionRejected {
+                rule,
+                reason: format!("{ctx}: {reason}"),
+            },
             // Fallback: variants with structured non-message fields get
             // lossy-wrapped so context isn't silently dropped.
             other => Self::ValidationFailed(format!("{ctx}: {other}")),
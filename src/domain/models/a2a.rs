@@ -752,6 +752,10 @@ pub struct CerebrateStatus {
     pub url: Option<String>,
     /// Number of consecutive missed heartbeats.
     pub missed_heartbeats: u32,
+    /// Whether the cerebrate's agent card advertises push notifications,
+    /// i.e. it will POST task status transitions back to us.
+    #[serde(default)]
+    pub push_notifications: bool,
 }
 
 impl CerebrateStatus {
@@ -767,6 +771,7 @@ impl CerebrateStatus {
             last_heartbeat_at: None,
             url: None,
             missed_heartbeats: 0,
+            push_notifications: false,
         }
     }
 
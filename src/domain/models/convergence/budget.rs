@@ -71,6 +71,9 @@ pub struct ConvergenceBudget {
     pub extensions_granted: u32,
     /// Maximum number of extensions that may be granted.
     pub max_extensions: u32,
+    /// Iterations added by the adaptive budget (see [`AdaptiveBudgetConfig`]).
+    #[serde(default)]
+    pub bonus_iterations_granted: u32,
 }
 
 impl Default for ConvergenceBudget {
@@ -87,6 +90,7 @@ impl Default for ConvergenceBudget {
             extensions_requested: 0,
             extensions_granted: 0,
             max_extensions: 1,
+            bonus_iterations_granted: 0,
         }
     }
 }
@@ -169,6 +173,47 @@ impl ConvergenceBudget {
             extensions_requested: 0,
             extensions_granted: 0,
             max_extensions: self.max_extensions,
+            bonus_iterations_granted: 0,
+        }
+    }
+
+    /// Raise the iteration cap by one as an adaptive-budget bonus.
+    pub fn grant_bonus_iteration(&mut self) {
+        self.max_iterations += 1;
+        self.bonus_iterations_granted += 1;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// AdaptiveBudgetConfig
+// ---------------------------------------------------------------------------
+
+/// Opt-in adaptive iteration budget driven by the recent convergence slope.
+///
+/// The slope is the mean `convergence_delta` over the last
+/// [`window`](Self::window) measured observations. Below
+/// [`min_slope`](Self::min_slope) the trajectory has plateaued and the engine
+/// stops early rather than spending its remaining iterations. Reaching the
+/// iteration cap while the slope is still at or above `min_slope` earns one
+/// bonus iteration at a time, up to
+/// [`max_bonus_iterations`](Self::max_bonus_iterations). With fewer than
+/// `window` measured observations the fixed cap applies unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveBudgetConfig {
+    /// Number of recent observations the slope is averaged over.
+    pub window: usize,
+    /// Minimum improvement per iteration worth paying for.
+    pub min_slope: f64,
+    /// Maximum iterations that may be added beyond the budget's cap.
+    pub max_bonus_iterations: u32,
+}
+
+impl Default for AdaptiveBudgetConfig {
+    fn default() -> Self {
+        Self {
+            window: 3,
+            min_slope: 0.02,
+            max_bonus_iterations: 3,
         }
     }
 }
@@ -39,6 +39,9 @@ pub enum LoopControl {
     /// intent verification entirely since static checks objectively confirm
     /// the work is correct and the trajectory has stabilized.
     OverseerConverged,
+    /// The adaptive budget's convergence slope fell below its minimum; stop
+    /// instead of spending the remaining budget on a plateau.
+    EarlyStop,
 }
 
 // ---------------------------------------------------------------------------
@@ -218,9 +221,10 @@ mod tests {
             LoopControl::RequestExtension,
             LoopControl::Decompose,
             LoopControl::OverseerConverged,
+            LoopControl::EarlyStop,
         ];
 
-        assert_eq!(variants.len(), 7);
+        assert_eq!(variants.len(), 8);
 
         assert!(matches!(variants[0], LoopControl::Continue));
         assert!(matches!(variants[1], LoopControl::IntentCheck));
@@ -229,5 +233,6 @@ mod tests {
         assert!(matches!(variants[4], LoopControl::RequestExtension));
         assert!(matches!(variants[5], LoopControl::Decompose));
         assert!(matches!(variants[6], LoopControl::OverseerConverged));
+        assert!(matches!(variants[7], LoopControl::EarlyStop));
     }
 }
@@ -143,6 +143,18 @@ pub struct CustomCheckResult {
     pub details: String,
 }
 
+impl CustomCheckResult {
+    /// Failing check recorded for an overseer that did not finish within its
+    /// time budget.
+    pub fn timed_out(overseer: &str, timeout_ms: u64) -> Self {
+        Self {
+            name: overseer.to_string(),
+            passed: false,
+            details: format!("timed out after {timeout_ms}ms"),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // OverseerSignals
 // ---------------------------------------------------------------------------
@@ -293,6 +305,44 @@ impl OverseerSignals {
             .unwrap_or(0)
     }
 
+    /// Names of the overseers whose signals currently block convergence.
+    ///
+    /// Mirrors the checks in [`all_passing_relative`](Self::all_passing_relative),
+    /// in cheap-to-expensive order, with custom checks reported by name.
+    pub fn failing_overseers(&self, lint_baseline: u32) -> Vec<String> {
+        let mut failing = Vec::new();
+        if self.build_result.as_ref().is_some_and(|b| !b.success) {
+            failing.push("build".to_string());
+        }
+        if self.type_check.as_ref().is_some_and(|t| !t.clean) {
+            failing.push("type_check".to_string());
+        }
+        if self
+            .lint_results
+            .as_ref()
+            .is_some_and(|l| l.error_count > lint_baseline)
+        {
+            failing.push("lint".to_string());
+        }
+        if self.test_results.as_ref().is_some_and(|t| !t.all_passing()) {
+            failing.push("tests".to_string());
+        }
+        if self
+            .security_scan
+            .as_ref()
+            .is_some_and(|s| s.critical_count > 0)
+        {
+            failing.push("security".to_string());
+        }
+        failing.extend(
+            self.custom_checks
+                .iter()
+                .filter(|c| !c.passed)
+                .map(|c| c.name.clone()),
+        );
+        failing
+    }
+
     /// Create an empty `OverseerSignals` with all fields set to `None`
     /// and no custom checks.
     pub fn empty() -> Self {
@@ -402,6 +452,9 @@ pub enum OverseerSignalUpdate {
     SecurityScan(SecurityScanResult),
     /// Append to the `custom_checks` vector.
     CustomCheck(CustomCheckResult),
+    /// The named overseer exceeded its time budget. Recorded as a failing
+    /// entry in the `custom_checks` vector.
+    Timeout { overseer: String, timeout_ms: u64 },
 }
 
 // ---------------------------------------------------------------------------
@@ -597,6 +650,12 @@ impl OverseerCluster {
             OverseerSignalUpdate::BuildResult(r) => signals.build_result = Some(r),
             OverseerSignalUpdate::SecurityScan(r) => signals.security_scan = Some(r),
             OverseerSignalUpdate::CustomCheck(r) => signals.custom_checks.push(r),
+            OverseerSignalUpdate::Timeout {
+                overseer,
+                timeout_ms,
+            } => signals
+                .custom_checks
+                .push(CustomCheckResult::timed_out(&overseer, timeout_ms)),
         }
     }
 
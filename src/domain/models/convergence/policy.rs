@@ -12,7 +12,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::budget::ConvergenceBudget;
+use super::budget::{AdaptiveBudgetConfig, ConvergenceBudget};
 
 /// Governs convergence behavior for a trajectory.
 ///
@@ -110,6 +110,10 @@ pub struct ConvergencePolicy {
     /// scratch. This cap prevents unbounded restarts; once reached, the
     /// engine must either accept a partial result or escalate.
     pub max_fresh_starts: u32,
+
+    /// Slope-driven iteration budget; `None` keeps the fixed iteration cap.
+    #[serde(default)]
+    pub adaptive_budget: Option<AdaptiveBudgetConfig>,
 }
 
 impl Default for ConvergencePolicy {
@@ -127,6 +131,7 @@ impl Default for ConvergencePolicy {
             intent_check_interval: 2,
             intent_check_at_budget_fraction: 0.5,
             max_fresh_starts: 3,
+            adaptive_budget: None,
         }
     }
 }
@@ -183,6 +183,22 @@ impl Trajectory {
             .unwrap_or(0.0)
     }
 
+    /// Mean convergence delta over the last `window` observations that have
+    /// metrics, or `None` when fewer than `window` are available.
+    pub fn convergence_slope(&self, window: usize) -> Option<f64> {
+        if window == 0 {
+            return None;
+        }
+        let deltas: Vec<f64> = self
+            .observations
+            .iter()
+            .rev()
+            .filter_map(|o| o.metrics.as_ref().map(|m| m.convergence_delta))
+            .take(window)
+            .collect();
+        (deltas.len() == window).then(|| deltas.iter().sum::<f64>() / window as f64)
+    }
+
     /// Whether this trajectory has reached the `Converged` phase.
     pub fn is_converged(&self) -> bool {
         matches!(self.phase, ConvergencePhase::Converged)
@@ -588,6 +604,14 @@ pub enum ConvergencePhase {
     Trapped,
 }
 
+impl ConvergencePhase {
+    /// Whether the trajectory has finished (`Converged`, `Exhausted`, or
+    /// `Trapped`) and will record no further observations.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Converged | Self::Exhausted | Self::Trapped)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // VerificationResult
 // ---------------------------------------------------------------------------
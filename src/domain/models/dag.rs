@@ -135,6 +135,72 @@ impl TaskDag {
         false
     }
 
+    /// Find the dependency cycles in the graph.
+    ///
+    /// Returns the tasks of each strongly connected component that contains
+    /// a cycle (including a task that depends on itself), each list sorted,
+    /// so tasks caught in overlapping loops are reported together once.
+    pub fn find_cycles(&self) -> Vec<Vec<Uuid>> {
+        #[derive(Default)]
+        struct Tarjan {
+            next_index: usize,
+            index: HashMap<Uuid, usize>,
+            low: HashMap<Uuid, usize>,
+            stack: Vec<Uuid>,
+            on_stack: HashSet<Uuid>,
+            cycles: Vec<Vec<Uuid>>,
+        }
+
+        fn strong_connect(dag: &TaskDag, id: Uuid, t: &mut Tarjan) {
+            t.index.insert(id, t.next_index);
+            t.low.insert(id, t.next_index);
+            t.next_index += 1;
+            t.stack.push(id);
+            t.on_stack.insert(id);
+
+            let node = &dag.nodes[&id];
+            for &dep in &node.dependencies {
+                if !dag.nodes.contains_key(&dep) {
+                    continue;
+                }
+                if !t.index.contains_key(&dep) {
+                    strong_connect(dag, dep, t);
+                    let low = t.low[&id].min(t.low[&dep]);
+                    t.low.insert(id, low);
+                } else if t.on_stack.contains(&dep) {
+                    let low = t.low[&id].min(t.index[&dep]);
+                    t.low.insert(id, low);
+                }
+            }
+
+            if t.low[&id] == t.index[&id] {
+                let mut component = Vec::new();
+                while let Some(member) = t.stack.pop() {
+                    t.on_stack.remove(&member);
+                    component.push(member);
+                    if member == id {
+                        break;
+                    }
+                }
+                if component.len() > 1 || node.dependencies.contains(&id) {
+                    component.sort();
+                    t.cycles.push(component);
+                }
+            }
+        }
+
+        let mut ids: Vec<Uuid> = self.nodes.keys().copied().collect();
+        ids.sort();
+        let mut tarjan = Tarjan::default();
+        for id in ids {
+            if !tarjan.index.contains_key(&id) {
+                strong_connect(self, id, &mut tarjan);
+            }
+        }
+        tarjan.cycles.sort();
+        tarjan.cycles
+    }
+
     /// Perform topological sort and return tasks in execution order.
     pub fn topological_sort(&self) -> Result<Vec<Uuid>, DagError> {
         if self.has_cycle() {
@@ -447,6 +513,34 @@ mod tests {
         assert!(dag.topological_sort().is_err());
     }
 
+    #[test]
+    fn test_find_cycles() {
+        let ids: Vec<Uuid> = (0..6).map(|_| Uuid::new_v4()).collect();
+
+        // 0 <-> 1 and 2 -> 2 are cycles; 3 -> 4 -> 5 -> 1 leads into the
+        // first loop without being part of it.
+        let dag = TaskDag::from_tasks(vec![
+            make_task(ids[0], "a", vec![ids[1]]),
+            make_task(ids[1], "b", vec![ids[0]]),
+            make_task(ids[2], "self", vec![ids[2]]),
+            make_task(ids[3], "c", vec![ids[4]]),
+            make_task(ids[4], "d", vec![ids[5]]),
+            make_task(ids[5], "e", vec![ids[1]]),
+        ]);
+
+        let mut pair = vec![ids[0], ids[1]];
+        pair.sort();
+        let mut expected = vec![pair, vec![ids[2]]];
+        expected.sort();
+        assert_eq!(dag.find_cycles(), expected);
+
+        let acyclic = TaskDag::from_tasks(vec![
+            make_task(ids[0], "a", vec![]),
+            make_task(ids[1], "b", vec![ids[0]]),
+        ]);
+        assert!(acyclic.find_cycles().is_empty());
+    }
+
     #[test]
     fn test_critical_path() {
         let id1 = Uuid::new_v4();
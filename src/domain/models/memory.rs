@@ -98,10 +98,47 @@ impl MemoryTier {
             Self::Semantic => None, // No expiry
         }
     }
+
+    /// Hours for a memory in this tier to decay to half relevance.
+    pub fn half_life_hours(&self) -> f32 {
+        match self {
+            Self::Working => 0.5,    // 30 minutes
+            Self::Episodic => 24.0,  // 1 day
+            Self::Semantic => 168.0, // 1 week (but never expires)
+        }
+    }
+}
+
+/// Shape of a memory's decay over time. Every curve is scaled by the
+/// tier's half-life (see [`MemoryTier::half_life_hours`]).
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "curve")]
+pub enum DecayFunction {
+    /// Halves every half-life: `2^(-age/half_life)`.
+    #[default]
+    Exponential,
+    /// Falls in a straight line, reaching zero after two half-lives.
+    Linear,
+    /// Stays fully relevant for `plateau_hours`, then decays exponentially.
+    StepThenDecay { plateau_hours: f32 },
+}
+
+impl DecayFunction {
+    /// Decay factor (0.0-1.0) after `age_hours` for the given half-life.
+    pub fn factor(&self, age_hours: f32, half_life_hours: f32) -> f32 {
+        let age = age_hours.max(0.0);
+        match self {
+            Self::Exponential => 0.5_f32.powf(age / half_life_hours),
+            Self::Linear => (1.0 - age / (2.0 * half_life_hours)).clamp(0.0, 1.0),
+            Self::StepThenDecay { plateau_hours } => {
+                0.5_f32.powf((age - plateau_hours).max(0.0) / half_life_hours)
+            }
+        }
+    }
 }
 
 /// Type of memory content.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum MemoryType {
     /// Raw text/fact
@@ -168,6 +205,11 @@ pub struct MemoryMetadata {
     pub relevance: f32,
     /// Custom key-value pairs
     pub custom: std::collections::HashMap<String, serde_json::Value>,
+    /// Per-memory minimum decay factor (0.0-1.0), overriding the tier floor
+    /// in `DecayConfig`. Decay asymptotes at this value instead of reaching
+    /// zero, and a floored memory is never pruned for decay.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decay_floor: Option<f32>,
 }
 
 /// A memory entry in the system.
@@ -203,6 +245,10 @@ pub struct Memory {
     /// None if embeddings are disabled or not yet computed.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub embedding: Option<Vec<f32>>,
+    /// Identifier of the embedding model that produced `embedding`
+    /// (see [`EmbeddingProvider::model_id`](crate::domain::ports::EmbeddingProvider::model_id)).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_model: Option<String>,
     /// Set of distinct accessors that have accessed this memory.
     ///
     /// Used for promotion integrity: promotion requires access from multiple
@@ -234,6 +280,7 @@ impl Memory {
             version: 1,
             tier,
             embedding: None,
+            embedding_model: None,
             distinct_accessors: HashSet::new(),
         }
     }
@@ -329,24 +376,34 @@ impl Memory {
     }
 
     /// Calculate decay factor (0.0 = fully decayed, 1.0 = fresh).
-    /// Uses exponential decay based on time since last access.
+    /// Uses exponential decay based on time since last access, clamped to
+    /// the per-memory `decay_floor` when one is set. Tier floors live in
+    /// `DecayConfig`; use `MemoryService::decay_factor` to apply them too.
     pub fn decay_factor(&self) -> f32 {
+        self.decay_factor_at_rate(1.0)
+    }
+
+    /// [`Self::decay_factor`] with time running `rate` times as fast
+    /// (0.0 = never decays, 2.0 = half the tier's half-life).
+    pub fn decay_factor_at_rate(&self, rate: f32) -> f32 {
+        self.decay_factor_with(DecayFunction::Exponential, rate)
+    }
+
+    /// [`Self::decay_factor_at_rate`] along `function` instead of the
+    /// exponential curve.
+    pub fn decay_factor_with(&self, function: DecayFunction, rate: f32) -> f32 {
         let age = Utc::now() - self.last_accessed;
         let hours = age.num_hours() as f32;
 
-        // Half-life depends on tier
-        let half_life_hours = match self.tier {
-            MemoryTier::Working => 0.5,    // 30 minutes
-            MemoryTier::Episodic => 24.0,  // 1 day
-            MemoryTier::Semantic => 168.0, // 1 week (but never expires)
-        };
-
-        // Exponential decay: factor = 2^(-age/half_life)
         // Access count slows decay
         let access_bonus = (self.access_count as f32).ln_1p() * 0.1;
         let effective_age = (hours - access_bonus).max(0.0);
 
-        0.5_f32.powf(effective_age / half_life_hours)
+        let factor = function.factor(effective_age * rate.max(0.0), self.tier.half_life_hours());
+        match self.metadata.decay_floor {
+            Some(floor) => factor.max(floor.clamp(0.0, 1.0)),
+            None => factor,
+        }
     }
 
     /// Promote memory to higher tier.
@@ -545,6 +602,21 @@ impl Memory {
         (0.30 * jaccard + 0.50 * tf_idf_score + 0.20 * bigram_score).min(1.0)
     }
 
+    /// Keyword relevance of this memory to `query` (0.0-1.0).
+    ///
+    /// An exact (case-insensitive) key match scores 1.0; otherwise the better
+    /// of the key and content text similarities.
+    pub fn lexical_score(&self, query: &str) -> f32 {
+        let query = query.trim();
+        if query.is_empty() {
+            return 0.0;
+        }
+        if self.key.eq_ignore_ascii_case(query) {
+            return 1.0;
+        }
+        Self::text_similarity(query, &self.key).max(Self::text_similarity(query, &self.content))
+    }
+
     /// Compute cosine similarity between this memory's embedding and a query vector.
     ///
     /// Returns None if either embedding is missing or dimensions don't match.
@@ -584,6 +656,14 @@ impl Memory {
         if self.content.is_empty() {
             return Err("Memory content cannot be empty".to_string());
         }
+        if let Some(floor) = self.metadata.decay_floor
+            && !(0.0..=1.0).contains(&floor)
+        {
+            return Err(format!(
+                "Memory decay floor must be between 0.0 and 1.0, got {}",
+                floor
+            ));
+        }
         Ok(())
     }
 }
@@ -659,6 +739,67 @@ impl RelevanceWeights {
     }
 }
 
+/// How memories are selected for an agent's task prompt.
+///
+/// Configured per agent type under `[memory_retrieval]` in `abathur.toml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub enum MemoryRetrievalStrategy {
+    /// Memories from one namespace, ranked by default relevance weights.
+    Namespace { namespace: String },
+    /// Text matches ranked purely by similarity to the task description.
+    Similarity,
+    /// Most recently accessed memories, regardless of content.
+    Recency,
+    /// Text matches ranked by a weighted blend of similarity, recency, and
+    /// importance.
+    Blend {
+        semantic_weight: f32,
+        recency_weight: f32,
+        importance_weight: f32,
+    },
+}
+
+impl Default for MemoryRetrievalStrategy {
+    /// Semantic-biased blend, the historical prompt-assembly behavior.
+    fn default() -> Self {
+        let weights = RelevanceWeights::semantic_biased();
+        Self::Blend {
+            semantic_weight: weights.semantic_weight,
+            recency_weight: weights.decay_weight,
+            importance_weight: weights.importance_weight,
+        }
+    }
+}
+
+impl MemoryRetrievalStrategy {
+    /// Relevance weights used to rank candidates under this strategy.
+    pub fn weights(&self) -> RelevanceWeights {
+        match self {
+            Self::Namespace { .. } => RelevanceWeights::default(),
+            Self::Similarity => RelevanceWeights {
+                semantic_weight: 1.0,
+                decay_weight: 0.0,
+                importance_weight: 0.0,
+            },
+            Self::Recency => RelevanceWeights {
+                semantic_weight: 0.0,
+                decay_weight: 1.0,
+                importance_weight: 0.0,
+            },
+            Self::Blend {
+                semantic_weight,
+                recency_weight,
+                importance_weight,
+            } => RelevanceWeights {
+                semantic_weight: *semantic_weight,
+                decay_weight: *recency_weight,
+                importance_weight: *importance_weight,
+            },
+        }
+    }
+}
+
 /// A scored memory entry with its composite relevance score.
 #[derive(Debug, Clone)]
 pub struct ScoredMemory {
@@ -681,6 +822,36 @@ pub struct ScoreBreakdown {
     pub importance_score: f32,
 }
 
+/// A memory ranked by a blend of lexical and vector similarity.
+#[derive(Debug, Clone)]
+pub struct HybridSearchResult {
+    /// The memory entry.
+    pub memory: Memory,
+    /// Blended score (0.0-1.0).
+    pub score: f32,
+    /// Keyword match against the key and content (0.0-1.0).
+    pub lexical_score: f32,
+    /// Cosine similarity to the query embedding, clamped to 0.0-1.0.
+    /// `None` when no embedding model is available.
+    pub vector_score: Option<f32>,
+}
+
+/// Aggregate size and freshness of one memory namespace.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NamespaceSummary {
+    pub namespace: String,
+    /// Number of memory entries.
+    pub count: u64,
+    /// Total content size in bytes.
+    pub total_bytes: u64,
+    /// Entries per tier.
+    pub working: u64,
+    pub episodic: u64,
+    pub semantic: u64,
+    /// Most recent `updated_at` across the namespace's entries.
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
 /// Query specification for memory retrieval.
 #[derive(Debug, Clone, Default)]
 pub struct MemoryQuery {
@@ -797,6 +968,49 @@ mod tests {
         assert!(mem.decay_factor() > 0.9);
     }
 
+    #[test]
+    fn test_decay_function_curves() {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-6;
+        let half_life = 24.0;
+
+        let exp = DecayFunction::Exponential;
+        assert!(close(exp.factor(0.0, half_life), 1.0));
+        assert!(close(exp.factor(24.0, half_life), 0.5));
+        assert!(close(exp.factor(48.0, half_life), 0.25));
+        assert!(close(exp.factor(72.0, half_life), 0.125));
+
+        let linear = DecayFunction::Linear;
+        assert!(close(linear.factor(0.0, half_life), 1.0));
+        assert!(close(linear.factor(12.0, half_life), 0.75));
+        assert!(close(linear.factor(24.0, half_life), 0.5));
+        assert!(close(linear.factor(48.0, half_life), 0.0));
+        assert!(close(linear.factor(72.0, half_life), 0.0));
+
+        let step = DecayFunction::StepThenDecay {
+            plateau_hours: 48.0,
+        };
+        assert!(close(step.factor(0.0, half_life), 1.0));
+        assert!(close(step.factor(48.0, half_life), 1.0));
+        assert!(close(step.factor(72.0, half_life), 0.5));
+        assert!(close(step.factor(96.0, half_life), 0.25));
+    }
+
+    #[test]
+    fn test_decay_factor_with_follows_curve_and_floor() {
+        let mut mem = Memory::episodic("key", "content");
+        mem.last_accessed = Utc::now() - Duration::hours(24);
+        assert!((mem.decay_factor() - 0.5).abs() < 1e-6);
+        assert!((mem.decay_factor_with(DecayFunction::Linear, 1.0) - 0.5).abs() < 1e-6);
+        let step = DecayFunction::StepThenDecay {
+            plateau_hours: 48.0,
+        };
+        assert!((mem.decay_factor_with(step, 1.0) - 1.0).abs() < 1e-6);
+
+        mem.last_accessed = Utc::now() - Duration::hours(72);
+        mem.metadata.decay_floor = Some(0.2);
+        assert!((mem.decay_factor_with(DecayFunction::Linear, 1.0) - 0.2).abs() < 1e-6);
+    }
+
     #[test]
     fn test_record_access() {
         let mut mem = Memory::working("key", "content");
@@ -15,6 +15,7 @@ pub mod quiet_window;
 pub mod specialist_templates;
 pub mod substrate;
 pub mod swarm_dag;
+pub mod swarm_stats;
 pub mod task;
 pub mod task_schedule;
 pub mod workflow_state;
@@ -36,6 +37,7 @@ pub use quiet_window::*;
 pub use specialist_templates::*;
 pub use substrate::*;
 pub use swarm_dag::*;
+pub use swarm_stats::*;
 pub use task::*;
 pub use task_schedule::*;
 pub use workflow_state::*;
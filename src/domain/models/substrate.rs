@@ -69,6 +69,12 @@ pub struct SubstrateConfig {
     pub allowed_files: Vec<String>,
     /// Denied file patterns (glob)
     pub denied_files: Vec<String>,
+    /// Maximum output tokens per response (if applicable)
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    /// Sequences that stop generation (if applicable)
+    #[serde(default)]
+    pub stop_sequences: Vec<String>,
 }
 
 impl Default for SubstrateConfig {
@@ -85,6 +91,73 @@ impl Default for SubstrateConfig {
             allowed_tool_names: vec![],
             allowed_files: vec![],
             denied_files: vec![],
+            max_tokens: None,
+            stop_sequences: vec![],
+        }
+    }
+}
+
+/// Per-task overrides for substrate invocation parameters.
+///
+/// Unset fields fall back to the agent template and orchestrator defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionParameters {
+    /// Turn budget, replacing the agent/role default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_turns: Option<u32>,
+    /// Sampling temperature (0.0-1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Maximum output tokens per response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    /// Sequences that stop generation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stop_sequences: Vec<String>,
+}
+
+impl ExecutionParameters {
+    /// True when no parameter is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.max_turns.is_none()
+            && self.temperature.is_none()
+            && self.max_tokens.is_none()
+            && self.stop_sequences.is_empty()
+    }
+
+    /// Validate override ranges.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_turns == Some(0) {
+            return Err("max_turns must be greater than 0".to_string());
+        }
+        if let Some(t) = self.temperature
+            && !(0.0..=1.0).contains(&t)
+        {
+            return Err(format!(
+                "temperature must be between 0.0 and 1.0, got {}",
+                t
+            ));
+        }
+        if self.max_tokens == Some(0) {
+            return Err("max_tokens must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+
+    /// Apply the sampling overrides (temperature, max tokens, stop sequences)
+    /// to a substrate config.
+    ///
+    /// `max_turns` is not applied here: the spawner resolves it together with
+    /// the agent default and the retry turn-budget bump.
+    pub fn apply_sampling(&self, config: &mut SubstrateConfig) {
+        if let Some(t) = self.temperature {
+            config.temperature = Some(t);
+        }
+        if let Some(m) = self.max_tokens {
+            config.max_tokens = Some(m);
+        }
+        if !self.stop_sequences.is_empty() {
+            config.stop_sequences = self.stop_sequences.clone();
         }
     }
 }
@@ -218,6 +291,17 @@ impl SessionStatus {
     }
 }
 
+/// Final token and cost totals for a session, as reported by the substrate.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_write_tokens: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_cents: Option<f64>,
+}
+
 /// A substrate session representing an LLM invocation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubstrateSession {
@@ -288,6 +372,17 @@ impl SubstrateSession {
         self.output_tokens += output_tokens;
     }
 
+    /// Replace the running token counts with the substrate's final totals.
+    pub fn record_usage(&mut self, usage: &SessionUsage) {
+        self.input_tokens = usage.input_tokens;
+        self.output_tokens = usage.output_tokens;
+        self.cache_read_tokens = usage.cache_read_tokens;
+        self.cache_write_tokens = usage.cache_write_tokens;
+        if usage.cost_cents.is_some() {
+            self.cost_cents = usage.cost_cents;
+        }
+    }
+
     pub fn complete(&mut self, result: impl Into<String>) {
         self.status = SessionStatus::Completed;
         self.result = Some(result.into());
@@ -345,8 +440,13 @@ pub enum SubstrateOutput {
         input_tokens: u64,
         output_tokens: u64,
     },
-    /// Session complete
-    SessionComplete { result: String },
+    /// Session complete, with the session's final totals when the
+    /// substrate reports them.
+    SessionComplete {
+        result: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        usage: Option<SessionUsage>,
+    },
     /// Agent ran out of turns before completing
     MaxTurnsExceeded { result: String },
     /// Error occurred
@@ -455,4 +555,29 @@ mod tests {
         session.record_turn(100, 50);
         assert!(session.is_over_turn_limit());
     }
+
+    #[test]
+    fn test_execution_parameters_apply_sampling() {
+        let params = ExecutionParameters {
+            max_turns: Some(5),
+            temperature: Some(0.7),
+            stop_sequences: vec!["DONE".to_string()],
+            ..Default::default()
+        };
+        assert!(params.validate().is_ok());
+
+        let mut config = SubstrateConfig::default().with_max_turns(25);
+        params.apply_sampling(&mut config);
+        assert_eq!(config.max_turns, 25, "max_turns is resolved by the spawner");
+        assert_eq!(config.temperature, Some(0.7));
+        assert_eq!(config.max_tokens, None);
+        assert_eq!(config.stop_sequences, vec!["DONE".to_string()]);
+
+        let hot = ExecutionParameters {
+            temperature: Some(1.5),
+            ..Default::default()
+        };
+        assert!(hot.validate().is_err());
+        assert!(ExecutionParameters::default().is_empty());
+    }
 }
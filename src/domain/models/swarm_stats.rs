@@ -0,0 +1,29 @@
+//! Swarm activity counters and the session baselines `swarm stats` measures
+//! against.
+//!
+//! Counters accumulate over the lifetime of the database. `swarm stats
+//! --reset` snapshots them into a [`StatBaseline`]; "this session" is the
+//! activity timestamped at or after the latest baseline's `reset_at`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Cumulative swarm activity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SwarmCounters {
+    /// Tasks in the Complete state.
+    pub tasks_completed: u64,
+    /// Tasks in the Failed state.
+    pub tasks_failed: u64,
+    /// Agent runs recorded against a template.
+    pub executions: u64,
+    /// Tokens consumed by those runs.
+    pub tokens_used: u64,
+}
+
+/// Counters as they stood when a new stats session was started.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatBaseline {
+    pub reset_at: DateTime<Utc>,
+    pub counters: SwarmCounters,
+}
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicU64, Ordering};
 use uuid::Uuid;
 
+use crate::domain::models::ExecutionParameters;
 use crate::domain::models::workflow_state::WorkflowState;
 
 // ===========================================================================
@@ -34,6 +35,7 @@ pub(crate) const KEY_VERIFICATION_PHASE_CONTEXT: &str = "verification_phase_cont
 pub(crate) const KEY_VERIFICATION_AGGREGATION_SUMMARY: &str = "verification_aggregation_summary";
 pub(crate) const KEY_REVIEW_LOOP_ACTIVE: &str = "review_loop_active";
 pub(crate) const KEY_REVIEW_ITERATION: &str = "review_iteration";
+pub(crate) const KEY_REVIEW_LOOP_ORIGIN: &str = "review_loop_origin";
 pub(crate) const KEY_LAST_FAILURE_REASON: &str = "last_failure_reason";
 pub(crate) const KEY_ITERATION: &str = "iteration";
 pub(crate) const KEY_INTENT_GAP_CONTEXT: &str = "intent_gap_context";
@@ -42,6 +44,24 @@ pub(crate) const KEY_GAPS: &str = "gaps";
 pub(crate) const KEY_CONFIDENCE: &str = "confidence";
 pub(crate) const KEY_ACCOMPLISHMENT_SUMMARY: &str = "accomplishment_summary";
 pub(crate) const KEY_SATISFACTION: &str = "satisfaction";
+pub(crate) const KEY_PARTIAL_OUTPUT: &str = "partial_output";
+pub(crate) const KEY_PARTIAL_OUTPUT_AVAILABLE: &str = "partial_output_available";
+pub(crate) const KEY_SUBSTRATE: &str = "substrate";
+pub(crate) const KEY_RESULT: &str = "result";
+pub(crate) const KEY_INJECT_DEPENDENCY_RESULTS: &str = "inject_dependency_results";
+pub(crate) const KEY_OUTPUT_SCHEMA: &str = "output_schema";
+pub(crate) const KEY_CACHEABLE: &str = "cacheable";
+pub(crate) const KEY_RESULT_CACHED: &str = "result_cached";
+
+/// Context keys describing what the task is rather than how a run of it
+/// went; the only custom keys a rerun carries over (see
+/// [`TaskContext::durable`]).
+pub(crate) const DURABLE_CONTEXT_KEYS: &[&str] = &[
+    KEY_GOAL_ID,
+    KEY_INJECT_DEPENDENCY_RESULTS,
+    KEY_OUTPUT_SCHEMA,
+    KEY_CACHEABLE,
+];
 
 /// Interior-mutable version tag used for optimistic locking.
 ///
@@ -197,6 +217,8 @@ pub enum TaskSource {
     Schedule(Uuid),
     /// Task ingested from an external system via a named adapter
     Adapter(String),
+    /// Task pushed by an external system through the tasks webhook endpoint
+    Webhook { source_name: String },
 }
 
 impl Default for TaskSource {
@@ -277,6 +299,9 @@ impl Default for TaskPriority {
 }
 
 impl TaskPriority {
+    /// Every priority, lowest first.
+    pub const ALL: [TaskPriority; 4] = [Self::Low, Self::Normal, Self::High, Self::Critical];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Low => "low",
@@ -420,6 +445,42 @@ impl ExecutionMode {
     }
 }
 
+/// Where a task's agent runs, chosen at submit time.
+///
+/// `Auto` (the default) defers to the task's workflow and the agent's role:
+/// read-only agents run in the repo root, everything else gets the
+/// workflow's workspace (usually a worktree).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceMode {
+    /// Infer from the workflow and agent role.
+    #[default]
+    Auto,
+    /// Run directly in the repo root; no worktree is created.
+    Ephemeral,
+    /// Always run in a dedicated git worktree.
+    Worktree,
+}
+
+impl WorkspaceMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Ephemeral => "ephemeral",
+            Self::Worktree => "worktree",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "ephemeral" => Some(Self::Ephemeral),
+            "worktree" => Some(Self::Worktree),
+            _ => None,
+        }
+    }
+}
+
 /// Hints for agent routing.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RoutingHints {
@@ -427,6 +488,10 @@ pub struct RoutingHints {
     pub preferred_agent: Option<String>,
     /// Required tools/capabilities
     pub required_tools: Vec<String>,
+    /// Capabilities the agent must declare. When the task has no concrete
+    /// agent type, any active template declaring all of them may take it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_capabilities: Vec<String>,
     /// Estimated complexity
     pub complexity: Complexity,
     /// Prompt tier for context assembly
@@ -479,6 +544,23 @@ impl TaskContext {
     /// of the vector so that the most recent hints are always preserved.
     pub const MAX_HINTS: usize = 20;
 
+    /// Copy of this context without per-run state: custom keys are limited
+    /// to [`DURABLE_CONTEXT_KEYS`], so workflow progress, verification
+    /// bookkeeping, results and the substrate a run used are dropped.
+    pub fn durable(&self) -> TaskContext {
+        TaskContext {
+            input: self.input.clone(),
+            hints: self.hints.clone(),
+            relevant_files: self.relevant_files.clone(),
+            custom: self
+                .custom
+                .iter()
+                .filter(|(key, _)| DURABLE_CONTEXT_KEYS.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        }
+    }
+
     /// Push a hint, enforcing the [`MAX_HINTS`](Self::MAX_HINTS) cap.
     ///
     /// If the hints vector would exceed `MAX_HINTS` after the push, the
@@ -491,6 +573,32 @@ impl TaskContext {
             self.hints.drain(..excess);
         }
     }
+
+    /// Record the dependency edges whose results are injected into the
+    /// task's prompt (see [`Task::injected_dependencies`]).
+    pub fn set_injected_dependencies(&mut self, task_ids: &[Uuid]) {
+        self.custom.insert(
+            KEY_INJECT_DEPENDENCY_RESULTS.to_string(),
+            serde_json::json!(task_ids.iter().map(Uuid::to_string).collect::<Vec<_>>()),
+        );
+    }
+}
+
+/// The fields the scheduler ranks a Ready task by, loadable for the whole
+/// ready set without fetching full tasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadyTaskKey {
+    pub id: Uuid,
+    pub priority: TaskPriority,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ReadyTaskKey {
+    /// See [`Task::aged_priority`].
+    pub fn aged_priority(&self, now: DateTime<Utc>, aging_coefficient: f64) -> f64 {
+        let wait_secs = (now - self.created_at).num_seconds().max(1) as f64;
+        self.priority as i32 as f64 + aging_coefficient * wait_secs.ln()
+    }
 }
 
 /// A discrete unit of work that can be executed by an agent.
@@ -546,6 +654,30 @@ pub struct Task {
     pub trajectory_id: Option<Uuid>,
     /// What kind of work this task represents (standard, verification, research, review).
     pub task_type: TaskType,
+    /// Submitter's effort estimate in seconds, used for velocity reporting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate_secs: Option<u64>,
+    /// Actual execution time in seconds, recorded on transition to Complete.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<u64>,
+    /// Current rung of the agent type's model escalation ladder, set when
+    /// the task is claimed by an agent type that has a ladder configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_ladder_position: Option<u32>,
+    /// Per-task substrate parameter overrides applied over the agent
+    /// defaults at spawn time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_params: Option<ExecutionParameters>,
+    /// The completed task this task re-runs (see `task rerun`), if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supersedes: Option<Uuid>,
+    /// Overseers (e.g. `compilation`, `test-suite`) that must pass once
+    /// after a direct-mode agent completes; any failure fails the task.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_overseers: Vec<String>,
+    /// Whether the agent runs in a worktree or the repo root.
+    #[serde(default)]
+    pub workspace_mode: WorkspaceMode,
     /// The DB version at read time, used for optimistic locking.
     /// This is never serialized/deserialized — it is set when loading from the DB
     /// and compared in the UPDATE WHERE clause to detect concurrent modifications.
@@ -585,6 +717,13 @@ impl Task {
             execution_mode: ExecutionMode::default(),
             trajectory_id: None,
             task_type: TaskType::default(),
+            estimate_secs: None,
+            duration_secs: None,
+            model_ladder_position: None,
+            execution_params: None,
+            supersedes: None,
+            required_overseers: Vec::new(),
+            workspace_mode: WorkspaceMode::default(),
             loaded_version: VersionTag::new(1),
         }
     }
@@ -618,6 +757,13 @@ impl Task {
             execution_mode: ExecutionMode::default(),
             trajectory_id: None,
             task_type: TaskType::default(),
+            estimate_secs: None,
+            duration_secs: None,
+            model_ladder_position: None,
+            execution_params: None,
+            supersedes: None,
+            required_overseers: Vec::new(),
+            workspace_mode: WorkspaceMode::default(),
             loaded_version: VersionTag::new(1),
         }
     }
@@ -636,6 +782,14 @@ impl Task {
         self
     }
 
+    /// Add a data-flow dependency: like [`with_dependency`](Self::with_dependency),
+    /// but the dependency's result is also injected into this task's prompt.
+    pub fn with_result_dependency(mut self, task_id: Uuid) -> Self {
+        self = self.with_dependency(task_id);
+        self.set_inject_dependency_result(task_id);
+        self
+    }
+
     /// Set priority.
     pub fn with_priority(mut self, priority: TaskPriority) -> Self {
         self.priority = priority;
@@ -672,12 +826,30 @@ impl Task {
         self
     }
 
+    /// Set workspace mode.
+    pub fn with_workspace_mode(mut self, mode: WorkspaceMode) -> Self {
+        self.workspace_mode = mode;
+        self
+    }
+
     /// Set task type.
     pub fn with_task_type(mut self, task_type: TaskType) -> Self {
         self.task_type = task_type;
         self
     }
 
+    /// Set effort estimate (seconds).
+    pub fn with_estimate_secs(mut self, secs: u64) -> Self {
+        self.estimate_secs = Some(secs);
+        self
+    }
+
+    /// Set substrate parameter overrides. Empty overrides are dropped.
+    pub fn with_execution_params(mut self, params: ExecutionParameters) -> Self {
+        self.execution_params = (!params.is_empty()).then_some(params);
+        self
+    }
+
     /// Check if can transition to given status.
     pub fn can_transition_to(&self, new_status: TaskStatus) -> bool {
         self.status.can_transition_to(new_status)
@@ -714,6 +886,13 @@ impl Task {
             _ => {}
         }
 
+        // Record actual duration for velocity reporting.
+        if new_status == TaskStatus::Complete
+            && let (Some(start), Some(end)) = (self.started_at, self.completed_at)
+        {
+            self.duration_secs = Some((end - start).num_seconds().max(0) as u64);
+        }
+
         Ok(())
     }
 
@@ -722,6 +901,24 @@ impl Task {
         self.status.is_terminal()
     }
 
+    /// Scheduling priority with wait-time aging folded in:
+    /// `priority + aging_coefficient * ln(wait_secs)`, with the wait measured
+    /// from `created_at` and floored at one second. Unlike the priority-aging
+    /// handler this never touches the stored priority; a zero coefficient
+    /// yields the base priority.
+    pub fn aged_priority(&self, now: DateTime<Utc>, aging_coefficient: f64) -> f64 {
+        self.ready_key().aged_priority(now, aging_coefficient)
+    }
+
+    /// The fields the scheduler ranks this task by.
+    pub fn ready_key(&self) -> ReadyTaskKey {
+        ReadyTaskKey {
+            id: self.id,
+            priority: self.priority,
+            created_at: self.created_at,
+        }
+    }
+
     /// Check if task can be retried.
     pub fn can_retry(&self) -> bool {
         self.status == TaskStatus::Failed && self.retry_count < self.max_retries
@@ -739,6 +936,34 @@ impl Task {
         Ok(())
     }
 
+    /// Operator reset of a wedged task (see `swarm restart-task`): move a
+    /// Running, Validating, Failed or Blocked task back to Ready or Pending,
+    /// counting the interrupted attempt against `retry_count`.
+    pub fn restart(&mut self, target: TaskStatus) -> Result<(), String> {
+        if !matches!(target, TaskStatus::Ready | TaskStatus::Pending) {
+            return Err(format!(
+                "Cannot restart to {}: target must be ready or pending",
+                target.as_str()
+            ));
+        }
+        if !matches!(
+            self.status,
+            TaskStatus::Running | TaskStatus::Validating | TaskStatus::Failed | TaskStatus::Blocked
+        ) {
+            return Err(format!(
+                "Cannot restart a {} task: only running, validating, failed or blocked tasks can be restarted",
+                self.status.as_str()
+            ));
+        }
+        self.retry_count += 1;
+        self.status = target;
+        self.started_at = None;
+        self.completed_at = None;
+        self.updated_at = Utc::now();
+        self.version += 1;
+        Ok(())
+    }
+
     /// Validate task.
     pub fn validate(&self) -> Result<(), String> {
         if self.title.is_empty() {
@@ -982,6 +1207,24 @@ impl Task {
         );
     }
 
+    // --- review_loop_origin: Uuid (stored as string) -------------------------
+
+    /// ID of the review task that started this task's review loop chain.
+    pub fn review_loop_origin(&self) -> Option<Uuid> {
+        self.context
+            .custom
+            .get(KEY_REVIEW_LOOP_ORIGIN)
+            .and_then(|v| v.as_str())
+            .and_then(|s| Uuid::parse_str(s).ok())
+    }
+
+    pub fn set_review_loop_origin(&mut self, origin: Uuid) {
+        self.context.custom.insert(
+            KEY_REVIEW_LOOP_ORIGIN.to_string(),
+            serde_json::Value::String(origin.to_string()),
+        );
+    }
+
     // --- last_failure_reason: String ----------------------------------------
 
     pub fn last_failure_reason(&self) -> Option<&str> {
@@ -1114,6 +1357,153 @@ impl Task {
     pub fn iteration_value(&self) -> Option<&serde_json::Value> {
         self.context.custom.get(KEY_ITERATION)
     }
+
+    // --- partial_output: String (+ partial_output_available: bool) ---------
+
+    /// Output an agent streamed before it timed out or was killed.
+    pub fn partial_output(&self) -> Option<&str> {
+        self.context
+            .custom
+            .get(KEY_PARTIAL_OUTPUT)
+            .and_then(|v| v.as_str())
+    }
+
+    pub fn partial_output_available(&self) -> bool {
+        self.context
+            .custom
+            .get(KEY_PARTIAL_OUTPUT_AVAILABLE)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Save partial agent output and flag it as available to humans/retries.
+    pub fn set_partial_output(&mut self, output: impl Into<String>) {
+        self.context.custom.insert(
+            KEY_PARTIAL_OUTPUT.to_string(),
+            serde_json::Value::String(output.into()),
+        );
+        self.context.custom.insert(
+            KEY_PARTIAL_OUTPUT_AVAILABLE.to_string(),
+            serde_json::Value::Bool(true),
+        );
+    }
+
+    pub fn clear_partial_output(&mut self) {
+        self.context.custom.remove(KEY_PARTIAL_OUTPUT);
+        self.context.custom.remove(KEY_PARTIAL_OUTPUT_AVAILABLE);
+    }
+
+    // --- substrate: String --------------------------------------------------
+
+    /// Substrate the latest attempt actually ran on, which differs from the
+    /// configured primary after a circuit-breaker failover.
+    pub fn substrate(&self) -> Option<&str> {
+        self.context.custom.get(KEY_SUBSTRATE).and_then(|v| v.as_str())
+    }
+
+    pub fn set_substrate(&mut self, name: impl Into<String>) {
+        self.context.custom.insert(
+            KEY_SUBSTRATE.to_string(),
+            serde_json::Value::String(name.into()),
+        );
+    }
+
+    // --- result: String -----------------------------------------------------
+
+    /// Final output of the agent that completed this task.
+    pub fn result(&self) -> Option<&str> {
+        self.context.custom.get(KEY_RESULT).and_then(|v| v.as_str())
+    }
+
+    pub fn set_result(&mut self, result: impl Into<String>) {
+        self.context.custom.insert(
+            KEY_RESULT.to_string(),
+            serde_json::Value::String(result.into()),
+        );
+    }
+
+    // --- output_schema: JSON Schema object ---------------------------------
+
+    /// JSON Schema the agent's final output must satisfy. Checked once the
+    /// agent completes; a mismatch fails the task for retry.
+    pub fn output_schema(&self) -> Option<&serde_json::Value> {
+        self.context
+            .custom
+            .get(KEY_OUTPUT_SCHEMA)
+            .filter(|v| v.is_object())
+    }
+
+    pub fn set_output_schema(&mut self, schema: serde_json::Value) {
+        self.context
+            .custom
+            .insert(KEY_OUTPUT_SCHEMA.to_string(), schema);
+    }
+
+    // --- cacheable / result_cached: bool -----------------------------------
+
+    /// Whether this task is idempotent, so an identical earlier run against
+    /// the same repository state may stand in for it (see `result_cache`).
+    pub fn is_cacheable(&self) -> bool {
+        self.context
+            .custom
+            .get(KEY_CACHEABLE)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    pub fn set_cacheable(&mut self, cacheable: bool) {
+        self.context.custom.insert(
+            KEY_CACHEABLE.to_string(),
+            serde_json::Value::Bool(cacheable),
+        );
+    }
+
+    /// Whether the result was served from the result cache rather than by
+    /// running an agent.
+    pub fn result_cached(&self) -> bool {
+        self.context
+            .custom
+            .get(KEY_RESULT_CACHED)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    pub fn set_result_cached(&mut self) {
+        self.context
+            .custom
+            .insert(KEY_RESULT_CACHED.to_string(), serde_json::Value::Bool(true));
+    }
+
+    // --- inject_dependency_results: JSON array of task IDs -----------------
+
+    /// Dependencies whose result and artifacts are pulled into this task's
+    /// prompt once they complete. Edges not listed here only gate ordering.
+    pub fn injected_dependencies(&self) -> Vec<Uuid> {
+        self.context
+            .custom
+            .get(KEY_INJECT_DEPENDENCY_RESULTS)
+            .and_then(|v| v.as_array())
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| id.as_str().and_then(|s| Uuid::parse_str(s).ok()))
+                    .filter(|id| self.depends_on.contains(id))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Mark the dependency edge to `task_id` as passing its result into
+    /// this task's prompt. Has no effect unless `task_id` is a dependency.
+    pub fn set_inject_dependency_result(&mut self, task_id: Uuid) {
+        if !self.depends_on.contains(&task_id) {
+            return;
+        }
+        let mut ids = self.injected_dependencies();
+        if !ids.contains(&task_id) {
+            ids.push(task_id);
+        }
+        self.context.set_injected_dependencies(&ids);
+    }
 }
 
 /// Generate a short title from a prompt string.
@@ -1133,6 +1523,35 @@ fn generate_title(prompt: &str) -> String {
     }
 }
 
+/// Estimate-vs-actual accuracy and throughput over completed tasks in a window.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaskVelocity {
+    /// Length of the reporting window in seconds.
+    pub window_secs: u64,
+    /// Tasks completed within the window.
+    pub completed: u64,
+    /// Completed tasks that carried both an estimate and a recorded duration.
+    pub estimated: u64,
+    /// Mean actual duration across completed tasks with a recorded duration.
+    pub avg_actual_secs: Option<f64>,
+    /// Mean estimate across estimated tasks.
+    pub avg_estimate_secs: Option<f64>,
+    /// Mean absolute estimate error (|actual - estimate|) across estimated tasks.
+    pub mean_abs_error_secs: Option<f64>,
+    /// Mean signed estimate error (actual - estimate); positive means overruns.
+    pub mean_error_secs: Option<f64>,
+}
+
+impl TaskVelocity {
+    /// Completed tasks per day over the window.
+    pub fn throughput_per_day(&self) -> f64 {
+        if self.window_secs == 0 {
+            return 0.0;
+        }
+        self.completed as f64 * 86_400.0 / self.window_secs as f64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1187,6 +1606,20 @@ mod tests {
         assert!(task.is_terminal());
     }
 
+    #[test]
+    fn test_complete_records_duration() {
+        let mut task = Task::new("Timed").with_estimate_secs(600);
+        task.transition_to(TaskStatus::Ready).unwrap();
+        task.transition_to(TaskStatus::Running).unwrap();
+        task.started_at = Some(Utc::now() - chrono::Duration::seconds(90));
+        assert_eq!(task.duration_secs, None);
+
+        task.transition_to(TaskStatus::Complete).unwrap();
+        let secs = task.duration_secs.unwrap();
+        assert!((90..=91).contains(&secs), "got {}", secs);
+        assert_eq!(task.estimate_secs, Some(600));
+    }
+
     #[test]
     fn test_task_retry() {
         let mut task = Task::new("Test task description");
@@ -1206,6 +1639,22 @@ mod tests {
         assert!(task.depends_on.contains(&dep_id));
     }
 
+    #[test]
+    fn test_result_dependency_marks_only_its_edge() {
+        let data_dep = Uuid::new_v4();
+        let order_dep = Uuid::new_v4();
+        let mut task = Task::new("Consume the design")
+            .with_result_dependency(data_dep)
+            .with_dependency(order_dep);
+
+        assert_eq!(task.depends_on, vec![data_dep, order_dep]);
+        assert_eq!(task.injected_dependencies(), vec![data_dep]);
+
+        // Edges that are not dependencies cannot be marked.
+        task.set_inject_dependency_result(Uuid::new_v4());
+        assert_eq!(task.injected_dependencies(), vec![data_dep]);
+    }
+
     #[test]
     fn test_validating_transitions() {
         // Running -> Validating -> Complete
@@ -28,6 +28,14 @@ pub trait IngestionAdapter: Send + Sync {
     /// that have been created or updated since that timestamp. If `None`,
     /// the adapter should perform a full initial sync.
     async fn poll(&self, last_poll: Option<DateTime<Utc>>) -> DomainResult<Vec<IngestionItem>>;
+
+    /// Verify the external system is reachable with the configured
+    /// credentials, using a lightweight authenticated call.
+    ///
+    /// Defaults to `Ok(())` for adapters with nothing to check.
+    async fn health_check(&self) -> DomainResult<()> {
+        Ok(())
+    }
 }
 
 /// Port for adapters that push results to an external system.
@@ -45,4 +53,10 @@ pub trait EgressAdapter: Send + Sync {
     /// Returns an [`EgressResult`] indicating whether the action succeeded,
     /// along with any external identifiers or URLs for the affected resource.
     async fn execute(&self, action: &EgressAction) -> DomainResult<EgressResult>;
+
+    /// Verify the external system is reachable with the configured
+    /// credentials. See [`IngestionAdapter::health_check`].
+    async fn health_check(&self) -> DomainResult<()> {
+        Ok(())
+    }
 }
@@ -50,6 +50,11 @@ pub trait AgentRepository: Send + Sync {
     /// Get active templates.
     async fn get_active_templates(&self) -> DomainResult<Vec<AgentTemplate>>;
 
+    /// Active templates that declare `capability`, either on their agent card
+    /// or as a tool name (case-insensitive). One template per name, at its
+    /// latest active version, ordered by name.
+    async fn find_by_capability(&self, capability: &str) -> DomainResult<Vec<AgentTemplate>>;
+
     // Instance operations
 
     /// Create a new agent instance.
@@ -34,6 +34,12 @@ pub trait EmbeddingProvider: Send + Sync {
     /// Embedding dimension for this provider/model.
     fn dimension(&self) -> usize;
 
+    /// Identifier stamped on stored vectors so embeddings from different
+    /// models are never compared. Must change whenever the vector space does.
+    fn model_id(&self) -> String {
+        format!("{}@{}", self.name(), self.dimension())
+    }
+
     /// Generate an embedding for a single text.
     async fn embed(&self, text: &str) -> DomainResult<Vec<f32>>;
 
@@ -0,0 +1,24 @@
+//! Leader lease repository port.
+//!
+//! Swarm processes sharing one database use named leases to agree on a single
+//! leader for work that must not run in every process. A lease is held until
+//! its expiry and renewed by the holder; once it lapses, any process may take
+//! it over.
+
+use async_trait::async_trait;
+use std::time::Duration;
+
+use crate::domain::errors::DomainResult;
+
+/// Repository interface for named leadership leases.
+#[async_trait]
+pub trait LeaderLeaseRepository: Send + Sync {
+    /// Take or renew lease `name` for `holder`, expiring `ttl` from now.
+    ///
+    /// Succeeds when the lease is unheld, expired, or already held by
+    /// `holder`. Returns whether `holder` holds the lease afterwards.
+    async fn try_acquire(&self, name: &str, holder: &str, ttl: Duration) -> DomainResult<bool>;
+
+    /// Give up lease `name` if `holder` holds it.
+    async fn release(&self, name: &str, holder: &str) -> DomainResult<()>;
+}
@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::domain::errors::DomainResult;
-use crate::domain::models::{Memory, MemoryQuery, MemoryTier};
+use crate::domain::models::{Memory, MemoryQuery, MemoryTier, NamespaceSummary};
 
 /// Repository interface for Memory persistence.
 #[async_trait]
@@ -58,4 +58,24 @@ pub trait MemoryRepository: Send + Sync {
 
     /// Count memories by tier.
     async fn count_by_tier(&self) -> DomainResult<std::collections::HashMap<MemoryTier, u64>>;
+
+    /// Summarize every namespace (entry count, size, tier breakdown, last
+    /// update), ordered by namespace.
+    async fn list_namespaces(&self) -> DomainResult<Vec<NamespaceSummary>>;
+
+    /// Count memories whose stored embedding was not produced by `model`
+    /// (including memories with no embedding).
+    async fn count_stale_embeddings(&self, model: &str) -> DomainResult<u64>;
+
+    /// Up to `limit` memories whose stored embedding was not produced by
+    /// `model`, in a stable order.
+    async fn list_stale_embeddings(&self, model: &str, limit: usize) -> DomainResult<Vec<Memory>>;
+
+    /// Atomically replace the embeddings of the given memories, stamping
+    /// each with `model`.
+    async fn store_embeddings(
+        &self,
+        model: &str,
+        embeddings: &[(Uuid, Vec<f32>)],
+    ) -> DomainResult<()>;
 }
@@ -5,13 +5,16 @@ pub mod agent_repository;
 pub mod embedding;
 pub mod federated_goal_repository;
 pub mod goal_repository;
+pub mod leader_lease_repository;
 pub mod memory_repository;
 pub mod merge_request_repository;
 pub mod null_embedding;
 pub mod null_memory;
 pub mod outbox_repository;
 pub mod quiet_window_repository;
+pub mod stats_repository;
 pub mod substrate;
+pub mod swarm_control_repository;
 pub mod task_repository;
 pub mod task_schedule_repository;
 pub mod trajectory_repository;
@@ -23,13 +26,16 @@ pub use agent_repository::{AgentFilter, AgentRepository};
 pub use embedding::{EmbeddingInput, EmbeddingOutput, EmbeddingProvider};
 pub use federated_goal_repository::FederatedGoalRepository;
 pub use goal_repository::{GoalFilter, GoalRepository};
+pub use leader_lease_repository::LeaderLeaseRepository;
 pub use memory_repository::MemoryRepository;
 pub use merge_request_repository::MergeRequestRepository;
 pub use null_embedding::NullEmbeddingProvider;
 pub use null_memory::NullMemoryRepository;
 pub use outbox_repository::OutboxRepository;
 pub use quiet_window_repository::{QuietWindowFilter, QuietWindowRepository};
+pub use stats_repository::StatsRepository;
 pub use substrate::{Substrate, SubstrateFactory};
+pub use swarm_control_repository::SwarmControlRepository;
 pub use task_repository::{TaskFilter, TaskRepository};
 pub use task_schedule_repository::{TaskScheduleFilter, TaskScheduleRepository};
 pub use trajectory_repository::*;
@@ -8,7 +8,7 @@ use uuid::Uuid;
 
 use super::MemoryRepository;
 use crate::domain::errors::DomainResult;
-use crate::domain::models::{Memory, MemoryQuery, MemoryTier};
+use crate::domain::models::{Memory, MemoryQuery, MemoryTier, NamespaceSummary};
 
 /// A no-op memory repository that stores nothing.
 ///
@@ -88,4 +88,28 @@ impl MemoryRepository for NullMemoryRepository {
     async fn count_by_tier(&self) -> DomainResult<std::collections::HashMap<MemoryTier, u64>> {
         Ok(std::collections::HashMap::new())
     }
+
+    async fn list_namespaces(&self) -> DomainResult<Vec<NamespaceSummary>> {
+        Ok(Vec::new())
+    }
+
+    async fn count_stale_embeddings(&self, _model: &str) -> DomainResult<u64> {
+        Ok(0)
+    }
+
+    async fn list_stale_embeddings(
+        &self,
+        _model: &str,
+        _limit: usize,
+    ) -> DomainResult<Vec<Memory>> {
+        Ok(Vec::new())
+    }
+
+    async fn store_embeddings(
+        &self,
+        _model: &str,
+        _embeddings: &[(Uuid, Vec<f32>)],
+    ) -> DomainResult<()> {
+        Ok(())
+    }
 }
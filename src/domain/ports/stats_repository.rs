@@ -0,0 +1,27 @@
+//! Swarm stats repository port.
+//!
+//! Exposes the lifetime activity counters behind `swarm stats` and the
+//! baselines that split them into sessions.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::errors::DomainResult;
+use crate::domain::models::{StatBaseline, SwarmCounters};
+
+/// Repository interface for swarm activity counters and stat baselines.
+#[async_trait]
+pub trait StatsRepository: Send + Sync {
+    /// Current lifetime counters.
+    async fn counters(&self) -> DomainResult<SwarmCounters>;
+
+    /// Counters for activity at or after `since`: tasks that completed or
+    /// failed, and agent runs recorded, from that moment on.
+    async fn counters_since(&self, since: DateTime<Utc>) -> DomainResult<SwarmCounters>;
+
+    /// The most recently recorded baseline, if any.
+    async fn latest_baseline(&self) -> DomainResult<Option<StatBaseline>>;
+
+    /// Snapshot the current counters as a new baseline and return it.
+    async fn reset_baseline(&self) -> DomainResult<StatBaseline>;
+}
@@ -33,6 +33,20 @@ pub trait Substrate: Send + Sync {
         request: SubstrateRequest,
     ) -> DomainResult<(mpsc::Receiver<SubstrateOutput>, SubstrateSession)>;
 
+    /// Run a session to completion like [`Substrate::execute`], forwarding
+    /// parsed output to `output` as it arrives.
+    ///
+    /// The default ignores `output`; substrates that can observe their output
+    /// while it is produced override this.
+    async fn execute_with_output(
+        &self,
+        request: SubstrateRequest,
+        output: mpsc::Sender<SubstrateOutput>,
+    ) -> DomainResult<SubstrateSession> {
+        drop(output);
+        self.execute(request).await
+    }
+
     /// Resume an existing session.
     async fn resume(
         &self,
@@ -0,0 +1,18 @@
+//! Swarm control repository port.
+//!
+//! Carries operator requests from CLI processes to a running orchestrator
+//! that shares the database, such as pausing dispatch before a deploy.
+
+use async_trait::async_trait;
+
+use crate::domain::errors::DomainResult;
+
+/// Repository interface for persisted swarm control flags.
+#[async_trait]
+pub trait SwarmControlRepository: Send + Sync {
+    /// Whether an operator has asked the swarm to pause.
+    async fn is_paused(&self) -> DomainResult<bool>;
+
+    /// Record whether the swarm should be paused.
+    async fn set_paused(&self, paused: bool) -> DomainResult<()>;
+}
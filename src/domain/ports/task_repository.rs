@@ -5,13 +5,15 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::errors::DomainResult;
-use crate::domain::models::{Task, TaskPriority, TaskStatus, TaskType};
+use crate::domain::models::{ReadyTaskKey, Task, TaskPriority, TaskStatus, TaskType, TaskVelocity};
 
 /// Filter criteria for listing tasks.
 #[derive(Debug, Clone, Default)]
 pub struct TaskFilter {
     pub status: Option<TaskStatus>,
     pub priority: Option<TaskPriority>,
+    /// Only include tasks whose priority lies within this inclusive `(min, max)` range.
+    pub priority_range: Option<(TaskPriority, TaskPriority)>,
     pub parent_id: Option<Uuid>,
     pub agent_type: Option<String>,
     pub task_type: Option<TaskType>,
@@ -26,6 +28,13 @@ pub trait TaskRepository: Send + Sync {
     /// Create a new task.
     async fn create(&self, task: &Task) -> DomainResult<()>;
 
+    /// Insert many tasks atomically.
+    ///
+    /// Rows and their dependency edges either all commit or none do. Tasks
+    /// may reference each other (as parent or dependency) in any order;
+    /// references outside the batch must already exist.
+    async fn bulk_insert(&self, tasks: &[Task]) -> DomainResult<()>;
+
     /// Get a task by ID.
     async fn get(&self, id: Uuid) -> DomainResult<Option<Task>>;
 
@@ -38,6 +47,13 @@ pub trait TaskRepository: Send + Sync {
     /// List tasks with optional filters.
     async fn list(&self, filter: TaskFilter) -> DomainResult<Vec<Task>>;
 
+    /// Search task titles and descriptions for `query`, best match first.
+    ///
+    /// Every whitespace-separated term must match. Results are restricted
+    /// to tasks that also satisfy `filter`, whose `limit` caps the count.
+    /// An empty query matches nothing.
+    async fn search_by_text(&self, query: &str, filter: TaskFilter) -> DomainResult<Vec<Task>>;
+
     /// Get tasks by status.
     async fn list_by_status(&self, status: TaskStatus) -> DomainResult<Vec<Task>>;
 
@@ -47,6 +63,10 @@ pub trait TaskRepository: Send + Sync {
     /// Get ready tasks (dependencies met, ordered by priority).
     async fn get_ready_tasks(&self, limit: usize) -> DomainResult<Vec<Task>>;
 
+    /// Scheduling keys of every ready task, ordered by priority then age.
+    /// Light enough to rank the whole ready set in memory.
+    async fn ready_task_keys(&self) -> DomainResult<Vec<ReadyTaskKey>>;
+
     /// Get tasks assigned to a specific agent type.
     async fn get_by_agent(&self, agent_type: &str) -> DomainResult<Vec<Task>>;
 
@@ -68,12 +88,21 @@ pub trait TaskRepository: Send + Sync {
     /// Get task by idempotency key.
     async fn get_by_idempotency_key(&self, key: &str) -> DomainResult<Option<Task>>;
 
+    /// Count tasks whose idempotency key starts with `prefix`.
+    ///
+    /// Used by handlers that namespace their idempotency keys to derive
+    /// durable counters (e.g. review-loop iterations) from persisted rows.
+    async fn count_by_idempotency_prefix(&self, prefix: &str) -> DomainResult<u64>;
+
     /// List tasks by source type (e.g., "human", "system", "subtask", "goal_evaluation").
     async fn list_by_source(&self, source_type: &str) -> DomainResult<Vec<Task>>;
 
     /// Count tasks by status.
     async fn count_by_status(&self) -> DomainResult<std::collections::HashMap<TaskStatus, u64>>;
 
+    /// Aggregate estimate-vs-actual accuracy over tasks completed since `since`.
+    async fn velocity_since(&self, since: DateTime<Utc>) -> DomainResult<TaskVelocity>;
+
     /// Atomically claim a Ready task by transitioning it to Running.
     ///
     /// Uses `UPDATE ... WHERE status = 'ready'` to prevent TOCTOU races.
@@ -84,6 +113,21 @@ pub trait TaskRepository: Send + Sync {
         agent_type: &str,
     ) -> DomainResult<Option<Task>>;
 
+    /// Atomically claim the first of `candidates` that is still Ready,
+    /// transitioning it to Running for an `agent_type` agent.
+    ///
+    /// `candidates` is in the caller's preference order — the orchestrator
+    /// passes tasks it has already ranked by [`Task::aged_priority`] and
+    /// routed through the pre-spawn middleware, so nothing the gates refuse
+    /// is ever claimed. Selection and claim happen in a single statement, so
+    /// concurrent callers never receive the same task. Returns `Ok(None)`
+    /// when no candidate is still Ready.
+    async fn claim_next_ready(
+        &self,
+        candidates: &[Uuid],
+        agent_type: &str,
+    ) -> DomainResult<Option<Task>>;
+
     /// Get only the parent_id for a task, without loading the full struct.
     ///
     /// Returns `Ok(None)` if the task does not exist or has no parent.
@@ -55,6 +55,10 @@ pub trait TrajectoryRepository: Send + Sync {
     /// Get the most recent trajectories, ordered by `updated_at` descending.
     async fn get_recent(&self, limit: usize) -> DomainResult<Vec<Trajectory>>;
 
+    /// Get all trajectories that have not reached a terminal phase
+    /// (`converged`, `exhausted`, `trapped`), ordered by `updated_at` descending.
+    async fn get_active(&self) -> DomainResult<Vec<Trajectory>>;
+
     /// Get successful strategy entries for a given attractor type.
     ///
     /// Used for convergence memory and learning: retrieves strategies that
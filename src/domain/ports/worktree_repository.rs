@@ -24,6 +24,19 @@ pub trait WorktreeRepository: Send + Sync {
     /// Update a worktree.
     async fn update(&self, worktree: &Worktree) -> DomainResult<()>;
 
+    /// Atomically hand a completed or merged worktree from one task to
+    /// another and mark it active again.
+    ///
+    /// Returns `false` without changing anything when the worktree is no
+    /// longer owned by `from_task` or in neither state, e.g. because another
+    /// task reused it first, a merge started, or it was cleaned up.
+    async fn reassign_completed(
+        &self,
+        id: Uuid,
+        from_task: Uuid,
+        to_task: Uuid,
+    ) -> DomainResult<bool>;
+
     /// Delete a worktree record.
     async fn delete(&self, id: Uuid) -> DomainResult<()>;
 
@@ -4,15 +4,42 @@ use clap::Parser;
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 
 use abathur::cli::{Cli, Commands};
+use abathur::services::log_sampling::SamplingLayer;
 
 #[tokio::main]
 async fn main() {
+    let cli = Cli::parse();
+
+    // Sampling is read before logging is up, so a broken config only costs
+    // us sampling here; invariant violations are reported below, and
+    // unreadable files by the command itself.
+    let config = abathur::services::Config::from_file_unvalidated(&cli.config).ok();
+    let config_errors = config
+        .as_ref()
+        .and_then(|config| config.validate_all().err())
+        .unwrap_or_default();
+    let sampling = config
+        .filter(|_| config_errors.is_empty())
+        .map(|config| config.logging.sampling)
+        .unwrap_or_default();
+    let sampler = Some(SamplingLayer::new(&sampling)).filter(SamplingLayer::is_active);
+
     tracing_subscriber::registry()
         .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with(sampler)
         .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
         .init();
 
-    let cli = Cli::parse();
+    abathur::cli::display::configure(cli.no_color, cli.theme);
+
+    // `init` and `config` must keep working so a broken config can be fixed.
+    let repairs_config = matches!(
+        cli.command,
+        Commands::Init(_) | Commands::Config(_) | Commands::Completions { .. }
+    );
+    if !config_errors.is_empty() && !repairs_config {
+        abathur::cli::handle_config_errors(&config_errors, cli.json);
+    }
 
     let result = match cli.command {
         Commands::Init(args) => abathur::cli::commands::init::execute(args, cli.json).await,
@@ -26,9 +53,17 @@ async fn main() {
         Commands::Trigger(args) => abathur::cli::commands::trigger::execute(args, cli.json).await,
         Commands::Schedule(args) => abathur::cli::commands::schedule::execute(args, cli.json).await,
         Commands::Event(args) => abathur::cli::commands::event::execute(args, cli.json).await,
+        Commands::Audit(args) => abathur::cli::commands::audit::execute(args, cli.json).await,
         Commands::Workflow(args) => abathur::cli::commands::workflow::execute(args, cli.json).await,
         Commands::Adapter(args) => abathur::cli::commands::adapter::execute(args, cli.json).await,
         Commands::Cron(args) => abathur::cli::commands::cron::execute(args, cli.json).await,
+        Commands::Loop(args) => {
+            abathur::cli::commands::convergence_loop::execute(args, cli.json).await
+        }
+        Commands::Config(args) => {
+            abathur::cli::commands::config::execute(args, &cli.config, cli.json).await
+        }
+        Commands::Db(args) => abathur::cli::commands::db::execute(args, cli.json).await,
         Commands::Completions { shell } => {
             abathur::cli::print_completions(shell);
             Ok(())
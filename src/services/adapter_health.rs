@@ -0,0 +1,261 @@
+//! Adapter health reporting.
+//!
+//! Combines a live connectivity probe ([`AdapterRegistry::health_check`])
+//! with recent adapter activity from the event store to produce a per-adapter
+//! [`AdapterStatus`]. Used by `abathur adapter status` and by the
+//! `AdapterHealthHandler`, which escalates adapters that stay unhealthy.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::services::adapter_registry::AdapterRegistry;
+use crate::services::event_bus::{EventPayload, UnifiedEvent};
+
+/// Upper bound on a single adapter's connectivity probe.
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Health and recent activity of one registered adapter.
+#[derive(Debug, Clone, Serialize)]
+pub struct AdapterStatus {
+    pub name: String,
+    pub healthy: bool,
+    /// Why the connectivity probe failed, when unhealthy.
+    pub error: Option<String>,
+    /// Most recent `AdapterIngestionCompleted` for this adapter.
+    pub last_successful_poll: Option<DateTime<Utc>>,
+    /// Egress actions that failed since the adapter's last successful egress.
+    pub pending_egress: usize,
+}
+
+/// Activity derived from adapter events, keyed by adapter name.
+#[derive(Debug, Default, Clone)]
+struct AdapterActivity {
+    last_successful_poll: Option<DateTime<Utc>>,
+    last_successful_egress: Option<DateTime<Utc>>,
+    failed_egress: Vec<DateTime<Utc>>,
+}
+
+fn summarize_activity(events: &[UnifiedEvent]) -> HashMap<String, AdapterActivity> {
+    let mut activity: HashMap<String, AdapterActivity> = HashMap::new();
+
+    for event in events {
+        match &event.payload {
+            EventPayload::AdapterIngestionCompleted { adapter_name, .. } => {
+                let entry = activity.entry(adapter_name.clone()).or_default();
+                entry.last_successful_poll = entry.last_successful_poll.max(Some(event.timestamp));
+            }
+            EventPayload::AdapterEgressCompleted {
+                adapter_name,
+                success: true,
+                ..
+            } => {
+                let entry = activity.entry(adapter_name.clone()).or_default();
+                entry.last_successful_egress =
+                    entry.last_successful_egress.max(Some(event.timestamp));
+            }
+            EventPayload::AdapterEgressCompleted {
+                adapter_name,
+                success: false,
+                ..
+            }
+            | EventPayload::AdapterEgressFailed { adapter_name, .. } => {
+                activity
+                    .entry(adapter_name.clone())
+                    .or_default()
+                    .failed_egress
+                    .push(event.timestamp);
+            }
+            _ => {}
+        }
+    }
+
+    activity
+}
+
+/// Probe every registered adapter and attach its recent activity from `events`.
+///
+/// Results are sorted by adapter name.
+pub async fn check_adapter_statuses(
+    registry: &AdapterRegistry,
+    events: &[UnifiedEvent],
+) -> Vec<AdapterStatus> {
+    let activity = summarize_activity(events);
+    let mut names = registry.adapter_names();
+    names.sort_unstable();
+
+    let mut statuses = Vec::with_capacity(names.len());
+    for name in names {
+        let error =
+            match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, registry.health_check(name)).await {
+                Ok(Ok(())) => None,
+                Ok(Err(e)) => Some(e.to_string()),
+                Err(_) => Some(format!(
+                    "health check timed out after {}s",
+                    HEALTH_CHECK_TIMEOUT.as_secs()
+                )),
+            };
+
+        let act = activity.get(name).cloned().unwrap_or_default();
+        let pending_egress = act
+            .failed_egress
+            .iter()
+            .filter(|t| act.last_successful_egress.is_none_or(|ok| **t > ok))
+            .count();
+
+        statuses.push(AdapterStatus {
+            name: name.to_string(),
+            healthy: error.is_none(),
+            error,
+            last_successful_poll: act.last_successful_poll,
+            pending_egress,
+        });
+    }
+
+    statuses
+}
+
+/// Registry holding one healthy prompt adapter (`"docs"`) and one ingestion
+/// adapter whose connectivity check always fails (`unhealthy`).
+#[cfg(test)]
+pub(crate) fn registry_with_unhealthy_adapter(unhealthy: &str) -> AdapterRegistry {
+    use async_trait::async_trait;
+
+    use crate::domain::errors::{DomainError, DomainResult};
+    use crate::domain::models::adapter::{
+        AdapterDirection, AdapterManifest, AdapterType, IngestionItem,
+    };
+    use crate::domain::ports::adapter::IngestionAdapter;
+    use crate::services::adapter_loader::LoadedAdapter;
+    use crate::services::prompt_adapter::PromptAdapter;
+
+    struct ExpiredCredentialsAdapter(AdapterManifest);
+
+    #[async_trait]
+    impl IngestionAdapter for ExpiredCredentialsAdapter {
+        fn manifest(&self) -> &AdapterManifest {
+            &self.0
+        }
+
+        async fn poll(
+            &self,
+            _last_poll: Option<DateTime<Utc>>,
+        ) -> DomainResult<Vec<IngestionItem>> {
+            Ok(vec![])
+        }
+
+        async fn health_check(&self) -> DomainResult<()> {
+            Err(DomainError::ExternalServiceError {
+                service: self.0.name.clone(),
+                reason: "401 Bad credentials".to_string(),
+            })
+        }
+    }
+
+    let broken = AdapterManifest::new(unhealthy, AdapterType::Native, AdapterDirection::Ingestion);
+    let docs = AdapterManifest::new("docs", AdapterType::Prompt, AdapterDirection::Egress);
+    AdapterRegistry::from_loaded(
+        vec![
+            LoadedAdapter {
+                manifest: broken.clone(),
+                ingestion: Some(Box::new(ExpiredCredentialsAdapter(broken))),
+                egress: None,
+                prompt_content: None,
+            },
+            LoadedAdapter {
+                manifest: docs.clone(),
+                ingestion: None,
+                egress: Some(Box::new(PromptAdapter::new(docs, String::new()))),
+                prompt_content: None,
+            },
+        ],
+        HashMap::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::event_bus::{EventCategory, EventSeverity};
+    use crate::services::event_factory::make_event;
+
+    fn adapter_event(payload: EventPayload, at: DateTime<Utc>) -> UnifiedEvent {
+        let mut event = make_event(
+            EventSeverity::Info,
+            EventCategory::Adapter,
+            None,
+            None,
+            payload,
+        );
+        event.timestamp = at;
+        event
+    }
+
+    #[tokio::test]
+    async fn test_statuses_report_health_and_activity() {
+        let registry = registry_with_unhealthy_adapter("tracker");
+        let t0 = Utc::now() - chrono::Duration::hours(2);
+        let events = vec![
+            adapter_event(
+                EventPayload::AdapterIngestionCompleted {
+                    adapter_name: "tracker".into(),
+                    items_found: 1,
+                    tasks_created: 1,
+                    duplicates_skipped: 0,
+                    rate_limited: 0,
+                    poll_duration_ms: 5,
+                },
+                t0,
+            ),
+            adapter_event(
+                EventPayload::AdapterEgressFailed {
+                    adapter_name: "docs".into(),
+                    task_id: None,
+                    error: "boom".into(),
+                },
+                t0,
+            ),
+            adapter_event(
+                EventPayload::AdapterEgressCompleted {
+                    adapter_name: "docs".into(),
+                    task_id: uuid::Uuid::new_v4(),
+                    action: "post_comment".into(),
+                    success: true,
+                },
+                t0 + chrono::Duration::minutes(1),
+            ),
+            adapter_event(
+                EventPayload::AdapterEgressFailed {
+                    adapter_name: "docs".into(),
+                    task_id: None,
+                    error: "boom".into(),
+                },
+                t0 + chrono::Duration::minutes(2),
+            ),
+        ];
+
+        let statuses = check_adapter_statuses(&registry, &events).await;
+        let names: Vec<&str> = statuses.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["docs", "tracker"]);
+
+        let docs = &statuses[0];
+        assert!(docs.healthy);
+        assert_eq!(
+            docs.pending_egress, 1,
+            "only failures after the last success count"
+        );
+
+        let tracker = &statuses[1];
+        assert!(!tracker.healthy);
+        assert!(
+            tracker
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("Bad credentials")
+        );
+        assert_eq!(tracker.last_successful_poll, Some(t0));
+    }
+}
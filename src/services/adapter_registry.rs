@@ -4,12 +4,66 @@
 //! provides lookup methods by name. It also stores prompt content for
 //! prompt-based adapters and can generate a consolidated prompt section
 //! for injection into agent system prompts.
+//!
+//! The registry also owns ingestion scheduling: each ingestion adapter is
+//! polled on its own interval, due adapters are polled concurrently, and
+//! task submissions from all adapters share one rate limit.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-use std::collections::HashMap;
+use futures::StreamExt;
 
-use crate::domain::models::adapter::AdapterManifest;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::models::adapter::{AdapterManifest, IngestionItem};
 use crate::domain::ports::adapter::{EgressAdapter, IngestionAdapter};
 use crate::services::adapter_loader::LoadedAdapter;
+use crate::services::config::AdapterConfig;
+
+/// Upper bound on a single adapter's ingestion poll, so a hung external
+/// system cannot hold up the other adapters polled in the same cycle.
+pub const INGESTION_POLL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Manifest `[config]` key that overrides the default poll interval.
+const POLL_INTERVAL_KEY: &str = "poll_interval_secs";
+
+/// Ingestion scheduling shared by every ingestion adapter in a registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngestionSettings {
+    /// Interval for adapters whose manifest sets no `poll_interval_secs`.
+    pub default_poll_interval: Duration,
+    /// Most adapters polled at the same time.
+    pub max_concurrent_polls: usize,
+    /// Most tasks submitted across all adapters in any 60-second window.
+    pub max_submissions_per_minute: Option<u32>,
+}
+
+impl Default for IngestionSettings {
+    fn default() -> Self {
+        Self::from_config(&AdapterConfig::default())
+    }
+}
+
+impl IngestionSettings {
+    /// Settings described by `[adapters]`.
+    pub fn from_config(config: &AdapterConfig) -> Self {
+        Self {
+            default_poll_interval: Duration::from_secs(config.default_poll_interval_secs),
+            max_concurrent_polls: config.max_concurrent_polls,
+            max_submissions_per_minute: config.max_submissions_per_minute,
+        }
+    }
+}
+
+/// Result of polling one ingestion adapter.
+#[derive(Debug)]
+pub struct IngestionPollOutcome {
+    pub adapter_name: String,
+    /// Items returned, or why the poll failed or timed out.
+    pub result: Result<Vec<IngestionItem>, String>,
+    pub duration: Duration,
+}
 
 /// Central registry of loaded adapters.
 ///
@@ -25,6 +79,12 @@ pub struct AdapterRegistry {
     egress: HashMap<String, Box<dyn EgressAdapter>>,
     /// Prompt content keyed by adapter name (for prompt adapters).
     prompts: HashMap<String, String>,
+    /// Ingestion scheduling and rate-limit settings.
+    ingestion_settings: IngestionSettings,
+    /// When each ingestion adapter was last polled.
+    last_polled: Mutex<HashMap<String, Instant>>,
+    /// Submission times within the last minute, oldest first.
+    submission_times: Mutex<VecDeque<Instant>>,
 }
 
 impl std::fmt::Debug for AdapterRegistry {
@@ -34,6 +94,7 @@ impl std::fmt::Debug for AdapterRegistry {
             .field("ingestion", &self.ingestion.keys().collect::<Vec<_>>())
             .field("egress", &self.egress.keys().collect::<Vec<_>>())
             .field("prompts", &self.prompts.keys().collect::<Vec<_>>())
+            .field("ingestion_settings", &self.ingestion_settings)
             .finish()
     }
 }
@@ -46,6 +107,9 @@ impl Default for AdapterRegistry {
             ingestion: HashMap::new(),
             egress: HashMap::new(),
             prompts: HashMap::new(),
+            ingestion_settings: IngestionSettings::default(),
+            last_polled: Mutex::new(HashMap::new()),
+            submission_times: Mutex::new(VecDeque::new()),
         }
     }
 }
@@ -78,9 +142,16 @@ impl AdapterRegistry {
             ingestion,
             egress,
             prompts,
+            ..Self::default()
         }
     }
 
+    /// Replace the ingestion scheduling settings.
+    pub fn with_ingestion_settings(mut self, settings: IngestionSettings) -> Self {
+        self.ingestion_settings = settings;
+        self
+    }
+
     /// Look up an ingestion adapter by name.
     pub fn get_ingestion(&self, name: &str) -> Option<&dyn IngestionAdapter> {
         self.ingestion.get(name).map(|a| a.as_ref())
@@ -116,6 +187,151 @@ impl AdapterRegistry {
         self.egress.keys().map(|s| s.as_str()).collect()
     }
 
+    /// Run the connectivity check of the named adapter's ingestion and egress
+    /// implementations. Adapters with neither (e.g. prompt-only) pass.
+    pub async fn health_check(&self, name: &str) -> DomainResult<()> {
+        if !self.manifests.contains_key(name) {
+            return Err(DomainError::ValidationFailed(format!(
+                "adapter '{}' is not registered",
+                name
+            )));
+        }
+        if let Some(ingestion) = self.get_ingestion(name) {
+            ingestion.health_check().await?;
+        }
+        if let Some(egress) = self.get_egress(name) {
+            egress.health_check().await?;
+        }
+        Ok(())
+    }
+
+    /// How often the named ingestion adapter is polled: its manifest's
+    /// `poll_interval_secs`, or the registry default.
+    pub fn poll_interval(&self, name: &str) -> Duration {
+        self.manifests
+            .get(name)
+            .and_then(|m| m.config.get(POLL_INTERVAL_KEY))
+            .and_then(|v| v.as_u64())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(self.ingestion_settings.default_poll_interval)
+    }
+
+    /// Shortest poll interval of any ingestion adapter, i.e. how often the
+    /// ingestion schedule must tick to honor every adapter's interval.
+    pub fn min_poll_interval(&self) -> Option<Duration> {
+        self.ingestion
+            .keys()
+            .map(|name| self.poll_interval(name))
+            .min()
+    }
+
+    /// Ingestion adapters never polled, or whose interval has elapsed by `now`.
+    pub fn due_ingestion_names(&self, now: Instant) -> Vec<String> {
+        let last_polled = self.last_polled.lock().unwrap();
+        let mut due: Vec<String> = self
+            .ingestion
+            .keys()
+            .filter(|name| match last_polled.get(name.as_str()) {
+                Some(at) => now.saturating_duration_since(*at) >= self.poll_interval(name),
+                None => true,
+            })
+            .cloned()
+            .collect();
+        due.sort();
+        due
+    }
+
+    /// Poll every due ingestion adapter concurrently.
+    ///
+    /// Each poll is bounded by [`INGESTION_POLL_TIMEOUT`]; a failing or hung
+    /// adapter yields an `Err` outcome without delaying the others. Adapters
+    /// are marked polled whether or not they succeed, so a failing adapter is
+    /// retried on its next interval rather than every tick. Outcomes are
+    /// sorted by adapter name.
+    pub async fn poll_due(&self) -> Vec<IngestionPollOutcome> {
+        let now = Instant::now();
+        let due = self.due_ingestion_names(now);
+        {
+            let mut last_polled = self.last_polled.lock().unwrap();
+            for name in &due {
+                last_polled.insert(name.clone(), now);
+            }
+        }
+
+        let mut outcomes: Vec<IngestionPollOutcome> = futures::stream::iter(due)
+            .map(|name| self.poll_one(name))
+            .buffer_unordered(self.ingestion_settings.max_concurrent_polls.max(1))
+            .collect()
+            .await;
+        outcomes.sort_by(|a, b| a.adapter_name.cmp(&b.adapter_name));
+        outcomes
+    }
+
+    async fn poll_one(&self, name: String) -> IngestionPollOutcome {
+        let started = Instant::now();
+        let result = match self.ingestion.get(&name) {
+            Some(adapter) => {
+                match tokio::time::timeout(INGESTION_POLL_TIMEOUT, adapter.poll(None)).await {
+                    Ok(Ok(items)) => Ok(items),
+                    Ok(Err(e)) => Err(e.to_string()),
+                    Err(_) => Err(format!(
+                        "poll timed out after {}s",
+                        INGESTION_POLL_TIMEOUT.as_secs()
+                    )),
+                }
+            }
+            None => Err(format!("adapter '{}' has no ingestion support", name)),
+        };
+        let duration = started.elapsed();
+
+        let outcome = if result.is_ok() {
+            "succeeded"
+        } else {
+            "failed"
+        };
+        metrics::counter!(
+            "abathur_adapter_polls_total",
+            "adapter" => name.clone(),
+            "outcome" => outcome
+        )
+        .increment(1);
+        metrics::histogram!("abathur_adapter_poll_duration_seconds", "adapter" => name.clone())
+            .record(duration.as_secs_f64());
+
+        IngestionPollOutcome {
+            adapter_name: name,
+            result,
+            duration,
+        }
+    }
+
+    /// Claim one slot of the shared ingestion submission rate limit.
+    ///
+    /// Returns `false` when the per-minute limit is exhausted; the caller
+    /// should leave the item for a later poll.
+    pub fn try_acquire_submission(&self) -> bool {
+        self.try_acquire_submission_at(Instant::now())
+    }
+
+    fn try_acquire_submission_at(&self, now: Instant) -> bool {
+        let Some(limit) = self.ingestion_settings.max_submissions_per_minute else {
+            return true;
+        };
+        let mut times = self.submission_times.lock().unwrap();
+        while times
+            .front()
+            .is_some_and(|t| now.saturating_duration_since(*t) >= Duration::from_secs(60))
+        {
+            times.pop_front();
+        }
+        if times.len() >= limit as usize {
+            return false;
+        }
+        times.push_back(now);
+        true
+    }
+
     /// Whether any adapters are registered.
     pub fn has_adapters(&self) -> bool {
         !self.manifests.is_empty()
@@ -337,6 +553,43 @@ mod tests {
         assert!(!section.contains("ingestion-only"));
     }
 
+    #[test]
+    fn test_poll_interval_override_and_shared_submission_limit() {
+        let fast = make_ingestion_manifest("fast").with_config("poll_interval_secs", 30.into());
+        let slow = make_ingestion_manifest("slow");
+        let registry = AdapterRegistry::from_loaded(
+            vec![
+                LoadedAdapter {
+                    manifest: fast,
+                    ingestion: None,
+                    egress: None,
+                    prompt_content: None,
+                },
+                LoadedAdapter {
+                    manifest: slow,
+                    ingestion: None,
+                    egress: None,
+                    prompt_content: None,
+                },
+            ],
+            HashMap::new(),
+        )
+        .with_ingestion_settings(IngestionSettings {
+            default_poll_interval: Duration::from_secs(300),
+            max_concurrent_polls: 4,
+            max_submissions_per_minute: Some(2),
+        });
+
+        assert_eq!(registry.poll_interval("fast"), Duration::from_secs(30));
+        assert_eq!(registry.poll_interval("slow"), Duration::from_secs(300));
+
+        let t0 = Instant::now();
+        assert!(registry.try_acquire_submission_at(t0));
+        assert!(registry.try_acquire_submission_at(t0));
+        assert!(!registry.try_acquire_submission_at(t0 + Duration::from_secs(59)));
+        assert!(registry.try_acquire_submission_at(t0 + Duration::from_secs(60)));
+    }
+
     #[test]
     fn test_debug_impl() {
         let registry = AdapterRegistry::default();
@@ -9,6 +9,7 @@ use crate::domain::models::{
     ToolCapability, specialist_templates, workflow_template::WorkflowTemplate,
 };
 use crate::domain::ports::{AgentFilter, AgentRepository};
+use crate::services::config::TaskRoutingConfig;
 use crate::services::event_bus::{
     EventBus, EventCategory, EventId, EventPayload, EventSeverity, SequenceNumber, UnifiedEvent,
 };
@@ -673,6 +674,32 @@ impl<R: AgentRepository> AgentService<R> {
         Ok(seeded)
     }
 
+    /// Agent types in `routing.fallbacks` for which neither the type itself
+    /// nor any fallback in its chain has a template. Tasks requesting them run
+    /// with a generic prompt instead of degrading to a configured agent.
+    pub async fn unresolvable_fallbacks(
+        &self,
+        routing: &TaskRoutingConfig,
+    ) -> DomainResult<Vec<String>> {
+        let mut unresolvable = Vec::new();
+        'types: for agent_type in routing.fallbacks.keys() {
+            let chain = routing.fallback_chain(agent_type);
+            for candidate in std::iter::once(agent_type.as_str()).chain(chain) {
+                if self
+                    .repository
+                    .get_template_by_name(candidate)
+                    .await?
+                    .is_some()
+                {
+                    continue 'types;
+                }
+            }
+            unresolvable.push(agent_type.clone());
+        }
+        unresolvable.sort();
+        Ok(unresolvable)
+    }
+
     /// Ensure a specific specialist template exists, creating if needed.
     ///
     /// With the overmind-only model, this will only find specialists that
@@ -876,6 +903,23 @@ mod tests {
         assert_eq!(template.version, 99);
     }
 
+    #[tokio::test]
+    async fn test_unresolvable_fallbacks_after_seeding() {
+        let (service, pool) = setup_service().await;
+        service.seed_baseline_agents().await.unwrap();
+
+        let routing = TaskRoutingConfig {
+            fallbacks: std::collections::HashMap::from([
+                ("rust-expert".to_string(), "backend-dev".to_string()),
+                ("backend-dev".to_string(), "overmind".to_string()),
+                ("designer".to_string(), "illustrator".to_string()),
+            ]),
+            ..Default::default()
+        };
+        let unresolvable = service.unresolvable_fallbacks(&routing).await.unwrap();
+        assert_eq!(unresolvable, vec!["designer".to_string()]);
+    }
+
     #[tokio::test]
     async fn test_disable_enable() {
         let (service, pool) = setup_service().await;
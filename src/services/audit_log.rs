@@ -2,8 +2,12 @@
 //!
 //! Records all state changes and autonomous decisions with full rationale.
 //! Supports structured querying for post-hoc analysis and debugging.
+//!
+//! Entries are kept in an in-memory ring buffer. With a SQLite pool attached
+//! (and `persist_to_db` set) they are also written to `audit_entries`, and
+//! queries read from there so history outlives the buffer and the process.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::Arc;
@@ -15,7 +19,7 @@ use uuid::Uuid;
 pub struct AuditLogConfig {
     /// Maximum entries to keep in memory.
     pub max_entries: usize,
-    /// Whether to persist entries to database.
+    /// Whether to persist entries to database (requires a pool via `with_pool`).
     pub persist_to_db: bool,
     /// Log level threshold.
     pub min_level: AuditLevel,
@@ -115,6 +119,20 @@ impl AuditCategory {
             Self::Config => "config",
         }
     }
+
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "task" => Some(Self::Task),
+            "goal" => Some(Self::Goal),
+            "agent" => Some(Self::Agent),
+            "memory" => Some(Self::Memory),
+            "execution" => Some(Self::Execution),
+            "system" => Some(Self::System),
+            "security" => Some(Self::Security),
+            "config" => Some(Self::Config),
+            _ => None,
+        }
+    }
 }
 
 /// Type of state change or action.
@@ -239,6 +257,18 @@ pub enum AuditActor {
     External { source: String },
 }
 
+impl std::fmt::Display for AuditActor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::System => write!(f, "system"),
+            Self::Agent { name, .. } => write!(f, "agent:{}", name),
+            Self::User { identifier } => write!(f, "user:{}", identifier),
+            Self::Daemon { name } => write!(f, "daemon:{}", name),
+            Self::External { source } => write!(f, "external:{}", source),
+        }
+    }
+}
+
 /// Decision rationale for autonomous decisions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DecisionRationale {
@@ -378,6 +408,8 @@ pub struct AuditFilter {
     pub category: Option<AuditCategory>,
     /// Filter by action.
     pub action: Option<AuditAction>,
+    /// Filter by actor.
+    pub actor: Option<AuditActor>,
     /// Filter by entity ID.
     pub entity_id: Option<Uuid>,
     /// Filter by time range start.
@@ -408,6 +440,11 @@ impl AuditFilter {
         self
     }
 
+    pub fn with_actor(mut self, actor: AuditActor) -> Self {
+        self.actor = Some(actor);
+        self
+    }
+
     pub fn with_entity(mut self, id: Uuid) -> Self {
         self.entity_id = Some(id);
         self
@@ -444,6 +481,12 @@ impl AuditFilter {
             return false;
         }
 
+        if let Some(ref actor) = self.actor
+            && &entry.actor != actor
+        {
+            return false;
+        }
+
         if let Some(entity_id) = self.entity_id
             && entry.entity_id != Some(entity_id)
         {
@@ -477,10 +520,12 @@ pub struct AuditStats {
     pub decisions_logged: usize,
 }
 
-/// In-memory audit log service.
+/// Audit log service with an in-memory ring buffer and optional SQLite persistence.
 pub struct AuditLogService {
     config: AuditLogConfig,
     entries: Arc<RwLock<VecDeque<AuditEntry>>>,
+    /// Optional SQLite pool for entry persistence.
+    pool: Option<sqlx::SqlitePool>,
 }
 
 impl AuditLogService {
@@ -489,6 +534,7 @@ impl AuditLogService {
         Self {
             config,
             entries: Arc::new(RwLock::new(VecDeque::new())),
+            pool: None,
         }
     }
 
@@ -497,6 +543,79 @@ impl AuditLogService {
         Self::new(AuditLogConfig::default())
     }
 
+    /// Attach a SQLite pool for entry persistence.
+    pub fn with_pool(mut self, pool: sqlx::SqlitePool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// The configuration this service was built with.
+    pub fn config(&self) -> &AuditLogConfig {
+        &self.config
+    }
+
+    /// The pool entries are persisted to, if persistence is enabled.
+    fn persistent_pool(&self) -> Option<&sqlx::SqlitePool> {
+        self.pool.as_ref().filter(|_| self.config.persist_to_db)
+    }
+
+    /// Persist an entry to the database.
+    async fn persist_entry(pool: &sqlx::SqlitePool, entry: &AuditEntry) {
+        let json = match serde_json::to_string(entry) {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::warn!("Failed to serialize audit entry {}: {}", entry.id, e);
+                return;
+            }
+        };
+
+        if let Err(e) = sqlx::query(
+            "INSERT OR IGNORE INTO audit_entries (id, timestamp, level, category, entry)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )
+        .bind(entry.id.to_string())
+        .bind(db_timestamp(entry.timestamp))
+        .bind(entry.level.as_str())
+        .bind(entry.category.as_str())
+        .bind(&json)
+        .execute(pool)
+        .await
+        {
+            tracing::warn!("Failed to persist audit entry {}: {}", entry.id, e);
+        }
+    }
+
+    /// Query persisted entries, newest first.
+    ///
+    /// Category and time range are applied in SQL; the remaining filters are
+    /// applied to the decoded entries.
+    async fn query_db(
+        pool: &sqlx::SqlitePool,
+        filter: &AuditFilter,
+    ) -> Result<Vec<AuditEntry>, sqlx::Error> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT entry FROM audit_entries
+             WHERE (?1 IS NULL OR category = ?1)
+               AND (?2 IS NULL OR timestamp >= ?2)
+               AND (?3 IS NULL OR timestamp <= ?3)
+             ORDER BY timestamp DESC, rowid DESC",
+        )
+        .bind(filter.category.as_ref().map(|c| c.as_str()))
+        .bind(filter.from.map(db_timestamp))
+        .bind(filter.to.map(db_timestamp))
+        .fetch_all(pool)
+        .await?;
+
+        let matching = rows
+            .iter()
+            .filter_map(|(json,)| serde_json::from_str::<AuditEntry>(json).ok())
+            .filter(|e| filter.matches(e));
+        Ok(match filter.limit {
+            Some(limit) => matching.take(limit).collect(),
+            None => matching.collect(),
+        })
+    }
+
     /// Log an audit entry.
     pub async fn log(&self, entry: AuditEntry) {
         // Check level threshold
@@ -504,6 +623,10 @@ impl AuditLogService {
             return;
         }
 
+        if let Some(pool) = self.persistent_pool() {
+            Self::persist_entry(pool, &entry).await;
+        }
+
         let mut entries = self.entries.write().await;
 
         // Enforce max entries
@@ -590,8 +713,20 @@ impl AuditLogService {
         .await;
     }
 
-    /// Query audit entries.
+    /// Query audit entries, newest first.
+    ///
+    /// Reads persisted entries when persistence is enabled, falling back to
+    /// the in-memory buffer if the database query fails.
     pub async fn query(&self, filter: AuditFilter) -> Vec<AuditEntry> {
+        if let Some(pool) = self.persistent_pool() {
+            match Self::query_db(pool, &filter).await {
+                Ok(results) => return results,
+                Err(e) => {
+                    tracing::warn!("Failed to query persisted audit log: {}", e);
+                }
+            }
+        }
+
         let entries = self.entries.read().await;
         let mut results: Vec<AuditEntry> = entries
             .iter()
@@ -657,7 +792,7 @@ impl AuditLogService {
         }
     }
 
-    /// Clear all entries.
+    /// Clear all in-memory entries. Persisted entries are kept.
     pub async fn clear(&self) {
         let mut entries = self.entries.write().await;
         entries.clear();
@@ -670,6 +805,11 @@ impl AuditLogService {
     }
 }
 
+/// Timestamp format for `audit_entries`; fixed-width so text comparison orders correctly.
+fn db_timestamp(dt: DateTime<Utc>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
 /// Helper to create system actor.
 pub fn system_actor() -> AuditActor {
     AuditActor::System
@@ -781,6 +921,48 @@ mod tests {
         assert!(!filter.matches(&entry));
     }
 
+    #[test]
+    fn test_filters_compose() {
+        let agent = agent_actor(Uuid::new_v4(), "implementer");
+        let now = Utc::now();
+        let mut entry = AuditEntry::new(
+            AuditLevel::Warning,
+            AuditCategory::Agent,
+            AuditAction::AgentFailed,
+            agent.clone(),
+            "Agent failed",
+        );
+        entry.timestamp = now - chrono::Duration::minutes(30);
+
+        let filter = AuditFilter::new()
+            .with_category(AuditCategory::Agent)
+            .with_actor(agent.clone())
+            .with_min_level(AuditLevel::Warning)
+            .with_time_range(now - chrono::Duration::hours(1), now);
+        assert!(filter.matches(&entry));
+
+        // Each criterion on its own can exclude the entry.
+        assert!(
+            !filter
+                .clone()
+                .with_category(AuditCategory::Task)
+                .matches(&entry)
+        );
+        assert!(!filter.clone().with_actor(system_actor()).matches(&entry));
+        assert!(
+            !filter
+                .clone()
+                .with_min_level(AuditLevel::Error)
+                .matches(&entry)
+        );
+        assert!(
+            !filter
+                .clone()
+                .with_time_range(now - chrono::Duration::minutes(10), now)
+                .matches(&entry)
+        );
+    }
+
     #[tokio::test]
     async fn test_audit_log_service() {
         let service = AuditLogService::with_defaults();
@@ -858,6 +1040,53 @@ mod tests {
         assert_eq!(stats.total_entries, 5);
     }
 
+    #[tokio::test]
+    async fn test_persisted_entries_outlive_buffer() {
+        let pool = crate::adapters::sqlite::create_migrated_test_pool()
+            .await
+            .unwrap();
+        let config = AuditLogConfig {
+            max_entries: 2,
+            ..Default::default()
+        };
+        let service = AuditLogService::new(config).with_pool(pool.clone());
+
+        for i in 0..4 {
+            service
+                .info(
+                    AuditCategory::Agent,
+                    AuditAction::AgentSpawned,
+                    format!("Agent {}", i),
+                )
+                .await;
+        }
+        service
+            .info(
+                AuditCategory::Task,
+                AuditAction::TaskCreated,
+                "Task created",
+            )
+            .await;
+
+        // A separate reader (e.g. the CLI) sees every persisted entry.
+        let reader = AuditLogService::with_defaults().with_pool(pool);
+        let agents = reader
+            .query(
+                AuditFilter::new()
+                    .with_category(AuditCategory::Agent)
+                    .with_time_range(Utc::now() - chrono::Duration::hours(1), Utc::now()),
+            )
+            .await;
+        assert_eq!(agents.len(), 4);
+        assert_eq!(agents[0].message, "Agent 3");
+
+        let limited = reader
+            .query(AuditFilter::new().with_actor(system_actor()).with_limit(2))
+            .await;
+        assert_eq!(limited.len(), 2);
+        assert_eq!(limited[0].message, "Task created");
+    }
+
     #[test]
     fn test_actor_helpers() {
         let sys = system_actor();
@@ -12,14 +12,18 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
 use super::config::BudgetConfig;
-use super::event_bus::{BudgetPressureLevel, EventBus, EventCategory, EventPayload, EventSeverity};
+use super::event_bus::{
+    BudgetPressureLevel, EventBus, EventCategory, EventPayload, EventSeverity,
+    HumanEscalationPayload,
+};
 use super::event_factory;
-use crate::domain::models::TaskPriority;
+use crate::domain::models::{Goal, TaskPriority};
 
 // ============================================================================
 // Supporting types
@@ -83,6 +87,17 @@ pub struct BudgetState {
 // Configuration
 // ============================================================================
 
+/// What the swarm does once total token spend reaches the configured ceiling.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetPolicy {
+    /// Stop spawning new agents; running agents finish their work.
+    #[default]
+    Throttle,
+    /// Stop spawning, cancel every running task, and pause the orchestrator.
+    HardStop,
+}
+
 /// Runtime configuration for [`BudgetTracker`].
 ///
 /// Thresholds are fractions in `[0.0, 1.0]` of total quota consumed.
@@ -106,6 +121,14 @@ pub struct BudgetTrackerConfig {
     pub max_agents_warning: u32,
     /// Maximum concurrent agents allowed under `Critical` pressure.
     pub max_agents_critical: u32,
+    /// Token budget for goals without an override; `None` means uncapped.
+    pub default_goal_token_budget: Option<u64>,
+    /// Per-goal token budgets keyed by goal ID or goal name.
+    pub goal_token_budgets: HashMap<String, u64>,
+    /// Swarm-wide token ceiling; `None` means uncapped.
+    pub token_ceiling: Option<u64>,
+    /// Behaviour once the ceiling is reached.
+    pub policy: BudgetPolicy,
 }
 
 impl Default for BudgetTrackerConfig {
@@ -120,6 +143,10 @@ impl Default for BudgetTrackerConfig {
             max_agents_caution: 4,
             max_agents_warning: 2,
             max_agents_critical: 1,
+            default_goal_token_budget: None,
+            goal_token_budgets: HashMap::new(),
+            token_ceiling: None,
+            policy: BudgetPolicy::Throttle,
         }
     }
 }
@@ -137,6 +164,10 @@ impl BudgetTrackerConfig {
             max_agents_caution: cfg.max_agents_caution,
             max_agents_warning: cfg.max_agents_warning,
             max_agents_critical: cfg.max_agents_critical,
+            default_goal_token_budget: cfg.default_goal_token_budget,
+            goal_token_budgets: cfg.goal_token_budgets.clone(),
+            token_ceiling: cfg.token_ceiling,
+            policy: cfg.policy,
         }
     }
 }
@@ -150,6 +181,10 @@ struct Inner {
     pressure_level: BudgetPressureLevel,
     total_tokens_recorded: u64,
     last_opportunity: Option<BudgetOpportunity>,
+    /// Tokens attributed to each goal via [`BudgetTracker::record_goal_tokens`].
+    goal_tokens: HashMap<Uuid, u64>,
+    /// Goals whose attributed spend has reached their budget.
+    exceeded_goals: HashSet<Uuid>,
 }
 
 // ============================================================================
@@ -184,6 +219,8 @@ impl BudgetTracker {
                 pressure_level: BudgetPressureLevel::Normal,
                 total_tokens_recorded: 0,
                 last_opportunity: None,
+                goal_tokens: HashMap::new(),
+                exceeded_goals: HashSet::new(),
             })),
         }
     }
@@ -285,6 +322,72 @@ impl BudgetTracker {
         inner.total_tokens_recorded += tokens;
     }
 
+    /// Charge `tokens` to `goal`'s running spend.
+    ///
+    /// The first time the spend reaches the goal's budget, the goal is marked
+    /// exceeded and [`EventPayload::GoalBudgetExceeded`] plus a human
+    /// escalation are emitted. The goal stays exceeded for the lifetime of the
+    /// tracker.
+    pub async fn record_goal_tokens(&self, goal: &Goal, tokens: u64) {
+        let budget = self.goal_token_budget(goal);
+        let tokens_used = {
+            let mut inner = self.inner.write().await;
+            let used = inner.goal_tokens.entry(goal.id).or_insert(0);
+            *used += tokens;
+            let used = *used;
+            match budget {
+                Some(b) if used >= b && inner.exceeded_goals.insert(goal.id) => used,
+                _ => return,
+            }
+        };
+        let budget = budget.unwrap_or_default();
+
+        tracing::warn!(
+            goal_id = %goal.id,
+            goal = %goal.name,
+            tokens_used,
+            budget,
+            "goal token budget exceeded; pausing spawns for its tasks"
+        );
+
+        self.event_bus
+            .publish(event_factory::make_event(
+                EventSeverity::Warning,
+                EventCategory::Budget,
+                Some(goal.id),
+                None,
+                EventPayload::GoalBudgetExceeded {
+                    goal_id: goal.id,
+                    goal_name: goal.name.clone(),
+                    tokens_used,
+                    budget,
+                },
+            ))
+            .await;
+        self.event_bus
+            .publish(event_factory::make_event(
+                EventSeverity::Warning,
+                EventCategory::Escalation,
+                Some(goal.id),
+                None,
+                EventPayload::HumanEscalationRequired(HumanEscalationPayload {
+                    goal_id: Some(goal.id),
+                    task_id: None,
+                    reason: format!(
+                        "Goal '{}' used {} tokens against a budget of {}",
+                        goal.name, tokens_used, budget
+                    ),
+                    urgency: "high".to_string(),
+                    questions: vec![format!(
+                        "Raise the token budget for goal '{}' or retire it?",
+                        goal.name
+                    )],
+                    is_blocking: false,
+                }),
+            ))
+            .await;
+    }
+
     // -------------------------------------------------------------------------
     // Queries
     // -------------------------------------------------------------------------
@@ -300,6 +403,35 @@ impl BudgetTracker {
         }
     }
 
+    /// Token budget that applies to `goal`: an override keyed by its ID, then
+    /// one keyed by its name, then the default.
+    pub fn goal_token_budget(&self, goal: &Goal) -> Option<u64> {
+        self.config
+            .goal_token_budgets
+            .get(&goal.id.to_string())
+            .or_else(|| self.config.goal_token_budgets.get(&goal.name))
+            .copied()
+            .or(self.config.default_goal_token_budget)
+    }
+
+    /// Tokens attributed to `goal_id` so far.
+    pub async fn goal_tokens_used(&self, goal_id: Uuid) -> u64 {
+        let inner = self.inner.read().await;
+        inner.goal_tokens.get(&goal_id).copied().unwrap_or(0)
+    }
+
+    /// Return `true` if `goal_id` has spent its token budget.
+    pub async fn is_goal_over_budget(&self, goal_id: Uuid) -> bool {
+        let inner = self.inner.read().await;
+        inner.exceeded_goals.contains(&goal_id)
+    }
+
+    /// Return `true` if any goal has spent its token budget.
+    pub async fn any_goal_over_budget(&self) -> bool {
+        let inner = self.inner.read().await;
+        !inner.exceeded_goals.is_empty()
+    }
+
     /// Return the effective maximum concurrent agents given a caller-supplied
     /// base maximum, capped by the pressure-level constraint.
     ///
@@ -341,6 +473,33 @@ impl BudgetTracker {
         }
     }
 
+    /// The configured ceiling policy.
+    pub fn policy(&self) -> BudgetPolicy {
+        self.config.policy
+    }
+
+    /// The configured swarm-wide token ceiling, if any.
+    pub fn token_ceiling(&self) -> Option<u64> {
+        self.config.token_ceiling
+    }
+
+    /// Return `true` if `total_tokens` has reached the token ceiling.
+    ///
+    /// Callers pass the orchestrator's running token counter, which is the
+    /// source of truth for swarm-wide spend; the tracker's own tally only
+    /// sees completions routed through the event bus.
+    pub fn is_ceiling_reached(&self, total_tokens: u64) -> bool {
+        self.config
+            .token_ceiling
+            .is_some_and(|ceiling| total_tokens >= ceiling)
+    }
+
+    /// Return `true` if the ceiling is reached under [`BudgetPolicy::HardStop`],
+    /// i.e. running tasks should be canceled and the orchestrator paused.
+    pub fn should_hard_stop(&self, total_tokens: u64) -> bool {
+        self.config.policy == BudgetPolicy::HardStop && self.is_ceiling_reached(total_tokens)
+    }
+
     /// Return `true` if all new non-critical work should be paused.
     ///
     /// Currently triggers only at `Critical` pressure.
@@ -526,6 +685,30 @@ mod tests {
         assert!(tracker.should_dispatch_task(TaskPriority::Low).await);
     }
 
+    #[test]
+    fn test_hard_stop_only_past_ceiling_under_hard_stop_policy() {
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let config = BudgetTrackerConfig {
+            token_ceiling: Some(1_000),
+            policy: BudgetPolicy::HardStop,
+            ..Default::default()
+        };
+        let hard = BudgetTracker::new(config.clone(), bus.clone());
+        assert!(!hard.should_hard_stop(999));
+        assert!(hard.should_hard_stop(1_000));
+
+        let throttle = BudgetTracker::new(
+            BudgetTrackerConfig {
+                policy: BudgetPolicy::Throttle,
+                ..config
+            },
+            bus,
+        );
+        assert!(throttle.is_ceiling_reached(1_500));
+        assert!(!throttle.should_hard_stop(1_500));
+        assert!(!make_tracker().is_ceiling_reached(u64::MAX));
+    }
+
     #[tokio::test]
     async fn test_should_dispatch_task_critical_pressure() {
         let tracker = make_tracker();
@@ -573,6 +756,53 @@ mod tests {
         assert_eq!(state.total_tokens_recorded, 1500);
     }
 
+    #[tokio::test]
+    async fn test_goal_budget_prefers_override_by_id_then_name() {
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let by_id = Goal::new("by-id", "d");
+        let by_name = Goal::new("by-name", "d");
+        let other = Goal::new("other", "d");
+        let config = BudgetTrackerConfig {
+            default_goal_token_budget: Some(1_000),
+            goal_token_budgets: HashMap::from([
+                (by_id.id.to_string(), 10),
+                ("by-name".to_string(), 20),
+            ]),
+            ..Default::default()
+        };
+        let tracker = BudgetTracker::new(config, bus.clone());
+        let mut rx = bus.subscribe();
+
+        assert_eq!(tracker.goal_token_budget(&by_id), Some(10));
+        assert_eq!(tracker.goal_token_budget(&by_name), Some(20));
+        assert_eq!(tracker.goal_token_budget(&other), Some(1_000));
+
+        tracker.record_goal_tokens(&by_id, 6).await;
+        assert!(!tracker.is_goal_over_budget(by_id.id).await);
+        tracker.record_goal_tokens(&by_id, 6).await;
+        tracker.record_goal_tokens(&by_id, 6).await;
+        assert!(tracker.is_goal_over_budget(by_id.id).await);
+        assert!(!tracker.is_goal_over_budget(other.id).await);
+        assert_eq!(tracker.goal_tokens_used(by_id.id).await, 18);
+
+        // Exactly one GoalBudgetExceeded + escalation pair, on the crossing.
+        let first = rx.recv().await.unwrap();
+        assert!(matches!(
+            first.payload,
+            EventPayload::GoalBudgetExceeded {
+                tokens_used: 12,
+                budget: 10,
+                ..
+            }
+        ));
+        let second = rx.recv().await.unwrap();
+        assert!(matches!(
+            second.payload,
+            EventPayload::HumanEscalationRequired(ref p) if p.goal_id == Some(by_id.id)
+        ));
+        assert!(rx.try_recv().is_err());
+    }
+
     /// Regression test for the aggregate pressure bug:
     /// A low-consumption window reporting in must never drop the aggregate
     /// pressure that was established by a high-consumption window.
@@ -86,6 +86,7 @@ impl EventHandler for A2APollHandler {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -194,6 +195,10 @@ impl EventHandler for A2APollHandler {
                     deadline: None,
                     task_type: None,
                     execution_mode: None,
+                    estimate_secs: None,
+                    execution_params: None,
+                    required_overseers: Vec::new(),
+                    workspace_mode: None,
                 }),
             );
 
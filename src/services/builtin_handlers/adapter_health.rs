@@ -0,0 +1,225 @@
+//! Built-in reactive event handler.
+//!
+//! All handlers are **idempotent** — safe to run even if the poll loop already
+//! handled the same state change. They check current state before acting.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+
+use crate::services::adapter_health::check_adapter_statuses;
+use crate::services::adapter_registry::AdapterRegistry;
+use crate::services::event_bus::{
+    EventCategory, EventId, EventPayload, EventSeverity, HumanEscalationPayload, SequenceNumber,
+    UnifiedEvent,
+};
+use crate::services::event_reactor::{
+    ErrorStrategy, EventFilter, EventHandler, HandlerContext, HandlerId, HandlerMetadata,
+    HandlerPriority, Reaction,
+};
+
+// ============================================================================
+// AdapterHealthHandler (Adapter integration)
+// ============================================================================
+
+/// Periodically probes every registered adapter's connectivity and emits a
+/// `HumanEscalationRequired` once an adapter has stayed unhealthy for longer
+/// than the configured threshold (e.g. expired credentials).
+///
+/// Triggered by `ScheduledEventFired { name: "adapter-health-check" }`. Each
+/// unhealthy streak is escalated once; recovery resets the streak.
+pub struct AdapterHealthHandler {
+    adapter_registry: Arc<AdapterRegistry>,
+    /// How long an adapter may stay unhealthy before escalating.
+    escalation_threshold: chrono::Duration,
+    /// In-memory streak tracking: adapter name → first failed check.
+    unhealthy_since: RwLock<HashMap<String, DateTime<Utc>>>,
+    /// Adapters already escalated in their current unhealthy streak.
+    escalated: RwLock<HashSet<String>>,
+}
+
+impl AdapterHealthHandler {
+    pub fn new(adapter_registry: Arc<AdapterRegistry>, unhealthy_escalation_secs: u64) -> Self {
+        Self {
+            adapter_registry,
+            escalation_threshold: chrono::Duration::seconds(unhealthy_escalation_secs as i64),
+            unhealthy_since: RwLock::new(HashMap::new()),
+            escalated: RwLock::new(HashSet::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for AdapterHealthHandler {
+    fn metadata(&self) -> HandlerMetadata {
+        HandlerMetadata {
+            id: HandlerId::new(),
+            name: "AdapterHealthHandler".to_string(),
+            filter: EventFilter {
+                categories: vec![EventCategory::Scheduler],
+                payload_types: vec!["ScheduledEventFired".to_string()],
+                custom_predicate: Some(Arc::new(|event| {
+                    matches!(
+                        &event.payload,
+                        EventPayload::ScheduledEventFired { name, .. } if name == "adapter-health-check"
+                    )
+                })),
+                ..Default::default()
+            },
+            priority: HandlerPriority::LOW,
+            error_strategy: ErrorStrategy::LogAndContinue,
+            critical: false,
+            singleton: false,
+        }
+    }
+
+    async fn handle(
+        &self,
+        event: &UnifiedEvent,
+        _ctx: &HandlerContext,
+    ) -> Result<Reaction, String> {
+        match &event.payload {
+            EventPayload::ScheduledEventFired { name, .. } if name == "adapter-health-check" => {}
+            _ => return Ok(Reaction::None),
+        }
+
+        let statuses = check_adapter_statuses(&self.adapter_registry, &[]).await;
+        let now = Utc::now();
+        let mut unhealthy_since = self.unhealthy_since.write().await;
+        let mut escalated = self.escalated.write().await;
+        let mut new_events = Vec::new();
+
+        for status in statuses {
+            if status.healthy {
+                if unhealthy_since.remove(&status.name).is_some() {
+                    tracing::info!(adapter = %status.name, "AdapterHealth: adapter recovered");
+                }
+                escalated.remove(&status.name);
+                continue;
+            }
+
+            let error = status.error.unwrap_or_default();
+            tracing::warn!(
+                adapter = %status.name,
+                error = %error,
+                "AdapterHealth: adapter connectivity check failed"
+            );
+
+            let since = *unhealthy_since.entry(status.name.clone()).or_insert(now);
+            let unhealthy_for = now - since;
+            if unhealthy_for < self.escalation_threshold || !escalated.insert(status.name.clone()) {
+                continue;
+            }
+
+            new_events.push(UnifiedEvent {
+                id: EventId::new(),
+                sequence: SequenceNumber(0),
+                timestamp: now,
+                severity: EventSeverity::Warning,
+                category: EventCategory::Escalation,
+                goal_id: None,
+                task_id: None,
+                correlation_id: event.correlation_id,
+                source_process_id: None,
+                payload: EventPayload::HumanEscalationRequired(HumanEscalationPayload {
+                    goal_id: None,
+                    task_id: None,
+                    reason: format!(
+                        "Adapter '{}' has been unhealthy for {} minutes: {}",
+                        status.name,
+                        unhealthy_for.num_minutes(),
+                        error
+                    ),
+                    urgency: "high".to_string(),
+                    questions: vec![format!(
+                        "Are the credentials and configuration for adapter '{}' still valid?",
+                        status.name
+                    )],
+                    is_blocking: false,
+                }),
+            });
+        }
+
+        if new_events.is_empty() {
+            Ok(Reaction::None)
+        } else {
+            Ok(Reaction::EmitEvents(new_events))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::adapter_health::registry_with_unhealthy_adapter;
+
+    fn make_health_check_event() -> UnifiedEvent {
+        UnifiedEvent {
+            id: EventId::new(),
+            sequence: SequenceNumber(0),
+            timestamp: Utc::now(),
+            severity: EventSeverity::Debug,
+            category: EventCategory::Scheduler,
+            goal_id: None,
+            task_id: None,
+            correlation_id: None,
+            source_process_id: None,
+            payload: EventPayload::ScheduledEventFired {
+                schedule_id: uuid::Uuid::new_v4(),
+                name: "adapter-health-check".to_string(),
+            },
+        }
+    }
+
+    fn escalation_reasons(reaction: Reaction) -> Vec<String> {
+        match reaction {
+            Reaction::EmitEvents(events) => events
+                .into_iter()
+                .map(|e| match e.payload {
+                    EventPayload::HumanEscalationRequired(p) => p.reason,
+                    other => panic!("unexpected payload {:?}", other),
+                })
+                .collect(),
+            Reaction::None => vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_escalates_unhealthy_adapter_once_past_threshold() {
+        let registry = Arc::new(registry_with_unhealthy_adapter("tracker"));
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        // Below the threshold: the streak starts but nothing is escalated.
+        let patient = AdapterHealthHandler::new(registry.clone(), 3600);
+        let reaction = patient
+            .handle(&make_health_check_event(), &ctx)
+            .await
+            .unwrap();
+        assert!(escalation_reasons(reaction).is_empty());
+
+        let handler = AdapterHealthHandler::new(registry, 0);
+        let reasons = escalation_reasons(
+            handler
+                .handle(&make_health_check_event(), &ctx)
+                .await
+                .unwrap(),
+        );
+        assert_eq!(reasons.len(), 1, "only the broken adapter escalates");
+        assert!(reasons[0].contains("tracker"));
+        assert!(reasons[0].contains("Bad credentials"));
+
+        let reasons = escalation_reasons(
+            handler
+                .handle(&make_health_check_event(), &ctx)
+                .await
+                .unwrap(),
+        );
+        assert!(reasons.is_empty(), "a streak is escalated only once");
+    }
+}
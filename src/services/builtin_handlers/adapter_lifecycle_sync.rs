@@ -443,6 +443,7 @@ impl<T: TaskRepository + 'static> EventHandler for AdapterLifecycleSyncHandler<T
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -44,8 +44,8 @@ use super::{try_update_task, update_with_retry};
 // AgentTerminationHandler
 // ============================================================================
 
-/// When a task fails, terminate the underlying agent subprocess and free
-/// the guardrail agent slot.
+/// When a task fails, is canceled, or is force-restarted, terminate the
+/// underlying agent subprocess and free the guardrail agent slot.
 ///
 /// Without this, a timed-out task gets retried back to Ready while the
 /// original agent process is still running and holding a concurrency slot,
@@ -75,10 +75,15 @@ impl EventHandler for AgentTerminationHandler {
             name: "AgentTerminationHandler".to_string(),
             filter: EventFilter::new()
                 .categories(vec![EventCategory::Task])
-                .payload_types(vec!["TaskFailed".to_string()]),
+                .payload_types(vec![
+                    "TaskFailed".to_string(),
+                    "TaskCanceled".to_string(),
+                    "TaskRestarted".to_string(),
+                ]),
             priority: HandlerPriority::SYSTEM,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: true,
+            singleton: false,
         }
     }
 
@@ -88,7 +93,9 @@ impl EventHandler for AgentTerminationHandler {
         _ctx: &HandlerContext,
     ) -> Result<Reaction, String> {
         let task_id = match &event.payload {
-            EventPayload::TaskFailed { task_id, .. } => *task_id,
+            EventPayload::TaskFailed { task_id, .. }
+            | EventPayload::TaskCanceled { task_id, .. }
+            | EventPayload::TaskRestarted { task_id, .. } => *task_id,
             _ => return Ok(Reaction::None),
         };
 
@@ -112,3 +119,83 @@ impl EventHandler for AgentTerminationHandler {
         Ok(Reaction::None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::test_support::{make_task_service, setup_task_repo};
+    use crate::adapters::substrates::mock::{MockResponse, MockSubstrate};
+    use crate::domain::models::{SessionStatus, SubstrateRequest};
+    use crate::domain::ports::Substrate;
+
+    #[tokio::test]
+    async fn test_restart_running_task_kills_agent_and_resets_to_ready() {
+        let repo = setup_task_repo().await;
+        let service = make_task_service(&repo);
+
+        let mut task = Task::new("Wedged task");
+        task.transition_to(TaskStatus::Ready).unwrap();
+        task.transition_to(TaskStatus::Running).unwrap();
+        repo.create(&task).await.unwrap();
+
+        let substrate = Arc::new(MockSubstrate::with_default_response(
+            MockResponse::partial_then_stall("working..."),
+        ));
+        let (_rx, session) = substrate
+            .execute_streaming(SubstrateRequest::new(task.id, "coder", "sys", "go"))
+            .await
+            .unwrap();
+        assert_eq!(session.status, SessionStatus::Active);
+
+        let (restarted, events) = service
+            .restart_task(task.id, TaskStatus::Ready, "agent hung")
+            .await
+            .unwrap();
+        assert_eq!(restarted.status, TaskStatus::Ready);
+        assert_eq!(restarted.retry_count, 1);
+        assert!(restarted.started_at.is_none());
+        let stored = repo.get(task.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, TaskStatus::Ready);
+        assert_eq!(stored.retry_count, 1);
+
+        let restarted_event = events
+            .iter()
+            .find(|e| matches!(e.payload, EventPayload::TaskRestarted { .. }))
+            .expect("TaskRestarted event");
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(e.payload, EventPayload::TaskReady { .. }))
+        );
+
+        let handler = AgentTerminationHandler::new(
+            substrate.clone(),
+            Arc::new(crate::services::guardrails::Guardrails::with_defaults()),
+        );
+        assert!(handler.metadata().filter.matches(restarted_event));
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+        handler.handle(restarted_event, &ctx).await.unwrap();
+
+        let killed = substrate.get_session(session.id).await.unwrap().unwrap();
+        assert_eq!(killed.status, SessionStatus::Terminated);
+    }
+
+    #[tokio::test]
+    async fn test_restart_rejects_non_stuck_task() {
+        let repo = setup_task_repo().await;
+        let service = make_task_service(&repo);
+
+        let task = Task::new("Pending task");
+        repo.create(&task).await.unwrap();
+
+        let err = service
+            .restart_task(task.id, TaskStatus::Ready, "nope")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DomainError::ValidationFailed(_)));
+        assert_eq!(repo.get(task.id).await.unwrap().unwrap().retry_count, 0);
+    }
+}
@@ -0,0 +1,332 @@
+//! Built-in reactive event handler.
+//!
+//! All handlers are **idempotent** — safe to run even if the poll loop already
+//! handled the same state change. They check current state before acting.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::models::TaskStatus;
+use crate::domain::ports::TaskRepository;
+use crate::services::event_bus::{
+    EventCategory, EventId, EventPayload, EventSeverity, SequenceNumber, UnifiedEvent,
+};
+use crate::services::event_reactor::{
+    ErrorStrategy, EventFilter, EventHandler, HandlerContext, HandlerId, HandlerMetadata,
+    HandlerPriority, Reaction,
+};
+
+use super::update_with_retry;
+
+// ============================================================================
+// ArtifactPruningHandler
+// ============================================================================
+
+/// Triggered by the "artifact-pruning" scheduled event.
+/// Drops all but the newest `keep_per_task` artifact references from terminal
+/// tasks that finished more than `retention_days` ago. If terminal tasks
+/// still hold more than `max_total` artifacts after that, the oldest ones are
+/// dropped first (earliest-finished task, earliest artifact) until the total
+/// fits, never going below `keep_per_task` on any task.
+pub struct ArtifactPruningHandler<T: TaskRepository> {
+    task_repo: Arc<T>,
+    /// Retention duration in days.
+    retention_days: u64,
+    /// Number of most recent artifacts kept on every task.
+    keep_per_task: usize,
+    /// Cap on artifacts held across all terminal tasks.
+    max_total: usize,
+}
+
+impl<T: TaskRepository> ArtifactPruningHandler<T> {
+    pub fn new(task_repo: Arc<T>, retention_days: u64, keep_per_task: usize) -> Self {
+        Self {
+            task_repo,
+            retention_days,
+            keep_per_task,
+            max_total: usize::MAX,
+        }
+    }
+
+    /// Cap the number of artifacts kept across all terminal tasks.
+    pub fn with_max_total(mut self, max_total: usize) -> Self {
+        self.max_total = max_total;
+        self
+    }
+}
+
+#[async_trait]
+impl<T: TaskRepository + 'static> EventHandler for ArtifactPruningHandler<T> {
+    fn metadata(&self) -> HandlerMetadata {
+        HandlerMetadata {
+            id: HandlerId::new(),
+            name: "ArtifactPruningHandler".to_string(),
+            filter: EventFilter {
+                categories: vec![EventCategory::Scheduler],
+                payload_types: vec!["ScheduledEventFired".to_string()],
+                custom_predicate: Some(Arc::new(|event| {
+                    matches!(
+                        &event.payload,
+                        EventPayload::ScheduledEventFired { name, .. } if name == "artifact-pruning"
+                    )
+                })),
+                ..Default::default()
+            },
+            priority: HandlerPriority::LOW,
+            error_strategy: ErrorStrategy::LogAndContinue,
+            critical: false,
+            singleton: true,
+        }
+    }
+
+    async fn handle(
+        &self,
+        event: &UnifiedEvent,
+        _ctx: &HandlerContext,
+    ) -> Result<Reaction, String> {
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(self.retention_days as i64);
+        let keep = self.keep_per_task;
+
+        let mut tasks = Vec::new();
+        for status in &[
+            TaskStatus::Complete,
+            TaskStatus::Failed,
+            TaskStatus::Canceled,
+        ] {
+            tasks.extend(
+                self.task_repo
+                    .list_by_status(*status)
+                    .await
+                    .map_err(|e| format!("ArtifactPruning: failed to list tasks: {}", e))?,
+            );
+        }
+        // Oldest first, so the size cap eats into the earliest-finished tasks.
+        tasks.sort_by_key(|t| t.completed_at.unwrap_or(t.updated_at));
+
+        // How many of each task's oldest artifacts to drop: everything past
+        // `keep` for tasks older than the cutoff, then more until the total
+        // fits under `max_total`.
+        let mut drops: Vec<usize> = tasks
+            .iter()
+            .map(|t| {
+                let finished_at = t.completed_at.unwrap_or(t.updated_at);
+                if finished_at < cutoff {
+                    t.artifacts.len().saturating_sub(keep)
+                } else {
+                    0
+                }
+            })
+            .collect();
+        let retained: usize = tasks
+            .iter()
+            .zip(&drops)
+            .map(|(t, drop)| t.artifacts.len() - drop)
+            .sum();
+        let mut excess = retained.saturating_sub(self.max_total);
+        for (task, drop) in tasks.iter().zip(drops.iter_mut()) {
+            if excess == 0 {
+                break;
+            }
+            let extra = (task.artifacts.len().saturating_sub(keep) - *drop).min(excess);
+            *drop += extra;
+            excess -= extra;
+        }
+
+        let mut tasks_affected = 0u32;
+        let mut artifacts_removed = 0u32;
+
+        for (task, drop) in tasks.iter().zip(drops) {
+            if drop == 0 {
+                continue;
+            }
+
+            let updated = update_with_retry(
+                self.task_repo.as_ref(),
+                task.id,
+                |t| {
+                    let drop = drop.min(t.artifacts.len().saturating_sub(keep));
+                    if drop == 0 {
+                        return Ok(false);
+                    }
+                    t.artifacts.drain(..drop);
+                    t.updated_at = chrono::Utc::now();
+                    Ok(true)
+                },
+                3,
+                "ArtifactPruning",
+            )
+            .await?;
+
+            if let Some(updated) = updated {
+                tasks_affected += 1;
+                artifacts_removed +=
+                    task.artifacts.len().saturating_sub(updated.artifacts.len()) as u32;
+            }
+        }
+
+        if tasks_affected == 0 {
+            return Ok(Reaction::None);
+        }
+
+        tracing::info!(
+            "ArtifactPruning: pruned {} artifacts from {} tasks (retention {} days)",
+            artifacts_removed,
+            tasks_affected,
+            self.retention_days
+        );
+
+        let summary = UnifiedEvent {
+            id: EventId::new(),
+            sequence: SequenceNumber(0),
+            timestamp: chrono::Utc::now(),
+            severity: EventSeverity::Info,
+            category: EventCategory::Task,
+            goal_id: None,
+            task_id: None,
+            correlation_id: event.correlation_id,
+            source_process_id: None,
+            payload: EventPayload::ArtifactsPruned {
+                tasks_affected,
+                artifacts_removed,
+                retention_days: self.retention_days,
+            },
+        };
+
+        Ok(Reaction::EmitEvents(vec![summary]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::test_support::setup_task_repo;
+    use crate::domain::models::{ArtifactRef, ArtifactType, Task};
+
+    fn make_artifact_pruning_event() -> UnifiedEvent {
+        UnifiedEvent {
+            id: EventId::new(),
+            sequence: SequenceNumber(0),
+            timestamp: chrono::Utc::now(),
+            severity: EventSeverity::Debug,
+            category: EventCategory::Scheduler,
+            goal_id: None,
+            task_id: None,
+            correlation_id: None,
+            source_process_id: None,
+            payload: EventPayload::ScheduledEventFired {
+                schedule_id: uuid::Uuid::new_v4(),
+                name: "artifact-pruning".to_string(),
+            },
+        }
+    }
+
+    fn completed_task_with_artifacts(title: &str, count: usize, age_days: i64) -> Task {
+        let mut task = Task::new(title);
+        task.transition_to(TaskStatus::Ready).unwrap();
+        task.transition_to(TaskStatus::Running).unwrap();
+        task.transition_to(TaskStatus::Complete).unwrap();
+        task.completed_at = Some(chrono::Utc::now() - chrono::Duration::days(age_days));
+        task.artifacts = (0..count)
+            .map(|i| ArtifactRef {
+                uri: format!("worktree://{}/file-{}", task.id, i),
+                artifact_type: ArtifactType::File,
+                checksum: None,
+            })
+            .collect();
+        task
+    }
+
+    #[tokio::test]
+    async fn test_prunes_old_artifacts_and_retains_recent() {
+        let repo = setup_task_repo().await;
+        let handler = ArtifactPruningHandler::new(repo.clone(), 30, 1);
+
+        let old = completed_task_with_artifacts("Old task", 3, 45);
+        let recent = completed_task_with_artifacts("Recent task", 3, 2);
+        repo.create(&old).await.unwrap();
+        repo.create(&recent).await.unwrap();
+
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+        let reaction = handler
+            .handle(&make_artifact_pruning_event(), &ctx)
+            .await
+            .unwrap();
+
+        let old_after = repo.get(old.id).await.unwrap().unwrap();
+        assert_eq!(old_after.artifacts.len(), 1);
+        assert_eq!(old_after.artifacts[0], old.artifacts[2]);
+
+        let recent_after = repo.get(recent.id).await.unwrap().unwrap();
+        assert_eq!(recent_after.artifacts, recent.artifacts);
+
+        match reaction {
+            Reaction::EmitEvents(events) => {
+                assert_eq!(events.len(), 1);
+                assert!(matches!(
+                    events[0].payload,
+                    EventPayload::ArtifactsPruned {
+                        tasks_affected: 1,
+                        artifacts_removed: 2,
+                        retention_days: 30,
+                    }
+                ));
+            }
+            _ => panic!("Expected a summary event"),
+        }
+
+        // A second pass has nothing left to prune.
+        let reaction = handler
+            .handle(&make_artifact_pruning_event(), &ctx)
+            .await
+            .unwrap();
+        assert!(matches!(reaction, Reaction::None));
+    }
+
+    #[tokio::test]
+    async fn test_size_cap_prunes_oldest_artifacts_first() {
+        let repo = setup_task_repo().await;
+        // Nothing is past the age cutoff; only the cap applies.
+        let handler = ArtifactPruningHandler::new(repo.clone(), 30, 1).with_max_total(6);
+
+        let oldest = completed_task_with_artifacts("Oldest", 3, 5);
+        let older = completed_task_with_artifacts("Older", 3, 3);
+        let newest = completed_task_with_artifacts("Newest", 3, 1);
+        for task in [&oldest, &older, &newest] {
+            repo.create(task).await.unwrap();
+        }
+
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+        let reaction = handler
+            .handle(&make_artifact_pruning_event(), &ctx)
+            .await
+            .unwrap();
+
+        // 9 artifacts, cap 6: the oldest task drops to its kept artifact,
+        // then the next oldest gives up its earliest one.
+        let oldest_after = repo.get(oldest.id).await.unwrap().unwrap();
+        assert_eq!(oldest_after.artifacts, oldest.artifacts[2..].to_vec());
+        let older_after = repo.get(older.id).await.unwrap().unwrap();
+        assert_eq!(older_after.artifacts, older.artifacts[1..].to_vec());
+        let newest_after = repo.get(newest.id).await.unwrap().unwrap();
+        assert_eq!(newest_after.artifacts, newest.artifacts);
+
+        assert!(matches!(
+            reaction,
+            Reaction::EmitEvents(ref events) if matches!(
+                events[0].payload,
+                EventPayload::ArtifactsPruned {
+                    tasks_affected: 2,
+                    artifacts_removed: 3,
+                    ..
+                }
+            )
+        ));
+    }
+}
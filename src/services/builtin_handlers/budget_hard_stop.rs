@@ -0,0 +1,323 @@
+//! Built-in reactive event handler.
+//!
+//! All handlers are **idempotent** — safe to run even if the poll loop already
+//! handled the same state change. They check current state before acting.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::models::TaskStatus;
+use crate::domain::ports::TaskRepository;
+use crate::services::budget_tracker::BudgetTracker;
+use crate::services::event_bus::{EventCategory, EventPayload, EventSeverity, UnifiedEvent};
+use crate::services::event_factory;
+use crate::services::event_reactor::{
+    ErrorStrategy, EventFilter, EventHandler, HandlerContext, HandlerId, HandlerMetadata,
+    HandlerPriority, Reaction,
+};
+use crate::services::swarm_orchestrator::OrchestratorStatus;
+use crate::services::swarm_orchestrator::agent_slots::AgentSlots;
+
+use super::update_with_retry;
+
+// ============================================================================
+// BudgetHardStopHandler
+// ============================================================================
+
+/// Triggered by the "budget-check" scheduled event. Under
+/// [`BudgetPolicy::HardStop`](crate::services::budget_tracker::BudgetPolicy::HardStop),
+/// once the orchestrator's token counter reaches the ceiling, cancels the
+/// Running tasks this process's agents hold and pauses the orchestrator.
+///
+/// The token counter is per process, so every process runs this handler
+/// (not just the reactor leader) and stops only its own work; tasks other
+/// processes are running in the shared database are left to them.
+///
+/// The pre-spawn ceiling gate also fires this schedule name when it turns a
+/// spawn away, so the stop does not wait for the next tick.
+pub struct BudgetHardStopHandler<T: TaskRepository> {
+    budget_tracker: Arc<BudgetTracker>,
+    task_repo: Arc<T>,
+    total_tokens: Arc<AtomicU64>,
+    status: Arc<RwLock<OrchestratorStatus>>,
+    agent_slots: Arc<AgentSlots>,
+}
+
+impl<T: TaskRepository> BudgetHardStopHandler<T> {
+    pub fn new(
+        budget_tracker: Arc<BudgetTracker>,
+        task_repo: Arc<T>,
+        total_tokens: Arc<AtomicU64>,
+        status: Arc<RwLock<OrchestratorStatus>>,
+        agent_slots: Arc<AgentSlots>,
+    ) -> Self {
+        Self {
+            budget_tracker,
+            task_repo,
+            total_tokens,
+            status,
+            agent_slots,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: TaskRepository + 'static> EventHandler for BudgetHardStopHandler<T> {
+    fn metadata(&self) -> HandlerMetadata {
+        HandlerMetadata {
+            id: HandlerId::new(),
+            name: "BudgetHardStopHandler".to_string(),
+            filter: EventFilter {
+                categories: vec![EventCategory::Scheduler],
+                payload_types: vec!["ScheduledEventFired".to_string()],
+                custom_predicate: Some(Arc::new(|event| {
+                    matches!(
+                        &event.payload,
+                        EventPayload::ScheduledEventFired { name, .. } if name == "budget-check"
+                    )
+                })),
+                ..Default::default()
+            },
+            priority: HandlerPriority::SYSTEM,
+            error_strategy: ErrorStrategy::LogAndContinue,
+            critical: false,
+            singleton: false,
+        }
+    }
+
+    async fn handle(
+        &self,
+        _event: &UnifiedEvent,
+        _ctx: &HandlerContext,
+    ) -> Result<Reaction, String> {
+        let total_tokens = self.total_tokens.load(Ordering::Relaxed);
+        if !self.budget_tracker.should_hard_stop(total_tokens) {
+            return Ok(Reaction::None);
+        }
+        let ceiling = self.budget_tracker.token_ceiling().unwrap_or_default();
+        let reason = format!(
+            "Token budget exhausted: {} of {} tokens used",
+            total_tokens, ceiling
+        );
+
+        let mut events = Vec::new();
+        for task_id in self.agent_slots.owned_tasks() {
+            let canceled = update_with_retry(
+                self.task_repo.as_ref(),
+                task_id,
+                |t| {
+                    if t.status != TaskStatus::Running {
+                        return Ok(false);
+                    }
+                    t.transition_to(TaskStatus::Canceled)
+                        .map(|_| true)
+                        .map_err(|e| format!("transition failed: {}", e))
+                },
+                3,
+                "BudgetHardStop",
+            )
+            .await?;
+
+            if canceled.is_some() {
+                events.push(event_factory::make_event(
+                    EventSeverity::Warning,
+                    EventCategory::Task,
+                    None,
+                    Some(task_id),
+                    EventPayload::TaskCanceled {
+                        task_id,
+                        reason: reason.clone(),
+                    },
+                ));
+            }
+        }
+
+        let paused = {
+            let mut status = self.status.write().await;
+            if *status == OrchestratorStatus::Running {
+                *status = OrchestratorStatus::Paused;
+                true
+            } else {
+                false
+            }
+        };
+        if paused {
+            events.push(event_factory::orchestrator_event(
+                EventSeverity::Warning,
+                EventPayload::OrchestratorPaused,
+            ));
+        }
+
+        if events.is_empty() {
+            return Ok(Reaction::None);
+        }
+
+        tracing::warn!(
+            total_tokens,
+            ceiling,
+            canceled = events.len() - usize::from(paused),
+            paused,
+            "BudgetHardStop: token ceiling reached; canceled this process's running tasks"
+        );
+
+        Ok(Reaction::EmitEvents(events))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::test_support::setup_task_repo;
+    use crate::domain::models::Task;
+    use crate::services::budget_tracker::{BudgetPolicy, BudgetTrackerConfig};
+    use crate::services::event_bus::{EventBus, EventBusConfig};
+
+    fn make_budget_check_event() -> UnifiedEvent {
+        event_factory::make_event(
+            EventSeverity::Debug,
+            EventCategory::Scheduler,
+            None,
+            None,
+            EventPayload::ScheduledEventFired {
+                schedule_id: uuid::Uuid::new_v4(),
+                name: "budget-check".to_string(),
+            },
+        )
+    }
+
+    fn make_tracker(policy: BudgetPolicy) -> Arc<BudgetTracker> {
+        let config = BudgetTrackerConfig {
+            token_ceiling: Some(10_000),
+            policy,
+            ..Default::default()
+        };
+        Arc::new(BudgetTracker::new(
+            config,
+            Arc::new(EventBus::new(EventBusConfig::default())),
+        ))
+    }
+
+    async fn create_task(repo: &impl TaskRepository, title: &str, running: bool) -> Task {
+        let mut task = Task::new(title);
+        task.transition_to(TaskStatus::Ready).unwrap();
+        if running {
+            task.transition_to(TaskStatus::Running).unwrap();
+        }
+        repo.create(&task).await.unwrap();
+        task
+    }
+
+    #[tokio::test]
+    async fn test_crossing_ceiling_cancels_owned_running_tasks_and_pauses() {
+        let repo = setup_task_repo().await;
+        let running_a = create_task(repo.as_ref(), "Running A", true).await;
+        let running_b = create_task(repo.as_ref(), "Running B", true).await;
+        let ready = create_task(repo.as_ref(), "Ready", false).await;
+        // Claimed by another process sharing the database.
+        let foreign = create_task(repo.as_ref(), "Foreign", true).await;
+
+        let agent_slots = Arc::new(AgentSlots::new(4, Default::default()));
+        let mut permits = Vec::new();
+        for id in [running_a.id, running_b.id] {
+            let mut permit = agent_slots.try_acquire("coder").unwrap();
+            permit.bind_task(id);
+            permits.push(permit);
+        }
+
+        let total_tokens = Arc::new(AtomicU64::new(9_000));
+        let status = Arc::new(RwLock::new(OrchestratorStatus::Running));
+        let handler = BudgetHardStopHandler::new(
+            make_tracker(BudgetPolicy::HardStop),
+            repo.clone(),
+            total_tokens.clone(),
+            status.clone(),
+            agent_slots,
+        );
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        // Under the ceiling: nothing happens.
+        let reaction = handler
+            .handle(&make_budget_check_event(), &ctx)
+            .await
+            .unwrap();
+        assert!(matches!(reaction, Reaction::None));
+        assert_eq!(*status.read().await, OrchestratorStatus::Running);
+
+        total_tokens.fetch_add(1_500, Ordering::Relaxed);
+        let reaction = handler
+            .handle(&make_budget_check_event(), &ctx)
+            .await
+            .unwrap();
+
+        let Reaction::EmitEvents(events) = reaction else {
+            panic!("Expected cancellation events");
+        };
+        let mut canceled: Vec<_> = events
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::TaskCanceled { task_id, .. } => Some(*task_id),
+                _ => None,
+            })
+            .collect();
+        canceled.sort();
+        let mut expected = vec![running_a.id, running_b.id];
+        expected.sort();
+        assert_eq!(canceled, expected);
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(e.payload, EventPayload::OrchestratorPaused))
+        );
+
+        assert_eq!(*status.read().await, OrchestratorStatus::Paused);
+        for id in [running_a.id, running_b.id] {
+            let task = repo.get(id).await.unwrap().unwrap();
+            assert_eq!(task.status, TaskStatus::Canceled);
+        }
+        let ready_after = repo.get(ready.id).await.unwrap().unwrap();
+        assert_eq!(ready_after.status, TaskStatus::Ready);
+        let foreign_after = repo.get(foreign.id).await.unwrap().unwrap();
+        assert_eq!(foreign_after.status, TaskStatus::Running);
+
+        // Already stopped: a later tick is a no-op.
+        let reaction = handler
+            .handle(&make_budget_check_event(), &ctx)
+            .await
+            .unwrap();
+        assert!(matches!(reaction, Reaction::None));
+    }
+
+    #[tokio::test]
+    async fn test_throttle_policy_leaves_running_tasks_alone() {
+        let repo = setup_task_repo().await;
+        let running = create_task(repo.as_ref(), "Running", true).await;
+
+        let status = Arc::new(RwLock::new(OrchestratorStatus::Running));
+        let handler = BudgetHardStopHandler::new(
+            make_tracker(BudgetPolicy::Throttle),
+            repo.clone(),
+            Arc::new(AtomicU64::new(50_000)),
+            status.clone(),
+            Arc::new(AgentSlots::new(4, Default::default())),
+        );
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        let reaction = handler
+            .handle(&make_budget_check_event(), &ctx)
+            .await
+            .unwrap();
+        assert!(matches!(reaction, Reaction::None));
+        assert_eq!(*status.read().await, OrchestratorStatus::Running);
+        let task = repo.get(running.id).await.unwrap().unwrap();
+        assert_eq!(task.status, TaskStatus::Running);
+    }
+}
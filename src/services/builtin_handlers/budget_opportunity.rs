@@ -74,6 +74,7 @@ impl EventHandler for BudgetOpportunityHandler {
             priority: HandlerPriority::HIGH,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
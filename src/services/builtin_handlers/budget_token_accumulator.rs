@@ -48,14 +48,60 @@ use super::{try_update_task, update_with_retry};
 /// `BudgetTracker` and recomputes aggregate budget pressure.
 ///
 /// This allows the budget system to maintain a running tally of tokens
-/// consumed by the swarm without polling external APIs.
+/// consumed by the swarm without polling external APIs. With goal attribution
+/// enabled, each completion is also charged to the goals the task serves so
+/// per-goal budgets can be enforced.
 pub struct BudgetTokenAccumulatorHandler {
     budget_tracker: Arc<crate::services::budget_tracker::BudgetTracker>,
+    task_repo: Option<Arc<dyn TaskRepository>>,
+    goal_repo: Option<Arc<dyn GoalRepository>>,
 }
 
 impl BudgetTokenAccumulatorHandler {
     pub fn new(budget_tracker: Arc<crate::services::budget_tracker::BudgetTracker>) -> Self {
-        Self { budget_tracker }
+        Self {
+            budget_tracker,
+            task_repo: None,
+            goal_repo: None,
+        }
+    }
+
+    /// Charge each completion's tokens to the goals its task serves.
+    pub fn with_goal_attribution(
+        mut self,
+        task_repo: Arc<dyn TaskRepository>,
+        goal_repo: Arc<dyn GoalRepository>,
+    ) -> Self {
+        self.task_repo = Some(task_repo);
+        self.goal_repo = Some(goal_repo);
+        self
+    }
+
+    async fn attribute_to_goals(&self, task_id: uuid::Uuid, tokens: u64) {
+        let (Some(task_repo), Some(goal_repo)) = (&self.task_repo, &self.goal_repo) else {
+            return;
+        };
+        let task = match task_repo.get(task_id).await {
+            Ok(Some(task)) => task,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!(%task_id, "goal token attribution: failed to load task: {}", e);
+                return;
+            }
+        };
+        let goals = match GoalContextService::new(goal_repo.clone())
+            .get_goals_charged_for_task(&task)
+            .await
+        {
+            Ok(goals) => goals,
+            Err(e) => {
+                tracing::warn!(%task_id, "goal token attribution: failed to load goals: {}", e);
+                return;
+            }
+        };
+        for goal in &goals {
+            self.budget_tracker.record_goal_tokens(goal, tokens).await;
+        }
     }
 }
 
@@ -71,6 +117,7 @@ impl EventHandler for BudgetTokenAccumulatorHandler {
             priority: HandlerPriority::SYSTEM,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -91,6 +138,7 @@ impl EventHandler for BudgetTokenAccumulatorHandler {
         self.budget_tracker
             .record_tokens_used(task_id, tokens_used)
             .await;
+        self.attribute_to_goals(task_id, tokens_used).await;
         self.budget_tracker.recompute_state().await;
 
         Ok(Reaction::None)
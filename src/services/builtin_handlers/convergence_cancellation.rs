@@ -68,6 +68,7 @@ impl<T: TaskRepository + 'static> EventHandler for ConvergenceCancellationHandle
             priority: HandlerPriority::HIGH,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -75,6 +75,7 @@ impl<T: TaskRepository + 'static> EventHandler for ConvergenceCoordinationHandle
             priority: HandlerPriority::HIGH,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -84,6 +84,7 @@ impl<T: TaskRepository + 'static, Tr: TrajectoryRepository + 'static> EventHandl
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -73,6 +73,7 @@ impl<T: TaskRepository + 'static> EventHandler for ConvergenceEvolutionHandler<T
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -188,6 +189,7 @@ impl<T: TaskRepository + 'static> EventHandler for ConvergenceEvolutionHandler<T
                     retry_count: updated.retry_count,
                     tokens_used: total_tokens,
                     egress: None,
+                    output: None,
                 },
             },
         };
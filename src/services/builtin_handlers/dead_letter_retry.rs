@@ -79,6 +79,7 @@ impl EventHandler for DeadLetterRetryHandler {
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
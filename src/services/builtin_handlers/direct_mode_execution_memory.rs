@@ -76,6 +76,7 @@ impl<M: MemoryRepository + 'static> EventHandler for DirectModeExecutionMemoryHa
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -81,6 +81,7 @@ impl EventHandler for EgressRoutingHandler {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -251,6 +252,7 @@ mod tests {
             retry_count: 0,
             tokens_used: 0,
             egress: None,
+            output: None,
         };
         let event = make_event(
             EventPayload::TaskCompletedWithResult { task_id, result },
@@ -283,6 +285,7 @@ mod tests {
             retry_count: 0,
             tokens_used: 0,
             egress: Some(directive),
+            output: None,
         };
         let event = make_event(
             EventPayload::TaskCompletedWithResult { task_id, result },
@@ -330,6 +333,7 @@ mod tests {
             retry_count: 0,
             tokens_used: 0,
             egress: None,
+            output: None,
         };
         let event = make_event(
             EventPayload::TaskCompletedWithResult { task_id, result },
@@ -381,6 +385,7 @@ mod tests {
             retry_count: 0,
             tokens_used: 0,
             egress: Some(dedicated),
+            output: None,
         };
         let event = make_event(
             EventPayload::TaskCompletedWithResult { task_id, result },
@@ -0,0 +1,159 @@
+//! Built-in reactive event handler.
+//!
+//! All handlers are **idempotent** — safe to run even if the poll loop already
+//! handled the same state change. They check current state before acting.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::services::escalation_notifier::EscalationNotifier;
+use crate::services::event_bus::{EventCategory, EventPayload, UnifiedEvent};
+use crate::services::event_reactor::{
+    ErrorStrategy, EventFilter, EventHandler, HandlerContext, HandlerId, HandlerMetadata,
+    HandlerPriority, Reaction,
+};
+
+// ============================================================================
+// EscalationNotificationHandler
+// ============================================================================
+
+/// Announces new human escalations through the notification sinks routed for
+/// their urgency (see `[notifications]`).
+pub struct EscalationNotificationHandler {
+    notifier: Arc<EscalationNotifier>,
+}
+
+impl EscalationNotificationHandler {
+    pub fn new(notifier: Arc<EscalationNotifier>) -> Self {
+        Self { notifier }
+    }
+}
+
+#[async_trait]
+impl EventHandler for EscalationNotificationHandler {
+    fn metadata(&self) -> HandlerMetadata {
+        HandlerMetadata {
+            id: HandlerId::new(),
+            name: "EscalationNotificationHandler".to_string(),
+            filter: EventFilter::new()
+                .categories(vec![EventCategory::Escalation])
+                .payload_types(vec![
+                    "HumanEscalationRequired".to_string(),
+                    "HumanEscalationNeeded".to_string(),
+                ]),
+            priority: HandlerPriority::LOW,
+            error_strategy: ErrorStrategy::LogAndContinue,
+            critical: false,
+            singleton: false,
+        }
+    }
+
+    async fn handle(
+        &self,
+        event: &UnifiedEvent,
+        _ctx: &HandlerContext,
+    ) -> Result<Reaction, String> {
+        let escalation = match &event.payload {
+            EventPayload::HumanEscalationRequired(p) | EventPayload::HumanEscalationNeeded(p) => p,
+            _ => return Ok(Reaction::None),
+        };
+
+        self.notifier.notify(escalation).await;
+        Ok(Reaction::None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    use tokio::sync::Mutex;
+
+    use crate::services::config::{
+        NotificationSinkConfig, NotificationSinkKind, NotificationsConfig,
+    };
+    use crate::services::escalation_notifier::NotificationSink;
+    use crate::services::event_bus::{
+        EventId, EventSeverity, HumanEscalationPayload, SequenceNumber,
+    };
+
+    /// Records the urgency of every escalation it receives.
+    #[derive(Default)]
+    struct RecordingSink {
+        received: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl NotificationSink for RecordingSink {
+        async fn notify(&self, escalation: &HumanEscalationPayload) -> Result<(), String> {
+            self.received.lock().await.push(escalation.urgency.clone());
+            Ok(())
+        }
+    }
+
+    fn escalation_event(urgency: &str) -> UnifiedEvent {
+        UnifiedEvent {
+            id: EventId::new(),
+            sequence: SequenceNumber(0),
+            timestamp: chrono::Utc::now(),
+            severity: EventSeverity::Warning,
+            category: EventCategory::Escalation,
+            goal_id: None,
+            task_id: None,
+            correlation_id: None,
+            source_process_id: None,
+            payload: EventPayload::HumanEscalationRequired(HumanEscalationPayload {
+                goal_id: None,
+                task_id: None,
+                reason: "Database migration is stuck".to_string(),
+                urgency: urgency.to_string(),
+                questions: vec![],
+                is_blocking: true,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routes_escalations_to_sinks_by_urgency() {
+        let config = NotificationsConfig {
+            sinks: HashMap::from([(
+                "pager".to_string(),
+                NotificationSinkConfig {
+                    kind: NotificationSinkKind::Webhook,
+                    url: Some("https://pager.example.com/hook".to_string()),
+                },
+            )]),
+            escalation_routes: HashMap::from([
+                ("critical".to_string(), vec!["pager".to_string()]),
+                ("medium".to_string(), vec!["log".to_string()]),
+            ]),
+            ..Default::default()
+        };
+        let pager = Arc::new(RecordingSink::default());
+        let log = Arc::new(RecordingSink::default());
+        let notifier = EscalationNotifier::from_config(&config)
+            .with_sink("pager", pager.clone())
+            .with_sink("log", log.clone());
+        let handler = EscalationNotificationHandler::new(Arc::new(notifier));
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        handler
+            .handle(&escalation_event("critical"), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(*pager.received.lock().await, vec!["critical"]);
+        assert!(log.received.lock().await.is_empty());
+
+        handler
+            .handle(&escalation_event("medium"), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(*pager.received.lock().await, vec!["critical"]);
+        assert_eq!(*log.received.lock().await, vec!["medium"]);
+    }
+}
@@ -82,6 +82,7 @@ impl EventHandler for EventPruningHandler {
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: true,
         }
     }
 
@@ -135,6 +135,7 @@ impl EventHandler for EventStorePollerHandler {
             priority: HandlerPriority::SYSTEM,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
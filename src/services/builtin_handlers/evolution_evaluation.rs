@@ -36,7 +36,7 @@ use crate::services::event_store::EventStore;
 use crate::services::goal_context_service::GoalContextService;
 use crate::services::memory_service::MemoryService;
 use crate::services::swarm_orchestrator::SwarmStats;
-use crate::services::task_service::TaskService;
+use crate::services::task_service::{TaskService, agent_type_metrics};
 
 use super::{try_update_task, update_with_retry};
 
@@ -77,6 +77,7 @@ impl<T: TaskRepository + 'static> EventHandler for EvolutionEvaluationHandler<T>
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -85,8 +86,6 @@ impl<T: TaskRepository + 'static> EventHandler for EvolutionEvaluationHandler<T>
         event: &UnifiedEvent,
         _ctx: &HandlerContext,
     ) -> Result<Reaction, String> {
-        use std::collections::HashMap;
-
         // Get recently completed and failed tasks
         let completed = self
             .task_repo
@@ -100,51 +99,33 @@ impl<T: TaskRepository + 'static> EventHandler for EvolutionEvaluationHandler<T>
             .map_err(|e| format!("Failed to list failed tasks: {}", e))?;
 
         // Compute per-agent-type success rates
-        let mut agent_stats: HashMap<String, (u32, u32)> = HashMap::new(); // (success, total)
-
-        for task in &completed {
-            let agent = task.agent_type.as_deref().unwrap_or("unknown");
-            let entry = agent_stats.entry(agent.to_string()).or_insert((0, 0));
-            entry.0 += 1;
-            entry.1 += 1;
-        }
-
-        for task in &failed {
-            if task.retry_count >= task.max_retries {
-                let agent = task.agent_type.as_deref().unwrap_or("unknown");
-                let entry = agent_stats.entry(agent.to_string()).or_insert((0, 0));
-                entry.1 += 1;
-            }
-        }
+        let agent_stats = agent_type_metrics(completed.iter().chain(&failed));
 
         let mut new_events = Vec::new();
 
         // Emit EvolutionTriggered for agents with low success rates
-        for (agent_name, (successes, total)) in &agent_stats {
-            if *total >= 5 {
-                let success_rate = *successes as f64 / *total as f64;
-                if success_rate < 0.6 {
-                    new_events.push(UnifiedEvent {
-                        id: EventId::new(),
-                        sequence: SequenceNumber(0),
-                        timestamp: chrono::Utc::now(),
-                        severity: EventSeverity::Info,
-                        category: EventCategory::Agent,
-                        goal_id: None,
-                        task_id: None,
-                        correlation_id: event.correlation_id,
-                        source_process_id: None,
-                        payload: EventPayload::EvolutionTriggered {
-                            template_name: agent_name.clone(),
-                            trigger: format!(
-                                "Low success rate: {:.0}% ({}/{})",
-                                success_rate * 100.0,
-                                successes,
-                                total
-                            ),
-                        },
-                    });
-                }
+        for stats in &agent_stats {
+            if stats.total >= 5 && stats.success_rate < 0.6 {
+                new_events.push(UnifiedEvent {
+                    id: EventId::new(),
+                    sequence: SequenceNumber(0),
+                    timestamp: chrono::Utc::now(),
+                    severity: EventSeverity::Info,
+                    category: EventCategory::Agent,
+                    goal_id: None,
+                    task_id: None,
+                    correlation_id: event.correlation_id,
+                    source_process_id: None,
+                    payload: EventPayload::EvolutionTriggered {
+                        template_name: stats.agent_type.clone(),
+                        trigger: format!(
+                            "Low success rate: {:.0}% ({}/{})",
+                            stats.success_rate * 100.0,
+                            stats.completed,
+                            stats.total
+                        ),
+                    },
+                });
             }
         }
 
@@ -68,6 +68,7 @@ impl EventHandler for EvolutionTriggeredTemplateUpdateHandler {
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -117,6 +118,10 @@ impl EventHandler for EvolutionTriggeredTemplateUpdateHandler {
                 deadline: None,
                 task_type: None,
                 execution_mode: None,
+                estimate_secs: None,
+                execution_params: None,
+                required_overseers: Vec::new(),
+                workspace_mode: None,
             }),
         );
 
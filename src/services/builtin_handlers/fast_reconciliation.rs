@@ -94,6 +94,7 @@ impl<T: TaskRepository + 'static> EventHandler for FastReconciliationHandler<T>
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
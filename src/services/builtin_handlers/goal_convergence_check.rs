@@ -188,6 +188,7 @@ impl<G: GoalRepository + 'static, T: TaskRepository + 'static> EventHandler
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -362,6 +363,10 @@ impl<G: GoalRepository + 'static, T: TaskRepository + 'static> EventHandler
                 deadline: None,
                 task_type: None,
                 execution_mode: None,
+                estimate_secs: None,
+                execution_params: None,
+                required_overseers: Vec::new(),
+                workspace_mode: None,
             }),
         );
 
@@ -78,6 +78,7 @@ impl<G: GoalRepository + 'static> EventHandler for GoalCreatedHandler<G> {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -105,6 +105,7 @@ impl<G: GoalRepository + 'static, T: TaskRepository + 'static> EventHandler
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -94,6 +94,7 @@ impl EventHandler for GoalEvaluationTaskCreationHandler {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -171,6 +172,10 @@ impl EventHandler for GoalEvaluationTaskCreationHandler {
                             deadline: None,
                             task_type: None,
                             execution_mode: None,
+                            estimate_secs: None,
+                            execution_params: None,
+                            required_overseers: Vec::new(),
+                            workspace_mode: None,
                         }),
                     );
 
@@ -209,6 +214,10 @@ impl EventHandler for GoalEvaluationTaskCreationHandler {
                         deadline: None,
                         task_type: None,
                         execution_mode: None,
+                        estimate_secs: None,
+                        execution_params: None,
+                        required_overseers: Vec::new(),
+                        workspace_mode: None,
                     }),
                 );
 
@@ -85,6 +85,7 @@ impl<G: GoalRepository + 'static> EventHandler for GoalReconciliationHandler<G>
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
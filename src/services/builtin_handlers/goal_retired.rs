@@ -82,6 +82,7 @@ impl<G: GoalRepository + 'static> EventHandler for GoalRetiredHandler<G> {
             priority: HandlerPriority::HIGH,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -83,6 +83,7 @@ impl<G: GoalRepository + 'static> EventHandler for GoalStagnationDetectorHandler
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
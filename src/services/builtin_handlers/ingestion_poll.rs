@@ -44,9 +44,11 @@ use super::{try_update_task, update_with_retry};
 // IngestionPollHandler (Adapter integration)
 // ============================================================================
 
-/// Polls all registered ingestion adapters for new work items and creates
-/// tasks for each one via the CommandBus. Deduplicates using idempotency
-/// keys of the form `adapter:{name}:{external_id}`.
+/// Polls the ingestion adapters that are due (see
+/// [`AdapterRegistry::poll_due`](crate::services::adapter_registry::AdapterRegistry::poll_due))
+/// for new work items and creates tasks for each one via the CommandBus.
+/// Deduplicates using idempotency keys of the form
+/// `adapter:{name}:{external_id}`.
 pub struct IngestionPollHandler<T: TaskRepository> {
     task_repo: Arc<T>,
     adapter_registry: Arc<crate::services::adapter_registry::AdapterRegistry>,
@@ -92,6 +94,7 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -128,16 +131,16 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
             return Ok(Reaction::None);
         }
 
-        let remaining_capacity = self.max_pending - active_adapter_tasks;
+        let mut remaining_capacity = self.max_pending - active_adapter_tasks;
         let mut all_events = Vec::new();
 
-        for adapter_name in self.adapter_registry.ingestion_names() {
-            let adapter = match self.adapter_registry.get_ingestion(adapter_name) {
-                Some(a) => a,
-                None => continue,
-            };
+        // Due adapters are polled concurrently; a failing or slow adapter
+        // only affects its own outcome.
+        for outcome in self.adapter_registry.poll_due().await {
+            let adapter_name = outcome.adapter_name.as_str();
+            let poll_duration_ms = outcome.duration.as_millis() as u64;
 
-            let items = match adapter.poll(None).await {
+            let items = match outcome.result {
                 Ok(items) => items,
                 Err(e) => {
                     tracing::warn!(
@@ -152,7 +155,7 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
                         None,
                         EventPayload::AdapterIngestionFailed {
                             adapter_name: adapter_name.to_string(),
-                            error: e.to_string(),
+                            error: e,
                         },
                     ));
                     continue;
@@ -161,14 +164,16 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
 
             let items_found = items.len();
             let mut tasks_created: usize = 0;
+            let mut duplicates_skipped: usize = 0;
+            let mut rate_limited: usize = 0;
 
-            for item in &items {
+            for (index, item) in items.iter().enumerate() {
                 // Stop creating tasks once we've filled remaining capacity.
-                if tasks_created >= remaining_capacity {
+                if remaining_capacity == 0 {
                     tracing::info!(
                         adapter = adapter_name,
                         tasks_created,
-                        remaining_items = items_found - tasks_created,
+                        remaining_items = items_found - index,
                         "Ingestion paused mid-poll: max_pending_ingestion_tasks reached"
                     );
                     break;
@@ -197,6 +202,7 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
                             external_id = %item.external_id,
                             "Skipping duplicate ingestion item"
                         );
+                        duplicates_skipped += 1;
                         continue;
                     }
                     Ok(None) => {} // new item, proceed
@@ -210,6 +216,18 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
                     }
                 }
 
+                // Shared across adapters; items left over are picked up by a
+                // later poll since dedup is keyed on the external ID.
+                if !self.adapter_registry.try_acquire_submission() {
+                    rate_limited = items_found - index;
+                    tracing::info!(
+                        adapter = adapter_name,
+                        deferred = rate_limited,
+                        "Ingestion paused mid-poll: submission rate limit reached"
+                    );
+                    break;
+                }
+
                 // Map priority
                 let priority = item
                     .priority
@@ -288,6 +306,10 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
                             deadline: None,
                             task_type,
                             execution_mode,
+                            estimate_secs: None,
+                            execution_params: None,
+                            required_overseers: Vec::new(),
+                            workspace_mode: None,
                         },
                     ),
                 );
@@ -295,6 +317,7 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
                 match self.command_bus.dispatch(envelope).await {
                     Ok(crate::services::command_bus::CommandResult::Task(task)) => {
                         tasks_created += 1;
+                        remaining_capacity -= 1;
                         all_events.push(crate::services::event_factory::make_event(
                             EventSeverity::Info,
                             EventCategory::Adapter,
@@ -308,8 +331,10 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
                     }
                     Ok(_) => {
                         tasks_created += 1;
+                        remaining_capacity -= 1;
                     }
                     Err(crate::services::command_bus::CommandError::DuplicateCommand(_)) => {
+                        duplicates_skipped += 1;
                         tracing::debug!(
                             adapter = adapter_name,
                             external_id = %item.external_id,
@@ -331,8 +356,23 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
                 adapter = adapter_name,
                 items_found = items_found,
                 tasks_created = tasks_created,
+                duplicates_skipped = duplicates_skipped,
+                rate_limited = rate_limited,
+                poll_duration_ms = poll_duration_ms,
                 "Ingestion poll completed"
             );
+            metrics::counter!(
+                "abathur_adapter_tasks_ingested_total",
+                "adapter" => adapter_name.to_string()
+            )
+            .increment(tasks_created as u64);
+            if rate_limited > 0 {
+                metrics::counter!(
+                    "abathur_adapter_items_rate_limited_total",
+                    "adapter" => adapter_name.to_string()
+                )
+                .increment(rate_limited as u64);
+            }
 
             all_events.push(crate::services::event_factory::make_event(
                 EventSeverity::Info,
@@ -343,6 +383,9 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
                     adapter_name: adapter_name.to_string(),
                     items_found,
                     tasks_created,
+                    duplicates_skipped,
+                    rate_limited,
+                    poll_duration_ms,
                 },
             ));
         }
@@ -354,3 +397,165 @@ impl<T: TaskRepository + 'static> EventHandler for IngestionPollHandler<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+
+    use chrono::{DateTime, Utc};
+
+    use crate::adapters::sqlite::test_support::{self, setup_task_repo};
+    use crate::domain::errors::DomainResult;
+    use crate::domain::models::adapter::{
+        AdapterDirection, AdapterManifest, AdapterType, IngestionItem,
+    };
+    use crate::domain::ports::NullMemoryRepository;
+    use crate::domain::ports::adapter::IngestionAdapter;
+    use crate::services::adapter_loader::LoadedAdapter;
+    use crate::services::adapter_registry::AdapterRegistry;
+    use crate::services::command_bus::CommandBus;
+    use crate::services::goal_service::GoalService;
+    use crate::services::memory_maintenance_service::MemoryMaintenanceService;
+
+    /// Ingestion adapter that takes a while to answer and records how many
+    /// polls were in flight at once.
+    struct SlowAdapter {
+        manifest: AdapterManifest,
+        fail: bool,
+        in_flight: Arc<AtomicUsize>,
+        peak_in_flight: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl IngestionAdapter for SlowAdapter {
+        fn manifest(&self) -> &AdapterManifest {
+            &self.manifest
+        }
+
+        async fn poll(
+            &self,
+            _last_poll: Option<DateTime<Utc>>,
+        ) -> DomainResult<Vec<IngestionItem>> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak_in_flight.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            if self.fail {
+                return Err(DomainError::ExternalServiceError {
+                    service: self.manifest.name.clone(),
+                    reason: "503 Service Unavailable".to_string(),
+                });
+            }
+            Ok(vec![IngestionItem::new(
+                "1",
+                format!("{} item", self.manifest.name),
+                "body",
+            )])
+        }
+    }
+
+    async fn setup_command_bus<R: TaskRepository + 'static>(repo: Arc<R>) -> Arc<CommandBus> {
+        let goal_repo = test_support::setup_goal_repo().await;
+        let memory_service = Arc::new(MemoryService::new(Arc::new(NullMemoryRepository::new())));
+        Arc::new(CommandBus::new(
+            Arc::new(TaskService::new(repo)),
+            Arc::new(GoalService::new(goal_repo)),
+            Arc::new(MemoryMaintenanceService::from_memory_service(
+                memory_service,
+            )),
+            Arc::new(EventBus::new(crate::services::EventBusConfig {
+                persist_events: false,
+                ..Default::default()
+            })),
+        ))
+    }
+
+    fn poll_event() -> UnifiedEvent {
+        crate::services::event_factory::make_event(
+            EventSeverity::Debug,
+            EventCategory::Scheduler,
+            None,
+            None,
+            EventPayload::ScheduledEventFired {
+                schedule_id: uuid::Uuid::new_v4(),
+                name: "adapter-ingestion-poll".to_string(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_adapters_poll_concurrently_and_all_ingest_in_one_cycle() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak_in_flight = Arc::new(AtomicUsize::new(0));
+        let loaded = [("jira", false), ("linear", false), ("tracker", true)]
+            .into_iter()
+            .map(|(name, fail)| {
+                let manifest =
+                    AdapterManifest::new(name, AdapterType::Native, AdapterDirection::Ingestion);
+                LoadedAdapter {
+                    manifest: manifest.clone(),
+                    ingestion: Some(Box::new(SlowAdapter {
+                        manifest,
+                        fail,
+                        in_flight: in_flight.clone(),
+                        peak_in_flight: peak_in_flight.clone(),
+                    })),
+                    egress: None,
+                    prompt_content: None,
+                }
+            })
+            .collect();
+        let registry = Arc::new(AdapterRegistry::from_loaded(loaded, HashMap::new()));
+
+        let repo = setup_task_repo().await;
+        let handler = IngestionPollHandler::new(
+            repo.clone(),
+            registry,
+            setup_command_bus(repo.clone()).await,
+            10,
+        );
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        let events = match handler.handle(&poll_event(), &ctx).await.unwrap() {
+            Reaction::EmitEvents(events) => events,
+            Reaction::None => panic!("expected ingestion events"),
+        };
+
+        assert_eq!(
+            peak_in_flight.load(Ordering::SeqCst),
+            3,
+            "polls ran sequentially"
+        );
+        let mut ingested: Vec<String> = events
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::AdapterIngestionCompleted {
+                    adapter_name,
+                    tasks_created: 1,
+                    poll_duration_ms,
+                    ..
+                } if *poll_duration_ms > 0 => Some(adapter_name.clone()),
+                _ => None,
+            })
+            .collect();
+        ingested.sort();
+        assert_eq!(ingested, vec!["jira", "linear"]);
+        assert!(events.iter().any(|e| matches!(
+            &e.payload,
+            EventPayload::AdapterIngestionFailed { adapter_name, .. } if adapter_name == "tracker"
+        )));
+        assert_eq!(repo.list_by_source("adapter").await.unwrap().len(), 2);
+
+        // Neither adapter is due again until its interval elapses.
+        assert!(matches!(
+            handler.handle(&poll_event(), &ctx).await.unwrap(),
+            Reaction::None
+        ));
+    }
+}
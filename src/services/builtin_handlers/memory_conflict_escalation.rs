@@ -72,6 +72,7 @@ impl EventHandler for MemoryConflictEscalationHandler {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
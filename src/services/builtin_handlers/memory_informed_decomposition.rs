@@ -75,6 +75,7 @@ impl<G: GoalRepository + 'static> EventHandler for MemoryInformedDecompositionHa
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
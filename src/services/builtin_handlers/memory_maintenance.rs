@@ -78,6 +78,7 @@ impl<M: MemoryRepository + 'static> EventHandler for MemoryMaintenanceHandler<M>
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -80,6 +80,7 @@ impl<M: MemoryRepository + 'static> EventHandler for MemoryReconciliationHandler
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -6,8 +6,11 @@
 mod helpers;
 
 mod a2a_poll;
+mod adapter_health;
 mod adapter_lifecycle_sync;
 mod agent_termination;
+mod artifact_pruning;
+mod budget_hard_stop;
 mod budget_opportunity;
 mod budget_token_accumulator;
 mod convergence_cancellation;
@@ -19,6 +22,7 @@ mod convergence_sla_pressure;
 mod dead_letter_retry;
 mod direct_mode_execution_memory;
 mod egress_routing;
+mod escalation_notification;
 mod escalation_timeout;
 mod event_pruning;
 mod event_store_poller;
@@ -65,10 +69,14 @@ mod worktree_reconciliation;
 pub(super) mod test_support;
 
 pub(crate) use helpers::{try_update_task, update_with_retry};
+pub(crate) use worktree_reconciliation::has_uncommitted_changes;
 
 pub use a2a_poll::A2APollHandler;
+pub use adapter_health::AdapterHealthHandler;
 pub use adapter_lifecycle_sync::AdapterLifecycleSyncHandler;
 pub use agent_termination::AgentTerminationHandler;
+pub use artifact_pruning::ArtifactPruningHandler;
+pub use budget_hard_stop::BudgetHardStopHandler;
 pub use budget_opportunity::BudgetOpportunityHandler;
 pub use budget_token_accumulator::BudgetTokenAccumulatorHandler;
 pub use convergence_cancellation::ConvergenceCancellationHandler;
@@ -80,6 +88,7 @@ pub use convergence_sla_pressure::ConvergenceSLAPressureHandler;
 pub use dead_letter_retry::DeadLetterRetryHandler;
 pub use direct_mode_execution_memory::DirectModeExecutionMemoryHandler;
 pub use egress_routing::EgressRoutingHandler;
+pub use escalation_notification::EscalationNotificationHandler;
 pub use escalation_timeout::EscalationTimeoutHandler;
 pub use event_pruning::EventPruningHandler;
 pub use event_store_poller::EventStorePollerHandler;
@@ -103,7 +112,7 @@ pub use priority_aging::PriorityAgingHandler;
 pub use ready_task_polling::ReadyTaskPollingHandler;
 pub use reconciliation::ReconciliationHandler;
 pub use retry_processing::RetryProcessingHandler;
-pub use review_failure_loop::ReviewFailureLoopHandler;
+pub use review_failure_loop::{DEFAULT_MAX_LOOP_TASKS_PER_ROOT, ReviewFailureLoopHandler};
 pub use specialist_check::SpecialistCheckHandler;
 pub use startup_catch_up::StartupCatchUpHandler;
 pub use stats_update::StatsUpdateHandler;
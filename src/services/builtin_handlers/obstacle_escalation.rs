@@ -300,6 +300,7 @@ impl<T: TaskRepository + 'static, M: MemoryRepository + 'static, G: GoalReposito
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -89,6 +89,7 @@ impl<T: TaskRepository + 'static> EventHandler for PriorityAgingHandler<T> {
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
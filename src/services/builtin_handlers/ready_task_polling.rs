@@ -83,6 +83,7 @@ impl<T: TaskRepository + 'static> EventHandler for ReadyTaskPollingHandler<T> {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -96,6 +96,7 @@ impl<T: TaskRepository + 'static> EventHandler for ReconciliationHandler<T> {
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: true,
         }
     }
 
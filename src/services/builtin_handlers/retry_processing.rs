@@ -80,6 +80,7 @@ impl<T: TaskRepository + 'static> EventHandler for RetryProcessingHandler<T> {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -44,15 +44,24 @@ use super::{try_update_task, update_with_retry};
 // ReviewFailureLoopHandler
 // ============================================================================
 
+/// Default cap on the total number of loop-created tasks under one root task.
+pub const DEFAULT_MAX_LOOP_TASKS_PER_ROOT: u64 = 30;
+
 /// When a review task fails, loop back by creating a new plan → implement → review
 /// cycle that incorporates the review feedback. Bounded by `max_review_iterations`.
 ///
+/// The iteration count is derived from persisted loop tasks (via their
+/// namespaced idempotency keys) as well as the task context, so a daemon
+/// restart cannot resurrect an exhausted loop. A second, global bound caps the
+/// total number of loop-created tasks under a single root task.
+///
 /// Runs at HIGH priority so it can set the `review_loop_active` flag before the
 /// NORMAL-priority `TaskFailedRetryHandler` sees the event.
 pub struct ReviewFailureLoopHandler<T: TaskRepository> {
     task_repo: Arc<T>,
     command_bus: Arc<crate::services::command_bus::CommandBus>,
     max_review_iterations: u32,
+    max_loop_tasks_per_root: u64,
 }
 
 impl<T: TaskRepository> ReviewFailureLoopHandler<T> {
@@ -65,9 +74,26 @@ impl<T: TaskRepository> ReviewFailureLoopHandler<T> {
             task_repo,
             command_bus,
             max_review_iterations,
+            max_loop_tasks_per_root: DEFAULT_MAX_LOOP_TASKS_PER_ROOT,
         }
     }
 
+    /// Override the cap on total loop-created tasks per root task.
+    pub fn with_max_loop_tasks_per_root(mut self, max: u64) -> Self {
+        self.max_loop_tasks_per_root = max;
+        self
+    }
+
+    /// Idempotency-key prefix shared by every loop task under `root_id`.
+    fn root_key_prefix(root_id: uuid::Uuid) -> String {
+        format!("review-loop:{}:", root_id)
+    }
+
+    /// Idempotency-key prefix for loop tasks of one review chain.
+    fn chain_key_prefix(root_id: uuid::Uuid, origin_id: uuid::Uuid) -> String {
+        format!("review-loop:{}:{}:", root_id, origin_id)
+    }
+
     /// Check whether a task is a review task based on agent_type or title.
     fn is_review_task(task: &Task) -> bool {
         if let Some(ref agent_type) = task.agent_type
@@ -91,6 +117,7 @@ impl<T: TaskRepository + 'static> EventHandler for ReviewFailureLoopHandler<T> {
             priority: HandlerPriority::HIGH,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -133,8 +160,50 @@ impl<T: TaskRepository + 'static> EventHandler for ReviewFailureLoopHandler<T> {
             return Ok(Reaction::None);
         }
 
-        // Check iteration count
-        let current_iteration = task.review_iteration().unwrap_or(1) as u32;
+        // Resolve the chain origin and the hierarchy root. Both are durable:
+        // the origin is persisted in loop-task context, the root is derived
+        // from the origin's parent chain (loop tasks share the origin's parent,
+        // so unparented chains still resolve to a single root).
+        let origin_id = task.review_loop_origin().unwrap_or(task_id);
+        let root_id = self
+            .task_repo
+            .find_root_task_id(origin_id)
+            .await
+            .map_err(|e| format!("ReviewFailureLoopHandler: failed to find root task: {}", e))?;
+        let chain_prefix = Self::chain_key_prefix(root_id, origin_id);
+
+        // Global cap on loop-created tasks under this root.
+        let root_loop_tasks = self
+            .task_repo
+            .count_by_idempotency_prefix(&Self::root_key_prefix(root_id))
+            .await
+            .map_err(|e| {
+                format!(
+                    "ReviewFailureLoopHandler: failed to count loop tasks: {}",
+                    e
+                )
+            })?;
+        if root_loop_tasks >= self.max_loop_tasks_per_root {
+            tracing::warn!(
+                "ReviewFailureLoopHandler: root task {} already has {}/{} loop-created tasks, deferring to normal failure handling",
+                root_id,
+                root_loop_tasks,
+                self.max_loop_tasks_per_root,
+            );
+            return Ok(Reaction::None);
+        }
+
+        // Check iteration count. Each completed loop-back persists exactly one
+        // re-review task, so the durable iteration is that count plus the
+        // original review. Take the max with the context value so a reset
+        // context can never lower the count.
+        let persisted_reviews = self
+            .task_repo
+            .count_by_idempotency_prefix(&format!("{}review:", chain_prefix))
+            .await
+            .map_err(|e| format!("ReviewFailureLoopHandler: failed to count reviews: {}", e))?;
+        let durable_iteration = persisted_reviews + 1;
+        let current_iteration = task.review_iteration().unwrap_or(1).max(durable_iteration) as u32;
 
         if current_iteration >= self.max_review_iterations {
             tracing::info!(
@@ -176,13 +245,17 @@ impl<T: TaskRepository + 'static> EventHandler for ReviewFailureLoopHandler<T> {
             crate::domain::models::task::KEY_REVIEW_ITERATION.to_string(),
             serde_json::json!(next_iteration),
         );
+        replan_context.custom.insert(
+            crate::domain::models::task::KEY_REVIEW_LOOP_ORIGIN.to_string(),
+            serde_json::json!(origin_id.to_string()),
+        );
         // `review_feedback` is rare (1-site, new-task context only) — stays untyped.
         replan_context.custom.insert(
             "review_feedback".to_string(),
             serde_json::json!(task.description),
         );
 
-        let replan_idem = format!("review-loop:plan:{}:{}", task_id, next_iteration);
+        let replan_idem = format!("{}plan:{}", chain_prefix, next_iteration);
         let replan_envelope = CommandEnvelope::new(
             CommandSource::EventHandler("ReviewFailureLoopHandler".to_string()),
             DomainCommand::Task(TaskCommand::Submit {
@@ -201,6 +274,10 @@ impl<T: TaskRepository + 'static> EventHandler for ReviewFailureLoopHandler<T> {
                 deadline: None,
                 task_type: None,
                 execution_mode: None,
+                estimate_secs: None,
+                execution_params: None,
+                required_overseers: Vec::new(),
+                workspace_mode: None,
             }),
         );
 
@@ -223,8 +300,12 @@ impl<T: TaskRepository + 'static> EventHandler for ReviewFailureLoopHandler<T> {
             crate::domain::models::task::KEY_REVIEW_ITERATION.to_string(),
             serde_json::json!(next_iteration),
         );
+        reimpl_context.custom.insert(
+            crate::domain::models::task::KEY_REVIEW_LOOP_ORIGIN.to_string(),
+            serde_json::json!(origin_id.to_string()),
+        );
 
-        let reimpl_idem = format!("review-loop:impl:{}:{}", task_id, next_iteration);
+        let reimpl_idem = format!("{}impl:{}", chain_prefix, next_iteration);
         let reimpl_envelope = CommandEnvelope::new(
             CommandSource::EventHandler("ReviewFailureLoopHandler".to_string()),
             DomainCommand::Task(TaskCommand::Submit {
@@ -246,6 +327,10 @@ impl<T: TaskRepository + 'static> EventHandler for ReviewFailureLoopHandler<T> {
                 deadline: None,
                 task_type: None,
                 execution_mode: None,
+                estimate_secs: None,
+                execution_params: None,
+                required_overseers: Vec::new(),
+                workspace_mode: None,
             }),
         );
 
@@ -268,8 +353,12 @@ impl<T: TaskRepository + 'static> EventHandler for ReviewFailureLoopHandler<T> {
             crate::domain::models::task::KEY_REVIEW_ITERATION.to_string(),
             serde_json::json!(next_iteration),
         );
+        rereview_context.custom.insert(
+            crate::domain::models::task::KEY_REVIEW_LOOP_ORIGIN.to_string(),
+            serde_json::json!(origin_id.to_string()),
+        );
 
-        let rereview_idem = format!("review-loop:review:{}:{}", task_id, next_iteration);
+        let rereview_idem = format!("{}review:{}", chain_prefix, next_iteration);
         let rereview_envelope = CommandEnvelope::new(
             CommandSource::EventHandler("ReviewFailureLoopHandler".to_string()),
             DomainCommand::Task(TaskCommand::Submit {
@@ -288,6 +377,10 @@ impl<T: TaskRepository + 'static> EventHandler for ReviewFailureLoopHandler<T> {
                 deadline: None,
                 task_type: None,
                 execution_mode: None,
+                estimate_secs: None,
+                execution_params: None,
+                required_overseers: Vec::new(),
+                workspace_mode: None,
             }),
         );
 
@@ -695,6 +788,104 @@ mod tests {
         }
     }
 
+    /// Fail the successor re-review recorded on `prev`, wiping its in-context
+    /// iteration to simulate state lost across a daemon restart.
+    async fn fail_successor_with_reset_context<R: TaskRepository>(repo: &R, prev: Uuid) -> Uuid {
+        let prev_task = repo.get(prev).await.unwrap().unwrap();
+        let successor: Uuid = prev_task.context.custom["review_loop_successor"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let mut next = repo.get(successor).await.unwrap().unwrap();
+        next.context
+            .custom
+            .remove(crate::domain::models::task::KEY_REVIEW_ITERATION);
+        next.transition_to(TaskStatus::Ready).unwrap();
+        next.transition_to(TaskStatus::Running).unwrap();
+        next.transition_to(TaskStatus::Failed).unwrap();
+        repo.update(&next).await.unwrap();
+        successor
+    }
+
+    #[tokio::test]
+    async fn test_review_failure_loop_survives_restart() {
+        let repo = setup_task_repo().await;
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        let mut review_task = Task::new("Review implementation");
+        review_task.agent_type = Some("code-reviewer".to_string());
+        review_task.transition_to(TaskStatus::Ready).unwrap();
+        review_task.transition_to(TaskStatus::Running).unwrap();
+        review_task.transition_to(TaskStatus::Failed).unwrap();
+        repo.create(&review_task).await.unwrap();
+
+        let mut failed_id = review_task.id;
+        let mut loops_created = 0;
+        // Each round builds a fresh handler (a "restart") and fails the next
+        // re-review with its context iteration wiped.
+        for _ in 0..5 {
+            let command_bus = setup_command_bus(repo.clone()).await;
+            let handler = ReviewFailureLoopHandler::new(repo.clone(), command_bus, 3);
+            let event = make_task_failed_event(failed_id, 0);
+            match handler.handle(&event, &ctx).await.unwrap() {
+                Reaction::EmitEvents(_) => loops_created += 1,
+                Reaction::None => break,
+            }
+            failed_id = fail_successor_with_reset_context(repo.as_ref(), failed_id).await;
+        }
+
+        // Iterations 2 and 3 are created; the third restart sees the durable
+        // count and stops at the cap.
+        assert_eq!(loops_created, 2);
+        let prefix = format!("review-loop:{}:", review_task.id);
+        assert_eq!(repo.count_by_idempotency_prefix(&prefix).await.unwrap(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_review_failure_loop_root_task_cap() {
+        let repo = setup_task_repo().await;
+        let command_bus = setup_command_bus(repo.clone()).await;
+        let handler = ReviewFailureLoopHandler::new(repo.clone(), command_bus, 10)
+            .with_max_loop_tasks_per_root(3);
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        let root = Task::new("Root task");
+        repo.create(&root).await.unwrap();
+
+        // Two independent review chains under the same root.
+        let mut reviews = Vec::new();
+        for _ in 0..2 {
+            let mut review_task = Task::new("Review implementation").with_parent(root.id);
+            review_task.agent_type = Some("code-reviewer".to_string());
+            review_task.transition_to(TaskStatus::Ready).unwrap();
+            review_task.transition_to(TaskStatus::Running).unwrap();
+            review_task.transition_to(TaskStatus::Failed).unwrap();
+            repo.create(&review_task).await.unwrap();
+            reviews.push(review_task.id);
+        }
+
+        let first = handler
+            .handle(&make_task_failed_event(reviews[0], 0), &ctx)
+            .await
+            .unwrap();
+        assert!(matches!(first, Reaction::EmitEvents(_)));
+
+        // The first chain already created 3 tasks under the root, so the
+        // second chain is refused even though its own iteration count is 1.
+        let second = handler
+            .handle(&make_task_failed_event(reviews[1], 0), &ctx)
+            .await
+            .unwrap();
+        assert!(matches!(second, Reaction::None));
+    }
+
     // ========================================================================
     // SystemStallDetectorHandler tests
 }
@@ -87,6 +87,7 @@ impl<T: TaskRepository + 'static> EventHandler for SpecialistCheckHandler<T> {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
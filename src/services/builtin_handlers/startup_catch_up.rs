@@ -10,6 +10,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
 use tokio::sync::{RwLock, Semaphore};
 
 use crate::domain::errors::DomainError;
@@ -52,6 +53,8 @@ pub struct StartupCatchUpHandler<T: TaskRepository, G: GoalRepository> {
     event_store: Arc<dyn EventStore>,
     stale_threshold_secs: u64,
     max_replay_events: u64,
+    /// Maximum number of orphaned tasks fixed concurrently.
+    concurrency: usize,
 }
 
 impl<T: TaskRepository, G: GoalRepository> StartupCatchUpHandler<T, G> {
@@ -68,8 +71,15 @@ impl<T: TaskRepository, G: GoalRepository> StartupCatchUpHandler<T, G> {
             event_store,
             stale_threshold_secs,
             max_replay_events,
+            concurrency: 1,
         }
     }
+
+    /// Fix up to `concurrency` orphaned tasks at a time (minimum 1).
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
 }
 
 #[async_trait]
@@ -86,6 +96,7 @@ impl<T: TaskRepository + 'static, G: GoalRepository + 'static> EventHandler
             priority: HandlerPriority::SYSTEM,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -108,36 +119,45 @@ impl<T: TaskRepository + 'static, G: GoalRepository + 'static> EventHandler
 
         let stale_cutoff = now - chrono::Duration::seconds(self.stale_threshold_secs as i64);
 
-        for task in running {
-            let is_stale = task.started_at.is_none_or(|s| s < stale_cutoff);
-            if is_stale {
+        let correlation_id = event.correlation_id;
+        let fixes: Vec<Result<Option<UnifiedEvent>, String>> = stream::iter(running)
+            .filter(|task| std::future::ready(task.started_at.is_none_or(|s| s < stale_cutoff)))
+            .map(|task| async move {
                 let mut updated = task.clone();
                 updated.retry_count += 1;
-                if updated.transition_to(TaskStatus::Failed).is_ok() {
-                    self.task_repo
-                        .update(&updated)
-                        .await
-                        .map_err(|e| format!("StartupCatchUp: failed to update task: {}", e))?;
-                    orphaned_tasks_fixed += 1;
-
-                    new_events.push(UnifiedEvent {
-                        id: EventId::new(),
-                        sequence: SequenceNumber(0),
-                        timestamp: now,
-                        severity: EventSeverity::Warning,
-                        category: EventCategory::Task,
-                        goal_id: None,
-                        task_id: Some(task.id),
-                        correlation_id: event.correlation_id,
-                        source_process_id: None,
-                        payload: EventPayload::TaskFailed {
-                            task_id: task.id,
-                            error: "orchestrator-restart: task was running during shutdown"
-                                .to_string(),
-                            retry_count: updated.retry_count,
-                        },
-                    });
+                if updated.transition_to(TaskStatus::Failed).is_err() {
+                    return Ok(None);
                 }
+                self.task_repo
+                    .update(&updated)
+                    .await
+                    .map_err(|e| format!("StartupCatchUp: failed to update task: {}", e))?;
+
+                Ok(Some(UnifiedEvent {
+                    id: EventId::new(),
+                    sequence: SequenceNumber(0),
+                    timestamp: now,
+                    severity: EventSeverity::Warning,
+                    category: EventCategory::Task,
+                    goal_id: None,
+                    task_id: Some(task.id),
+                    correlation_id,
+                    source_process_id: None,
+                    payload: EventPayload::TaskFailed {
+                        task_id: task.id,
+                        error: "orchestrator-restart: task was running during shutdown".to_string(),
+                        retry_count: updated.retry_count,
+                    },
+                }))
+            })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+
+        for fix in fixes {
+            if let Some(failed_event) = fix? {
+                orphaned_tasks_fixed += 1;
+                new_events.push(failed_event);
             }
         }
 
@@ -259,3 +279,74 @@ impl<T: TaskRepository + 'static, G: GoalRepository + 'static> EventHandler
         Ok(Reaction::EmitEvents(new_events))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::test_support::setup_goal_and_task_repos;
+    use crate::services::event_store::InMemoryEventStore;
+
+    #[tokio::test]
+    async fn test_concurrent_catch_up_fails_all_orphaned_running_tasks() {
+        let (goal_repo, task_repo) = setup_goal_and_task_repos().await;
+        let mut orphaned = Vec::new();
+        for i in 0..50 {
+            let mut task = Task::new(format!("Orphan {i}"));
+            task.transition_to(TaskStatus::Ready).unwrap();
+            task.transition_to(TaskStatus::Running).unwrap();
+            task.started_at = None;
+            task_repo.create(&task).await.unwrap();
+            orphaned.push(task.id);
+        }
+
+        let handler = StartupCatchUpHandler::new(
+            task_repo.clone(),
+            goal_repo,
+            Arc::new(InMemoryEventStore::new()),
+            300,
+            100,
+        )
+        .with_concurrency(8);
+
+        let event = UnifiedEvent {
+            id: EventId::new(),
+            sequence: SequenceNumber(0),
+            timestamp: chrono::Utc::now(),
+            severity: EventSeverity::Info,
+            category: EventCategory::Orchestrator,
+            goal_id: None,
+            task_id: None,
+            correlation_id: None,
+            source_process_id: None,
+            payload: EventPayload::OrchestratorStarted,
+        };
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        let Reaction::EmitEvents(events) = handler.handle(&event, &ctx).await.unwrap() else {
+            panic!("expected emitted events");
+        };
+
+        let fixed = events.iter().find_map(|e| match e.payload {
+            EventPayload::StartupCatchUpCompleted {
+                orphaned_tasks_fixed,
+                ..
+            } => Some(orphaned_tasks_fixed),
+            _ => None,
+        });
+        assert_eq!(fixed, Some(50));
+        let failed_events = events
+            .iter()
+            .filter(|e| matches!(e.payload, EventPayload::TaskFailed { .. }))
+            .count();
+        assert_eq!(failed_events, 50);
+
+        for id in orphaned {
+            let task = task_repo.get(id).await.unwrap().unwrap();
+            assert_eq!(task.status, TaskStatus::Failed);
+            assert_eq!(task.retry_count, 1);
+        }
+    }
+}
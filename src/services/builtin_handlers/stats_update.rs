@@ -22,6 +22,7 @@ use crate::domain::ports::{
     GoalRepository, MemoryRepository, TaskRepository, TaskScheduleRepository, TrajectoryRepository,
     WorktreeRepository,
 };
+use crate::services::circuit_breaker::CircuitBreakerService;
 #[cfg(test)]
 use crate::services::event_bus::ConvergenceTerminatedPayload;
 use crate::services::event_bus::{
@@ -36,6 +37,7 @@ use crate::services::event_store::EventStore;
 use crate::services::goal_context_service::GoalContextService;
 use crate::services::memory_service::MemoryService;
 use crate::services::swarm_orchestrator::SwarmStats;
+use crate::services::swarm_orchestrator::agent_slots::AgentSlots;
 use crate::services::task_service::TaskService;
 
 use super::{try_update_task, update_with_retry};
@@ -50,9 +52,9 @@ pub struct StatsUpdateHandler<G: GoalRepository, T: TaskRepository, W: WorktreeR
     task_repo: Arc<T>,
     worktree_repo: Arc<W>,
     stats: Arc<RwLock<SwarmStats>>,
-    agent_semaphore: Arc<Semaphore>,
-    max_agents: usize,
+    agent_slots: Arc<AgentSlots>,
     total_tokens: Arc<AtomicU64>,
+    circuit_breaker: Arc<CircuitBreakerService>,
 }
 
 impl<G: GoalRepository, T: TaskRepository, W: WorktreeRepository> StatsUpdateHandler<G, T, W> {
@@ -61,18 +63,18 @@ impl<G: GoalRepository, T: TaskRepository, W: WorktreeRepository> StatsUpdateHan
         task_repo: Arc<T>,
         worktree_repo: Arc<W>,
         stats: Arc<RwLock<SwarmStats>>,
-        agent_semaphore: Arc<Semaphore>,
-        max_agents: usize,
+        agent_slots: Arc<AgentSlots>,
         total_tokens: Arc<AtomicU64>,
+        circuit_breaker: Arc<CircuitBreakerService>,
     ) -> Self {
         Self {
             goal_repo,
             task_repo,
             worktree_repo,
             stats,
-            agent_semaphore,
-            max_agents,
+            agent_slots,
             total_tokens,
+            circuit_breaker,
         }
     }
 }
@@ -99,6 +101,7 @@ impl<G: GoalRepository + 'static, T: TaskRepository + 'static, W: WorktreeReposi
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -136,9 +139,10 @@ impl<G: GoalRepository + 'static, T: TaskRepository + 'static, W: WorktreeReposi
             running_tasks: *task_counts.get(&TaskStatus::Running).unwrap_or(&0) as usize,
             completed_tasks: *task_counts.get(&TaskStatus::Complete).unwrap_or(&0) as usize,
             failed_tasks: *task_counts.get(&TaskStatus::Failed).unwrap_or(&0) as usize,
-            active_agents: self.max_agents - self.agent_semaphore.available_permits(),
+            active_agents: self.agent_slots.active_agents(),
             active_worktrees,
             total_tokens_used: self.total_tokens.load(Ordering::Relaxed),
+            half_open_circuits: self.circuit_breaker.get_half_open_circuits().await.len(),
         };
 
         {
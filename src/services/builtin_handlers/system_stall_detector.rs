@@ -95,6 +95,7 @@ impl<T: TaskRepository + 'static> EventHandler for SystemStallDetectorHandler<T>
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -85,6 +85,7 @@ impl<T: TaskRepository + 'static> EventHandler for TaskCompletedReadinessHandler
             priority: HandlerPriority::SYSTEM,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: true,
+            singleton: false,
         }
     }
 
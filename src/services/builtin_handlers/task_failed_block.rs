@@ -166,6 +166,7 @@ impl<T: TaskRepository + 'static> EventHandler for TaskFailedBlockHandler<T> {
             priority: HandlerPriority::SYSTEM,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: true,
+            singleton: false,
         }
     }
 
@@ -8,6 +8,7 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 use async_trait::async_trait;
 use tokio::sync::{RwLock, Semaphore};
@@ -22,6 +23,7 @@ use crate::domain::ports::{
     GoalRepository, MemoryRepository, TaskRepository, TaskScheduleRepository, TrajectoryRepository,
     WorktreeRepository,
 };
+use crate::services::config::RetryBackoffConfig;
 #[cfg(test)]
 use crate::services::event_bus::ConvergenceTerminatedPayload;
 use crate::services::event_bus::{
@@ -44,11 +46,43 @@ use super::{try_update_task, update_with_retry};
 // TaskFailedRetryHandler
 // ============================================================================
 
+/// Delay before retrying a task that has already been retried `retry_count`
+/// times: `base * 2^retry_count`, capped at `cap`.
+///
+/// `jitter` is a sample in `[0, 1]` scaling the capped delay ("full
+/// jitter"); `None` disables jitter.
+pub fn next_retry_delay(
+    retry_count: u32,
+    base: Duration,
+    cap: Duration,
+    jitter: Option<f64>,
+) -> Duration {
+    let capped = base
+        .saturating_mul(2u32.saturating_pow(retry_count))
+        .min(cap);
+    match jitter {
+        Some(sample) => capped.mul_f64(sample.clamp(0.0, 1.0)),
+        None => capped,
+    }
+}
+
+/// Jitter sample for one retry attempt. Seeded by task and attempt so every
+/// re-evaluation of the same failure (the scheduled retry-check re-runs this
+/// handler) sees the same delay instead of rerolling until one is short.
+fn jitter_sample(task_id: uuid::Uuid, retry_count: u32) -> f64 {
+    use rand::{Rng, SeedableRng};
+
+    let bits = task_id.as_u128();
+    let seed = (bits as u64) ^ ((bits >> 64) as u64) ^ u64::from(retry_count);
+    rand::rngs::StdRng::seed_from_u64(seed).gen_range(0.0..=1.0)
+}
+
 /// When a task fails with retries remaining, transition it back to Ready.
 /// Runs at NORMAL priority (after SYSTEM-priority TaskFailedBlockHandler).
 pub struct TaskFailedRetryHandler<T: TaskRepository> {
     task_repo: Arc<T>,
     max_retries: u32,
+    backoff: RetryBackoffConfig,
 }
 
 impl<T: TaskRepository> TaskFailedRetryHandler<T> {
@@ -56,8 +90,27 @@ impl<T: TaskRepository> TaskFailedRetryHandler<T> {
         Self {
             task_repo,
             max_retries,
+            backoff: RetryBackoffConfig::default(),
         }
     }
+
+    pub fn with_backoff(mut self, backoff: RetryBackoffConfig) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    fn retry_delay(&self, task: &Task) -> Duration {
+        let jitter = self
+            .backoff
+            .jitter
+            .then(|| jitter_sample(task.id, task.retry_count));
+        next_retry_delay(
+            task.retry_count,
+            Duration::from_secs(self.backoff.base_secs),
+            Duration::from_secs(self.backoff.cap_secs),
+            jitter,
+        )
+    }
 }
 
 #[async_trait]
@@ -72,6 +125,7 @@ impl<T: TaskRepository + 'static> EventHandler for TaskFailedRetryHandler<T> {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -126,6 +180,8 @@ impl<T: TaskRepository + 'static> EventHandler for TaskFailedRetryHandler<T> {
         }
 
         let is_max_turns = error.starts_with("error_max_turns");
+        let is_schema_failure =
+            error.starts_with(crate::services::output_schema::SCHEMA_VALIDATION_FAILED);
 
         // Circuit-break: tasks that repeatedly exhaust their turn budget should not retry
         // indefinitely. After MAX_CONSECUTIVE_BUDGET_FAILURES consecutive budget failures,
@@ -150,15 +206,18 @@ impl<T: TaskRepository + 'static> EventHandler for TaskFailedRetryHandler<T> {
             }
         }
 
-        // Skip exponential backoff for structural failures (max_turns) — immediate retry
-        if !is_max_turns {
-            let backoff_secs = 2u64.pow(task.retry_count.min(10));
-            if let Some(completed_at) = task.completed_at {
-                let elapsed = (chrono::Utc::now() - completed_at).num_seconds();
-                if elapsed < backoff_secs as i64 {
-                    // Not ready to retry yet; the scheduled retry-check will try again
-                    return Ok(Reaction::None);
-                }
+        // Skip exponential backoff for structural failures (max_turns, output
+        // schema) — immediate retry
+        if !is_max_turns
+            && !is_schema_failure
+            && let Some(completed_at) = task.completed_at
+        {
+            let elapsed = (chrono::Utc::now() - completed_at)
+                .to_std()
+                .unwrap_or_default();
+            if elapsed < self.retry_delay(&task) {
+                // Not ready to retry yet; the scheduled retry-check will try again
+                return Ok(Reaction::None);
             }
         }
 
@@ -183,6 +242,14 @@ impl<T: TaskRepository + 'static> EventHandler for TaskFailedRetryHandler<T> {
                 .push_hint_bounded("retry:max_turns_exceeded".to_string());
             updated.set_last_failure_reason(error);
         } else {
+            // Schema failures carry the validation errors into the next
+            // prompt so the agent can correct its output.
+            if is_schema_failure {
+                updated
+                    .context
+                    .push_hint_bounded("retry:schema_validation_failed".to_string());
+                updated.set_last_failure_reason(error);
+            }
             // Non-budget failure — reset the consecutive budget failure counter so a
             // later budget failure doesn't inherit a stale count from a different failure mode.
             updated.context.custom.remove("consecutive_budget_failures");
@@ -349,4 +416,93 @@ mod tests {
         assert_eq!(updated.status, TaskStatus::Ready);
         assert_eq!(updated.retry_count, 2);
     }
+
+    #[test]
+    fn test_next_retry_delay_doubles_up_to_cap() {
+        let base = Duration::from_secs(1);
+        let cap = Duration::from_secs(10);
+        assert_eq!(next_retry_delay(0, base, cap, None), Duration::from_secs(1));
+        assert_eq!(next_retry_delay(3, base, cap, None), Duration::from_secs(8));
+        assert_eq!(next_retry_delay(4, base, cap, None), cap);
+        assert_eq!(next_retry_delay(u32::MAX, base, cap, None), cap);
+    }
+
+    #[test]
+    fn test_next_retry_delay_full_jitter_scales_capped_delay() {
+        let base = Duration::from_secs(2);
+        let cap = Duration::from_secs(60);
+        assert_eq!(next_retry_delay(2, base, cap, Some(0.0)), Duration::ZERO);
+        assert_eq!(
+            next_retry_delay(2, base, cap, Some(0.5)),
+            Duration::from_secs(4)
+        );
+        assert_eq!(next_retry_delay(10, base, cap, Some(1.0)), cap);
+        // Out-of-range samples are clamped rather than exceeding the bound.
+        assert_eq!(
+            next_retry_delay(2, base, cap, Some(3.0)),
+            Duration::from_secs(8)
+        );
+    }
+
+    #[test]
+    fn test_jitter_sample_is_stable_per_attempt() {
+        let id = Uuid::new_v4();
+        let sample = jitter_sample(id, 2);
+        assert!((0.0..=1.0).contains(&sample));
+        assert_eq!(sample, jitter_sample(id, 2));
+    }
+
+    #[tokio::test]
+    async fn test_retry_handler_waits_out_backoff_without_jitter() {
+        let repo = setup_task_repo().await;
+        let handler =
+            TaskFailedRetryHandler::new(repo.clone(), 3).with_backoff(RetryBackoffConfig {
+                base_secs: 60,
+                cap_secs: 600,
+                jitter: false,
+            });
+
+        let mut task = Task::new("Flaky build");
+        task.max_retries = 3;
+        task.transition_to(TaskStatus::Ready).unwrap();
+        task.transition_to(TaskStatus::Running).unwrap();
+        task.transition_to(TaskStatus::Failed).unwrap();
+        task.completed_at = Some(chrono::Utc::now() - chrono::Duration::seconds(30));
+        repo.create(&task).await.unwrap();
+
+        let event = UnifiedEvent {
+            id: EventId::new(),
+            sequence: SequenceNumber(0),
+            timestamp: chrono::Utc::now(),
+            severity: EventSeverity::Error,
+            category: EventCategory::Task,
+            goal_id: None,
+            task_id: Some(task.id),
+            correlation_id: None,
+            source_process_id: None,
+            payload: EventPayload::TaskFailed {
+                task_id: task.id,
+                error: "connection reset".to_string(),
+                retry_count: 0,
+            },
+        };
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        // 30s into a 60s backoff: not yet.
+        let reaction = handler.handle(&event, &ctx).await.unwrap();
+        assert!(matches!(reaction, Reaction::None));
+        assert_eq!(
+            repo.get(task.id).await.unwrap().unwrap().status,
+            TaskStatus::Failed
+        );
+
+        let mut stored = repo.get(task.id).await.unwrap().unwrap();
+        stored.completed_at = Some(chrono::Utc::now() - chrono::Duration::seconds(61));
+        repo.update(&stored).await.unwrap();
+        let reaction = handler.handle(&event, &ctx).await.unwrap();
+        assert!(matches!(reaction, Reaction::EmitEvents(_)));
+    }
 }
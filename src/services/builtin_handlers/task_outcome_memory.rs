@@ -89,6 +89,7 @@ impl<T: TaskRepository + 'static, M: MemoryRepository + 'static> EventHandler
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
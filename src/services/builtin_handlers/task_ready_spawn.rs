@@ -73,6 +73,7 @@ impl<T: TaskRepository + 'static> EventHandler for TaskReadySpawnHandler<T> {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
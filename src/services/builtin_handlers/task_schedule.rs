@@ -110,6 +110,7 @@ impl<S: TaskScheduleRepository + 'static, T: TaskRepository + 'static> EventHand
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -220,6 +221,10 @@ impl<S: TaskScheduleRepository + 'static, T: TaskRepository + 'static> EventHand
             deadline: None,
             task_type: None,
             execution_mode: None,
+            estimate_secs: None,
+            execution_params: None,
+            required_overseers: Vec::new(),
+            workspace_mode: None,
         });
 
         let envelope =
@@ -267,3 +272,76 @@ impl<S: TaskScheduleRepository + 'static, T: TaskRepository + 'static> EventHand
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::{
+        SqliteGoalRepository, SqliteMemoryRepository, SqliteTaskRepository,
+        SqliteTaskScheduleRepository, test_support,
+    };
+    use crate::domain::ports::TaskFilter;
+    use crate::services::task_schedule_service::TaskScheduleService;
+
+    #[tokio::test]
+    async fn test_due_schedule_submits_exactly_one_task() {
+        let pool = test_support::setup_pool().await;
+        let task_repo = Arc::new(SqliteTaskRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(SqliteTaskScheduleRepository::new(pool.clone()));
+        let command_bus = test_support::make_command_bus(
+            &task_repo,
+            &Arc::new(SqliteGoalRepository::new(pool.clone())),
+            &Arc::new(SqliteMemoryRepository::new(pool.clone())),
+        );
+
+        let mut schedule = TaskSchedule::new(
+            "weekly-deps",
+            "",
+            TaskScheduleType::Cron {
+                expression: "0 0 9 * * Mon".to_string(),
+            },
+            "Update dependencies",
+            "Bump outdated crates",
+        )
+        .with_agent_type("dep-updater");
+        schedule.created_at = chrono::Utc::now() - chrono::Duration::weeks(3);
+        TaskScheduleService::new(schedule_repo.clone())
+            .create_schedule(schedule.clone())
+            .await
+            .unwrap();
+
+        // Three weekly windows were missed; the schedule is due (once).
+        let sched_event =
+            TaskScheduleService::<SqliteTaskScheduleRepository>::to_scheduled_event(&schedule);
+        assert!(sched_event.is_due(chrono::Utc::now()));
+
+        let handler =
+            TaskScheduleHandler::new(schedule_repo.clone(), task_repo.clone(), command_bus);
+        let fired = crate::services::event_factory::make_event(
+            EventSeverity::Info,
+            EventCategory::Scheduler,
+            None,
+            None,
+            EventPayload::ScheduledEventFired {
+                schedule_id: sched_event.id,
+                name: sched_event.name.clone(),
+            },
+        );
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+        handler.handle(&fired, &ctx).await.unwrap();
+        // A redelivered fire must not submit a second instance.
+        handler.handle(&fired, &ctx).await.unwrap();
+
+        let tasks = task_repo.list(TaskFilter::default()).await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].source, TaskSource::Schedule(schedule.id));
+        assert_eq!(tasks[0].agent_type.as_deref(), Some("dep-updater"));
+
+        let saved = schedule_repo.get(schedule.id).await.unwrap().unwrap();
+        assert_eq!(saved.fire_count, 1);
+        assert_eq!(saved.last_task_id, Some(tasks[0].id));
+    }
+}
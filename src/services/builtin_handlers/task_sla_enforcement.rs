@@ -89,6 +89,7 @@ impl<T: TaskRepository + 'static> EventHandler for TaskSLAEnforcementHandler<T>
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -84,6 +84,7 @@ impl EventHandler for TriggerCatchupHandler {
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -32,7 +32,7 @@ use crate::services::event_reactor::{
     ErrorStrategy, EventFilter, EventHandler, HandlerContext, HandlerId, HandlerMetadata,
     HandlerPriority, Reaction,
 };
-use crate::services::event_store::EventStore;
+use crate::services::event_store::{EventStore, HANDLER_LAG_WARN_THRESHOLD, handler_lags};
 use crate::services::goal_context_service::GoalContextService;
 use crate::services::memory_service::MemoryService;
 use crate::services::swarm_orchestrator::SwarmStats;
@@ -83,6 +83,7 @@ impl EventHandler for WatermarkAuditHandler {
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -102,35 +103,23 @@ impl EventHandler for WatermarkAuditHandler {
             None => return Ok(Reaction::None), // No events in store yet
         };
 
+        let lags = handler_lags(self.event_store.as_ref(), latest_seq, &self.handler_names)
+            .await
+            .map_err(|e| format!("WatermarkAudit: failed to get watermarks: {}", e))?;
+
         let mut behind_count = 0u32;
-        let mut max_lag: u64 = 0;
+        let max_lag = lags.iter().map(|l| l.lag).max().unwrap_or(0);
         let mut new_events = Vec::new();
 
-        for name in &self.handler_names {
-            let wm = self.event_store.get_watermark(name).await.map_err(|e| {
-                format!(
-                    "WatermarkAudit: failed to get watermark for {}: {}",
-                    name, e
-                )
-            })?;
-
-            let handler_seq = wm.map(|s| s.0).unwrap_or(0);
-            let lag = latest_seq.saturating_sub(handler_seq);
-
-            if lag > max_lag {
-                max_lag = lag;
-            }
-
-            if lag > 100 {
-                tracing::warn!(
-                    handler = %name,
-                    handler_seq = handler_seq,
-                    latest_seq = latest_seq,
-                    lag = lag,
-                    "WatermarkAudit: handler is significantly behind"
-                );
-                behind_count += 1;
-            }
+        for lag in lags.iter().filter(|l| l.is_behind()) {
+            tracing::warn!(
+                handler = %lag.handler,
+                handler_seq = lag.watermark,
+                latest_seq = latest_seq,
+                lag = lag.lag,
+                "WatermarkAudit: handler is significantly behind"
+            );
+            behind_count += 1;
         }
 
         if behind_count > 0 {
@@ -141,7 +130,7 @@ impl EventHandler for WatermarkAuditHandler {
             );
 
             // When lag > 100: trigger a catch-up sweep
-            if max_lag > 100 {
+            if max_lag > HANDLER_LAG_WARN_THRESHOLD {
                 new_events.push(UnifiedEvent {
                     id: EventId::new(),
                     sequence: SequenceNumber(0),
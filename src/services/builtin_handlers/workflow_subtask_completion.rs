@@ -89,6 +89,7 @@ impl<T: TaskRepository + 'static> EventHandler for WorkflowSubtaskCompletionHand
             priority: HandlerPriority::SYSTEM, // S4: was HIGH — raised to SYSTEM to prevent priority inversion with TaskCompletedReadinessHandler
             error_strategy: ErrorStrategy::CircuitBreak,
             critical: false,
+            singleton: false,
         }
     }
 
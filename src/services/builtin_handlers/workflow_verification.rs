@@ -86,6 +86,7 @@ impl<T: TaskRepository + 'static> EventHandler for WorkflowVerificationHandler<T
             priority: HandlerPriority::HIGH,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -46,10 +46,17 @@ use super::{try_update_task, update_with_retry};
 
 /// Triggered by the "reconciliation" scheduled event (piggybacks on existing schedule).
 /// Detects orphaned worktrees — active worktrees whose associated task is in a
-/// terminal state — and emits warning events. Does not delete worktrees.
+/// terminal state — and destroys them.
+///
+/// An orphaned worktree with uncommitted changes is left in place unless
+/// `force` is set; a human escalation is emitted (once per worktree) so the
+/// work can be salvaged.
 pub struct WorktreeReconciliationHandler<T: TaskRepository, W: WorktreeRepository> {
     task_repo: Arc<T>,
     worktree_repo: Arc<W>,
+    force: bool,
+    /// In-memory dedup: dirty worktrees already escalated.
+    escalated: RwLock<HashSet<uuid::Uuid>>,
 }
 
 impl<T: TaskRepository, W: WorktreeRepository> WorktreeReconciliationHandler<T, W> {
@@ -57,6 +64,44 @@ impl<T: TaskRepository, W: WorktreeRepository> WorktreeReconciliationHandler<T,
         Self {
             task_repo,
             worktree_repo,
+            force: false,
+            escalated: RwLock::new(HashSet::new()),
+        }
+    }
+
+    /// Destroy orphaned worktrees even when they have uncommitted changes.
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+}
+
+/// Whether the worktree at `path` has uncommitted (staged, unstaged, or
+/// untracked) changes. A missing worktree has nothing to salvage, but one git
+/// cannot read is treated as dirty: the caller would otherwise delete work it
+/// could not inspect.
+pub(crate) async fn has_uncommitted_changes(path: &str) -> bool {
+    if !std::path::Path::new(path).exists() {
+        return false;
+    }
+    match tokio::process::Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(path)
+        .output()
+        .await
+    {
+        Ok(o) if o.status.success() => !String::from_utf8_lossy(&o.stdout).trim().is_empty(),
+        Ok(o) => {
+            tracing::warn!(
+                path,
+                stderr = %String::from_utf8_lossy(&o.stderr).trim(),
+                "git status failed; treating worktree as dirty"
+            );
+            true
+        }
+        Err(e) => {
+            tracing::warn!(path, error = %e, "failed to run git status; treating worktree as dirty");
+            true
         }
     }
 }
@@ -83,6 +128,7 @@ impl<T: TaskRepository + 'static, W: WorktreeRepository + 'static> EventHandler
             priority: HandlerPriority::LOW,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -116,19 +162,64 @@ impl<T: TaskRepository + 'static, W: WorktreeRepository + 'static> EventHandler
             };
 
             if is_orphaned {
-                orphan_count += 1;
-
                 let reason = match &task {
                     Some(t) => format!("task in terminal state: {}", t.status.as_str()),
                     None => "task not found".to_string(),
                 };
 
+                // A task that failed mid-edit leaves uncommitted work behind.
+                // Keep the worktree and ask a human instead of silently
+                // discarding it.
+                if !self.force && has_uncommitted_changes(&wt.path).await {
+                    if !self.escalated.write().await.insert(wt.id) {
+                        continue;
+                    }
+
+                    tracing::warn!(
+                        worktree_id = %wt.id,
+                        task_id = %wt.task_id,
+                        path = %wt.path,
+                        reason = %reason,
+                        "WorktreeReconciliation: orphaned worktree has uncommitted changes, skipping cleanup"
+                    );
+
+                    new_events.push(UnifiedEvent {
+                        id: EventId::new(),
+                        sequence: SequenceNumber(0),
+                        timestamp: chrono::Utc::now(),
+                        severity: EventSeverity::Warning,
+                        category: EventCategory::Escalation,
+                        goal_id: None,
+                        task_id: Some(wt.task_id),
+                        correlation_id: event.correlation_id,
+                        source_process_id: None,
+                        payload: EventPayload::HumanEscalationRequired(HumanEscalationPayload {
+                            goal_id: None,
+                            task_id: Some(wt.task_id),
+                            reason: format!(
+                                "Orphaned worktree at {} ({}) has uncommitted changes",
+                                wt.path, reason
+                            ),
+                            urgency: "medium".to_string(),
+                            questions: vec![format!(
+                                "Should the uncommitted work in {} be salvaged before the worktree is removed?",
+                                wt.path
+                            )],
+                            is_blocking: false,
+                        }),
+                    });
+                    continue;
+                }
+
+                orphan_count += 1;
+
                 // Actually destroy the orphaned worktree on disk BEFORE marking
                 // it Removed in the DB, so operators don't end up with stale
                 // on-disk worktrees that the DB thinks are gone. `--force`
-                // because the task is in a terminal state — we don't care
-                // about dirty state at this point. On failure we log and
-                // continue: the DB reconciliation must still proceed.
+                // because the task is in a terminal state and any dirty state
+                // was either checked above or explicitly overridden. On
+                // failure we log and continue: the DB reconciliation must
+                // still proceed.
                 match tokio::process::Command::new("git")
                     .args(["worktree", "remove", "--force", &wt.path])
                     .output()
@@ -229,3 +320,110 @@ impl<T: TaskRepository + 'static, W: WorktreeRepository + 'static> EventHandler
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::test_support::setup_pool;
+    use crate::adapters::sqlite::{SqliteTaskRepository, SqliteWorktreeRepository};
+    use crate::domain::models::{Worktree, WorktreeStatus};
+
+    fn make_reconciliation_event() -> UnifiedEvent {
+        UnifiedEvent {
+            id: EventId::new(),
+            sequence: SequenceNumber(0),
+            timestamp: chrono::Utc::now(),
+            severity: EventSeverity::Info,
+            category: EventCategory::Scheduler,
+            goal_id: None,
+            task_id: None,
+            correlation_id: None,
+            source_process_id: None,
+            payload: EventPayload::ScheduledEventFired {
+                schedule_id: uuid::Uuid::new_v4(),
+                name: "reconciliation".to_string(),
+            },
+        }
+    }
+
+    async fn run_git(dir: &std::path::Path, args: &[&str]) {
+        let out = tokio::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .await
+            .expect("git command");
+        assert!(out.status.success(), "git {:?} failed", args);
+    }
+
+    #[tokio::test]
+    async fn test_dirty_orphaned_worktree_is_escalated_not_removed() {
+        let pool = setup_pool().await;
+        let task_repo = Arc::new(SqliteTaskRepository::new(pool.clone()));
+        let worktree_repo = Arc::new(SqliteWorktreeRepository::new(pool));
+
+        let mut task = Task::new("edit some files");
+        task.status = TaskStatus::Failed;
+        task_repo.create(&task).await.unwrap();
+
+        // Fixture worktree: a committed repo with an uncommitted edit on top.
+        let dir = tempfile::TempDir::new().unwrap();
+        run_git(dir.path(), &["init", "-q"]).await;
+        run_git(dir.path(), &["config", "user.email", "test@test.com"]).await;
+        run_git(dir.path(), &["config", "user.name", "Test"]).await;
+        std::fs::write(dir.path().join("README"), "init").unwrap();
+        run_git(dir.path(), &["add", "."]).await;
+        run_git(dir.path(), &["commit", "-q", "-m", "initial"]).await;
+        std::fs::write(dir.path().join("README"), "half-finished edit").unwrap();
+
+        let path = dir.path().to_str().unwrap();
+        let mut wt = Worktree::new(task.id, path, "abathur/task-dirty", "main");
+        wt.activate();
+        worktree_repo.create(&wt).await.unwrap();
+
+        let handler = WorktreeReconciliationHandler::new(task_repo, worktree_repo.clone());
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+
+        let events = match handler
+            .handle(&make_reconciliation_event(), &ctx)
+            .await
+            .unwrap()
+        {
+            Reaction::EmitEvents(events) => events,
+            Reaction::None => panic!("expected an escalation"),
+        };
+        assert_eq!(events.len(), 1);
+        match &events[0].payload {
+            EventPayload::HumanEscalationRequired(p) => assert_eq!(p.task_id, Some(task.id)),
+            other => panic!("expected HumanEscalationRequired, got {:?}", other),
+        }
+
+        let stored = worktree_repo.get(wt.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, WorktreeStatus::Active);
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("README")).unwrap(),
+            "half-finished edit"
+        );
+
+        // The escalation is raised once, not on every sweep.
+        let reaction = handler
+            .handle(&make_reconciliation_event(), &ctx)
+            .await
+            .unwrap();
+        assert!(matches!(reaction, Reaction::None));
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_worktree_counts_as_dirty() {
+        let missing = tempfile::TempDir::new().unwrap();
+        let missing_path = missing.path().join("gone");
+        assert!(!has_uncommitted_changes(missing_path.to_str().unwrap()).await);
+
+        // Not a git checkout, so `git status` fails.
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(has_uncommitted_changes(dir.path().to_str().unwrap()).await);
+    }
+}
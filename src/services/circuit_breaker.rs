@@ -17,8 +17,9 @@ pub struct CircuitBreakerConfig {
     pub failure_threshold: u32,
     /// Duration to keep circuit open before trying half-open.
     pub open_timeout: Duration,
-    /// Number of successful calls in half-open state to close circuit.
-    pub success_threshold: u32,
+    /// Trial calls admitted while half-open. The circuit closes once this
+    /// many succeed in a row and re-opens on the first failure.
+    pub half_open_max_probes: u32,
     /// Window size for tracking failures (older failures are forgotten).
     pub failure_window: Duration,
     /// Whether to enable circuit breakers.
@@ -30,7 +31,7 @@ impl Default for CircuitBreakerConfig {
         Self {
             failure_threshold: 5,
             open_timeout: Duration::minutes(5),
-            success_threshold: 2,
+            half_open_max_probes: 2,
             failure_window: Duration::minutes(10),
             enabled: true,
         }
@@ -43,7 +44,7 @@ impl CircuitBreakerConfig {
         Self {
             failure_threshold: 3,
             open_timeout: Duration::minutes(2),
-            success_threshold: 1,
+            half_open_max_probes: 1,
             failure_window: Duration::minutes(5),
             enabled: true,
         }
@@ -54,7 +55,7 @@ impl CircuitBreakerConfig {
         Self {
             failure_threshold: 10,
             open_timeout: Duration::minutes(10),
-            success_threshold: 3,
+            half_open_max_probes: 3,
             failure_window: Duration::minutes(15),
             enabled: true,
         }
@@ -93,6 +94,8 @@ pub enum CircuitScope {
     Agent(String),
     /// Circuit for a specific operation type.
     Operation(String),
+    /// Circuit for an LLM substrate, keyed by substrate name.
+    Substrate(String),
     /// Global circuit (affects everything).
     Global,
 }
@@ -109,6 +112,10 @@ impl CircuitScope {
     pub fn operation(name: impl Into<String>) -> Self {
         Self::Operation(name.into())
     }
+
+    pub fn substrate(name: impl Into<String>) -> Self {
+        Self::Substrate(name.into())
+    }
 }
 
 /// A failure record for tracking purposes.
@@ -148,6 +155,8 @@ pub struct CircuitBreaker {
     pub failures: Vec<FailureRecord>,
     /// Successful calls in half-open state.
     pub half_open_successes: u32,
+    /// Trial calls admitted in the current half-open phase.
+    pub half_open_probes: u32,
     /// When the circuit was opened.
     pub opened_at: Option<DateTime<Utc>>,
     /// When state last changed.
@@ -164,6 +173,7 @@ impl CircuitBreaker {
             state: CircuitState::Closed,
             failures: Vec::new(),
             half_open_successes: 0,
+            half_open_probes: 0,
             opened_at: None,
             state_changed_at: Utc::now(),
             open_count: 0,
@@ -193,7 +203,7 @@ impl CircuitBreaker {
     pub fn record_success(&mut self, config: &CircuitBreakerConfig) {
         if self.state == CircuitState::HalfOpen {
             self.half_open_successes += 1;
-            if self.half_open_successes >= config.success_threshold {
+            if self.half_open_successes >= config.half_open_max_probes {
                 self.close();
             }
         }
@@ -205,6 +215,7 @@ impl CircuitBreaker {
         self.opened_at = Some(Utc::now());
         self.state_changed_at = Utc::now();
         self.half_open_successes = 0;
+        self.half_open_probes = 0;
         self.open_count += 1;
     }
 
@@ -214,6 +225,7 @@ impl CircuitBreaker {
         self.opened_at = None;
         self.state_changed_at = Utc::now();
         self.half_open_successes = 0;
+        self.half_open_probes = 0;
         self.failures.clear();
     }
 
@@ -222,6 +234,17 @@ impl CircuitBreaker {
         self.state = CircuitState::HalfOpen;
         self.state_changed_at = Utc::now();
         self.half_open_successes = 0;
+        self.half_open_probes = 0;
+    }
+
+    /// Admit a trial call if the half-open probe budget has room.
+    fn admit_probe(&mut self, config: &CircuitBreakerConfig) -> bool {
+        if self.half_open_probes < config.half_open_max_probes {
+            self.half_open_probes += 1;
+            true
+        } else {
+            false
+        }
     }
 
     /// Check if the circuit allows requests.
@@ -233,7 +256,7 @@ impl CircuitBreaker {
                 if let Some(opened_at) = self.opened_at {
                     if Utc::now() > opened_at + config.open_timeout {
                         self.half_open();
-                        true // Allow one test request
+                        self.admit_probe(config)
                     } else {
                         false
                     }
@@ -241,7 +264,32 @@ impl CircuitBreaker {
                     false
                 }
             }
-            CircuitState::HalfOpen => true, // Allow test requests
+            CircuitState::HalfOpen => {
+                // Probes that never report back would otherwise wedge the
+                // circuit; start a fresh round after another timeout.
+                if self.half_open_probes >= config.half_open_max_probes
+                    && Utc::now() > self.state_changed_at + config.open_timeout
+                {
+                    self.half_open();
+                }
+                self.admit_probe(config)
+            }
+        }
+    }
+
+    /// Whether [`allows`](Self::allows) would admit a request right now,
+    /// without moving the circuit to half-open or taking a probe slot.
+    pub fn would_allow(&self, config: &CircuitBreakerConfig) -> bool {
+        let probe_round_due = |since: DateTime<Utc>| {
+            Utc::now() > since + config.open_timeout && config.half_open_max_probes > 0
+        };
+        match self.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => self.opened_at.is_some_and(probe_round_due),
+            CircuitState::HalfOpen => {
+                self.half_open_probes < config.half_open_max_probes
+                    || probe_round_due(self.state_changed_at)
+            }
         }
     }
 
@@ -504,14 +552,34 @@ impl CircuitBreakerService {
                 CircuitCheckResult::Allowed
             }
         } else {
+            // A half-open circuit with its probes in flight reopens its
+            // probe budget one timeout after entering half-open.
+            let retry_after = if circuit.state == CircuitState::HalfOpen {
+                circuit.state_changed_at + self.config.open_timeout
+            } else {
+                circuit.opened_at.unwrap_or_else(Utc::now) + self.config.open_timeout
+            };
             CircuitCheckResult::Blocked {
                 scope,
                 opened_at: circuit.opened_at.unwrap_or_else(Utc::now),
-                retry_after: circuit.opened_at.unwrap_or_else(Utc::now) + self.config.open_timeout,
+                retry_after,
             }
         }
     }
 
+    /// Whether [`check`](Self::check) would allow a request for `scope`,
+    /// without admitting one. Use it to filter candidates; only `check` the
+    /// one actually used, so half-open probe slots are not spent on the rest.
+    pub async fn would_allow(&self, scope: &CircuitScope) -> bool {
+        if !self.config.enabled {
+            return true;
+        }
+        let circuits = self.circuits.read().await;
+        circuits
+            .get(scope)
+            .is_none_or(|circuit| circuit.would_allow(&self.config))
+    }
+
     /// Record a failure for the given scope.
     pub async fn record_failure(&self, scope: CircuitScope, error: impl Into<String>) {
         if !self.config.enabled {
@@ -604,10 +672,19 @@ impl CircuitBreakerService {
 
     /// Get open circuits.
     pub async fn get_open_circuits(&self) -> Vec<CircuitScope> {
+        self.circuits_in_state(CircuitState::Open).await
+    }
+
+    /// Get half-open circuits (probing for recovery).
+    pub async fn get_half_open_circuits(&self) -> Vec<CircuitScope> {
+        self.circuits_in_state(CircuitState::HalfOpen).await
+    }
+
+    async fn circuits_in_state(&self, state: CircuitState) -> Vec<CircuitScope> {
         let circuits = self.circuits.read().await;
         circuits
             .iter()
-            .filter(|(_, c)| c.state == CircuitState::Open)
+            .filter(|(_, c)| c.state == state)
             .map(|(s, _)| s.clone())
             .collect()
     }
@@ -771,7 +848,7 @@ mod tests {
         let mut circuit = CircuitBreaker::new(CircuitScope::Global);
         let config = CircuitBreakerConfig {
             failure_threshold: 2,
-            success_threshold: 2,
+            half_open_max_probes: 2,
             ..Default::default()
         };
 
@@ -825,6 +902,9 @@ mod tests {
 
         let op_scope = CircuitScope::operation("file-write");
         assert!(matches!(op_scope, CircuitScope::Operation(_)));
+
+        let substrate_scope = CircuitScope::substrate("claude_code");
+        assert!(matches!(substrate_scope, CircuitScope::Substrate(_)));
     }
 
     #[test]
@@ -877,6 +957,46 @@ mod tests {
         assert!(result.is_blocked());
     }
 
+    #[tokio::test]
+    async fn test_half_open_admits_limited_probes() {
+        let service = CircuitBreakerService::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_timeout: Duration::milliseconds(50),
+            half_open_max_probes: 2,
+            ..Default::default()
+        });
+        let scope = CircuitScope::substrate("flaky");
+
+        service.record_failure(scope.clone(), "down").await;
+        assert!(service.check(scope.clone()).await.is_blocked());
+        tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+
+        // Two trial calls, then nothing until they report back.
+        for _ in 0..2 {
+            let result = service.check(scope.clone()).await;
+            assert!(matches!(result, CircuitCheckResult::Testing { .. }));
+        }
+        assert!(service.check(scope.clone()).await.is_blocked());
+        assert_eq!(service.get_half_open_circuits().await, vec![scope.clone()]);
+
+        // Closing takes as many consecutive successes as there are probes.
+        service.record_success(scope.clone()).await;
+        assert_eq!(
+            service.get_state(&scope).await,
+            Some(CircuitState::HalfOpen)
+        );
+        service.record_success(scope.clone()).await;
+        assert_eq!(service.get_state(&scope).await, Some(CircuitState::Closed));
+        assert!(service.get_half_open_circuits().await.is_empty());
+
+        // A failing probe re-opens immediately.
+        service.record_failure(scope.clone(), "down again").await;
+        tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+        assert!(service.check(scope.clone()).await.is_allowed());
+        service.record_failure(scope.clone(), "still down").await;
+        assert_eq!(service.get_state(&scope).await, Some(CircuitState::Open));
+    }
+
     #[tokio::test]
     async fn test_circuit_breaker_stats() {
         let service = CircuitBreakerService::with_defaults();
@@ -14,9 +14,9 @@ use uuid::Uuid;
 use crate::adapters::sqlite::tx_context::{self, SharedTx};
 use crate::domain::errors::DomainError;
 use crate::domain::models::{
-    AccessorId, ExecutionMode, Goal, GoalConstraint, GoalPriority, GoalStatus, Memory,
-    MemoryMetadata, MemoryTier, MemoryType, Task, TaskContext, TaskPriority, TaskSource,
-    TaskStatus, TaskType,
+    AccessorId, ExecutionMode, ExecutionParameters, Goal, GoalConstraint, GoalPriority, GoalStatus,
+    Memory, MemoryMetadata, MemoryTier, MemoryType, Task, TaskContext, TaskPriority, TaskSource,
+    TaskStatus, TaskType, WorkspaceMode,
 };
 use crate::domain::ports::OutboxRepository;
 use crate::services::event_bus::{EventBus, UnifiedEvent};
@@ -120,6 +120,9 @@ pub enum DomainCommand {
 }
 
 /// Task mutation commands.
+// `Submit` is already trimmed by boxing its rarely-set fields; boxing
+// `source` as well would touch every submission site for a few bytes.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum TaskCommand {
     Submit {
@@ -135,6 +138,15 @@ pub enum TaskCommand {
         deadline: Option<chrono::DateTime<chrono::Utc>>,
         task_type: Option<TaskType>,
         execution_mode: Option<ExecutionMode>,
+        /// Effort estimate in seconds, used for velocity reporting.
+        estimate_secs: Option<u64>,
+        /// Substrate parameter overrides applied at spawn time. Boxed to
+        /// keep the `Submit` variant from inflating every `TaskCommand`.
+        execution_params: Option<Box<ExecutionParameters>>,
+        /// Overseers that must pass once the agent completes (direct mode).
+        required_overseers: Vec<String>,
+        /// Worktree vs repo-root selection; `None` means auto.
+        workspace_mode: Option<WorkspaceMode>,
     },
     Claim {
         task_id: Uuid,
@@ -151,6 +163,12 @@ pub enum TaskCommand {
     Retry {
         task_id: Uuid,
     },
+    /// Submit a copy of a completed task, linked back via `supersedes`.
+    Rerun {
+        task_id: Uuid,
+        /// Start from a fresh worktree instead of inheriting the original's.
+        fresh: bool,
+    },
     Cancel {
         task_id: Uuid,
         reason: String,
@@ -172,6 +190,13 @@ pub enum TaskCommand {
         new_status: TaskStatus,
         reason: String,
     },
+    /// Reset a stuck Running/Validating/Failed/Blocked task back to Ready or
+    /// Pending, counting it as a retry and killing any live agent.
+    Restart {
+        task_id: Uuid,
+        to: TaskStatus,
+        reason: String,
+    },
 }
 
 /// Goal mutation commands.
@@ -244,6 +269,9 @@ pub enum MemoryCommand {
 // ---------------------------------------------------------------------------
 
 /// Typed result of command execution.
+// Results are short-lived and moved straight to the caller; boxing `Task`
+// would ripple through every handler for no measurable gain.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
 pub enum CommandResult {
     Task(Task),
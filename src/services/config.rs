@@ -1,6 +1,9 @@
 //! Configuration management for the Abathur swarm system.
 
+use crate::adapters::substrates::RoutingPolicy;
+use crate::domain::models::{DecayFunction, MemoryRetrievalStrategy, MemoryType, SubstrateType};
 use crate::domain::models::workflow_template::WorkflowTemplate;
+use crate::services::memory_service::NamespacePolicy;
 use crate::services::swarm_orchestrator::PollingConfig;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -14,10 +17,19 @@ pub enum ConfigError {
     ReadError(#[from] std::io::Error),
     #[error("Failed to parse configuration: {0}")]
     ParseError(#[from] toml::de::Error),
+    #[error("Failed to parse configuration: {0}")]
+    SyntaxError(#[from] toml_edit::TomlError),
     #[error("Validation failed for {field}: {reason}")]
     ValidationError { field: String, reason: String },
 }
 
+/// Ports `abathur swarm start --with-mcp-servers` binds unless overridden.
+pub const DEFAULT_MCP_PORTS: [(&str, u16); 3] =
+    [("memory", 9100), ("tasks", 9101), ("events", 9102)];
+
+/// Upper bound on `limits.max_retries`.
+const MAX_SANE_RETRIES: u32 = 20;
+
 /// Default workflow name.
 fn default_workflow_name() -> String {
     "code".to_string()
@@ -28,9 +40,18 @@ fn default_workflows_dir() -> String {
     ".abathur/workflows".to_string()
 }
 
+/// Default schema version for configs built in code.
+fn default_config_version() -> u32 {
+    crate::services::config_migration::CURRENT_CONFIG_VERSION
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Config {
+    /// Schema version; see `config_migration`. Absent in files written
+    /// before versioning, which are treated as version 1.
+    #[serde(default = "default_config_version")]
+    pub version: u32,
     pub limits: LimitsConfig,
     pub spawn_limits: SpawnLimitsConfig,
     pub restructure_limits: RestructureLimitsConfig,
@@ -68,11 +89,54 @@ pub struct Config {
     /// Quiet window definitions loaded from config file.
     #[serde(default)]
     pub quiet_windows: Vec<QuietWindowConfig>,
+    /// Authentication for the MCP/HTTP servers.
+    #[serde(default)]
+    pub http_auth: HttpAuthConfig,
+    /// Per-agent-type model escalation ladders for retries.
+    #[serde(default)]
+    pub model_escalation: ModelEscalationConfig,
+    /// Delay before a failed task is automatically retried.
+    #[serde(default)]
+    pub retry_backoff: RetryBackoffConfig,
+    /// Per-agent-type memory retrieval strategies for prompt assembly.
+    #[serde(default)]
+    pub memory_retrieval: MemoryRetrievalConfig,
+    /// Keyword rules that choose an agent type for unassigned tasks.
+    #[serde(default)]
+    pub task_routing: TaskRoutingConfig,
+    /// Policies a task must satisfy before it is accepted for submission.
+    #[serde(default)]
+    pub task_validation: TaskValidationConfig,
+    /// Reuse of agent results for repeated idempotent tasks.
+    #[serde(default)]
+    pub result_cache: ResultCacheConfig,
+    /// Where human escalations are announced, by urgency.
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+    /// Per-substrate overrides keyed by substrate name (e.g. `claude_code`).
+    #[serde(default)]
+    pub substrates: std::collections::HashMap<String, SubstrateTomlConfig>,
+    /// Spread agent spawns over several substrates instead of preferring the
+    /// primary. The pool is the primary plus every `[substrates.*]` entry
+    /// with a `weight`.
+    ///
+    /// ```toml
+    /// substrate_routing = "weighted_round_robin"
+    ///
+    /// [substrates.claude_code]
+    /// weight = 3
+    ///
+    /// [substrates.anthropic_api]
+    /// weight = 1
+    /// ```
+    #[serde(default)]
+    pub substrate_routing: Option<RoutingPolicy>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            version: default_config_version(),
             limits: LimitsConfig::default(),
             spawn_limits: SpawnLimitsConfig::default(),
             restructure_limits: RestructureLimitsConfig::default(),
@@ -92,6 +156,16 @@ impl Default for Config {
             workflows_dir: default_workflows_dir(),
             scheduling: SchedulingConfig::default(),
             quiet_windows: Vec::new(),
+            http_auth: HttpAuthConfig::default(),
+            model_escalation: ModelEscalationConfig::default(),
+            retry_backoff: RetryBackoffConfig::default(),
+            memory_retrieval: MemoryRetrievalConfig::default(),
+            task_routing: TaskRoutingConfig::default(),
+            task_validation: TaskValidationConfig::default(),
+            result_cache: ResultCacheConfig::default(),
+            notifications: NotificationsConfig::default(),
+            substrates: std::collections::HashMap::new(),
+            substrate_routing: None,
         }
     }
 }
@@ -109,6 +183,19 @@ pub struct LimitsConfig {
     /// Shorter than Running timeout because Validating should resolve quickly.
     /// Default: 1800 (30 minutes).
     pub stale_validating_timeout_secs: u64,
+    /// Maximum agent spawns in any sliding one-minute window. Unlike the
+    /// concurrency caps this bounds the spawn *rate*, so a retry loop of
+    /// short-lived agents cannot run away. Default: unlimited.
+    pub max_agent_spawns_per_minute: Option<u32>,
+    /// Maximum agent spawns in any sliding one-hour window. Default: unlimited.
+    pub max_agent_spawns_per_hour: Option<u32>,
+    /// Concurrency slots each agent type occupies, keyed by agent type.
+    /// Unlisted agent types take one slot. Default: empty.
+    pub agent_slot_weights: std::collections::HashMap<String, u32>,
+    /// Maximum concurrently running agents of a given type, keyed by agent
+    /// type. Unlisted agent types are bounded only by the global cap.
+    /// Default: empty.
+    pub max_agents_per_type: std::collections::HashMap<String, usize>,
 }
 
 impl Default for LimitsConfig {
@@ -121,6 +208,10 @@ impl Default for LimitsConfig {
             max_retries: 3,
             task_timeout_secs: 300,
             stale_validating_timeout_secs: 1800,
+            max_agent_spawns_per_minute: None,
+            max_agent_spawns_per_hour: None,
+            agent_slot_weights: std::collections::HashMap::new(),
+            max_agents_per_type: std::collections::HashMap::new(),
         }
     }
 }
@@ -242,6 +333,27 @@ pub struct MemoryConfig {
     pub prune_threshold: f64,
     pub maintenance_interval_secs: u64,
     pub max_per_namespace: usize,
+    /// Maximum size in bytes of a single memory's content. Larger content is
+    /// stored as a parent memory plus linked chunks of at most this size.
+    pub max_content_size: usize,
+    /// Content similarity (0.0-1.0) at which a stored memory reinforces an
+    /// existing one in the same namespace instead of being inserted.
+    /// Default: unset (dedup disabled).
+    pub dedup_similarity_threshold: Option<f64>,
+    /// Expiry and decay overrides keyed by namespace. Default: empty.
+    pub namespaces: std::collections::HashMap<String, NamespacePolicy>,
+    /// Minimum decay factor (0.0-1.0) for working memories. Decay never
+    /// takes a memory below its tier's floor, and a floored memory is not
+    /// pruned for decay. Default: 0.0 (no floor).
+    pub working_decay_floor: f32,
+    /// Minimum decay factor (0.0-1.0) for episodic memories. Default: 0.0.
+    pub episodic_decay_floor: f32,
+    /// Minimum decay factor (0.0-1.0) for semantic memories. Default: 0.0.
+    pub semantic_decay_floor: f32,
+    /// Decay curve overrides keyed by memory type, e.g.
+    /// `code = { curve = "linear" }`. Listed types replace the built-in
+    /// curve; unlisted types keep it. Default: empty.
+    pub decay_functions: std::collections::HashMap<MemoryType, DecayFunction>,
 }
 
 impl Default for MemoryConfig {
@@ -251,6 +363,13 @@ impl Default for MemoryConfig {
             prune_threshold: 0.1,
             maintenance_interval_secs: 3600,
             max_per_namespace: 10000,
+            max_content_size: crate::services::memory_service::DEFAULT_MAX_CONTENT_SIZE,
+            dedup_similarity_threshold: None,
+            namespaces: std::collections::HashMap::new(),
+            working_decay_floor: 0.0,
+            episodic_decay_floor: 0.0,
+            semantic_decay_floor: 0.0,
+            decay_functions: std::collections::HashMap::new(),
         }
     }
 }
@@ -266,6 +385,14 @@ pub struct WorktreeConfig {
     /// Whether to fetch from remote before creating worktrees and merging.
     /// Default: true. Set to false for local-only / offline development.
     pub fetch_on_sync: bool,
+    /// Whether a task whose dependency just completed reuses that
+    /// dependency's worktree, if not yet merged or cleaned up, instead of
+    /// creating a new one. Default: false.
+    pub reuse_for_chains: bool,
+    /// Whether reconciliation destroys orphaned worktrees that still have
+    /// uncommitted changes. Default: false — such worktrees are kept and
+    /// escalated to a human.
+    pub force_cleanup: bool,
 }
 
 impl Default for WorktreeConfig {
@@ -277,6 +404,8 @@ impl Default for WorktreeConfig {
             enabled: true,
             branch_prefix: "abathur/task".to_string(),
             fetch_on_sync: true,
+            reuse_for_chains: false,
+            force_cleanup: false,
         }
     }
 }
@@ -490,11 +619,48 @@ impl Default for DatabaseConfig {
     }
 }
 
+impl DatabaseConfig {
+    /// A missing parent directory is fine (`abathur init` creates it); one
+    /// that exists but cannot be written would only fail at first connect.
+    fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |reason: String| ConfigError::ValidationError {
+            field: "database.path".to_string(),
+            reason,
+        };
+        if self.path.trim().is_empty() {
+            return Err(invalid("must not be empty".to_string()));
+        }
+        if self.max_connections == 0 {
+            return Err(ConfigError::ValidationError {
+                field: "database.max_connections".to_string(),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        let parent = match Path::new(&self.path).parent() {
+            Some(p) if !p.as_os_str().is_empty() => p,
+            _ => Path::new("."),
+        };
+        match std::fs::metadata(parent) {
+            Ok(meta) if !meta.is_dir() => Err(invalid(format!(
+                "'{}' is not a directory",
+                parent.display()
+            ))),
+            Ok(meta) if meta.permissions().readonly() => Err(invalid(format!(
+                "directory '{}' is not writable",
+                parent.display()
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct LoggingConfig {
     pub level: String,
     pub format: String,
+    /// Sampling of high-volume debug/trace events.
+    pub sampling: LogSamplingConfig,
 }
 
 impl Default for LoggingConfig {
@@ -502,10 +668,631 @@ impl Default for LoggingConfig {
         Self {
             level: "info".to_string(),
             format: "pretty".to_string(),
+            sampling: LogSamplingConfig::default(),
         }
     }
 }
 
+/// `[logging.sampling]`: keep 1-in-N `debug`/`trace` events.
+///
+/// `info` and above are never sampled. Events carrying a `correlation_id`
+/// (on the event or an enclosing span) are kept or dropped as a chain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogSamplingConfig {
+    /// Keep one in this many debug/trace events. 1 keeps everything.
+    pub rate: u32,
+    /// Per-target overrides, keyed by target prefix (e.g.
+    /// `"abathur::services::event_bus"`). The longest matching prefix wins.
+    pub targets: std::collections::HashMap<String, u32>,
+}
+
+impl Default for LogSamplingConfig {
+    fn default() -> Self {
+        Self {
+            rate: 1,
+            targets: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl LogSamplingConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.rate == 0 {
+            return Err(ConfigError::ValidationError {
+                field: "logging.sampling.rate".to_string(),
+                reason: "must be greater than 0 (1 keeps every event)".to_string(),
+            });
+        }
+        if let Some((target, _)) = self.targets.iter().find(|(_, rate)| **rate == 0) {
+            return Err(ConfigError::ValidationError {
+                field: format!("logging.sampling.targets.{}", target),
+                reason: "must be greater than 0 (1 keeps every event)".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Authentication scheme for the MCP/HTTP servers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpAuthScheme {
+    /// No authentication; only safe when bound to localhost.
+    #[default]
+    AllowAll,
+    /// Shared bearer token in the `Authorization` header.
+    Bearer,
+    /// HMAC-SHA256 request signatures.
+    Hmac,
+}
+
+impl HttpAuthScheme {
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "allow_all" | "none" => Some(Self::AllowAll),
+            "bearer" => Some(Self::Bearer),
+            "hmac" => Some(Self::Hmac),
+            _ => None,
+        }
+    }
+
+    /// Parse the `ABATHUR_HTTP_AUTH_SCHEME` override, rejecting unknown values.
+    fn parse_env(val: &str) -> Result<Self, ConfigError> {
+        Self::parse_str(val).ok_or_else(|| ConfigError::ValidationError {
+            field: "ABATHUR_HTTP_AUTH_SCHEME".to_string(),
+            reason: format!(
+                "invalid value '{}' (expected allow_all, bearer or hmac)",
+                val
+            ),
+        })
+    }
+}
+
+/// Authentication applied to the memory/tasks/agents/events/A2A HTTP servers.
+///
+/// Overridable via `ABATHUR_HTTP_AUTH_SCHEME`, `ABATHUR_HTTP_AUTH_SECRET`, and
+/// `ABATHUR_HTTP_AUTH_WEBHOOK_SECRET`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HttpAuthConfig {
+    pub scheme: HttpAuthScheme,
+    /// Bearer token or HMAC key. Required unless `scheme` is `allow_all`.
+    pub secret: Option<String>,
+    /// Shared secret for the tasks webhook endpoint, sent by callers in the
+    /// `X-Abathur-Webhook-Secret` header. The endpoint is disabled when unset.
+    pub webhook_secret: Option<String>,
+}
+
+impl HttpAuthConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.scheme != HttpAuthScheme::AllowAll
+            && self.secret.as_deref().is_none_or(str::is_empty)
+        {
+            return Err(ConfigError::ValidationError {
+                field: "http_auth.secret".to_string(),
+                reason: "required when http_auth.scheme is not allow_all".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Keyword rules that pick an agent type for tasks submitted without one,
+/// and fallbacks for agent types that have no template.
+///
+/// Rules are tried in order; the first whose keyword appears in the task's
+/// title or description (case-insensitive) wins. Tasks no rule matches fall
+/// through to the built-in routing (preferred agent, tool match, overmind).
+///
+/// Each `fallbacks` entry names the agent type to try when the key has no
+/// template; entries chain, so the example below runs `rust-expert` tasks
+/// as `backend-dev`, or failing that `worker`.
+///
+/// ```toml
+/// [[task_routing.rules]]
+/// agent_type = "qa-engineer"
+/// keywords = ["failing test", "flaky", "regression"]
+///
+/// [task_routing.fallbacks]
+/// rust-expert = "backend-dev"
+/// backend-dev = "worker"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaskRoutingConfig {
+    pub rules: Vec<KeywordRoutingRule>,
+    pub fallbacks: std::collections::HashMap<String, String>,
+}
+
+/// One `[[task_routing.rules]]` entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeywordRoutingRule {
+    /// Agent template assigned when the rule matches.
+    pub agent_type: String,
+    /// Phrases matched against the task title and description.
+    pub keywords: Vec<String>,
+}
+
+impl TaskRoutingConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        for (i, rule) in self.rules.iter().enumerate() {
+            let reason = if rule.agent_type.trim().is_empty() {
+                "agent_type must not be empty"
+            } else if rule.keywords.iter().all(|k| k.trim().is_empty()) {
+                "rule must list at least one keyword"
+            } else {
+                continue;
+            };
+            return Err(ConfigError::ValidationError {
+                field: format!("task_routing.rules[{}]", i),
+                reason: reason.to_string(),
+            });
+        }
+        for (agent_type, fallback) in &self.fallbacks {
+            let reason = if fallback.trim().is_empty() {
+                "fallback must not be empty"
+            } else if self.fallbacks.contains_key(
+                self.fallback_chain(agent_type)
+                    .last()
+                    .copied()
+                    .unwrap_or(agent_type),
+            ) {
+                // The chain only stops early when it revisits a type.
+                "fallback chain loops back on itself"
+            } else {
+                continue;
+            };
+            return Err(ConfigError::ValidationError {
+                field: format!("task_routing.fallbacks.{}", agent_type),
+                reason: reason.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Agent types to try, in order, when `agent_type` has no template. Stops
+    /// before any type already in the chain.
+    pub fn fallback_chain(&self, agent_type: &str) -> Vec<&str> {
+        let mut chain: Vec<&str> = Vec::new();
+        let mut current = agent_type;
+        while let Some(next) = self.fallbacks.get(current) {
+            if next == agent_type || chain.contains(&next.as_str()) {
+                break;
+            }
+            chain.push(next);
+            current = next;
+        }
+        chain
+    }
+}
+
+/// Submission policies checked before a task enters the queue.
+///
+/// ```toml
+/// [task_validation]
+/// allowed_agent_types = ["overmind", "coder", "qa-engineer"]
+/// max_description_chars = 20000
+/// blocked_phrases = ["DROP TABLE"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaskValidationConfig {
+    /// Reject tasks whose description is empty or whitespace.
+    pub require_description: bool,
+    /// Agent types a task may name. Empty allows any.
+    pub allowed_agent_types: Vec<String>,
+    /// Reject descriptions longer than this many characters.
+    pub max_description_chars: Option<usize>,
+    /// Phrases (case-insensitive) that may not appear in the title or description.
+    pub blocked_phrases: Vec<String>,
+    /// Reject tasks whose title or description looks like it contains a
+    /// credential (API keys, tokens, private keys, password assignments).
+    pub scan_for_secrets: bool,
+}
+
+impl Default for TaskValidationConfig {
+    fn default() -> Self {
+        Self {
+            require_description: true,
+            allowed_agent_types: Vec::new(),
+            max_description_chars: None,
+            blocked_phrases: Vec::new(),
+            scan_for_secrets: true,
+        }
+    }
+}
+
+/// Cache of agent results for tasks marked cacheable.
+///
+/// A task opts in with `task create --cacheable`. When it runs with the same
+/// agent type and prompt against the same repository state as an earlier
+/// successful run within `ttl_secs`, the earlier result is reused and no agent
+/// is started. Only read-only agents are eligible, since a cached result
+/// cannot reproduce file changes.
+///
+/// ```toml
+/// [result_cache]
+/// enabled = true
+/// ttl_secs = 3600
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResultCacheConfig {
+    pub enabled: bool,
+    /// How long a cached result stays reusable.
+    pub ttl_secs: u64,
+    /// Most results kept; the least recently used are evicted first.
+    pub max_entries: u64,
+}
+
+impl Default for ResultCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttl_secs: 3600,
+            max_entries: 1000,
+        }
+    }
+}
+
+impl ResultCacheConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.ttl_secs == 0 {
+            return Err(ConfigError::ValidationError {
+                field: "result_cache.ttl_secs".to_string(),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        if self.max_entries == 0 {
+            return Err(ConfigError::ValidationError {
+                field: "result_cache.max_entries".to_string(),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl TaskValidationConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_description_chars == Some(0) {
+            return Err(ConfigError::ValidationError {
+                field: "task_validation.max_description_chars".to_string(),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        if self.allowed_agent_types.iter().any(|a| a.trim().is_empty()) {
+            return Err(ConfigError::ValidationError {
+                field: "task_validation.allowed_agent_types".to_string(),
+                reason: "agent types must not be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Escalation notification sinks and the urgency routing between them.
+///
+/// `escalation_routes` maps an escalation urgency (`low`, `medium`, `high`,
+/// `critical`) to the sinks it is sent to; urgencies without a route use
+/// `default_sinks`. The `log` sink is built in and writes to the swarm log.
+///
+/// ```toml
+/// [notifications.sinks.pager]
+/// kind = "webhook"
+/// url = "https://pager.example.com/hooks/abathur"
+///
+/// [notifications.sinks.ops]
+/// kind = "slack"
+/// url = "https://hooks.slack.com/services/T000/B000/XXXX"
+///
+/// [notifications.escalation_routes]
+/// critical = ["pager", "log"]
+/// medium = ["ops"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationsConfig {
+    /// Named sinks, referenced from `escalation_routes` and `default_sinks`.
+    pub sinks: std::collections::HashMap<String, NotificationSinkConfig>,
+    pub escalation_routes: std::collections::HashMap<String, Vec<String>>,
+    pub default_sinks: Vec<String>,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            sinks: std::collections::HashMap::new(),
+            escalation_routes: std::collections::HashMap::new(),
+            default_sinks: vec![LOG_SINK.to_string()],
+        }
+    }
+}
+
+/// Name of the built-in sink that writes escalations to the swarm log.
+pub const LOG_SINK: &str = "log";
+
+/// Escalation urgencies accepted as `escalation_routes` keys.
+const ESCALATION_URGENCIES: &[&str] = &["low", "medium", "high", "critical"];
+
+/// One `[notifications.sinks.<name>]` entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationSinkConfig {
+    pub kind: NotificationSinkKind,
+    /// Endpoint POSTed to. Required for `webhook` and `slack`.
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// How a notification sink delivers an escalation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationSinkKind {
+    /// Write to the swarm log.
+    Log,
+    /// POST the escalation as JSON (pagers, incident tools).
+    Webhook,
+    /// POST a Slack incoming-webhook message.
+    Slack,
+}
+
+impl NotificationsConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        for (name, sink) in &self.sinks {
+            if name == LOG_SINK {
+                return Err(ConfigError::ValidationError {
+                    field: format!("notifications.sinks.{}", name),
+                    reason: "`log` is a built-in sink and cannot be redefined".to_string(),
+                });
+            }
+            if sink.kind != NotificationSinkKind::Log
+                && sink.url.as_deref().is_none_or(|u| u.trim().is_empty())
+            {
+                return Err(ConfigError::ValidationError {
+                    field: format!("notifications.sinks.{}.url", name),
+                    reason: "required for webhook and slack sinks".to_string(),
+                });
+            }
+        }
+        let known = |sink: &String| sink == LOG_SINK || self.sinks.contains_key(sink);
+        for (urgency, sinks) in &self.escalation_routes {
+            if !ESCALATION_URGENCIES.contains(&urgency.as_str()) {
+                return Err(ConfigError::ValidationError {
+                    field: format!("notifications.escalation_routes.{}", urgency),
+                    reason: "urgency must be one of low, medium, high, critical".to_string(),
+                });
+            }
+            if let Some(unknown) = sinks.iter().find(|s| !known(s)) {
+                return Err(ConfigError::ValidationError {
+                    field: format!("notifications.escalation_routes.{}", urgency),
+                    reason: format!("unknown sink `{}`", unknown),
+                });
+            }
+        }
+        if let Some(unknown) = self.default_sinks.iter().find(|s| !known(s)) {
+            return Err(ConfigError::ValidationError {
+                field: "notifications.default_sinks".to_string(),
+                reason: format!("unknown sink `{}`", unknown),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Model escalation ladders keyed by agent type.
+///
+/// Each ladder lists models from weakest to strongest. After every
+/// `failures_per_step` failed attempts a retry moves one rung up, staying on
+/// the top rung once reached. Agent types without a ladder use the
+/// complexity-based model routing.
+///
+/// ```toml
+/// [model_escalation]
+/// failures_per_step = 1
+///
+/// [model_escalation.ladders]
+/// coder = ["haiku", "sonnet", "opus"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelEscalationConfig {
+    /// Failed attempts spent on each rung before escalating (default 1).
+    pub failures_per_step: u32,
+    pub ladders: std::collections::HashMap<String, Vec<String>>,
+}
+
+impl Default for ModelEscalationConfig {
+    fn default() -> Self {
+        Self {
+            failures_per_step: 1,
+            ladders: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl ModelEscalationConfig {
+    /// Ladder rung `(position, model)` for an agent type after `retry_count`
+    /// failed attempts, or `None` if the agent type has no ladder.
+    pub fn rung(&self, agent_type: &str, retry_count: u32) -> Option<(u32, &str)> {
+        let ladder = self.ladders.get(agent_type).filter(|l| !l.is_empty())?;
+        let step = retry_count / self.failures_per_step.max(1);
+        let position = step.min(ladder.len() as u32 - 1);
+        Some((position, ladder[position as usize].as_str()))
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.failures_per_step == 0 {
+            return Err(ConfigError::ValidationError {
+                field: "model_escalation.failures_per_step".to_string(),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        if let Some((agent_type, _)) = self.ladders.iter().find(|(_, l)| l.is_empty()) {
+            return Err(ConfigError::ValidationError {
+                field: format!("model_escalation.ladders.{}", agent_type),
+                reason: "ladder must list at least one model".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Exponential backoff between automatic retries of a failed task.
+///
+/// Attempt `n` waits up to `base_secs * 2^n`, capped at `cap_secs`. With
+/// `jitter` (the default) the actual wait is drawn uniformly from zero to
+/// that bound, so tasks that fail together do not all retry together.
+///
+/// ```toml
+/// [retry_backoff]
+/// base_secs = 2
+/// cap_secs = 300
+/// jitter = false
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryBackoffConfig {
+    pub base_secs: u64,
+    pub cap_secs: u64,
+    pub jitter: bool,
+}
+
+impl Default for RetryBackoffConfig {
+    fn default() -> Self {
+        Self {
+            base_secs: 1,
+            cap_secs: 1024,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryBackoffConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.cap_secs < self.base_secs {
+            return Err(ConfigError::ValidationError {
+                field: "retry_backoff.cap_secs".to_string(),
+                reason: "must be at least retry_backoff.base_secs".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Memory retrieval strategies for agent prompts, keyed by agent type.
+///
+/// Agent types without an entry use `default`, a semantic-biased blend.
+///
+/// ```toml
+/// [memory_retrieval.agents.researcher]
+/// strategy = "namespace"
+/// namespace = "research"
+///
+/// [memory_retrieval.agents.coder]
+/// strategy = "blend"
+/// semantic_weight = 0.5
+/// recency_weight = 0.4
+/// importance_weight = 0.1
+/// ```
+///
+/// Memories loaded into an agent's context are reinforced (an access is
+/// recorded, slowing their decay and counting toward promotion). At most
+/// `max_reinforced_per_retrieval` of the best-ranked memories are reinforced
+/// per task; set `reinforce_on_retrieval = false` to keep retrieval read-only.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MemoryRetrievalConfig {
+    pub default: MemoryRetrievalStrategy,
+    pub agents: std::collections::HashMap<String, MemoryRetrievalStrategy>,
+    pub reinforce_on_retrieval: bool,
+    pub max_reinforced_per_retrieval: usize,
+}
+
+impl Default for MemoryRetrievalConfig {
+    fn default() -> Self {
+        Self {
+            default: MemoryRetrievalStrategy::default(),
+            agents: std::collections::HashMap::new(),
+            reinforce_on_retrieval: true,
+            max_reinforced_per_retrieval: 5,
+        }
+    }
+}
+
+impl MemoryRetrievalConfig {
+    /// Strategy for an agent type, falling back to `default`.
+    pub fn strategy_for(&self, agent_type: &str) -> &MemoryRetrievalStrategy {
+        self.agents.get(agent_type).unwrap_or(&self.default)
+    }
+
+    /// How many retrieved memories to reinforce per task (0 when disabled).
+    pub fn reinforcement_limit(&self) -> usize {
+        if self.reinforce_on_retrieval {
+            self.max_reinforced_per_retrieval
+        } else {
+            0
+        }
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        let entries = std::iter::once(("default".to_string(), &self.default)).chain(
+            self.agents
+                .iter()
+                .map(|(agent, s)| (format!("agents.{}", agent), s)),
+        );
+        for (name, strategy) in entries {
+            let reason = match strategy {
+                MemoryRetrievalStrategy::Namespace { namespace } if namespace.is_empty() => {
+                    "namespace must not be empty"
+                }
+                MemoryRetrievalStrategy::Blend {
+                    semantic_weight,
+                    recency_weight,
+                    importance_weight,
+                } => {
+                    let weights = [*semantic_weight, *recency_weight, *importance_weight];
+                    if weights.iter().any(|w| *w < 0.0) {
+                        "blend weights must not be negative"
+                    } else if weights.iter().all(|w| *w == 0.0) {
+                        "blend weights must not all be zero"
+                    } else {
+                        continue;
+                    }
+                }
+                _ => continue,
+            };
+            return Err(ConfigError::ValidationError {
+                field: format!("memory_retrieval.{}", name),
+                reason: reason.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Settings for a single substrate.
+///
+/// ```toml
+/// [substrates.claude_code]
+/// max_context_tokens = 100000
+/// failover = ["anthropic_api"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubstrateTomlConfig {
+    /// Token budget for the assembled agent prompt. When unset, the model's
+    /// context window applies.
+    pub max_context_tokens: Option<usize>,
+    /// Substrates to run tasks on, in order, while this substrate's circuit
+    /// breaker is open.
+    pub failover: Vec<String>,
+    /// Share of spawns under `substrate_routing`; 0 keeps the substrate out
+    /// of the pool. Unset means 1 for the primary and out of the pool for
+    /// any other substrate.
+    pub weight: Option<u32>,
+}
+
 /// Configuration for the budget-aware scheduling subsystem.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
@@ -528,6 +1315,16 @@ pub struct BudgetConfig {
     pub max_agents_warning: u32,
     /// Maximum concurrent agents when pressure is Critical.
     pub max_agents_critical: u32,
+    /// Token budget applied to every goal without an override. `None`
+    /// leaves goals uncapped.
+    pub default_goal_token_budget: Option<u64>,
+    /// Per-goal token budgets, keyed by goal ID or goal name.
+    pub goal_token_budgets: std::collections::HashMap<String, u64>,
+    /// Swarm-wide token ceiling. `None` leaves the swarm uncapped.
+    pub token_ceiling: Option<u64>,
+    /// What to do once the ceiling is reached: `throttle` stops new spawns,
+    /// `hard_stop` also cancels running tasks and pauses the swarm.
+    pub policy: crate::services::budget_tracker::BudgetPolicy,
 }
 
 impl Default for BudgetConfig {
@@ -542,6 +1339,10 @@ impl Default for BudgetConfig {
             max_agents_caution: 4,
             max_agents_warning: 2,
             max_agents_critical: 1,
+            default_goal_token_budget: None,
+            goal_token_budgets: std::collections::HashMap::new(),
+            token_ceiling: None,
+            policy: crate::services::budget_tracker::BudgetPolicy::Throttle,
         }
     }
 }
@@ -554,8 +1355,14 @@ pub struct AdapterConfig {
     pub enabled: bool,
     /// Directory containing adapter definitions (relative to project root).
     pub adapters_dir: String,
-    /// Default polling interval for ingestion adapters (seconds).
+    /// Default polling interval for ingestion adapters (seconds). An adapter
+    /// overrides it with `poll_interval_secs` in its manifest `[config]`.
     pub default_poll_interval_secs: u64,
+    /// Most ingestion adapters polled concurrently.
+    pub max_concurrent_polls: usize,
+    /// Most tasks ingested across all adapters per minute. `None` disables
+    /// the limit.
+    pub max_submissions_per_minute: Option<u32>,
 }
 
 impl Default for AdapterConfig {
@@ -564,7 +1371,33 @@ impl Default for AdapterConfig {
             enabled: true,
             adapters_dir: ".abathur/adapters".to_string(),
             default_poll_interval_secs: 300,
+            max_concurrent_polls: 4,
+            max_submissions_per_minute: None,
+        }
+    }
+}
+
+impl AdapterConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.default_poll_interval_secs == 0 {
+            return Err(ConfigError::ValidationError {
+                field: "adapters.default_poll_interval_secs".to_string(),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        if self.max_concurrent_polls == 0 {
+            return Err(ConfigError::ValidationError {
+                field: "adapters.max_concurrent_polls".to_string(),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        if self.max_submissions_per_minute == Some(0) {
+            return Err(ConfigError::ValidationError {
+                field: "adapters.max_submissions_per_minute".to_string(),
+                reason: "must be greater than 0 (omit to disable)".to_string(),
+            });
         }
+        Ok(())
     }
 }
 
@@ -578,6 +1411,15 @@ pub struct SchedulingConfig {
     pub check_interval_secs: u64,
     /// Default IANA timezone for quiet windows when not specified.
     pub default_timezone: String,
+    /// Probability (0.0–1.0) that each spawn slot picks a random ready task
+    /// instead of the highest-priority one, so low-priority work still makes
+    /// progress. Default: 0.0 (strict priority order).
+    pub exploration_epsilon: f64,
+    /// Weight of wait time when ranking ready tasks: each task is ranked by
+    /// `priority + coefficient * ln(seconds waiting)`, so long-waiting work
+    /// can overtake newer higher-priority work without its stored priority
+    /// changing. Default: 0.0 (no aging).
+    pub priority_aging_coefficient: f64,
 }
 
 impl Default for SchedulingConfig {
@@ -586,6 +1428,8 @@ impl Default for SchedulingConfig {
             quiet_hours_enabled: false,
             check_interval_secs: 60,
             default_timezone: "UTC".to_string(),
+            exploration_epsilon: 0.0,
+            priority_aging_coefficient: 0.0,
         }
     }
 }
@@ -610,30 +1454,82 @@ pub struct QuietWindowConfig {
 
 impl Config {
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let config = Self::from_file_unvalidated(path)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Read and parse a config file (plus env overrides) without checking
+    /// invariants; pair with [`Config::validate_all`] to report every problem.
+    pub fn from_file_unvalidated(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
         let path = path.as_ref();
         if !path.exists() {
             return Err(ConfigError::FileNotFound(path.display().to_string()));
         }
         let content = std::fs::read_to_string(path)?;
-        let mut config: Config = toml::from_str(&content)?;
-        config.apply_env_overrides();
-        config.validate()?;
+        let (mut config, unknown) = Self::parse_str(&content)?;
+        for key in &unknown {
+            tracing::warn!(key = %key, path = %path.display(), "unknown config key ignored");
+        }
+        config.apply_env_overrides()?;
         Ok(config)
     }
 
+    /// Parse config TOML, upgrading older schema versions in memory.
+    ///
+    /// Returns the config together with the dotted paths of any keys that
+    /// did not match a known field; those are otherwise ignored.
+    pub fn parse_str(content: &str) -> Result<(Self, Vec<String>), ConfigError> {
+        let (migrated, report) = crate::services::config_migration::migrate_str(content)?;
+        if report.has_renames() {
+            let changes: Vec<String> = report.changes.iter().map(ToString::to_string).collect();
+            tracing::warn!(
+                from_version = report.from_version,
+                changes = %changes.join("; "),
+                "config uses deprecated keys; run `abathur config migrate` to upgrade it"
+            );
+        }
+
+        let mut unknown = Vec::new();
+        let config: Config =
+            serde_ignored::deserialize(toml::Deserializer::new(&migrated), |path| {
+                unknown.push(path.to_string())
+            })?;
+        Ok((config, unknown))
+    }
+
     pub fn load() -> Result<Self, ConfigError> {
         let path = Path::new("abathur.toml");
         if path.exists() {
             Self::from_file(path)
         } else {
             let mut config = Config::default();
-            config.apply_env_overrides();
+            config.apply_env_overrides()?;
             config.validate()?;
             Ok(config)
         }
     }
 
-    fn apply_env_overrides(&mut self) {
+    /// Load only the `[http_auth]` section (file + env), validating just it.
+    ///
+    /// HTTP servers use this so an unrelated config problem can never
+    /// silently downgrade them to unauthenticated.
+    pub fn load_http_auth() -> Result<HttpAuthConfig, ConfigError> {
+        let path = Path::new("abathur.toml");
+        let mut config: Config = if path.exists() {
+            toml::from_str(&std::fs::read_to_string(path)?)?
+        } else {
+            Config::default()
+        };
+        config.apply_env_overrides()?;
+        config.http_auth.validate()?;
+        Ok(config.http_auth)
+    }
+
+    /// Apply `ABATHUR_*` environment overrides. An unrecognised auth scheme
+    /// is an error rather than ignored, so a typo can never leave the HTTP
+    /// servers on a weaker scheme than the operator asked for.
+    fn apply_env_overrides(&mut self) -> Result<(), ConfigError> {
         if let Ok(val) = std::env::var("ABATHUR_LIMITS_MAX_DEPTH")
             && let Ok(v) = val.parse()
         {
@@ -648,26 +1544,202 @@ impl Config {
         if let Ok(val) = std::env::var("ABATHUR_DEFAULT_WORKFLOW") {
             self.default_workflow = val;
         }
+        if let Ok(val) = std::env::var("ABATHUR_HTTP_AUTH_SCHEME") {
+            self.http_auth.scheme = HttpAuthScheme::parse_env(&val)?;
+        }
+        if let Ok(val) = std::env::var("ABATHUR_HTTP_AUTH_SECRET") {
+            self.http_auth.secret = Some(val);
+        }
+        if let Ok(val) = std::env::var("ABATHUR_HTTP_AUTH_WEBHOOK_SECRET") {
+            self.http_auth.webhook_secret = Some(val);
+        }
+        Ok(())
     }
 
     fn validate(&self) -> Result<(), ConfigError> {
+        self.validate_all().map_err(|errors| {
+            errors
+                .into_iter()
+                .next()
+                .expect("validate_all reports at least one error")
+        })
+    }
+
+    /// Check every invariant and report all violations at once, each naming
+    /// the offending field, so a misconfiguration is caught before startup
+    /// rather than deep inside it.
+    pub fn validate_all(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
         if self.limits.max_depth == 0 {
-            return Err(ConfigError::ValidationError {
+            errors.push(ConfigError::ValidationError {
                 field: "limits.max_depth".to_string(),
                 reason: "must be greater than 0".to_string(),
             });
         }
+        for (field, limit) in [
+            (
+                "limits.max_agent_spawns_per_minute",
+                self.limits.max_agent_spawns_per_minute,
+            ),
+            (
+                "limits.max_agent_spawns_per_hour",
+                self.limits.max_agent_spawns_per_hour,
+            ),
+        ] {
+            if limit == Some(0) {
+                errors.push(ConfigError::ValidationError {
+                    field: field.to_string(),
+                    reason: "must be greater than 0 (omit to disable)".to_string(),
+                });
+            }
+        }
+        if let Some((agent_type, _)) = self
+            .limits
+            .agent_slot_weights
+            .iter()
+            .find(|(_, w)| **w == 0)
+        {
+            errors.push(ConfigError::ValidationError {
+                field: format!("limits.agent_slot_weights.{}", agent_type),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        if let Some((agent_type, _)) = self
+            .limits
+            .max_agents_per_type
+            .iter()
+            .find(|(_, max)| **max == 0)
+        {
+            errors.push(ConfigError::ValidationError {
+                field: format!("limits.max_agents_per_type.{}", agent_type),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
         if self.memory.decay_rate < 0.0 || self.memory.decay_rate > 1.0 {
-            return Err(ConfigError::ValidationError {
+            errors.push(ConfigError::ValidationError {
                 field: "memory.decay_rate".to_string(),
                 reason: "must be between 0.0 and 1.0".to_string(),
             });
         }
+        if self.memory.max_content_size == 0 {
+            errors.push(ConfigError::ValidationError {
+                field: "memory.max_content_size".to_string(),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        if let Some(threshold) = self.memory.dedup_similarity_threshold
+            && !(threshold > 0.0 && threshold <= 1.0)
+        {
+            errors.push(ConfigError::ValidationError {
+                field: "memory.dedup_similarity_threshold".to_string(),
+                reason: "must be greater than 0.0 and at most 1.0".to_string(),
+            });
+        }
+        for (namespace, policy) in &self.memory.namespaces {
+            if policy.ttl_secs == Some(0) {
+                errors.push(ConfigError::ValidationError {
+                    field: format!("memory.namespaces.{}.ttl_secs", namespace),
+                    reason: "must be greater than 0 (omit to never expire)".to_string(),
+                });
+            }
+            if !policy.decay_rate.is_finite() || policy.decay_rate < 0.0 {
+                errors.push(ConfigError::ValidationError {
+                    field: format!("memory.namespaces.{}.decay_rate", namespace),
+                    reason: "must be a non-negative number".to_string(),
+                });
+            }
+        }
+        for (field, floor) in [
+            ("working_decay_floor", self.memory.working_decay_floor),
+            ("episodic_decay_floor", self.memory.episodic_decay_floor),
+            ("semantic_decay_floor", self.memory.semantic_decay_floor),
+        ] {
+            if !(0.0..=1.0).contains(&floor) {
+                errors.push(ConfigError::ValidationError {
+                    field: format!("memory.{}", field),
+                    reason: "must be between 0.0 and 1.0".to_string(),
+                });
+            }
+        }
+        if !(0.0..=1.0).contains(&self.scheduling.exploration_epsilon) {
+            errors.push(ConfigError::ValidationError {
+                field: "scheduling.exploration_epsilon".to_string(),
+                reason: "must be between 0.0 and 1.0".to_string(),
+            });
+        }
+        let aging = self.scheduling.priority_aging_coefficient;
+        if !aging.is_finite() || aging < 0.0 {
+            errors.push(ConfigError::ValidationError {
+                field: "scheduling.priority_aging_coefficient".to_string(),
+                reason: "must be a non-negative number".to_string(),
+            });
+        }
+        errors.extend(self.logging.sampling.validate().err());
+        errors.extend(self.http_auth.validate().err());
+        errors.extend(self.model_escalation.validate().err());
+        errors.extend(self.retry_backoff.validate().err());
+        errors.extend(self.memory_retrieval.validate().err());
+        errors.extend(self.task_routing.validate().err());
+        errors.extend(self.task_validation.validate().err());
+        errors.extend(self.adapters.validate().err());
+        errors.extend(self.result_cache.validate().err());
+        errors.extend(self.notifications.validate().err());
+        if self.budget.default_goal_token_budget == Some(0) {
+            errors.push(ConfigError::ValidationError {
+                field: "budget.default_goal_token_budget".to_string(),
+                reason: "must be greater than 0 (omit to disable)".to_string(),
+            });
+        }
+        if self.budget.token_ceiling == Some(0) {
+            errors.push(ConfigError::ValidationError {
+                field: "budget.token_ceiling".to_string(),
+                reason: "must be greater than 0 (omit to disable)".to_string(),
+            });
+        }
+        if let Some((goal, _)) = self
+            .budget
+            .goal_token_budgets
+            .iter()
+            .find(|(_, b)| **b == 0)
+        {
+            errors.push(ConfigError::ValidationError {
+                field: format!("budget.goal_token_budgets.{}", goal),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        if let Some((name, _)) = self
+            .substrates
+            .iter()
+            .find(|(_, s)| s.max_context_tokens == Some(0))
+        {
+            errors.push(ConfigError::ValidationError {
+                field: format!("substrates.{}.max_context_tokens", name),
+                reason: "must be greater than 0".to_string(),
+            });
+        }
+        for (name, substrate) in &self.substrates {
+            if let Some(bad) = substrate
+                .failover
+                .iter()
+                .find(|f| SubstrateType::parse(f).is_none() || *f == name)
+            {
+                errors.push(ConfigError::ValidationError {
+                    field: format!("substrates.{}.failover", name),
+                    reason: format!("'{}' is not a known alternative substrate", bad),
+                });
+            }
+            if substrate.weight.is_some() && SubstrateType::parse(name).is_none() {
+                errors.push(ConfigError::ValidationError {
+                    field: format!("substrates.{}.weight", name),
+                    reason: format!("'{}' is not a known substrate", name),
+                });
+            }
+        }
 
         // Validate each workflow template.
         for wf in &self.workflows {
             if let Err(reason) = wf.validate() {
-                return Err(ConfigError::ValidationError {
+                errors.push(ConfigError::ValidationError {
                     field: format!("workflows.{}", wf.name),
                     reason,
                 });
@@ -678,7 +1750,7 @@ impl Config {
         let mut seen_names = std::collections::HashSet::new();
         for wf in &self.workflows {
             if !seen_names.insert(&wf.name) {
-                return Err(ConfigError::ValidationError {
+                errors.push(ConfigError::ValidationError {
                     field: "workflows".to_string(),
                     reason: format!("duplicate workflow name '{}'", wf.name),
                 });
@@ -700,7 +1772,7 @@ impl Config {
                 names.dedup();
                 names.join(", ")
             };
-            return Err(ConfigError::ValidationError {
+            errors.push(ConfigError::ValidationError {
                 field: "default_workflow".to_string(),
                 reason: format!(
                     "default workflow '{}' not found. Known workflows: [{}]. \
@@ -713,7 +1785,84 @@ impl Config {
             });
         }
 
-        Ok(())
+        if self.limits.max_concurrent_tasks == 0 {
+            errors.push(ConfigError::ValidationError {
+                field: "limits.max_concurrent_tasks".to_string(),
+                reason: "must be at least 1, otherwise no agent is ever spawned".to_string(),
+            });
+        }
+        if self.limits.max_retries > MAX_SANE_RETRIES {
+            errors.push(ConfigError::ValidationError {
+                field: "limits.max_retries".to_string(),
+                reason: format!(
+                    "must be at most {}; a task failing that often needs a human, not a retry",
+                    MAX_SANE_RETRIES
+                ),
+            });
+        }
+        if self.a2a.federation.rate_limit_per_swarm == 0 {
+            errors.push(ConfigError::ValidationError {
+                field: "a2a.federation.rate_limit_per_swarm".to_string(),
+                reason: "must be greater than 0 requests per minute".to_string(),
+            });
+        }
+        for swarm in &self.a2a.federation.trusted_swarms {
+            if swarm.rate_limit_override == Some(0) {
+                errors.push(ConfigError::ValidationError {
+                    field: format!(
+                        "a2a.federation.trusted_swarms.{}.rate_limit_override",
+                        swarm.id
+                    ),
+                    reason: "must be greater than 0 (omit to use rate_limit_per_swarm)".to_string(),
+                });
+            }
+        }
+        if self.scheduling.quiet_hours_enabled && self.scheduling.check_interval_secs == 0 {
+            errors.push(ConfigError::ValidationError {
+                field: "scheduling.check_interval_secs".to_string(),
+                reason: "must be greater than 0 when quiet hours are enabled".to_string(),
+            });
+        }
+        errors.extend(self.port_collisions());
+        errors.extend(self.database.validate().err());
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Listener ports that clash with each other or with the MCP servers'
+    /// default ports ([`DEFAULT_MCP_PORTS`]).
+    fn port_collisions(&self) -> Vec<ConfigError> {
+        let mut listeners = Vec::new();
+        if self.a2a.enabled {
+            listeners.push(("a2a.gateway_port", self.a2a.gateway_port));
+        }
+        if self.federation.enabled {
+            listeners.push(("federation.port", self.federation.port));
+        }
+
+        let mut errors = Vec::new();
+        for (i, (field, port)) in listeners.iter().enumerate() {
+            let clash = listeners[..i]
+                .iter()
+                .map(|(other, p)| (other.to_string(), *p))
+                .chain(
+                    DEFAULT_MCP_PORTS
+                        .iter()
+                        .map(|(server, p)| (format!("the {} MCP server", server), *p)),
+                )
+                .find(|(_, p)| p == port);
+            if let Some((other, _)) = clash {
+                errors.push(ConfigError::ValidationError {
+                    field: field.to_string(),
+                    reason: format!("port {} is already used by {}", port, other),
+                });
+            }
+        }
+        errors
     }
 
     /// Load YAML workflow definitions from the configured workflows directory.
@@ -810,4 +1959,488 @@ mod tests {
         assert_eq!(config.limits.max_depth, 5);
         assert_eq!(config.memory.decay_rate, 0.05);
     }
+
+    #[test]
+    fn test_http_auth_requires_secret() {
+        let mut config: Config = toml::from_str("[http_auth]\nscheme = \"bearer\"\n").unwrap();
+        assert_eq!(config.http_auth.scheme, HttpAuthScheme::Bearer);
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "http_auth.secret"
+        ));
+
+        config.http_auth.secret = Some("token".to_string());
+        assert!(!matches!(
+            config.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "http_auth.secret"
+        ));
+    }
+
+    #[test]
+    fn test_http_auth_scheme_env_rejects_unknown_values() {
+        assert_eq!(
+            HttpAuthScheme::parse_env("HMAC").unwrap(),
+            HttpAuthScheme::Hmac
+        );
+        let err = HttpAuthScheme::parse_env("bearer-token").unwrap_err();
+        assert!(err.to_string().contains("bearer-token"), "{}", err);
+    }
+
+    #[test]
+    fn test_model_escalation_ladders_from_toml() {
+        let config: Config = toml::from_str(
+            "[model_escalation]\nfailures_per_step = 2\n\n[model_escalation.ladders]\ncoder = [\"haiku\", \"opus\"]\n",
+        )
+        .unwrap();
+        assert_eq!(config.model_escalation.failures_per_step, 2);
+        assert_eq!(config.model_escalation.rung("coder", 2), Some((1, "opus")));
+        assert!(config.validate().is_ok());
+
+        let mut bad = config.clone();
+        bad.model_escalation.failures_per_step = 0;
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "model_escalation.failures_per_step"
+        ));
+    }
+
+    #[test]
+    fn test_retry_backoff_from_toml() {
+        let config: Config =
+            toml::from_str("[retry_backoff]\ncap_secs = 60\njitter = false\n").unwrap();
+        assert_eq!(config.retry_backoff.base_secs, 1);
+        assert_eq!(config.retry_backoff.cap_secs, 60);
+        assert!(!config.retry_backoff.jitter);
+        assert!(config.validate().is_ok());
+
+        let mut bad = config.clone();
+        bad.retry_backoff.base_secs = 120;
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "retry_backoff.cap_secs"
+        ));
+    }
+
+    #[test]
+    fn test_budget_hard_stop_from_toml() {
+        use crate::services::budget_tracker::BudgetPolicy;
+
+        let config: Config =
+            toml::from_str("[budget]\ntoken_ceiling = 500000\npolicy = \"hard_stop\"\n").unwrap();
+        assert_eq!(config.budget.token_ceiling, Some(500_000));
+        assert_eq!(config.budget.policy, BudgetPolicy::HardStop);
+        assert!(config.validate().is_ok());
+        assert_eq!(Config::default().budget.policy, BudgetPolicy::Throttle);
+
+        let mut bad = config.clone();
+        bad.budget.token_ceiling = Some(0);
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "budget.token_ceiling"
+        ));
+    }
+
+    #[test]
+    fn test_memory_decay_floors_from_toml() {
+        let config: Config =
+            toml::from_str("[memory]\nworking_decay_floor = 0.1\nsemantic_decay_floor = 0.5\n")
+                .unwrap();
+        assert_eq!(config.memory.working_decay_floor, 0.1);
+        assert_eq!(config.memory.episodic_decay_floor, 0.0);
+        assert_eq!(config.memory.semantic_decay_floor, 0.5);
+        assert!(config.validate().is_ok());
+
+        let mut bad = config.clone();
+        bad.memory.episodic_decay_floor = 1.5;
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "memory.episodic_decay_floor"
+        ));
+    }
+
+    #[test]
+    fn test_memory_decay_functions_from_toml() {
+        let config: Config = toml::from_str(
+            "[memory.decay_functions]\ncode = { curve = \"linear\" }\n\
+             decision = { curve = \"step_then_decay\", plateau_hours = 24.0 }\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config.memory.decay_functions[&MemoryType::Code],
+            DecayFunction::Linear
+        );
+        assert_eq!(
+            config.memory.decay_functions[&MemoryType::Decision],
+            DecayFunction::StepThenDecay {
+                plateau_hours: 24.0
+            }
+        );
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_substrate_max_context_tokens_from_toml() {
+        let config: Config =
+            toml::from_str("[substrates.claude_code]\nmax_context_tokens = 100000\n").unwrap();
+        assert_eq!(
+            config.substrates["claude_code"].max_context_tokens,
+            Some(100_000)
+        );
+        assert!(config.validate().is_ok());
+
+        let mut bad = config.clone();
+        bad.substrates
+            .get_mut("claude_code")
+            .unwrap()
+            .max_context_tokens = Some(0);
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "substrates.claude_code.max_context_tokens"
+        ));
+    }
+
+    #[test]
+    fn test_substrate_routing_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+substrate_routing = "least_recently_used"
+
+[substrates.anthropic_api]
+weight = 2
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.substrate_routing,
+            Some(RoutingPolicy::LeastRecentlyUsed)
+        );
+        assert_eq!(config.substrates["anthropic_api"].weight, Some(2));
+        assert!(config.validate().is_ok());
+        assert_eq!(Config::default().substrate_routing, None);
+
+        let mut bad = config.clone();
+        bad.substrates.insert(
+            "gpt".to_string(),
+            SubstrateTomlConfig {
+                weight: Some(1),
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "substrates.gpt.weight"
+        ));
+    }
+
+    #[test]
+    fn test_substrate_failover_from_toml() {
+        let config: Config =
+            toml::from_str("[substrates.claude_code]\nfailover = [\"anthropic_api\"]\n").unwrap();
+        assert_eq!(config.substrates["claude_code"].failover, vec!["anthropic_api"]);
+        assert!(config.validate().is_ok());
+
+        let mut bad = config.clone();
+        bad.substrates.get_mut("claude_code").unwrap().failover = vec!["gpt".to_string()];
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "substrates.claude_code.failover"
+        ));
+    }
+
+    #[test]
+    fn test_spawn_rate_limits_from_toml() {
+        let config: Config = toml::from_str("[limits]\nmax_agent_spawns_per_minute = 6\n").unwrap();
+        assert_eq!(config.limits.max_agent_spawns_per_minute, Some(6));
+        assert_eq!(config.limits.max_agent_spawns_per_hour, None);
+        assert!(config.validate().is_ok());
+
+        let mut bad = config.clone();
+        bad.limits.max_agent_spawns_per_hour = Some(0);
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "limits.max_agent_spawns_per_hour"
+        ));
+    }
+
+    #[test]
+    fn test_memory_retrieval_strategy_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+[memory_retrieval.agents.researcher]
+strategy = "namespace"
+namespace = "research"
+
+[memory_retrieval.agents.coder]
+strategy = "blend"
+semantic_weight = 0.5
+recency_weight = 0.4
+importance_weight = 0.1
+"#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.memory_retrieval.strategy_for("researcher"),
+            &MemoryRetrievalStrategy::Namespace {
+                namespace: "research".to_string()
+            }
+        );
+        assert_eq!(
+            config.memory_retrieval.strategy_for("coder").weights().decay_weight,
+            0.4
+        );
+        assert_eq!(
+            config.memory_retrieval.strategy_for("reviewer"),
+            &MemoryRetrievalStrategy::default()
+        );
+
+        let mut bad = config.clone();
+        bad.memory_retrieval.agents.insert(
+            "coder".to_string(),
+            MemoryRetrievalStrategy::Blend {
+                semantic_weight: 0.0,
+                recency_weight: 0.0,
+                importance_weight: 0.0,
+            },
+        );
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "memory_retrieval.agents.coder"
+        ));
+    }
+
+    #[test]
+    fn test_task_routing_rules_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+[[task_routing.rules]]
+agent_type = "qa-engineer"
+keywords = ["failing test", "flaky"]
+"#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.task_routing.rules[0].agent_type, "qa-engineer");
+
+        let mut bad = config.clone();
+        bad.task_routing.rules[0].keywords.clear();
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "task_routing.rules[0]"
+        ));
+    }
+
+    #[test]
+    fn test_task_routing_fallback_chain() {
+        let config: Config = toml::from_str(
+            r#"
+[task_routing.fallbacks]
+rust-expert = "backend-dev"
+backend-dev = "worker"
+"#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        let routing = &config.task_routing;
+        assert_eq!(
+            routing.fallback_chain("rust-expert"),
+            vec!["backend-dev", "worker"]
+        );
+        assert_eq!(routing.fallback_chain("backend-dev"), vec!["worker"]);
+        assert!(routing.fallback_chain("worker").is_empty());
+
+        let mut looping = config.clone();
+        looping
+            .task_routing
+            .fallbacks
+            .insert("worker".to_string(), "rust-expert".to_string());
+        assert_eq!(
+            looping.task_routing.fallback_chain("rust-expert"),
+            vec!["backend-dev", "worker"]
+        );
+        assert!(matches!(
+            looping.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field.starts_with("task_routing.fallbacks.")
+        ));
+    }
+
+    #[test]
+    fn test_task_validation_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+[task_validation]
+allowed_agent_types = ["coder"]
+scan_for_secrets = false
+"#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        assert!(config.task_validation.require_description);
+        assert!(!config.task_validation.scan_for_secrets);
+        assert_eq!(config.task_validation.allowed_agent_types, vec!["coder"]);
+
+        let mut bad = config.clone();
+        bad.task_validation.max_description_chars = Some(0);
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. })
+                if field == "task_validation.max_description_chars"
+        ));
+    }
+
+    #[test]
+    fn test_notifications_routes_must_name_known_sinks() {
+        let config: Config = toml::from_str(
+            r#"
+[notifications.sinks.pager]
+kind = "webhook"
+url = "https://pager.example.com/hook"
+
+[notifications.escalation_routes]
+critical = ["pager", "log"]
+medium = ["log"]
+"#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.notifications.default_sinks, vec!["log"]);
+
+        let mut bad = config.clone();
+        bad.notifications
+            .escalation_routes
+            .insert("high".to_string(), vec!["slack".to_string()]);
+        assert!(matches!(
+            bad.validate(),
+            Err(ConfigError::ValidationError { ref field, .. })
+                if field == "notifications.escalation_routes.high"
+        ));
+    }
+
+    fn invalid_fields(config: &Config) -> Vec<String> {
+        config
+            .validate_all()
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|e| match e {
+                ConfigError::ValidationError { field, .. } => Some(field),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_all_reports_every_problem() {
+        let mut config = Config::default();
+        config.limits.max_depth = 0;
+        config.limits.max_concurrent_tasks = 0;
+        config.memory.decay_rate = 2.0;
+
+        let fields = invalid_fields(&config);
+        assert_eq!(
+            fields,
+            vec![
+                "limits.max_depth",
+                "memory.decay_rate",
+                "limits.max_concurrent_tasks"
+            ]
+        );
+        // The fail-fast path still reports the first problem.
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::ValidationError { ref field, .. }) if field == "limits.max_depth"
+        ));
+    }
+
+    #[test]
+    fn test_max_concurrent_tasks_must_be_positive() {
+        let mut config = Config::default();
+        config.limits.max_concurrent_tasks = 0;
+        assert_eq!(invalid_fields(&config), vec!["limits.max_concurrent_tasks"]);
+
+        config.limits.max_concurrent_tasks = 1;
+        assert!(invalid_fields(&config).is_empty());
+    }
+
+    #[test]
+    fn test_max_retries_must_be_sane() {
+        let mut config = Config::default();
+        config.limits.max_retries = MAX_SANE_RETRIES;
+        assert!(invalid_fields(&config).is_empty());
+
+        config.limits.max_retries = 1000;
+        assert_eq!(invalid_fields(&config), vec!["limits.max_retries"]);
+    }
+
+    #[test]
+    fn test_rate_limit_windows_must_be_positive() {
+        let mut config = Config::default();
+        config.a2a.federation.rate_limit_per_swarm = 0;
+        let peer = TrustedSwarmConfig {
+            id: "peer".to_string(),
+            rate_limit_override: Some(0),
+            ..Default::default()
+        };
+        config.a2a.federation.trusted_swarms.push(peer);
+        config.scheduling.quiet_hours_enabled = true;
+        config.scheduling.check_interval_secs = 0;
+
+        assert_eq!(
+            invalid_fields(&config),
+            vec![
+                "a2a.federation.rate_limit_per_swarm",
+                "a2a.federation.trusted_swarms.peer.rate_limit_override",
+                "scheduling.check_interval_secs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_listener_ports_must_not_collide() {
+        let mut config = Config::default();
+        config.a2a.enabled = true;
+        config.federation.enabled = true;
+        config.federation.port = config.a2a.gateway_port;
+        assert_eq!(invalid_fields(&config), vec!["federation.port"]);
+
+        config.federation.port = 8443;
+        config.a2a.gateway_port = 9101;
+        let errors = config.validate_all().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("tasks MCP server"));
+
+        // Disabled listeners are not bound, so they cannot collide.
+        config.a2a.enabled = false;
+        assert!(invalid_fields(&config).is_empty());
+    }
+
+    #[test]
+    fn test_database_path_must_be_writable() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+
+        config.database.path = " ".to_string();
+        assert_eq!(invalid_fields(&config), vec!["database.path"]);
+
+        // Parent that does not exist yet is created by `abathur init`.
+        config.database.path = dir.path().join("new/abathur.db").display().to_string();
+        assert!(invalid_fields(&config).is_empty());
+
+        let file = dir.path().join("not-a-dir");
+        std::fs::write(&file, "").unwrap();
+        config.database.path = file.join("abathur.db").display().to_string();
+        assert_eq!(invalid_fields(&config), vec!["database.path"]);
+
+        let readonly = dir.path().join("readonly");
+        std::fs::create_dir(&readonly).unwrap();
+        let mut perms = std::fs::metadata(&readonly).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(&readonly, perms.clone()).unwrap();
+        config.database.path = readonly.join("abathur.db").display().to_string();
+        assert_eq!(invalid_fields(&config), vec!["database.path"]);
+
+        #[allow(clippy::permissions_set_readonly_false)]
+        perms.set_readonly(false);
+        std::fs::set_permissions(&readonly, perms).unwrap();
+    }
 }
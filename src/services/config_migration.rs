@@ -0,0 +1,216 @@
+//! Versioned upgrades for `abathur.toml`.
+//!
+//! Each config file carries a top-level `version` (files written before
+//! versioning existed have none and are treated as version 1). Migrations
+//! form a chain, each one upgrading a document by exactly one version, so an
+//! old file is walked forward step by step to [`CURRENT_CONFIG_VERSION`].
+//!
+//! Migrations operate on a `toml_edit` document so `abathur config migrate`
+//! can rewrite a file in place without discarding the user's comments and
+//! layout. [`Config::from_file`](crate::services::Config::from_file) runs the
+//! same chain in memory, so deprecated keys keep working until the file is
+//! upgraded.
+
+use serde::Serialize;
+use toml_edit::{DocumentMut, value};
+
+use crate::services::config::ConfigError;
+
+/// Schema version written by this build.
+pub const CURRENT_CONFIG_VERSION: u32 = 2;
+
+/// A single edit made while migrating a config document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ConfigChange {
+    /// A key or section moved to a new name.
+    Renamed { from: String, to: String },
+    /// A key that older versions left implicit was written out explicitly.
+    Defaulted { key: String, value: String },
+    /// A key was both present under its old and new name; the old one was
+    /// dropped in favour of the new one.
+    Dropped { key: String, reason: String },
+}
+
+impl std::fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Renamed { from, to } => write!(f, "renamed {} -> {}", from, to),
+            Self::Defaulted { key, value } => write!(f, "set {} = {}", key, value),
+            Self::Dropped { key, reason } => write!(f, "dropped {} ({})", key, reason),
+        }
+    }
+}
+
+/// Outcome of running the migration chain over a document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MigrationReport {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub changes: Vec<ConfigChange>,
+}
+
+impl MigrationReport {
+    /// Whether the document was already current.
+    pub fn is_noop(&self) -> bool {
+        self.from_version == self.to_version && self.changes.is_empty()
+    }
+
+    /// Whether any deprecated key was rewritten (as opposed to only
+    /// defaults being made explicit).
+    pub fn has_renames(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|c| !matches!(c, ConfigChange::Defaulted { .. }))
+    }
+}
+
+/// One step of the chain: upgrades a document from `from` to `from + 1`.
+struct Migration {
+    from: u32,
+    apply: fn(&mut DocumentMut, &mut Vec<ConfigChange>),
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 1,
+    apply: v1_to_v2,
+}];
+
+/// Version 1 → 2: write out the default workflow, which version 1 files
+/// left implicit (`"code"`), so the file states which workflow it runs.
+fn v1_to_v2(doc: &mut DocumentMut, changes: &mut Vec<ConfigChange>) {
+    if !doc.contains_key("default_workflow") {
+        doc.insert("default_workflow", value("code"));
+        changes.push(ConfigChange::Defaulted {
+            key: "default_workflow".to_string(),
+            value: "\"code\"".to_string(),
+        });
+    }
+}
+
+/// Schema version declared by `doc` (1 when the key is absent).
+pub fn config_version(doc: &DocumentMut) -> Result<u32, ConfigError> {
+    match doc.get("version") {
+        None => Ok(1),
+        Some(item) => item
+            .as_integer()
+            .and_then(|v| u32::try_from(v).ok())
+            .filter(|v| *v >= 1)
+            .ok_or_else(|| ConfigError::ValidationError {
+                field: "version".to_string(),
+                reason: "must be a positive integer".to_string(),
+            }),
+    }
+}
+
+/// Upgrade `doc` in place to [`CURRENT_CONFIG_VERSION`].
+///
+/// Fails if the document is newer than this build understands.
+pub fn migrate(doc: &mut DocumentMut) -> Result<MigrationReport, ConfigError> {
+    let from_version = config_version(doc)?;
+    if from_version > CURRENT_CONFIG_VERSION {
+        return Err(ConfigError::ValidationError {
+            field: "version".to_string(),
+            reason: format!(
+                "config version {} is newer than this build supports ({})",
+                from_version, CURRENT_CONFIG_VERSION
+            ),
+        });
+    }
+
+    let mut changes = Vec::new();
+    let mut version = from_version;
+    while version < CURRENT_CONFIG_VERSION {
+        let step = MIGRATIONS
+            .iter()
+            .find(|m| m.from == version)
+            .expect("migration chain covers every version below current");
+        (step.apply)(doc, &mut changes);
+        version += 1;
+    }
+    if from_version != CURRENT_CONFIG_VERSION || !doc.contains_key("version") {
+        doc.insert("version", value(i64::from(CURRENT_CONFIG_VERSION)));
+    }
+
+    Ok(MigrationReport {
+        from_version,
+        to_version: CURRENT_CONFIG_VERSION,
+        changes,
+    })
+}
+
+/// Parse `content`, migrate it, and return the upgraded TOML text.
+pub fn migrate_str(content: &str) -> Result<(String, MigrationReport), ConfigError> {
+    let mut doc: DocumentMut = content.parse()?;
+    let report = migrate(&mut doc)?;
+    Ok((doc.to_string(), report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Config;
+
+    const V1_SAMPLE: &str = r#"# Abathur config from before versioning
+[limits]
+max_depth = 7 # keep trees shallow
+max_retries = 2
+
+[worktrees]
+enabled = false
+base_path = ".abathur/wt"
+
+[logging]
+level = "debug"
+"#;
+
+    #[test]
+    fn test_migrate_v1_sample_to_current_schema() {
+        let (upgraded, report) = migrate_str(V1_SAMPLE).unwrap();
+
+        assert_eq!(report.from_version, 1);
+        assert_eq!(report.to_version, CURRENT_CONFIG_VERSION);
+        assert_eq!(
+            report.changes,
+            vec![ConfigChange::Defaulted {
+                key: "default_workflow".into(),
+                value: "\"code\"".into()
+            }]
+        );
+        assert!(!report.has_renames());
+
+        // Comments survive the rewrite.
+        assert!(upgraded.contains("# Abathur config from before versioning"));
+        assert!(upgraded.contains("# keep trees shallow"));
+
+        let (config, unknown) = Config::parse_str(&upgraded).unwrap();
+        assert!(unknown.is_empty(), "unexpected unknown keys: {:?}", unknown);
+        assert_eq!(config.version, CURRENT_CONFIG_VERSION);
+        assert_eq!(config.limits.max_depth, 7);
+        assert_eq!(config.limits.max_retries, 2);
+        assert!(!config.worktrees.enabled);
+        assert_eq!(config.worktrees.base_path, ".abathur/wt");
+        assert_eq!(config.logging.level, "debug");
+        assert_eq!(config.default_workflow, "code");
+
+        // Migrating again is a no-op.
+        let (again, report) = migrate_str(&upgraded).unwrap();
+        assert!(report.is_noop());
+        assert_eq!(again, upgraded);
+    }
+
+    #[test]
+    fn test_migrate_keeps_explicit_default_workflow() {
+        let (upgraded, report) = migrate_str("default_workflow = \"review\"\n").unwrap();
+        assert!(report.changes.is_empty());
+        assert_eq!(report.to_version, CURRENT_CONFIG_VERSION);
+        let (config, _) = Config::parse_str(&upgraded).unwrap();
+        assert_eq!(config.default_workflow, "review");
+    }
+
+    #[test]
+    fn test_migrate_rejects_future_version() {
+        let err = migrate_str("version = 99\n").unwrap_err();
+        assert!(matches!(err, ConfigError::ValidationError { ref field, .. } if field == "version"));
+    }
+}
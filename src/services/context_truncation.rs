@@ -102,7 +102,11 @@ pub fn truncate_to_token_budget(text: &str, token_budget: usize) -> String {
         return text.to_string();
     }
 
-    let truncate_at = text[..max_chars].rfind('\n').unwrap_or(max_chars);
+    let mut limit = max_chars;
+    while !text.is_char_boundary(limit) {
+        limit -= 1;
+    }
+    let truncate_at = text[..limit].rfind('\n').unwrap_or(limit);
     let truncated = &text[..truncate_at];
 
     format!(
@@ -187,6 +191,15 @@ mod tests {
         assert!(result.contains("[... truncated"));
     }
 
+    #[test]
+    fn test_truncate_to_token_budget_respects_char_boundaries() {
+        // Three-byte characters never line up with the 4-byte-per-token limit.
+        let text = "記憶".repeat(100);
+        let result = truncate_to_token_budget(&text, 5);
+        assert!(result.starts_with("記憶"));
+        assert!(result.contains("[... truncated"));
+    }
+
     #[test]
     fn test_truncate_to_token_budget_no_truncation() {
         let text = "short text";
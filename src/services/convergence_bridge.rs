@@ -18,7 +18,7 @@ use crate::domain::models::convergence::{
 };
 use crate::domain::models::intent_verification::IntentVerificationResult;
 use crate::domain::models::task::{Complexity, ExecutionMode, Task, TaskPriority};
-use crate::domain::models::{Memory, MemoryQuery, MemoryTier};
+use crate::domain::models::{Memory, MemoryQuery, MemoryTier, NamespaceSummary};
 use crate::domain::ports::{MemoryRepository, StrategyStats, TrajectoryRepository};
 use crate::services::swarm_orchestrator::types::SwarmConfig;
 
@@ -293,6 +293,7 @@ pub fn build_engine_config(config: &SwarmConfig) -> ConvergenceEngineConfig {
     let policy = ConvergencePolicy {
         acceptance_threshold: loop_config.min_confidence_threshold,
         partial_acceptance: loop_config.auto_retry_partial,
+        adaptive_budget: loop_config.adaptive_budget.clone(),
         ..ConvergencePolicy::default()
     };
 
@@ -355,6 +356,10 @@ impl TrajectoryRepository for DynTrajectoryRepository {
         self.0.get_recent(limit).await
     }
 
+    async fn get_active(&self) -> DomainResult<Vec<Trajectory>> {
+        self.0.get_active().await
+    }
+
     async fn get_successful_strategies(
         &self,
         attractor_type: &AttractorType,
@@ -461,6 +466,25 @@ impl MemoryRepository for DynMemoryRepository {
     async fn count_by_tier(&self) -> DomainResult<HashMap<MemoryTier, u64>> {
         self.0.count_by_tier().await
     }
+    async fn list_namespaces(&self) -> DomainResult<Vec<NamespaceSummary>> {
+        self.0.list_namespaces().await
+    }
+
+    async fn count_stale_embeddings(&self, model: &str) -> DomainResult<u64> {
+        self.0.count_stale_embeddings(model).await
+    }
+
+    async fn list_stale_embeddings(&self, model: &str, limit: usize) -> DomainResult<Vec<Memory>> {
+        self.0.list_stale_embeddings(model, limit).await
+    }
+
+    async fn store_embeddings(
+        &self,
+        model: &str,
+        embeddings: &[(Uuid, Vec<f32>)],
+    ) -> DomainResult<()> {
+        self.0.store_embeddings(model, embeddings).await
+    }
 }
 
 // -- Internal helpers --
@@ -143,6 +143,18 @@ impl<T: TrajectoryRepository, M: MemoryRepository, O: OverseerMeasurer> Converge
         // Update context health
         trajectory.context_health = estimate_context_health(&trajectory.observations);
 
+        // Adaptive budget: reaching the iteration cap while still improving
+        // at least `min_slope` per iteration earns a bonus iteration.
+        if let Some(adaptive) = &trajectory.policy.adaptive_budget
+            && trajectory.budget.iterations_used >= trajectory.budget.max_iterations
+            && trajectory.budget.bonus_iterations_granted < adaptive.max_bonus_iterations
+            && trajectory
+                .convergence_slope(adaptive.window)
+                .is_some_and(|slope| slope >= adaptive.min_slope)
+        {
+            trajectory.budget.grant_bonus_iteration();
+        }
+
         // Persist trajectory
         self.trajectory_store.save(trajectory).await?;
 
@@ -189,6 +201,8 @@ impl<T: TrajectoryRepository, M: MemoryRepository, O: OverseerMeasurer> Converge
     /// Evaluates, in priority order:
     /// 1. Budget exhausted -> Exhausted or RequestExtension
     /// 2. IntentCheck -> iteration interval, budget fraction, or FixedPoint trigger
+    ///    (after OverseerConverged, and EarlyStop when the adaptive budget's
+    ///    slope has dropped below its minimum)
     /// 3. Trapped -> limit cycle with no escape strategies
     /// 4. Decompose -> divergent trajectory with sufficient budget
     /// 5. Near-budget extension check
@@ -275,6 +289,21 @@ impl<T: TrajectoryRepository, M: MemoryRepository, O: OverseerMeasurer> Converge
             }
         }
 
+        // 2c. Adaptive early stop: improvement per iteration over the recent
+        // window is below the policy minimum. Divergent trajectories are left
+        // to the decomposition check below.
+        if let Some(adaptive) = &trajectory.policy.adaptive_budget
+            && !matches!(
+                &trajectory.attractor_state.classification,
+                AttractorType::Divergent { .. }
+            )
+            && trajectory
+                .convergence_slope(adaptive.window)
+                .is_some_and(|slope| slope < adaptive.min_slope)
+        {
+            return Ok(LoopControl::EarlyStop);
+        }
+
         if interval_trigger
             || at_fixed_point
             || budget_trigger
@@ -132,6 +132,15 @@ pub enum ConvergenceDomainEvent {
         to: String,
         confidence: f64,
     },
+    /// The adaptive budget stopped the loop because the convergence slope
+    /// fell below its minimum. Orchestrator sinks translate this to
+    /// `EventPayload::ConvergenceEarlyStop`.
+    ConvergenceEarlyStop {
+        trajectory_id: uuid::Uuid,
+        iteration: u32,
+        slope: f64,
+        min_slope: f64,
+    },
 }
 
 impl ConvergenceDomainEvent {
@@ -323,6 +332,20 @@ impl ConvergenceEventSink for TracingEventSink {
                     "Attractor classification changed",
                 );
             }
+            ConvergenceDomainEvent::ConvergenceEarlyStop {
+                trajectory_id,
+                iteration,
+                slope,
+                min_slope,
+            } => {
+                tracing::info!(
+                    trajectory_id = %trajectory_id,
+                    iteration = iteration,
+                    slope = slope,
+                    min_slope = min_slope,
+                    "Convergence plateaued; stopping early",
+                );
+            }
         }
     }
 }
@@ -413,6 +413,41 @@ impl<T: TrajectoryRepository, M: MemoryRepository, O: OverseerMeasurer> Converge
                         "convergence budget exhausted".to_string(),
                     ));
                 }
+                LoopControl::EarlyStop => {
+                    let adaptive = trajectory
+                        .policy
+                        .adaptive_budget
+                        .clone()
+                        .unwrap_or_default();
+                    let slope = trajectory.convergence_slope(adaptive.window).unwrap_or(0.0);
+                    event_sink
+                        .emit(ConvergenceDomainEvent::ConvergenceEarlyStop {
+                            trajectory_id: trajectory.id,
+                            iteration: sequence,
+                            slope,
+                            min_slope: adaptive.min_slope,
+                        })
+                        .await;
+                    // Give the advisor the same final say as on exhaustion
+                    // (e.g. an intent check that may still accept the work).
+                    let directive = advisor.on_pre_exhaustion(&trajectory).await?;
+                    if let Some(outcome) =
+                        self.apply_directive(directive, &mut trajectory, &bandit).await?
+                    {
+                        return Ok(outcome);
+                    }
+                    let outcome = ConvergenceOutcome::Exhausted {
+                        trajectory_id: trajectory.id.to_string(),
+                        best_observation_sequence: trajectory
+                            .best_observation()
+                            .map(|o| o.sequence),
+                    };
+                    self.finalize(&mut trajectory, &outcome, &bandit).await?;
+                    return Ok(ConvergenceRunOutcome::Exhausted(format!(
+                        "convergence plateaued (slope {:.3} < {:.3})",
+                        slope, adaptive.min_slope
+                    )));
+                }
                 LoopControl::Trapped => {
                     let outcome = ConvergenceOutcome::Trapped {
                         trajectory_id: trajectory.id.to_string(),
@@ -9,7 +9,7 @@ use uuid::Uuid;
 use crate::domain::errors::DomainResult;
 use crate::domain::models::convergence::*;
 use crate::domain::models::task::Complexity;
-use crate::domain::models::{Memory, MemoryQuery, MemoryTier};
+use crate::domain::models::{Memory, MemoryQuery, MemoryTier, NamespaceSummary};
 use crate::domain::ports::{MemoryRepository, TrajectoryRepository};
 
 use super::{ConvergenceEngine, OverseerMeasurer};
@@ -55,6 +55,10 @@ impl TrajectoryRepository for MockTrajectoryRepo {
         Ok(vec![])
     }
 
+    async fn get_active(&self) -> DomainResult<Vec<Trajectory>> {
+        Ok(vec![])
+    }
+
     async fn get_successful_strategies(
         &self,
         _attractor_type: &AttractorType,
@@ -208,6 +212,30 @@ impl MemoryRepository for MockMemoryRepo {
     async fn count_by_tier(&self) -> DomainResult<std::collections::HashMap<MemoryTier, u64>> {
         Ok(HashMap::new())
     }
+
+    async fn list_namespaces(&self) -> DomainResult<Vec<NamespaceSummary>> {
+        Ok(Vec::new())
+    }
+
+    async fn count_stale_embeddings(&self, _model: &str) -> DomainResult<u64> {
+        Ok(0)
+    }
+
+    async fn list_stale_embeddings(
+        &self,
+        _model: &str,
+        _limit: usize,
+    ) -> DomainResult<Vec<Memory>> {
+        Ok(Vec::new())
+    }
+
+    async fn store_embeddings(
+        &self,
+        _model: &str,
+        _embeddings: &[(Uuid, Vec<f32>)],
+    ) -> DomainResult<()> {
+        Ok(())
+    }
 }
 
 // -----------------------------------------------------------------------
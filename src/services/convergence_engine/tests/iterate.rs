@@ -2330,3 +2330,111 @@ async fn test_measure_delegates_to_overseer_measurer() {
         expected_signals.test_results.as_ref().unwrap().failed,
     );
 }
+
+// -----------------------------------------------------------------------
+// Adaptive budget tests
+// -----------------------------------------------------------------------
+
+/// Trajectory with one unmeasured baseline followed by observations whose
+/// convergence deltas are `deltas`, with interval/budget intent triggers off.
+fn trajectory_with_deltas(deltas: &[f64]) -> Trajectory {
+    let mut trajectory = test_trajectory();
+    trajectory.policy.intent_check_interval = u32::MAX;
+    trajectory.policy.intent_check_at_budget_fraction = 1.0;
+    trajectory.budget.max_iterations = 20;
+    trajectory
+        .observations
+        .push(test_observation(0, StrategyKind::RetryWithFeedback));
+    for (i, delta) in deltas.iter().enumerate() {
+        let seq = i as u32 + 1;
+        trajectory.observations.push(
+            test_observation(seq, StrategyKind::RetryWithFeedback)
+                .with_metrics(metrics_with(*delta, 0.5)),
+        );
+    }
+    trajectory
+}
+
+#[test]
+fn test_loop_control_adaptive_early_stop_on_flat_slope() {
+    let engine = test_engine();
+    let bandit = StrategyBandit::with_default_priors();
+
+    // Without an adaptive budget a flat trajectory keeps iterating.
+    let mut trajectory = trajectory_with_deltas(&[0.01, 0.0, 0.01]);
+    let result = engine.check_loop_control(&trajectory, &bandit).unwrap();
+    assert!(matches!(result, LoopControl::Continue), "got {:?}", result);
+
+    trajectory.policy.adaptive_budget = Some(AdaptiveBudgetConfig::default());
+    let result = engine.check_loop_control(&trajectory, &bandit).unwrap();
+    assert!(matches!(result, LoopControl::EarlyStop), "got {:?}", result);
+
+    // Still improving steeply: no early stop.
+    let mut steep = trajectory_with_deltas(&[0.2, 0.15, 0.1]);
+    steep.policy.adaptive_budget = Some(AdaptiveBudgetConfig::default());
+    let result = engine.check_loop_control(&steep, &bandit).unwrap();
+    assert!(matches!(result, LoopControl::Continue), "got {:?}", result);
+
+    // Fewer measured observations than the window: fall back to the fixed cap.
+    let mut short = trajectory_with_deltas(&[0.0, 0.0]);
+    short.policy.adaptive_budget = Some(AdaptiveBudgetConfig::default());
+    let result = engine.check_loop_control(&short, &bandit).unwrap();
+    assert!(matches!(result, LoopControl::Continue), "got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_iterate_once_adaptive_grants_bonus_iterations_while_steep() {
+    let engine = test_engine_with_measurer(vec![]);
+    let mut bandit = StrategyBandit::with_default_priors();
+    let strategy = StrategyKind::RetryWithFeedback;
+
+    let mut trajectory = trajectory_with_deltas(&[0.5, 0.5]);
+    trajectory.phase = ConvergencePhase::Iterating;
+    trajectory.policy.adaptive_budget = Some(AdaptiveBudgetConfig {
+        window: 3,
+        min_slope: 0.02,
+        max_bonus_iterations: 1,
+    });
+    // The next iteration reaches the fixed cap.
+    trajectory.budget.max_iterations = 3;
+    trajectory.budget.iterations_used = 2;
+    trajectory.observations.last_mut().unwrap().overseer_signals = failing_signals();
+
+    let observation = Observation::new(
+        3,
+        test_artifact(3),
+        all_passing_signals(),
+        strategy.clone(),
+        5_000,
+        1_000,
+    );
+    let control = engine
+        .iterate_once(&mut trajectory, &mut bandit, &strategy, observation)
+        .await
+        .unwrap();
+
+    assert_eq!(trajectory.budget.bonus_iterations_granted, 1);
+    assert_eq!(trajectory.budget.max_iterations, 4);
+    assert!(trajectory.budget.has_remaining());
+    assert!(
+        !matches!(control, LoopControl::Exhausted),
+        "got {:?}",
+        control
+    );
+
+    // The bonus allowance is spent: the next cap hit is final.
+    let observation = Observation::new(
+        4,
+        test_artifact(4),
+        all_passing_signals(),
+        strategy.clone(),
+        5_000,
+        1_000,
+    );
+    engine
+        .iterate_once(&mut trajectory, &mut bandit, &strategy, observation)
+        .await
+        .unwrap();
+    assert_eq!(trajectory.budget.max_iterations, 4);
+    assert!(!trajectory.budget.has_remaining());
+}
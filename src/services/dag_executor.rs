@@ -8,8 +8,8 @@ use uuid::Uuid;
 
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::models::{
-    ConstraintType, Goal, SessionStatus, SubstrateConfig, SubstrateRequest, SubstrateSession,
-    TaskDag, TaskStatus,
+    ConstraintType, Goal, SessionStatus, SubstrateConfig, SubstrateOutput, SubstrateRequest,
+    SubstrateSession, TaskDag, TaskStatus,
 };
 use crate::domain::ports::{AgentRepository, GoalRepository, Substrate, TaskRepository};
 use crate::services::circuit_breaker::{CircuitBreakerService, CircuitScope};
@@ -20,6 +20,7 @@ use crate::services::dag_restructure::DagRestructureService;
 use crate::services::event_bus::EventBus;
 use crate::services::guardrails::{GuardrailResult, Guardrails};
 use crate::services::model_router::ModelRouter;
+use crate::services::partial_output::{OutputBuffer, record_attempt_output};
 
 /// Configuration for the DAG executor.
 #[derive(Debug, Clone)]
@@ -836,6 +837,85 @@ where
     event_bus: Option<Arc<EventBus>>,
 }
 
+/// Result of one streamed substrate attempt.
+enum AttemptOutcome {
+    /// The stream closed; the session carries the final status. Boxed
+    /// because the session dominates the enum size.
+    Finished {
+        session: Box<SubstrateSession>,
+        output: OutputBuffer,
+    },
+    /// The substrate could not start the session.
+    Error(DomainError),
+    /// The timeout elapsed; `output` is everything streamed before it.
+    TimedOut { output: OutputBuffer },
+}
+
+/// Run one attempt over `execute_streaming`, buffering assistant text and
+/// tool results so a timeout or kill still leaves the partial output behind.
+async fn run_streamed_attempt(
+    substrate: &Arc<dyn Substrate>,
+    request: SubstrateRequest,
+    timeout_secs: u64,
+) -> AttemptOutcome {
+    let (mut rx, mut session) = match substrate.execute_streaming(request).await {
+        Ok(started) => started,
+        Err(e) => return AttemptOutcome::Error(e),
+    };
+
+    let mut output = OutputBuffer::new();
+    let drain = async {
+        while let Some(event) = rx.recv().await {
+            output.record(&event);
+            match event {
+                SubstrateOutput::TurnComplete {
+                    input_tokens,
+                    output_tokens,
+                    ..
+                } => session.record_turn(input_tokens, output_tokens),
+                SubstrateOutput::SessionComplete { result, usage } => {
+                    // The completion event carries the substrate's own result
+                    // and totals; streamed text only stands in when it's empty.
+                    if let Some(usage) = usage {
+                        session.record_usage(&usage);
+                    }
+                    if result.trim().is_empty() {
+                        session.complete(output.as_str().trim());
+                    } else {
+                        session.complete(result);
+                    }
+                }
+                SubstrateOutput::Error { message } => session.fail(message),
+                _ => {}
+            }
+        }
+    };
+
+    if timeout(Duration::from_secs(timeout_secs), drain)
+        .await
+        .is_err()
+    {
+        if let Err(e) = substrate.terminate(session.id).await {
+            tracing::warn!(session_id = %session.id, error = %e, "failed to terminate timed-out session");
+        }
+        return AttemptOutcome::TimedOut { output };
+    }
+
+    // Substrates that track sessions themselves hold the authoritative copy
+    // once the stream has closed.
+    if let Ok(Some(stored)) = substrate.get_session(session.id).await
+        && stored.status.is_terminal()
+    {
+        session = stored;
+    } else if !session.status.is_terminal() {
+        session.fail("Substrate stream closed without a result");
+    }
+    AttemptOutcome::Finished {
+        session: Box::new(session),
+        output,
+    }
+}
+
 /// Execute a single task with retry logic and timeout.
 async fn execute_single_task<T, A>(params: ExecuteSingleTaskParams<T, A>) -> TaskResult
 where
@@ -861,7 +941,7 @@ where
     // Check guardrails before starting
     if let Some(ref g) = guardrails {
         match g.check_task_start(task_id).await {
-            GuardrailResult::Blocked(reason) => {
+            GuardrailResult::Blocked(reason) | GuardrailResult::RateLimited(reason) => {
                 return TaskResult {
                     task_id,
                     status: TaskStatus::Failed,
@@ -1126,6 +1206,7 @@ where
     // Execute with retries
     let mut last_error = None;
     let mut last_session = None;
+    let mut partial_output: Option<OutputBuffer> = None;
     let mut retry_count = 0u32;
 
     for attempt in 0..=config.max_retries {
@@ -1181,15 +1262,9 @@ where
         let request = SubstrateRequest::new(task_id, agent_type, &system_prompt, &task.description)
             .with_config(attempt_config);
 
-        // Execute with timeout
-        let execution_result = timeout(
-            Duration::from_secs(config.task_timeout_secs),
-            substrate.execute(request),
-        )
-        .await;
-
-        match execution_result {
-            Ok(Ok(session)) => {
+        // Execute with timeout, keeping whatever the agent streams
+        match run_streamed_attempt(&substrate, request, config.task_timeout_secs).await {
+            AttemptOutcome::Finished { session, output } => {
                 // Track tokens
                 let tokens_used = session.total_tokens();
                 {
@@ -1249,6 +1324,7 @@ where
                     // Success - update task and return
                     let mut completed_task = task.clone();
                     completed_task.retry_count = retry_count;
+                    record_attempt_output(&mut completed_task, true, "");
                     if completed_task.transition_to(TaskStatus::Complete).is_ok() {
                         let _ = task_repo.update(&completed_task).await;
                     }
@@ -1281,7 +1357,7 @@ where
                         task_id,
                         status: TaskStatus::Complete,
                         error: None,
-                        session: Some(session.clone()),
+                        session: Some(*session),
                         duration_secs: start.elapsed().as_secs(),
                         retry_count,
                     };
@@ -1300,24 +1376,38 @@ where
                         .error
                         .clone()
                         .or(Some("Session did not complete".to_string()));
-                    last_session = Some(session);
+                    last_session = Some(*session);
+                    if !output.is_empty() {
+                        partial_output = Some(output);
+                    }
                 }
             }
-            Ok(Err(e)) => {
+            AttemptOutcome::Error(e) => {
                 last_error = Some(e.to_string());
             }
-            Err(_) => {
+            AttemptOutcome::TimedOut { output } => {
                 last_error = Some(format!(
                     "Task timed out after {} seconds",
                     config.task_timeout_secs
                 ));
+                if !output.is_empty() {
+                    partial_output = Some(output);
+                }
             }
         }
     }
 
-    // All retries exhausted - mark as failed
-    let mut failed_task = task.clone();
+    // All retries exhausted - mark as failed, keeping any partial output so a
+    // human or a later retry can pick it up. Re-read the task so the write
+    // builds on the Running transition persisted above.
+    let mut failed_task = match task_repo.get(task_id).await {
+        Ok(Some(current)) => current,
+        _ => task.clone(),
+    };
     failed_task.retry_count = retry_count;
+    if let Some(ref partial) = partial_output {
+        record_attempt_output(&mut failed_task, false, partial.as_str());
+    }
     if failed_task.transition_to(TaskStatus::Failed).is_ok() {
         let _ = task_repo.update(&failed_task).await;
     }
@@ -1581,4 +1671,136 @@ mod tests {
             "B must not have completed after A failed under fail_fast"
         );
     }
+
+    #[tokio::test]
+    async fn test_timeout_saves_partial_streamed_output() {
+        use crate::adapters::substrates::mock::MockResponse;
+        use crate::domain::models::Task;
+
+        let (task_repo, agent_repo, goal_repo) = test_support::setup_task_agent_goal_repos().await;
+        let mut task = Task::new("Long-running analysis");
+        task.status = TaskStatus::Ready;
+        let mock = MockSubstrate::new();
+        mock.set_response_for_task(
+            task.id,
+            MockResponse::partial_then_stall("Found 3 of 5 call sites so far"),
+        )
+        .await;
+        let substrate: Arc<dyn Substrate> = Arc::new(mock);
+
+        let config = ExecutorConfig {
+            max_retries: 0,
+            task_timeout_secs: 1,
+            ..Default::default()
+        };
+        let executor = DagExecutor::new(task_repo.clone(), agent_repo, substrate, config)
+            .with_goal_repo(goal_repo);
+        task_repo.create(&task).await.unwrap();
+
+        let dag = TaskDag::from_tasks(vec![task.clone()]);
+        let results = executor.execute(&dag).await.unwrap();
+        assert_eq!(results.failed_tasks, 1);
+
+        let saved = task_repo.get(task.id).await.unwrap().unwrap();
+        assert_eq!(saved.status, TaskStatus::Failed);
+        assert!(saved.partial_output_available());
+        assert_eq!(
+            saved.partial_output(),
+            Some("Found 3 of 5 call sites so far")
+        );
+    }
+
+    /// Streams a draft, then completes with a different result and totals.
+    struct ScriptedStreamSubstrate;
+
+    #[async_trait::async_trait]
+    impl Substrate for ScriptedStreamSubstrate {
+        fn name(&self) -> &'static str {
+            "scripted"
+        }
+
+        async fn is_available(&self) -> DomainResult<bool> {
+            Ok(true)
+        }
+
+        async fn execute(&self, _request: SubstrateRequest) -> DomainResult<SubstrateSession> {
+            Err(DomainError::SubstrateError("streaming only".to_string()))
+        }
+
+        async fn execute_streaming(
+            &self,
+            request: SubstrateRequest,
+        ) -> DomainResult<(mpsc::Receiver<SubstrateOutput>, SubstrateSession)> {
+            let mut session =
+                SubstrateSession::new(request.task_id, &request.agent_template, request.config);
+            session.start(None);
+            let (tx, rx) = mpsc::channel(8);
+            tokio::spawn(async move {
+                let _ = tx
+                    .send(SubstrateOutput::AssistantText {
+                        content: "Draft: looking around".to_string(),
+                    })
+                    .await;
+                let _ = tx
+                    .send(SubstrateOutput::TurnComplete {
+                        turn_number: 1,
+                        input_tokens: 10,
+                        output_tokens: 5,
+                    })
+                    .await;
+                let _ = tx
+                    .send(SubstrateOutput::SessionComplete {
+                        result: "Final answer".to_string(),
+                        usage: Some(crate::domain::models::SessionUsage {
+                            input_tokens: 1200,
+                            output_tokens: 300,
+                            cache_read_tokens: 800,
+                            cache_write_tokens: 0,
+                            cost_cents: Some(4.5),
+                        }),
+                    })
+                    .await;
+            });
+            Ok((rx, session))
+        }
+
+        async fn resume(
+            &self,
+            _session_id: Uuid,
+            _additional_prompt: Option<String>,
+        ) -> DomainResult<SubstrateSession> {
+            Err(DomainError::SubstrateError("streaming only".to_string()))
+        }
+
+        async fn terminate(&self, _session_id: Uuid) -> DomainResult<()> {
+            Ok(())
+        }
+
+        async fn get_session(&self, _session_id: Uuid) -> DomainResult<Option<SubstrateSession>> {
+            Ok(None)
+        }
+
+        async fn is_running(&self, _session_id: Uuid) -> DomainResult<bool> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_streamed_attempt_takes_result_and_usage_from_session_complete() {
+        let substrate: Arc<dyn Substrate> = Arc::new(ScriptedStreamSubstrate);
+        let request = SubstrateRequest::new(Uuid::new_v4(), "coder", "system", "do it");
+
+        let AttemptOutcome::Finished { session, output } =
+            run_streamed_attempt(&substrate, request, 5).await
+        else {
+            panic!("attempt should finish");
+        };
+        assert_eq!(session.status, SessionStatus::Completed);
+        assert_eq!(session.result.as_deref(), Some("Final answer"));
+        assert_eq!(session.input_tokens, 1200);
+        assert_eq!(session.output_tokens, 300);
+        assert_eq!(session.cache_read_tokens, 800);
+        assert_eq!(session.cost_cents, Some(4.5));
+        assert_eq!(output.as_str(), "Draft: looking around");
+    }
 }
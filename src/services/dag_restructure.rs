@@ -2,15 +2,27 @@
 //!
 //! Handles intelligent re-planning when tasks permanently fail by invoking
 //! the Overmind with failure context to find alternative approaches.
+//!
+//! Restructuring is two-phase: [`DagRestructureService::plan`] turns decisions
+//! into a serializable [`RestructurePlan`] (new tasks, edge changes, status
+//! transitions) without touching the DAG, and [`DagRestructureService::apply`]
+//! executes it. Applying a plan is idempotent, so a plan can be previewed,
+//! audited, and replayed safely.
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 use std::sync::Arc;
 
-use crate::domain::errors::DomainResult;
-use crate::domain::models::{Goal, Task, TaskStatus};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::models::{Goal, Task, TaskPriority, TaskSource, TaskStatus};
+use crate::domain::ports::TaskRepository;
+use crate::services::command_bus::{
+    CommandBus, CommandEnvelope, CommandError, CommandResult, CommandSource, DomainCommand,
+    TaskCommand,
+};
 
 /// Configuration for DAG restructuring.
 #[derive(Debug, Clone)]
@@ -102,7 +114,8 @@ pub struct FailedAttempt {
 }
 
 /// Decision from restructuring analysis.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum RestructureDecision {
     /// Retry the task with a different approach.
     RetryDifferentApproach {
@@ -127,8 +140,22 @@ pub enum RestructureDecision {
     AcceptFailure { reason: String },
 }
 
+impl RestructureDecision {
+    /// Stable snake_case name of the decision variant.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::RetryDifferentApproach { .. } => "retry_different_approach",
+            Self::AlternativePath { .. } => "alternative_path",
+            Self::DecomposeDifferently { .. } => "decompose_differently",
+            Self::Escalate { .. } => "escalate",
+            Self::WaitAndRetry { .. } => "wait_and_retry",
+            Self::AcceptFailure { .. } => "accept_failure",
+        }
+    }
+}
+
 /// Specification for a new task created during restructuring.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct NewTaskSpec {
     /// Task title.
     pub title: String,
@@ -143,7 +170,8 @@ pub struct NewTaskSpec {
 }
 
 /// Priority modifier for restructured tasks.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TaskPriorityModifier {
     /// Same priority as the original.
     Same,
@@ -153,6 +181,253 @@ pub enum TaskPriorityModifier {
     Lower,
 }
 
+/// Reference to a task in a [`RestructurePlan`]: either one already in the
+/// DAG or one the plan will create, identified by its idempotency key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "ref", rename_all = "snake_case")]
+pub enum TaskRef {
+    Existing(Uuid),
+    Planned(String),
+}
+
+/// The decision a plan carries out for one failed task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedDecision {
+    pub task_id: Uuid,
+    pub decision: RestructureDecision,
+}
+
+/// A task the plan will submit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedTask {
+    /// Idempotency key used on submit, so re-applying never duplicates.
+    pub key: String,
+    /// The failed task this one was planned for.
+    pub replaces: Uuid,
+    pub title: String,
+    pub description: String,
+    pub agent_type: Option<String>,
+    pub priority: TaskPriority,
+}
+
+/// A dependency edge: `task` depends on `depends_on`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedEdge {
+    pub task: TaskRef,
+    pub depends_on: TaskRef,
+}
+
+/// A status change for an existing task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedTransition {
+    pub task_id: Uuid,
+    pub from: TaskStatus,
+    pub to: TaskStatus,
+    pub reason: String,
+}
+
+/// A note appended to an existing task's description before it is retried.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedDescriptionUpdate {
+    pub task_id: Uuid,
+    pub note: String,
+    pub agent_type: Option<String>,
+}
+
+/// Proposed changes to a DAG, computed by [`DagRestructureService::plan`]
+/// and executed by [`DagRestructureService::apply`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestructurePlan {
+    pub decisions: Vec<PlannedDecision>,
+    pub new_tasks: Vec<PlannedTask>,
+    pub edges_added: Vec<PlannedEdge>,
+    pub edges_removed: Vec<PlannedEdge>,
+    pub transitions: Vec<PlannedTransition>,
+    pub description_updates: Vec<PlannedDescriptionUpdate>,
+}
+
+impl RestructurePlan {
+    /// Translate a decision for `failed_task` into DAG changes. `tasks` is
+    /// the surrounding task set, used to rewire dependents of a replaced
+    /// task; `attempt` scopes the idempotency keys of new tasks.
+    pub fn from_decision(
+        failed_task: &Task,
+        decision: &RestructureDecision,
+        attempt: u32,
+        tasks: &[Task],
+    ) -> Self {
+        let mut plan = Self {
+            decisions: vec![PlannedDecision {
+                task_id: failed_task.id,
+                decision: decision.clone(),
+            }],
+            ..Self::default()
+        };
+        match decision {
+            RestructureDecision::RetryDifferentApproach {
+                new_approach,
+                new_agent_type,
+            } => {
+                plan.description_updates.push(PlannedDescriptionUpdate {
+                    task_id: failed_task.id,
+                    note: format!(
+                        "\n\n## Restructure Note\nPrevious approach failed. Try: {}",
+                        new_approach
+                    ),
+                    agent_type: new_agent_type.clone(),
+                });
+                plan.transitions.push(PlannedTransition {
+                    task_id: failed_task.id,
+                    from: failed_task.status,
+                    to: TaskStatus::Ready,
+                    reason: format!(
+                        "DAG restructure: retry with different approach — {}",
+                        new_approach
+                    ),
+                });
+            }
+            RestructureDecision::DecomposeDifferently {
+                new_subtasks,
+                remove_original,
+            } => {
+                let keys = plan.push_new_tasks(failed_task, new_subtasks, attempt);
+                if *remove_original {
+                    plan.replace_original(failed_task, &keys, tasks);
+                }
+            }
+            RestructureDecision::AlternativePath { new_tasks, .. } => {
+                plan.push_new_tasks(failed_task, new_tasks, attempt);
+            }
+            RestructureDecision::Escalate { .. }
+            | RestructureDecision::WaitAndRetry { .. }
+            | RestructureDecision::AcceptFailure { .. } => {}
+        }
+        plan
+    }
+
+    /// Add planned tasks for `specs` plus the edges between them, returning
+    /// their keys in spec order. Dependencies resolve by title against specs
+    /// listed earlier; unknown titles are dropped.
+    fn push_new_tasks(
+        &mut self,
+        failed_task: &Task,
+        specs: &[NewTaskSpec],
+        attempt: u32,
+    ) -> Vec<String> {
+        let mut keys: Vec<(String, String)> = Vec::new();
+        for (i, spec) in specs.iter().enumerate() {
+            let key = format!("restructure:{}:{}:{}", failed_task.id, attempt, i);
+            for dep_title in &spec.depends_on {
+                if let Some((_, dep_key)) = keys.iter().find(|(t, _)| t == dep_title) {
+                    self.edges_added.push(PlannedEdge {
+                        task: TaskRef::Planned(key.clone()),
+                        depends_on: TaskRef::Planned(dep_key.clone()),
+                    });
+                }
+            }
+            self.new_tasks.push(PlannedTask {
+                key: key.clone(),
+                replaces: failed_task.id,
+                title: spec.title.clone(),
+                description: spec.description.clone(),
+                agent_type: spec.agent_type.clone(),
+                priority: match spec.priority {
+                    TaskPriorityModifier::Same => failed_task.priority,
+                    TaskPriorityModifier::Higher => TaskPriority::High,
+                    TaskPriorityModifier::Lower => TaskPriority::Low,
+                },
+            });
+            keys.push((spec.title.clone(), key));
+        }
+        keys.into_iter().map(|(_, key)| key).collect()
+    }
+
+    /// Cancel `failed_task` and point its dependents at the planned tasks
+    /// that nothing else in the replacement depends on.
+    fn replace_original(&mut self, failed_task: &Task, keys: &[String], tasks: &[Task]) {
+        let sinks: Vec<&String> = keys
+            .iter()
+            .filter(|key| {
+                !self
+                    .edges_added
+                    .iter()
+                    .any(|e| e.depends_on == TaskRef::Planned((*key).clone()))
+            })
+            .collect();
+        for dependent in tasks
+            .iter()
+            .filter(|t| t.depends_on.contains(&failed_task.id))
+        {
+            self.edges_removed.push(PlannedEdge {
+                task: TaskRef::Existing(dependent.id),
+                depends_on: TaskRef::Existing(failed_task.id),
+            });
+            for key in &sinks {
+                self.edges_added.push(PlannedEdge {
+                    task: TaskRef::Existing(dependent.id),
+                    depends_on: TaskRef::Planned((*key).clone()),
+                });
+            }
+        }
+        self.transitions.push(PlannedTransition {
+            task_id: failed_task.id,
+            from: failed_task.status,
+            to: TaskStatus::Canceled,
+            reason: "Replaced by restructure subtasks".to_string(),
+        });
+    }
+
+    /// Fold another plan's changes into this one.
+    pub fn merge(&mut self, other: RestructurePlan) {
+        self.decisions.extend(other.decisions);
+        self.new_tasks.extend(other.new_tasks);
+        self.edges_added.extend(other.edges_added);
+        self.edges_removed.extend(other.edges_removed);
+        self.transitions.extend(other.transitions);
+        self.description_updates.extend(other.description_updates);
+    }
+
+    /// Whether applying the plan would change the DAG.
+    pub fn is_empty(&self) -> bool {
+        self.new_tasks.is_empty()
+            && self.edges_added.is_empty()
+            && self.edges_removed.is_empty()
+            && self.transitions.is_empty()
+            && self.description_updates.is_empty()
+    }
+}
+
+/// What [`DagRestructureService::apply`] changed. Steps already in effect
+/// (from an earlier apply of the same plan) are not counted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RestructureApplied {
+    /// Task IDs for each planned key, whether newly created or found.
+    pub task_ids: HashMap<String, Uuid>,
+    pub tasks_created: usize,
+    pub edges_added: usize,
+    pub edges_removed: usize,
+    pub transitions: usize,
+    pub descriptions_updated: usize,
+}
+
+impl RestructureApplied {
+    /// Whether the apply made no changes.
+    pub fn is_noop(&self) -> bool {
+        self.tasks_created == 0
+            && self.edges_added == 0
+            && self.edges_removed == 0
+            && self.transitions == 0
+            && self.descriptions_updated == 0
+    }
+}
+
+fn command_error(e: CommandError) -> DomainError {
+    match e {
+        CommandError::DomainError(e) => e,
+        other => DomainError::ValidationFailed(other.to_string()),
+    }
+}
+
 /// Restructure state for tracking attempts.
 #[derive(Debug, Clone)]
 struct RestructureState {
@@ -400,6 +675,232 @@ impl DagRestructureService {
         }
     }
 
+    /// Plan restructuring for every permanently failed task in `tasks`
+    /// without changing anything. A task qualifies when it is failed, has
+    /// used all its retries, and [`should_restructure`](Self::should_restructure)
+    /// allows another attempt; a set with no such task yields an empty plan.
+    ///
+    /// Deciding counts as a restructure attempt for each qualifying task.
+    pub async fn plan(&mut self, tasks: &[Task]) -> DomainResult<RestructurePlan> {
+        let mut plan = RestructurePlan::default();
+        for task in tasks {
+            let trigger = RestructureTrigger::PermanentFailure {
+                task_id: task.id,
+                retries_exhausted: task.retry_count,
+            };
+            if !self.is_eligible(task)
+                || task.retry_count < task.max_retries
+                || !self.should_restructure(&trigger)
+            {
+                continue;
+            }
+            let context = RestructureContext {
+                goal: None,
+                failed_task: task.clone(),
+                failure_reason: format!("Task failed after {} retries", task.retry_count),
+                previous_attempts: vec![],
+                related_failures: tasks
+                    .iter()
+                    .filter(|t| t.id != task.id && t.status == TaskStatus::Failed)
+                    .cloned()
+                    .collect(),
+                available_approaches: vec![],
+                attempt_number: self.attempt_count(task.id) + 1,
+                time_since_last: None,
+            };
+            plan.merge(self.plan_for_failure(&context, tasks).await?);
+        }
+        Ok(plan)
+    }
+
+    /// Decide how to restructure `context.failed_task` and return the
+    /// resulting plan. `tasks` is the surrounding task set whose dependents
+    /// of the failed task may be rewired.
+    pub async fn plan_for_failure(
+        &mut self,
+        context: &RestructureContext,
+        tasks: &[Task],
+    ) -> DomainResult<RestructurePlan> {
+        let decision = self.analyze_and_decide(context).await?;
+        let attempt = self.attempt_count(context.failed_task.id);
+        Ok(RestructurePlan::from_decision(
+            &context.failed_task,
+            &decision,
+            attempt,
+            tasks,
+        ))
+    }
+
+    /// Execute a plan. New tasks go through the command bus with the plan's
+    /// idempotency keys, edges through the repository, and transitions only
+    /// fire when the task is still in the planned `from` status, so applying
+    /// the same plan twice changes nothing the second time.
+    pub async fn apply<T: TaskRepository + ?Sized>(
+        &self,
+        plan: &RestructurePlan,
+        task_repo: &T,
+        command_bus: &CommandBus,
+    ) -> DomainResult<RestructureApplied> {
+        let mut applied = RestructureApplied::default();
+
+        for update in &plan.description_updates {
+            let Some(mut task) = task_repo.get(update.task_id).await? else {
+                continue;
+            };
+            if task.description.ends_with(&update.note) {
+                continue;
+            }
+            task.description.push_str(&update.note);
+            if let Some(agent_type) = &update.agent_type {
+                task.agent_type = Some(agent_type.clone());
+            }
+            task.retry_count = 0;
+            task_repo.update(&task).await?;
+            applied.descriptions_updated += 1;
+        }
+
+        for planned in &plan.new_tasks {
+            // Edges between planned tasks ride along on submit so the new
+            // task is never briefly runnable ahead of its dependencies.
+            let depends_on = plan
+                .edges_added
+                .iter()
+                .filter(|e| e.task == TaskRef::Planned(planned.key.clone()))
+                .filter_map(|e| match &e.depends_on {
+                    TaskRef::Planned(key) => applied.task_ids.get(key).copied(),
+                    TaskRef::Existing(id) => Some(*id),
+                })
+                .collect();
+            let existed = task_repo
+                .get_by_idempotency_key(&planned.key)
+                .await?
+                .is_some();
+            let envelope = CommandEnvelope::new(
+                CommandSource::System,
+                DomainCommand::Task(TaskCommand::Submit {
+                    title: Some(planned.title.clone()),
+                    description: planned.description.clone(),
+                    parent_id: None,
+                    priority: planned.priority,
+                    agent_type: planned.agent_type.clone(),
+                    depends_on,
+                    context: Box::new(None),
+                    idempotency_key: Some(planned.key.clone()),
+                    source: TaskSource::System,
+                    deadline: None,
+                    task_type: None,
+                    execution_mode: None,
+                    estimate_secs: None,
+                    execution_params: None,
+                    required_overseers: Vec::new(),
+                    workspace_mode: None,
+                }),
+            );
+            match command_bus
+                .dispatch(envelope)
+                .await
+                .map_err(command_error)?
+            {
+                CommandResult::Task(task) => {
+                    applied.task_ids.insert(planned.key.clone(), task.id);
+                    if !existed {
+                        applied.tasks_created += 1;
+                    }
+                }
+                other => {
+                    return Err(DomainError::ValidationFailed(format!(
+                        "unexpected result submitting restructure task '{}': {:?}",
+                        planned.title, other
+                    )));
+                }
+            }
+        }
+
+        let resolve = |task_ref: &TaskRef| match task_ref {
+            TaskRef::Existing(id) => Some(*id),
+            TaskRef::Planned(key) => applied.task_ids.get(key).copied(),
+        };
+        let mut edges_added = 0;
+        for edge in &plan.edges_added {
+            let (Some(task_id), Some(depends_on)) =
+                (resolve(&edge.task), resolve(&edge.depends_on))
+            else {
+                continue;
+            };
+            if matches!(edge.task, TaskRef::Planned(_)) {
+                // Already set on submit.
+                continue;
+            }
+            let existing = task_repo.get_dependencies(task_id).await?;
+            if existing.iter().all(|t| t.id != depends_on) {
+                task_repo.add_dependency(task_id, depends_on).await?;
+                edges_added += 1;
+            }
+        }
+        let mut edges_removed = 0;
+        for edge in &plan.edges_removed {
+            let (Some(task_id), Some(depends_on)) =
+                (resolve(&edge.task), resolve(&edge.depends_on))
+            else {
+                continue;
+            };
+            let existing = task_repo.get_dependencies(task_id).await?;
+            if existing.iter().any(|t| t.id == depends_on) {
+                task_repo.remove_dependency(task_id, depends_on).await?;
+                edges_removed += 1;
+            }
+        }
+        applied.edges_added = edges_added;
+        applied.edges_removed = edges_removed;
+
+        for transition in &plan.transitions {
+            let Some(task) = task_repo.get(transition.task_id).await? else {
+                continue;
+            };
+            if task.status != transition.from {
+                if task.status != transition.to {
+                    tracing::warn!(
+                        "Skipping restructure transition for task {}: expected {}, found {}",
+                        task.id,
+                        transition.from.as_str(),
+                        task.status.as_str()
+                    );
+                }
+                continue;
+            }
+            // Cancel refuses terminal tasks, so a failed task being replaced
+            // is retired with a forced transition instead.
+            let command = if transition.to == TaskStatus::Canceled && transition.from.is_terminal()
+            {
+                TaskCommand::ForceTransition {
+                    task_id: task.id,
+                    new_status: transition.to,
+                    reason: transition.reason.clone(),
+                }
+            } else if transition.to == TaskStatus::Canceled {
+                TaskCommand::Cancel {
+                    task_id: task.id,
+                    reason: transition.reason.clone(),
+                }
+            } else {
+                TaskCommand::Transition {
+                    task_id: task.id,
+                    new_status: transition.to,
+                }
+            };
+            command_bus
+                .dispatch(CommandEnvelope::new(
+                    CommandSource::System,
+                    DomainCommand::Task(command),
+                ))
+                .await
+                .map_err(command_error)?;
+            applied.transitions += 1;
+        }
+
+        Ok(applied)
+    }
+
     /// Check if a task is eligible for restructuring.
     pub fn is_eligible(&self, task: &Task) -> bool {
         // Task must be in a failed state
@@ -962,4 +1463,124 @@ mod tests {
             _ => panic!("Expected AlternativePath decision for attempt 2"),
         }
     }
+
+    /// A permanently failed task with one downstream dependent, stored in a
+    /// fresh repository alongside a command bus over it.
+    async fn setup_failed_dag() -> (
+        Arc<crate::adapters::sqlite::SqliteTaskRepository>,
+        Arc<CommandBus>,
+        Task,
+        Task,
+    ) {
+        use crate::adapters::sqlite::test_support;
+
+        let repo = test_support::setup_task_repo().await;
+        let bus = test_support::make_command_bus(
+            &repo,
+            &test_support::setup_goal_repo().await,
+            &test_support::setup_memory_repo().await,
+        );
+        let mut failed = create_test_task();
+        failed.retry_count = failed.max_retries;
+        repo.create(&failed).await.unwrap();
+        let mut dependent = Task::with_title("Downstream", "Needs the failed task");
+        dependent.status = TaskStatus::Blocked;
+        dependent.depends_on = vec![failed.id];
+        repo.create(&dependent).await.unwrap();
+        repo.add_dependency(dependent.id, failed.id).await.unwrap();
+        (repo, bus, failed, dependent)
+    }
+
+    #[tokio::test]
+    async fn test_plan_decompose_rewires_dependents_without_applying() {
+        let (repo, _bus, failed, dependent) = setup_failed_dag().await;
+        let mut service = DagRestructureService::with_defaults();
+
+        let plan = service
+            .plan(&[failed.clone(), dependent.clone()])
+            .await
+            .unwrap();
+
+        assert_eq!(plan.decisions.len(), 1);
+        assert_eq!(plan.decisions[0].decision.kind(), "decompose_differently");
+        assert_eq!(plan.new_tasks.len(), 2);
+        let (research, implement) = (&plan.new_tasks[0], &plan.new_tasks[1]);
+        assert!(plan.edges_added.contains(&PlannedEdge {
+            task: TaskRef::Planned(implement.key.clone()),
+            depends_on: TaskRef::Planned(research.key.clone()),
+        }));
+        // The dependent moves from the failed task onto the last new task.
+        assert_eq!(
+            plan.edges_removed,
+            vec![PlannedEdge {
+                task: TaskRef::Existing(dependent.id),
+                depends_on: TaskRef::Existing(failed.id),
+            }]
+        );
+        assert!(plan.edges_added.contains(&PlannedEdge {
+            task: TaskRef::Existing(dependent.id),
+            depends_on: TaskRef::Planned(implement.key.clone()),
+        }));
+        assert_eq!(plan.transitions[0].to, TaskStatus::Canceled);
+
+        // Planning changed nothing, and the plan survives an audit round trip.
+        assert_eq!(
+            repo.get(failed.id).await.unwrap().unwrap().status,
+            TaskStatus::Failed
+        );
+        assert_eq!(repo.get_dependencies(dependent.id).await.unwrap().len(), 1);
+        let json = serde_json::to_value(&plan).unwrap();
+        assert_eq!(
+            serde_json::from_value::<RestructurePlan>(json).unwrap(),
+            plan
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_plan_twice_changes_nothing_the_second_time() {
+        let (repo, bus, failed, dependent) = setup_failed_dag().await;
+        let mut service = DagRestructureService::with_defaults();
+        let plan = service
+            .plan(&[failed.clone(), dependent.clone()])
+            .await
+            .unwrap();
+
+        let first = service.apply(&plan, repo.as_ref(), &bus).await.unwrap();
+        assert_eq!(first.tasks_created, 2);
+        assert_eq!((first.edges_added, first.edges_removed), (1, 1));
+        assert_eq!(first.transitions, 1);
+        let implement_id = first.task_ids[&plan.new_tasks[1].key];
+        let deps = repo.get_dependencies(dependent.id).await.unwrap();
+        assert_eq!(
+            deps.iter().map(|t| t.id).collect::<Vec<_>>(),
+            vec![implement_id]
+        );
+        assert_eq!(
+            repo.get(failed.id).await.unwrap().unwrap().status,
+            TaskStatus::Canceled
+        );
+
+        let second = service.apply(&plan, repo.as_ref(), &bus).await.unwrap();
+        assert!(second.is_noop());
+        assert_eq!(second.task_ids, first.task_ids);
+    }
+
+    #[tokio::test]
+    async fn test_apply_plan_of_well_formed_dag_is_noop() {
+        let (repo, bus, failed, dependent) = setup_failed_dag().await;
+        let mut healthy = repo.get(failed.id).await.unwrap().unwrap();
+        healthy.status = TaskStatus::Complete;
+        repo.update(&healthy).await.unwrap();
+        let mut service = DagRestructureService::with_defaults();
+
+        let plan = service.plan(&[healthy, dependent.clone()]).await.unwrap();
+        assert!(plan.is_empty());
+        assert!(plan.decisions.is_empty());
+
+        for _ in 0..2 {
+            let applied = service.apply(&plan, repo.as_ref(), &bus).await.unwrap();
+            assert!(applied.is_noop());
+        }
+        assert_eq!(repo.get_dependencies(dependent.id).await.unwrap().len(), 1);
+    }
 }
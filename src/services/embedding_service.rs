@@ -66,6 +66,11 @@ impl EmbeddingService {
         self.provider.dimension()
     }
 
+    /// Identifier of the model producing this service's vectors.
+    pub fn model_id(&self) -> String {
+        self.provider.model_id()
+    }
+
     /// Embed a single text.
     pub async fn embed_single(&self, text: &str) -> DomainResult<Vec<f32>> {
         self.provider.embed(text).await
@@ -0,0 +1,159 @@
+//! Urgency-based delivery of human escalations to notification sinks.
+//!
+//! `[notifications]` names a set of sinks (the swarm log, generic webhooks,
+//! Slack incoming webhooks) and maps each escalation urgency to the sinks it
+//! reaches, so a `critical` escalation can page someone while `low` ones only
+//! land in a log or channel.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::services::config::{
+    LOG_SINK, NotificationSinkConfig, NotificationSinkKind, NotificationsConfig,
+};
+use crate::services::event_bus::HumanEscalationPayload;
+
+/// A destination escalations are delivered to.
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    async fn notify(&self, escalation: &HumanEscalationPayload) -> Result<(), String>;
+}
+
+/// Writes escalations to the swarm log.
+pub struct LogSink;
+
+#[async_trait]
+impl NotificationSink for LogSink {
+    async fn notify(&self, escalation: &HumanEscalationPayload) -> Result<(), String> {
+        tracing::warn!(
+            goal_id = ?escalation.goal_id,
+            task_id = ?escalation.task_id,
+            urgency = %escalation.urgency,
+            blocking = escalation.is_blocking,
+            "Human escalation: {}",
+            escalation.reason
+        );
+        Ok(())
+    }
+}
+
+/// POSTs escalations to an HTTP endpoint, either as the raw escalation JSON
+/// or as a Slack incoming-webhook message.
+pub struct WebhookSink {
+    client: reqwest::Client,
+    url: String,
+    slack: bool,
+}
+
+impl WebhookSink {
+    pub fn new(url: impl Into<String>, slack: bool) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.into(),
+            slack,
+        }
+    }
+
+    fn body(&self, escalation: &HumanEscalationPayload) -> serde_json::Value {
+        if !self.slack {
+            return serde_json::to_value(escalation).unwrap_or_default();
+        }
+        let mut text = format!(
+            "*[{}] Human escalation*{}\n{}",
+            escalation.urgency.to_uppercase(),
+            if escalation.is_blocking {
+                " (blocking)"
+            } else {
+                ""
+            },
+            escalation.reason
+        );
+        for question in &escalation.questions {
+            text.push_str(&format!("\n• {}", question));
+        }
+        serde_json::json!({ "text": text })
+    }
+}
+
+#[async_trait]
+impl NotificationSink for WebhookSink {
+    async fn notify(&self, escalation: &HumanEscalationPayload) -> Result<(), String> {
+        let response = self
+            .client
+            .post(&self.url)
+            .json(&self.body(escalation))
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(|e| format!("POST {} failed: {}", self.url, e))?;
+        if !response.status().is_success() {
+            return Err(format!("POST {} returned {}", self.url, response.status()));
+        }
+        Ok(())
+    }
+}
+
+/// Routes each escalation to the sinks configured for its urgency.
+pub struct EscalationNotifier {
+    sinks: HashMap<String, Arc<dyn NotificationSink>>,
+    routes: HashMap<String, Vec<String>>,
+    default_sinks: Vec<String>,
+}
+
+impl EscalationNotifier {
+    pub fn from_config(config: &NotificationsConfig) -> Self {
+        let mut notifier = Self {
+            sinks: HashMap::new(),
+            routes: config.escalation_routes.clone(),
+            default_sinks: config.default_sinks.clone(),
+        }
+        .with_sink(LOG_SINK, Arc::new(LogSink));
+        for (name, sink) in &config.sinks {
+            notifier = notifier.with_sink(name, build_sink(sink));
+        }
+        notifier
+    }
+
+    /// Register (or replace) the sink called `name`.
+    pub fn with_sink(mut self, name: &str, sink: Arc<dyn NotificationSink>) -> Self {
+        self.sinks.insert(name.to_string(), sink);
+        self
+    }
+
+    /// Names of the sinks an escalation of `urgency` is sent to.
+    pub fn route(&self, urgency: &str) -> &[String] {
+        self.routes
+            .get(&urgency.to_lowercase())
+            .unwrap_or(&self.default_sinks)
+    }
+
+    /// Deliver `escalation` to every sink on its route. A failing sink is
+    /// logged and does not stop delivery to the others; returns the number of
+    /// sinks that accepted it.
+    pub async fn notify(&self, escalation: &HumanEscalationPayload) -> usize {
+        let mut delivered = 0;
+        for name in self.route(&escalation.urgency) {
+            let Some(sink) = self.sinks.get(name) else {
+                tracing::warn!(sink = %name, "Escalation routed to unknown notification sink");
+                continue;
+            };
+            match sink.notify(escalation).await {
+                Ok(()) => delivered += 1,
+                Err(e) => tracing::warn!(sink = %name, error = %e, "Escalation notification failed"),
+            }
+        }
+        delivered
+    }
+}
+
+fn build_sink(config: &NotificationSinkConfig) -> Arc<dyn NotificationSink> {
+    let url = config.url.clone().unwrap_or_default();
+    match config.kind {
+        NotificationSinkKind::Log => Arc::new(LogSink),
+        NotificationSinkKind::Webhook => Arc::new(WebhookSink::new(url, false)),
+        NotificationSinkKind::Slack => Arc::new(WebhookSink::new(url, true)),
+    }
+}
@@ -22,6 +22,8 @@ pub struct SwarmStatsPayload {
     pub active_agents: usize,
     pub active_worktrees: usize,
     pub total_tokens_used: u64,
+    #[serde(default)]
+    pub half_open_circuits: usize,
 }
 
 impl From<SwarmStats> for SwarmStatsPayload {
@@ -36,6 +38,7 @@ impl From<SwarmStats> for SwarmStatsPayload {
             active_agents: stats.active_agents,
             active_worktrees: stats.active_worktrees,
             total_tokens_used: stats.total_tokens_used,
+            half_open_circuits: stats.half_open_circuits,
         }
     }
 }
@@ -66,6 +69,9 @@ pub struct TaskResultPayload {
     /// `serde(default)`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub egress: Option<crate::domain::models::adapter::EgressDirective>,
+    /// Final text the agent produced, when the substrate reported one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
 }
 
 impl From<TaskResult> for TaskResultPayload {
@@ -82,6 +88,7 @@ impl From<TaskResult> for TaskResultPayload {
                 .map(|s| s.total_tokens())
                 .unwrap_or(0),
             egress: None,
+            output: result.session.and_then(|s| s.result),
         }
     }
 }
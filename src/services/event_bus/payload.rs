@@ -140,6 +140,13 @@ pub enum EventPayload {
         rule_id: Uuid,
         rule_name: String,
     },
+    /// Asks a running trigger engine to reload its rules from the repository
+    /// (published by `abathur trigger reload`).
+    TriggerRulesReloadRequested,
+    /// The trigger engine swapped in a freshly loaded rule set.
+    TriggerRulesReloaded {
+        rule_count: u32,
+    },
 
     /// A subsystem encountered an error that was isolated (not propagated).
     /// Emitted when the main loop catches a subsystem failure to ensure
@@ -243,6 +250,15 @@ pub enum EventPayload {
         task_id: Uuid,
         result: TaskResultPayload,
     },
+    /// A cacheable task reused the result of an identical earlier run
+    /// instead of starting an agent.
+    TaskResultCached {
+        task_id: Uuid,
+        /// Task whose result was reused.
+        source_task_id: Uuid,
+        /// Tokens the earlier run consumed, saved by this hit.
+        tokens_saved: u64,
+    },
     TaskFailed {
         task_id: Uuid,
         error: String,
@@ -253,6 +269,31 @@ pub enum EventPayload {
         attempt: u32,
         max_attempts: u32,
     },
+    /// An operator force-reset a stuck task back to a runnable state.
+    TaskRestarted {
+        task_id: Uuid,
+        from_status: String,
+        to_status: String,
+        retry_count: u32,
+    },
+    /// Optional context sections were trimmed so the assembled prompt fits
+    /// the substrate's context window.
+    PromptTruncated {
+        task_id: Uuid,
+        /// Sections dropped or truncated, lowest priority first.
+        dropped_sections: Vec<String>,
+        estimated_tokens: u64,
+        max_context_tokens: u64,
+    },
+    /// A piece of a running agent's output: assistant text, a tool call, or
+    /// a tool result. Persisted with the task so `task logs` can replay it.
+    AgentOutputChunk {
+        task_id: Uuid,
+        agent_type: String,
+        /// `text`, `tool_start`, `tool_result`, or `tool_error`.
+        kind: String,
+        content: String,
+    },
     TaskQueuedForMerge {
         task_id: Uuid,
         stage: String,
@@ -310,6 +351,11 @@ pub enum EventPayload {
         task_id: Uuid,
         reason: String,
     },
+    ArtifactsPruned {
+        tasks_affected: u32,
+        artifacts_removed: u32,
+        retention_days: u64,
+    },
     WorktreeCreated {
         task_id: Uuid,
         path: String,
@@ -434,6 +480,13 @@ pub enum EventPayload {
         task_id: Option<Uuid>,
         template_name: String,
     },
+    /// A task's agent type had no template, so a fallback from
+    /// `[task_routing.fallbacks]` runs it instead.
+    AgentTypeSubstituted {
+        task_id: Uuid,
+        requested: String,
+        substitute: String,
+    },
 
     // ========================================================================
     // Verification — intent/wave/branch verification, task alignment, verified
@@ -586,6 +639,13 @@ pub enum EventPayload {
         reason: String,
     },
 
+    /// A batch of memories was re-embedded with `model`.
+    MemoryReembedProgress {
+        model: String,
+        processed: u64,
+        total: u64,
+    },
+
     // ========================================================================
     // Scheduler — scheduled events, quiet-window enter/exit
     // ========================================================================
@@ -639,6 +699,15 @@ pub enum EventPayload {
         confidence: f64,
     },
 
+    /// Emitted when the adaptive budget stops a plateaued convergence loop.
+    ConvergenceEarlyStop {
+        task_id: Uuid,
+        trajectory_id: Uuid,
+        iteration: u32,
+        slope: f64,
+        min_slope: f64,
+    },
+
     /// Emitted when convergence requests a budget extension.
     ConvergenceBudgetExtension {
         task_id: Uuid,
@@ -752,6 +821,14 @@ pub enum EventPayload {
         adapter_name: String,
         items_found: usize,
         tasks_created: usize,
+        /// Items skipped because a task already exists for them.
+        #[serde(default)]
+        duplicates_skipped: usize,
+        /// Items left for a later poll by the shared submission rate limit.
+        #[serde(default)]
+        rate_limited: usize,
+        #[serde(default)]
+        poll_duration_ms: u64,
     },
 
     /// Adapter ingestion poll failed.
@@ -803,6 +880,15 @@ pub enum EventPayload {
         opportunity_score: f64,
     },
 
+    /// A goal's attributed token spend reached its per-goal budget; tasks in
+    /// the goal's domains are no longer spawned.
+    GoalBudgetExceeded {
+        goal_id: Uuid,
+        goal_name: String,
+        tokens_used: u64,
+        budget: u64,
+    },
+
     // ========================================================================
     // Federation — cerebrate connectivity, task delegation, federated goals,
     //              swarm DAGs
@@ -979,8 +1065,12 @@ impl EventPayload {
             Self::TaskStarted { .. } => "TaskStarted",
             Self::TaskCompleted { .. } => "TaskCompleted",
             Self::TaskCompletedWithResult { .. } => "TaskCompletedWithResult",
+            Self::TaskResultCached { .. } => "TaskResultCached",
             Self::TaskFailed { .. } => "TaskFailed",
             Self::TaskRetrying { .. } => "TaskRetrying",
+            Self::TaskRestarted { .. } => "TaskRestarted",
+            Self::PromptTruncated { .. } => "PromptTruncated",
+            Self::AgentOutputChunk { .. } => "AgentOutputChunk",
             Self::TaskVerified { .. } => "TaskVerified",
             Self::TaskQueuedForMerge { .. } => "TaskQueuedForMerge",
             Self::PullRequestCreated { .. } => "PullRequestCreated",
@@ -1028,6 +1118,7 @@ impl EventPayload {
             Self::AgentInstanceSpawned { .. } => "AgentInstanceSpawned",
             Self::AgentInstanceAssigned { .. } => "AgentInstanceAssigned",
             Self::AgentInstanceFailed { .. } => "AgentInstanceFailed",
+            Self::AgentTypeSubstituted { .. } => "AgentTypeSubstituted",
             Self::MemoryDeleted { .. } => "MemoryDeleted",
             Self::MemoryConflictDetected { .. } => "MemoryConflictDetected",
             Self::MemoryConflictResolved { .. } => "MemoryConflictResolved",
@@ -1039,14 +1130,18 @@ impl EventPayload {
             Self::TriggerRuleCreated { .. } => "TriggerRuleCreated",
             Self::TriggerRuleToggled { .. } => "TriggerRuleToggled",
             Self::TriggerRuleDeleted { .. } => "TriggerRuleDeleted",
+            Self::TriggerRulesReloadRequested => "TriggerRulesReloadRequested",
+            Self::TriggerRulesReloaded { .. } => "TriggerRulesReloaded",
             Self::MemoryMaintenanceCompleted { .. } => "MemoryMaintenanceCompleted",
             Self::MemoryMaintenanceFailed { .. } => "MemoryMaintenanceFailed",
             Self::MemoryDaemonDegraded { .. } => "MemoryDaemonDegraded",
             Self::MemoryDaemonStopped { .. } => "MemoryDaemonStopped",
+            Self::MemoryReembedProgress { .. } => "MemoryReembedProgress",
             Self::HandlerError { .. } => "HandlerError",
             Self::CriticalHandlerDegraded { .. } => "CriticalHandlerDegraded",
             Self::TaskDependencyChanged { .. } => "TaskDependencyChanged",
             Self::TaskPriorityChanged { .. } => "TaskPriorityChanged",
+            Self::ArtifactsPruned { .. } => "ArtifactsPruned",
             Self::HumanEscalationExpired { .. } => "HumanEscalationExpired",
             Self::WorktreeDestroyed { .. } => "WorktreeDestroyed",
             Self::StartupCatchUpCompleted { .. } => "StartupCatchUpCompleted",
@@ -1061,6 +1156,7 @@ impl EventPayload {
             Self::ConvergenceStarted { .. } => "ConvergenceStarted",
             Self::ConvergenceIteration(_) => "ConvergenceIteration",
             Self::ConvergenceAttractorTransition { .. } => "ConvergenceAttractorTransition",
+            Self::ConvergenceEarlyStop { .. } => "ConvergenceEarlyStop",
             Self::ConvergenceBudgetExtension { .. } => "ConvergenceBudgetExtension",
             Self::ConvergenceFreshStart { .. } => "ConvergenceFreshStart",
             Self::ConvergenceTerminated(_) => "ConvergenceTerminated",
@@ -1073,6 +1169,7 @@ impl EventPayload {
             Self::AdapterTaskIngested { .. } => "AdapterTaskIngested",
             Self::BudgetPressureChanged { .. } => "BudgetPressureChanged",
             Self::BudgetOpportunityDetected { .. } => "BudgetOpportunityDetected",
+            Self::GoalBudgetExceeded { .. } => "GoalBudgetExceeded",
             Self::WorkflowEnrolled { .. } => "WorkflowEnrolled",
             Self::WorkflowPhaseStarted { .. } => "WorkflowPhaseStarted",
             Self::WorkflowGateReached { .. } => "WorkflowGateReached",
@@ -1124,7 +1221,9 @@ impl EventPayload {
             | Self::CriticalHandlerDegraded { .. }
             | Self::TriggerRuleCreated { .. }
             | Self::TriggerRuleToggled { .. }
-            | Self::TriggerRuleDeleted { .. } => Some(EventCategory::Orchestrator),
+            | Self::TriggerRuleDeleted { .. }
+            | Self::TriggerRulesReloadRequested
+            | Self::TriggerRulesReloaded { .. } => Some(EventCategory::Orchestrator),
 
             Self::GoalStarted { .. }
             | Self::GoalDecomposed { .. }
@@ -1146,8 +1245,12 @@ impl EventPayload {
             | Self::TaskStarted { .. }
             | Self::TaskCompleted { .. }
             | Self::TaskCompletedWithResult { .. }
+            | Self::TaskResultCached { .. }
             | Self::TaskFailed { .. }
             | Self::TaskRetrying { .. }
+            | Self::TaskRestarted { .. }
+            | Self::PromptTruncated { .. }
+            | Self::AgentOutputChunk { .. }
             | Self::TaskQueuedForMerge { .. }
             | Self::TaskMerged { .. }
             | Self::TaskClaimed { .. }
@@ -1161,6 +1264,7 @@ impl EventPayload {
             | Self::TaskDependencyChanged { .. }
             | Self::TaskPriorityChanged { .. }
             | Self::TaskDescriptionUpdated { .. }
+            | Self::ArtifactsPruned { .. }
             | Self::WorktreeCreated { .. }
             | Self::WorktreeDestroyed { .. }
             | Self::PullRequestCreated { .. }
@@ -1183,7 +1287,8 @@ impl EventPayload {
             | Self::AgentTemplateStatusChanged { .. }
             | Self::AgentInstanceSpawned { .. }
             | Self::AgentInstanceAssigned { .. }
-            | Self::AgentInstanceFailed { .. } => Some(EventCategory::Agent),
+            | Self::AgentInstanceFailed { .. }
+            | Self::AgentTypeSubstituted { .. } => Some(EventCategory::Agent),
 
             Self::IntentVerificationStarted { .. }
             | Self::IntentVerificationCompleted(_)
@@ -1213,7 +1318,8 @@ impl EventPayload {
             | Self::MemoryMaintenanceCompleted { .. }
             | Self::MemoryMaintenanceFailed { .. }
             | Self::MemoryDaemonDegraded { .. }
-            | Self::MemoryDaemonStopped { .. } => Some(EventCategory::Memory),
+            | Self::MemoryDaemonStopped { .. }
+            | Self::MemoryReembedProgress { .. } => Some(EventCategory::Memory),
 
             Self::ScheduledEventFired { .. }
             | Self::ScheduledEventRegistered { .. }
@@ -1222,6 +1328,7 @@ impl EventPayload {
             Self::ConvergenceStarted { .. }
             | Self::ConvergenceIteration(_)
             | Self::ConvergenceAttractorTransition { .. }
+            | Self::ConvergenceEarlyStop { .. }
             | Self::ConvergenceBudgetExtension { .. }
             | Self::ConvergenceFreshStart { .. }
             | Self::ConvergenceTerminated(_) => Some(EventCategory::Convergence),
@@ -1247,9 +1354,9 @@ impl EventPayload {
             | Self::AdapterEgressFailed { .. }
             | Self::AdapterTaskIngested { .. } => Some(EventCategory::Adapter),
 
-            Self::BudgetPressureChanged { .. } | Self::BudgetOpportunityDetected { .. } => {
-                Some(EventCategory::Budget)
-            }
+            Self::BudgetPressureChanged { .. }
+            | Self::BudgetOpportunityDetected { .. }
+            | Self::GoalBudgetExceeded { .. } => Some(EventCategory::Budget),
 
             Self::FederationCerebrateConnected { .. }
             | Self::FederationCerebrateDisconnected { .. }
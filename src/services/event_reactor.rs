@@ -20,6 +20,7 @@ use super::event_bus::{
     EventBus, EventCategory, EventId, EventPayload, EventSeverity, SequenceNumber, UnifiedEvent,
 };
 use super::event_store::EventStore;
+use super::leader_election::LeaderElection;
 use super::supervise_with_handle;
 
 /// Unique identifier for a registered handler.
@@ -186,6 +187,10 @@ pub struct HandlerMetadata {
     /// transitions). When their circuit breaker trips, they use aggressive retry
     /// with exponential backoff instead of the default flat cooldown.
     pub critical: bool,
+    /// Singleton handlers do shared-database housekeeping that must not be
+    /// repeated by every swarm process. When the reactor has a
+    /// [`LeaderElection`], they only run in the process holding the lease.
+    pub singleton: bool,
 }
 
 /// Context passed to handlers during event processing.
@@ -399,6 +404,9 @@ pub struct ReactorConfig {
     /// Maximum number of events to replay during startup catch-up.
     /// None means replay all missed events (unbounded).
     pub startup_max_replay_events: Option<usize>,
+    /// Events fetched from the store per replay page. Watermarks are
+    /// persisted after each page so an interrupted replay resumes there.
+    pub replay_page_size: usize,
 }
 
 impl Default for ReactorConfig {
@@ -414,6 +422,7 @@ impl Default for ReactorConfig {
             critical_cooldown_max_secs: 16,
             dedup_set_capacity: 50_000,
             startup_max_replay_events: Some(10_000),
+            replay_page_size: 500,
         }
     }
 }
@@ -434,6 +443,7 @@ pub struct EventReactor {
     watermark_buffer: Arc<RwLock<HashMap<String, SequenceNumber>>>,
     last_watermark_flush: Arc<RwLock<Instant>>,
     watermark_event_count: Arc<AtomicU64>,
+    leader_election: Option<Arc<LeaderElection>>,
 }
 
 impl EventReactor {
@@ -451,6 +461,7 @@ impl EventReactor {
             watermark_buffer: Arc::new(RwLock::new(HashMap::new())),
             last_watermark_flush: Arc::new(RwLock::new(Instant::now())),
             watermark_event_count: Arc::new(AtomicU64::new(0)),
+            leader_election: None,
         }
     }
 
@@ -460,6 +471,14 @@ impl EventReactor {
         self
     }
 
+    /// Gate singleton handlers on holding the cross-process leader lease.
+    ///
+    /// Without this, singleton handlers run in every process like any other.
+    pub fn with_leader_election(mut self, election: Arc<LeaderElection>) -> Self {
+        self.leader_election = Some(election);
+        self
+    }
+
     /// Register a handler.
     pub async fn register(&self, handler: Arc<dyn EventHandler>) {
         let meta = handler.metadata();
@@ -490,6 +509,19 @@ impl EventReactor {
         let watermark_buffer = self.watermark_buffer.clone();
         let last_watermark_flush = self.last_watermark_flush.clone();
         let watermark_event_count = self.watermark_event_count.clone();
+        let leader_election = self.leader_election.clone();
+
+        // Keep the lease renewed while idle so leadership does not bounce
+        // between processes whenever no singleton-relevant events arrive.
+        if let Some(election) = leader_election.clone() {
+            let running = self.running.clone();
+            supervise_with_handle("event_reactor_leader_lease", async move {
+                while running.load(Ordering::SeqCst) {
+                    election.is_leader().await;
+                    tokio::time::sleep(election.renew_interval()).await;
+                }
+            });
+        }
 
         supervise_with_handle("event_reactor_dispatch", async move {
             let mut receiver = event_bus.subscribe();
@@ -515,59 +547,81 @@ impl EventReactor {
                         );
                         // Recover missed events from the journal
                         if let Some(ref store) = event_store {
-                            match store
-                                .replay_since(SequenceNumber(last_processed_sequence))
-                                .await
-                            {
-                                Ok(missed) => {
-                                    tracing::info!(
-                                        "EventReactor: recovering {} events from journal",
-                                        missed.len()
-                                    );
-                                    let hs = handlers.read().await;
-                                    for missed_event in &missed {
-                                        if dedup.contains(&missed_event.sequence.0) {
-                                            continue;
-                                        }
-                                        for handler in hs.iter() {
-                                            let meta = handler.metadata();
-                                            if !meta.filter.matches(missed_event) {
+                            let page_size = config.replay_page_size.max(1);
+                            let mut cursor = SequenceNumber(last_processed_sequence);
+                            loop {
+                                match store.replay_since_page(cursor, page_size).await {
+                                    Ok(missed) => {
+                                        tracing::info!(
+                                            "EventReactor: recovering {} events from journal",
+                                            missed.len()
+                                        );
+                                        let hs = handlers.read().await;
+                                        for missed_event in &missed {
+                                            if dedup.contains(&missed_event.sequence.0) {
                                                 continue;
                                             }
-                                            let ctx = HandlerContext {
-                                                chain_depth: 0,
-                                                correlation_id: missed_event.correlation_id,
-                                            };
-                                            let _ = tokio::time::timeout(
-                                                Duration::from_millis(config.handler_timeout_ms),
-                                                handler.handle(missed_event, &ctx),
-                                            )
-                                            .await;
-                                        }
-                                        // Advance watermarks for all handlers (processed or filter-skipped)
-                                        {
-                                            let mut wm_buf = watermark_buffer.write().await;
                                             for handler in hs.iter() {
-                                                wm_buf.insert(
-                                                    handler.metadata().name.clone(),
-                                                    missed_event.sequence,
-                                                );
+                                                let meta = handler.metadata();
+                                                if !meta.filter.matches(missed_event) {
+                                                    continue;
+                                                }
+                                                // The leader handles this event; advance
+                                                // the watermark so it is not replayed again.
+                                                if meta.singleton
+                                                    && !is_leader(leader_election.as_deref()).await
+                                                {
+                                                    watermark_buffer.write().await.insert(
+                                                        meta.name.clone(),
+                                                        missed_event.sequence,
+                                                    );
+                                                    continue;
+                                                }
+                                                let ctx = HandlerContext {
+                                                    chain_depth: 0,
+                                                    correlation_id: missed_event.correlation_id,
+                                                };
+                                                let _ = tokio::time::timeout(
+                                                    Duration::from_millis(
+                                                        config.handler_timeout_ms,
+                                                    ),
+                                                    handler.handle(missed_event, &ctx),
+                                                )
+                                                .await;
+                                            }
+                                            // Advance watermarks for all handlers (processed or filter-skipped)
+                                            {
+                                                let mut wm_buf = watermark_buffer.write().await;
+                                                for handler in hs.iter() {
+                                                    wm_buf.insert(
+                                                        handler.metadata().name.clone(),
+                                                        missed_event.sequence,
+                                                    );
+                                                }
+                                            }
+                                            if missed_event.sequence.0 > last_processed_sequence {
+                                                last_processed_sequence = missed_event.sequence.0;
+                                            }
+                                            dedup.push_back(missed_event.sequence.0);
+                                            if dedup.len() > config.dedup_set_capacity {
+                                                dedup.pop_front();
                                             }
                                         }
-                                        if missed_event.sequence.0 > last_processed_sequence {
-                                            last_processed_sequence = missed_event.sequence.0;
-                                        }
-                                        dedup.push_back(missed_event.sequence.0);
-                                        if dedup.len() > config.dedup_set_capacity {
-                                            dedup.pop_front();
+                                        let Some(last) = missed.last() else {
+                                            break;
+                                        };
+                                        cursor = SequenceNumber(last.sequence.0 + 1);
+                                        if missed.len() < page_size {
+                                            break;
                                         }
                                     }
-                                }
-                                Err(e) => {
-                                    tracing::error!(
-                                        "EventReactor: failed to recover from lag: {}",
-                                        e
-                                    );
+                                    Err(e) => {
+                                        tracing::error!(
+                                            "EventReactor: failed to recover from lag: {}",
+                                            e
+                                        );
+                                        break;
+                                    }
                                 }
                             }
                         }
@@ -662,6 +716,13 @@ impl EventReactor {
                         continue;
                     }
 
+                    // Singleton handlers run only in the leader process; the
+                    // leader handles this event, so the watermark still advances.
+                    if meta.singleton && !is_leader(leader_election.as_deref()).await {
+                        skipped_handlers.push(meta.name.clone());
+                        continue;
+                    }
+
                     let ctx = HandlerContext {
                         chain_depth,
                         correlation_id: event.correlation_id,
@@ -1100,86 +1161,105 @@ impl EventReactor {
             None => return Ok(0),
         };
 
-        // Query events since the minimum watermark
-        let mut events = store
-            .replay_since(min_seq)
-            .await
-            .map_err(|e| format!("Failed to replay events: {}", e))?;
-
-        // Apply max replay limit from config
-        if let Some(max) = self.config.startup_max_replay_events
-            && events.len() > max
-        {
-            tracing::warn!(
-                "Truncating replay from {} to {} events (startup_max_replay_events)",
-                events.len(),
-                max
-            );
-            events.truncate(max);
-        }
-
+        let page_size = self.config.replay_page_size.max(1);
+        let mut cursor = min_seq;
         let mut replayed_count: u64 = 0;
 
-        for event in &events {
-            for handler in handlers.iter() {
-                let meta = handler.metadata();
-
-                // Only dispatch to handlers whose watermark is below this event's sequence
-                let handler_wm = handler_watermarks
-                    .get(&meta.name)
-                    .copied()
-                    .unwrap_or(SequenceNumber(0));
+        loop {
+            // Apply max replay limit from config
+            let limit = match self.config.startup_max_replay_events {
+                Some(max) => page_size.min(max.saturating_sub(replayed_count as usize)),
+                None => page_size,
+            };
+            if limit == 0 {
+                tracing::warn!(
+                    "Stopping replay after {} events (startup_max_replay_events)",
+                    replayed_count
+                );
+                break;
+            }
 
-                if event.sequence <= handler_wm {
-                    continue;
-                }
+            let events = store
+                .replay_since_page(cursor, limit)
+                .await
+                .map_err(|e| format!("Failed to replay events: {}", e))?;
+            let Some(last) = events.last() else {
+                break;
+            };
+            cursor = SequenceNumber(last.sequence.0 + 1);
+
+            for event in &events {
+                for handler in handlers.iter() {
+                    let meta = handler.metadata();
 
-                if !meta.filter.matches(event) {
-                    // Filter-skipped: still advance watermark (replay would skip again)
-                    handler_watermarks.insert(meta.name.clone(), event.sequence);
-                    continue;
-                }
+                    // Only dispatch to handlers whose watermark is below this event's sequence
+                    let handler_wm = handler_watermarks
+                        .get(&meta.name)
+                        .copied()
+                        .unwrap_or(SequenceNumber(0));
 
-                let ctx = HandlerContext {
-                    chain_depth: 0,
-                    correlation_id: event.correlation_id,
-                };
+                    if event.sequence <= handler_wm {
+                        continue;
+                    }
 
-                // Execute handler, ignore reactions during replay
-                match tokio::time::timeout(
-                    Duration::from_millis(self.config.handler_timeout_ms),
-                    handler.handle(event, &ctx),
-                )
-                .await
-                {
-                    Ok(Ok(_)) => {
-                        // Update handler watermark in our local map
+                    if !meta.filter.matches(event) {
+                        // Filter-skipped: still advance watermark (replay would skip again)
                         handler_watermarks.insert(meta.name.clone(), event.sequence);
+                        continue;
                     }
-                    Ok(Err(e)) => {
-                        tracing::warn!(
-                            "Replay: handler '{}' error on seq {}: {}",
-                            meta.name,
-                            event.sequence,
-                            e
-                        );
+
+                    // Leader-skipped: the leader handles it, so advance too.
+                    if meta.singleton && !is_leader(self.leader_election.as_deref()).await {
+                        handler_watermarks.insert(meta.name.clone(), event.sequence);
+                        continue;
                     }
-                    Err(_) => {
-                        tracing::warn!(
-                            "Replay: handler '{}' timed out on seq {}",
-                            meta.name,
-                            event.sequence
-                        );
+
+                    let ctx = HandlerContext {
+                        chain_depth: 0,
+                        correlation_id: event.correlation_id,
+                    };
+
+                    // Execute handler, ignore reactions during replay
+                    match tokio::time::timeout(
+                        Duration::from_millis(self.config.handler_timeout_ms),
+                        handler.handle(event, &ctx),
+                    )
+                    .await
+                    {
+                        Ok(Ok(_)) => {
+                            // Update handler watermark in our local map
+                            handler_watermarks.insert(meta.name.clone(), event.sequence);
+                        }
+                        Ok(Err(e)) => {
+                            tracing::warn!(
+                                "Replay: handler '{}' error on seq {}: {}",
+                                meta.name,
+                                event.sequence,
+                                e
+                            );
+                        }
+                        Err(_) => {
+                            tracing::warn!(
+                                "Replay: handler '{}' timed out on seq {}",
+                                meta.name,
+                                event.sequence
+                            );
+                        }
                     }
                 }
+                replayed_count += 1;
             }
-            replayed_count += 1;
-        }
 
-        // Flush updated watermarks to store
-        for (name, seq) in &handler_watermarks {
-            if let Err(e) = store.set_watermark(name, *seq).await {
-                tracing::warn!("Failed to update watermark for {}: {}", name, e);
+            // Flush watermarks after every page so a crash mid-replay
+            // resumes from here instead of the original minimum.
+            for (name, seq) in &handler_watermarks {
+                if let Err(e) = store.set_watermark(name, *seq).await {
+                    tracing::warn!("Failed to update watermark for {}: {}", name, e);
+                }
+            }
+
+            if events.len() < limit {
+                break;
             }
         }
 
@@ -1187,10 +1267,20 @@ impl EventReactor {
     }
 }
 
+/// Whether singleton handlers may run in this process. Without leader
+/// election every process counts as the leader.
+async fn is_leader(election: Option<&LeaderElection>) -> bool {
+    match election {
+        Some(election) => election.is_leader().await,
+        None => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::services::event_bus::{EventBusConfig, EventId, EventPayload, SequenceNumber};
+    use crate::services::event_store::EventStoreError;
     use chrono::Utc;
 
     struct TestHandler {
@@ -1218,6 +1308,7 @@ mod tests {
                 priority: HandlerPriority::NORMAL,
                 error_strategy: ErrorStrategy::CircuitBreak,
                 critical: false,
+                singleton: false,
             }
         }
 
@@ -1356,6 +1447,7 @@ mod tests {
                     priority: HandlerPriority::NORMAL,
                     error_strategy: ErrorStrategy::LogAndContinue,
                     critical: false,
+                    singleton: false,
                 }
             }
             async fn handle(
@@ -1438,6 +1530,7 @@ mod tests {
                 priority: self.priority,
                 error_strategy: self.error_strategy,
                 critical: false,
+                singleton: false,
             }
         }
 
@@ -1480,6 +1573,7 @@ mod tests {
                 priority: self.priority,
                 error_strategy: ErrorStrategy::LogAndContinue,
                 critical: false,
+                singleton: false,
             }
         }
 
@@ -1671,6 +1765,120 @@ mod tests {
         handle.abort();
     }
 
+    /// Delegates to an `InMemoryEventStore`, recording each replay page
+    /// request and every watermark write.
+    struct PageRecordingStore {
+        inner: crate::services::event_store::InMemoryEventStore,
+        pages: tokio::sync::Mutex<Vec<(u64, usize)>>,
+        watermark_writes: tokio::sync::Mutex<Vec<u64>>,
+    }
+
+    #[async_trait]
+    impl EventStore for PageRecordingStore {
+        async fn append(&self, event: &UnifiedEvent) -> Result<(), EventStoreError> {
+            self.inner.append(event).await
+        }
+
+        async fn query(
+            &self,
+            query: crate::services::event_store::EventQuery,
+        ) -> Result<Vec<UnifiedEvent>, EventStoreError> {
+            self.inner.query(query).await
+        }
+
+        async fn latest_sequence(&self) -> Result<Option<SequenceNumber>, EventStoreError> {
+            self.inner.latest_sequence().await
+        }
+
+        async fn count(&self) -> Result<u64, EventStoreError> {
+            self.inner.count().await
+        }
+
+        async fn prune_older_than(&self, duration: Duration) -> Result<u64, EventStoreError> {
+            self.inner.prune_older_than(duration).await
+        }
+
+        async fn replay_since_page(
+            &self,
+            sequence: SequenceNumber,
+            limit: usize,
+        ) -> Result<Vec<UnifiedEvent>, EventStoreError> {
+            let page = self.inner.replay_since_page(sequence, limit).await?;
+            assert!(page.len() <= limit, "page exceeded requested limit");
+            self.pages.lock().await.push((sequence.0, page.len()));
+            Ok(page)
+        }
+
+        async fn get_watermark(
+            &self,
+            handler_name: &str,
+        ) -> Result<Option<SequenceNumber>, EventStoreError> {
+            self.inner.get_watermark(handler_name).await
+        }
+
+        async fn set_watermark(
+            &self,
+            handler_name: &str,
+            seq: SequenceNumber,
+        ) -> Result<(), EventStoreError> {
+            self.watermark_writes.lock().await.push(seq.0);
+            self.inner.set_watermark(handler_name, seq).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_missed_events_in_bounded_pages() {
+        let store = Arc::new(PageRecordingStore {
+            inner: crate::services::event_store::InMemoryEventStore::new(),
+            pages: tokio::sync::Mutex::new(Vec::new()),
+            watermark_writes: tokio::sync::Mutex::new(Vec::new()),
+        });
+        for seq in 1..=25 {
+            let mut event = make_test_event(EventCategory::Task);
+            event.sequence = SequenceNumber(seq);
+            store.append(&event).await.unwrap();
+        }
+        store
+            .set_watermark("paged", SequenceNumber(0))
+            .await
+            .unwrap();
+        store.watermark_writes.lock().await.clear();
+
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let config = ReactorConfig {
+            replay_page_size: 10,
+            startup_max_replay_events: None,
+            ..Default::default()
+        };
+        let reactor = EventReactor::new(bus, config).with_store(store.clone());
+        let call_count = Arc::new(AtomicU64::new(0));
+        reactor
+            .register(Arc::new(TestHandler {
+                id: HandlerId::new(),
+                name: "paged".to_string(),
+                filter: EventFilter {
+                    categories: vec![EventCategory::Task],
+                    ..Default::default()
+                },
+                call_count: call_count.clone(),
+                should_fail: false,
+            }))
+            .await;
+
+        let replayed = reactor.replay_missed_events().await.unwrap();
+        assert_eq!(replayed, 25);
+        assert_eq!(call_count.load(Ordering::Relaxed), 25);
+
+        // Three bounded pages, each starting just past the previous one.
+        assert_eq!(*store.pages.lock().await, vec![(0, 10), (11, 10), (21, 5)]);
+        // Watermark persisted once per page at that page's last sequence.
+        assert_eq!(*store.watermark_writes.lock().await, vec![10, 20, 25]);
+        assert_eq!(
+            store.get_watermark("paged").await.unwrap(),
+            Some(SequenceNumber(25))
+        );
+    }
+
     #[tokio::test]
     async fn test_handlers_execute_in_priority_order() {
         let bus = Arc::new(EventBus::new(EventBusConfig::default()));
@@ -1800,6 +2008,7 @@ mod tests {
                 priority: HandlerPriority::NORMAL,
                 error_strategy: self.error_strategy,
                 critical: self.critical,
+                singleton: false,
             }
         }
 
@@ -10,6 +10,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use uuid::Uuid;
@@ -18,6 +19,7 @@ use super::event_bus::{
     EventBus, EventCategory, EventId, EventPayload, EventSeverity, SequenceNumber, UnifiedEvent,
 };
 use super::supervise_with_handle;
+use super::trigger_rules::normalize_cron_expression;
 
 /// Type of schedule.
 #[derive(Debug, Clone)]
@@ -26,8 +28,15 @@ pub enum ScheduleType {
     Once { at: DateTime<Utc> },
     /// Fire at a fixed interval.
     Interval { every: Duration },
-    /// Fire according to a cron expression.
-    Cron { expression: String },
+    /// Fire according to a cron expression (5, 6, or 7 fields).
+    ///
+    /// The expression is evaluated against wall-clock time in `timezone`
+    /// (UTC when `None`). Across DST transitions a local time that does not
+    /// exist that day is skipped, and one that occurs twice fires once.
+    Cron {
+        expression: String,
+        timezone: Option<Tz>,
+    },
 }
 
 /// Serializable form of ScheduleType for DB persistence.
@@ -39,7 +48,11 @@ enum ScheduleData {
     #[serde(rename = "interval")]
     Interval { every_secs: u64 },
     #[serde(rename = "cron")]
-    Cron { cron: String },
+    Cron {
+        cron: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timezone: Option<String>,
+    },
 }
 
 impl From<&ScheduleType> for ScheduleData {
@@ -51,8 +64,12 @@ impl From<&ScheduleType> for ScheduleData {
             ScheduleType::Interval { every } => ScheduleData::Interval {
                 every_secs: every.as_secs(),
             },
-            ScheduleType::Cron { expression } => ScheduleData::Cron {
+            ScheduleType::Cron {
+                expression,
+                timezone,
+            } => ScheduleData::Cron {
                 cron: expression.clone(),
+                timezone: timezone.map(|tz| tz.name().to_string()),
             },
         }
     }
@@ -71,8 +88,9 @@ impl ScheduleData {
             ScheduleData::Interval { every_secs } => Some(ScheduleType::Interval {
                 every: Duration::from_secs(*every_secs),
             }),
-            ScheduleData::Cron { cron } => Some(ScheduleType::Cron {
+            ScheduleData::Cron { cron, timezone } => Some(ScheduleType::Cron {
                 expression: cron.clone(),
+                timezone: timezone.as_deref().map(str::parse::<Tz>).transpose().ok()?,
             }),
         }
     }
@@ -88,6 +106,11 @@ impl ScheduleType {
     }
 }
 
+/// Parse a cron expression, accepting the 5- and 6-field forms as well.
+fn parse_cron(expression: &str) -> Result<cron::Schedule, cron::error::Error> {
+    cron::Schedule::from_str(&normalize_cron_expression(expression))
+}
+
 /// A registered scheduled event.
 #[derive(Debug, Clone)]
 pub struct ScheduledEvent {
@@ -104,6 +127,43 @@ pub struct ScheduledEvent {
     pub fire_count: u64,
 }
 
+impl ScheduledEvent {
+    /// Whether this schedule should fire at `now`.
+    ///
+    /// Cron schedules compare against the first occurrence after the last
+    /// fire (or creation). Since firing moves `last_fired` to `now`, any
+    /// number of windows missed while the scheduler was down collapse into
+    /// a single catch-up fire rather than one per missed period.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.active && self.next_fire_at().is_some_and(|next| now >= next)
+    }
+
+    /// When this schedule next fires, or `None` if it never will.
+    ///
+    /// May be in the past for a schedule that is already due.
+    pub fn next_fire_at(&self) -> Option<DateTime<Utc>> {
+        match &self.schedule {
+            ScheduleType::Once { at } => (self.fire_count == 0).then_some(*at),
+            ScheduleType::Interval { every } => match self.last_fired {
+                None => Some(self.created_at), // Never fired, fire now
+                Some(last) => last.checked_add_signed(chrono::Duration::from_std(*every).ok()?),
+            },
+            ScheduleType::Cron {
+                expression,
+                timezone,
+            } => {
+                let schedule = parse_cron(expression).ok()?;
+                let tz = timezone.unwrap_or(chrono_tz::UTC);
+                let reference_time = self.last_fired.unwrap_or(self.created_at);
+                schedule
+                    .after(&reference_time.with_timezone(&tz))
+                    .next()
+                    .map(|next| next.with_timezone(&Utc))
+            }
+        }
+    }
+}
+
 /// Configuration for the EventScheduler.
 #[derive(Debug, Clone)]
 pub struct SchedulerConfig {
@@ -263,6 +323,17 @@ impl EventScheduler {
             );
             return None;
         }
+        if let ScheduleType::Cron { expression, .. } = &schedule.schedule
+            && let Err(e) = parse_cron(expression)
+        {
+            tracing::warn!(
+                "EventScheduler: invalid cron expression '{}' for '{}': {}",
+                expression,
+                schedule.name,
+                e
+            );
+            return None;
+        }
         let id = schedule.id;
 
         // Emit registration event
@@ -361,38 +432,7 @@ impl EventScheduler {
                 {
                     let scheds = schedules.read().await;
                     for (idx, sched) in scheds.iter().enumerate() {
-                        if !sched.active {
-                            continue;
-                        }
-
-                        let should_fire = match &sched.schedule {
-                            ScheduleType::Once { at } => now >= *at && sched.fire_count == 0,
-                            ScheduleType::Interval { every } => {
-                                match sched.last_fired {
-                                    None => true, // Never fired, fire now
-                                    Some(last) => {
-                                        let elapsed = now.signed_duration_since(last);
-                                        elapsed
-                                            >= chrono::Duration::from_std(*every)
-                                                .unwrap_or(chrono::TimeDelta::MAX)
-                                    }
-                                }
-                            }
-                            ScheduleType::Cron { expression } => {
-                                if let Ok(schedule) = cron::Schedule::from_str(expression) {
-                                    let reference_time =
-                                        sched.last_fired.unwrap_or(sched.created_at);
-                                    schedule
-                                        .after(&reference_time)
-                                        .next()
-                                        .is_some_and(|next| now >= next)
-                                } else {
-                                    false
-                                }
-                            }
-                        };
-
-                        if should_fire {
+                        if sched.is_due(now) {
                             let event = UnifiedEvent {
                                 id: EventId::new(),
                                 sequence: SequenceNumber(0),
@@ -414,12 +454,18 @@ impl EventScheduler {
                 }
 
                 // Update state and publish events
+                let mut flush_now = false;
                 if !to_fire.is_empty() {
                     let mut scheds = schedules.write().await;
                     for (idx, _event) in &to_fire {
                         if let Some(sched) = scheds.get_mut(*idx) {
                             sched.last_fired = Some(now);
                             sched.fire_count += 1;
+                            // A restart before the batched flush would re-fire
+                            // one-shot and cron schedules, so persist those now.
+                            if !matches!(sched.schedule, ScheduleType::Interval { .. }) {
+                                flush_now = true;
+                            }
 
                             // Auto-deactivate one-shot schedules
                             if matches!(sched.schedule, ScheduleType::Once { .. }) {
@@ -438,7 +484,7 @@ impl EventScheduler {
 
                 // Batch-flush fire state to DB every 10 ticks
                 if let Some(pool_ref) = pool.as_ref()
-                    && tick_count.is_multiple_of(10)
+                    && (tick_count.is_multiple_of(10) || flush_now)
                     && fire_state_dirty.load(Ordering::Acquire) > 0
                 {
                     let scheds = schedules.read().await;
@@ -496,6 +542,32 @@ pub fn interval_schedule(
     }
 }
 
+/// Helper to create a named cron schedule evaluated in `timezone` (UTC if `None`).
+pub fn cron_schedule(
+    name: impl Into<String>,
+    expression: impl Into<String>,
+    timezone: Option<Tz>,
+    category: EventCategory,
+    severity: EventSeverity,
+) -> ScheduledEvent {
+    ScheduledEvent {
+        id: Uuid::new_v4(),
+        name: name.into(),
+        schedule: ScheduleType::Cron {
+            expression: expression.into(),
+            timezone,
+        },
+        category,
+        severity,
+        goal_id: None,
+        task_id: None,
+        active: true,
+        created_at: Utc::now(),
+        last_fired: None,
+        fire_count: 0,
+    }
+}
+
 /// Row from the `scheduled_events` table.
 #[derive(sqlx::FromRow)]
 struct ScheduleRow {
@@ -690,4 +762,139 @@ mod tests {
         assert!(scheduler.register(s2).await.is_some());
         assert!(scheduler.register(s3).await.is_none()); // Exceeds max
     }
+
+    #[test]
+    fn test_cron_catch_up_fires_once_for_missed_windows() {
+        let now = Utc::now();
+        let mut sched = ScheduledEvent {
+            id: Uuid::new_v4(),
+            name: "hourly".to_string(),
+            schedule: ScheduleType::Cron {
+                expression: "0 0 * * * *".to_string(),
+                timezone: None,
+            },
+            category: EventCategory::Scheduler,
+            severity: EventSeverity::Info,
+            goal_id: None,
+            task_id: None,
+            active: true,
+            created_at: now - chrono::Duration::days(3),
+            last_fired: None,
+            fire_count: 0,
+        };
+
+        // ~72 hourly windows were missed; the schedule is due...
+        assert!(sched.is_due(now));
+
+        // ...but once it fires, it is not due again until the next window.
+        sched.last_fired = Some(now);
+        sched.fire_count += 1;
+        assert!(!sched.is_due(now));
+        assert!(!sched.is_due(now + chrono::Duration::seconds(1)));
+    }
+
+    fn utc(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_cron_follows_wall_clock_across_spring_forward() {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let mut sched = cron_schedule(
+            "nightly",
+            "0 30 2 * * *",
+            Some(tz),
+            EventCategory::Scheduler,
+            EventSeverity::Info,
+        );
+        // Fired at 02:30 EST on the Saturday before clocks spring forward.
+        sched.last_fired = Some(utc("2025-03-08T07:30:00Z"));
+
+        // 02:30 does not exist on 2025-03-09, so the next fire is 02:30 EDT
+        // on the 10th -- four hours behind UTC rather than five.
+        assert_eq!(sched.next_fire_at(), Some(utc("2025-03-10T06:30:00Z")));
+        assert!(!sched.is_due(utc("2025-03-09T07:30:00Z")));
+        assert!(sched.is_due(utc("2025-03-10T06:30:00Z")));
+    }
+
+    #[test]
+    fn test_cron_fires_once_in_repeated_fall_back_hour() {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let mut sched = cron_schedule(
+            "early",
+            "30 1 * * *",
+            Some(tz),
+            EventCategory::Scheduler,
+            EventSeverity::Info,
+        );
+        sched.last_fired = Some(utc("2025-11-01T05:30:00Z"));
+
+        // 01:30 occurs twice on 2025-11-02; the first (EDT) one fires...
+        assert_eq!(sched.next_fire_at(), Some(utc("2025-11-02T05:30:00Z")));
+
+        // ...and the repeat an hour later (EST) does not.
+        sched.last_fired = Some(utc("2025-11-02T05:30:00Z"));
+        assert!(!sched.is_due(utc("2025-11-02T06:30:00Z")));
+        assert_eq!(sched.next_fire_at(), Some(utc("2025-11-03T06:30:00Z")));
+    }
+
+    #[tokio::test]
+    async fn test_scheduler_rejects_invalid_cron() {
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let scheduler = EventScheduler::new(bus, SchedulerConfig::default());
+
+        let sched = cron_schedule(
+            "broken",
+            "not a cron",
+            None,
+            EventCategory::Scheduler,
+            EventSeverity::Info,
+        );
+        assert!(scheduler.register(sched).await.is_none());
+        assert!(scheduler.list().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unfired_cron_round_trips_through_store() {
+        let pool = crate::adapters::sqlite::create_migrated_test_pool()
+            .await
+            .unwrap();
+        let tz: Tz = "Europe/London".parse().unwrap();
+        let sched = cron_schedule(
+            "maintenance-sweep",
+            "0 2 * * *",
+            Some(tz),
+            EventCategory::Scheduler,
+            EventSeverity::Info,
+        );
+        let expected_next = sched.next_fire_at();
+
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let scheduler =
+            EventScheduler::new(bus.clone(), SchedulerConfig::default()).with_pool(pool.clone());
+        scheduler.register(sched).await.unwrap();
+
+        // A fresh scheduler (as after a restart) restores the expression,
+        // timezone and creation time, so the pending fire is neither lost
+        // nor brought forward.
+        let restarted = EventScheduler::new(bus, SchedulerConfig::default()).with_pool(pool);
+        restarted.initialize_from_store().await;
+        let loaded = restarted.list().await;
+        assert_eq!(loaded.len(), 1);
+        match &loaded[0].schedule {
+            ScheduleType::Cron {
+                expression,
+                timezone,
+            } => {
+                assert_eq!(expression, "0 2 * * *");
+                assert_eq!(*timezone, Some(tz));
+            }
+            other => panic!("expected cron schedule, got {:?}", other),
+        }
+        assert!(loaded[0].last_fired.is_none());
+        assert_eq!(loaded[0].next_fire_at(), expected_next);
+        assert!(!loaded[0].is_due(Utc::now()));
+    }
 }
@@ -144,6 +144,54 @@ pub struct EventStoreStats {
     pub events_by_category: Vec<(EventCategory, u64)>,
 }
 
+/// Event counts over a time window, grouped by category and payload type.
+#[derive(Debug, Clone, Default)]
+pub struct EventVolume {
+    /// Total events in the window.
+    pub total_events: u64,
+    /// Counts per category, most frequent first.
+    pub by_category: Vec<(EventCategory, u64)>,
+    /// Counts per `EventPayload` variant, most frequent first.
+    pub by_payload_type: Vec<(String, u64)>,
+}
+
+/// Lag threshold above which a handler is considered significantly behind.
+pub const HANDLER_LAG_WARN_THRESHOLD: u64 = 100;
+
+/// How far a handler's watermark trails the latest event sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandlerLag {
+    pub handler: String,
+    /// Last processed sequence (0 if the handler has no watermark yet).
+    pub watermark: u64,
+    pub lag: u64,
+}
+
+impl HandlerLag {
+    /// Whether the lag exceeds [`HANDLER_LAG_WARN_THRESHOLD`].
+    pub fn is_behind(&self) -> bool {
+        self.lag > HANDLER_LAG_WARN_THRESHOLD
+    }
+}
+
+/// Compute each handler's lag behind `latest_seq` from its stored watermark.
+pub async fn handler_lags(
+    store: &dyn EventStore,
+    latest_seq: u64,
+    handler_names: &[String],
+) -> Result<Vec<HandlerLag>, EventStoreError> {
+    let mut lags = Vec::with_capacity(handler_names.len());
+    for name in handler_names {
+        let watermark = store.get_watermark(name).await?.map(|s| s.0).unwrap_or(0);
+        lags.push(HandlerLag {
+            handler: name.clone(),
+            watermark,
+            lag: latest_seq.saturating_sub(watermark),
+        });
+    }
+    Ok(lags)
+}
+
 /// Trait for event persistence implementations.
 #[async_trait]
 pub trait EventStore: Send + Sync {
@@ -153,6 +201,49 @@ pub trait EventStore: Send + Sync {
     /// Query events based on filter criteria.
     async fn query(&self, query: EventQuery) -> Result<Vec<UnifiedEvent>, EventStoreError>;
 
+    /// Get one page of at most `page_size` events matching `query`, starting
+    /// just past `cursor` in the query's sequence order.
+    ///
+    /// Returns the page and the cursor for the next one (the last sequence
+    /// in the page), or `None` once no matching events remain. Pages are
+    /// bounded by sequence rather than offset, so events appended while
+    /// paging never shift rows between pages: ascending paging picks them up
+    /// at the end and descending paging never reaches them. `query.limit`
+    /// and `query.offset` are ignored.
+    async fn query_page(
+        &self,
+        mut query: EventQuery,
+        cursor: Option<SequenceNumber>,
+        page_size: u32,
+    ) -> Result<(Vec<UnifiedEvent>, Option<SequenceNumber>), EventStoreError> {
+        if let Some(SequenceNumber(cursor)) = cursor {
+            if query.ascending {
+                let from = cursor.saturating_add(1);
+                let since = query.since_sequence.map_or(from, |s| s.0.max(from));
+                query.since_sequence = Some(SequenceNumber(since));
+            } else {
+                let Some(to) = cursor.checked_sub(1) else {
+                    return Ok((Vec::new(), None));
+                };
+                let until = query.until_sequence.map_or(to, |u| u.0.min(to));
+                query.until_sequence = Some(SequenceNumber(until));
+            }
+        }
+
+        // Fetch one extra row to learn whether another page follows.
+        let page_size = page_size.max(1) as usize;
+        query.offset = None;
+        query.limit = Some(page_size as u32 + 1);
+        let mut events = self.query(query).await?;
+        let next_cursor = if events.len() > page_size {
+            events.truncate(page_size);
+            events.last().map(|e| e.sequence)
+        } else {
+            None
+        };
+        Ok((events, next_cursor))
+    }
+
     /// Get the latest sequence number in the store.
     async fn latest_sequence(&self) -> Result<Option<SequenceNumber>, EventStoreError>;
 
@@ -173,6 +264,39 @@ pub trait EventStore: Send + Sync {
         })
     }
 
+    /// Count events at or after `since` (all events when `None`) by category
+    /// and payload type.
+    async fn volume_since(
+        &self,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<EventVolume, EventStoreError> {
+        let mut query = EventQuery::new().ascending();
+        query.since_time = since;
+        let events = self.query(query).await?;
+
+        // EventCategory isn't hashable; there are few enough to scan linearly.
+        let mut by_category: Vec<(EventCategory, u64)> = Vec::new();
+        let mut by_payload_type = std::collections::BTreeMap::new();
+        for event in &events {
+            match by_category.iter_mut().find(|(c, _)| *c == event.category) {
+                Some((_, count)) => *count += 1,
+                None => by_category.push((event.category, 1)),
+            }
+            *by_payload_type
+                .entry(event.payload.variant_name().to_string())
+                .or_insert(0) += 1;
+        }
+        by_category.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        let mut by_payload_type: Vec<_> = by_payload_type.into_iter().collect();
+        by_payload_type.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+
+        Ok(EventVolume {
+            total_events: events.len() as u64,
+            by_category,
+            by_payload_type,
+        })
+    }
+
     /// Get a single event by sequence number.
     async fn get_by_sequence(
         &self,
@@ -198,6 +322,25 @@ pub trait EventStore: Send + Sync {
             .await
     }
 
+    /// Get at most `limit` events at or after `sequence`, oldest first.
+    ///
+    /// Cursor-based page of `replay_since`: pass the last returned sequence
+    /// plus one to fetch the next page. A page shorter than `limit` means
+    /// the replay has caught up.
+    async fn replay_since_page(
+        &self,
+        sequence: SequenceNumber,
+        limit: usize,
+    ) -> Result<Vec<UnifiedEvent>, EventStoreError> {
+        self.query(
+            EventQuery::new()
+                .since_sequence(sequence)
+                .ascending()
+                .limit(limit as u32),
+        )
+        .await
+    }
+
     /// Get the last processed sequence number for a handler.
     async fn get_watermark(
         &self,
@@ -221,6 +364,11 @@ pub trait EventStore: Send + Sync {
         Ok(None)
     }
 
+    /// Names of all handlers with a recorded watermark, sorted.
+    async fn watermark_handler_names(&self) -> Result<Vec<String>, EventStoreError> {
+        Ok(vec![])
+    }
+
     /// Detect gaps in the sequence number range [from, to].
     ///
     /// Returns a list of (gap_start, gap_end) pairs representing missing
@@ -544,6 +692,13 @@ impl EventStore for InMemoryEventStore {
         Ok(watermarks.values().copied().min())
     }
 
+    async fn watermark_handler_names(&self) -> Result<Vec<String>, EventStoreError> {
+        let watermarks = self.watermarks.read().await;
+        let mut names: Vec<String> = watermarks.keys().cloned().collect();
+        names.sort();
+        Ok(names)
+    }
+
     // === Sequence Gap Detection ===
 
     async fn detect_sequence_gaps(
@@ -822,6 +977,179 @@ mod tests {
         assert_eq!(since.len(), 2);
     }
 
+    async fn collect_pages(
+        store: &InMemoryEventStore,
+        query: EventQuery,
+        page_size: u32,
+    ) -> Vec<Vec<u64>> {
+        let mut pages = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next) = store
+                .query_page(query.clone(), cursor, page_size)
+                .await
+                .unwrap();
+            pages.push(page.iter().map(|e| e.sequence.0).collect());
+            match next {
+                Some(next) => cursor = Some(next),
+                None => return pages,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_page_walks_all_events_in_sequence_order() {
+        let store = InMemoryEventStore::new();
+        // Appended out of order; pages must still follow sequence order.
+        for seq in (1..=7).rev() {
+            store.append(&make_test_event(seq)).await.unwrap();
+        }
+
+        let pages = collect_pages(&store, EventQuery::new().ascending(), 3).await;
+        assert_eq!(pages, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+
+        let pages = collect_pages(&store, EventQuery::new().descending(), 3).await;
+        assert_eq!(pages, vec![vec![7, 6, 5], vec![4, 3, 2], vec![1]]);
+
+        // An exact multiple of the page size ends without an empty page.
+        let pages = collect_pages(&store, EventQuery::new().ascending().limit(1), 7).await;
+        assert_eq!(pages, vec![vec![1, 2, 3, 4, 5, 6, 7]]);
+
+        // Sequence bounds in the query still apply.
+        let query = EventQuery::new()
+            .ascending()
+            .since_sequence(SequenceNumber(3))
+            .until_sequence(SequenceNumber(6));
+        assert_eq!(
+            collect_pages(&store, query, 3).await,
+            vec![vec![3, 4, 5], vec![6]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_page_unaffected_by_concurrent_appends() {
+        let store = InMemoryEventStore::new();
+        for seq in 1..=5 {
+            store.append(&make_test_event(seq)).await.unwrap();
+        }
+
+        let (first, cursor) = store
+            .query_page(EventQuery::new().descending(), None, 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            first.iter().map(|e| e.sequence.0).collect::<Vec<_>>(),
+            vec![5, 4]
+        );
+        // Newer events would shift an offset-based second page by two rows.
+        store.append(&make_test_event(6)).await.unwrap();
+        store.append(&make_test_event(7)).await.unwrap();
+        let (second, _) = store
+            .query_page(EventQuery::new().descending(), cursor, 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            second.iter().map(|e| e.sequence.0).collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+
+        let (first, cursor) = store
+            .query_page(EventQuery::new().ascending(), None, 4)
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 4);
+        store.append(&make_test_event(8)).await.unwrap();
+        let (rest, next) = store
+            .query_page(EventQuery::new().ascending(), cursor, 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            rest.iter().map(|e| e.sequence.0).collect::<Vec<_>>(),
+            vec![5, 6, 7, 8]
+        );
+        assert!(next.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_volume_since_counts_by_category_and_payload() {
+        let store = InMemoryEventStore::new();
+
+        let task_id = Uuid::new_v4();
+        let task_event = |seq: u64, payload: EventPayload| UnifiedEvent {
+            category: EventCategory::Task,
+            task_id: Some(task_id),
+            payload,
+            ..make_test_event(seq)
+        };
+        store.append(&make_test_event(0)).await.unwrap();
+        store
+            .append(&task_event(
+                1,
+                EventPayload::TaskReady {
+                    task_id,
+                    task_title: "t".to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+        store
+            .append(&task_event(
+                2,
+                EventPayload::TaskCompleted {
+                    task_id,
+                    tokens_used: 0,
+                },
+            ))
+            .await
+            .unwrap();
+        store
+            .append(&task_event(
+                3,
+                EventPayload::TaskReady {
+                    task_id,
+                    task_title: "t".to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+
+        let volume = store.volume_since(None).await.unwrap();
+        assert_eq!(volume.total_events, 4);
+        assert_eq!(
+            volume.by_category,
+            vec![(EventCategory::Task, 3), (EventCategory::Orchestrator, 1)]
+        );
+        assert_eq!(volume.by_payload_type[0], ("TaskReady".to_string(), 2));
+        assert_eq!(volume.by_payload_type.len(), 3);
+
+        let future = Utc::now() + chrono::Duration::hours(1);
+        let empty = store.volume_since(Some(future)).await.unwrap();
+        assert_eq!(empty.total_events, 0);
+        assert!(empty.by_category.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handler_lags_from_watermarks() {
+        let store = InMemoryEventStore::new();
+        store
+            .set_watermark("fast", SequenceNumber(495))
+            .await
+            .unwrap();
+        store
+            .set_watermark("slow", SequenceNumber(10))
+            .await
+            .unwrap();
+
+        let names = store.watermark_handler_names().await.unwrap();
+        assert_eq!(names, vec!["fast".to_string(), "slow".to_string()]);
+
+        let lags = handler_lags(&store, 500, &names).await.unwrap();
+        assert_eq!(lags[0].lag, 5);
+        assert!(!lags[0].is_behind());
+        assert_eq!(lags[1].lag, 490);
+        assert!(lags[1].is_behind());
+    }
+
     #[tokio::test]
     async fn test_in_memory_store_pagination() {
         let store = InMemoryEventStore::new();
@@ -1026,6 +1026,13 @@ impl crate::domain::ports::AgentRepository for MockAgentRepo {
     {
         Ok(vec![])
     }
+    async fn find_by_capability(
+        &self,
+        _capability: &str,
+    ) -> crate::domain::errors::DomainResult<Vec<crate::domain::models::AgentTemplate>>
+    {
+        Ok(vec![])
+    }
     async fn create_instance(
         &self,
         _instance: &crate::domain::models::AgentInstance,
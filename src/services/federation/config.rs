@@ -52,6 +52,16 @@ pub struct FederationConfig {
     pub parent: Option<FederationParentConfig>,
     /// Cerebrate configurations (only used when role = Overmind).
     pub cerebrates: Vec<CerebrateConfig>,
+    /// URL cerebrates POST task status transitions to — this swarm's
+    /// `/api/v1/federation/callbacks` gateway route. Callbacks are only
+    /// requested when `callback_secret` is also set.
+    pub callback_url: Option<String>,
+    /// Shared secret cerebrates must present in the
+    /// `X-A2A-Notification-Token` header of every callback.
+    pub callback_secret: Option<String>,
+    /// Interval for polling `tasks/get` on delegations whose cerebrate
+    /// does not support callbacks (0 disables polling).
+    pub status_poll_interval_secs: u64,
 }
 
 impl Default for FederationConfig {
@@ -69,6 +79,9 @@ impl Default for FederationConfig {
             tls: FederationTlsConfig::default(),
             parent: None,
             cerebrates: Vec::new(),
+            callback_url: None,
+            callback_secret: None,
+            status_poll_interval_secs: 60,
         }
     }
 }
@@ -179,6 +192,9 @@ mod tests {
                 max_accepted_tasks: 5,
             }),
             cerebrates: Vec::new(),
+            callback_url: Some("https://overmind.example.com/api/v1/federation/callbacks".to_string()),
+            callback_secret: Some("callback-secret".to_string()),
+            status_poll_interval_secs: 30,
         };
 
         let json = serde_json::to_string(&config)
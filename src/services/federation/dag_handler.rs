@@ -83,6 +83,7 @@ impl EventHandler for SwarmDagEventHandler {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
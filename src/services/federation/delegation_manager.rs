@@ -32,6 +32,7 @@ use crate::services::event_factory;
 
 use super::config::FederationConfig;
 use super::service::FederationHttpClient;
+use super::status_sync::DelegatedTaskStatus;
 use super::traits::{
     DelegationDecision, FederationDelegationStrategy, FederationTaskTransformer,
 };
@@ -45,6 +46,7 @@ pub(super) struct DelegationManager {
     rejection_history: Arc<RwLock<HashMap<Uuid, Vec<String>>>>,
     last_activity: Arc<RwLock<HashMap<Uuid, chrono::DateTime<chrono::Utc>>>>,
     task_to_federated_goal: Arc<RwLock<HashMap<String, Uuid>>>,
+    delegated_statuses: Arc<RwLock<HashMap<Uuid, DelegatedTaskStatus>>>,
     event_bus: Arc<EventBus>,
     http_client: FederationHttpClient,
     delegation_strategy: Arc<dyn FederationDelegationStrategy>,
@@ -65,6 +67,7 @@ pub(super) struct DelegationManagerParams {
     pub rejection_history: Arc<RwLock<HashMap<Uuid, Vec<String>>>>,
     pub last_activity: Arc<RwLock<HashMap<Uuid, chrono::DateTime<chrono::Utc>>>>,
     pub task_to_federated_goal: Arc<RwLock<HashMap<String, Uuid>>>,
+    pub delegated_statuses: Arc<RwLock<HashMap<Uuid, DelegatedTaskStatus>>>,
     pub event_bus: Arc<EventBus>,
     pub http_client: FederationHttpClient,
     pub delegation_strategy: Arc<dyn FederationDelegationStrategy>,
@@ -82,6 +85,7 @@ impl DelegationManager {
             rejection_history: params.rejection_history,
             last_activity: params.last_activity,
             task_to_federated_goal: params.task_to_federated_goal,
+            delegated_statuses: params.delegated_statuses,
             event_bus: params.event_bus,
             http_client: params.http_client,
             delegation_strategy: params.delegation_strategy,
@@ -109,6 +113,14 @@ impl DelegationManager {
         cerebrates.values().cloned().collect()
     }
 
+    /// `pushNotificationConfig` asking a cerebrate to POST status
+    /// transitions to our callback endpoint, when one is configured.
+    fn callback_push_config(&self) -> Option<serde_json::Value> {
+        let url = self.config.callback_url.as_ref()?;
+        let secret = self.config.callback_secret.as_ref()?;
+        Some(serde_json::json!({ "url": url, "token": secret }))
+    }
+
     /// Delegate a task to a specific cerebrate.
     ///
     /// NOTE: The `active_delegations` counter on `CerebrateStatus` can become
@@ -138,28 +150,37 @@ impl DelegationManager {
             }
         }
 
-        // Get the cerebrate URL before attempting to send
-        let url = {
+        // Get the cerebrate URL before attempting to send, and whether it
+        // will push status callbacks back to us.
+        let (url, push_capable) = {
             let cerebrates = self.cerebrates.read().await;
-            cerebrates.get(cerebrate_id).and_then(|s| s.url.clone())
+            cerebrates
+                .get(cerebrate_id)
+                .map(|s| (s.url.clone(), s.push_notifications))
+                .unwrap_or_default()
         };
+        let push_config = self.callback_push_config().filter(|_| push_capable);
 
         // Send the envelope to the remote cerebrate.
         // Prefer a2a_client when available; fall back to legacy http_client.
+        let mut remote_task = None;
         if let Some(ref url) = url {
             let mut sent_via_a2a = false;
 
             if let Some(ref a2a) = self.a2a_client {
-                let params = TaskSendParams::from(envelope);
+                let mut params = TaskSendParams::from(envelope);
+                params.push_notification_config = push_config.clone();
                 match a2a.send_message(url, params).await {
                     Ok(task) => {
                         tracing::info!(
                             cerebrate_id = %cerebrate_id,
                             task_id = %envelope.task_id,
                             a2a_task_id = %task.id,
+                            callbacks = push_config.is_some(),
                             "Delegated via A2A tasks/send"
                         );
                         sent_via_a2a = true;
+                        remote_task = Some(task);
                     }
                     Err(e) => {
                         tracing::warn!(
@@ -211,6 +232,22 @@ impl DelegationManager {
             let mut activity = self.last_activity.write().await;
             activity.insert(envelope.task_id, chrono::Utc::now());
         }
+        if let Some(task) = remote_task {
+            // Track remote A2A state: updated by callbacks when requested,
+            // otherwise by the status poller.
+            let mut statuses = self.delegated_statuses.write().await;
+            statuses.insert(
+                envelope.task_id,
+                DelegatedTaskStatus {
+                    task_id: envelope.task_id,
+                    cerebrate_id: cerebrate_id.to_string(),
+                    remote_task_id: task.id,
+                    state: task.status.state,
+                    callbacks: push_config.is_some(),
+                    updated_at: chrono::Utc::now(),
+                },
+            );
+        }
         {
             let mut cerebrates = self.cerebrates.write().await;
             if let Some(status) = cerebrates.get_mut(cerebrate_id) {
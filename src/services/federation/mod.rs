@@ -21,6 +21,7 @@ mod delegation_manager;
 pub mod handler;
 mod result_processor;
 pub mod service;
+mod status_sync;
 pub mod swarm_dag_executor;
 pub mod traits;
 
@@ -33,6 +34,7 @@ pub use convergence_publisher::ConvergencePublisher;
 pub use dag_handler::SwarmDagEventHandler;
 pub use handler::FederationResultHandler;
 pub use service::{FederationHttpClient, FederationService};
+pub use status_sync::{CallbackError, DelegatedTaskStatus};
 pub use swarm_dag_executor::SwarmDagExecutor;
 pub use traits::{
     DefaultDelegationStrategy, DefaultResultProcessor, DefaultTaskTransformer,
@@ -10,6 +10,8 @@
 //!   delegation, reconciliation, stall/orphan monitoring.
 //! - [`super::result_processor::ResultProcessor`] for inbound
 //!   accept/progress/result ingest.
+//! - [`super::status_sync::StatusSync`] for remote A2A status of delegated
+//!   tasks, via cerebrate callbacks or `tasks/get` polling.
 //!
 //! The public API of [`FederationService`] is unchanged; it forwards
 //! delegation / result methods to the collaborators, which share state
@@ -26,6 +28,7 @@ use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::models::a2a::{
     CerebrateStatus, ConnectionState, FederationCard, FederationResult, FederationTaskEnvelope,
 };
+use crate::domain::models::a2a_protocol::A2ATask;
 use crate::domain::models::goal::Goal;
 use crate::domain::models::goal_federation::{ConvergenceContract, FederatedGoal};
 use crate::domain::ports::GoalRepository;
@@ -35,6 +38,7 @@ use crate::services::event_factory;
 use super::config::FederationConfig;
 use super::delegation_manager::{DelegationManager, DelegationManagerParams};
 use super::result_processor::{ResultProcessor, ResultProcessorParams};
+use super::status_sync::{CallbackError, DelegatedTaskStatus, StatusSync, StatusSyncParams};
 use super::traits::{
     DefaultDelegationStrategy, DefaultResultProcessor, DefaultTaskTransformer,
     DelegationDecision, FederationDelegationStrategy, FederationReaction,
@@ -359,6 +363,8 @@ pub struct FederationService {
     task_to_federated_goal: Arc<RwLock<HashMap<String, Uuid>>>,
     /// Delegation timestamps for stall detection (task_id → last_activity_at).
     last_activity: Arc<RwLock<HashMap<Uuid, chrono::DateTime<chrono::Utc>>>>,
+    /// Remote A2A state of tasks delegated via `tasks/send` (task_id → status).
+    delegated_statuses: Arc<RwLock<HashMap<Uuid, DelegatedTaskStatus>>>,
     /// EventBus for emitting federation events.
     event_bus: Arc<EventBus>,
     /// HTTP client for outbound federation calls.
@@ -380,6 +386,8 @@ pub struct FederationService {
     delegation: Arc<DelegationManager>,
     /// Internal collaborator: inbound result ingest.
     results: Arc<ResultProcessor>,
+    /// Internal collaborator: delegated-task status callbacks and polling.
+    status_sync: Arc<StatusSync>,
     /// Optional goal repository for cross-cutting goal lookups (e.g.
     /// validating that a goal_id referenced from a federation request
     /// actually exists). Optional because not every construction site
@@ -400,6 +408,7 @@ impl FederationService {
         let rejection_history = Arc::new(RwLock::new(HashMap::new()));
         let last_activity = Arc::new(RwLock::new(HashMap::new()));
         let task_to_federated_goal = Arc::new(RwLock::new(HashMap::new()));
+        let delegated_statuses = Arc::new(RwLock::new(HashMap::new()));
         let schemas = Arc::new(RwLock::new(schemas_map));
         let http_client = FederationHttpClient::new();
         let delegation_strategy: Arc<dyn FederationDelegationStrategy> =
@@ -418,6 +427,7 @@ impl FederationService {
             rejection_history: Arc::clone(&rejection_history),
             last_activity: Arc::clone(&last_activity),
             task_to_federated_goal: Arc::clone(&task_to_federated_goal),
+            delegated_statuses: Arc::clone(&delegated_statuses),
             event_bus: Arc::clone(&event_bus),
             http_client: http_client.clone(),
             delegation_strategy: Arc::clone(&delegation_strategy),
@@ -434,6 +444,16 @@ impl FederationService {
             result_processor: Arc::clone(&result_processor),
             schemas: Arc::clone(&schemas),
         }));
+        let status_sync = Arc::new(StatusSync::new(StatusSyncParams {
+            callback_secret: config.callback_secret.clone(),
+            cerebrates: Arc::clone(&cerebrates),
+            in_flight: Arc::clone(&in_flight),
+            delegated_envelopes: Arc::clone(&delegated_envelopes),
+            statuses: Arc::clone(&delegated_statuses),
+            last_activity: Arc::clone(&last_activity),
+            a2a_client: a2a_client.clone(),
+            results: Arc::clone(&results),
+        }));
 
         Self {
             config,
@@ -443,6 +463,7 @@ impl FederationService {
             rejection_history,
             task_to_federated_goal,
             last_activity,
+            delegated_statuses,
             event_bus,
             http_client,
             delegation_strategy,
@@ -453,6 +474,7 @@ impl FederationService {
             a2a_client,
             delegation,
             results,
+            status_sync,
             goal_repository: None,
         }
     }
@@ -469,6 +491,7 @@ impl FederationService {
             rejection_history: Arc::clone(&self.rejection_history),
             last_activity: Arc::clone(&self.last_activity),
             task_to_federated_goal: Arc::clone(&self.task_to_federated_goal),
+            delegated_statuses: Arc::clone(&self.delegated_statuses),
             event_bus: Arc::clone(&self.event_bus),
             http_client: self.http_client.clone(),
             delegation_strategy: Arc::clone(&self.delegation_strategy),
@@ -485,6 +508,16 @@ impl FederationService {
             result_processor: Arc::clone(&self.result_processor),
             schemas: Arc::clone(&self.schemas),
         }));
+        self.status_sync = Arc::new(StatusSync::new(StatusSyncParams {
+            callback_secret: self.config.callback_secret.clone(),
+            cerebrates: Arc::clone(&self.cerebrates),
+            in_flight: Arc::clone(&self.in_flight),
+            delegated_envelopes: Arc::clone(&self.delegated_envelopes),
+            statuses: Arc::clone(&self.delegated_statuses),
+            last_activity: Arc::clone(&self.last_activity),
+            a2a_client: self.a2a_client.clone(),
+            results: Arc::clone(&self.results),
+        }));
     }
 
     /// Set an A2A wire-protocol client for outbound federation calls.
@@ -626,8 +659,13 @@ impl FederationService {
                         tracing::info!(
                             cerebrate_id = %id,
                             agent_name = %card.name,
+                            push_notifications = card.capabilities.push_notifications,
                             "A2A discovery succeeded"
                         );
+                        let mut cerebrates = self.cerebrates.write().await;
+                        if let Some(status) = cerebrates.get_mut(id) {
+                            status.push_notifications = card.capabilities.push_notifications;
+                        }
                     }
                     Err(e) => {
                         tracing::warn!(
@@ -936,6 +974,34 @@ impl FederationService {
         self.results.handle_result(result, parent_context).await
     }
 
+    // ========================================================================
+    // Remote status (forwarded to StatusSync)
+    // ========================================================================
+
+    /// Apply a status-transition callback POSTed by a cerebrate.
+    ///
+    /// `token` is the presented `X-A2A-Notification-Token`; it must match
+    /// `callback_secret`. Terminal states are processed like a
+    /// `federation/result`, completing or failing the originating task.
+    pub async fn handle_task_callback(
+        &self,
+        token: Option<&str>,
+        task: &A2ATask,
+    ) -> Result<DelegatedTaskStatus, CallbackError> {
+        self.status_sync.handle_callback(token, task).await
+    }
+
+    /// Last known remote status of a task delegated via A2A.
+    pub async fn delegated_task_status(&self, task_id: Uuid) -> Option<DelegatedTaskStatus> {
+        self.status_sync.status(task_id).await
+    }
+
+    /// Poll `tasks/get` for delegations whose cerebrate does not push
+    /// status callbacks.
+    pub async fn poll_delegated_statuses(&self) {
+        self.status_sync.poll().await
+    }
+
     // ========================================================================
     // Persistence
     // ========================================================================
@@ -1148,6 +1214,30 @@ impl FederationService {
             }
         }
 
+        // Spawn status poller for delegations without callbacks
+        {
+            let service = Arc::clone(self);
+            let mut shutdown_rx = tx.subscribe();
+            let poll_interval = self.config.status_poll_interval_secs;
+            if poll_interval > 0 {
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(Duration::from_secs(poll_interval));
+                    interval.tick().await;
+                    loop {
+                        tokio::select! {
+                            _ = interval.tick() => {
+                                service.poll_delegated_statuses().await;
+                            }
+                            _ = shutdown_rx.recv() => {
+                                tracing::debug!("Federation status poller shutting down");
+                                break;
+                            }
+                        }
+                    }
+                });
+            }
+        }
+
         tracing::info!(
             role = %self.config.role,
             swarm_id = %self.config.swarm_id,
@@ -1733,4 +1823,246 @@ mod tests {
         let status = svc2.get_cerebrate("c1").await.unwrap();
         assert_eq!(status.display_name, "Cerebrate 1");
     }
+
+    // -- Remote status callbacks ---------------------------------------------
+
+    use crate::adapters::a2a::client::A2AWireError;
+    use crate::domain::models::a2a_protocol::{
+        A2ACapabilities, A2APart, A2AProtocolMessage, A2ARole, A2AStandardAgentCard,
+        A2AStreamEvent, A2ATaskState, A2ATaskStatus, TaskSendParams,
+    };
+    use crate::services::event_bus::EventPayload;
+    use futures::stream::Stream;
+    use std::pin::Pin;
+    use std::sync::Mutex as StdMutex;
+
+    /// A2A client standing in for a remote cerebrate: discovery reports
+    /// `push_notifications`, `tasks/send` returns `remote-1` as submitted,
+    /// and `tasks/get` returns `polled_state`.
+    struct CerebrateA2AClient {
+        push_notifications: bool,
+        polled_state: A2ATaskState,
+        sent: StdMutex<Vec<TaskSendParams>>,
+    }
+
+    impl CerebrateA2AClient {
+        fn new(push_notifications: bool, polled_state: A2ATaskState) -> Self {
+            Self {
+                push_notifications,
+                polled_state,
+                sent: StdMutex::new(Vec::new()),
+            }
+        }
+    }
+
+    fn remote_task(state: A2ATaskState, summary: &str) -> A2ATask {
+        A2ATask {
+            id: "remote-1".to_string(),
+            context_id: None,
+            status: A2ATaskStatus {
+                state,
+                message: Some(A2AProtocolMessage {
+                    role: A2ARole::Agent,
+                    parts: vec![A2APart::Text {
+                        text: summary.to_string(),
+                    }],
+                    metadata: None,
+                }),
+                timestamp: Some(chrono::Utc::now()),
+            },
+            history: None,
+            artifacts: Vec::new(),
+            metadata: None,
+        }
+    }
+
+    #[async_trait]
+    impl A2AClient for CerebrateA2AClient {
+        async fn discover(&self, url: &str) -> Result<A2AStandardAgentCard, A2AWireError> {
+            Ok(A2AStandardAgentCard {
+                id: "c1".to_string(),
+                name: "Cerebrate 1".to_string(),
+                description: String::new(),
+                url: url.to_string(),
+                version: None,
+                provider: None,
+                capabilities: A2ACapabilities {
+                    push_notifications: self.push_notifications,
+                    ..A2ACapabilities::default()
+                },
+                skills: Vec::new(),
+                security_schemes: Vec::new(),
+                default_input_modes: Vec::new(),
+                default_output_modes: Vec::new(),
+            })
+        }
+
+        async fn send_message(
+            &self,
+            _url: &str,
+            params: TaskSendParams,
+        ) -> Result<A2ATask, A2AWireError> {
+            self.sent.lock().unwrap().push(params);
+            Ok(remote_task(A2ATaskState::Submitted, "Accepted"))
+        }
+
+        async fn send_streaming(
+            &self,
+            _url: &str,
+            _params: TaskSendParams,
+        ) -> Result<
+            Pin<Box<dyn Stream<Item = Result<A2AStreamEvent, A2AWireError>> + Send>>,
+            A2AWireError,
+        > {
+            Err(A2AWireError::Discovery("streaming not mocked".to_string()))
+        }
+
+        async fn get_task(
+            &self,
+            _url: &str,
+            _task_id: &str,
+            _history_length: Option<u32>,
+        ) -> Result<A2ATask, A2AWireError> {
+            Ok(remote_task(self.polled_state, "Polled"))
+        }
+
+        async fn cancel_task(&self, _url: &str, _task_id: &str) -> Result<A2ATask, A2AWireError> {
+            Err(A2AWireError::Discovery("cancel not mocked".to_string()))
+        }
+
+        async fn subscribe_to_task(
+            &self,
+            _url: &str,
+            _task_id: &str,
+        ) -> Result<
+            Pin<Box<dyn Stream<Item = Result<A2AStreamEvent, A2AWireError>> + Send>>,
+            A2AWireError,
+        > {
+            Err(A2AWireError::Discovery("subscribe not mocked".to_string()))
+        }
+    }
+
+    fn make_callback_service(
+        client: Arc<CerebrateA2AClient>,
+    ) -> (FederationService, Arc<EventBus>) {
+        let config = FederationConfig {
+            callback_url: Some("https://overmind.example.com/api/v1/federation/callbacks".into()),
+            callback_secret: Some("s3cret".into()),
+            ..FederationConfig::default()
+        };
+        let event_bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let svc = FederationService::new(config, event_bus.clone()).with_a2a_client(client);
+        (svc, event_bus)
+    }
+
+    #[tokio::test]
+    async fn test_remote_completion_callback_completes_delegated_task() {
+        let client = Arc::new(CerebrateA2AClient::new(true, A2ATaskState::Working));
+        let (svc, event_bus) = make_callback_service(client.clone());
+        svc.register_cerebrate("c1", "Cerebrate 1", "https://c1.example.com")
+            .await;
+        svc.connect("c1").await.unwrap();
+
+        let task_id = Uuid::new_v4();
+        let envelope = FederationTaskEnvelope::new(task_id, "Test task", "Do the thing");
+        svc.delegate_to(&envelope, "c1").await.unwrap();
+
+        // The delegation registered our callback URL with the cerebrate.
+        let push = client.sent.lock().unwrap()[0]
+            .push_notification_config
+            .clone()
+            .expect("push config sent");
+        assert_eq!(push["token"], "s3cret");
+        let status = svc.delegated_task_status(task_id).await.unwrap();
+        assert!(status.callbacks);
+        assert_eq!(status.state, A2ATaskState::Submitted);
+
+        let completed = remote_task(A2ATaskState::Completed, "Shipped it");
+        assert_eq!(
+            svc.handle_task_callback(Some("wrong"), &completed).await,
+            Err(CallbackError::Unauthorized)
+        );
+        assert_eq!(
+            svc.handle_task_callback(None, &completed).await,
+            Err(CallbackError::Unauthorized)
+        );
+        assert_eq!(svc.in_flight_count().await, 1);
+
+        let mut rx = event_bus.subscribe();
+        let status = svc
+            .handle_task_callback(Some("s3cret"), &completed)
+            .await
+            .unwrap();
+        assert_eq!(status.task_id, task_id);
+        assert_eq!(status.state, A2ATaskState::Completed);
+        assert_eq!(
+            svc.delegated_task_status(task_id).await.unwrap().state,
+            A2ATaskState::Completed
+        );
+
+        // The originating task is resolved through the normal result path.
+        assert_eq!(svc.in_flight_count().await, 0);
+        assert_eq!(svc.get_cerebrate("c1").await.unwrap().active_delegations, 0);
+        let event = tokio::time::timeout(std::time::Duration::from_millis(100), rx.recv())
+            .await
+            .expect("result event emitted")
+            .unwrap();
+        match event.payload {
+            EventPayload::FederationResultReceived {
+                task_id: id,
+                status,
+                summary,
+                ..
+            } => {
+                assert_eq!(id, task_id);
+                assert_eq!(status, "completed");
+                assert_eq!(summary, "Shipped it");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        // A redelivered callback is acknowledged without reprocessing.
+        let again = svc
+            .handle_task_callback(Some("s3cret"), &completed)
+            .await
+            .unwrap();
+        assert_eq!(again.state, A2ATaskState::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_callback_rejected_when_no_secret_configured() {
+        let svc = make_service();
+        let task = remote_task(A2ATaskState::Completed, "done");
+        assert_eq!(
+            svc.handle_task_callback(Some("anything"), &task).await,
+            Err(CallbackError::Disabled)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delegation_without_push_support_falls_back_to_polling() {
+        let client = Arc::new(CerebrateA2AClient::new(false, A2ATaskState::Completed));
+        let (svc, _event_bus) = make_callback_service(client.clone());
+        svc.register_cerebrate("c1", "Cerebrate 1", "https://c1.example.com")
+            .await;
+        svc.connect("c1").await.unwrap();
+
+        let task_id = Uuid::new_v4();
+        let envelope = FederationTaskEnvelope::new(task_id, "Test task", "Do the thing");
+        svc.delegate_to(&envelope, "c1").await.unwrap();
+
+        assert!(client.sent.lock().unwrap()[0].push_notification_config.is_none());
+        assert!(!svc.delegated_task_status(task_id).await.unwrap().callbacks);
+
+        svc.poll_delegated_statuses().await;
+        assert_eq!(svc.in_flight_count().await, 0);
+        assert_eq!(
+            svc.delegated_task_status(task_id).await.unwrap().state,
+            A2ATaskState::Completed
+        );
+
+        // The next tick drops the finished entry.
+        svc.poll_delegated_statuses().await;
+        assert!(svc.delegated_task_status(task_id).await.is_none());
+    }
 }
@@ -0,0 +1,268 @@
+//! Remote status tracking for tasks delegated over A2A.
+//!
+//! `StatusSync` keeps the last known `A2ATaskState` of every task that
+//! `DelegationManager` sent via `tasks/send`. Cerebrates that advertise
+//! push notifications POST their status transitions back to us (see
+//! [`FederationConfig::callback_url`](super::FederationConfig)); the rest
+//! are polled with `tasks/get`. Either way, a terminal state is fed
+//! through `ResultProcessor` exactly like a `federation/result` call, so
+//! the originating task sees the same `FederationResultReceived` event.
+//!
+//! This type is a private implementation detail of `FederationService`;
+//! it shares state with the service via `Arc`s rather than owning it
+//! outright.
+//!
+//! Only [`DelegatedTaskStatus`] and [`CallbackError`] are re-exported
+//! from the module root.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::adapters::a2a::A2AClient;
+use crate::adapters::mcp::auth::constant_time_eq;
+use crate::domain::models::a2a::{
+    Artifact, CerebrateStatus, FederationResult, FederationTaskEnvelope,
+};
+use crate::domain::models::a2a_protocol::{A2APart, A2ATask, A2ATaskState};
+
+use super::result_processor::ResultProcessor;
+use super::traits::ParentContext;
+
+/// Last known remote state of a task delegated over A2A.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DelegatedTaskStatus {
+    /// Local federation task ID (the envelope's `task_id`).
+    pub task_id: Uuid,
+    /// Cerebrate the task was delegated to.
+    pub cerebrate_id: String,
+    /// Task ID assigned by the cerebrate's `tasks/send`.
+    pub remote_task_id: String,
+    /// Last state reported by a callback or poll.
+    pub state: A2ATaskState,
+    /// Whether the cerebrate was asked to push status callbacks. When
+    /// false the task is polled instead.
+    pub callbacks: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Why an inbound status callback was refused.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CallbackError {
+    #[error("status callbacks are not enabled on this swarm")]
+    Disabled,
+    #[error("invalid or missing callback token")]
+    Unauthorized,
+    #[error("no delegated task matches remote task {0}")]
+    UnknownTask(String),
+}
+
+/// Delegated-task status sync — see module docs.
+pub(super) struct StatusSync {
+    callback_secret: Option<String>,
+    cerebrates: Arc<RwLock<HashMap<String, CerebrateStatus>>>,
+    in_flight: Arc<RwLock<HashMap<Uuid, String>>>,
+    delegated_envelopes: Arc<RwLock<HashMap<Uuid, FederationTaskEnvelope>>>,
+    statuses: Arc<RwLock<HashMap<Uuid, DelegatedTaskStatus>>>,
+    last_activity: Arc<RwLock<HashMap<Uuid, DateTime<Utc>>>>,
+    a2a_client: Option<Arc<dyn A2AClient>>,
+    results: Arc<ResultProcessor>,
+}
+
+/// Inputs for [`StatusSync::new`].
+pub(super) struct StatusSyncParams {
+    pub callback_secret: Option<String>,
+    pub cerebrates: Arc<RwLock<HashMap<String, CerebrateStatus>>>,
+    pub in_flight: Arc<RwLock<HashMap<Uuid, String>>>,
+    pub delegated_envelopes: Arc<RwLock<HashMap<Uuid, FederationTaskEnvelope>>>,
+    pub statuses: Arc<RwLock<HashMap<Uuid, DelegatedTaskStatus>>>,
+    pub last_activity: Arc<RwLock<HashMap<Uuid, DateTime<Utc>>>>,
+    pub a2a_client: Option<Arc<dyn A2AClient>>,
+    pub results: Arc<ResultProcessor>,
+}
+
+impl StatusSync {
+    pub(super) fn new(params: StatusSyncParams) -> Self {
+        Self {
+            callback_secret: params.callback_secret,
+            cerebrates: params.cerebrates,
+            in_flight: params.in_flight,
+            delegated_envelopes: params.delegated_envelopes,
+            statuses: params.statuses,
+            last_activity: params.last_activity,
+            a2a_client: params.a2a_client,
+            results: params.results,
+        }
+    }
+
+    pub(super) async fn status(&self, task_id: Uuid) -> Option<DelegatedTaskStatus> {
+        self.statuses.read().await.get(&task_id).cloned()
+    }
+
+    /// Apply a status callback POSTed by a cerebrate.
+    ///
+    /// `token` is the `X-A2A-Notification-Token` header value; it must
+    /// match the configured callback secret. Returns the status now
+    /// recorded for the task.
+    pub(super) async fn handle_callback(
+        &self,
+        token: Option<&str>,
+        task: &A2ATask,
+    ) -> Result<DelegatedTaskStatus, CallbackError> {
+        let secret = self
+            .callback_secret
+            .as_deref()
+            .ok_or(CallbackError::Disabled)?;
+        if !token.is_some_and(|t| constant_time_eq(t.as_bytes(), secret.as_bytes())) {
+            return Err(CallbackError::Unauthorized);
+        }
+
+        let unknown = || CallbackError::UnknownTask(task.id.clone());
+        let task_id = self.resolve(task).await.ok_or_else(unknown)?;
+        self.apply(task_id, task).await.ok_or_else(unknown)
+    }
+
+    /// Poll `tasks/get` for every tracked delegation that is not receiving
+    /// callbacks, and drop entries that are finished or no longer in flight.
+    pub(super) async fn poll(&self) {
+        {
+            let in_flight = self.in_flight.read().await;
+            let mut statuses = self.statuses.write().await;
+            statuses.retain(|id, s| !s.state.is_terminal() && in_flight.contains_key(id));
+        }
+
+        let Some(ref a2a) = self.a2a_client else {
+            return;
+        };
+        let pending: Vec<DelegatedTaskStatus> = {
+            let statuses = self.statuses.read().await;
+            statuses.values().filter(|s| !s.callbacks).cloned().collect()
+        };
+
+        for status in pending {
+            let url = {
+                let cerebrates = self.cerebrates.read().await;
+                cerebrates.get(&status.cerebrate_id).and_then(|c| c.url.clone())
+            };
+            let Some(url) = url else {
+                continue;
+            };
+            match a2a.get_task(&url, &status.remote_task_id, None).await {
+                Ok(task) => {
+                    if task.status.state != status.state {
+                        self.apply(status.task_id, &task).await;
+                    }
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        task_id = %status.task_id,
+                        cerebrate_id = %status.cerebrate_id,
+                        error = %e,
+                        "Delegated task status poll failed"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Map a remote task back to the local federation task ID, by remote
+    /// ID first and then by the `abathur:federation.task_id` metadata we
+    /// attach on delegation.
+    async fn resolve(&self, task: &A2ATask) -> Option<Uuid> {
+        let statuses = self.statuses.read().await;
+        if let Some(status) = statuses.values().find(|s| s.remote_task_id == task.id) {
+            return Some(status.task_id);
+        }
+        task.metadata
+            .as_ref()
+            .and_then(|m| m.get("abathur:federation"))
+            .and_then(|f| f.get("task_id"))
+            .and_then(|v| v.as_str())
+            .and_then(|s| Uuid::parse_str(s).ok())
+            .filter(|id| statuses.contains_key(id))
+    }
+
+    /// Record `task`'s state for `task_id`; terminal states are routed
+    /// through the result processor once. Repeated deliveries of a
+    /// terminal state are ignored. Returns `None` if `task_id` is not
+    /// tracked.
+    async fn apply(&self, task_id: Uuid, task: &A2ATask) -> Option<DelegatedTaskStatus> {
+        let state = task.status.state;
+        let now = Utc::now();
+        let updated = {
+            let mut statuses = self.statuses.write().await;
+            let entry = statuses.get_mut(&task_id)?;
+            if entry.state.is_terminal() {
+                return Some(entry.clone());
+            }
+            entry.state = state;
+            entry.updated_at = now;
+            entry.clone()
+        };
+
+        if !state.is_terminal() {
+            let mut activity = self.last_activity.write().await;
+            activity.insert(task_id, now);
+            return Some(updated);
+        }
+
+        let correlation_id = {
+            let envs = self.delegated_envelopes.read().await;
+            envs.get(&task_id).map(|e| e.correlation_id).unwrap_or_else(Uuid::nil)
+        };
+        tracing::info!(task_id = %task_id, state = %state.as_str(), "Delegated task reached terminal state");
+        self.results
+            .handle_result(
+                terminal_result(task_id, correlation_id, task),
+                ParentContext::default(),
+            )
+            .await;
+        Some(updated)
+    }
+}
+
+/// Build the `FederationResult` for a remote task in a terminal state.
+fn terminal_result(task_id: Uuid, correlation_id: Uuid, task: &A2ATask) -> FederationResult {
+    let summary = task
+        .status
+        .message
+        .as_ref()
+        .map(|m| text_of(&m.parts))
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| format!("Remote task {}", task.status.state.as_str()));
+
+    let mut result = match task.status.state {
+        A2ATaskState::Completed => FederationResult::completed(task_id, correlation_id, summary),
+        state => FederationResult::failed(
+            task_id,
+            correlation_id,
+            summary,
+            format!("remote task {}", state.as_str()),
+        ),
+    };
+    result.artifacts = task
+        .artifacts
+        .iter()
+        .map(|a| {
+            Artifact::new(
+                a.name.clone().unwrap_or_else(|| a.artifact_id.clone()),
+                text_of(&a.parts),
+            )
+        })
+        .collect();
+    result
+}
+
+fn text_of(parts: &[A2APart]) -> String {
+    parts
+        .iter()
+        .filter_map(|p| match p {
+            A2APart::Text { text } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
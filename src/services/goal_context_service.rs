@@ -131,11 +131,11 @@ impl AgentContext {
 /// - Context budget management (Manus AI pattern)
 /// - Domain-scoped memory loading for context isolation (DynTaskMAS pattern)
 /// - Multi-factor relevance scoring for memory selection
-pub struct GoalContextService<G: GoalRepository> {
+pub struct GoalContextService<G: GoalRepository + ?Sized> {
     goal_repo: Arc<G>,
 }
 
-impl<G: GoalRepository> GoalContextService<G> {
+impl<G: GoalRepository + ?Sized> GoalContextService<G> {
     pub fn new(goal_repo: Arc<G>) -> Self {
         Self { goal_repo }
     }
@@ -362,6 +362,23 @@ impl<G: GoalRepository> GoalContextService<G> {
         self.get_relevant_goals(&domains).await
     }
 
+    /// Goals a task's token spend is charged to.
+    ///
+    /// A task explicitly linked to a goal is charged to that goal alone.
+    /// Otherwise it is charged to every active goal whose domains it touches;
+    /// universal goals (no domains) are not charged, since they would absorb
+    /// the spend of every task.
+    pub async fn get_goals_charged_for_task(&self, task: &Task) -> DomainResult<Vec<Goal>> {
+        if let Some(goal_id) = task.goal_id() {
+            return Ok(self.goal_repo.get(goal_id).await?.into_iter().collect());
+        }
+        let goals = self.get_goals_for_task(task).await?;
+        Ok(goals
+            .into_iter()
+            .filter(|g| !g.applicability_domains.is_empty())
+            .collect())
+    }
+
     /// Format goals as contextual guidance text for inclusion in an agent prompt.
     pub fn format_goal_context(goals: &[Goal]) -> String {
         if goals.is_empty() {
@@ -9,10 +9,10 @@
 //! using an `AtomicU64`.  Public API surfaces continue to accept and return
 //! values denominated in *cents* (`f64`), so callers are unaffected.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
 
@@ -27,6 +27,13 @@ pub struct GuardrailsConfig {
     pub max_concurrent_tasks: usize,
     /// Maximum concurrent agents.
     pub max_concurrent_agents: usize,
+    /// Maximum concurrent agents of one type, keyed by agent type. Unlisted
+    /// types are bounded only by `max_concurrent_agents`.
+    pub per_type_limits: HashMap<String, usize>,
+    /// Maximum agent spawns in any sliding one-minute window (None = unlimited).
+    pub max_agent_spawns_per_minute: Option<u32>,
+    /// Maximum agent spawns in any sliding one-hour window (None = unlimited).
+    pub max_agent_spawns_per_hour: Option<u32>,
     /// Maximum depth for goal decomposition.
     pub max_decomposition_depth: usize,
     /// Maximum retries per task.
@@ -49,6 +56,9 @@ impl Default for GuardrailsConfig {
             max_tokens_per_hour: 1_000_000,
             max_concurrent_tasks: 10,
             max_concurrent_agents: 4,
+            per_type_limits: HashMap::new(),
+            max_agent_spawns_per_minute: None,
+            max_agent_spawns_per_hour: None,
             max_decomposition_depth: 3,
             max_task_retries: 3,
             max_turns_per_invocation: 50,
@@ -74,15 +84,17 @@ pub enum GuardrailResult {
     Blocked(String),
     /// Action is allowed but with a warning.
     Warning(String),
+    /// Action is blocked because a rate limit over a time window is exhausted.
+    RateLimited(String),
 }
 
 impl GuardrailResult {
     pub fn is_allowed(&self) -> bool {
-        !matches!(self, Self::Blocked(_))
+        !self.is_blocked()
     }
 
     pub fn is_blocked(&self) -> bool {
-        matches!(self, Self::Blocked(_))
+        matches!(self, Self::Blocked(_) | Self::RateLimited(_))
     }
 }
 
@@ -236,7 +248,10 @@ pub struct Guardrails {
     config: GuardrailsConfig,
     metrics: Arc<RuntimeMetrics>,
     current_tasks: Arc<RwLock<HashSet<uuid::Uuid>>>,
-    current_agents: Arc<RwLock<HashSet<String>>>,
+    /// Running agents: instance ID -> agent type.
+    current_agents: Arc<RwLock<HashMap<String, String>>>,
+    /// Ring buffer of recent spawn times, sized to the largest rate limit.
+    spawn_times: Arc<RwLock<VecDeque<Instant>>>,
 }
 
 impl Guardrails {
@@ -245,7 +260,8 @@ impl Guardrails {
             config,
             metrics: Arc::new(RuntimeMetrics::default()),
             current_tasks: Arc::new(RwLock::new(HashSet::new())),
-            current_agents: Arc::new(RwLock::new(HashSet::new())),
+            current_agents: Arc::new(RwLock::new(HashMap::new())),
+            spawn_times: Arc::new(RwLock::new(VecDeque::new())),
         }
     }
 
@@ -329,11 +345,15 @@ impl Guardrails {
         tracing::info!(%task_id, success, remaining_count = remaining, "task registered as ended");
     }
 
-    /// Check if we can spawn a new agent.
-    pub async fn check_agent_spawn(&self, agent_id: &str) -> GuardrailResult {
+    /// Check if we can spawn a new agent of `agent_type`.
+    ///
+    /// Blocked when the instance is already running, or when either the
+    /// global or the type's own concurrency limit is reached; the reason
+    /// names the limit that fired.
+    pub async fn check_agent_spawn(&self, agent_type: &str, agent_id: &str) -> GuardrailResult {
         let agents = self.current_agents.read().await;
 
-        if agents.contains(agent_id) {
+        if agents.contains_key(agent_id) {
             tracing::warn!(agent_id, "agent spawn blocked: agent already running");
             return GuardrailResult::Blocked(format!("Agent '{}' is already running", agent_id));
         }
@@ -351,22 +371,104 @@ impl Guardrails {
             ));
         }
 
+        if let Some(&limit) = self.config.per_type_limits.get(agent_type) {
+            let running = agents.values().filter(|t| *t == agent_type).count();
+            if running >= limit {
+                tracing::warn!(
+                    agent_id,
+                    agent_type,
+                    current_count = running,
+                    max = limit,
+                    "agent spawn blocked: max concurrent agents of this type reached"
+                );
+                return GuardrailResult::Blocked(format!(
+                    "Maximum concurrent '{}' agents ({}) reached",
+                    agent_type, limit
+                ));
+            }
+        }
+        drop(agents);
+
+        self.check_spawn_rate(Instant::now()).await
+    }
+
+    /// Check the sliding-window spawn rate limits as of `now`.
+    ///
+    /// Independent of concurrency: a burst of short-lived agents can stay
+    /// under `max_concurrent_agents` while still spawning without bound.
+    async fn check_spawn_rate(&self, now: Instant) -> GuardrailResult {
+        let spawn_times = self.spawn_times.read().await;
+        let windows = [
+            (
+                self.config.max_agent_spawns_per_minute,
+                Duration::from_secs(60),
+                "minute",
+            ),
+            (
+                self.config.max_agent_spawns_per_hour,
+                Duration::from_secs(3600),
+                "hour",
+            ),
+        ];
+
+        for (limit, window, unit) in windows {
+            let Some(limit) = limit else { continue };
+            let recent = spawn_times
+                .iter()
+                .rev()
+                .take_while(|t| now.saturating_duration_since(**t) < window)
+                .count();
+            if recent >= limit as usize {
+                tracing::warn!(
+                    recent,
+                    limit,
+                    window = unit,
+                    "agent spawn blocked: spawn rate limit reached"
+                );
+                return GuardrailResult::RateLimited(format!(
+                    "Agent spawn rate limit ({}/{}) reached",
+                    limit, unit
+                ));
+            }
+        }
+
         GuardrailResult::Allowed
     }
 
-    /// Register an agent as spawned.
-    pub async fn register_agent_spawn(&self, agent_id: &str) {
+    /// Record a spawn in the rate-limit ring buffer, evicting the oldest
+    /// entry once the buffer holds as many spawns as the largest limit.
+    async fn record_spawn_time(&self, now: Instant) {
+        let capacity = self
+            .config
+            .max_agent_spawns_per_minute
+            .max(self.config.max_agent_spawns_per_hour);
+        let Some(capacity) = capacity.filter(|c| *c > 0) else {
+            return;
+        };
+
+        let mut spawn_times = self.spawn_times.write().await;
+        while spawn_times.len() >= capacity as usize {
+            spawn_times.pop_front();
+        }
+        spawn_times.push_back(now);
+    }
+
+    /// Register an agent of `agent_type` as spawned.
+    pub async fn register_agent_spawn(&self, agent_type: &str, agent_id: &str) {
+        self.record_spawn_time(Instant::now()).await;
         let mut agents = self.current_agents.write().await;
-        agents.insert(agent_id.to_string());
+        agents.insert(agent_id.to_string(), agent_type.to_string());
         self.metrics.record_agent_spawned();
         tracing::info!(
             agent_id,
+            agent_type,
             current_count = agents.len(),
             "agent registered as spawned"
         );
     }
 
-    /// Register an agent as finished.
+    /// Register an agent as finished. Its type is remembered from
+    /// [`Self::register_agent_spawn`], so per-type counts stay accurate.
     pub async fn register_agent_end(&self, agent_id: &str) {
         let mut agents = self.current_agents.write().await;
         agents.remove(agent_id);
@@ -894,18 +996,141 @@ mod tests {
         };
         let guardrails = Guardrails::new(config);
 
-        assert!(guardrails.check_agent_spawn("agent-1").await.is_allowed());
-        guardrails.register_agent_spawn("agent-1").await;
+        assert!(
+            guardrails
+                .check_agent_spawn("worker", "agent-1")
+                .await
+                .is_allowed()
+        );
+        guardrails.register_agent_spawn("worker", "agent-1").await;
 
-        assert!(guardrails.check_agent_spawn("agent-2").await.is_allowed());
-        guardrails.register_agent_spawn("agent-2").await;
+        assert!(
+            guardrails
+                .check_agent_spawn("worker", "agent-2")
+                .await
+                .is_allowed()
+        );
+        guardrails.register_agent_spawn("worker", "agent-2").await;
 
         // Third agent should be blocked — at capacity
-        assert!(guardrails.check_agent_spawn("agent-3").await.is_blocked());
+        assert!(
+            guardrails
+                .check_agent_spawn("worker", "agent-3")
+                .await
+                .is_blocked()
+        );
 
         // Free up a slot
         guardrails.register_agent_end("agent-1").await;
-        assert!(guardrails.check_agent_spawn("agent-3").await.is_allowed());
+        assert!(
+            guardrails
+                .check_agent_spawn("worker", "agent-3")
+                .await
+                .is_allowed()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_per_type_limit_blocks_type_but_not_others() {
+        let config = GuardrailsConfig {
+            max_concurrent_agents: 4,
+            per_type_limits: HashMap::from([("code-reviewer".to_string(), 1)]),
+            ..Default::default()
+        };
+        let guardrails = Guardrails::new(config);
+
+        assert!(
+            guardrails
+                .check_agent_spawn("code-reviewer", "review-1")
+                .await
+                .is_allowed()
+        );
+        guardrails
+            .register_agent_spawn("code-reviewer", "review-1")
+            .await;
+
+        let result = guardrails
+            .check_agent_spawn("code-reviewer", "review-2")
+            .await;
+        match result {
+            GuardrailResult::Blocked(msg) => assert!(
+                msg.contains("'code-reviewer' agents (1)"),
+                "Expected the per-type limit to be named, got: {}",
+                msg
+            ),
+            other => panic!("Expected Blocked result, got {:?}", other),
+        }
+
+        // Other types still fill the remaining global capacity...
+        for id in ["work-1", "work-2", "work-3"] {
+            assert!(
+                guardrails
+                    .check_agent_spawn("worker", id)
+                    .await
+                    .is_allowed()
+            );
+            guardrails.register_agent_spawn("worker", id).await;
+        }
+        // ...after which the global limit fires first.
+        guardrails.register_agent_end("review-1").await;
+        guardrails.register_agent_spawn("worker", "work-4").await;
+        let result = guardrails
+            .check_agent_spawn("code-reviewer", "review-2")
+            .await;
+        match result {
+            GuardrailResult::Blocked(msg) => {
+                assert!(
+                    msg.contains("Maximum concurrent agents (4)"),
+                    "got: {}",
+                    msg
+                )
+            }
+            other => panic!("Expected Blocked result, got {:?}", other),
+        }
+
+        // Ending an agent frees its type's slot.
+        guardrails.register_agent_end("work-4").await;
+        assert!(
+            guardrails
+                .check_agent_spawn("code-reviewer", "review-2")
+                .await
+                .is_allowed()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spawn_rate_limit_denies_despite_free_concurrency() {
+        let config = GuardrailsConfig {
+            max_concurrent_agents: 10,
+            max_agent_spawns_per_minute: Some(2),
+            ..Default::default()
+        };
+        let guardrails = Guardrails::new(config);
+
+        // Short-lived agents: each ends right after spawning, so concurrency
+        // never exceeds one.
+        for id in ["agent-1", "agent-2"] {
+            assert!(
+                guardrails
+                    .check_agent_spawn("worker", id)
+                    .await
+                    .is_allowed()
+            );
+            guardrails.register_agent_spawn("worker", id).await;
+            guardrails.register_agent_end(id).await;
+        }
+
+        let result = guardrails.check_agent_spawn("worker", "agent-3").await;
+        assert!(
+            matches!(result, GuardrailResult::RateLimited(_)),
+            "got {:?}",
+            result
+        );
+        assert!(result.is_blocked());
+
+        // Once the window slides past the earlier spawns, spawning resumes.
+        let later = Instant::now() + Duration::from_secs(61);
+        assert!(guardrails.check_spawn_rate(later).await.is_allowed());
     }
 
     #[tokio::test]
@@ -923,12 +1148,26 @@ mod tests {
         let task_id_a = uuid::Uuid::new_v4().to_string();
         let task_id_b = uuid::Uuid::new_v4().to_string();
 
-        assert!(guardrails.check_agent_spawn(&task_id_a).await.is_allowed());
-        guardrails.register_agent_spawn(&task_id_a).await;
+        assert!(
+            guardrails
+                .check_agent_spawn("implementer", &task_id_a)
+                .await
+                .is_allowed()
+        );
+        guardrails
+            .register_agent_spawn("implementer", &task_id_a)
+            .await;
 
         // Second agent with a DIFFERENT unique ID must also be allowed
-        assert!(guardrails.check_agent_spawn(&task_id_b).await.is_allowed());
-        guardrails.register_agent_spawn(&task_id_b).await;
+        assert!(
+            guardrails
+                .check_agent_spawn("implementer", &task_id_b)
+                .await
+                .is_allowed()
+        );
+        guardrails
+            .register_agent_spawn("implementer", &task_id_b)
+            .await;
 
         // Both agents tracked independently — count is 2
         {
@@ -941,7 +1180,7 @@ mod tests {
         {
             let agents = guardrails.current_agents.read().await;
             assert_eq!(agents.len(), 1);
-            assert!(agents.contains(&task_id_b));
+            assert!(agents.contains_key(&task_id_b));
         }
     }
 
@@ -955,11 +1194,16 @@ mod tests {
 
         let agent_id = "task-abc-123";
 
-        assert!(guardrails.check_agent_spawn(agent_id).await.is_allowed());
-        guardrails.register_agent_spawn(agent_id).await;
+        assert!(
+            guardrails
+                .check_agent_spawn("worker", agent_id)
+                .await
+                .is_allowed()
+        );
+        guardrails.register_agent_spawn("worker", agent_id).await;
 
         // Same ID should be blocked even though we haven't hit the limit
-        let result = guardrails.check_agent_spawn(agent_id).await;
+        let result = guardrails.check_agent_spawn("worker", agent_id).await;
         assert!(result.is_blocked());
         match result {
             GuardrailResult::Blocked(msg) => {
@@ -974,7 +1218,12 @@ mod tests {
 
         // After ending, the same ID can be re-used
         guardrails.register_agent_end(agent_id).await;
-        assert!(guardrails.check_agent_spawn(agent_id).await.is_allowed());
+        assert!(
+            guardrails
+                .check_agent_spawn("worker", agent_id)
+                .await
+                .is_allowed()
+        );
     }
 
     #[tokio::test]
@@ -0,0 +1,131 @@
+//! Reuse of verification verdicts across identical re-checks.
+//!
+//! A verdict is keyed by the intent's source (the goal or task it was
+//! extracted from) and a fingerprint of the evaluated work: each task's
+//! description and artifacts plus the [`repo_state`] of its worktree. Each
+//! entry also records a fingerprint of the intent's spec, so a verdict given
+//! against an earlier version of the goal is never returned; storing a
+//! verdict for a new spec drops every entry recorded against the old one.
+
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::models::{IntentVerificationResult, OriginalIntent, Task};
+use crate::services::result_cache::repo_state;
+
+#[derive(Debug, Clone)]
+struct CachedVerdict {
+    /// [`VerdictCache::spec_fingerprint`] of the intent the verdict answers.
+    spec: String,
+    result: IntentVerificationResult,
+}
+
+/// In-memory LRU of verdicts, keyed by `(intent source, work fingerprint)`.
+pub(super) struct VerdictCache {
+    entries: moka::future::Cache<(Uuid, String), CachedVerdict>,
+}
+
+impl VerdictCache {
+    pub(super) fn new(capacity: u64) -> Self {
+        Self {
+            entries: moka::future::Cache::builder()
+                .max_capacity(capacity)
+                .support_invalidation_closures()
+                .build(),
+        }
+    }
+
+    /// Hash of what the work is judged against: the intent text,
+    /// requirements, and success criteria.
+    pub(super) fn spec_fingerprint(intent: &OriginalIntent) -> String {
+        let mut hasher = Sha256::new();
+        update(&mut hasher, &intent.original_text);
+        for part in intent
+            .key_requirements
+            .iter()
+            .chain(&intent.success_criteria)
+        {
+            update(&mut hasher, part);
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    /// Hash of the work being judged. `None` when a task's worktree is not a
+    /// git repository, since changes to it cannot be detected.
+    pub(super) async fn work_fingerprint(
+        tasks: &[Task],
+        include_artifacts: bool,
+    ) -> Option<String> {
+        let mut hasher = Sha256::new();
+        for task in tasks {
+            update(&mut hasher, &task.id.to_string());
+            update(&mut hasher, &task.title);
+            update(&mut hasher, &task.description);
+            if include_artifacts {
+                for artifact in &task.artifacts {
+                    update(&mut hasher, &artifact.uri);
+                    update(&mut hasher, &format!("{:?}", artifact.artifact_type));
+                    update(
+                        &mut hasher,
+                        artifact.checksum.as_deref().unwrap_or_default(),
+                    );
+                }
+            }
+            if let Some(worktree) = &task.worktree_path {
+                update(&mut hasher, &repo_state(Path::new(worktree)).await?);
+            }
+        }
+        Some(hex::encode(hasher.finalize()))
+    }
+
+    /// The verdict stored for this work, if it answered the same spec. An
+    /// entry recorded against a different spec is stale and is removed.
+    pub(super) async fn lookup(
+        &self,
+        source_id: Uuid,
+        work: &str,
+        spec: &str,
+    ) -> Option<IntentVerificationResult> {
+        let key = (source_id, work.to_string());
+        let entry = self.entries.get(&key).await?;
+        if entry.spec == spec {
+            return Some(entry.result);
+        }
+        self.entries.invalidate(&key).await;
+        None
+    }
+
+    pub(super) async fn store(
+        &self,
+        source_id: Uuid,
+        work: String,
+        spec: String,
+        result: IntentVerificationResult,
+    ) {
+        let current = spec.clone();
+        // Only fails when invalidation closures are unsupported, which the
+        // builder above enables.
+        let _ = self
+            .entries
+            .invalidate_entries_if(move |(source, _), entry| {
+                *source == source_id && entry.spec != current
+            });
+        self.entries
+            .insert((source_id, work), CachedVerdict { spec, result })
+            .await;
+    }
+
+    /// Drop every verdict recorded for `source_id`.
+    pub(super) fn invalidate_source(&self, source_id: Uuid) {
+        let _ = self
+            .entries
+            .invalidate_entries_if(move |(source, _), _| *source == source_id);
+    }
+}
+
+fn update(hasher: &mut Sha256, part: &str) {
+    hasher.update((part.len() as u64).to_le_bytes());
+    hasher.update(part.as_bytes());
+}
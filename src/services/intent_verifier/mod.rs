@@ -27,10 +27,13 @@
 //!   builder, and the overseer-evidence appender.
 //! - [`parser`] parses the verifier agent's structured text responses back
 //!   into [`IntentVerificationResult`] domain values.
+//! - [`cache`] remembers verdicts so re-checking unchanged work against an
+//!   unchanged intent skips the verifier agent.
 //! - This module owns the `IntentVerifierService` itself: repository I/O,
 //!   substrate orchestration, the convergence loop, and the
 //!   [`ConvergentIntentVerifier`] trait impl.
 
+mod cache;
 mod parser;
 mod prompt;
 
@@ -61,6 +64,9 @@ pub struct IntentVerifierConfig {
     pub include_task_output: bool,
     /// Agent type to use for verification.
     pub verifier_agent_type: String,
+    /// Maximum verdicts kept for reuse when the same work is re-checked
+    /// against the same intent. `0` disables caching.
+    pub cache_capacity: u64,
 }
 
 impl Default for IntentVerifierConfig {
@@ -71,6 +77,7 @@ impl Default for IntentVerifierConfig {
             include_artifacts: true,
             include_task_output: true,
             verifier_agent_type: "intent-verifier".to_string(),
+            cache_capacity: 256,
         }
     }
 }
@@ -85,6 +92,7 @@ where
     task_repo: Arc<T>,
     substrate: Arc<dyn Substrate>,
     config: IntentVerifierConfig,
+    verdict_cache: Option<cache::VerdictCache>,
 }
 
 impl<G, T> IntentVerifierService<G, T>
@@ -98,11 +106,14 @@ where
         substrate: Arc<dyn Substrate>,
         config: IntentVerifierConfig,
     ) -> Self {
+        let verdict_cache =
+            (config.cache_capacity > 0).then(|| cache::VerdictCache::new(config.cache_capacity));
         Self {
             goal_repo,
             task_repo,
             substrate,
             config,
+            verdict_cache,
         }
     }
 
@@ -243,12 +254,87 @@ where
     }
 
     /// Verify that completed tasks satisfy the original intent.
+    ///
+    /// Returns the cached verdict when the same work was already verified
+    /// against the same intent; see [`Self::verify_intent_with`].
     pub async fn verify_intent(
         &self,
         intent: &OriginalIntent,
         completed_tasks: &[Task],
         iteration: u32,
     ) -> DomainResult<IntentVerificationResult> {
+        self.verify_intent_with(intent, completed_tasks, iteration, false)
+            .await
+    }
+
+    /// Verify that completed tasks satisfy the original intent, optionally
+    /// ignoring any cached verdict.
+    ///
+    /// Verdicts are cached per intent source and keyed by the tasks'
+    /// descriptions, artifacts, and worktree state, so an unrelated retry
+    /// does not re-run the verifier agent. A change to the intent's text,
+    /// requirements, or success criteria invalidates the source's entries.
+    /// With `bypass_cache` the agent always runs and its verdict replaces the
+    /// cached one.
+    pub async fn verify_intent_with(
+        &self,
+        intent: &OriginalIntent,
+        completed_tasks: &[Task],
+        iteration: u32,
+        bypass_cache: bool,
+    ) -> DomainResult<IntentVerificationResult> {
+        let cached = match &self.verdict_cache {
+            Some(cache) => cache::VerdictCache::work_fingerprint(
+                completed_tasks,
+                self.config.include_artifacts,
+            )
+            .await
+            .map(|work| (cache, work, cache::VerdictCache::spec_fingerprint(intent))),
+            None => None,
+        };
+
+        if !bypass_cache
+            && let Some((cache, work, spec)) = &cached
+            && let Some(mut verdict) = cache.lookup(intent.source_id, work, spec).await
+        {
+            tracing::debug!(
+                source_id = %intent.source_id,
+                "Reusing cached intent verification verdict"
+            );
+            verdict.id = Uuid::new_v4();
+            verdict.intent_id = intent.id;
+            verdict.iteration = iteration;
+            return Ok(verdict);
+        }
+
+        let (result, completed) = self
+            .execute_verification(intent, completed_tasks, iteration)
+            .await?;
+        // A verifier that failed to finish says nothing about the work.
+        if completed && let Some((cache, work, spec)) = cached {
+            cache
+                .store(intent.source_id, work, spec, result.clone())
+                .await;
+        }
+        Ok(result)
+    }
+
+    /// Drop cached verdicts for the intent extracted from `source_id` (a goal
+    /// or task), forcing the next verification to run the verifier agent.
+    pub fn invalidate_cached_verdicts(&self, source_id: Uuid) {
+        if let Some(cache) = &self.verdict_cache {
+            cache.invalidate_source(source_id);
+        }
+    }
+
+    /// Run the verifier agent. The flag reports whether its session
+    /// completed, i.e. whether the result is an actual verdict.
+    async fn execute_verification(
+        &self,
+        intent: &OriginalIntent,
+        completed_tasks: &[Task],
+        iteration: u32,
+    ) -> DomainResult<(IntentVerificationResult, bool)> {
         // Build the verification prompt
         let prompt = prompt::build_verification_prompt(
             intent,
@@ -288,7 +374,8 @@ where
         let session = self.substrate.execute(request).await?;
 
         // Parse the response to build the verification result
-        let result = if session.status == SessionStatus::Completed {
+        let completed = session.status == SessionStatus::Completed;
+        let result = if completed {
             parser::parse_verification_response(&session, intent, completed_tasks, iteration)?
         } else {
             // Verification failed - return indeterminate
@@ -301,7 +388,7 @@ where
                 ))
         };
 
-        Ok(result)
+        Ok((result, completed))
     }
 
     /// Run a task convergence loop until work satisfies the guiding intent.
@@ -846,11 +933,16 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapters::sqlite::test_support::{setup_goal_repo, setup_task_repo};
+    use crate::adapters::sqlite::{SqliteGoalRepository, SqliteTaskRepository};
+    use crate::adapters::substrates::MockSubstrate;
+    use crate::adapters::substrates::mock::MockResponse;
     use crate::domain::models::{
         ConstraintConformance, ConstraintEvaluation, GapCategory, GapSeverity, IntentGap,
         NewTaskGuidance, RepromptApproach, RepromptGuidance, SessionStatus, SubstrateConfig,
         SubstrateSession, TaskPriority,
     };
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
     fn test_intent_verifier_config_default() {
@@ -955,6 +1047,78 @@ NEW_TASKS:
         assert_eq!(result.satisfaction, IntentSatisfaction::Indeterminate);
     }
 
+    /// A verifier whose substrate always answers "satisfied", plus a count of
+    /// the substrate calls it has made.
+    async fn counting_verifier() -> (
+        IntentVerifierService<SqliteGoalRepository, SqliteTaskRepository>,
+        Arc<AtomicUsize>,
+    ) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let substrate = MockSubstrate::with_response_fn(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            MockResponse::success("SATISFACTION: satisfied\nCONFIDENCE: 0.9\nSUMMARY: Done.")
+        });
+        let service = IntentVerifierService::with_defaults(
+            setup_goal_repo().await,
+            setup_task_repo().await,
+            Arc::new(substrate),
+        );
+        (service, calls)
+    }
+
+    #[tokio::test]
+    async fn test_identical_verification_reuses_cached_verdict() {
+        let (service, calls) = counting_verifier().await;
+        let intent = OriginalIntent::from_goal(Uuid::new_v4(), "Add a login page");
+        let tasks = vec![create_mock_task("Login page")];
+
+        let first = service.verify_intent(&intent, &tasks, 1).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let second = service.verify_intent(&intent, &tasks, 2).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(second.satisfaction, first.satisfaction);
+        assert_eq!(second.iteration, 2);
+
+        // A forced re-verification runs the agent even for identical work.
+        service
+            .verify_intent_with(&intent, &tasks, 3, true)
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Changed work misses the cache.
+        let mut changed = tasks.clone();
+        changed[0].description = "Login page with remember-me".to_string();
+        service.verify_intent(&intent, &changed, 4).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_goal_spec_change_invalidates_cached_verdicts() {
+        let (service, calls) = counting_verifier().await;
+        let goal_id = Uuid::new_v4();
+        let intent = OriginalIntent::from_goal(goal_id, "Add a login page");
+        let tasks = vec![create_mock_task("Login page")];
+        service.verify_intent(&intent, &tasks, 1).await.unwrap();
+
+        let mut revised = intent.clone();
+        revised
+            .key_requirements
+            .push("[MUST] sso: Support single sign-on".to_string());
+        service.verify_intent(&revised, &tasks, 2).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Verdicts for the old spec were dropped rather than kept around.
+        service.verify_intent(&intent, &tasks, 3).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        service.invalidate_cached_verdicts(goal_id);
+        service.verify_intent(&intent, &tasks, 4).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
     #[test]
     fn test_convergence_config_in_verifier_config() {
         let config = IntentVerifierConfig {
@@ -969,6 +1133,7 @@ NEW_TASKS:
             include_artifacts: true,
             include_task_output: false,
             verifier_agent_type: "custom-verifier".to_string(),
+            cache_capacity: 0,
         };
 
         assert_eq!(config.convergence.max_iterations, 5);
@@ -0,0 +1,261 @@
+//! Cross-process leader election over a shared database.
+//!
+//! Several swarm processes can run against the same database. Work that must
+//! happen once per database rather than once per process (pruning,
+//! reconciliation sweeps) is gated on holding a lease in the
+//! [`LeaderLeaseRepository`]. The leader renews its lease while it keeps
+//! checking; if it stops, the lease lapses and the next process to check
+//! takes over.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+use crate::domain::ports::LeaderLeaseRepository;
+
+/// Lease name used for singleton event handlers.
+pub const REACTOR_LEADER_LEASE: &str = "event_reactor";
+
+/// Default lease lifetime.
+const DEFAULT_LEASE_TTL: Duration = Duration::from_secs(30);
+
+/// Tracks whether this process holds a named leadership lease.
+pub struct LeaderElection {
+    repo: Arc<dyn LeaderLeaseRepository>,
+    lease_name: String,
+    holder_id: String,
+    ttl: Duration,
+    /// Result of the last lease check and when it was made.
+    last_check: Mutex<Option<(Instant, bool)>>,
+}
+
+impl LeaderElection {
+    pub fn new(repo: Arc<dyn LeaderLeaseRepository>, holder_id: impl Into<String>) -> Self {
+        Self {
+            repo,
+            lease_name: REACTOR_LEADER_LEASE.to_string(),
+            holder_id: holder_id.into(),
+            ttl: DEFAULT_LEASE_TTL,
+            last_check: Mutex::new(None),
+        }
+    }
+
+    /// Set the lease lifetime. Leadership is rechecked every third of it.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Use a lease name other than [`REACTOR_LEADER_LEASE`].
+    pub fn with_lease_name(mut self, name: impl Into<String>) -> Self {
+        self.lease_name = name.into();
+        self
+    }
+
+    pub fn holder_id(&self) -> &str {
+        &self.holder_id
+    }
+
+    /// How often the lease should be renewed to keep it from lapsing.
+    pub fn renew_interval(&self) -> Duration {
+        self.ttl / 3
+    }
+
+    /// Whether this process currently leads, acquiring or renewing the lease
+    /// when the cached answer is older than [`Self::renew_interval`].
+    ///
+    /// A database error counts as not leading, so a process that cannot reach
+    /// the lease table never runs singleton work.
+    pub async fn is_leader(&self) -> bool {
+        let mut last_check = self.last_check.lock().await;
+        if let Some((checked_at, leader)) = *last_check
+            && checked_at.elapsed() < self.renew_interval()
+        {
+            return leader;
+        }
+
+        let leader = match self
+            .repo
+            .try_acquire(&self.lease_name, &self.holder_id, self.ttl)
+            .await
+        {
+            Ok(acquired) => acquired,
+            Err(e) => {
+                tracing::warn!(
+                    lease = %self.lease_name,
+                    "Leader lease check failed, assuming follower: {}",
+                    e
+                );
+                false
+            }
+        };
+
+        if leader != last_check.is_some_and(|(_, was_leader)| was_leader) {
+            tracing::info!(
+                lease = %self.lease_name,
+                holder = %self.holder_id,
+                leader,
+                "Leadership changed"
+            );
+        }
+        *last_check = Some((Instant::now(), leader));
+        leader
+    }
+
+    /// Give up the lease so another process can take over immediately.
+    pub async fn release(&self) {
+        let mut last_check = self.last_check.lock().await;
+        if let Err(e) = self.repo.release(&self.lease_name, &self.holder_id).await {
+            tracing::warn!(lease = %self.lease_name, "Failed to release leader lease: {}", e);
+        }
+        *last_check = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::{SqliteLeaderLeaseRepository, create_migrated_test_pool};
+    use crate::services::builtin_handlers::EventPruningHandler;
+    use crate::services::event_bus::{
+        EventBus, EventBusConfig, EventCategory, EventPayload, EventSeverity, SequenceNumber,
+    };
+    use crate::services::event_factory::make_event;
+    use crate::services::event_reactor::{EventReactor, ReactorConfig};
+    use crate::services::event_store::{EventStore, InMemoryEventStore};
+    use sqlx::SqlitePool;
+    use uuid::Uuid;
+
+    /// One simulated swarm process: its own bus, reactor and event store,
+    /// sharing only the lease database with its peers.
+    struct Process {
+        bus: Arc<EventBus>,
+        reactor: EventReactor,
+        store: Arc<InMemoryEventStore>,
+    }
+
+    async fn spawn_process(pool: &SqlitePool, holder: &str, ttl: Duration) -> Process {
+        let store = Arc::new(InMemoryEventStore::new());
+        let mut stale = make_event(
+            EventSeverity::Debug,
+            EventCategory::Orchestrator,
+            None,
+            None,
+            EventPayload::OrchestratorResumed,
+        );
+        stale.timestamp = chrono::Utc::now() - chrono::Duration::hours(1);
+        store.append(&stale).await.unwrap();
+
+        let election = LeaderElection::new(
+            Arc::new(SqliteLeaderLeaseRepository::new(pool.clone())),
+            holder,
+        )
+        .with_ttl(ttl);
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let reactor = EventReactor::new(bus.clone(), ReactorConfig::default())
+            .with_leader_election(Arc::new(election));
+        reactor
+            .register(Arc::new(EventPruningHandler::new(store.clone(), 0)))
+            .await;
+        reactor.start();
+        Process {
+            bus,
+            reactor,
+            store,
+        }
+    }
+
+    async fn fire_pruning(process: &Process) {
+        process
+            .bus
+            .publish(make_event(
+                EventSeverity::Debug,
+                EventCategory::Scheduler,
+                None,
+                None,
+                EventPayload::ScheduledEventFired {
+                    schedule_id: Uuid::new_v4(),
+                    name: "event-pruning".to_string(),
+                },
+            ))
+            .await;
+    }
+
+    async fn pruned(process: &Process) -> bool {
+        process.store.count().await.unwrap() == 0
+    }
+
+    #[tokio::test]
+    async fn test_only_leader_runs_singleton_pruning_and_follower_takes_over() {
+        let pool = create_migrated_test_pool().await.unwrap();
+        let ttl = Duration::from_millis(300);
+        let a = spawn_process(&pool, "process-a", ttl).await;
+        let b = spawn_process(&pool, "process-b", ttl).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        fire_pruning(&a).await;
+        fire_pruning(&b).await;
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        let (a_pruned, b_pruned) = (pruned(&a).await, pruned(&b).await);
+        assert!(
+            a_pruned ^ b_pruned,
+            "exactly one process should prune (a: {a_pruned}, b: {b_pruned})"
+        );
+        let (leader, follower) = if a_pruned { (a, b) } else { (b, a) };
+
+        // Once the leader stops renewing, its lease lapses and the follower
+        // is elected.
+        leader.reactor.stop();
+        tokio::time::sleep(ttl * 2).await;
+        fire_pruning(&follower).await;
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(pruned(&follower).await);
+        follower.reactor.stop();
+    }
+
+    #[tokio::test]
+    async fn test_follower_replay_advances_singleton_watermark() {
+        let pool = create_migrated_test_pool().await.unwrap();
+        let lease_repo = Arc::new(SqliteLeaderLeaseRepository::new(pool.clone()));
+        assert!(
+            lease_repo
+                .try_acquire(REACTOR_LEADER_LEASE, "process-a", Duration::from_secs(60))
+                .await
+                .unwrap()
+        );
+
+        let store = Arc::new(InMemoryEventStore::new());
+        let mut fired = make_event(
+            EventSeverity::Debug,
+            EventCategory::Scheduler,
+            None,
+            None,
+            EventPayload::ScheduledEventFired {
+                schedule_id: Uuid::new_v4(),
+                name: "event-pruning".to_string(),
+            },
+        );
+        fired.sequence = SequenceNumber(5);
+        fired.timestamp = chrono::Utc::now() - chrono::Duration::hours(1);
+        store.append(&fired).await.unwrap();
+
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let reactor = EventReactor::new(bus, ReactorConfig::default())
+            .with_store(store.clone())
+            .with_leader_election(Arc::new(LeaderElection::new(lease_repo, "process-b")));
+        reactor
+            .register(Arc::new(EventPruningHandler::new(store.clone(), 0)))
+            .await;
+
+        reactor.replay_missed_events().await.unwrap();
+
+        // The follower did not prune, but will not replay the event again.
+        assert_eq!(store.count().await.unwrap(), 1);
+        assert_eq!(
+            store.get_watermark("EventPruningHandler").await.unwrap(),
+            Some(SequenceNumber(5))
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Sampling layer for high-volume `debug`/`trace` tracing output.
+//!
+//! [`SamplingLayer`] keeps one in N `debug`/`trace` events and drops the
+//! rest before any formatting layer sees them. `info`, `warn` and `error`
+//! always pass. N comes from [`LogSamplingConfig`], with per-target
+//! overrides matched by longest prefix.
+//!
+//! Sampling is deterministic per correlation id: when an event carries a
+//! `correlation_id` field, or runs inside a span that does, the keep/drop
+//! decision is a hash of that id. Every event in a sampled chain is
+//! therefore logged in full. Events without a correlation id are sampled
+//! round-robin.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::services::config::LogSamplingConfig;
+
+/// Field name that ties related events into one sampled chain.
+const CORRELATION_FIELD: &str = "correlation_id";
+
+/// Hashed correlation id stored in a span's extensions.
+#[derive(Debug, Clone, Copy)]
+struct CorrelationKey(u64);
+
+/// Tracing layer that samples `debug`/`trace` events — see module docs.
+#[derive(Debug)]
+pub struct SamplingLayer {
+    rate: u64,
+    /// `(target prefix, rate)`, longest prefix first.
+    targets: Vec<(String, u64)>,
+    counter: AtomicU64,
+}
+
+impl SamplingLayer {
+    pub fn new(config: &LogSamplingConfig) -> Self {
+        let mut targets: Vec<(String, u64)> = config
+            .targets
+            .iter()
+            .map(|(target, rate)| (target.clone(), u64::from(*rate).max(1)))
+            .collect();
+        targets.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+        Self {
+            rate: u64::from(config.rate).max(1),
+            targets,
+            counter: AtomicU64::new(0),
+        }
+    }
+
+    /// Whether any sampling is configured at all.
+    pub fn is_active(&self) -> bool {
+        self.rate > 1 || self.targets.iter().any(|(_, rate)| *rate > 1)
+    }
+
+    fn rate_for(&self, target: &str) -> u64 {
+        self.targets
+            .iter()
+            .find(|(prefix, _)| target.starts_with(prefix.as_str()))
+            .map_or(self.rate, |(_, rate)| *rate)
+    }
+}
+
+impl<S> Layer<S> for SamplingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = CorrelationVisitor::default();
+        attrs.record(&mut visitor);
+        if let (Some(key), Some(span)) = (visitor.key, ctx.span(id)) {
+            span.extensions_mut().insert(CorrelationKey(key));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let mut visitor = CorrelationVisitor::default();
+        values.record(&mut visitor);
+        if let (Some(key), Some(span)) = (visitor.key, ctx.span(id)) {
+            span.extensions_mut().replace(CorrelationKey(key));
+        }
+    }
+
+    fn event_enabled(&self, event: &Event<'_>, ctx: Context<'_, S>) -> bool {
+        let meta = event.metadata();
+        if !matches!(*meta.level(), Level::DEBUG | Level::TRACE) {
+            return true;
+        }
+        let rate = self.rate_for(meta.target());
+        if rate <= 1 {
+            return true;
+        }
+
+        let mut visitor = CorrelationVisitor::default();
+        event.record(&mut visitor);
+        let key = visitor.key.or_else(|| {
+            ctx.event_scope(event)?
+                .find_map(|span| span.extensions().get::<CorrelationKey>().map(|key| key.0))
+        });
+
+        match key {
+            Some(key) => key.is_multiple_of(rate),
+            None => self
+                .counter
+                .fetch_add(1, Ordering::Relaxed)
+                .is_multiple_of(rate),
+        }
+    }
+}
+
+/// Extracts and hashes the `correlation_id` field, if present.
+#[derive(Default)]
+struct CorrelationVisitor {
+    key: Option<u64>,
+}
+
+impl CorrelationVisitor {
+    fn hash(value: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Visit for CorrelationVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == CORRELATION_FIELD {
+            self.key = Some(Self::hash(value));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == CORRELATION_FIELD {
+            self.key = Some(Self::hash(&format!("{:?}", value)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Counts the events that reach it, by level.
+    #[derive(Clone, Default)]
+    struct Counts {
+        debug: Arc<AtomicUsize>,
+        warn: Arc<AtomicUsize>,
+    }
+
+    impl<S: Subscriber> Layer<S> for Counts {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            match *event.metadata().level() {
+                Level::DEBUG => self.debug.fetch_add(1, Ordering::Relaxed),
+                Level::WARN => self.warn.fetch_add(1, Ordering::Relaxed),
+                _ => 0,
+            };
+        }
+    }
+
+    fn sampling(rate: u32, targets: &[(&str, u32)]) -> LogSamplingConfig {
+        LogSamplingConfig {
+            rate,
+            targets: targets
+                .iter()
+                .map(|(t, r)| (t.to_string(), *r))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    fn with_sampling(config: &LogSamplingConfig, f: impl FnOnce()) -> Counts {
+        let counts = Counts::default();
+        let subscriber = tracing_subscriber::registry()
+            .with(SamplingLayer::new(config))
+            .with(counts.clone());
+        tracing::subscriber::with_default(subscriber, f);
+        counts
+    }
+
+    #[test]
+    fn test_one_in_ten_keeps_tenth_of_debug_and_all_warnings() {
+        let counts = with_sampling(&sampling(10, &[]), || {
+            for i in 0..1000 {
+                tracing::debug!(i, "noisy");
+                if i % 10 == 0 {
+                    tracing::warn!(i, "important");
+                }
+            }
+        });
+
+        let debug = counts.debug.load(Ordering::Relaxed);
+        assert!(
+            (90..=110).contains(&debug),
+            "kept {} of 1000 debug lines",
+            debug
+        );
+        assert_eq!(counts.warn.load(Ordering::Relaxed), 100);
+    }
+
+    #[test]
+    fn test_correlated_chain_is_kept_or_dropped_together() {
+        let ids: Vec<String> = (0..200).map(|i| format!("corr-{}", i)).collect();
+        let counts = with_sampling(&sampling(10, &[]), || {
+            for id in &ids {
+                tracing::debug!(correlation_id = %id, "first");
+                let span = tracing::info_span!("chain", correlation_id = %id);
+                let _guard = span.enter();
+                tracing::debug!("second");
+                tracing::trace!("third");
+            }
+        });
+
+        // Each kept chain contributes both of its debug events.
+        let debug = counts.debug.load(Ordering::Relaxed);
+        assert_eq!(debug % 2, 0);
+        assert!(debug > 0 && debug < 200, "kept {} debug lines", debug);
+    }
+
+    #[test]
+    fn test_target_override_takes_longest_prefix() {
+        let layer = SamplingLayer::new(&sampling(
+            10,
+            &[
+                ("abathur::services", 100),
+                ("abathur::services::event_bus", 1),
+            ],
+        ));
+        assert!(layer.is_active());
+        assert_eq!(layer.rate_for("abathur::services::event_bus::bus"), 1);
+        assert_eq!(layer.rate_for("abathur::services::memory_service"), 100);
+        assert_eq!(layer.rate_for("abathur::adapters"), 10);
+        assert!(!SamplingLayer::new(&LogSamplingConfig::default()).is_active());
+    }
+}
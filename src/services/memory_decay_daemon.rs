@@ -440,7 +440,7 @@ mod tests {
     use uuid::Uuid;
 
     use crate::domain::errors::{DomainError, DomainResult};
-    use crate::domain::models::{Memory, MemoryQuery, MemoryTier};
+    use crate::domain::models::{Memory, MemoryQuery, MemoryTier, NamespaceSummary};
     use crate::domain::ports::MemoryRepository;
     use crate::services::memory_service::MemoryService;
 
@@ -519,6 +519,29 @@ mod tests {
         async fn count_by_tier(&self) -> DomainResult<std::collections::HashMap<MemoryTier, u64>> {
             Ok(std::collections::HashMap::new())
         }
+        async fn list_namespaces(&self) -> DomainResult<Vec<NamespaceSummary>> {
+            Ok(Vec::new())
+        }
+
+        async fn count_stale_embeddings(&self, _model: &str) -> DomainResult<u64> {
+            Ok(0)
+        }
+
+        async fn list_stale_embeddings(
+            &self,
+            _model: &str,
+            _limit: usize,
+        ) -> DomainResult<Vec<Memory>> {
+            Ok(Vec::new())
+        }
+
+        async fn store_embeddings(
+            &self,
+            _model: &str,
+            _embeddings: &[(Uuid, Vec<f32>)],
+        ) -> DomainResult<()> {
+            Ok(())
+        }
     }
 
     /// Helper to create a daemon with the FailingMemoryRepository.
@@ -955,6 +978,29 @@ mod tests {
         async fn count_by_tier(&self) -> DomainResult<std::collections::HashMap<MemoryTier, u64>> {
             Ok(std::collections::HashMap::new())
         }
+        async fn list_namespaces(&self) -> DomainResult<Vec<NamespaceSummary>> {
+            Ok(Vec::new())
+        }
+
+        async fn count_stale_embeddings(&self, _model: &str) -> DomainResult<u64> {
+            Ok(0)
+        }
+
+        async fn list_stale_embeddings(
+            &self,
+            _model: &str,
+            _limit: usize,
+        ) -> DomainResult<Vec<Memory>> {
+            Ok(Vec::new())
+        }
+
+        async fn store_embeddings(
+            &self,
+            _model: &str,
+            _embeddings: &[(Uuid, Vec<f32>)],
+        ) -> DomainResult<()> {
+            Ok(())
+        }
     }
 
     /// Helper to create a daemon backed by EventProducingMemoryRepository.
@@ -15,10 +15,13 @@
 //! they are also consumed by query-side helpers. The decay service holds an
 //! `Arc<MemoryService<R>>` and delegates to those.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use uuid::Uuid;
+
 use crate::domain::errors::DomainResult;
-use crate::domain::models::{Memory, MemoryTier};
+use crate::domain::models::{Memory, MemoryQuery, MemoryTier};
 use crate::domain::ports::MemoryRepository;
 use crate::services::event_bus::{EventCategory, EventPayload, EventSeverity, UnifiedEvent};
 use crate::services::event_factory;
@@ -58,8 +61,19 @@ impl<R: MemoryRepository> MemoryDecayService<R> {
     }
 
     /// Prune expired memories. Returns the count and events to be journaled.
+    ///
+    /// Namespaces with a [`NamespacePolicy`](crate::services::memory_service::NamespacePolicy)
+    /// expire by that policy's TTL instead of each memory's `expires_at`.
     pub async fn prune_expired(&self) -> DomainResult<(u64, Vec<UnifiedEvent>)> {
-        let count = self.repository().prune_expired().await?;
+        let count = if self.memory_service.namespace_policies().is_empty() {
+            self.repository().prune_expired().await?
+        } else {
+            let expired = self.find_expired().await?;
+            for mem in &expired {
+                self.repository().delete(mem.id).await?;
+            }
+            expired.len() as u64
+        };
         let mut events = Vec::new();
         if count > 0 {
             events.push(Self::make_event(
@@ -74,35 +88,53 @@ impl<R: MemoryRepository> MemoryDecayService<R> {
         Ok((count, events))
     }
 
+    /// List the memories [`Self::prune_expired`] would delete, without
+    /// deleting them.
+    pub async fn find_expired(&self) -> DomainResult<Vec<Memory>> {
+        let policies = self.memory_service.namespace_policies();
+        let mut expired: Vec<Memory> = self
+            .repository()
+            .get_expired()
+            .await?
+            .into_iter()
+            .filter(|mem| !policies.contains_key(&mem.namespace))
+            .collect();
+
+        for (namespace, policy) in policies {
+            if policy.ttl_secs.is_none() {
+                continue;
+            }
+            let memories = self.namespace_memories(namespace, None).await?;
+            expired.extend(memories.into_iter().filter(|mem| policy.is_expired(mem)));
+        }
+
+        Ok(expired)
+    }
+
+    async fn namespace_memories(
+        &self,
+        namespace: &str,
+        tier: Option<MemoryTier>,
+    ) -> DomainResult<Vec<Memory>> {
+        let query = MemoryQuery {
+            namespace: Some(namespace.to_string()),
+            tier,
+            ..Default::default()
+        };
+        self.repository().query(query).await
+    }
+
     /// Prune decayed memories (below threshold). Returns count and events.
+    ///
+    /// Memories with a decay floor (per-tier or per-memory) are skipped;
+    /// only `prune_expired` removes them.
     pub async fn prune_decayed(&self) -> DomainResult<(u64, Vec<UnifiedEvent>)> {
         let mut count = 0;
         let mut events = Vec::new();
 
-        let decay_config = self.memory_service.decay_config();
-
-        // Prune working memories
-        let decayed = self
-            .repository()
-            .get_decayed(decay_config.working_prune_threshold)
-            .await?;
-        for mem in decayed {
-            if mem.tier == MemoryTier::Working {
-                self.repository().delete(mem.id).await?;
-                count += 1;
-            }
-        }
-
-        // Prune episodic memories
-        let decayed = self
-            .repository()
-            .get_decayed(decay_config.episodic_prune_threshold)
-            .await?;
-        for mem in decayed {
-            if mem.tier == MemoryTier::Episodic {
-                self.repository().delete(mem.id).await?;
-                count += 1;
-            }
+        for mem in self.find_decayed(&HashSet::new()).await? {
+            self.repository().delete(mem.id).await?;
+            count += 1;
         }
 
         if count > 0 {
@@ -119,6 +151,30 @@ impl<R: MemoryRepository> MemoryDecayService<R> {
         Ok((count, events))
     }
 
+    /// List the memories [`Self::prune_decayed`] would delete, without deleting
+    /// them. Memories in `exclude` (e.g. already slated for expiry) are skipped.
+    ///
+    /// Memories decay along their type's curve, and those in namespaces with a
+    /// policy decay at that policy's rate (see [`MemoryService::decay_factor`]).
+    pub async fn find_decayed(&self, exclude: &HashSet<Uuid>) -> DomainResult<Vec<Memory>> {
+        let decay_config = self.memory_service.decay_config();
+        let mut candidates = Vec::new();
+
+        for (tier, threshold) in [
+            (MemoryTier::Working, decay_config.working_prune_threshold),
+            (MemoryTier::Episodic, decay_config.episodic_prune_threshold),
+        ] {
+            let memories = self.repository().list_by_tier(tier).await?;
+            candidates.extend(memories.into_iter().filter(|mem| {
+                self.memory_service.decay_factor(mem) < threshold
+                    && !decay_config.is_floored(mem)
+                    && !exclude.contains(&mem.id)
+            }));
+        }
+
+        Ok(candidates)
+    }
+
     /// Automatically detect and resolve memory conflicts.
     ///
     /// This method scans all memories for conflicts and applies automatic
@@ -156,12 +212,7 @@ impl<R: MemoryRepository> MemoryDecayService<R> {
                 },
             ));
 
-            if matches!(
-                &conflict.resolution,
-                Some(ConflictResolution::PreferNewer { .. })
-                    | Some(ConflictResolution::PreferHigherConfidence { .. })
-                    | Some(ConflictResolution::SoftMerge { .. })
-            ) {
+            if conflict.is_auto_resolvable() {
                 if let Ok(events) = self.memory_service.resolve_conflict(&conflict).await {
                     all_events.extend(events);
                     resolved_count += 1;
@@ -250,6 +301,102 @@ mod tests {
         assert!(events.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_floored_memories_survive_decay_cycles() {
+        use crate::services::memory_service::DecayConfig;
+
+        let memory_service = Arc::new(
+            test_support::setup_memory_service()
+                .await
+                .with_decay_config(DecayConfig {
+                    episodic_decay_floor: 0.1,
+                    ..Default::default()
+                }),
+        );
+        let decay = MemoryDecayService::new(memory_service.clone());
+        let repo = memory_service.repository();
+
+        let plain = Memory::working("plain", "scratch note");
+        let mut pinned = Memory::working("pinned", "baseline fact");
+        pinned.metadata.decay_floor = Some(0.3);
+        let tier_floored = Memory::episodic("tier_floored", "episodic baseline");
+        for mem in [&plain, &pinned, &tier_floored] {
+            repo.store(mem).await.unwrap();
+        }
+
+        // Each cycle ages every surviving memory by a day, then sweeps.
+        for _ in 0..30 {
+            for id in [plain.id, pinned.id, tier_floored.id] {
+                if let Some(mut mem) = repo.get(id).await.unwrap() {
+                    mem.last_accessed -= chrono::Duration::days(1);
+                    repo.update(&mem).await.unwrap();
+                }
+            }
+            decay.prune_decayed().await.unwrap();
+        }
+
+        assert!(repo.get(plain.id).await.unwrap().is_none());
+        let pinned = repo
+            .get(pinned.id)
+            .await
+            .unwrap()
+            .expect("pinned memory pruned");
+        assert!((pinned.decay_factor() - 0.3).abs() < f32::EPSILON);
+        let tier_floored = repo
+            .get(tier_floored.id)
+            .await
+            .unwrap()
+            .expect("tier-floored memory pruned");
+        assert!(tier_floored.decay_factor() < 0.1);
+        assert!((memory_service.decay_factor(&tier_floored) - 0.1).abs() < f32::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn test_decay_curves_by_memory_type_drive_pruning_and_promotion() {
+        let (service, decay) = setup().await;
+        let repo = service.repository();
+
+        // Three days idle: a fact is down to 1/8, a pattern just left its plateau.
+        let mut fact = Memory::episodic("fact", "the build uses cargo");
+        fact.memory_type = MemoryType::Fact;
+        let mut pattern = Memory::episodic("pattern", "flaky tests share a tempdir");
+        pattern.memory_type = MemoryType::Pattern;
+        for mem in [&mut fact, &mut pattern] {
+            mem.last_accessed -= chrono::Duration::hours(72);
+            repo.store(mem).await.unwrap();
+        }
+        assert!((service.decay_factor(&fact) - 0.125).abs() < 1e-6);
+        assert!((service.decay_factor(&pattern) - 1.0).abs() < 1e-6);
+
+        // Another three days: the fact falls below the prune threshold.
+        for id in [fact.id, pattern.id] {
+            let mut mem = repo.get(id).await.unwrap().unwrap();
+            mem.last_accessed -= chrono::Duration::hours(72);
+            repo.update(&mem).await.unwrap();
+        }
+        let decayed: Vec<_> = decay
+            .find_decayed(&HashSet::new())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(decayed, vec![fact.id]);
+
+        // Promotion consults the same scores: a well-used but decayed memory
+        // stays put, the still-relevant one moves up.
+        let config = service.decay_config();
+        for id in [fact.id, pattern.id] {
+            let mut mem = repo.get(id).await.unwrap().unwrap();
+            mem.access_count = config.promote_to_semantic_threshold;
+            for _ in 0..config.promote_to_semantic_distinct_accessors {
+                mem.distinct_accessors
+                    .insert(crate::domain::models::AccessorId::task(Uuid::new_v4()));
+            }
+            assert_eq!(service.is_promotion_candidate(&mem), id == pattern.id);
+        }
+    }
+
     #[tokio::test]
     async fn test_prune_decayed_no_decayed_returns_zero() {
         let (_service, decay) = setup().await;
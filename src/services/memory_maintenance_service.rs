@@ -9,17 +9,51 @@
 //! cadence.
 
 use async_trait::async_trait;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::domain::errors::DomainResult;
-use crate::domain::models::{Memory, MemoryQuery};
+use crate::domain::models::{Memory, MemoryQuery, MemoryTier};
 use crate::domain::ports::MemoryRepository;
 use crate::services::command_bus::{
     CommandError, CommandOutcome, CommandResult, MemoryCommand, MemoryCommandHandler,
 };
 use crate::services::event_bus::UnifiedEvent;
 use crate::services::memory_decay_service::MemoryDecayService;
-use crate::services::memory_service::{MaintenanceReport, MemoryService};
+use crate::services::memory_service::{MaintenanceReport, MemoryConflict, MemoryService};
+
+/// What a maintenance run would do, computed without mutating the store.
+///
+/// Produced by [`MemoryMaintenanceService::maintenance_plan`]. Each set is
+/// computed against the state the real run would see at that step, so
+/// [`MaintenancePlan::report`] matches the counts of a subsequent real run.
+#[derive(Debug, Clone, Default)]
+pub struct MaintenancePlan {
+    /// Memories past their TTL (or their namespace policy's TTL).
+    pub expired: Vec<Memory>,
+    /// Memories below their tier's decay threshold (excluding expired ones).
+    pub decayed: Vec<Memory>,
+    /// Memories that would move up a tier, as they are before promotion.
+    pub promoted: Vec<Memory>,
+    /// Conflicts detected among the surviving working/episodic memories.
+    pub conflicts: Vec<MemoryConflict>,
+}
+
+impl MaintenancePlan {
+    /// Summarize the plan in the same shape as a real run's report.
+    pub fn report(&self) -> MaintenanceReport {
+        MaintenanceReport {
+            expired_pruned: self.expired.len() as u64,
+            decayed_pruned: self.decayed.len() as u64,
+            promoted: self.promoted.len() as u64,
+            conflicts_resolved: self
+                .conflicts
+                .iter()
+                .filter(|c| c.is_auto_resolvable())
+                .count() as u64,
+        }
+    }
+}
 
 /// Orchestrates full memory maintenance by delegating to the underlying
 /// CRUD service (promotions, review lookup) and decay service
@@ -92,6 +126,66 @@ impl<R: MemoryRepository> MemoryMaintenanceService<R> {
         ))
     }
 
+    /// Compute what [`Self::run_maintenance`] would prune, promote, and
+    /// resolve, without mutating anything.
+    ///
+    /// Steps are simulated in the same order as the real run: expired
+    /// memories are excluded from the decay set, pruned memories are not
+    /// promotion candidates, and conflicts are detected over the remaining
+    /// working/episodic memories with promotions applied in memory.
+    pub async fn maintenance_plan(&self) -> DomainResult<MaintenancePlan> {
+        let expired = self.decay_service.find_expired().await?;
+        let mut removed: HashSet<_> = expired.iter().map(|m| m.id).collect();
+
+        let decayed = self.decay_service.find_decayed(&removed).await?;
+        removed.extend(decayed.iter().map(|m| m.id));
+
+        let repo = self.memory_service.repository();
+        let mut working = repo.list_by_tier(MemoryTier::Working).await?;
+        let mut episodic = repo.list_by_tier(MemoryTier::Episodic).await?;
+        working.retain(|m| !removed.contains(&m.id));
+        episodic.retain(|m| !removed.contains(&m.id));
+
+        // Working memories promoted to episodic are re-checked in the
+        // episodic pass, exactly as `check_all_promotions` does.
+        let mut promoted = Vec::new();
+        let mut survivors = Vec::new();
+        for mem in working {
+            match self.simulate_promotion(&mem) {
+                Some(after) => {
+                    promoted.push(mem);
+                    episodic.push(after);
+                }
+                None => survivors.push(mem),
+            }
+        }
+        for mem in episodic {
+            match self.simulate_promotion(&mem) {
+                Some(_) => promoted.push(mem),
+                None => survivors.push(mem),
+            }
+        }
+
+        let conflicts = self.memory_service.detect_conflicts(&survivors);
+
+        Ok(MaintenancePlan {
+            expired,
+            decayed,
+            promoted,
+            conflicts,
+        })
+    }
+
+    /// The promoted copy of `memory`, if it qualifies for promotion.
+    fn simulate_promotion(&self, memory: &Memory) -> Option<Memory> {
+        if !self.memory_service.is_promotion_candidate(memory) {
+            return None;
+        }
+        let mut promoted = memory.clone();
+        promoted.promote().ok()?;
+        Some(promoted)
+    }
+
     /// Get all memories flagged for review due to unresolved conflicts.
     pub async fn get_memories_needing_review(&self) -> DomainResult<Vec<Memory>> {
         let query = MemoryQuery {
@@ -252,6 +346,141 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_dry_run_matches_real_run_without_mutating() {
+        use crate::domain::models::AccessorId;
+
+        let (service, maintenance) = setup().await;
+        let repo = service.repository();
+
+        let mut expired = Memory::working("expired", "short-lived scratch");
+        expired.expires_at = Some(chrono::Utc::now() - chrono::Duration::hours(1));
+        let mut stale = Memory::working("stale", "untouched for a month");
+        stale.last_accessed -= chrono::Duration::days(30);
+        let mut hot = Memory::working("hot", "read by several agents");
+        hot.access_count = 10;
+        hot.record_access(AccessorId::agent("planner"));
+        hot.record_access(AccessorId::agent("reviewer"));
+        for mem in [&expired, &stale, &hot] {
+            repo.store(mem).await.unwrap();
+        }
+        for content in [
+            "the quick brown fox jumps over the lazy dog",
+            "the quick brown fox jumps over the lazy cat today",
+        ] {
+            service
+                .store(
+                    "dry_key".to_string(),
+                    content.to_string(),
+                    "ns".to_string(),
+                    MemoryTier::Working,
+                    MemoryType::Fact,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        async fn snapshot(
+            service: &MemoryService<SqliteMemoryRepository>,
+        ) -> Vec<(uuid::Uuid, MemoryTier, String, Vec<String>)> {
+            let mut all = Vec::new();
+            for tier in [
+                MemoryTier::Working,
+                MemoryTier::Episodic,
+                MemoryTier::Semantic,
+            ] {
+                for m in service.repository().list_by_tier(tier).await.unwrap() {
+                    all.push((m.id, m.tier, m.content, m.metadata.tags));
+                }
+            }
+            all.sort_by_key(|(id, ..)| *id);
+            all
+        }
+
+        let before = snapshot(&service).await;
+        let plan = maintenance.maintenance_plan().await.unwrap();
+        assert_eq!(
+            snapshot(&service).await,
+            before,
+            "dry run mutated the store"
+        );
+
+        let preview = plan.report();
+        assert_eq!(plan.expired[0].id, expired.id);
+        assert_eq!(plan.decayed[0].id, stale.id);
+        assert_eq!(plan.promoted[0].id, hot.id);
+
+        let (report, _events) = maintenance.run_maintenance().await.unwrap();
+        assert_eq!(preview.expired_pruned, report.expired_pruned);
+        assert_eq!(preview.decayed_pruned, report.decayed_pruned);
+        assert_eq!(preview.promoted, report.promoted);
+        assert_eq!(preview.conflicts_resolved, report.conflicts_resolved);
+        assert_eq!(report.expired_pruned, 1);
+        assert_eq!(report.decayed_pruned, 1);
+        assert_eq!(report.promoted, 1);
+        assert!(report.conflicts_resolved >= 1);
+    }
+
+    #[tokio::test]
+    async fn test_plan_lists_exactly_the_memories_run_maintenance_removes() {
+        let (service, maintenance) = setup().await;
+        let repo = service.repository();
+
+        let mut expired_working = Memory::working("expired_w", "scratch");
+        expired_working.expires_at = Some(chrono::Utc::now() - chrono::Duration::minutes(5));
+        let mut expired_episodic = Memory::episodic("expired_e", "old episode");
+        expired_episodic.expires_at = Some(chrono::Utc::now() - chrono::Duration::days(1));
+        let mut stale_working = Memory::working("stale_w", "never read again");
+        stale_working.last_accessed -= chrono::Duration::days(30);
+        let mut stale_semantic = Memory::semantic("stale_s", "long-term fact");
+        stale_semantic.last_accessed -= chrono::Duration::days(365);
+        let fresh = Memory::working("fresh", "just written");
+        for mem in [
+            &expired_working,
+            &expired_episodic,
+            &stale_working,
+            &stale_semantic,
+            &fresh,
+        ] {
+            repo.store(mem).await.unwrap();
+        }
+
+        async fn all_ids(service: &MemoryService<SqliteMemoryRepository>) -> HashSet<uuid::Uuid> {
+            let mut ids = HashSet::new();
+            for tier in [
+                MemoryTier::Working,
+                MemoryTier::Episodic,
+                MemoryTier::Semantic,
+            ] {
+                let memories = service.repository().list_by_tier(tier).await.unwrap();
+                ids.extend(memories.into_iter().map(|m| m.id));
+            }
+            ids
+        }
+
+        let before = all_ids(&service).await;
+        let plan = maintenance.maintenance_plan().await.unwrap();
+        let planned: HashSet<_> = plan
+            .expired
+            .iter()
+            .chain(&plan.decayed)
+            .map(|m| m.id)
+            .collect();
+
+        maintenance.run_maintenance().await.unwrap();
+        let removed: HashSet<_> = before
+            .difference(&all_ids(&service).await)
+            .copied()
+            .collect();
+
+        assert_eq!(planned, removed);
+        assert_eq!(
+            planned,
+            HashSet::from([expired_working.id, expired_episodic.id, stale_working.id])
+        );
+    }
+
     #[tokio::test]
     async fn test_get_memories_needing_review_filters_by_tag() {
         let (service, maintenance) = setup().await;
@@ -296,4 +525,93 @@ mod tests {
             );
         }
     }
+
+    async fn setup_with_policies() -> (
+        Arc<MemoryService<SqliteMemoryRepository>>,
+        MemoryMaintenanceService<SqliteMemoryRepository>,
+    ) {
+        use crate::services::memory_service::NamespacePolicy;
+
+        let policies = std::collections::HashMap::from([
+            (
+                "project-context".to_string(),
+                NamespacePolicy {
+                    ttl_secs: None,
+                    decay_rate: 0.0,
+                },
+            ),
+            (
+                "task-learnings".to_string(),
+                NamespacePolicy {
+                    ttl_secs: Some(24 * 3600),
+                    decay_rate: 1.0,
+                },
+            ),
+        ]);
+        let memory_service = Arc::new(
+            test_support::setup_memory_service()
+                .await
+                .with_namespace_policies(policies),
+        );
+        let maintenance = MemoryMaintenanceService::from_memory_service(memory_service.clone());
+        (memory_service, maintenance)
+    }
+
+    fn aged_episodic(key: &str, namespace: &str, age: chrono::Duration) -> Memory {
+        let mut mem = Memory::episodic(key, "same age, different namespace");
+        mem.namespace = namespace.to_string();
+        mem.created_at -= age;
+        mem.last_accessed -= age;
+        mem.expires_at = mem.expires_at.map(|exp| exp - age);
+        mem
+    }
+
+    #[tokio::test]
+    async fn test_namespace_ttl_prunes_equal_age_memories_differently() {
+        let (service, maintenance) = setup_with_policies().await;
+        let repo = service.repository();
+
+        // Both are past the episodic tier's default 7-day TTL.
+        let age = chrono::Duration::days(10);
+        let context = aged_episodic("ctx", "project-context", age);
+        let learning = aged_episodic("lesson", "task-learnings", age);
+        let unlisted = aged_episodic("other", "scratch", age);
+        assert!(context.is_expired() && learning.is_expired() && unlisted.is_expired());
+        // A day-old learning is still within its namespace TTL.
+        let recent = aged_episodic("recent", "task-learnings", chrono::Duration::hours(12));
+        for mem in [&context, &learning, &unlisted, &recent] {
+            repo.store(mem).await.unwrap();
+        }
+
+        let plan = maintenance.maintenance_plan().await.unwrap();
+        let planned: HashSet<_> = plan.expired.iter().map(|m| m.id).collect();
+        assert_eq!(planned, HashSet::from([learning.id, unlisted.id]));
+
+        let (report, _events) = maintenance.run_maintenance().await.unwrap();
+        assert_eq!(report.expired_pruned, 2);
+        assert!(repo.get(context.id).await.unwrap().is_some());
+        assert!(repo.get(recent.id).await.unwrap().is_some());
+        assert!(repo.get(learning.id).await.unwrap().is_none());
+        assert!(repo.get(unlisted.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_namespace_decay_rate_overrides_tier_decay() {
+        let (service, maintenance) = setup_with_policies().await;
+        let repo = service.repository();
+
+        let mut kept = Memory::working("kept", "project convention");
+        kept.namespace = "project-context".to_string();
+        kept.last_accessed -= chrono::Duration::hours(3);
+        let mut decayed = Memory::working("decayed", "scratch note");
+        decayed.last_accessed -= chrono::Duration::hours(3);
+        for mem in [&kept, &decayed] {
+            repo.store(mem).await.unwrap();
+        }
+
+        assert_eq!(service.decay_factor(&kept), 1.0);
+        let plan = maintenance.maintenance_plan().await.unwrap();
+        let planned: Vec<_> = plan.decayed.iter().map(|m| m.id).collect();
+        assert_eq!(planned, vec![decayed.id]);
+    }
 }
@@ -4,16 +4,22 @@
 //! - [`crate::services::memory_decay_service::MemoryDecayService`]
 //! - [`crate::services::memory_maintenance_service::MemoryMaintenanceService`]
 
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::models::{
-    AccessorId, Memory, MemoryMetadata, MemoryQuery, MemoryTier, MemoryType, RelevanceWeights,
+    AccessorId, DecayFunction, HybridSearchResult, Memory, MemoryMetadata, MemoryQuery,
+    MemoryRetrievalStrategy, MemoryTier, MemoryType, NamespaceSummary, RelevanceWeights,
     ScoredMemory,
 };
-use crate::domain::ports::MemoryRepository;
-use crate::services::event_bus::{EventCategory, EventPayload, EventSeverity, UnifiedEvent};
+use crate::domain::ports::{EmbeddingInput, MemoryRepository};
+use crate::services::embedding_service::EmbeddingService;
+use crate::services::event_bus::{
+    EventBus, EventCategory, EventPayload, EventSeverity, UnifiedEvent,
+};
 use crate::services::event_factory;
 
 /// Configuration for memory decay thresholds.
@@ -38,6 +44,17 @@ pub struct DecayConfig {
     /// access from multiple distinct sources ensures promotion reflects genuine
     /// repeated utility, not a single loop.
     pub promote_to_semantic_distinct_accessors: usize,
+    /// Minimum decay factor for working memories (0.0 = no floor).
+    pub working_decay_floor: f32,
+    /// Minimum decay factor for episodic memories (0.0 = no floor).
+    pub episodic_decay_floor: f32,
+    /// Minimum decay factor for semantic memories (0.0 = no floor).
+    pub semantic_decay_floor: f32,
+    /// Decay curve per memory type; unlisted types decay exponentially.
+    pub decay_functions: HashMap<MemoryType, DecayFunction>,
+    /// Minimum decay factor a memory needs to be promoted, so one about to
+    /// be pruned for decay is not promoted instead.
+    pub promote_min_decay: f32,
 }
 
 impl Default for DecayConfig {
@@ -49,14 +66,168 @@ impl Default for DecayConfig {
             promote_to_semantic_threshold: 20,
             promote_to_episodic_distinct_accessors: 2,
             promote_to_semantic_distinct_accessors: 3,
+            working_decay_floor: 0.0,
+            episodic_decay_floor: 0.0,
+            semantic_decay_floor: 0.0,
+            // Patterns stay relevant for a few days, then drop off.
+            decay_functions: HashMap::from([(
+                MemoryType::Pattern,
+                DecayFunction::StepThenDecay {
+                    plateau_hours: 72.0,
+                },
+            )]),
+            promote_min_decay: 0.1,
+        }
+    }
+}
+
+impl DecayConfig {
+    /// Decay floor for a tier.
+    pub fn tier_floor(&self, tier: MemoryTier) -> f32 {
+        match tier {
+            MemoryTier::Working => self.working_decay_floor,
+            MemoryTier::Episodic => self.episodic_decay_floor,
+            MemoryTier::Semantic => self.semantic_decay_floor,
+        }
+    }
+
+    /// Effective decay floor for a memory: its own override, else the tier floor.
+    pub fn decay_floor(&self, memory: &Memory) -> f32 {
+        memory
+            .metadata
+            .decay_floor
+            .unwrap_or_else(|| self.tier_floor(memory.tier))
+    }
+
+    /// Decay curve for memories of `memory_type`.
+    pub fn decay_function(&self, memory_type: &MemoryType) -> DecayFunction {
+        self.decay_functions
+            .get(memory_type)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Whether decay can never push `memory` below a non-zero floor.
+    ///
+    /// Floored memories are exempt from decay pruning; they can still be
+    /// removed when their TTL expires.
+    pub fn is_floored(&self, memory: &Memory) -> bool {
+        self.decay_floor(memory) > 0.0
+    }
+}
+
+/// Retention overrides for one memory namespace.
+///
+/// Listing a namespace replaces the tier defaults for its memories: they
+/// expire `ttl_secs` after creation (never, when unset) and decay
+/// `decay_rate` times as fast as their tier normally would.
+///
+/// ```toml
+/// [memory.namespaces.project-context]
+/// decay_rate = 0.1
+///
+/// [memory.namespaces.task-learnings]
+/// ttl_secs = 86400
+/// decay_rate = 2.0
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NamespacePolicy {
+    pub ttl_secs: Option<u64>,
+    pub decay_rate: f32,
+}
+
+impl Default for NamespacePolicy {
+    fn default() -> Self {
+        Self {
+            ttl_secs: None,
+            decay_rate: 1.0,
+        }
+    }
+}
+
+impl NamespacePolicy {
+    /// Whether `memory` has outlived this policy's TTL.
+    pub fn is_expired(&self, memory: &Memory) -> bool {
+        self.ttl_secs
+            .and_then(|secs| chrono::Duration::try_seconds(i64::try_from(secs).ok()?))
+            .is_some_and(|ttl| memory.created_at + ttl < chrono::Utc::now())
+    }
+}
+
+/// Default cap on a single memory's content, in bytes. Larger content is
+/// stored as a parent memory plus linked chunks.
+pub const DEFAULT_MAX_CONTENT_SIZE: usize = 16 * 1024;
+
+/// `metadata.custom` key on a chunk holding its parent memory's ID.
+pub const CHUNK_PARENT_KEY: &str = "chunk_of";
+/// `metadata.custom` key on a chunk holding its 1-based position.
+pub const CHUNK_INDEX_KEY: &str = "chunk_index";
+/// `metadata.custom` key on a chunked parent holding the number of chunks.
+pub const CHUNK_COUNT_KEY: &str = "chunk_count";
+
+/// Most recently accessed memories in a namespace compared against new
+/// content when dedup-on-store is enabled.
+const DEDUP_CANDIDATE_LIMIT: usize = 200;
+
+/// Split `content` into pieces of at most `max_bytes` bytes.
+///
+/// Pieces break at the last paragraph break, line break or whitespace in
+/// each window where possible, falling back to a hard split on a character
+/// boundary. Concatenating the pieces yields the original content.
+pub fn chunk_content(content: &str, max_bytes: usize) -> Vec<String> {
+    let max_bytes = max_bytes.max(1);
+    let mut chunks = Vec::new();
+    let mut rest = content;
+
+    while rest.len() > max_bytes {
+        let mut end = max_bytes;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
         }
+        if end == 0 {
+            // A single character wider than the cap: emit it whole.
+            end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+        }
+
+        let window = &rest[..end];
+        // A window that already ends at a word boundary needs no search.
+        let at_boundary = rest[end..].starts_with(char::is_whitespace);
+        let split = Some(end)
+            .filter(|_| at_boundary)
+            .or_else(|| window.rfind("\n\n").map(|i| i + 2))
+            .or_else(|| window.rfind('\n').map(|i| i + 1))
+            .or_else(|| {
+                window
+                    .rfind(char::is_whitespace)
+                    .map(|i| i + window[i..].chars().next().map_or(1, char::len_utf8))
+            })
+            .unwrap_or(end);
+
+        chunks.push(rest[..split].to_string());
+        rest = &rest[split..];
+    }
+
+    if !rest.is_empty() {
+        chunks.push(rest.to_string());
     }
+    chunks
 }
 
 #[derive(Clone)]
 pub struct MemoryService<R: MemoryRepository> {
     repository: Arc<R>,
     decay_config: DecayConfig,
+    /// Content larger than this many bytes is split into chunks on store.
+    max_content_size: usize,
+    /// When set, `store` reinforces an existing memory in the same namespace
+    /// whose content is at least this similar instead of inserting a new one.
+    dedup_threshold: Option<f64>,
+    /// Embedding model used by hybrid search; keyword-only when absent.
+    embeddings: Option<Arc<EmbeddingService>>,
+    /// Retention overrides keyed by namespace; unlisted namespaces follow
+    /// the tier defaults.
+    namespace_policies: HashMap<String, NamespacePolicy>,
 }
 
 impl<R: MemoryRepository> MemoryService<R> {
@@ -64,6 +235,10 @@ impl<R: MemoryRepository> MemoryService<R> {
         Self {
             repository,
             decay_config: DecayConfig::default(),
+            max_content_size: DEFAULT_MAX_CONTENT_SIZE,
+            dedup_threshold: None,
+            embeddings: None,
+            namespace_policies: HashMap::new(),
         }
     }
 
@@ -72,6 +247,45 @@ impl<R: MemoryRepository> MemoryService<R> {
         self
     }
 
+    pub fn with_max_content_size(mut self, max_content_size: usize) -> Self {
+        self.max_content_size = max_content_size;
+        self
+    }
+
+    /// Enable dedup-on-store at the given content similarity (0.0-1.0);
+    /// `None` disables it.
+    pub fn with_dedup_threshold(mut self, threshold: Option<f64>) -> Self {
+        self.dedup_threshold = threshold;
+        self
+    }
+
+    /// Use `embeddings` for the vector half of [`Self::search_hybrid`].
+    pub fn with_embeddings(mut self, embeddings: Arc<EmbeddingService>) -> Self {
+        self.embeddings = Some(embeddings);
+        self
+    }
+
+    /// Apply the `[memory]` settings that shape stores and retention: the
+    /// content size cap, dedup-on-store, the per-namespace policies, the
+    /// tier decay floors and the per-type decay curves.
+    pub fn with_memory_config(mut self, config: &crate::services::config::MemoryConfig) -> Self {
+        self.decay_config.working_decay_floor = config.working_decay_floor;
+        self.decay_config.episodic_decay_floor = config.episodic_decay_floor;
+        self.decay_config.semantic_decay_floor = config.semantic_decay_floor;
+        self.decay_config
+            .decay_functions
+            .extend(config.decay_functions.clone());
+        self.with_max_content_size(config.max_content_size)
+            .with_dedup_threshold(config.dedup_similarity_threshold)
+            .with_namespace_policies(config.namespaces.clone())
+    }
+
+    /// Override expiry and decay for the listed namespaces.
+    pub fn with_namespace_policies(mut self, policies: HashMap<String, NamespacePolicy>) -> Self {
+        self.namespace_policies = policies;
+        self
+    }
+
     /// Access the underlying repository.
     ///
     /// Exposed so sibling services (decay, maintenance) and command-bus
@@ -88,6 +302,32 @@ impl<R: MemoryRepository> MemoryService<R> {
         &self.decay_config
     }
 
+    /// Per-namespace retention overrides.
+    pub fn namespace_policies(&self) -> &HashMap<String, NamespacePolicy> {
+        &self.namespace_policies
+    }
+
+    /// Whether `memory` is past its TTL, under its namespace's policy if
+    /// there is one.
+    pub fn is_expired(&self, memory: &Memory) -> bool {
+        match self.namespace_policies.get(&memory.namespace) {
+            Some(policy) => policy.is_expired(memory),
+            None => memory.is_expired(),
+        }
+    }
+
+    /// Decay factor of `memory` along its type's decay curve, at its
+    /// namespace's decay rate, never below its tier's decay floor.
+    pub fn decay_factor(&self, memory: &Memory) -> f32 {
+        let rate = self
+            .namespace_policies
+            .get(&memory.namespace)
+            .map_or(1.0, |policy| policy.decay_rate);
+        memory
+            .decay_factor_with(self.decay_config.decay_function(&memory.memory_type), rate)
+            .max(self.decay_config.decay_floor(memory).clamp(0.0, 1.0))
+    }
+
     /// Helper to build a UnifiedEvent with standard fields.
     fn make_event(
         severity: EventSeverity,
@@ -98,6 +338,16 @@ impl<R: MemoryRepository> MemoryService<R> {
     }
 
     /// Store a new memory. Returns the memory and events to be journaled.
+    ///
+    /// Content over the configured maximum size is split with
+    /// [`chunk_content`]: each piece is stored as its own memory under
+    /// `<key>#chunk-<n>` so it is indexed (and searchable) separately, and
+    /// the returned parent memory under `key` lists the chunks. Chunks left
+    /// over from an earlier, longer store under the same key are deleted.
+    ///
+    /// With a dedup threshold set, content that is near-identical to an
+    /// existing memory in the namespace reinforces that memory (recording an
+    /// access and keeping the higher relevance) and returns it instead.
     pub async fn store(
         &self,
         key: String,
@@ -119,6 +369,92 @@ impl<R: MemoryRepository> MemoryService<R> {
             memory.metadata = meta;
         }
 
+        if memory.content.len() <= self.max_content_size {
+            if let Some(threshold) = self.dedup_threshold
+                && let Some(existing) = self.find_near_duplicate(&memory, threshold).await?
+            {
+                return self.reinforce_duplicate(existing, &memory).await;
+            }
+            self.delete_surplus_chunks(&memory.key, &memory.namespace, 0)
+                .await?;
+            let event = self.store_single(&mut memory).await?;
+            return Ok((memory, vec![event]));
+        }
+
+        let chunks = chunk_content(&memory.content, self.max_content_size);
+        let chunk_keys: Vec<String> = (1..=chunks.len())
+            .map(|i| format!("{}#chunk-{}", memory.key, i))
+            .collect();
+        let mut events = Vec::with_capacity(chunks.len() + 1);
+        self.delete_surplus_chunks(&memory.key, &memory.namespace, chunk_keys.len())
+            .await?;
+
+        for (i, (chunk, chunk_key)) in chunks.into_iter().zip(&chunk_keys).enumerate() {
+            let mut piece = memory.clone();
+            piece.id = Uuid::new_v4();
+            piece.key = chunk_key.clone();
+            piece.content = chunk;
+            piece.metadata.custom.insert(
+                CHUNK_PARENT_KEY.to_string(),
+                serde_json::Value::String(memory.id.to_string()),
+            );
+            piece
+                .metadata
+                .custom
+                .insert(CHUNK_INDEX_KEY.to_string(), serde_json::json!(i + 1));
+            events.push(self.store_single(&mut piece).await?);
+        }
+
+        tracing::debug!(
+            key = %memory.key,
+            size = memory.content.len(),
+            chunks = chunk_keys.len(),
+            "memory content over size cap; stored as chunks"
+        );
+        memory.content = format!(
+            "Content ({} bytes) split into {} chunks: {}",
+            memory.content.len(),
+            chunk_keys.len(),
+            chunk_keys.join(", ")
+        );
+        memory.metadata.custom.insert(
+            CHUNK_COUNT_KEY.to_string(),
+            serde_json::json!(chunk_keys.len()),
+        );
+        events.insert(0, self.store_single(&mut memory).await?);
+
+        Ok((memory, events))
+    }
+
+    /// Delete every version of the `<key>#chunk-<n>` memories past `keep`
+    /// that the current memory under `key` was split into.
+    async fn delete_surplus_chunks(
+        &self,
+        key: &str,
+        namespace: &str,
+        keep: usize,
+    ) -> DomainResult<()> {
+        let Some(previous) = self.repository.get_by_key(key, namespace).await? else {
+            return Ok(());
+        };
+        let previous_count = previous
+            .metadata
+            .custom
+            .get(CHUNK_COUNT_KEY)
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0) as usize;
+        for i in keep + 1..=previous_count {
+            let chunk_key = format!("{}#chunk-{}", key, i);
+            while let Some(stale) = self.repository.get_by_key(&chunk_key, namespace).await? {
+                self.repository.delete(stale.id).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Persist one memory, bumping its version past any existing memory with
+    /// the same (namespace, key). Returns the `MemoryStored` event.
+    async fn store_single(&self, memory: &mut Memory) -> DomainResult<UnifiedEvent> {
         // Auto-increment version when a memory with the same (namespace, key) already exists
         // to avoid UNIQUE constraint violations and support conflict detection.
         if let Ok(Some(existing)) = self
@@ -130,9 +466,9 @@ impl<R: MemoryRepository> MemoryService<R> {
         }
 
         memory.validate().map_err(DomainError::ValidationFailed)?;
-        self.repository.store(&memory).await?;
+        self.repository.store(memory).await?;
 
-        let events = vec![Self::make_event(
+        Ok(Self::make_event(
             EventSeverity::Debug,
             EventCategory::Memory,
             EventPayload::MemoryStored {
@@ -142,9 +478,81 @@ impl<R: MemoryRepository> MemoryService<R> {
                 tier: memory.tier.as_str().to_string(),
                 memory_type: memory.memory_type.as_str().to_string(),
             },
+        ))
+    }
+
+    /// The most similar of the [`DEDUP_CANDIDATE_LIMIT`] most recently
+    /// accessed memories in `memory`'s namespace whose content similarity
+    /// reaches `threshold`. Chunked memories are never matched.
+    async fn find_near_duplicate(
+        &self,
+        memory: &Memory,
+        threshold: f64,
+    ) -> DomainResult<Option<Memory>> {
+        let candidates = self
+            .repository
+            .query(MemoryQuery {
+                namespace: Some(memory.namespace.clone()),
+                limit: Some(DEDUP_CANDIDATE_LIMIT),
+                ..Default::default()
+            })
+            .await?;
+        Ok(candidates
+            .into_iter()
+            .filter(|m| {
+                !m.metadata.custom.contains_key(CHUNK_PARENT_KEY)
+                    && !m.metadata.custom.contains_key(CHUNK_COUNT_KEY)
+            })
+            .map(|m| {
+                let similarity = self.compute_content_similarity(&m.content, &memory.content);
+                (similarity, m)
+            })
+            .filter(|(similarity, _)| *similarity >= threshold)
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, m)| m))
+    }
+
+    /// Fold a near-duplicate store into `existing`: count it as an access
+    /// and keep the higher relevance of the two.
+    async fn reinforce_duplicate(
+        &self,
+        mut existing: Memory,
+        duplicate: &Memory,
+    ) -> DomainResult<(Memory, Vec<UnifiedEvent>)> {
+        let accessor = duplicate
+            .metadata
+            .task_id
+            .map(AccessorId::task)
+            .unwrap_or_else(|| AccessorId::system("memory-dedup"));
+        existing.metadata.relevance = existing
+            .metadata
+            .relevance
+            .max(duplicate.metadata.relevance);
+        existing.record_access(accessor.clone());
+        self.repository.update(&existing).await?;
+
+        tracing::debug!(
+            key = %duplicate.key,
+            existing_key = %existing.key,
+            namespace = %existing.namespace,
+            "near-duplicate memory reinforced instead of stored"
+        );
+
+        let mut events = vec![Self::make_event(
+            EventSeverity::Debug,
+            EventCategory::Memory,
+            EventPayload::MemoryAccessed {
+                memory_id: existing.id,
+                key: existing.key.clone(),
+                access_count: existing.access_count,
+                accessor: accessor.to_string(),
+                distinct_accessor_count: existing.distinct_accessor_count() as u32,
+            },
         )];
+        let (_, promotion_events) = self.check_promotion(&mut existing).await?;
+        events.extend(promotion_events);
 
-        Ok((memory, events))
+        Ok((existing, events))
     }
 
     /// Store a working memory (convenience method). Returns the memory and events.
@@ -273,6 +681,145 @@ impl<R: MemoryRepository> MemoryService<R> {
         self.repository.search(query, namespace, limit).await
     }
 
+    /// Hybrid search: rank memories by a blend of keyword and embedding similarity.
+    ///
+    /// `alpha` interpolates from keyword-only (0.0) to vector-only (1.0):
+    ///   score = (1 - alpha) * lexical + alpha * cosine
+    ///
+    /// When vectors are in play the candidate pool is widened beyond full-text
+    /// matches so paraphrases can surface. Without a usable embedding model
+    /// (none configured, zero dimension, or a failed embedding call) results
+    /// are ranked by their keyword score alone.
+    pub async fn search_hybrid(
+        &self,
+        query: &str,
+        namespace: Option<&str>,
+        alpha: f64,
+        limit: usize,
+    ) -> DomainResult<Vec<HybridSearchResult>> {
+        let alpha = alpha.clamp(0.0, 1.0) as f32;
+        let fetch_limit = (limit * 3).max(50);
+        let mut candidates = self
+            .repository
+            .search(query, namespace, fetch_limit)
+            .await?;
+
+        let vectors_enabled = alpha > 0.0
+            && self
+                .embeddings
+                .as_ref()
+                .is_some_and(|embeddings| embeddings.dimension() > 0);
+        let mut query_vector = None;
+        if vectors_enabled {
+            let extra = self
+                .repository
+                .query(MemoryQuery {
+                    namespace: namespace.map(str::to_string),
+                    limit: Some(fetch_limit),
+                    ..Default::default()
+                })
+                .await?;
+            let seen: HashSet<Uuid> = candidates.iter().map(|m| m.id).collect();
+            candidates.extend(extra.into_iter().filter(|m| !seen.contains(&m.id)));
+
+            match self.embed_candidates(query, &mut candidates).await {
+                Ok(vector) => query_vector = Some(vector),
+                Err(e) => tracing::warn!(
+                    error = %e,
+                    "embedding failed; falling back to keyword-only hybrid search"
+                ),
+            }
+        }
+
+        let mut results: Vec<HybridSearchResult> = candidates
+            .into_iter()
+            .map(|memory| {
+                let lexical_score = memory.lexical_score(query);
+                let vector_score = query_vector
+                    .as_deref()
+                    .map(|v| memory.cosine_similarity(v).unwrap_or(0.0).clamp(0.0, 1.0));
+                let score = match vector_score {
+                    Some(v) => (1.0 - alpha) * lexical_score + alpha * v,
+                    None => lexical_score,
+                };
+                HybridSearchResult {
+                    memory,
+                    score,
+                    lexical_score,
+                    vector_score,
+                }
+            })
+            .filter(|r| r.score > 0.0)
+            .collect();
+
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        results.truncate(limit);
+
+        Ok(results)
+    }
+
+    /// Embed `query` and fill in embeddings for candidates that lack a
+    /// stored one from the same model. Vectors stamped with another model are
+    /// discarded rather than compared, even when the dimensions happen to match.
+    async fn embed_candidates(
+        &self,
+        query: &str,
+        candidates: &mut [Memory],
+    ) -> DomainResult<Vec<f32>> {
+        let Some(embeddings) = &self.embeddings else {
+            return Err(DomainError::ValidationFailed(
+                "no embedding model configured".to_string(),
+            ));
+        };
+        let query_vector = embeddings.embed_single(query).await?;
+        if query_vector.len() != embeddings.dimension() {
+            return Err(DomainError::ValidationFailed(format!(
+                "embedding model {} returned {} dimensions, expected {}",
+                embeddings.model_id(),
+                query_vector.len(),
+                embeddings.dimension()
+            )));
+        }
+
+        let model = embeddings.model_id();
+        for memory in candidates.iter_mut() {
+            let usable = memory.embedding_model.as_deref() == Some(model.as_str())
+                && memory
+                    .embedding
+                    .as_ref()
+                    .is_some_and(|v| v.len() == query_vector.len());
+            if !usable {
+                memory.embedding = None;
+            }
+        }
+
+        let inputs: Vec<EmbeddingInput> = candidates
+            .iter()
+            .filter(|m| m.embedding.is_none())
+            .map(|m| EmbeddingInput {
+                id: m.id.to_string(),
+                text: m.content.clone(),
+            })
+            .collect();
+        let mut vectors: HashMap<String, Vec<f32>> = embeddings
+            .embed_many(&inputs)
+            .await?
+            .into_iter()
+            .map(|out| (out.id, out.vector))
+            .collect();
+        for memory in candidates.iter_mut() {
+            if memory.embedding.is_none() {
+                memory.embedding = vectors.remove(&memory.id.to_string());
+            }
+        }
+
+        Ok(query_vector)
+    }
+
     /// Ranked search: search memories and return results scored by multi-factor relevance.
     ///
     /// Implements the research-recommended approach from DynTaskMAS (ICAPS 2025):
@@ -336,6 +883,83 @@ impl<R: MemoryRepository> MemoryService<R> {
             .await?;
 
         // Greedily fill the token budget with highest-scored memories
+        Ok(Self::fill_token_budget(scored, token_budget))
+    }
+
+    /// Load prompt context for a task using a configured retrieval strategy.
+    ///
+    /// Similarity and blend strategies rank full-text matches for `query`;
+    /// namespace and recency strategies rank the namespace's memories or the
+    /// most recently accessed ones, so they can surface memories that share
+    /// no words with the query.
+    pub async fn load_context_with_strategy(
+        &self,
+        query: &str,
+        strategy: &MemoryRetrievalStrategy,
+        token_budget: usize,
+    ) -> DomainResult<Vec<ScoredMemory>> {
+        let weights = strategy.weights();
+        let candidates = match strategy {
+            MemoryRetrievalStrategy::Similarity | MemoryRetrievalStrategy::Blend { .. } => {
+                return self
+                    .load_context_with_budget(query, None, token_budget, weights)
+                    .await;
+            }
+            MemoryRetrievalStrategy::Namespace { namespace } => {
+                self.repository
+                    .query(MemoryQuery::new().namespace(namespace).limit(100))
+                    .await?
+            }
+            MemoryRetrievalStrategy::Recency => {
+                self.repository.query(MemoryQuery::new().limit(100)).await?
+            }
+        };
+
+        let mut scored: Vec<ScoredMemory> = candidates
+            .into_iter()
+            .map(|mem| mem.relevance_score(query, &weights))
+            .collect();
+        scored.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        Ok(Self::fill_token_budget(scored, token_budget))
+    }
+
+    /// Reinforce memories that were retrieved into an agent's context.
+    ///
+    /// Records an access by `accessor` on at most `limit` of `memories`, in
+    /// the order given (pass them best-first). Memories `accessor` has already
+    /// accessed are skipped, so a retried task does not keep inflating their
+    /// counts. Each reinforced memory is re-read before the write so
+    /// concurrent edits are not overwritten. Returns how many were reinforced.
+    pub async fn reinforce_retrieved<'a>(
+        &self,
+        memories: impl IntoIterator<Item = &'a Memory>,
+        accessor: AccessorId,
+        limit: usize,
+    ) -> DomainResult<usize> {
+        let mut reinforced = 0;
+        for retrieved in memories {
+            if reinforced >= limit {
+                break;
+            }
+            let Some(mut mem) = self.repository.get(retrieved.id).await? else {
+                continue;
+            };
+            if mem.distinct_accessors.contains(&accessor) {
+                continue;
+            }
+            mem.record_access(accessor.clone());
+            self.repository.update(&mem).await?;
+            reinforced += 1;
+        }
+        Ok(reinforced)
+    }
+
+    /// Greedily keep the highest-scored memories that fit in `token_budget`.
+    fn fill_token_budget(scored: Vec<ScoredMemory>, token_budget: usize) -> Vec<ScoredMemory> {
         let mut selected = Vec::new();
         let mut tokens_used = 0;
 
@@ -348,7 +972,7 @@ impl<R: MemoryRepository> MemoryService<R> {
             // Don't break early - later entries might be smaller and still fit
         }
 
-        Ok(selected)
+        selected
     }
 
     /// Get memories for a specific task.
@@ -377,6 +1001,10 @@ impl<R: MemoryRepository> MemoryService<R> {
             .ok_or(DomainError::MemoryNotFound(id))?;
 
         if let Some(c) = content {
+            if c != memory.content {
+                memory.embedding = None;
+                memory.embedding_model = None;
+            }
             memory.content = c;
         }
         if let Some(ns) = namespace {
@@ -426,6 +1054,28 @@ impl<R: MemoryRepository> MemoryService<R> {
         Ok(events)
     }
 
+    /// Whether a memory meets the access and distinct-accessor thresholds for
+    /// promotion out of its current tier, and has not decayed below
+    /// [`DecayConfig::promote_min_decay`]. Semantic memories never qualify.
+    pub fn is_promotion_candidate(&self, memory: &Memory) -> bool {
+        if self.decay_factor(memory) < self.decay_config.promote_min_decay {
+            return false;
+        }
+        match memory.tier {
+            MemoryTier::Working => {
+                memory.access_count >= self.decay_config.promote_to_episodic_threshold
+                    && memory.distinct_accessor_count()
+                        >= self.decay_config.promote_to_episodic_distinct_accessors
+            }
+            MemoryTier::Episodic => {
+                memory.access_count >= self.decay_config.promote_to_semantic_threshold
+                    && memory.distinct_accessor_count()
+                        >= self.decay_config.promote_to_semantic_distinct_accessors
+            }
+            MemoryTier::Semantic => false,
+        }
+    }
+
     /// Check if a memory should be promoted based on access patterns and distinct accessor count.
     ///
     /// Promotion requires BOTH:
@@ -439,21 +1089,7 @@ impl<R: MemoryRepository> MemoryService<R> {
         &self,
         memory: &mut Memory,
     ) -> DomainResult<(bool, Vec<UnifiedEvent>)> {
-        let should_promote = match memory.tier {
-            MemoryTier::Working => {
-                memory.access_count >= self.decay_config.promote_to_episodic_threshold
-                    && memory.distinct_accessor_count()
-                        >= self.decay_config.promote_to_episodic_distinct_accessors
-            }
-            MemoryTier::Episodic => {
-                memory.access_count >= self.decay_config.promote_to_semantic_threshold
-                    && memory.distinct_accessor_count()
-                        >= self.decay_config.promote_to_semantic_distinct_accessors
-            }
-            MemoryTier::Semantic => false,
-        };
-
-        if should_promote {
+        if self.is_promotion_candidate(memory) {
             let from_tier = memory.tier.as_str().to_string();
             memory.promote().map_err(DomainError::ValidationFailed)?;
             let to_tier = memory.tier.as_str().to_string();
@@ -488,10 +1124,7 @@ impl<R: MemoryRepository> MemoryService<R> {
         // Check working memories
         let working = self.repository.list_by_tier(MemoryTier::Working).await?;
         for mut mem in working {
-            if mem.access_count >= self.decay_config.promote_to_episodic_threshold
-                && mem.distinct_accessor_count()
-                    >= self.decay_config.promote_to_episodic_distinct_accessors
-            {
+            if self.is_promotion_candidate(&mem) {
                 let (did_promote, events) = self.check_promotion(&mut mem).await?;
                 if did_promote {
                     promoted += 1;
@@ -503,10 +1136,7 @@ impl<R: MemoryRepository> MemoryService<R> {
         // Check episodic memories
         let episodic = self.repository.list_by_tier(MemoryTier::Episodic).await?;
         for mut mem in episodic {
-            if mem.access_count >= self.decay_config.promote_to_semantic_threshold
-                && mem.distinct_accessor_count()
-                    >= self.decay_config.promote_to_semantic_distinct_accessors
-            {
+            if self.is_promotion_candidate(&mem) {
                 let (did_promote, events) = self.check_promotion(&mut mem).await?;
                 if did_promote {
                     promoted += 1;
@@ -528,6 +1158,123 @@ impl<R: MemoryRepository> MemoryService<R> {
             semantic_count: *counts.get(&MemoryTier::Semantic).unwrap_or(&0),
         })
     }
+
+    /// Summarize every namespace: entry count, size, tiers, last update.
+    pub async fn list_namespaces(&self) -> DomainResult<Vec<NamespaceSummary>> {
+        self.repository.list_namespaces().await
+    }
+
+    /// Re-embed every memory whose stored vector was not produced by
+    /// `embeddings`' model, `batch_size` memories at a time.
+    ///
+    /// Each batch is written atomically and stamped with the new model, so
+    /// the run is resumable: after a crash, calling this again picks up the
+    /// memories still on the old model. Hybrid search ignores those in the
+    /// meantime. A `MemoryReembedProgress` event is published to
+    /// `event_bus` as soon as each batch is stored.
+    pub async fn reembed_all(
+        &self,
+        embeddings: &EmbeddingService,
+        batch_size: usize,
+        event_bus: &EventBus,
+    ) -> DomainResult<ReembedReport> {
+        let model = embeddings.model_id();
+        let dimension = embeddings.dimension();
+        if dimension == 0 {
+            return Err(DomainError::ValidationFailed(format!(
+                "embedding model {} produces no vectors",
+                model
+            )));
+        }
+
+        let total = self.repository.count_stale_embeddings(&model).await?;
+        let mut report = ReembedReport {
+            model: model.clone(),
+            total,
+            ..Default::default()
+        };
+
+        while report.reembedded < total {
+            let batch = self
+                .repository
+                .list_stale_embeddings(&model, batch_size.max(1))
+                .await?;
+            if batch.is_empty() {
+                break;
+            }
+
+            let inputs: Vec<EmbeddingInput> = batch
+                .iter()
+                .map(|m| EmbeddingInput {
+                    id: m.id.to_string(),
+                    text: m.content.clone(),
+                })
+                .collect();
+            let mut vectors: HashMap<String, Vec<f32>> = embeddings
+                .embed_many(&inputs)
+                .await?
+                .into_iter()
+                .map(|out| (out.id, out.vector))
+                .collect();
+
+            let mut updates = Vec::with_capacity(batch.len());
+            for memory in &batch {
+                match vectors.remove(&memory.id.to_string()) {
+                    Some(vector) if vector.len() == dimension => updates.push((memory.id, vector)),
+                    Some(vector) => {
+                        return Err(DomainError::ValidationFailed(format!(
+                            "embedding model {} returned {} dimensions for memory {}, expected {}",
+                            model,
+                            vector.len(),
+                            memory.id,
+                            dimension
+                        )));
+                    }
+                    None => {
+                        return Err(DomainError::ValidationFailed(format!(
+                            "embedding model {} returned no vector for memory {}",
+                            model, memory.id
+                        )));
+                    }
+                }
+            }
+            self.repository.store_embeddings(&model, &updates).await?;
+
+            report.reembedded += updates.len() as u64;
+            report.batches += 1;
+            tracing::info!(
+                model = %model,
+                processed = report.reembedded,
+                total,
+                "re-embedded memory batch"
+            );
+            event_bus
+                .publish(Self::make_event(
+                    EventSeverity::Info,
+                    EventCategory::Memory,
+                    EventPayload::MemoryReembedProgress {
+                        model: model.clone(),
+                        processed: report.reembedded,
+                        total,
+                    },
+                ))
+                .await;
+        }
+
+        Ok(report)
+    }
+}
+
+/// Report from [`MemoryService::reembed_all`].
+#[derive(Debug, Clone, Default)]
+pub struct ReembedReport {
+    /// Model the memories were re-embedded with.
+    pub model: String,
+    /// Memories that were stale when the run started.
+    pub total: u64,
+    /// Memories re-embedded by this run.
+    pub reembedded: u64,
+    pub batches: u64,
 }
 
 /// Report from maintenance run.
@@ -570,6 +1317,19 @@ pub struct MemoryConflict {
     pub resolution: Option<ConflictResolution>,
 }
 
+impl MemoryConflict {
+    /// Whether maintenance counts this conflict as resolved. Conflicts flagged
+    /// for review are tagged but not counted.
+    pub fn is_auto_resolvable(&self) -> bool {
+        matches!(
+            &self.resolution,
+            Some(ConflictResolution::PreferNewer { .. })
+                | Some(ConflictResolution::PreferHigherConfidence { .. })
+                | Some(ConflictResolution::SoftMerge { .. })
+        )
+    }
+}
+
 /// Resolution strategy for memory conflicts.
 #[derive(Debug, Clone)]
 pub enum ConflictResolution {
@@ -636,8 +1396,6 @@ impl<R: MemoryRepository> MemoryService<R> {
     /// 2. Checking if grouped memories have divergent content
     /// 3. Flagging memories with the same namespace and key but different content
     pub fn detect_conflicts(&self, memories: &[Memory]) -> Vec<MemoryConflict> {
-        use std::collections::HashMap;
-
         let mut conflicts = Vec::new();
 
         // Group by (namespace, key)
@@ -905,7 +1663,8 @@ impl<R: MemoryRepository> MemoryService<R> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::adapters::sqlite::test_support;
+    use crate::adapters::sqlite::{SqliteMemoryRepository, test_support};
+    use crate::services::event_bus::EventBusConfig;
 
     #[tokio::test]
     async fn test_remember_and_recall() {
@@ -926,6 +1685,261 @@ mod tests {
         assert_eq!(recalled.access_count, 1);
     }
 
+    #[test]
+    fn test_chunk_content_respects_cap_and_round_trips() {
+        let content = "alpha beta gamma\n\ndelta epsilon\nzeta eta theta iota kappa";
+        let chunks = chunk_content(content, 16);
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.len() <= 16));
+        assert_eq!(chunks.concat(), content);
+        assert_eq!(chunks[0], "alpha beta gamma");
+
+        // Multi-byte characters are never split.
+        let wide = "é".repeat(10);
+        let chunks = chunk_content(&wide, 5);
+        assert!(chunks.iter().all(|c| c.len() <= 5));
+        assert_eq!(chunks.concat(), wide);
+    }
+
+    #[tokio::test]
+    async fn test_store_oversized_memory_is_chunked_and_searchable() {
+        let service = test_support::setup_memory_service()
+            .await
+            .with_max_content_size(64);
+
+        let mut content = "filler text about nothing in particular. ".repeat(4);
+        content.push_str("The deployment uses a zanzibar authorization model.");
+        let (parent, events) = service
+            .store(
+                "big_blob".to_string(),
+                content.clone(),
+                "test".to_string(),
+                MemoryTier::Episodic,
+                MemoryType::Fact,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let chunk_count = parent.metadata.custom[CHUNK_COUNT_KEY].as_u64().unwrap() as usize;
+        assert!(chunk_count > 1);
+        assert_eq!(events.len(), chunk_count + 1);
+        assert!(parent.content.contains("big_blob#chunk-1"));
+
+        let mut chunks = Vec::new();
+        for i in 1..=chunk_count {
+            let chunk = service
+                .repository()
+                .get_by_key(&format!("big_blob#chunk-{}", i), "test")
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(chunk.content.len() <= 64);
+            assert_eq!(
+                chunk.metadata.custom[CHUNK_PARENT_KEY],
+                serde_json::json!(parent.id.to_string())
+            );
+            chunks.push(chunk.content);
+        }
+        assert_eq!(chunks.concat(), content);
+
+        let found = service.search("zanzibar", Some("test"), 10).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert!(found[0].key.starts_with("big_blob#chunk-"));
+        assert!(found[0].content.contains("zanzibar"));
+    }
+
+    #[tokio::test]
+    async fn test_restore_with_shorter_content_deletes_surplus_chunks() {
+        let service = test_support::setup_memory_service()
+            .await
+            .with_max_content_size(64);
+        let store = |content: String| {
+            service.store(
+                "big_blob".to_string(),
+                content,
+                "test".to_string(),
+                MemoryTier::Episodic,
+                MemoryType::Fact,
+                None,
+            )
+        };
+
+        let (parent, _) = store("filler text about nothing in particular. ".repeat(6))
+            .await
+            .unwrap();
+        let chunk_count = parent.metadata.custom[CHUNK_COUNT_KEY].as_u64().unwrap();
+        assert!(chunk_count > 2);
+
+        let (parent, _) = store("filler text about nothing in particular. ".repeat(2))
+            .await
+            .unwrap();
+        assert_eq!(
+            parent.metadata.custom[CHUNK_COUNT_KEY],
+            serde_json::json!(2)
+        );
+        let repo = service.repository().clone();
+        let chunk_exists = |i: u64| {
+            let repo = repo.clone();
+            async move {
+                let key = format!("big_blob#chunk-{}", i);
+                repo.get_by_key(&key, "test").await.unwrap().is_some()
+            }
+        };
+        assert!(chunk_exists(2).await);
+        for i in 3..=chunk_count {
+            assert!(!chunk_exists(i).await);
+        }
+
+        // Unchunked content drops the remaining chunks too.
+        store("short".to_string()).await.unwrap();
+        assert!(!chunk_exists(1).await);
+    }
+
+    #[tokio::test]
+    async fn test_memory_config_sets_tier_floors_and_store_keeps_memory_floor() {
+        let service = test_support::setup_memory_service()
+            .await
+            .with_memory_config(&crate::services::config::MemoryConfig {
+                episodic_decay_floor: 0.2,
+                ..Default::default()
+            });
+        assert_eq!(service.decay_config().tier_floor(MemoryTier::Working), 0.0);
+        assert_eq!(service.decay_config().tier_floor(MemoryTier::Episodic), 0.2);
+
+        let (stored, _) = service
+            .store(
+                "baseline".to_string(),
+                "the deploy window is tuesday".to_string(),
+                "test".to_string(),
+                MemoryTier::Working,
+                MemoryType::Fact,
+                Some(MemoryMetadata {
+                    decay_floor: Some(0.4),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        assert_eq!(stored.metadata.decay_floor, Some(0.4));
+        assert_eq!(service.decay_config().decay_floor(&stored), 0.4);
+
+        let err = service
+            .store(
+                "bad_floor".to_string(),
+                "content".to_string(),
+                "test".to_string(),
+                MemoryTier::Working,
+                MemoryType::Fact,
+                Some(MemoryMetadata {
+                    decay_floor: Some(1.5),
+                    ..Default::default()
+                }),
+            )
+            .await;
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_memory_config_decay_functions_reach_the_service() {
+        let service = test_support::setup_memory_service()
+            .await
+            .with_memory_config(&crate::services::config::MemoryConfig {
+                decay_functions: HashMap::from([(MemoryType::Code, DecayFunction::Linear)]),
+                ..Default::default()
+            });
+
+        let decay = service.decay_config();
+        assert_eq!(
+            decay.decay_function(&MemoryType::Code),
+            DecayFunction::Linear
+        );
+        assert_eq!(
+            decay.decay_function(&MemoryType::Pattern),
+            DecayConfig::default().decay_function(&MemoryType::Pattern)
+        );
+        assert_eq!(
+            decay.decay_function(&MemoryType::Fact),
+            DecayFunction::Exponential
+        );
+    }
+
+    #[tokio::test]
+    async fn test_store_near_duplicate_reinforces_existing_memory() {
+        let service = test_support::setup_memory_service()
+            .await
+            .with_memory_config(&crate::services::config::MemoryConfig {
+                dedup_similarity_threshold: Some(0.8),
+                ..Default::default()
+            });
+
+        let (first, _) = service
+            .store(
+                "retry_policy".to_string(),
+                "the api client retries failed requests three times with backoff".to_string(),
+                "test".to_string(),
+                MemoryTier::Episodic,
+                MemoryType::Fact,
+                None,
+            )
+            .await
+            .unwrap();
+        let importance_before = first.importance_score();
+
+        let (second, events) = service
+            .store(
+                "retry_behaviour".to_string(),
+                "The API client retries failed requests three times with backoff.".to_string(),
+                "test".to_string(),
+                MemoryTier::Episodic,
+                MemoryType::Fact,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(second.id, first.id);
+        assert!(matches!(
+            events[0].payload,
+            EventPayload::MemoryAccessed {
+                access_count: 1,
+                ..
+            }
+        ));
+
+        let stored = service
+            .repository()
+            .list_by_namespace("test")
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].key, "retry_policy");
+        assert_eq!(stored[0].access_count, 1);
+        assert!(stored[0].importance_score() > importance_before);
+
+        // Content below the threshold is stored as its own memory.
+        service
+            .store(
+                "db_pool".to_string(),
+                "the database pool holds ten connections".to_string(),
+                "test".to_string(),
+                MemoryTier::Episodic,
+                MemoryType::Fact,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            service
+                .repository()
+                .list_by_namespace("test")
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
     #[tokio::test]
     async fn test_learn_semantic() {
         let service = test_support::setup_memory_service().await;
@@ -1188,6 +2202,48 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_similarity_strategy_prefers_relevant_over_recent() {
+        let service = test_support::setup_memory_service().await;
+
+        service.store(
+            "retry_backoff".to_string(),
+            "Retry backoff for the webhook dispatcher doubles the delay after each failed delivery.".to_string(),
+            "test".to_string(),
+            MemoryTier::Working,
+            MemoryType::Fact,
+            None,
+        ).await.unwrap();
+
+        // Stored later, so these are the most recent memories.
+        for i in 0..3 {
+            service.store(
+                format!("standup_notes_{}", i),
+                "Standup notes: the team discussed holiday schedules and office seating.".to_string(),
+                "test".to_string(),
+                MemoryTier::Working,
+                MemoryType::Fact,
+                None,
+            ).await.unwrap();
+        }
+
+        let task_description = "webhook dispatcher retry backoff";
+        let similar = service
+            .load_context_with_strategy(task_description, &MemoryRetrievalStrategy::Similarity, 2000)
+            .await
+            .unwrap();
+        let keys: Vec<&str> = similar.iter().map(|r| r.memory.key.as_str()).collect();
+        assert_eq!(keys.first(), Some(&"retry_backoff"), "got: {:?}", keys);
+        assert!(!keys.iter().any(|k| k.starts_with("standup_notes")), "got: {:?}", keys);
+
+        // Recency ignores content, so the unrelated notes are candidates too.
+        let recent = service
+            .load_context_with_strategy(task_description, &MemoryRetrievalStrategy::Recency, 2000)
+            .await
+            .unwrap();
+        assert!(recent.iter().any(|r| r.memory.key.starts_with("standup_notes")));
+    }
+
     #[tokio::test]
     async fn test_load_context_with_budget_zero_returns_empty() {
         let service = test_support::setup_memory_service().await;
@@ -1852,4 +2908,200 @@ mod tests {
             results.len()
         );
     }
+
+    /// Embeds anything vehicle-related onto one axis and everything else onto
+    /// the other, regardless of shared words.
+    struct ConceptEmbeddingProvider;
+
+    #[async_trait::async_trait]
+    impl crate::domain::ports::EmbeddingProvider for ConceptEmbeddingProvider {
+        fn name(&self) -> &'static str {
+            "concept"
+        }
+
+        fn dimension(&self) -> usize {
+            2
+        }
+
+        async fn embed(&self, text: &str) -> DomainResult<Vec<f32>> {
+            let text = text.to_lowercase();
+            let vehicle = ["car", "automobile", "vehicle"]
+                .iter()
+                .any(|w| text.contains(w));
+            Ok(if vehicle {
+                vec![1.0, 0.0]
+            } else {
+                vec![0.0, 1.0]
+            })
+        }
+
+        async fn embed_batch(
+            &self,
+            inputs: &[EmbeddingInput],
+        ) -> DomainResult<Vec<crate::domain::ports::EmbeddingOutput>> {
+            let mut outputs = Vec::with_capacity(inputs.len());
+            for input in inputs {
+                outputs.push(crate::domain::ports::EmbeddingOutput {
+                    id: input.id.clone(),
+                    vector: self.embed(&input.text).await?,
+                });
+            }
+            Ok(outputs)
+        }
+
+        fn max_batch_size(&self) -> usize {
+            16
+        }
+    }
+
+    async fn hybrid_fixture(with_embeddings: bool) -> MemoryService<SqliteMemoryRepository> {
+        let mut service = test_support::setup_memory_service().await;
+        if with_embeddings {
+            service = service.with_embeddings(Arc::new(EmbeddingService::with_defaults(Arc::new(
+                ConceptEmbeddingProvider,
+            ))));
+        }
+        for (key, content) in [
+            ("car", "notes on the red car"),
+            ("upkeep", "automobile servicing every spring"),
+            ("weather", "sunny afternoon forecast"),
+        ] {
+            service
+                .remember(key.to_string(), content.to_string(), "garage")
+                .await
+                .unwrap();
+        }
+        service
+    }
+
+    #[tokio::test]
+    async fn test_search_hybrid_alpha_zero_is_keyword_only() {
+        let service = hybrid_fixture(true).await;
+
+        let results = service
+            .search_hybrid("car", Some("garage"), 0.0, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].memory.key, "car");
+        assert_eq!(results[0].lexical_score, 1.0, "exact key match");
+        assert_eq!(results[0].vector_score, None);
+        assert_eq!(results[0].score, results[0].lexical_score);
+    }
+
+    #[tokio::test]
+    async fn test_search_hybrid_alpha_one_ranks_by_vector() {
+        let service = hybrid_fixture(true).await;
+
+        let results = service
+            .search_hybrid("car", Some("garage"), 1.0, 10)
+            .await
+            .unwrap();
+
+        let keys: Vec<&str> = results.iter().map(|r| r.memory.key.as_str()).collect();
+        assert_eq!(results.len(), 2, "got {keys:?}");
+        assert!(keys.contains(&"car"));
+        // No shared words with the query, found purely by embedding.
+        let upkeep = results.iter().find(|r| r.memory.key == "upkeep").unwrap();
+        assert_eq!(upkeep.lexical_score, 0.0);
+        assert_eq!(upkeep.vector_score, Some(1.0));
+        assert!((upkeep.score - 1.0).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_search_hybrid_without_embeddings_degrades_to_keyword() {
+        let service = hybrid_fixture(false).await;
+
+        let results = service
+            .search_hybrid("car", Some("garage"), 1.0, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].memory.key, "car");
+        assert_eq!(results[0].vector_score, None);
+        assert_eq!(results[0].score, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_search_hybrid_ignores_vectors_from_another_model() {
+        let service = hybrid_fixture(true).await;
+        let upkeep = service
+            .repository()
+            .get_by_key("upkeep", "garage")
+            .await
+            .unwrap()
+            .unwrap();
+        // Same dimension, different model: must not be compared.
+        service
+            .repository()
+            .store_embeddings("other@2", &[(upkeep.id, vec![0.0, 1.0])])
+            .await
+            .unwrap();
+
+        let results = service
+            .search_hybrid("car", Some("garage"), 1.0, 10)
+            .await
+            .unwrap();
+
+        let upkeep = results.iter().find(|r| r.memory.key == "upkeep").unwrap();
+        assert_eq!(upkeep.vector_score, Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn test_reembed_all_is_resumable() {
+        let service = hybrid_fixture(false).await;
+        let embeddings = EmbeddingService::with_defaults(Arc::new(ConceptEmbeddingProvider));
+        let model = embeddings.model_id();
+        // A previous run got as far as one memory before dying.
+        let car = service
+            .repository()
+            .get_by_key("car", "garage")
+            .await
+            .unwrap()
+            .unwrap();
+        service
+            .repository()
+            .store_embeddings(&model, &[(car.id, vec![1.0, 0.0])])
+            .await
+            .unwrap();
+
+        let event_bus = EventBus::new(EventBusConfig::default());
+        let mut rx = event_bus.subscribe();
+
+        let report = service
+            .reembed_all(&embeddings, 1, &event_bus)
+            .await
+            .unwrap();
+
+        assert_eq!(report.total, 2);
+        assert_eq!(report.reembedded, 2);
+        assert_eq!(report.batches, 2);
+        let events: Vec<UnifiedEvent> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[1].payload,
+            EventPayload::MemoryReembedProgress {
+                processed: 2,
+                total: 2,
+                ..
+            }
+        ));
+        let upkeep = service
+            .repository()
+            .get_by_key("upkeep", "garage")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(upkeep.embedding, Some(vec![1.0, 0.0]));
+        assert_eq!(upkeep.embedding_model.as_deref(), Some(model.as_str()));
+
+        let report = service
+            .reembed_all(&embeddings, 1, &event_bus)
+            .await
+            .unwrap();
+        assert_eq!(report.reembedded, 0);
+        assert!(rx.try_recv().is_err());
+    }
 }
@@ -95,6 +95,20 @@ impl MergeStatus {
     }
 }
 
+/// Whether `task_id` has a merge request that is queued, in progress or
+/// parked on a conflict. Its worktree's branch is still going to be merged,
+/// so it must not be reused, reset or removed underneath the queue.
+pub async fn has_pending_merge(
+    merge_repo: &dyn MergeRequestRepository,
+    task_id: Uuid,
+) -> DomainResult<bool> {
+    Ok(merge_repo
+        .list_by_task(task_id)
+        .await?
+        .iter()
+        .any(|req| !req.status.is_terminal()))
+}
+
 /// A merge request in the queue.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MergeRequest {
@@ -13,7 +13,7 @@
 //!   `disabled` to skip exporter setup entirely.
 //!
 //! Labels throughout the codebase are intentionally cardinality-bounded —
-//! only task type, outcome, and handler name strings are used. No user IDs,
+//! only task type, outcome, handler name, and adapter name strings are used. No user IDs,
 //! task IDs, or goal IDs appear as labels.
 
 use std::net::SocketAddr;
@@ -1,5 +1,6 @@
 //! Application services for the Abathur swarm system.
 
+pub mod adapter_health;
 pub mod adapter_loader;
 pub mod adapter_registry;
 pub mod agent_service;
@@ -11,6 +12,7 @@ pub mod clock;
 pub mod cold_start;
 pub mod command_bus;
 pub mod config;
+pub mod config_migration;
 pub mod context_truncation;
 pub mod context_window;
 pub mod cost_tracker;
@@ -19,6 +21,7 @@ pub mod crypto;
 pub mod dag_executor;
 pub mod dag_restructure;
 pub mod embedding_service;
+pub mod escalation_notifier;
 pub mod event_bus;
 pub mod event_factory;
 pub mod event_reactor;
@@ -30,7 +33,9 @@ pub mod goal_service;
 pub mod guardrails;
 pub mod integration_verifier;
 pub mod intent_verifier;
+pub mod leader_election;
 pub mod llm_planner;
+pub mod log_sampling;
 pub mod memory_decay_daemon;
 pub mod memory_decay_service;
 pub mod memory_maintenance_service;
@@ -40,19 +45,26 @@ pub mod metrics_exporter;
 pub mod meta_planner; // Rust service module for decomposition planning
 pub mod model_router;
 pub mod outbox_poller;
+pub mod output_schema;
 pub mod overmind;
+pub mod partial_output;
 pub mod prompt_adapter;
+pub mod result_cache;
 pub mod supervisor;
 pub use supervisor::{supervise, supervise_result, supervise_with_handle};
 pub mod convergence_bridge;
 pub mod convergence_engine;
 pub mod federation;
 pub mod overseers;
+pub mod swarm_doctor;
 pub mod swarm_orchestrator;
+pub mod task_router;
 pub mod task_schedule_service;
 pub mod task_service;
+pub mod task_validation;
 pub mod trigger_rules;
 pub mod workflow_engine;
+pub mod workflow_health;
 pub mod worktree_service;
 
 pub use adapter_registry::AdapterRegistry;
@@ -62,7 +74,7 @@ pub use audit_log::{
     AuditLogService, AuditStats, DecisionRationale,
 };
 pub use budget_tracker::{
-    BudgetOpportunity, BudgetState, BudgetTracker, BudgetTrackerConfig, BudgetWindow,
+    BudgetOpportunity, BudgetPolicy, BudgetState, BudgetTracker, BudgetTrackerConfig, BudgetWindow,
     BudgetWindowType,
 };
 pub use circuit_breaker::{
@@ -100,8 +112,10 @@ pub use dag_executor::{
     DagExecutor, ExecutionEvent, ExecutionResults, ExecutionStatus, ExecutorConfig, TaskResult,
 };
 pub use dag_restructure::{
-    DagRestructureService, FailedAttempt, NewTaskSpec, RestructureConfig, RestructureContext,
-    RestructureDecision, RestructureTrigger, TaskPriorityModifier,
+    DagRestructureService, FailedAttempt, NewTaskSpec, PlannedDecision, PlannedDescriptionUpdate,
+    PlannedEdge, PlannedTask, PlannedTransition, RestructureApplied, RestructureConfig,
+    RestructureContext, RestructureDecision, RestructurePlan, RestructureTrigger,
+    TaskPriorityModifier, TaskRef,
 };
 pub use embedding_service::{BatchEmbeddingReport, EmbeddingService, EmbeddingServiceConfig};
 pub use event_bus::{
@@ -129,6 +143,7 @@ pub use integration_verifier::{
     IntegrationVerifierService, TestResult, VerificationCheck, VerificationResult, VerifierConfig,
 };
 pub use intent_verifier::{IntentVerifierConfig, IntentVerifierService};
+pub use leader_election::LeaderElection;
 pub use llm_planner::{
     AgentRefinementSuggestion, LlmDecomposition, LlmPlanner, LlmPlannerConfig, LlmTaskSpec,
     PlanningContext,
@@ -137,8 +152,10 @@ pub use memory_decay_daemon::{
     DaemonHandle, DaemonStatus, DecayDaemonConfig, DecayDaemonEvent, MemoryDecayDaemon, StopReason,
 };
 pub use memory_decay_service::MemoryDecayService;
-pub use memory_maintenance_service::MemoryMaintenanceService;
-pub use memory_service::{DecayConfig, MaintenanceReport, MemoryService, MemoryStats};
+pub use memory_maintenance_service::{MaintenancePlan, MemoryMaintenanceService};
+pub use memory_service::{
+    DecayConfig, MaintenanceReport, MemoryService, MemoryStats, ReembedReport,
+};
 pub use merge_queue::{
     MergeQueue, MergeQueueConfig, MergeQueueStats, MergeRequest, MergeResult, MergeStage,
     MergeStatus, validate_branch_name, validate_workdir,
@@ -151,13 +168,16 @@ pub use model_router::{AgentTierHint, ModelRouter, ModelRoutingConfig, ModelSele
 pub use outbox_poller::{OutboxPoller, OutboxPollerConfig, OutboxPollerHandle};
 pub use overmind::{OvermindConfig, OvermindService};
 pub use swarm_orchestrator::{
-    ConvergenceLoopConfig, McpServerConfig, OrchestratorStatus, SwarmConfig, SwarmEvent,
-    SwarmOrchestrator, SwarmStats, VerificationLevel,
+    ConvergenceLoopConfig, McpServerConfig, OrchestratorStatus, SpawnDecision, SpawnGate,
+    SwarmConfig, SwarmEvent, SwarmOrchestrator, SwarmStats, VerificationLevel,
 };
+pub use task_router::{DefaultTaskRouter, KeywordTaskRouter, TaskRouter};
 pub use task_schedule_service::TaskScheduleService;
 pub use task_service::{
-    PruneResult, PruneSkipped, SpawnLimitConfig, SpawnLimitResult, SpawnLimitType, TaskService,
+    PruneResult, PruneSkipped, SpawnLimitConfig, SpawnLimitResult, SpawnLimitType, SubmitExtras,
+    TaskService,
 };
+pub use task_validation::{SecretScanner, TaskValidator, TaskValidatorChain};
 pub use trigger_rules::{
     SerializableDomainCommand, SerializableEventFilter, TriggerAction, TriggerCondition,
     TriggerEventPayload, TriggerRule, TriggerRuleEngine, normalize_cron_expression,
@@ -2,9 +2,11 @@
 //!
 //! Selects the most cost-effective Claude model based on task complexity,
 //! agent tier, and retry count. Haiku for simple tasks (~19x cheaper
-//! than Opus), Sonnet for medium, Opus for complex.
+//! than Opus), Sonnet for medium, Opus for complex. Agent types with a
+//! configured escalation ladder instead climb that ladder on retries.
 
 use crate::domain::models::task::Complexity;
+use crate::services::config::ModelEscalationConfig;
 
 /// Agent tier for model routing decisions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,6 +36,8 @@ pub struct ModelRoutingConfig {
     pub retry_escalation: bool,
     /// Whether architect agents always get the complex model.
     pub architect_always_complex: bool,
+    /// Per-agent-type escalation ladders; take precedence over all other routing.
+    pub escalation: ModelEscalationConfig,
 }
 
 impl Default for ModelRoutingConfig {
@@ -46,6 +50,7 @@ impl Default for ModelRoutingConfig {
             complex_model: "opus".to_string(),
             retry_escalation: true,
             architect_always_complex: true,
+            escalation: ModelEscalationConfig::default(),
         }
     }
 }
@@ -59,6 +64,8 @@ pub struct ModelSelection {
     pub reason: String,
     /// Whether this was escalated from a cheaper model.
     pub escalated: bool,
+    /// Rung of the agent type's escalation ladder, when one was used.
+    pub ladder_position: Option<u32>,
 }
 
 /// Model router for selecting cost-effective models per task.
@@ -88,6 +95,7 @@ impl ModelRouter {
                 model: self.config.complex_model.clone(),
                 reason: "routing disabled".to_string(),
                 escalated: false,
+                ladder_position: None,
             };
         }
 
@@ -97,6 +105,7 @@ impl ModelRouter {
                 model: self.config.complex_model.clone(),
                 reason: "architect agent".to_string(),
                 escalated: false,
+                ladder_position: None,
             };
         }
 
@@ -132,8 +141,45 @@ impl ModelRouter {
             model,
             reason,
             escalated,
+            ladder_position: None,
         }
     }
+
+    /// Select a model for an agent type's next attempt.
+    ///
+    /// A configured escalation ladder wins; otherwise the template's
+    /// preferred model, then complexity-based routing.
+    pub fn select_for_agent(
+        &self,
+        agent_type: &str,
+        preferred_model: Option<&str>,
+        complexity: Complexity,
+        agent_tier: Option<AgentTierHint>,
+        retry_attempt: u32,
+    ) -> ModelSelection {
+        if let Some((position, model)) = self.config.escalation.rung(agent_type, retry_attempt) {
+            return ModelSelection {
+                model: model.to_string(),
+                reason: format!(
+                    "{} escalation ladder rung {} (retry #{})",
+                    agent_type, position, retry_attempt
+                ),
+                escalated: position > 0,
+                ladder_position: Some(position),
+            };
+        }
+
+        if let Some(model) = preferred_model {
+            return ModelSelection {
+                model: model.to_string(),
+                reason: "agent template preferred model".to_string(),
+                escalated: false,
+                ladder_position: None,
+            };
+        }
+
+        self.select_model(complexity, agent_tier, retry_attempt)
+    }
 }
 
 /// Escalate complexity by a number of levels based on retry count.
@@ -262,6 +308,7 @@ mod tests {
             complex_model: "my-opus".to_string(),
             retry_escalation: false,
             architect_always_complex: false,
+            escalation: ModelEscalationConfig::default(),
         };
         let router = ModelRouter::new(config);
 
@@ -274,4 +321,65 @@ mod tests {
         let selection = router.select_model(Complexity::Simple, Some(AgentTierHint::Architect), 0);
         assert_eq!(selection.model, "my-haiku");
     }
+
+    #[test]
+    fn test_escalation_ladder_climbs_on_retry() {
+        let mut escalation = ModelEscalationConfig::default();
+        escalation.ladders.insert(
+            "coder".to_string(),
+            vec![
+                "haiku".to_string(),
+                "sonnet".to_string(),
+                "opus".to_string(),
+            ],
+        );
+        let router = ModelRouter::new(ModelRoutingConfig {
+            escalation,
+            ..Default::default()
+        });
+        let select = |agent: &str, retry| {
+            router.select_for_agent(
+                agent,
+                Some("template-model"),
+                Complexity::Simple,
+                None,
+                retry,
+            )
+        };
+
+        let first = select("coder", 0);
+        assert_eq!(first.model, "haiku");
+        assert_eq!(first.ladder_position, Some(0));
+        assert!(!first.escalated);
+
+        // The ladder overrides the template's preferred model.
+        assert_eq!(select("coder", 1).model, "sonnet");
+        let second_retry = select("coder", 2);
+        assert_eq!(second_retry.model, "opus");
+        assert_eq!(second_retry.ladder_position, Some(2));
+        assert!(second_retry.escalated);
+        // Stays on the top rung.
+        assert_eq!(select("coder", 7).model, "opus");
+
+        // Agent types without a ladder keep the preferred model.
+        let other = select("reviewer", 2);
+        assert_eq!(other.model, "template-model");
+        assert_eq!(other.ladder_position, None);
+    }
+
+    #[test]
+    fn test_escalation_ladder_failures_per_step() {
+        let mut escalation = ModelEscalationConfig {
+            failures_per_step: 2,
+            ..Default::default()
+        };
+        escalation.ladders.insert(
+            "coder".to_string(),
+            vec!["haiku".to_string(), "sonnet".to_string()],
+        );
+
+        assert_eq!(escalation.rung("coder", 1), Some((0, "haiku")));
+        assert_eq!(escalation.rung("coder", 2), Some((1, "sonnet")));
+        assert_eq!(escalation.rung("other", 2), None);
+    }
 }
@@ -0,0 +1,269 @@
+//! Structured-output enforcement for agent results.
+//!
+//! A task may carry a JSON Schema (see [`Task::output_schema`]) that the
+//! agent's final output must satisfy. After the agent completes, the
+//! orchestrator runs [`validate_output`] as a completion gate; a mismatch
+//! fails the task with a [`SCHEMA_VALIDATION_FAILED`] error so the retry
+//! handler can re-run it with the validation errors as a corrective hint.
+//!
+//! Only the commonly used subset of JSON Schema is understood: `type`,
+//! `enum`, `const`, `properties`, `required`, `additionalProperties`,
+//! `items`, `minItems`/`maxItems`, `minLength`/`maxLength` and
+//! `minimum`/`maximum`. Unknown keywords are ignored.
+//!
+//! [`Task::output_schema`]: crate::domain::models::Task::output_schema
+
+use serde_json::Value;
+
+/// Prefix of the failure reason recorded when an agent's output does not
+/// match its task's schema.
+pub const SCHEMA_VALIDATION_FAILED: &str = "schema_validation_failed";
+
+/// Most validation errors reported back for a single output.
+const MAX_REPORTED_ERRORS: usize = 5;
+
+const KNOWN_TYPES: &[&str] = &[
+    "object", "array", "string", "number", "integer", "boolean", "null",
+];
+
+/// Check that `schema` is a usable schema before it is attached to a task.
+pub fn check_schema(schema: &Value) -> Result<(), String> {
+    let Some(obj) = schema.as_object() else {
+        return Err("schema must be a JSON object".to_string());
+    };
+    if let Some(ty) = obj.get("type") {
+        let names: Vec<&Value> = match ty {
+            Value::Array(names) => names.iter().collect(),
+            other => vec![other],
+        };
+        for name in names {
+            match name.as_str() {
+                Some(n) if KNOWN_TYPES.contains(&n) => {}
+                _ => return Err(format!("unsupported type {}", name)),
+            }
+        }
+    }
+    if let Some(props) = obj.get("properties") {
+        let Some(props) = props.as_object() else {
+            return Err("'properties' must be an object".to_string());
+        };
+        for (name, sub) in props {
+            check_schema(sub).map_err(|e| format!("properties.{}: {}", name, e))?;
+        }
+    }
+    if let Some(items) = obj.get("items") {
+        check_schema(items).map_err(|e| format!("items: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Parse `output` as JSON and validate it against `schema`.
+///
+/// The whole output is tried first, then the last fenced code block, since
+/// agents often wrap their answer in ```` ```json ```` fences. On failure the
+/// error lists the first few violations by JSON path.
+pub fn validate_output(schema: &Value, output: &str) -> Result<Value, String> {
+    let value = extract_json(output).ok_or_else(|| "output is not valid JSON".to_string())?;
+    let mut errors = Vec::new();
+    validate_value(schema, &value, "$", &mut errors);
+    if errors.is_empty() {
+        return Ok(value);
+    }
+    let extra = errors.len().saturating_sub(MAX_REPORTED_ERRORS);
+    errors.truncate(MAX_REPORTED_ERRORS);
+    let mut msg = errors.join("; ");
+    if extra > 0 {
+        msg.push_str(&format!("; and {} more", extra));
+    }
+    Err(msg)
+}
+
+fn extract_json(output: &str) -> Option<Value> {
+    let trimmed = output.trim();
+    if let Ok(v) = serde_json::from_str(trimmed) {
+        return Some(v);
+    }
+    let fenced = trimmed.rsplit("```").nth(1)?;
+    let body = fenced.strip_prefix("json").unwrap_or(fenced);
+    serde_json::from_str(body.trim()).ok()
+}
+
+fn type_matches(name: &str, value: &Value) -> bool {
+    match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn validate_value(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+
+    if let Some(ty) = schema.get("type") {
+        let names: Vec<&str> = match ty {
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            other => other.as_str().into_iter().collect(),
+        };
+        if !names.is_empty() && !names.iter().any(|n| type_matches(n, value)) {
+            errors.push(format!("{}: expected {}", path, names.join(" or ")));
+            return;
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+        && !allowed.contains(value)
+    {
+        errors.push(format!(
+            "{}: {} is not one of {}",
+            path,
+            value,
+            Value::from(allowed.clone())
+        ));
+    }
+    if let Some(expected) = schema.get("const")
+        && expected != value
+    {
+        errors.push(format!("{}: expected {}", path, expected));
+    }
+
+    match value {
+        Value::Object(obj) => {
+            let props = schema.get("properties").and_then(Value::as_object);
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !obj.contains_key(name) {
+                        errors.push(format!("{}: missing required property '{}'", path, name));
+                    }
+                }
+            }
+            for (name, child) in obj {
+                match props.and_then(|p| p.get(name)) {
+                    Some(sub) => validate_value(sub, child, &format!("{}.{}", path, name), errors),
+                    None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                        errors.push(format!("{}: unexpected property '{}'", path, name));
+                    }
+                    None => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            let len = items.len() as u64;
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+                && len < min
+            {
+                errors.push(format!(
+                    "{}: expected at least {} items, got {}",
+                    path, min, len
+                ));
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+                && len > max
+            {
+                errors.push(format!(
+                    "{}: expected at most {} items, got {}",
+                    path, max, len
+                ));
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    validate_value(item_schema, item, &format!("{}[{}]", path, i), errors);
+                }
+            }
+        }
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+                && len < min
+            {
+                errors.push(format!("{}: shorter than {} characters", path, min));
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+                && len > max
+            {
+                errors.push(format!("{}: longer than {} characters", path, max));
+            }
+        }
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or_default();
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+                && n < min
+            {
+                errors.push(format!("{}: {} is below the minimum {}", path, n, min));
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+                && n > max
+            {
+                errors.push(format!("{}: {} is above the maximum {}", path, n, max));
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn classifier_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "label": { "type": "string", "enum": ["bug", "feature", "question"] },
+                "confidence": { "type": "number", "minimum": 0, "maximum": 1 }
+            },
+            "required": ["label", "confidence"],
+            "additionalProperties": false
+        })
+    }
+
+    #[test]
+    fn test_validate_output_accepts_matching_and_fenced_json() {
+        let schema = classifier_schema();
+        assert!(validate_output(&schema, r#"{"label": "bug", "confidence": 0.9}"#).is_ok());
+        let fenced = "Here is the classification:\n```json\n{\"label\": \"feature\", \"confidence\": 0.5}\n```";
+        assert!(validate_output(&schema, fenced).is_ok());
+    }
+
+    #[test]
+    fn test_validate_output_reports_violations_by_path() {
+        let schema = classifier_schema();
+        assert_eq!(
+            validate_output(&schema, "It's probably a bug.").unwrap_err(),
+            "output is not valid JSON"
+        );
+
+        let err = validate_output(
+            &schema,
+            r#"{"label": "chore", "confidence": 2, "why": "x"}"#,
+        )
+        .unwrap_err();
+        assert!(err.contains("$.label"), "{err}");
+        assert!(
+            err.contains("$.confidence: 2 is above the maximum 1"),
+            "{err}"
+        );
+        assert!(err.contains("unexpected property 'why'"), "{err}");
+
+        let err = validate_output(&schema, r#"{"label": "bug"}"#).unwrap_err();
+        assert!(
+            err.contains("missing required property 'confidence'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_check_schema_rejects_malformed_schemas() {
+        assert!(check_schema(&classifier_schema()).is_ok());
+        assert!(check_schema(&json!("object")).is_err());
+        assert!(check_schema(&json!({"type": "map"})).is_err());
+        assert!(check_schema(&json!({"properties": {"a": {"type": "strng"}}})).is_err());
+    }
+}
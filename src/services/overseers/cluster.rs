@@ -10,14 +10,16 @@
 //! - Structured logging of cluster execution
 //! - A convenience method that accepts [`ConvergencePolicy`] directly
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
 
 use super::traits::{OverseerMeasurement, apply_signal_update, has_blocking_failures};
 use crate::domain::errors::DomainResult;
 use crate::domain::models::convergence::{
-    ArtifactReference, ConvergencePolicy, Overseer, OverseerCluster, OverseerCost, OverseerSignals,
+    ArtifactReference, ConvergencePolicy, Overseer, OverseerCluster, OverseerCost, OverseerResult,
+    OverseerSignalUpdate, OverseerSignals,
 };
 use crate::services::convergence_engine::OverseerMeasurer;
 
@@ -233,6 +235,203 @@ impl OverseerClusterService {
         let signals = OverseerSignals::merge(cheap_signals, moderate_signals, expensive_signals);
         (signals, all_measurements)
     }
+
+    /// Run all overseers like [`measure`](Self::measure), but run the
+    /// overseers within each cost tier concurrently.
+    ///
+    /// Tiers still run in order, and the blocking-failure and
+    /// `skip_expensive_overseers` rules are the same as in `measure`. At most
+    /// `max_concurrency` overseers in a tier run at once. An overseer that does
+    /// not finish within `per_overseer_timeout` is abandoned and recorded as a
+    /// failing [`OverseerSignalUpdate::Timeout`].
+    pub async fn measure_parallel(
+        &self,
+        artifact: &ArtifactReference,
+        policy: &ConvergencePolicy,
+        max_concurrency: usize,
+        per_overseer_timeout: Duration,
+    ) -> OverseerSignals {
+        tracing::info!(
+            artifact_path = %artifact.path,
+            overseer_count = self.overseers.len(),
+            skip_expensive = policy.skip_expensive_overseers,
+            max_concurrency,
+            timeout_ms = per_overseer_timeout.as_millis() as u64,
+            "Starting parallel overseer cluster measurement"
+        );
+
+        let cluster_start = Instant::now();
+        let mut phases = [
+            OverseerSignals::empty(),
+            OverseerSignals::empty(),
+            OverseerSignals::empty(),
+        ];
+
+        for (slot, cost) in [
+            OverseerCost::Cheap,
+            OverseerCost::Moderate,
+            OverseerCost::Expensive,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            if cost == OverseerCost::Expensive && policy.skip_expensive_overseers {
+                tracing::info!("Skipping expensive overseers per convergence policy");
+                break;
+            }
+
+            let (signals, measurements) = self
+                .run_phase_parallel(cost, artifact, max_concurrency, per_overseer_timeout)
+                .await;
+
+            tracing::info!(
+                phase = ?cost,
+                overseer_count = measurements.len(),
+                elapsed_ms = cluster_start.elapsed().as_millis() as u64,
+                "Parallel overseer phase complete"
+            );
+
+            let blocked = cost == OverseerCost::Cheap && has_blocking_failures(&signals);
+            phases[slot] = signals;
+            if blocked {
+                tracing::warn!(
+                    "Cheap overseers produced blocking failures; skipping moderate and expensive phases"
+                );
+                break;
+            }
+        }
+
+        let [cheap, moderate, expensive] = phases;
+        OverseerSignals::merge(cheap, moderate, expensive)
+    }
+
+    /// Concurrent counterpart of [`run_phase`](Self::run_phase).
+    ///
+    /// Results are applied in registration order regardless of which overseer
+    /// finishes first, so the merged signals match a sequential run.
+    async fn run_phase_parallel(
+        &self,
+        cost: OverseerCost,
+        artifact: &ArtifactReference,
+        max_concurrency: usize,
+        timeout: Duration,
+    ) -> (OverseerSignals, Vec<OverseerMeasurement>) {
+        let outcomes: Vec<_> = stream::iter(self.overseers.iter().filter(|o| o.cost() == cost))
+            .map(|overseer| async move {
+                let start = Instant::now();
+                let outcome = tokio::time::timeout(timeout, overseer.measure(artifact)).await;
+                (overseer, outcome, start.elapsed().as_millis() as u64)
+            })
+            .buffered(max_concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut signals = OverseerSignals::empty();
+        let mut measurements = Vec::new();
+
+        for (overseer, outcome, duration_ms) in outcomes {
+            let result = match outcome {
+                Ok(Ok(result)) => {
+                    tracing::debug!(
+                        overseer = overseer.name(),
+                        pass = result.pass,
+                        duration_ms = duration_ms,
+                        "Overseer measurement complete"
+                    );
+                    result
+                }
+                Ok(Err(err)) => {
+                    tracing::warn!(
+                        overseer = overseer.name(),
+                        error = %err,
+                        duration_ms = duration_ms,
+                        "Overseer measurement failed; skipping"
+                    );
+                    continue;
+                }
+                Err(_) => {
+                    let timeout_ms = timeout.as_millis() as u64;
+                    tracing::warn!(
+                        overseer = overseer.name(),
+                        timeout_ms,
+                        "Overseer measurement timed out"
+                    );
+                    OverseerResult {
+                        pass: false,
+                        signal: OverseerSignalUpdate::Timeout {
+                            overseer: overseer.name().to_string(),
+                            timeout_ms,
+                        },
+                    }
+                }
+            };
+
+            measurements.push(OverseerMeasurement {
+                overseer_name: overseer.name().to_string(),
+                result: result.clone(),
+                duration_ms,
+            });
+
+            apply_signal_update(&mut signals, result.signal);
+        }
+
+        (signals, measurements)
+    }
+
+    /// Run only the `required` overseers once against the artifact, as a
+    /// pass/fail gate outside the convergence loop.
+    ///
+    /// Names are resolved with [`canonical_overseer_name`]. Overseers run in
+    /// cost order and later phases are skipped once a phase fails. Returns a
+    /// description of each blocking failure; an empty list means the gate
+    /// passed. A required overseer that is not registered in the cluster, or
+    /// that errors while measuring, counts as a failure.
+    pub async fn verify(&self, required: &[String], artifact: &ArtifactReference) -> Vec<String> {
+        let mut failures = Vec::new();
+        let mut wanted = Vec::new();
+        for name in required {
+            match canonical_overseer_name(name) {
+                Some(canonical) if self.overseers.iter().any(|o| o.name() == canonical) => {
+                    wanted.push(canonical)
+                }
+                Some(canonical) => failures.push(format!("{canonical}: overseer not configured")),
+                None => failures.push(format!("{name}: unknown overseer")),
+            }
+        }
+        if !failures.is_empty() {
+            return failures;
+        }
+
+        for cost in [
+            OverseerCost::Cheap,
+            OverseerCost::Moderate,
+            OverseerCost::Expensive,
+        ] {
+            for overseer in self
+                .overseers
+                .iter()
+                .filter(|o| o.cost() == cost && wanted.contains(&o.name()))
+            {
+                match overseer.measure(artifact).await {
+                    Ok(result) if result.pass => {}
+                    Ok(_) => failures.push(format!("{}: failed", overseer.name())),
+                    Err(err) => failures.push(format!("{}: {}", overseer.name(), err)),
+                }
+            }
+            if !failures.is_empty() {
+                break;
+            }
+        }
+
+        tracing::info!(
+            artifact_path = %artifact.path,
+            required = ?wanted,
+            passed = failures.is_empty(),
+            "Overseer verification gate complete"
+        );
+
+        failures
+    }
 }
 
 impl Default for OverseerClusterService {
@@ -241,6 +440,22 @@ impl Default for OverseerClusterService {
     }
 }
 
+/// Resolve a user-facing overseer name (as accepted by `task submit
+/// --verify`) to the canonical [`Overseer::name`]. Short aliases such as
+/// `tests` and `security` are accepted.
+pub fn canonical_overseer_name(name: &str) -> Option<&'static str> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "compilation" | "compile" => Some("compilation"),
+        "type-check" | "typecheck" => Some("type-check"),
+        "build" => Some("build"),
+        "lint" => Some("lint"),
+        "security-scan" | "security" => Some("security-scan"),
+        "test-suite" | "tests" | "test" => Some("test-suite"),
+        "acceptance-test" | "acceptance" => Some("acceptance-test"),
+        _ => None,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // OverseerMeasurer trait implementation
 // ---------------------------------------------------------------------------
@@ -334,6 +549,29 @@ mod tests {
         }
     }
 
+    struct SlowOverseer {
+        name: &'static str,
+        cost: OverseerCost,
+        delay: Duration,
+        result: OverseerResult,
+    }
+
+    #[async_trait]
+    impl Overseer for SlowOverseer {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn measure(&self, _artifact: &ArtifactReference) -> anyhow::Result<OverseerResult> {
+            tokio::time::sleep(self.delay).await;
+            Ok(self.result.clone())
+        }
+
+        fn cost(&self) -> OverseerCost {
+            self.cost
+        }
+    }
+
     fn test_artifact() -> ArtifactReference {
         ArtifactReference::new("/test/path", "hash123")
     }
@@ -586,6 +824,139 @@ mod tests {
         assert_eq!(measurements[1].overseer_name, "lint");
     }
 
+    #[tokio::test]
+    async fn measure_parallel_records_timeout_for_slow_overseer() {
+        let mut cluster = OverseerClusterService::new();
+        cluster.add(Box::new(MockOverseer {
+            name: "type-check",
+            cost: OverseerCost::Cheap,
+            result: passing_type_check_result(),
+        }));
+        cluster.add(Box::new(SlowOverseer {
+            name: "build",
+            cost: OverseerCost::Cheap,
+            delay: Duration::from_secs(30),
+            result: passing_build_result(),
+        }));
+
+        let policy = ConvergencePolicy::default();
+        let start = Instant::now();
+        let signals = cluster
+            .measure_parallel(&test_artifact(), &policy, 4, Duration::from_millis(50))
+            .await;
+
+        // The hung overseer is abandoned rather than stalling the measurement.
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(signals.type_check.is_some());
+        assert!(signals.build_result.is_none());
+        assert_eq!(signals.custom_checks.len(), 1);
+        let timeout = &signals.custom_checks[0];
+        assert_eq!(timeout.name, "build");
+        assert!(!timeout.passed);
+        assert_eq!(timeout.details, "timed out after 50ms");
+    }
+
+    #[tokio::test]
+    async fn measure_parallel_runs_a_tier_concurrently() {
+        let mut cluster = OverseerClusterService::new();
+        cluster.add(Box::new(SlowOverseer {
+            name: "build",
+            cost: OverseerCost::Cheap,
+            delay: Duration::from_millis(300),
+            result: passing_build_result(),
+        }));
+        cluster.add(Box::new(SlowOverseer {
+            name: "type-check",
+            cost: OverseerCost::Cheap,
+            delay: Duration::from_millis(300),
+            result: passing_type_check_result(),
+        }));
+
+        let policy = ConvergencePolicy::default();
+        let start = Instant::now();
+        let signals = cluster
+            .measure_parallel(&test_artifact(), &policy, 2, Duration::from_secs(10))
+            .await;
+
+        assert!(start.elapsed() < Duration::from_millis(550));
+        assert!(signals.build_result.is_some());
+        assert!(signals.type_check.is_some());
+        assert!(signals.all_passing());
+    }
+
+    #[tokio::test]
+    async fn measure_parallel_blocking_failure_skips_later_tiers() {
+        let mut cluster = OverseerClusterService::new();
+        cluster.add(Box::new(MockOverseer {
+            name: "build",
+            cost: OverseerCost::Cheap,
+            result: failing_build_result(),
+        }));
+        cluster.add(Box::new(SlowOverseer {
+            name: "test-suite",
+            cost: OverseerCost::Expensive,
+            delay: Duration::from_secs(30),
+            result: passing_build_result(),
+        }));
+
+        let policy = ConvergencePolicy::default();
+        let signals = cluster
+            .measure_parallel(&test_artifact(), &policy, 4, Duration::from_millis(50))
+            .await;
+
+        // The expensive tier never started, so no timeout was recorded for it.
+        assert!(!signals.build_result.as_ref().unwrap().success);
+        assert!(signals.custom_checks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn verify_runs_only_required_overseers_as_a_gate() {
+        let mut cluster = OverseerClusterService::new();
+        cluster.add(Box::new(MockOverseer {
+            name: "compilation",
+            cost: OverseerCost::Cheap,
+            result: failing_build_result(),
+        }));
+        cluster.add(Box::new(MockOverseer {
+            name: "build",
+            cost: OverseerCost::Cheap,
+            result: passing_build_result(),
+        }));
+        cluster.add(Box::new(FailingOverseer {
+            name: "test-suite",
+            cost: OverseerCost::Expensive,
+        }));
+
+        // Only the required overseer is consulted.
+        assert!(
+            cluster
+                .verify(&["build".to_string()], &test_artifact())
+                .await
+                .is_empty()
+        );
+
+        // Cheap failures short-circuit the expensive phase.
+        let failures = cluster
+            .verify(
+                &["compilation".to_string(), "tests".to_string()],
+                &test_artifact(),
+            )
+            .await;
+        assert_eq!(failures, vec!["compilation: failed".to_string()]);
+
+        // Unregistered and unknown names fail the gate without measuring.
+        let failures = cluster
+            .verify(&["lint".to_string(), "vibes".to_string()], &test_artifact())
+            .await;
+        assert_eq!(
+            failures,
+            vec![
+                "lint: overseer not configured".to_string(),
+                "vibes: unknown overseer".to_string()
+            ]
+        );
+    }
+
     #[test]
     fn default_cluster_is_empty() {
         let cluster = OverseerClusterService::default();
@@ -56,7 +56,7 @@ pub mod type_check;
 
 pub use acceptance_test::AcceptanceTestOverseer;
 pub use build::BuildOverseer;
-pub use cluster::OverseerClusterService;
+pub use cluster::{OverseerClusterService, canonical_overseer_name};
 pub use compilation::CompilationOverseer;
 pub use lint::LintOverseer;
 pub use security_scan::SecurityScanOverseer;
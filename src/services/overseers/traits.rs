@@ -6,7 +6,7 @@
 //! (`domain::models::convergence::overseer`) -- this module re-exports them
 //! for convenience and adds service-layer concerns like timing.
 
-use crate::domain::models::convergence::{OverseerResult, OverseerSignals};
+use crate::domain::models::convergence::{CustomCheckResult, OverseerResult, OverseerSignals};
 
 // ---------------------------------------------------------------------------
 // OverseerMeasurement
@@ -66,6 +66,12 @@ pub fn apply_signal_update(
         OverseerSignalUpdate::BuildResult(r) => signals.build_result = Some(r),
         OverseerSignalUpdate::SecurityScan(r) => signals.security_scan = Some(r),
         OverseerSignalUpdate::CustomCheck(r) => signals.custom_checks.push(r),
+        OverseerSignalUpdate::Timeout {
+            overseer,
+            timeout_ms,
+        } => signals
+            .custom_checks
+            .push(CustomCheckResult::timed_out(&overseer, timeout_ms)),
     }
 }
 
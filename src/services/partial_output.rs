@@ -0,0 +1,157 @@
+//! Partial agent output kept on a task when an attempt does not complete.
+//!
+//! Both the DAG executor and the swarm orchestrator stream substrate output;
+//! they buffer it here and persist it through [`record_attempt_output`] so a
+//! timeout, kill or failure leaves the same trail regardless of which path
+//! ran the agent.
+
+use crate::domain::models::{SubstrateOutput, Task};
+
+/// Upper bound on partial output kept on a failed task; the tail is kept.
+pub const PARTIAL_OUTPUT_MAX_CHARS: usize = 64 * 1024;
+
+/// Assistant text and tool results streamed during one attempt.
+///
+/// Only the last [`PARTIAL_OUTPUT_MAX_CHARS`] characters are kept, so a
+/// long-running agent cannot grow the buffer without bound.
+#[derive(Debug, Clone, Default)]
+pub struct OutputBuffer {
+    text: String,
+    /// Characters in `text`, tracked to avoid recounting on every push.
+    chars: usize,
+}
+
+impl OutputBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append the textual content of `event`, if it carries any.
+    pub fn record(&mut self, event: &SubstrateOutput) {
+        match event {
+            SubstrateOutput::AssistantText { content } => self.push(content),
+            SubstrateOutput::ToolResult { result, .. } => self.push(result),
+            _ => {}
+        }
+    }
+
+    fn push(&mut self, chunk: &str) {
+        if chunk.is_empty() {
+            return;
+        }
+        if !self.text.is_empty() {
+            self.text.push('\n');
+            self.chars += 1;
+        }
+        self.text.push_str(chunk);
+        self.chars += chunk.chars().count();
+
+        let excess = self.chars.saturating_sub(PARTIAL_OUTPUT_MAX_CHARS);
+        if excess > 0 {
+            let cut = self
+                .text
+                .char_indices()
+                .nth(excess)
+                .map_or(self.text.len(), |(i, _)| i);
+            self.text.drain(..cut);
+            self.chars -= excess;
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// True when nothing but whitespace was streamed.
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
+
+/// Keep the last [`PARTIAL_OUTPUT_MAX_CHARS`] characters of `output`.
+pub fn partial_output_tail(output: &str) -> String {
+    let len = output.chars().count();
+    output
+        .chars()
+        .skip(len.saturating_sub(PARTIAL_OUTPUT_MAX_CHARS))
+        .collect()
+}
+
+/// Settle the partial output on `task` after an attempt: a completed attempt
+/// clears any stale output, an incomplete one saves the tail of what it
+/// streamed. Returns whether `task` changed and needs persisting.
+pub fn record_attempt_output(task: &mut Task, completed: bool, output: &str) -> bool {
+    if completed {
+        let had_partial = task.partial_output_available();
+        task.clear_partial_output();
+        return had_partial;
+    }
+    if output.trim().is_empty() {
+        return false;
+    }
+    tracing::info!(
+        task_id = %task.id,
+        chars = output.len(),
+        "Saving partial agent output on task"
+    );
+    task.set_partial_output(partial_output_tail(output));
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffer_keeps_text_and_tool_results() {
+        let mut buffer = OutputBuffer::new();
+        buffer.record(&SubstrateOutput::AssistantText {
+            content: "thinking".to_string(),
+        });
+        buffer.record(&SubstrateOutput::TurnComplete {
+            turn_number: 1,
+            input_tokens: 10,
+            output_tokens: 5,
+        });
+        buffer.record(&SubstrateOutput::ToolResult {
+            id: "t1".to_string(),
+            result: "ok".to_string(),
+            is_error: false,
+        });
+        assert_eq!(buffer.as_str(), "thinking\nok");
+    }
+
+    #[test]
+    fn test_buffer_keeps_a_rolling_tail() {
+        let mut buffer = OutputBuffer::new();
+        for _ in 0..3 {
+            buffer.record(&SubstrateOutput::AssistantText {
+                content: "é".repeat(PARTIAL_OUTPUT_MAX_CHARS / 2),
+            });
+        }
+        buffer.record(&SubstrateOutput::AssistantText {
+            content: "done".to_string(),
+        });
+        assert_eq!(buffer.as_str().chars().count(), PARTIAL_OUTPUT_MAX_CHARS);
+        assert!(buffer.as_str().ends_with("é\ndone"));
+    }
+
+    #[test]
+    fn test_record_attempt_output_saves_tail_and_clears_on_success() {
+        let mut task = Task::new("partial output");
+        let long = "x".repeat(PARTIAL_OUTPUT_MAX_CHARS + 10);
+
+        assert!(record_attempt_output(&mut task, false, &long));
+        assert_eq!(
+            task.partial_output().map(|s| s.len()),
+            Some(PARTIAL_OUTPUT_MAX_CHARS)
+        );
+
+        assert!(!record_attempt_output(&mut task, false, "   "));
+        assert!(task.partial_output_available());
+
+        assert!(record_attempt_output(&mut task, true, ""));
+        assert!(!task.partial_output_available());
+        assert!(!record_attempt_output(&mut task, true, ""));
+    }
+}
@@ -0,0 +1,132 @@
+//! Reuse of agent results for idempotent tasks.
+//!
+//! A task marked cacheable (see [`Task::is_cacheable`]) is keyed by a hash of
+//! its agent type and assembled prompts. Each entry also records the state of
+//! the repository the agent ran against ([`repo_state`]); a lookup made after
+//! the repository changed drops the entry instead of returning it. Entries
+//! also expire after `[result_cache] ttl_secs`.
+//!
+//! [`Task::is_cacheable`]: crate::domain::models::Task::is_cacheable
+
+use std::path::Path;
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::services::config::ResultCacheConfig;
+
+/// A stored agent result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedResult {
+    /// [`repo_state`] of the working directory the result was produced in.
+    pub repo_state: String,
+    /// The agent's final output.
+    pub result: Option<String>,
+    /// Task that produced the result.
+    pub source_task_id: Uuid,
+    /// Tokens the producing run consumed.
+    pub tokens: u64,
+}
+
+/// In-memory cache of agent results, shared by every task the orchestrator
+/// spawns.
+pub struct ResultCache {
+    entries: moka::future::Cache<String, CachedResult>,
+}
+
+impl ResultCache {
+    pub fn new(config: &ResultCacheConfig) -> Self {
+        Self {
+            entries: moka::future::Cache::builder()
+                .max_capacity(config.max_entries)
+                .time_to_live(Duration::from_secs(config.ttl_secs))
+                .build(),
+        }
+    }
+
+    /// Key identifying an agent invocation, independent of the task ID.
+    pub fn cache_key(agent_type: &str, system_prompt: &str, prompt: &str) -> String {
+        let mut hasher = Sha256::new();
+        for part in [agent_type, system_prompt, prompt] {
+            hasher.update((part.len() as u64).to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    /// The result stored under `key`, if it was produced against
+    /// `repo_state`. An entry from a different repository state is stale and
+    /// is removed.
+    pub async fn lookup(&self, key: &str, repo_state: &str) -> Option<CachedResult> {
+        let entry = self.entries.get(key).await?;
+        if entry.repo_state == repo_state {
+            return Some(entry);
+        }
+        self.entries.invalidate(key).await;
+        None
+    }
+
+    pub async fn store(&self, key: String, entry: CachedResult) {
+        self.entries.insert(key, entry).await;
+    }
+}
+
+/// Fingerprint of the git repository at `work_dir`: its `HEAD` commit plus
+/// any uncommitted changes (`git status` and the diff against `HEAD`).
+///
+/// `None` when `work_dir` is not a git repository, in which case changes
+/// cannot be detected and nothing should be cached.
+pub async fn repo_state(work_dir: &Path) -> Option<String> {
+    let head = git_output(work_dir, &["rev-parse", "HEAD"]).await?;
+    let status = git_output(work_dir, &["status", "--porcelain"]).await?;
+    let diff = git_output(work_dir, &["diff", "HEAD"]).await?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(&status);
+    hasher.update(&diff);
+    Some(format!(
+        "{}:{}",
+        String::from_utf8_lossy(&head).trim(),
+        hex::encode(hasher.finalize())
+    ))
+}
+
+async fn git_output(work_dir: &Path, args: &[&str]) -> Option<Vec<u8>> {
+    tokio::process::Command::new("git")
+        .args(args)
+        .current_dir(work_dir)
+        .output()
+        .await
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| o.stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lookup_invalidates_entry_from_other_repo_state() {
+        let cache = ResultCache::new(&ResultCacheConfig::default());
+        let key = ResultCache::cache_key("researcher", "system", "What is the answer?");
+        assert_ne!(
+            key,
+            ResultCache::cache_key("researcher", "system", "What is the question?")
+        );
+
+        let entry = CachedResult {
+            repo_state: "abc:1".to_string(),
+            result: Some("42".to_string()),
+            source_task_id: Uuid::new_v4(),
+            tokens: 1200,
+        };
+        cache.store(key.clone(), entry.clone()).await;
+        assert_eq!(cache.lookup(&key, "abc:1").await, Some(entry));
+
+        // The repository moved on: the entry is dropped, even for the old state.
+        assert_eq!(cache.lookup(&key, "def:1").await, None);
+        assert_eq!(cache.lookup(&key, "abc:1").await, None);
+    }
+}
@@ -0,0 +1,546 @@
+//! Health checks and repairs behind `swarm doctor`.
+//!
+//! [`SwarmDoctor::diagnose`] reports problems in a project's swarm state
+//! without changing anything; [`SwarmDoctor::fix`] repairs them. Every repair
+//! is idempotent — a second `fix` finds nothing left to do. Repairs that can
+//! discard work (removing a worktree with uncommitted changes) are only
+//! applied when the caller allows destructive fixes. Dependency cycles are
+//! only reported: which edge to drop is a judgement call. Orphaned workflow
+//! subtasks are handed to a live ancestor; failing them when there is none
+//! counts as destructive.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::adapters::sqlite::{
+    Migrator, SqliteTaskRepository, SqliteWorktreeRepository, all_embedded_migrations,
+};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::models::{TaskDag, TaskStatus, WorktreeStatus};
+use crate::domain::ports::{TaskFilter, TaskRepository, WorktreeRepository};
+use crate::services::builtin_handlers::has_uncommitted_changes;
+use crate::services::task_service::TaskService;
+use crate::services::workflow_health::WorkflowHealthMonitor;
+
+/// Area of swarm state a finding belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DoctorCheck {
+    /// Schema migrations not yet applied to the database.
+    PendingMigrations,
+    /// Indexes the migrated schema defines but the database lacks.
+    MissingIndexes,
+    /// Tasks marked Running while no swarm process is alive to run them.
+    DeadRunningTasks,
+    /// Active worktrees whose task is finished or gone.
+    OrphanedWorktrees,
+    /// Tasks that (transitively) depend on themselves and can never run.
+    DependencyCycles,
+    /// Live subtasks whose workflow parent is finished or gone.
+    OrphanedWorkflows,
+}
+
+impl DoctorCheck {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PendingMigrations => "pending_migrations",
+            Self::MissingIndexes => "missing_indexes",
+            Self::DeadRunningTasks => "dead_running_tasks",
+            Self::OrphanedWorktrees => "orphaned_worktrees",
+            Self::DependencyCycles => "dependency_cycles",
+            Self::OrphanedWorkflows => "orphaned_workflows",
+        }
+    }
+}
+
+/// What a repair does to a finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepairOutcome {
+    /// Found by `diagnose`; no repair attempted.
+    Found,
+    Fixed,
+    /// Needs destructive fixes to be allowed.
+    Skipped,
+    Failed,
+}
+
+/// One problem, and what `fix` did about it.
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorFinding {
+    pub check: DoctorCheck,
+    pub detail: String,
+    /// Repairing this can discard work.
+    pub destructive: bool,
+    pub outcome: RepairOutcome,
+}
+
+impl DoctorFinding {
+    fn new(check: DoctorCheck, detail: impl Into<String>) -> Self {
+        Self {
+            check,
+            detail: detail.into(),
+            destructive: false,
+            outcome: RepairOutcome::Found,
+        }
+    }
+}
+
+/// Inspects and repairs the swarm state stored in one project database.
+pub struct SwarmDoctor {
+    pool: SqlitePool,
+    task_repo: Arc<SqliteTaskRepository>,
+    worktree_repo: Arc<SqliteWorktreeRepository>,
+    /// Whether a swarm process is alive; Running tasks are only dead without one.
+    swarm_running: bool,
+}
+
+impl SwarmDoctor {
+    pub fn new(pool: SqlitePool, swarm_running: bool) -> Self {
+        Self {
+            task_repo: Arc::new(SqliteTaskRepository::new(pool.clone())),
+            worktree_repo: Arc::new(SqliteWorktreeRepository::new(pool.clone())),
+            pool,
+            swarm_running,
+        }
+    }
+
+    /// Report problems without changing anything.
+    pub async fn diagnose(&self) -> DomainResult<Vec<DoctorFinding>> {
+        self.run(false, false).await
+    }
+
+    /// Repair what `diagnose` would report. Destructive repairs are skipped
+    /// unless `allow_destructive` is set.
+    pub async fn fix(&self, allow_destructive: bool) -> DomainResult<Vec<DoctorFinding>> {
+        self.run(true, allow_destructive).await
+    }
+
+    async fn run(&self, fix: bool, allow_destructive: bool) -> DomainResult<Vec<DoctorFinding>> {
+        let mut findings = self.check_migrations(fix).await?;
+        // Index checks compare against the fully migrated schema, which only
+        // makes sense once no migrations are outstanding.
+        if findings.iter().all(|f| f.outcome != RepairOutcome::Found) {
+            findings.extend(self.check_indexes(fix).await?);
+        }
+        findings.extend(self.check_running_tasks(fix).await?);
+        findings.extend(self.check_worktrees(fix, allow_destructive).await?);
+        findings.extend(self.check_dependency_cycles().await?);
+        findings.extend(self.check_workflows(fix, allow_destructive).await?);
+        Ok(findings)
+    }
+
+    async fn check_migrations(&self, fix: bool) -> DomainResult<Vec<DoctorFinding>> {
+        let migrations = all_embedded_migrations();
+        let migrator = Migrator::new(self.pool.clone());
+        let status = migrator
+            .status(&migrations)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        if status.pending.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut finding = DoctorFinding::new(
+            DoctorCheck::PendingMigrations,
+            format!(
+                "{} pending migration(s) after schema version {}",
+                status.pending.len(),
+                status.current_version
+            ),
+        );
+        if fix {
+            finding.outcome = match migrator.run_embedded_migrations(migrations).await {
+                Ok(applied) => {
+                    finding.detail = format!("applied {} migration(s)", applied);
+                    RepairOutcome::Fixed
+                }
+                Err(e) => {
+                    finding.detail = format!("{}: {}", finding.detail, e);
+                    RepairOutcome::Failed
+                }
+            };
+        }
+        Ok(vec![finding])
+    }
+
+    async fn check_indexes(&self, fix: bool) -> DomainResult<Vec<DoctorFinding>> {
+        let expected = reference_indexes().await?;
+        let present: Vec<(String,)> =
+            sqlx::query_as("SELECT name FROM sqlite_master WHERE type = 'index'")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        let present: std::collections::HashSet<String> =
+            present.into_iter().map(|(name,)| name).collect();
+
+        let mut missing: Vec<_> = expected
+            .into_iter()
+            .filter(|(name, _)| !present.contains(name))
+            .collect();
+        missing.sort();
+
+        let mut findings = Vec::new();
+        for (name, sql) in missing {
+            let mut finding =
+                DoctorFinding::new(DoctorCheck::MissingIndexes, format!("index {}", name));
+            if fix {
+                finding.outcome = match sqlx::query(&sql).execute(&self.pool).await {
+                    Ok(_) => RepairOutcome::Fixed,
+                    Err(e) => {
+                        finding.detail = format!("index {}: {}", name, e);
+                        RepairOutcome::Failed
+                    }
+                };
+            }
+            findings.push(finding);
+        }
+        Ok(findings)
+    }
+
+    async fn check_running_tasks(&self, fix: bool) -> DomainResult<Vec<DoctorFinding>> {
+        if self.swarm_running {
+            return Ok(vec![]);
+        }
+        let task_service = TaskService::new(self.task_repo.clone());
+
+        let mut findings = Vec::new();
+        for task in self.task_repo.list_by_status(TaskStatus::Running).await? {
+            let mut finding = DoctorFinding::new(
+                DoctorCheck::DeadRunningTasks,
+                format!("task {} ({}) is Running with no swarm", task.id, task.title),
+            );
+            if fix {
+                finding.outcome = match task_service
+                    .restart_task(task.id, TaskStatus::Ready, "swarm doctor: agent not running")
+                    .await
+                {
+                    Ok(_) => RepairOutcome::Fixed,
+                    Err(e) => {
+                        finding.detail = format!("{}: {}", finding.detail, e);
+                        RepairOutcome::Failed
+                    }
+                };
+            }
+            findings.push(finding);
+        }
+        Ok(findings)
+    }
+
+    async fn check_worktrees(
+        &self,
+        fix: bool,
+        allow_destructive: bool,
+    ) -> DomainResult<Vec<DoctorFinding>> {
+        let mut findings = Vec::new();
+        for wt in self.worktree_repo.list_active().await? {
+            let reason = match self.task_repo.get(wt.task_id).await? {
+                Some(task) if !task.is_terminal() => continue,
+                Some(task) => format!("task {} is {}", task.id, task.status.as_str()),
+                None => format!("task {} not found", wt.task_id),
+            };
+            let mut finding = DoctorFinding::new(
+                DoctorCheck::OrphanedWorktrees,
+                format!("worktree {} ({})", wt.path, reason),
+            );
+            finding.destructive = has_uncommitted_changes(&wt.path).await;
+            if finding.destructive {
+                finding.detail.push_str(" has uncommitted changes");
+            }
+
+            if fix {
+                finding.outcome = if finding.destructive && !allow_destructive {
+                    RepairOutcome::Skipped
+                } else {
+                    self.remove_worktree(wt.id, &wt.path).await
+                };
+            }
+            findings.push(finding);
+        }
+        Ok(findings)
+    }
+
+    /// Report dependency cycles left by data written before edges were
+    /// cycle-checked (or edited directly in the database).
+    async fn check_dependency_cycles(&self) -> DomainResult<Vec<DoctorFinding>> {
+        let tasks = self.task_repo.list(TaskFilter::default()).await?;
+        let titles: HashMap<Uuid, String> = tasks.iter().map(|t| (t.id, t.title.clone())).collect();
+
+        Ok(TaskDag::from_tasks(tasks)
+            .find_cycles()
+            .into_iter()
+            .map(|cycle| {
+                let members: Vec<String> = cycle
+                    .iter()
+                    .map(|id| format!("{} ({})", id, titles[id]))
+                    .collect();
+                DoctorFinding::new(
+                    DoctorCheck::DependencyCycles,
+                    format!(
+                        "tasks depend on each other in a cycle: {}",
+                        members.join(", ")
+                    ),
+                )
+            })
+            .collect())
+    }
+
+    async fn check_workflows(
+        &self,
+        fix: bool,
+        allow_destructive: bool,
+    ) -> DomainResult<Vec<DoctorFinding>> {
+        let monitor = WorkflowHealthMonitor::new(self.task_repo.clone());
+        let mut findings = Vec::new();
+        for anomaly in monitor.diagnose().await? {
+            let mut finding = DoctorFinding::new(
+                DoctorCheck::OrphanedWorkflows,
+                match anomaly.adopter {
+                    Some(adopter) => format!("{} (ancestor {} can adopt them)", anomaly, adopter),
+                    None => format!("{} and no live ancestor", anomaly),
+                },
+            );
+            finding.destructive = anomaly.adopter.is_none();
+
+            if fix {
+                finding.outcome = if finding.destructive && !allow_destructive {
+                    RepairOutcome::Skipped
+                } else {
+                    let report = monitor
+                        .remediate_orphaned_workflow(anomaly.parent_id)
+                        .await?;
+                    finding.detail = format!(
+                        "parent {}: reassigned {}, failed {}",
+                        anomaly.parent_id,
+                        report.reassigned.len(),
+                        report.failed.len()
+                    );
+                    let remaining = monitor.diagnose().await?;
+                    if remaining.iter().any(|a| a.parent_id == anomaly.parent_id) {
+                        RepairOutcome::Failed
+                    } else {
+                        RepairOutcome::Fixed
+                    }
+                };
+            }
+            findings.push(finding);
+        }
+        Ok(findings)
+    }
+
+    async fn remove_worktree(&self, id: Uuid, path: &str) -> RepairOutcome {
+        // A failed `git worktree remove` (e.g. the directory is already gone)
+        // still leaves the record to clean up.
+        let removed = tokio::process::Command::new("git")
+            .args(["worktree", "remove", "--force", path])
+            .output()
+            .await;
+        if !matches!(&removed, Ok(o) if o.status.success()) {
+            tracing::debug!(path, "swarm doctor: git worktree remove failed");
+        }
+
+        let Ok(Some(mut wt)) = self.worktree_repo.get(id).await else {
+            return RepairOutcome::Failed;
+        };
+        let now = chrono::Utc::now();
+        wt.status = WorktreeStatus::Removed;
+        wt.updated_at = now;
+        wt.completed_at = Some(now);
+        match self.worktree_repo.update(&wt).await {
+            Ok(()) => RepairOutcome::Fixed,
+            Err(_) => RepairOutcome::Failed,
+        }
+    }
+}
+
+/// Index name → `CREATE INDEX` statement for a freshly migrated database.
+async fn reference_indexes() -> DomainResult<HashMap<String, String>> {
+    let reference = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    Migrator::new(reference.clone())
+        .run_embedded_migrations(all_embedded_migrations())
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    // Automatic indexes (for UNIQUE / PRIMARY KEY) have no SQL and come back
+    // with their table.
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT name, sql FROM sqlite_master WHERE type = 'index' AND sql IS NOT NULL",
+    )
+    .fetch_all(&reference)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    reference.close().await;
+    Ok(rows.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::create_migrated_test_pool;
+    use crate::domain::models::{Task, Worktree};
+
+    #[tokio::test]
+    async fn test_fix_resets_dead_running_task_and_cleans_orphaned_worktree() {
+        let pool = create_migrated_test_pool().await.unwrap();
+        let task_repo = SqliteTaskRepository::new(pool.clone());
+        let worktree_repo = SqliteWorktreeRepository::new(pool.clone());
+
+        let mut stuck = Task::new("Agent died mid-run");
+        stuck.transition_to(TaskStatus::Ready).unwrap();
+        stuck.transition_to(TaskStatus::Running).unwrap();
+        task_repo.create(&stuck).await.unwrap();
+
+        let mut done = Task::new("Finished long ago");
+        done.status = TaskStatus::Complete;
+        task_repo.create(&done).await.unwrap();
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut orphan = Worktree::new(
+            done.id,
+            dir.path().join("wt").to_str().unwrap(),
+            "abathur/task-done",
+            "main",
+        );
+        orphan.activate();
+        worktree_repo.create(&orphan).await.unwrap();
+
+        sqlx::query("DROP INDEX idx_tasks_status")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let doctor = SwarmDoctor::new(pool.clone(), false);
+        let found: Vec<_> = doctor
+            .diagnose()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|f| (f.check, f.outcome))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (DoctorCheck::MissingIndexes, RepairOutcome::Found),
+                (DoctorCheck::DeadRunningTasks, RepairOutcome::Found),
+                (DoctorCheck::OrphanedWorktrees, RepairOutcome::Found),
+            ]
+        );
+        // Diagnosing changes nothing.
+        assert_eq!(
+            task_repo.get(stuck.id).await.unwrap().unwrap().status,
+            TaskStatus::Running
+        );
+
+        let fixed = doctor.fix(false).await.unwrap();
+        assert_eq!(fixed.len(), 3);
+        assert!(fixed.iter().all(|f| f.outcome == RepairOutcome::Fixed));
+        assert_eq!(
+            task_repo.get(stuck.id).await.unwrap().unwrap().status,
+            TaskStatus::Ready
+        );
+        assert_eq!(
+            worktree_repo.get(orphan.id).await.unwrap().unwrap().status,
+            WorktreeStatus::Removed
+        );
+
+        // Repairs are idempotent: nothing is left to fix.
+        assert!(doctor.fix(false).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fix_only_fails_unadoptable_orphaned_subtasks_when_allowed() {
+        let pool = create_migrated_test_pool().await.unwrap();
+        let task_repo = SqliteTaskRepository::new(pool.clone());
+
+        let mut parent = Task::new("Abandoned workflow");
+        parent.status = TaskStatus::Canceled;
+        task_repo.create(&parent).await.unwrap();
+        let mut subtask = Task::new("Still queued");
+        subtask.parent_id = Some(parent.id);
+        task_repo.create(&subtask).await.unwrap();
+
+        let doctor = SwarmDoctor::new(pool, true);
+        let outcomes = |findings: Vec<DoctorFinding>| -> Vec<_> {
+            findings
+                .into_iter()
+                .filter(|f| f.check == DoctorCheck::OrphanedWorkflows)
+                .map(|f| (f.destructive, f.outcome))
+                .collect()
+        };
+        assert_eq!(
+            outcomes(doctor.fix(false).await.unwrap()),
+            vec![(true, RepairOutcome::Skipped)]
+        );
+        assert_eq!(
+            task_repo.get(subtask.id).await.unwrap().unwrap().status,
+            TaskStatus::Pending
+        );
+
+        assert_eq!(
+            outcomes(doctor.fix(true).await.unwrap()),
+            vec![(true, RepairOutcome::Fixed)]
+        );
+        assert_eq!(
+            task_repo.get(subtask.id).await.unwrap().unwrap().status,
+            TaskStatus::Failed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_reports_preexisting_dependency_cycle() {
+        let pool = create_migrated_test_pool().await.unwrap();
+        let task_repo = SqliteTaskRepository::new(pool.clone());
+
+        let a = Task::new("Build the schema");
+        let b = Task::new("Seed the schema");
+        let c = Task::new("Migrate the schema");
+        let unrelated = Task::new("Write the docs");
+        for task in [&a, &b, &c, &unrelated] {
+            task_repo.create(task).await.unwrap();
+        }
+        // The repository refuses cycle-forming edges, so write them directly.
+        for (task, dep) in [
+            (a.id, b.id),
+            (b.id, c.id),
+            (c.id, a.id),
+            (unrelated.id, a.id),
+        ] {
+            sqlx::query("INSERT INTO task_dependencies (task_id, depends_on_id) VALUES (?, ?)")
+                .bind(task.to_string())
+                .bind(dep.to_string())
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let doctor = SwarmDoctor::new(pool, false);
+        let cycles: Vec<_> = doctor
+            .diagnose()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|f| f.check == DoctorCheck::DependencyCycles)
+            .collect();
+        assert_eq!(cycles.len(), 1);
+        for task in [&a, &b, &c] {
+            assert!(cycles[0].detail.contains(&task.id.to_string()));
+        }
+        assert!(!cycles[0].detail.contains(&unrelated.id.to_string()));
+
+        // Cycles are reported, never repaired.
+        let fixed = doctor.fix(true).await.unwrap();
+        assert!(
+            fixed
+                .iter()
+                .filter(|f| f.check == DoctorCheck::DependencyCycles)
+                .all(|f| f.outcome == RepairOutcome::Found)
+        );
+    }
+}
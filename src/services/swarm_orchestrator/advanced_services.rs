@@ -18,16 +18,20 @@ use tokio::sync::RwLock;
 use crate::adapters::mcp::FederationClient;
 use crate::domain::ports::{
     AgentRepository, GoalRepository, MemoryRepository, MergeRequestRepository, OutboxRepository,
-    TaskRepository, TrajectoryRepository, TriggerRuleRepository, WorktreeRepository,
+    SwarmControlRepository, TaskRepository, TrajectoryRepository, TriggerRuleRepository,
+    WorktreeRepository,
 };
+use crate::services::task_router::TaskRouter;
 use crate::services::{
     IntentVerifierService, OvermindService,
     adapter_registry::AdapterRegistry,
     budget_tracker::BudgetTracker,
     command_bus::CommandBus,
     cost_window_service::CostWindowService,
+    escalation_notifier::EscalationNotifier,
     federation::FederationService,
     overseers::OverseerClusterService,
+    result_cache::ResultCache,
 };
 
 /// Optional, progressive-enhancement subsystems. Each field is independently
@@ -52,9 +56,13 @@ where
     pub(crate) outbox_repo: Option<Arc<dyn OutboxRepository>>,
     pub(crate) trigger_rule_repo: Option<Arc<dyn TriggerRuleRepository>>,
     pub(crate) merge_request_repo: Option<Arc<dyn MergeRequestRepository>>,
+    /// Operator pause flag set by `abathur swarm pause`/`resume`.
+    pub(crate) swarm_control_repo: Option<Arc<dyn SwarmControlRepository>>,
     pub(crate) adapter_registry: Option<Arc<AdapterRegistry>>,
     pub(crate) budget_tracker: Option<Arc<BudgetTracker>>,
     pub(crate) cost_window_service: Option<Arc<CostWindowService>>,
+    pub(crate) result_cache: Option<Arc<ResultCache>>,
+    pub(crate) escalation_notifier: Option<Arc<EscalationNotifier>>,
 
     pub(crate) federation_client: Option<Arc<FederationClient>>,
     pub(crate) federation_service: Option<Arc<FederationService>>,
@@ -63,6 +71,9 @@ where
     pub(crate) trajectory_repo: Option<Arc<dyn TrajectoryRepository>>,
     pub(crate) convergence_engine_config:
         Option<crate::domain::models::convergence::ConvergenceEngineConfig>,
+    /// Custom agent-type routing policy; when `None` a keyword router is
+    /// built from `SwarmConfig::task_routing`.
+    pub(crate) task_router: Option<Arc<dyn TaskRouter>>,
 
     // Phantom marker to consume the W generic, which is needed to keep this
     // bundle on the same generics as `SwarmOrchestrator` (so a single
@@ -91,14 +102,18 @@ where
             outbox_repo: None,
             trigger_rule_repo: None,
             merge_request_repo: None,
+            swarm_control_repo: None,
             adapter_registry: None,
             budget_tracker: None,
             cost_window_service: None,
+            result_cache: None,
+            escalation_notifier: None,
             federation_client: None,
             federation_service: None,
             overseer_cluster: None,
             trajectory_repo: None,
             convergence_engine_config: None,
+            task_router: None,
             _marker: std::marker::PhantomData,
         }
     }
@@ -0,0 +1,243 @@
+//! `AgentSlots` — weighted concurrency budget for spawned agents.
+//!
+//! The budget holds `max_agents` slots. Each agent type costs its configured
+//! weight (`[limits.agent_slot_weights]`, default 1), so a build agent
+//! weighted 3 uses as much capacity as three triage agents. Weights are
+//! clamped to the budget so a heavy agent can still run alone.
+//!
+//! A permit bound to a task records it as owned by this process until the
+//! permit drops, so process-local shutdown paths touch only their own tasks
+//! in a shared database.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// Weighted slot budget shared by every agent spawn path.
+#[derive(Debug)]
+pub struct AgentSlots {
+    semaphore: Arc<Semaphore>,
+    capacity: usize,
+    weights: HashMap<String, u32>,
+    active_agents: Arc<AtomicUsize>,
+    owned_tasks: Arc<Mutex<OwnedTasks>>,
+}
+
+/// Tasks bound to a live permit, with the worker running each once attached.
+type OwnedTasks = HashMap<Uuid, Option<JoinHandle<()>>>;
+
+/// Slots held by one running agent; released on drop.
+#[derive(Debug)]
+pub struct AgentSlotPermit {
+    _permit: OwnedSemaphorePermit,
+    active_agents: Arc<AtomicUsize>,
+    owned_tasks: Arc<Mutex<OwnedTasks>>,
+    task_id: Option<Uuid>,
+}
+
+impl AgentSlotPermit {
+    /// Record that this permit's agent runs `task_id`, claimed by this
+    /// process. The task stays owned until the permit drops.
+    pub fn bind_task(&mut self, task_id: Uuid) {
+        self.owned_tasks.lock().unwrap().insert(task_id, None);
+        self.task_id = Some(task_id);
+    }
+}
+
+impl Drop for AgentSlotPermit {
+    fn drop(&mut self) {
+        if let Some(task_id) = self.task_id {
+            self.owned_tasks.lock().unwrap().remove(&task_id);
+        }
+        self.active_agents.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl AgentSlots {
+    pub fn new(capacity: usize, weights: HashMap<String, u32>) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(capacity)),
+            capacity,
+            weights,
+            active_agents: Arc::new(AtomicUsize::new(0)),
+            owned_tasks: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Slots one agent of `agent_type` occupies.
+    pub fn weight(&self, agent_type: &str) -> usize {
+        let weight = self.weights.get(agent_type).copied().unwrap_or(1) as usize;
+        weight.clamp(1, self.capacity.max(1))
+    }
+
+    /// Take the slots for one `agent_type` agent, or `None` if they are not
+    /// all free right now.
+    pub fn try_acquire(&self, agent_type: &str) -> Option<AgentSlotPermit> {
+        let permit = self
+            .semaphore
+            .clone()
+            .try_acquire_many_owned(self.weight(agent_type) as u32)
+            .ok()?;
+        self.active_agents.fetch_add(1, Ordering::Relaxed);
+        Some(AgentSlotPermit {
+            _permit: permit,
+            active_agents: self.active_agents.clone(),
+            owned_tasks: self.owned_tasks.clone(),
+            task_id: None,
+        })
+    }
+
+    /// Whether an `agent_type` agent would fit in the free slots.
+    pub fn has_room_for(&self, agent_type: &str) -> bool {
+        self.available() >= self.weight(agent_type)
+    }
+
+    /// Total slot budget.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Free slots.
+    pub fn available(&self) -> usize {
+        self.semaphore.available_permits()
+    }
+
+    /// Slots held by running agents.
+    pub fn in_use(&self) -> usize {
+        self.capacity.saturating_sub(self.available())
+    }
+
+    /// Number of running agents, regardless of weight.
+    pub fn active_agents(&self) -> usize {
+        self.active_agents.load(Ordering::Relaxed)
+    }
+
+    /// Tasks this process's running agents were spawned for.
+    pub fn owned_tasks(&self) -> Vec<Uuid> {
+        self.owned_tasks.lock().unwrap().keys().copied().collect()
+    }
+
+    /// Record the worker running `task_id` so [`stop_owned`](Self::stop_owned)
+    /// can stop it. Ignored once the task's permit has dropped.
+    pub fn attach_worker(&self, task_id: Uuid, worker: JoinHandle<()>) {
+        if let Some(slot) = self.owned_tasks.lock().unwrap().get_mut(&task_id) {
+            *slot = Some(worker);
+        }
+    }
+
+    /// Abort the workers running this process's tasks and wait for them to
+    /// wind down, so none of them writes to its task afterwards. Returns the
+    /// tasks they were running.
+    pub async fn stop_owned(&self) -> Vec<Uuid> {
+        let (task_ids, workers): (Vec<_>, Vec<_>) = self
+            .owned_tasks
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .map(|(task_id, worker)| (*task_id, worker.take()))
+            .unzip();
+        for worker in workers.into_iter().flatten() {
+            worker.abort();
+            let _ = worker.await;
+        }
+        task_ids
+    }
+
+    /// Wait up to `timeout` for every slot to be free. Slots released while
+    /// waiting are held for the waiter, so no new agent starts in the
+    /// meantime. Returns whether all slots came free.
+    pub async fn wait_idle(&self, timeout: Duration) -> bool {
+        let all = u32::try_from(self.capacity).unwrap_or(u32::MAX);
+        matches!(
+            tokio::time::timeout(timeout, self.semaphore.acquire_many(all)).await,
+            Ok(Ok(_))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heavy_agents_consume_proportional_slots() {
+        let slots = AgentSlots::new(6, HashMap::from([("builder".to_string(), 3)]));
+
+        let build_a = slots.try_acquire("builder").unwrap();
+        let triage = slots.try_acquire("triage").unwrap();
+        assert_eq!(slots.in_use(), 4);
+        assert_eq!(slots.active_agents(), 2);
+
+        // Two free slots: room for triage agents, not another builder.
+        assert!(!slots.has_room_for("builder"));
+        assert!(slots.try_acquire("builder").is_none());
+        let triage_2 = slots.try_acquire("triage").unwrap();
+        assert_eq!(slots.available(), 1);
+
+        drop(build_a);
+        assert_eq!(slots.active_agents(), 2);
+        let build_b = slots.try_acquire("builder").unwrap();
+        assert_eq!(slots.available(), 1);
+
+        drop((triage, triage_2, build_b));
+        assert_eq!(slots.in_use(), 0);
+        assert_eq!(slots.active_agents(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_wait_idle_waits_for_running_agents() {
+        let slots = Arc::new(AgentSlots::new(2, HashMap::new()));
+        let permit = slots.try_acquire("coder").unwrap();
+        assert!(!slots.wait_idle(Duration::from_millis(20)).await);
+
+        let release = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(permit);
+        });
+        assert!(slots.wait_idle(Duration::from_secs(5)).await);
+        release.await.unwrap();
+        assert_eq!(slots.available(), 2);
+    }
+
+    #[test]
+    fn test_bound_tasks_are_owned_until_the_permit_drops() {
+        let slots = AgentSlots::new(4, HashMap::new());
+        let task_id = Uuid::new_v4();
+        let mut bound = slots.try_acquire("coder").unwrap();
+        bound.bind_task(task_id);
+        let _unbound = slots.try_acquire("coder").unwrap();
+        assert_eq!(slots.owned_tasks(), vec![task_id]);
+
+        drop(bound);
+        assert!(slots.owned_tasks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stop_owned_aborts_attached_workers() {
+        let slots = AgentSlots::new(4, HashMap::new());
+        let task_id = Uuid::new_v4();
+        let mut permit = slots.try_acquire("coder").unwrap();
+        permit.bind_task(task_id);
+        let worker = tokio::spawn(async move {
+            let _permit = permit;
+            std::future::pending::<()>().await;
+        });
+        slots.attach_worker(task_id, worker);
+
+        assert_eq!(slots.stop_owned().await, vec![task_id]);
+        assert!(slots.owned_tasks().is_empty());
+        assert_eq!(slots.active_agents(), 0);
+    }
+
+    #[test]
+    fn test_weight_is_clamped_to_capacity() {
+        let slots = AgentSlots::new(2, HashMap::from([("builder".to_string(), 5)]));
+        assert_eq!(slots.weight("builder"), 2);
+        assert!(slots.try_acquire("builder").is_some());
+    }
+}
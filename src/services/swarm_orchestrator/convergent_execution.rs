@@ -41,7 +41,7 @@ use crate::domain::models::intent_verification::{
     GapSeverity, IntentSatisfaction, IntentVerificationResult,
 };
 use crate::domain::models::task::Task;
-use crate::domain::models::{SubstrateConfig, SubstrateRequest};
+use crate::domain::models::{ExecutionParameters, SubstrateConfig, SubstrateRequest};
 use crate::domain::ports::{MemoryRepository, Substrate, TaskRepository, TrajectoryRepository};
 use crate::services::convergence_bridge;
 use crate::services::convergence_engine::{
@@ -143,6 +143,8 @@ pub(super) struct OrchestratorStrategyExecutor {
     /// Max turns to pass to the substrate. Stored on the executor because it
     /// does not vary across iterations of a single convergent run.
     max_turns: u32,
+    /// Task-level sampling overrides (temperature, max tokens, stop sequences).
+    execution_params: Option<ExecutionParameters>,
 }
 
 #[async_trait]
@@ -160,6 +162,9 @@ impl StrategyExecutor for OrchestratorStrategyExecutor {
         if let Some(ref wt) = self.worktree_path {
             config = config.with_working_dir(wt.to_string_lossy().as_ref());
         }
+        if let Some(ref params) = self.execution_params {
+            params.apply_sampling(&mut config);
+        }
         let request = SubstrateRequest::new(
             self.task_id,
             &self.agent_type,
@@ -664,6 +669,28 @@ impl ConvergenceEventSink for EventBusSink {
                     ))
                     .await;
             }
+            ConvergenceDomainEvent::ConvergenceEarlyStop {
+                trajectory_id,
+                iteration,
+                slope,
+                min_slope,
+            } => {
+                self.event_bus
+                    .publish(event_factory::make_event(
+                        EventSeverity::Info,
+                        crate::services::event_bus::EventCategory::Convergence,
+                        self.goal_id,
+                        Some(self.task_id),
+                        EventPayload::ConvergenceEarlyStop {
+                            task_id: self.task_id,
+                            trajectory_id,
+                            iteration,
+                            slope,
+                            min_slope,
+                        },
+                    ))
+                    .await;
+            }
             // All other variants are purely tracing-level observability; fall
             // back to the TracingEventSink semantics by logging here so the
             // orchestrator still gets the pre-PR-1 log lines.
@@ -997,6 +1024,7 @@ where
         let system_prompt = system_prompt.to_string();
         let wt_path = worktree_paths[i].clone();
         let cancellation_token = cancellation_token.clone();
+        let execution_params = task.execution_params.clone();
 
         // Select a strategy for this sample
         let eligible = eligible_strategies(
@@ -1032,9 +1060,12 @@ where
                 return Err(DomainError::ExecutionFailed("cancelled".to_string()));
             }
 
-            let config = SubstrateConfig::default()
+            let mut config = SubstrateConfig::default()
                 .with_max_turns(max_turns)
                 .with_working_dir(&wt_path);
+            if let Some(ref params) = execution_params {
+                params.apply_sampling(&mut config);
+            }
             let request = SubstrateRequest::new(task_id, &agent_type, &system_prompt, &prompt)
                 .with_config(config);
 
@@ -1291,6 +1322,7 @@ where
         system_prompt: system_prompt.to_string(),
         task_id: task.id,
         max_turns,
+        execution_params: task.execution_params.clone(),
     });
 
     let effects = Arc::new(OrchestratorStrategyEffects {
@@ -2156,6 +2188,13 @@ mod tests {
             self.tasks.lock().unwrap().insert(task.id, task.clone());
             Ok(())
         }
+        async fn bulk_insert(&self, tasks: &[Task]) -> DomainResult<()> {
+            let mut stored = self.tasks.lock().unwrap();
+            for task in tasks {
+                stored.insert(task.id, task.clone());
+            }
+            Ok(())
+        }
         async fn get(&self, id: Uuid) -> DomainResult<Option<Task>> {
             Ok(self.tasks.lock().unwrap().get(&id).cloned())
         }
@@ -2172,6 +2211,13 @@ mod tests {
         ) -> DomainResult<Vec<Task>> {
             Ok(vec![])
         }
+        async fn search_by_text(
+            &self,
+            _query: &str,
+            _filter: crate::domain::ports::task_repository::TaskFilter,
+        ) -> DomainResult<Vec<Task>> {
+            Ok(vec![])
+        }
         async fn list_by_status(
             &self,
             _status: crate::domain::models::TaskStatus,
@@ -2184,6 +2230,11 @@ mod tests {
         async fn get_ready_tasks(&self, _limit: usize) -> DomainResult<Vec<Task>> {
             Ok(vec![])
         }
+        async fn ready_task_keys(
+            &self,
+        ) -> DomainResult<Vec<crate::domain::models::ReadyTaskKey>> {
+            Ok(vec![])
+        }
         async fn get_by_agent(&self, _agent_type: &str) -> DomainResult<Vec<Task>> {
             Ok(vec![])
         }
@@ -2205,6 +2256,9 @@ mod tests {
         async fn get_by_idempotency_key(&self, _key: &str) -> DomainResult<Option<Task>> {
             Ok(None)
         }
+        async fn count_by_idempotency_prefix(&self, _prefix: &str) -> DomainResult<u64> {
+            Ok(0)
+        }
         async fn list_by_source(&self, _source_type: &str) -> DomainResult<Vec<Task>> {
             Ok(vec![])
         }
@@ -2214,6 +2268,12 @@ mod tests {
         {
             Ok(std::collections::HashMap::new())
         }
+        async fn velocity_since(
+            &self,
+            _since: chrono::DateTime<chrono::Utc>,
+        ) -> DomainResult<crate::domain::models::TaskVelocity> {
+            Ok(Default::default())
+        }
         async fn claim_task_atomic(
             &self,
             _task_id: Uuid,
@@ -2221,6 +2281,13 @@ mod tests {
         ) -> DomainResult<Option<Task>> {
             Ok(None)
         }
+        async fn claim_next_ready(
+            &self,
+            _candidates: &[Uuid],
+            _agent_type: &str,
+        ) -> DomainResult<Option<Task>> {
+            Ok(None)
+        }
         async fn get_parent_id(&self, _task_id: Uuid) -> DomainResult<Option<Uuid>> {
             Ok(None)
         }
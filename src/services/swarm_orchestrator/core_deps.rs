@@ -8,6 +8,7 @@
 
 use std::sync::Arc;
 
+use crate::adapters::substrates::{RoutingPolicy, SubstrateRegistry};
 use crate::domain::ports::{
     AgentRepository, GoalRepository, Substrate, TaskRepository, WorktreeRepository,
 };
@@ -30,5 +31,11 @@ where
     pub(crate) worktree_repo: Arc<W>,
     pub(crate) agent_repo: Arc<A>,
     pub(crate) substrate: Arc<dyn Substrate>,
+    /// Substrates to fail over to, in order, while `substrate`'s circuit
+    /// breaker is open.
+    pub(crate) failover_substrates: Vec<Arc<dyn Substrate>>,
+    /// When set, spawns are spread over the registry's substrates under the
+    /// policy instead of preferring `substrate`.
+    pub(crate) substrate_routing: Option<(Arc<SubstrateRegistry>, RoutingPolicy)>,
     pub(crate) config: SwarmConfig,
 }
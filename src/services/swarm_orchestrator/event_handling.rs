@@ -95,12 +95,16 @@ where
         let task_service = Arc::new(
             TaskService::new(self.core_deps.task_repo.clone())
                 .with_event_bus(self.subsystem_services.event_bus.clone())
-                .with_default_execution_mode(self.core_deps.config.default_execution_mode.clone()),
+                .with_default_execution_mode(self.core_deps.config.default_execution_mode.clone())
+                .with_router(self.task_router()),
         );
         let goal_service = Arc::new(GoalService::new(self.core_deps.goal_repo.clone()));
 
         if let Some(ref memory_repo) = self.advanced_services.memory_repo {
-            let memory_service = Arc::new(MemoryService::new(memory_repo.clone()));
+            let memory_service = Arc::new(
+                MemoryService::new(memory_repo.clone())
+                    .with_memory_config(&self.core_deps.config.memory),
+            );
             let maintenance_service =
                 Arc::new(MemoryMaintenanceService::from_memory_service(memory_service));
             let mut bus = CommandBus::new(
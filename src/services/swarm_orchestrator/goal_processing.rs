@@ -10,21 +10,26 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 
 use crate::domain::errors::DomainResult;
-use crate::domain::models::{Task, TaskStatus};
+use crate::domain::models::{ReadyTaskKey, Task, TaskStatus};
 use crate::domain::ports::{
     AgentRepository, GoalRepository, MemoryRepository, TaskRepository, WorktreeRepository,
 };
-use crate::services::{AuditAction, AuditActor, AuditCategory, AuditLevel, CircuitScope};
+use crate::services::{
+    AuditAction, AuditActor, AuditCategory, AuditLevel, CircuitScope, ModelRouter,
+    ModelRoutingConfig,
+};
 
 use crate::domain::models::workflow_state::WorkflowState;
 use crate::domain::models::workflow_template::WorkflowTemplate;
 
 use super::SwarmOrchestrator;
 use super::agent_prep::AgentPreparationService;
+use super::agent_slots::AgentSlotPermit;
 use super::exec_mode::ExecutionModeResolverService;
+use super::helpers::resolve_workspace_kind;
 use super::task_context::TaskContextService;
 use super::task_exec::{ExecutionConfig, TaskExecutionParams, execute_task};
-use super::types::SwarmEvent;
+use super::types::{OrchestratorStatus, SpawnDecision, SpawnGate, SwarmEvent};
 use super::workspace::WorkspaceProvisioningService;
 
 /// Re-emit `WorkflowGateRejected` for tasks that were rejected via MCP.
@@ -90,11 +95,7 @@ where
         event_tx: &mpsc::Sender<SwarmEvent>,
     ) -> DomainResult<()> {
         // Get ready tasks and spawn agents for them
-        let ready_tasks = self
-            .core_deps
-            .task_repo
-            .get_ready_tasks(self.core_deps.config.max_agents)
-            .await?;
+        let ready_tasks = self.ready_tasks_to_spawn().await?;
 
         for task in &ready_tasks {
             self.spawn_task_agent(task, event_tx).await?;
@@ -109,11 +110,7 @@ where
         event_tx: &mpsc::Sender<SwarmEvent>,
         already_spawned: &std::collections::HashSet<uuid::Uuid>,
     ) -> DomainResult<()> {
-        let ready_tasks = self
-            .core_deps
-            .task_repo
-            .get_ready_tasks(self.core_deps.config.max_agents)
-            .await?;
+        let ready_tasks = self.ready_tasks_to_spawn().await?;
 
         for task in &ready_tasks {
             if !already_spawned.contains(&task.id) {
@@ -124,26 +121,53 @@ where
         Ok(())
     }
 
-    /// Spawn an agent for a ready task.
+    /// Ready tasks to attempt this cycle, one per agent slot.
     ///
-    /// Runs the registered pre-spawn middleware chain (routing, circuit
-    /// breaker, quiet-window, budget gates, guardrails, etc.); on `Continue`
-    /// acquires an agent permit and invokes the substrate. On `Skip` returns
-    /// without spawning — the task stays `Ready` for the next cycle.
-    pub(super) async fn spawn_task_agent(
-        &self,
-        task: &Task,
-        event_tx: &mpsc::Sender<SwarmEvent>,
-    ) -> DomainResult<()> {
-        use super::middleware::{PreSpawnContext, PreSpawnDecision};
+    /// Strict priority order unless `priority_aging_coefficient` is set, in
+    /// which case the ready set is re-ranked by [`rank_by_aged_priority`],
+    /// or `exploration_epsilon` is set, in which case [`select_ready_tasks`]
+    /// samples from it. Both work on the scheduling keys of every ready task:
+    /// aging exists to lift old tasks stuck behind a deep backlog, and
+    /// exploration to give low-priority work a chance, so neither may be
+    /// limited to the head of the priority order.
+    async fn ready_tasks_to_spawn(&self) -> DomainResult<Vec<Task>> {
+        let slots = self.core_deps.config.max_agents;
+        let epsilon = self.core_deps.config.exploration_epsilon;
+        let aging = self.core_deps.config.priority_aging_coefficient;
+        if epsilon <= 0.0 && aging <= 0.0 {
+            return self.core_deps.task_repo.get_ready_tasks(slots).await;
+        }
+
+        let mut keys = self.core_deps.task_repo.ready_task_keys().await?;
+        if aging > 0.0 {
+            rank_by_aged_priority(&mut keys, |k| *k, chrono::Utc::now(), aging);
+        }
+        let chosen = select_ready_tasks(keys, slots, epsilon, &mut rand::thread_rng());
+        self.load_ready_tasks(chosen).await
+    }
 
-        // Build the pre-spawn context. Repos are coerced to trait objects so
-        // middleware can operate without being generic over the orchestrator.
+    /// Load the tasks behind `keys`, in order, skipping any that stopped
+    /// being Ready since the keys were read.
+    async fn load_ready_tasks(&self, keys: Vec<ReadyTaskKey>) -> DomainResult<Vec<Task>> {
+        let mut tasks = Vec::with_capacity(keys.len());
+        for key in keys {
+            if let Some(task) = self.core_deps.task_repo.get(key.id).await?
+                && task.status == TaskStatus::Ready
+            {
+                tasks.push(task);
+            }
+        }
+        Ok(tasks)
+    }
+
+    /// Build the pre-spawn context. Repos are coerced to trait objects so
+    /// middleware can operate without being generic over the orchestrator.
+    fn pre_spawn_context(&self, task: &Task) -> super::middleware::PreSpawnContext {
         let task_repo: Arc<dyn crate::domain::ports::TaskRepository> = self.core_deps.task_repo.clone();
         let agent_repo: Arc<dyn crate::domain::ports::AgentRepository> = self.core_deps.agent_repo.clone();
         let goal_repo: Arc<dyn crate::domain::ports::GoalRepository> = self.core_deps.goal_repo.clone();
 
-        let mut ctx = PreSpawnContext {
+        super::middleware::PreSpawnContext {
             task: task.clone(),
             agent_type: None,
             task_repo,
@@ -152,12 +176,219 @@ where
             audit_log: self.subsystem_services.audit_log.clone(),
             circuit_breaker: self.subsystem_services.circuit_breaker.clone(),
             guardrails: self.subsystem_services.guardrails.clone(),
+            event_bus: self.subsystem_services.event_bus.clone(),
             cost_window_service: self.advanced_services.cost_window_service.clone(),
             budget_tracker: self.advanced_services.budget_tracker.clone(),
-            agent_semaphore: self.runtime_state.agent_semaphore.clone(),
+            agent_slots: self.runtime_state.agent_slots.clone(),
             max_agents: self.core_deps.config.max_agents,
+            total_tokens: self.runtime_state.total_tokens(),
             federation_priority_bumps: 0,
+            dry_run: false,
+        }
+    }
+
+    /// Explain whether `spawn_task_agent` would spawn `task` right now.
+    ///
+    /// Evaluates the same gates in the same order — Ready status, the
+    /// pre-spawn middleware chain, then agent permits — with the chain in
+    /// dry-run mode so no routing is persisted and no audit entries are
+    /// written. Reports the first gate that blocks.
+    pub async fn spawn_decision(&self, task: &Task) -> DomainResult<SpawnDecision> {
+        let mut decision = SpawnDecision {
+            task_id: task.id,
+            agent_type: None,
+            blocked_by: None,
+            middleware: None,
+            reason: "would spawn".to_string(),
+        };
+
+        if task.status != TaskStatus::Ready {
+            decision.blocked_by = Some(SpawnGate::NotReady);
+            decision.reason = format!("task is {}", task.status.as_str());
+            return Ok(decision);
+        }
+
+        let mut ctx = self.pre_spawn_context(task);
+        ctx.dry_run = true;
+        let skipped = {
+            let chain = self.middleware.pre_spawn_chain.read().await;
+            chain.evaluate(&mut ctx).await?
         };
+        decision.agent_type = ctx.agent_type.clone();
+
+        if let Some((middleware, reason)) = skipped {
+            decision.blocked_by = Some(SpawnGate::from_middleware(middleware));
+            decision.middleware = Some(middleware.to_string());
+            decision.reason = reason;
+        } else {
+            let slots = &self.runtime_state.agent_slots;
+            let agent_type = decision.agent_type.as_deref().unwrap_or_default();
+            if !slots.has_room_for(agent_type) {
+                decision.blocked_by = Some(SpawnGate::AgentCapacity);
+                decision.reason = format!(
+                    "needs {} agent slot(s), {} of {} free",
+                    slots.weight(agent_type),
+                    slots.available(),
+                    slots.capacity()
+                );
+            }
+        }
+
+        Ok(decision)
+    }
+
+    /// Explain spawn decisions for up to `limit` Ready tasks.
+    ///
+    /// Registers the built-in middleware first when the chain is empty (i.e.
+    /// outside `run()`), so the report reflects the gates a running swarm
+    /// would apply.
+    pub async fn explain_ready_tasks(&self, limit: usize) -> DomainResult<Vec<SpawnDecision>> {
+        if self.middleware.pre_spawn_chain.read().await.is_empty() {
+            self.register_builtin_middleware().await;
+        }
+
+        let ready_tasks = self.core_deps.task_repo.get_ready_tasks(limit).await?;
+        let mut decisions = Vec::with_capacity(ready_tasks.len());
+        for task in &ready_tasks {
+            decisions.push(self.spawn_decision(task).await?);
+        }
+        Ok(decisions)
+    }
+
+    /// Record the substrate this attempt runs on and the escalation-ladder
+    /// rung its model is on, so the task shows where it actually ran and how
+    /// far its model has been escalated.
+    async fn record_attempt_placement(&self, mut claimed: Task, agent_type: &str, substrate: &str) {
+        let mut changed = claimed.substrate() != Some(substrate);
+        claimed.set_substrate(substrate);
+
+        if let Some((position, model)) = self
+            .core_deps
+            .config
+            .model_escalation
+            .rung(agent_type, claimed.retry_count)
+            && claimed.model_ladder_position != Some(position)
+        {
+            if position > 0 {
+                tracing::info!(
+                    task_id = %claimed.id,
+                    %agent_type,
+                    retry_count = claimed.retry_count,
+                    position,
+                    %model,
+                    "Escalating model for retry"
+                );
+            }
+            claimed.model_ladder_position = Some(position);
+            changed = true;
+        }
+
+        if !changed {
+            return;
+        }
+        if let Err(e) = self.core_deps.task_repo.update(&claimed).await {
+            tracing::warn!(
+                task_id = %claimed.id,
+                "Failed to record attempt substrate and model ladder position: {}",
+                e
+            );
+        }
+    }
+
+    /// Pick the substrate for a spawn: under the configured routing policy
+    /// when there is one, otherwise the primary, or the first configured
+    /// failover whose circuit breaker is not open.
+    pub(super) async fn select_substrate(&self) -> Option<Arc<dyn crate::domain::ports::Substrate>> {
+        if let Some((registry, policy)) = &self.core_deps.substrate_routing {
+            return registry
+                .select(*policy, &self.subsystem_services.circuit_breaker)
+                .await;
+        }
+        let candidates: Vec<_> = std::iter::once(self.core_deps.substrate.clone())
+            .chain(self.core_deps.failover_substrates.iter().cloned())
+            .collect();
+        let selected = crate::adapters::substrates::SubstrateRegistry::select_healthy(
+            &candidates,
+            &self.subsystem_services.circuit_breaker,
+        )
+        .await?;
+        if selected.name() != self.core_deps.substrate.name() {
+            tracing::warn!(
+                primary = self.core_deps.substrate.name(),
+                failover = selected.name(),
+                "Primary substrate circuit is open; failing over"
+            );
+        }
+        Some(selected)
+    }
+
+    /// Spawn an agent for a ready task.
+    ///
+    /// Runs the registered pre-spawn middleware chain (routing, circuit
+    /// breaker, quiet-window, budget gates, guardrails, etc.); on `Continue`
+    /// acquires an agent permit, claims the task and invokes the substrate.
+    /// On `Skip` returns without spawning — the task stays `Ready` for the
+    /// next cycle.
+    pub(super) async fn spawn_task_agent(
+        &self,
+        task: &Task,
+        event_tx: &mpsc::Sender<SwarmEvent>,
+    ) -> DomainResult<()> {
+        let Some(agent_type) = self.admit_spawn(task).await? else {
+            return Ok(());
+        };
+
+        // With every substrate's circuit open there is nowhere to run; leave
+        // the task Ready so a later cycle can pick it up once one recovers.
+        let Some(substrate) = self.select_substrate().await else {
+            tracing::debug!(
+                task_id = %task.id,
+                "spawn_task_agent: all substrate circuits open, skipping spawn"
+            );
+            return Ok(());
+        };
+
+        // Try to acquire this agent type's slots
+        let Some(permit) = self.runtime_state.agent_slots.try_acquire(&agent_type) else {
+            return Ok(());
+        };
+
+        // Atomically claim the task (Ready→Running) BEFORE spawning.
+        // This prevents TOCTOU races where multiple poll cycles see the
+        // same Ready task and spawn duplicate agents.
+        match self.core_deps.task_repo.claim_task_atomic(task.id, &agent_type).await {
+            Ok(None) => {
+                // Task was already claimed by another cycle — nothing to do
+                tracing::debug!("Task {} already claimed, skipping spawn", task.id);
+                Ok(())
+            }
+            Ok(Some(claimed)) => {
+                self.launch_claimed_task(task, claimed, agent_type, substrate, permit, event_tx)
+                    .await
+            }
+            Err(e) => {
+                tracing::warn!("Failed to atomically claim task {}: {}", task.id, e);
+                Ok(())
+            }
+        }
+    }
+
+    /// Run the pre-spawn middleware chain for `task` and return the agent
+    /// type it was routed to, or `None` when the swarm is draining or a
+    /// middleware skipped the spawn. Nothing is claimed here, so a refused
+    /// task simply stays `Ready`.
+    pub(super) async fn admit_spawn(&self, task: &Task) -> DomainResult<Option<String>> {
+        use super::middleware::PreSpawnDecision;
+
+        // Draining or stopped: no new agents.
+        if matches!(
+            self.runtime_state.status().await,
+            OrchestratorStatus::ShuttingDown | OrchestratorStatus::Stopped
+        ) {
+            return Ok(None);
+        }
+
+        let mut ctx = self.pre_spawn_context(task);
 
         // Run the registered pre-spawn middleware. Each middleware may
         // short-circuit with a Skip decision (logged below) or enrich the
@@ -173,12 +404,12 @@ where
                 %reason,
                 "spawn_task_agent: pre-spawn chain requested skip"
             );
-            return Ok(());
+            return Ok(None);
         }
 
         // Routing middleware is required to have populated agent_type before
         // we reach substrate invocation. If it didn't, the chain is broken.
-        let agent_type = ctx.agent_type.clone().ok_or_else(|| {
+        let agent_type = ctx.agent_type.ok_or_else(|| {
             crate::domain::errors::DomainError::ConfigError {
                 key: "RouteTaskMiddleware".to_string(),
                 reason: "pre-spawn chain completed without resolving an agent_type — \
@@ -186,114 +417,226 @@ where
                     .to_string(),
             }
         })?;
+        Ok(Some(agent_type))
+    }
 
+    /// Start the agent for `task`, which this process has just claimed;
+    /// `claimed` is the Running row and `permit` holds `agent_type`'s slots.
+    pub(super) async fn launch_claimed_task(
+        &self,
+        task: &Task,
+        claimed: Task,
+        agent_type: String,
+        substrate: Arc<dyn crate::domain::ports::Substrate>,
+        mut permit: AgentSlotPermit,
+        event_tx: &mpsc::Sender<SwarmEvent>,
+    ) -> DomainResult<()> {
         // Circuit-breaker scope is needed later for recording success/failure
         // outcomes on the spawned task. The gate check already ran in the
         // pre-spawn chain; this just recreates the scope value.
         let scope = CircuitScope::agent(&agent_type);
         let agent_unique_id = task.id.to_string();
 
-        // Try to acquire agent permit
-        if let Ok(permit) = self.runtime_state.agent_semaphore.clone().try_acquire_owned() {
-            // Atomically claim the task (Ready→Running) BEFORE spawning.
-            // This prevents TOCTOU races where multiple poll cycles see the
-            // same Ready task and spawn duplicate agents.
-            match self.core_deps.task_repo.claim_task_atomic(task.id, &agent_type).await {
-                Ok(None) => {
-                    // Task was already claimed by another cycle — nothing to do
-                    tracing::debug!("Task {} already claimed, skipping spawn", task.id);
-                    drop(permit);
-                    return Ok(());
-                }
-                Ok(Some(_)) => {
-                    // Register agent spawn with guardrails using unique task_id
-                    self.subsystem_services.guardrails.register_agent_spawn(&agent_unique_id).await;
+        permit.bind_task(task.id);
+        self.record_attempt_placement(claimed, &agent_type, substrate.name()).await;
 
-                    // Successfully claimed — publish event and continue to spawn
-                    self.subsystem_services.event_bus
-                        .publish(crate::services::event_factory::task_event(
-                            crate::services::event_bus::EventSeverity::Info,
-                            None,
-                            task.id,
-                            crate::services::event_bus::EventPayload::TaskClaimed {
-                                task_id: task.id,
-                                agent_type: agent_type.clone(),
-                            },
-                        ))
-                        .await;
+        // Register agent spawn with guardrails using unique task_id
+        self.subsystem_services.guardrails.register_agent_spawn(&agent_type, &agent_unique_id).await;
 
-                    // Workflow stays in Pending state — the Overmind decides
-                    // whether to workflow_advance (single subtask) or
-                    // workflow_fan_out (parallel slices) for the first phase.
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to atomically claim task {}: {}", task.id, e);
-                    drop(permit);
-                    return Ok(());
-                }
-            }
+        // Successfully claimed — publish event and continue to spawn
+        self.subsystem_services.event_bus
+            .publish(crate::services::event_factory::task_event(
+                crate::services::event_bus::EventSeverity::Info,
+                None,
+                task.id,
+                crate::services::event_bus::EventPayload::TaskClaimed {
+                    task_id: task.id,
+                    agent_type: agent_type.clone(),
+                },
+            ))
+            .await;
 
-            let system_prompt = self.get_agent_system_prompt(&agent_type).await;
-
-            // Resolve agent template metadata (capabilities, CLI tools,
-            // read-only role) via AgentPreparationService.
-            let agent_repo_dyn: Arc<dyn crate::domain::ports::AgentRepository> =
-                self.core_deps.agent_repo.clone();
-            let agent_prep = AgentPreparationService::new(agent_repo_dyn);
-            let agent_meta = agent_prep.prepare_agent(&agent_type).await?;
-            let template_version = agent_meta.version;
-            let agent_can_write = agent_meta.can_write;
-            let template_max_turns = agent_meta.max_turns;
-            let is_read_only_role = agent_meta.is_read_only_role;
-
-            // Register agent capabilities with A2A gateway if configured
-            if self.core_deps.config.mcp_servers.a2a_gateway.is_some()
-                && let Err(e) = self
-                    .register_agent_capabilities(&agent_type, agent_meta.capabilities.clone())
-                    .await
-            {
-                tracing::warn!(
-                    "Failed to register agent '{}' capabilities: {}",
-                    agent_type,
-                    e
+        // Workflow stays in Pending state — the Overmind decides
+        // whether to workflow_advance (single subtask) or
+        // workflow_fan_out (parallel slices) for the first phase.
+
+        let system_prompt = self.get_agent_system_prompt(&agent_type).await;
+
+        // Resolve agent template metadata (capabilities, CLI tools,
+        // read-only role) via AgentPreparationService.
+        let agent_repo_dyn: Arc<dyn crate::domain::ports::AgentRepository> =
+            self.core_deps.agent_repo.clone();
+        let agent_prep = AgentPreparationService::new(agent_repo_dyn);
+        let agent_meta = agent_prep.prepare_agent(&agent_type).await?;
+        let template_version = agent_meta.version;
+        let agent_can_write = agent_meta.can_write;
+        let template_max_turns = agent_meta.max_turns;
+        let is_read_only_role = agent_meta.is_read_only_role;
+
+        // Register agent capabilities with A2A gateway if configured
+        if self.core_deps.config.mcp_servers.a2a_gateway.is_some()
+            && let Err(e) = self
+                .register_agent_capabilities(&agent_type, agent_meta.capabilities.clone())
+                .await
+        {
+            tracing::warn!(
+                "Failed to register agent '{}' capabilities: {}",
+                agent_type,
+                e
+            );
+        }
+
+        // Publish TaskSpawned via EventBus (bridge forwards to event_tx)
+        self.subsystem_services.event_bus
+            .publish(crate::services::event_factory::task_event(
+                crate::services::event_bus::EventSeverity::Info,
+                None,
+                task.id,
+                crate::services::event_bus::EventPayload::TaskSpawned {
+                    task_id: task.id,
+                    task_title: task.title.clone(),
+                    agent_type: Some(agent_type.clone()),
+                },
+            ))
+            .await;
+
+        // Resolve workflow template for this task to determine workspace kind and
+        // output delivery mode. SwarmConfig.workflow_template is populated at
+        // startup from the resolved config; see `cli::commands::swarm`.
+        // If it is missing here, the orchestrator was misconfigured — fail the
+        // task with a structured event rather than crashing the whole swarm.
+        let task_workflow = match self.core_deps.config.workflow_template.clone() {
+            Some(wf) => wf,
+            None => {
+                let error_msg =
+                    "workflow_template was not resolved at swarm startup".to_string();
+
+                tracing::error!(
+                    task_id = %task.id,
+                    "{}",
+                    error_msg,
                 );
-            }
 
-            // Publish TaskSpawned via EventBus (bridge forwards to event_tx)
-            self.subsystem_services.event_bus
-                .publish(crate::services::event_factory::task_event(
-                    crate::services::event_bus::EventSeverity::Info,
+                if let Ok(Some(mut t)) = self.core_deps.task_repo.get(task.id).await {
+                    if !t.status.is_terminal() {
+                        let _ = t.transition_to(TaskStatus::Failed);
+                    }
+                    let _ = self.core_deps.task_repo.update(&t).await;
+                }
+
+                self.subsystem_services.audit_log.log(
+                    crate::services::AuditEntry::new(
+                        AuditLevel::Error,
+                        AuditCategory::Task,
+                        AuditAction::TaskFailed,
+                        AuditActor::System,
+                        format!(
+                            "Task {} failed: {}",
+                            task.id, error_msg,
+                        ),
+                    )
+                    .with_entity(task.id, "task"),
+                ).await;
+
+                self.subsystem_services.event_bus.publish(crate::services::event_factory::task_event(
+                    crate::services::event_bus::EventSeverity::Error,
                     None,
                     task.id,
-                    crate::services::event_bus::EventPayload::TaskSpawned {
+                    crate::services::event_bus::EventPayload::TaskFailed {
                         task_id: task.id,
-                        task_title: task.title.clone(),
-                        agent_type: Some(agent_type.clone()),
+                        error: error_msg,
+                        retry_count: 0,
                     },
-                ))
-                .await;
+                )).await;
+
+                self.subsystem_services.guardrails.register_agent_end(&agent_unique_id).await;
+                drop(permit);
+                return Ok(());
+            }
+        };
+        // The task's workspace mode (chosen at submit time) can override
+        // the workflow's kind: ephemeral tasks run in the repo root.
+        let task_workspace_kind = resolve_workspace_kind(
+            task.workspace_mode,
+            task_workflow.workspace_kind,
+            task.parent_id.is_some(),
+            &agent_meta,
+        );
+        let task_output_delivery = task_workflow.output_delivery.clone();
+
+        // Provision workspace based on workflow's WorkspaceKind.
+        // WorkspaceKind::Worktree → git worktree (existing behaviour)
+        // WorkspaceKind::TempDir  → plain temp directory (no git)
+        // WorkspaceKind::None     → no workspace (read-only agents)
+        let worktree_path = self
+            .provision_workspace_for_task(task.id, task_workspace_kind)
+            .await;
+
+        // Write per-worktree agent config files (CLAUDE.md, settings.json)
+        // via WorkspaceProvisioningService. The MCP servers block is left
+        // out of settings.json — the orchestrator provides MCP via
+        // --mcp-config with absolute paths instead.
+        if let Some(ref wt_path) = worktree_path {
+            WorkspaceProvisioningService::new().write_agent_config(wt_path);
+        }
 
-            // Resolve workflow template for this task to determine workspace kind and
-            // output delivery mode. SwarmConfig.workflow_template is populated at
-            // startup from the resolved config; see `cli::commands::swarm`.
-            // If it is missing here, the orchestrator was misconfigured — fail the
-            // task with a structured event rather than crashing the whole swarm.
-            let task_workflow = match self.core_deps.config.workflow_template.clone() {
-                Some(wf) => wf,
-                None => {
-                    let error_msg =
-                        "workflow_template was not resolved at swarm startup".to_string();
-
-                    tracing::error!(
+        // Load goal/memory/intent-gap context and assemble the final task
+        // description via TaskContextService.
+        let context_svc =
+            TaskContextService::new(self.core_deps.goal_repo.clone(), self.advanced_services.memory_repo.clone())
+                .with_memory_strategy(self.core_deps.config.memory_retrieval.strategy_for(&agent_type).clone())
+                .with_memory_reinforcement(self.core_deps.config.memory_retrieval.reinforcement_limit())
+                .with_memory_config(self.core_deps.config.memory.clone())
+                .with_task_repo(self.core_deps.task_repo.clone());
+        let mut task_context = context_svc.load_task_context(task).await?;
+
+        // Trim optional context to the selected substrate's configured
+        // window; after a failover that is not the primary's. A task whose
+        // system prompt and description alone overflow the window can
+        // never run, so it is failed up front.
+        if let Some(&max_context_tokens) =
+            self.core_deps.config.max_context_tokens.get(substrate.name())
+        {
+            match task_context.fit_to_window(task, &system_prompt, max_context_tokens) {
+                Ok(dropped_sections) if !dropped_sections.is_empty() => {
+                    let estimated_tokens = crate::services::estimate_tokens(&system_prompt)
+                        + crate::services::estimate_tokens(&task_context.combined_description);
+                    tracing::info!(
                         task_id = %task.id,
-                        "{}",
-                        error_msg,
+                        ?dropped_sections,
+                        estimated_tokens,
+                        max_context_tokens,
+                        "Trimmed task context to fit the substrate context window"
                     );
+                    self.subsystem_services.event_bus
+                        .publish(crate::services::event_factory::task_event(
+                            crate::services::event_bus::EventSeverity::Warning,
+                            None,
+                            task.id,
+                            crate::services::event_bus::EventPayload::PromptTruncated {
+                                task_id: task.id,
+                                dropped_sections,
+                                estimated_tokens: estimated_tokens as u64,
+                                max_context_tokens: max_context_tokens as u64,
+                            },
+                        ))
+                        .await;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    let error_msg = e.to_string();
+                    tracing::error!(task_id = %task.id, "{}", error_msg);
 
+                    // Retrying would assemble the same oversized prompt,
+                    // so spend the retry budget up front: the task stays
+                    // Failed until someone splits or rewrites it.
+                    let mut retry_count = task.max_retries;
                     if let Ok(Some(mut t)) = self.core_deps.task_repo.get(task.id).await {
                         if !t.status.is_terminal() {
                             let _ = t.transition_to(TaskStatus::Failed);
                         }
+                        t.retry_count = t.retry_count.max(t.max_retries);
+                        retry_count = t.retry_count;
                         let _ = self.core_deps.task_repo.update(&t).await;
                     }
 
@@ -303,10 +646,7 @@ where
                             AuditCategory::Task,
                             AuditAction::TaskFailed,
                             AuditActor::System,
-                            format!(
-                                "Task {} failed: {}",
-                                task.id, error_msg,
-                            ),
+                            format!("Task {} failed: {}", task.id, error_msg),
                         )
                         .with_entity(task.id, "task"),
                     ).await;
@@ -318,7 +658,7 @@ where
                         crate::services::event_bus::EventPayload::TaskFailed {
                             task_id: task.id,
                             error: error_msg,
-                            retry_count: 0,
+                            retry_count,
                         },
                     )).await;
 
@@ -326,206 +666,192 @@ where
                     drop(permit);
                     return Ok(());
                 }
-            };
-            let task_workspace_kind = task_workflow.workspace_kind;
-            let task_output_delivery = task_workflow.output_delivery.clone();
-
-            // Provision workspace based on workflow's WorkspaceKind.
-            // WorkspaceKind::Worktree → git worktree (existing behaviour)
-            // WorkspaceKind::TempDir  → plain temp directory (no git)
-            // WorkspaceKind::None     → no workspace (read-only agents)
-            let worktree_path = self
-                .provision_workspace_for_task(task.id, task_workspace_kind)
-                .await;
-
-            // Write per-worktree agent config files (CLAUDE.md, settings.json)
-            // via WorkspaceProvisioningService. The MCP servers block is left
-            // out of settings.json — the orchestrator provides MCP via
-            // --mcp-config with absolute paths instead.
-            if let Some(ref wt_path) = worktree_path {
-                WorkspaceProvisioningService::new().write_agent_config(wt_path);
-            }
-
-            // Load goal/memory/intent-gap context and assemble the final task
-            // description via TaskContextService.
-            let context_svc =
-                TaskContextService::new(self.core_deps.goal_repo.clone(), self.advanced_services.memory_repo.clone());
-            let task_context = context_svc.load_task_context(task).await?;
-            if let Some(ref goal_ctx) = task_context.goal_context {
-                // Preserve audit-log behaviour for goal-context loading.
-                // Count the goals informally by checking for the marker.
-                let _ = goal_ctx; // The goals count is no longer separately tracked.
-                self.subsystem_services.audit_log
-                    .info(
-                        AuditCategory::Goal,
-                        AuditAction::GoalEvaluated,
-                        format!("Task {} received guidance from relevant goal(s)", task.id),
-                    )
-                    .await;
-            }
-            let task_description = task_context.combined_description.clone();
-
-            // Spawn task execution
-            let task_id = task.id;
-
-            // Resolve effective execution mode (Direct vs Convergent) via
-            // ExecutionModeResolverService. The resolver applies the runtime
-            // upgrade rule: stored Direct → Convergent when convergence is
-            // enabled AND the agent is write-capable AND non-read-only.
-            let mode_resolver =
-                ExecutionModeResolverService::new(self.core_deps.config.convergence_enabled);
-            let (effective_mode, is_convergent) =
-                mode_resolver.resolve_mode(task.execution_mode.clone(), &agent_meta);
-            if effective_mode != task.execution_mode {
-                tracing::info!(
-                    task_id = %task_id,
-                    %agent_type,
-                    stored_mode = ?task.execution_mode,
-                    effective_mode = ?effective_mode,
-                    "Upgrading execution mode Direct -> Convergent (write-capable, non-read-only agent)"
-                );
             }
-
+        }
+        if let Some(ref goal_ctx) = task_context.goal_context {
+            // Preserve audit-log behaviour for goal-context loading.
+            // Count the goals informally by checking for the marker.
+            let _ = goal_ctx; // The goals count is no longer separately tracked.
+            self.subsystem_services.audit_log
+                .info(
+                    AuditCategory::Goal,
+                    AuditAction::GoalEvaluated,
+                    format!("Task {} received guidance from relevant goal(s)", task.id),
+                )
+                .await;
+        }
+        let task_description = task_context.combined_description.clone();
+
+        // Spawn task execution
+        let task_id = task.id;
+
+        // Resolve effective execution mode (Direct vs Convergent) via
+        // ExecutionModeResolverService. The resolver applies the runtime
+        // upgrade rule: stored Direct → Convergent when convergence is
+        // enabled AND the agent is write-capable AND non-read-only.
+        let mode_resolver =
+            ExecutionModeResolverService::new(self.core_deps.config.convergence_enabled);
+        let (effective_mode, is_convergent) =
+            mode_resolver.resolve_mode(task.execution_mode.clone(), &agent_meta);
+        if effective_mode != task.execution_mode {
             tracing::info!(
                 task_id = %task_id,
                 %agent_type,
                 stored_mode = ?task.execution_mode,
                 effective_mode = ?effective_mode,
-                convergence_enabled = self.core_deps.config.convergence_enabled,
-                is_read_only = is_read_only_role,
-                agent_can_write = agent_can_write,
-                will_converge = is_convergent,
-                "Task execution mode resolved"
+                "Upgrading execution mode Direct -> Convergent (write-capable, non-read-only agent)"
             );
+        }
 
-            // Spawn the per-task worker. All deps are cloned BEFORE the
-            // tokio::spawn so the spawn body owns its inputs and never holds a
-            // reference back to the orchestrator (Risk 1 mitigation).
-            let role_max_turns = {
-                let lower = agent_type.to_lowercase();
-                if lower.contains("researcher")
-                    || lower.contains("analyst")
-                    || lower.contains("explorer")
-                    || lower.contains("auditor")
-                {
-                    51 // Research: typical ~15 turns, ceiling 51
-                } else if lower.contains("planner")
-                    || lower.contains("architect")
-                    || lower.contains("designer")
-                    || lower.contains("reviewer")
-                    || lower.contains("verifier")
-                {
-                    30 // Planning/Review: typical ~10 turns, ceiling 30
-                } else if lower.contains("implement")
-                    || lower.contains("coder")
-                    || lower.contains("builder")
-                    || lower.contains("fixer")
-                {
-                    75 // Implementation: typical ~25 turns, ceiling 75
-                } else {
-                    self.core_deps.config.default_max_turns // Fallback to config default
-                }
-            };
+        tracing::info!(
+            task_id = %task_id,
+            %agent_type,
+            stored_mode = ?task.execution_mode,
+            effective_mode = ?effective_mode,
+            convergence_enabled = self.core_deps.config.convergence_enabled,
+            is_read_only = is_read_only_role,
+            agent_can_write = agent_can_write,
+            will_converge = is_convergent,
+            "Task execution mode resolved"
+        );
+
+        // Spawn the per-task worker. All deps are cloned BEFORE the
+        // tokio::spawn so the spawn body owns its inputs and never holds a
+        // reference back to the orchestrator (Risk 1 mitigation).
+        let role_max_turns = {
+            let lower = agent_type.to_lowercase();
+            if lower.contains("researcher")
+                || lower.contains("analyst")
+                || lower.contains("explorer")
+                || lower.contains("auditor")
+            {
+                51 // Research: typical ~15 turns, ceiling 51
+            } else if lower.contains("planner")
+                || lower.contains("architect")
+                || lower.contains("designer")
+                || lower.contains("reviewer")
+                || lower.contains("verifier")
+            {
+                30 // Planning/Review: typical ~10 turns, ceiling 30
+            } else if lower.contains("implement")
+                || lower.contains("coder")
+                || lower.contains("builder")
+                || lower.contains("fixer")
+            {
+                75 // Implementation: typical ~25 turns, ceiling 75
+            } else {
+                self.core_deps.config.default_max_turns // Fallback to config default
+            }
+        };
 
-            // Use agent template max_turns if explicitly set (non-zero),
-            // then role-aware default, then orchestrator config default.
-            let mut max_turns = if template_max_turns > 0 {
+        // Use agent template max_turns if explicitly set (non-zero),
+        // then role-aware default, then orchestrator config default.
+        // A task-level override replaces all of these.
+        let mut max_turns =
+            if let Some(turns) = task.execution_params.as_ref().and_then(|p| p.max_turns) {
+                turns
+            } else if template_max_turns > 0 {
                 template_max_turns.max(role_max_turns)
             } else {
                 role_max_turns
             };
 
-            // Bump turn budget for tasks retrying after max_turns exhaustion.
-            if task
-                .context
-                .hints
-                .iter()
-                .any(|h| h == "retry:max_turns_exceeded")
-            {
-                let multiplier = 1.5_f64.powi(task.retry_count as i32);
-                max_turns = ((max_turns as f64 * multiplier) as u32).min(100);
-            }
+        // Bump turn budget for tasks retrying after max_turns exhaustion.
+        if task
+            .context
+            .hints
+            .iter()
+            .any(|h| h == "retry:max_turns_exceeded")
+        {
+            let multiplier = 1.5_f64.powi(task.retry_count as i32);
+            max_turns = ((max_turns as f64 * multiplier) as u32).min(100);
+        }
 
-            // Without --dangerously-skip-permissions, disable direct merges to
-            // main and force PR-only mode so a human must approve before
-            // merging.
-            let use_merge_queue =
-                self.core_deps.config.use_merge_queue && self.core_deps.config.dangerously_skip_permissions;
-            let prefer_pull_requests =
-                self.core_deps.config.prefer_pull_requests || !self.core_deps.config.dangerously_skip_permissions;
-
-            let exec_cfg = ExecutionConfig {
-                repo_path: self.core_deps.config.repo_path.clone(),
-                default_base_ref: self.core_deps.config.default_base_ref.clone(),
-                agent_semaphore: self.runtime_state.agent_semaphore.clone(),
-                guardrails: self.subsystem_services.guardrails.clone(),
-                require_commits: agent_can_write && !is_read_only_role,
-                verify_on_completion: self.core_deps.config.verify_on_completion,
-                use_merge_queue,
-                prefer_pull_requests,
-                track_evolution: self.core_deps.config.track_evolution,
-                evolution_loop: self.subsystem_services.evolution_loop.clone(),
-                fetch_on_sync: self.core_deps.config.fetch_on_sync,
-                output_delivery: task_output_delivery.clone(),
-                merge_request_repo: self.advanced_services.merge_request_repo.clone(),
-                post_completion_chain: self.middleware.post_completion_chain.clone(),
-            };
+        // Without --dangerously-skip-permissions, disable direct merges to
+        // main and force PR-only mode so a human must approve before
+        // merging.
+        let use_merge_queue =
+            self.core_deps.config.use_merge_queue && self.core_deps.config.dangerously_skip_permissions;
+        let prefer_pull_requests =
+            self.core_deps.config.prefer_pull_requests || !self.core_deps.config.dangerously_skip_permissions;
+
+        let exec_cfg = ExecutionConfig {
+            repo_path: self.core_deps.config.repo_path.clone(),
+            default_base_ref: self.core_deps.config.default_base_ref.clone(),
+            agent_slots: self.runtime_state.agent_slots.clone(),
+            guardrails: self.subsystem_services.guardrails.clone(),
+            require_commits: agent_can_write && !is_read_only_role,
+            verify_on_completion: self.core_deps.config.verify_on_completion,
+            use_merge_queue,
+            prefer_pull_requests,
+            track_evolution: self.core_deps.config.track_evolution,
+            evolution_loop: self.subsystem_services.evolution_loop.clone(),
+            fetch_on_sync: self.core_deps.config.fetch_on_sync,
+            output_delivery: task_output_delivery.clone(),
+            merge_request_repo: self.advanced_services.merge_request_repo.clone(),
+            post_completion_chain: self.middleware.post_completion_chain.clone(),
+            result_cache: self.advanced_services.result_cache.clone(),
+            model_router: ModelRouter::new(ModelRoutingConfig {
+                escalation: self.core_deps.config.model_escalation.clone(),
+                ..Default::default()
+            }),
+        };
 
-            let intent_verifier_dyn: Option<
-                Arc<dyn super::convergent_execution::ConvergentIntentVerifier>,
-            > = self.advanced_services.intent_verifier.as_ref().map(|iv| {
-                Arc::clone(iv) as Arc<dyn super::convergent_execution::ConvergentIntentVerifier>
+        let intent_verifier_dyn: Option<
+            Arc<dyn super::convergent_execution::ConvergentIntentVerifier>,
+        > = self.advanced_services.intent_verifier.as_ref().map(|iv| {
+            Arc::clone(iv) as Arc<dyn super::convergent_execution::ConvergentIntentVerifier>
+        });
+        let memory_repo_dyn: Option<Arc<dyn crate::domain::ports::MemoryRepository>> =
+            self.advanced_services.memory_repo.as_ref().map(|m| {
+                Arc::clone(m) as Arc<dyn crate::domain::ports::MemoryRepository>
             });
-            let memory_repo_dyn: Option<Arc<dyn crate::domain::ports::MemoryRepository>> =
-                self.advanced_services.memory_repo.as_ref().map(|m| {
-                    Arc::clone(m) as Arc<dyn crate::domain::ports::MemoryRepository>
-                });
-            let goal_repo_dyn: Arc<dyn crate::domain::ports::GoalRepository> =
-                self.core_deps.goal_repo.clone();
-            let task_repo_dyn: Arc<dyn crate::domain::ports::TaskRepository> =
-                self.core_deps.task_repo.clone();
-            let worktree_repo_dyn: Arc<dyn crate::domain::ports::WorktreeRepository> =
-                self.core_deps.worktree_repo.clone();
-
-            let params = TaskExecutionParams {
-                task: task.clone(),
-                task_id,
-                agent_type: agent_type.clone(),
-                system_prompt,
-                task_description,
-                effective_mode,
-                is_convergent,
-                max_turns,
-                agent_meta,
-                worktree_path,
-                all_workflows: self.core_deps.config.all_workflows.clone(),
-                circuit_scope: scope,
-                agent_unique_id: agent_unique_id.clone(),
-                template_version,
-                agent_type_for_evolution: agent_type.clone(),
-                substrate: self.core_deps.substrate.clone(),
-                task_repo: task_repo_dyn,
-                worktree_repo: worktree_repo_dyn,
-                goal_repo: goal_repo_dyn,
-                event_bus: self.subsystem_services.event_bus.clone(),
-                event_tx: event_tx.clone(),
-                audit_log: self.subsystem_services.audit_log.clone(),
-                circuit_breaker: self.subsystem_services.circuit_breaker.clone(),
-                command_bus: self.advanced_services.command_bus.read().await.clone(),
-                total_tokens: self.runtime_state.total_tokens.clone(),
-                permit,
-                overseer_cluster: self.advanced_services.overseer_cluster.clone(),
-                trajectory_repo: self.advanced_services.trajectory_repo.clone(),
-                convergence_engine_config: self.advanced_services.convergence_engine_config.clone(),
-                memory_repo: memory_repo_dyn,
-                intent_verifier: intent_verifier_dyn,
-                config: exec_cfg,
-            };
+        let goal_repo_dyn: Arc<dyn crate::domain::ports::GoalRepository> =
+            self.core_deps.goal_repo.clone();
+        let task_repo_dyn: Arc<dyn crate::domain::ports::TaskRepository> =
+            self.core_deps.task_repo.clone();
+        let worktree_repo_dyn: Arc<dyn crate::domain::ports::WorktreeRepository> =
+            self.core_deps.worktree_repo.clone();
+
+        let params = TaskExecutionParams {
+            task: task.clone(),
+            task_id,
+            agent_type: agent_type.clone(),
+            system_prompt,
+            task_description,
+            effective_mode,
+            is_convergent,
+            max_turns,
+            agent_meta,
+            worktree_path,
+            all_workflows: self.core_deps.config.all_workflows.clone(),
+            circuit_scope: scope,
+            agent_unique_id: agent_unique_id.clone(),
+            template_version,
+            agent_type_for_evolution: agent_type.clone(),
+            substrate,
+            task_repo: task_repo_dyn,
+            worktree_repo: worktree_repo_dyn,
+            goal_repo: goal_repo_dyn,
+            event_bus: self.subsystem_services.event_bus.clone(),
+            event_tx: event_tx.clone(),
+            audit_log: self.subsystem_services.audit_log.clone(),
+            circuit_breaker: self.subsystem_services.circuit_breaker.clone(),
+            command_bus: self.advanced_services.command_bus.read().await.clone(),
+            total_tokens: self.runtime_state.total_tokens.clone(),
+            permit,
+            overseer_cluster: self.advanced_services.overseer_cluster.clone(),
+            trajectory_repo: self.advanced_services.trajectory_repo.clone(),
+            convergence_engine_config: self.advanced_services.convergence_engine_config.clone(),
+            memory_repo: memory_repo_dyn,
+            intent_verifier: intent_verifier_dyn,
+            config: exec_cfg,
+        };
 
-            // Per-task worker: spawned once per task, short-lived. Not a
-            // long-lived daemon, so no supervision wrapper.
-            tokio::spawn(execute_task(params));
-        }
+        // Per-task worker: spawned once per task, short-lived. Not a
+        // long-lived daemon, so no supervision wrapper.
+        let worker = tokio::spawn(execute_task(params));
+        self.runtime_state.agent_slots.attach_worker(task_id, worker);
 
         Ok(())
     }
@@ -545,6 +871,43 @@ pub(crate) fn can_safely_auto_complete(task: &Task) -> bool {
     true
 }
 
+/// Order `items` by [`ReadyTaskKey::aged_priority`], highest first; ties go
+/// to the task created earliest.
+pub(crate) fn rank_by_aged_priority<T>(
+    items: &mut [T],
+    key: impl Fn(&T) -> ReadyTaskKey,
+    now: chrono::DateTime<chrono::Utc>,
+    aging_coefficient: f64,
+) {
+    items.sort_by(|a, b| {
+        let (a, b) = (key(a), key(b));
+        b.aged_priority(now, aging_coefficient)
+            .total_cmp(&a.aged_priority(now, aging_coefficient))
+            .then(a.created_at.cmp(&b.created_at))
+    });
+}
+
+/// Epsilon-greedy pick of up to `slots` tasks from `ranked` (highest priority
+/// first): each slot takes the next task in priority order, except that with
+/// probability `epsilon` it takes a uniformly random remaining task instead.
+pub(crate) fn select_ready_tasks<T, R: rand::Rng + ?Sized>(
+    mut ranked: Vec<T>,
+    slots: usize,
+    epsilon: f64,
+    rng: &mut R,
+) -> Vec<T> {
+    let mut chosen = Vec::with_capacity(slots.min(ranked.len()));
+    while chosen.len() < slots && !ranked.is_empty() {
+        let idx = if epsilon > 0.0 && rng.gen_bool(epsilon.min(1.0)) {
+            rng.gen_range(0..ranked.len())
+        } else {
+            0
+        };
+        chosen.push(ranked.remove(idx));
+    }
+    chosen
+}
+
 /// Checks if an error message represents a max-turns exhaustion where the agent's
 /// last output indicates it believed it had completed successfully. In this case,
 /// the task can be auto-completed instead of failed, since the agent did finish
@@ -584,6 +947,133 @@ pub(crate) fn is_max_turns_auto_completable(error_msg: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use crate::domain::models::TaskPriority;
+
+    fn ranked_backlog() -> Vec<Task> {
+        let mut tasks: Vec<Task> = (0..8)
+            .map(|i| Task::new(format!("urgent {}", i)).with_priority(TaskPriority::High))
+            .collect();
+        tasks.push(Task::new("chore").with_priority(TaskPriority::Low));
+        tasks
+    }
+
+    #[test]
+    fn test_select_ready_tasks_explores_low_priority() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        // Without exploration the low-priority task never fits in 2 slots.
+        let picked = select_ready_tasks(ranked_backlog(), 2, 0.0, &mut rng);
+        assert!(picked.iter().all(|t| t.priority == TaskPriority::High));
+
+        let draws = 1000;
+        let low_picks = (0..draws)
+            .filter(|_| {
+                select_ready_tasks(ranked_backlog(), 2, 0.2, &mut rng)
+                    .iter()
+                    .any(|t| t.priority == TaskPriority::Low)
+            })
+            .count();
+        assert!(low_picks > 0, "low-priority task was never sampled");
+        assert!(
+            low_picks < draws / 2,
+            "exploration should stay occasional, got {}/{}",
+            low_picks,
+            draws
+        );
+    }
+
+    #[test]
+    fn test_rank_by_aged_priority_prefers_older_tasks() {
+        let now = chrono::Utc::now();
+        let mut fresh = Task::new("fresh");
+        fresh.created_at = now - chrono::Duration::minutes(1);
+        let mut stale = Task::new("stale");
+        stale.created_at = now - chrono::Duration::hours(6);
+
+        // Equal priority: the longer-waiting task ranks first.
+        let mut tasks = vec![fresh.clone(), stale.clone()];
+        rank_by_aged_priority(&mut tasks, Task::ready_key, now, 0.1);
+        assert_eq!(tasks[0].id, stale.id);
+
+        // With enough weight, age overtakes a higher base priority...
+        let mut urgent = Task::new("urgent").with_priority(TaskPriority::High);
+        urgent.created_at = now - chrono::Duration::minutes(1);
+        stale.priority = TaskPriority::Normal;
+        let mut tasks = vec![urgent.clone(), stale.clone()];
+        rank_by_aged_priority(&mut tasks, Task::ready_key, now, 0.5);
+        assert_eq!(tasks[0].id, stale.id);
+
+        // ...but without aging, base priority wins and nothing is mutated.
+        rank_by_aged_priority(&mut tasks, Task::ready_key, now, 0.0);
+        assert_eq!(tasks[0].id, urgent.id);
+        assert_eq!(tasks[1].priority, TaskPriority::Normal);
+    }
+
+    #[tokio::test]
+    async fn test_aging_ranks_the_whole_ready_set() {
+        use crate::adapters::sqlite::test_support::setup_task_repo;
+        use crate::domain::ports::TaskRepository;
+
+        let repo = setup_task_repo().await;
+        let slots = 2;
+        let now = chrono::Utc::now();
+        for i in 0..slots * 4 + 3 {
+            let mut task = Task::new(format!("urgent {}", i)).with_priority(TaskPriority::High);
+            task.status = TaskStatus::Ready;
+            task.created_at = now - chrono::Duration::minutes(1);
+            repo.create(&task).await.unwrap();
+        }
+        let mut old = Task::new("month-old chore").with_priority(TaskPriority::Low);
+        old.status = TaskStatus::Ready;
+        old.created_at = now - chrono::Duration::days(30);
+        repo.create(&old).await.unwrap();
+
+        // By base priority the old task is past a 4×slots candidate pool...
+        let mut keys = repo.ready_task_keys().await.unwrap();
+        let position = keys.iter().position(|k| k.id == old.id).unwrap();
+        assert!(position >= slots * 4);
+
+        // ...but ranking the full set lets its wait lift it to the front.
+        rank_by_aged_priority(&mut keys, |k| *k, now, 0.5);
+        let picked = select_ready_tasks(keys, slots, 0.0, &mut StdRng::seed_from_u64(1));
+        assert_eq!(picked[0].id, old.id);
+    }
+
+    #[tokio::test]
+    async fn test_exploration_samples_past_a_deep_high_priority_backlog() {
+        use crate::adapters::sqlite::test_support::setup_task_repo;
+        use crate::domain::ports::TaskRepository;
+
+        let repo = setup_task_repo().await;
+        let slots = 2;
+        for i in 0..slots * 4 + 3 {
+            let mut task = Task::new(format!("urgent {}", i)).with_priority(TaskPriority::High);
+            task.status = TaskStatus::Ready;
+            repo.create(&task).await.unwrap();
+        }
+        let mut chore = Task::new("chore").with_priority(TaskPriority::Low);
+        chore.status = TaskStatus::Ready;
+        repo.create(&chore).await.unwrap();
+
+        let keys = repo.ready_task_keys().await.unwrap();
+        assert_eq!(keys.last().unwrap().id, chore.id);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let low_picks = (0..1000)
+            .filter(|_| {
+                select_ready_tasks(keys.clone(), slots, 0.2, &mut rng)
+                    .iter()
+                    .any(|k| k.id == chore.id)
+            })
+            .count();
+        assert!(
+            low_picks > 0,
+            "low-priority task behind the backlog was never sampled"
+        );
+    }
 
     #[test]
     fn test_max_turns_floor_enforcement() {
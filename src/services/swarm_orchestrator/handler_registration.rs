@@ -10,11 +10,11 @@ use crate::domain::ports::{
     AgentRepository, GoalRepository, MemoryRepository, TaskRepository, WorktreeRepository,
 };
 use crate::services::builtin_handlers::{
-    A2APollHandler, AdapterLifecycleSyncHandler, AgentTerminationHandler,
-    ConvergenceCancellationHandler, ConvergenceCoordinationHandler,
+    A2APollHandler, AdapterHealthHandler, AdapterLifecycleSyncHandler, AgentTerminationHandler,
+    ArtifactPruningHandler, ConvergenceCancellationHandler, ConvergenceCoordinationHandler,
     ConvergenceEscalationFeedbackHandler, ConvergenceEvolutionHandler, ConvergenceMemoryHandler,
     ConvergenceSLAPressureHandler, DeadLetterRetryHandler, DirectModeExecutionMemoryHandler,
-    EgressRoutingHandler, EscalationTimeoutHandler, EventPruningHandler, EventStorePollerHandler,
+    EgressRoutingHandler, EscalationNotificationHandler, EscalationTimeoutHandler, EventPruningHandler, EventStorePollerHandler,
     FastReconciliationHandler, GoalConvergenceCheckHandler, GoalCreatedHandler,
     GoalEvaluationHandler, GoalEvaluationTaskCreationHandler, GoalReconciliationHandler,
     GoalRetiredHandler, GoalStagnationDetectorHandler, IngestionPollHandler,
@@ -144,10 +144,13 @@ where
 
         // TaskFailedRetryHandler (NORMAL) — retry after failure if retries remain
         reactor
-            .register(Arc::new(TaskFailedRetryHandler::new(
-                self.core_deps.task_repo.clone(),
-                self.core_deps.config.max_task_retries,
-            )))
+            .register(Arc::new(
+                TaskFailedRetryHandler::new(
+                    self.core_deps.task_repo.clone(),
+                    self.core_deps.config.max_task_retries,
+                )
+                .with_backoff(self.core_deps.config.retry_backoff.clone()),
+            ))
             .await;
 
         // GoalCreatedHandler (NORMAL) — refresh active goals cache
@@ -173,9 +176,9 @@ where
                 self.core_deps.task_repo.clone(),
                 self.core_deps.worktree_repo.clone(),
                 self.runtime_state.stats.clone(),
-                self.runtime_state.agent_semaphore.clone(),
-                self.core_deps.config.max_agents,
+                self.runtime_state.agent_slots.clone(),
                 self.runtime_state.total_tokens.clone(),
+                self.subsystem_services.circuit_breaker.clone(),
             )))
             .await;
 
@@ -194,10 +197,13 @@ where
 
         // WorktreeReconciliationHandler (LOW) — detect orphaned worktrees
         reactor
-            .register(Arc::new(WorktreeReconciliationHandler::new(
-                self.core_deps.task_repo.clone(),
-                self.core_deps.worktree_repo.clone(),
-            )))
+            .register(Arc::new(
+                WorktreeReconciliationHandler::new(
+                    self.core_deps.task_repo.clone(),
+                    self.core_deps.worktree_repo.clone(),
+                )
+                .with_force(self.core_deps.config.force_worktree_cleanup),
+            ))
             .await;
 
         // RetryProcessingHandler (NORMAL) — periodic retry sweep
@@ -217,9 +223,21 @@ where
             )))
             .await;
 
+        // EscalationNotificationHandler (LOW) — announce escalations by urgency
+        if let Some(ref notifier) = self.advanced_services.escalation_notifier {
+            reactor
+                .register(Arc::new(EscalationNotificationHandler::new(
+                    notifier.clone(),
+                )))
+                .await;
+        }
+
         // MemoryMaintenanceHandler (NORMAL) — periodic memory maintenance
         if let Some(ref memory_repo) = self.advanced_services.memory_repo {
-            let memory_service = Arc::new(MemoryService::new(memory_repo.clone()));
+            let memory_service = Arc::new(
+                MemoryService::new(memory_repo.clone())
+                    .with_memory_config(&self.core_deps.config.memory),
+            );
             let maintenance_service =
                 Arc::new(MemoryMaintenanceService::from_memory_service(memory_service));
             reactor
@@ -330,12 +348,16 @@ where
             let task_service = Arc::new(
                 TaskService::new(self.core_deps.task_repo.clone())
                     .with_event_bus(self.subsystem_services.event_bus.clone())
-                    .with_default_execution_mode(self.core_deps.config.default_execution_mode.clone()),
+                    .with_default_execution_mode(self.core_deps.config.default_execution_mode.clone())
+                    .with_router(self.task_router()),
             );
             let goal_service = Arc::new(GoalService::new(self.core_deps.goal_repo.clone()));
 
             let bus = if let Some(ref memory_repo) = self.advanced_services.memory_repo {
-                let memory_service = Arc::new(MemoryService::new(memory_repo.clone()));
+                let memory_service = Arc::new(
+                    MemoryService::new(memory_repo.clone())
+                        .with_memory_config(&self.core_deps.config.memory),
+                );
                 let maintenance_service =
                     Arc::new(MemoryMaintenanceService::from_memory_service(memory_service));
                 let mut bus = CommandBus::new(
@@ -382,11 +404,14 @@ where
 
         // ReviewFailureLoopHandler (HIGH) — loop review failures back to plan+implement
         reactor
-            .register(Arc::new(ReviewFailureLoopHandler::new(
-                self.core_deps.task_repo.clone(),
-                command_bus.clone(),
-                self.core_deps.config.max_review_iterations,
-            )))
+            .register(Arc::new(
+                ReviewFailureLoopHandler::new(
+                    self.core_deps.task_repo.clone(),
+                    command_bus.clone(),
+                    self.core_deps.config.max_review_iterations,
+                )
+                .with_max_loop_tasks_per_root(self.core_deps.config.max_review_loop_tasks_per_root),
+            ))
             .await;
 
         // A2APollHandler (NORMAL) — poll A2A gateway for delegations
@@ -535,13 +560,16 @@ where
             // StartupCatchUpHandler (SYSTEM) — fix orphaned tasks and replay missed events
             if p.startup_catchup_enabled {
                 reactor
-                    .register(Arc::new(StartupCatchUpHandler::new(
-                        self.core_deps.task_repo.clone(),
-                        self.core_deps.goal_repo.clone(),
-                        event_store,
-                        p.startup_stale_task_threshold_secs,
-                        p.startup_max_replay_events,
-                    )))
+                    .register(Arc::new(
+                        StartupCatchUpHandler::new(
+                            self.core_deps.task_repo.clone(),
+                            self.core_deps.goal_repo.clone(),
+                            event_store,
+                            p.startup_stale_task_threshold_secs,
+                            p.startup_max_replay_events,
+                        )
+                        .with_concurrency(p.startup_reconciliation_concurrency),
+                    ))
                     .await;
             }
         }
@@ -556,6 +584,20 @@ where
             )))
             .await;
 
+        // ArtifactPruningHandler (LOW) — drop stale artifacts from old terminal tasks
+        if p.artifact_pruning_enabled {
+            reactor
+                .register(Arc::new(
+                    ArtifactPruningHandler::new(
+                        self.core_deps.task_repo.clone(),
+                        p.artifact_retention_days,
+                        p.artifact_keep_per_task,
+                    )
+                    .with_max_total(p.artifact_max_total),
+                ))
+                .await;
+        }
+
         // PriorityAgingHandler (LOW) — age task priorities based on wait time
         if p.priority_aging_enabled {
             reactor
@@ -585,13 +627,18 @@ where
 
         // TaskCompletionLearningHandler (NORMAL) — store learning patterns for retried tasks
         if p.task_learning_enabled {
-            reactor
-                .register(Arc::new(TaskCompletionLearningHandler::new(
-                    command_bus.clone(),
-                    p.task_learning_min_retries,
-                    p.task_learning_store_efficiency,
-                )))
-                .await;
+            let mut handler = TaskCompletionLearningHandler::new(
+                command_bus.clone(),
+                p.task_learning_min_retries,
+                p.task_learning_store_efficiency,
+            )
+            .with_max_result_chars(p.task_learning_max_result_chars);
+            if p.task_learning_summarize {
+                handler = handler
+                    .with_summarizer(self.core_deps.substrate.clone())
+                    .with_agent_slots(self.runtime_state.agent_slots.clone());
+            }
+            reactor.register(Arc::new(handler)).await;
         }
 
         // ObstacleEscalationHandler (LOW) — detect repeated failure patterns and escalate to goals
@@ -694,6 +741,10 @@ where
                 .register(Arc::new(
                     crate::services::builtin_handlers::BudgetTokenAccumulatorHandler::new(
                         budget_tracker.clone(),
+                    )
+                    .with_goal_attribution(
+                        self.core_deps.task_repo.clone(),
+                        self.core_deps.goal_repo.clone(),
                     ),
                 ))
                 .await;
@@ -702,6 +753,17 @@ where
                     crate::services::builtin_handlers::BudgetOpportunityHandler::new(),
                 ))
                 .await;
+            reactor
+                .register(Arc::new(
+                    crate::services::builtin_handlers::BudgetHardStopHandler::new(
+                        budget_tracker.clone(),
+                        self.core_deps.task_repo.clone(),
+                        self.runtime_state.total_tokens.clone(),
+                        self.runtime_state.status.clone(),
+                        self.runtime_state.agent_slots.clone(),
+                    ),
+                ))
+                .await;
         }
 
         // FederationResultHandler (NORMAL) — process federation events
@@ -765,6 +827,16 @@ where
                     )))
                     .await;
             }
+
+            // AdapterHealthHandler (LOW) — escalate adapters that stay unreachable
+            if adapter_registry.has_adapters() {
+                reactor
+                    .register(Arc::new(AdapterHealthHandler::new(
+                        adapter_registry.clone(),
+                        p.adapter_unhealthy_escalation_secs,
+                    )))
+                    .await;
+            }
         }
     }
 
@@ -968,6 +1040,21 @@ where
             ))
             .await;
 
+        // Budget check — hard-stop enforcement of the swarm-wide token ceiling
+        if let Some(ref bt) = self.advanced_services.budget_tracker
+            && bt.policy() == crate::services::budget_tracker::BudgetPolicy::HardStop
+            && bt.token_ceiling().is_some()
+        {
+            scheduler
+                .register(interval_schedule(
+                    "budget-check",
+                    Duration::from_secs(p.budget_check_interval_secs),
+                    EventCategory::Scheduler,
+                    EventSeverity::Debug,
+                ))
+                .await;
+        }
+
         // Priority aging — periodic priority promotion for waiting tasks
         if p.priority_aging_enabled {
             scheduler
@@ -980,6 +1067,18 @@ where
                 .await;
         }
 
+        // Artifact pruning — drop stale artifacts based on retention policy
+        if p.artifact_pruning_enabled {
+            scheduler
+                .register(interval_schedule(
+                    "artifact-pruning",
+                    Duration::from_secs(p.artifact_pruning_interval_secs),
+                    EventCategory::Scheduler,
+                    EventSeverity::Debug,
+                ))
+                .await;
+        }
+
         // Adapter ingestion poll — periodic external system ingestion
         if let Some(ref adapter_registry) = self.advanced_services.adapter_registry
             && !adapter_registry.ingestion_names().is_empty()
@@ -987,7 +1086,25 @@ where
             scheduler
                 .register(interval_schedule(
                     "adapter-ingestion-poll",
-                    Duration::from_secs(300),
+                    // Tick at the shortest adapter interval; each tick polls
+                    // only the adapters whose own interval has elapsed.
+                    adapter_registry
+                        .min_poll_interval()
+                        .unwrap_or(Duration::from_secs(300)),
+                    EventCategory::Scheduler,
+                    EventSeverity::Debug,
+                ))
+                .await;
+        }
+
+        // Adapter health check — connectivity probe for all registered adapters
+        if let Some(ref adapter_registry) = self.advanced_services.adapter_registry
+            && adapter_registry.has_adapters()
+        {
+            scheduler
+                .register(interval_schedule(
+                    "adapter-health-check",
+                    Duration::from_secs(p.adapter_health_check_interval_secs),
                     EventCategory::Scheduler,
                     EventSeverity::Debug,
                 ))
@@ -1040,11 +1157,12 @@ where
     /// method entirely and register a custom set.
     pub(super) async fn register_builtin_middleware(&self) {
         use super::middleware::{
-            AutoshipMiddleware, BudgetConcurrencyMiddleware, BudgetDispatchMiddleware,
-            CircuitBreakerMiddleware, FederationPriorityMiddleware, GuardrailsMiddleware,
-            McpReadinessMiddleware, MemoryOnlyShortCircuitMiddleware, MergeQueueMiddleware,
-            PullRequestMiddleware, QuietWindowMiddleware, RouteTaskMiddleware,
-            SubtaskMergeBackMiddleware, VerificationMiddleware,
+            AutoshipMiddleware, BudgetCeilingMiddleware, BudgetConcurrencyMiddleware,
+            BudgetDispatchMiddleware,
+            CircuitBreakerMiddleware, FederationPriorityMiddleware, GoalBudgetMiddleware,
+            GuardrailsMiddleware, McpReadinessMiddleware, MemoryOnlyShortCircuitMiddleware,
+            MergeQueueMiddleware, PullRequestMiddleware, QuietWindowMiddleware,
+            RouteTaskMiddleware, SubtaskMergeBackMiddleware, VerificationMiddleware,
         };
 
         // -- Pre-spawn chain (order matches the previous inline sequence) --
@@ -1054,11 +1172,17 @@ where
                 self.core_deps.config.repo_path.clone(),
                 self.core_deps.config.mcp_servers.a2a_gateway.clone(),
             )));
-            chain.register(Arc::new(RouteTaskMiddleware::new()));
+            chain.register(Arc::new(
+                RouteTaskMiddleware::new()
+                    .with_router(self.task_router())
+                    .with_routing_config(self.core_deps.config.task_routing.clone()),
+            ));
             chain.register(Arc::new(CircuitBreakerMiddleware::new()));
             chain.register(Arc::new(QuietWindowMiddleware::new()));
+            chain.register(Arc::new(BudgetCeilingMiddleware::new()));
             chain.register(Arc::new(BudgetDispatchMiddleware::new()));
             chain.register(Arc::new(BudgetConcurrencyMiddleware::new()));
+            chain.register(Arc::new(GoalBudgetMiddleware::new()));
             chain.register(Arc::new(GuardrailsMiddleware::new()));
             // Federation priority is a no-op today; registered as an extension
             // point for future federation-signal handlers.
@@ -9,8 +9,8 @@ use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::models::workflow_template::OutputDelivery;
-use crate::domain::models::{TaskStatus, WorktreeStatus};
+use crate::domain::models::workflow_template::{OutputDelivery, WorkspaceKind};
+use crate::domain::models::{TaskStatus, WorkspaceMode, WorktreeStatus};
 use crate::domain::ports::{
     GoalRepository, MergeRequestRepository, TaskRepository, WorktreeRepository,
 };
@@ -21,6 +21,7 @@ use crate::services::{
 
 use crate::services::event_bus::EventBus;
 
+use super::agent_prep::AgentMetadata;
 use super::types::SwarmEvent;
 
 /// Known transient markdown filenames that agents may generate during execution.
@@ -674,6 +675,36 @@ pub(crate) fn truncate_description(description: &str, max_chars: usize) -> Strin
     }
 }
 
+/// Pick the workspace a task's agent runs in from its [`WorkspaceMode`].
+///
+/// `Ephemeral` runs in the repo root and `Worktree` forces a worktree (still
+/// subject to `use_worktrees`). `Auto` keeps the workflow's kind, except that
+/// a subtask whose agent has no file tools skips the worktree it could never
+/// touch. Root tasks always keep theirs, since subtasks branch from it.
+pub(crate) fn resolve_workspace_kind(
+    mode: WorkspaceMode,
+    workflow_kind: WorkspaceKind,
+    is_subtask: bool,
+    agent: &AgentMetadata,
+) -> WorkspaceKind {
+    match mode {
+        WorkspaceMode::Ephemeral => WorkspaceKind::None,
+        WorkspaceMode::Worktree => WorkspaceKind::Worktree,
+        WorkspaceMode::Auto => {
+            let touches_files = agent.can_write
+                || agent
+                    .capabilities
+                    .iter()
+                    .any(|c| matches!(c.to_lowercase().as_str(), "read" | "glob" | "grep"));
+            if is_subtask && !touches_files {
+                WorkspaceKind::None
+            } else {
+                workflow_kind
+            }
+        }
+    }
+}
+
 /// Outcome of merging a subtask branch into the feature branch.
 pub(crate) enum MergeBackOutcome {
     /// Subtask branch successfully merged into feature branch.
@@ -1609,6 +1640,61 @@ async fn has_any_successful_descendant<T: TaskRepository + ?Sized>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::models::AgentTier;
+
+    fn agent(capabilities: &[&str], can_write: bool) -> AgentMetadata {
+        AgentMetadata {
+            version: 1,
+            capabilities: capabilities.iter().map(|c| c.to_string()).collect(),
+            cli_tools: vec![],
+            can_write,
+            is_read_only: false,
+            max_turns: 0,
+            preferred_model: None,
+            tier: AgentTier::Worker,
+            is_read_only_role: !can_write,
+        }
+    }
+
+    #[test]
+    fn resolve_workspace_kind_honours_explicit_modes() {
+        let coder = agent(&["read", "write", "shell"], true);
+        assert_eq!(
+            resolve_workspace_kind(
+                WorkspaceMode::Ephemeral,
+                WorkspaceKind::Worktree,
+                true,
+                &coder
+            ),
+            WorkspaceKind::None
+        );
+        let aggregator = agent(&["tasks", "memory"], false);
+        assert_eq!(
+            resolve_workspace_kind(
+                WorkspaceMode::Worktree,
+                WorkspaceKind::None,
+                true,
+                &aggregator
+            ),
+            WorkspaceKind::Worktree
+        );
+    }
+
+    #[test]
+    fn resolve_workspace_kind_auto_skips_worktree_for_fileless_subtasks() {
+        let aggregator = agent(&["tasks", "memory"], false);
+        let researcher = agent(&["read", "grep", "memory"], false);
+        let coder = agent(&["read", "write", "shell"], true);
+
+        let auto = |is_subtask, a: &AgentMetadata| {
+            resolve_workspace_kind(WorkspaceMode::Auto, WorkspaceKind::Worktree, is_subtask, a)
+        };
+        assert_eq!(auto(true, &aggregator), WorkspaceKind::None);
+        assert_eq!(auto(true, &researcher), WorkspaceKind::Worktree);
+        assert_eq!(auto(true, &coder), WorkspaceKind::Worktree);
+        // A root task anchors the feature branch, so it keeps its worktree.
+        assert_eq!(auto(false, &aggregator), WorkspaceKind::Worktree);
+    }
 
     #[test]
     fn truncate_short_description_unchanged() {
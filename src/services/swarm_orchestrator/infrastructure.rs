@@ -3,6 +3,7 @@
 //! Manages cold start, memory decay daemon, MCP server lifecycle,
 //! worktree creation, task verification, and statistics tracking.
 
+use futures::stream::{self, StreamExt};
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use tokio::sync::mpsc;
@@ -10,7 +11,7 @@ use uuid::Uuid;
 
 use crate::domain::errors::DomainResult;
 use crate::domain::models::workflow_template::WorkspaceKind;
-use crate::domain::models::{GoalStatus, TaskStatus};
+use crate::domain::models::{GoalStatus, Task, TaskStatus, WorktreeStatus};
 use crate::domain::ports::{
     AgentRepository, GoalRepository, MemoryRepository, TaskRepository, WorktreeRepository,
 };
@@ -20,7 +21,8 @@ use crate::services::{
     MemoryDecayDaemon, MemoryMaintenanceService, MemoryService, VerificationResult,
     VerifierConfig, WorktreeConfig,
     WorktreeService,
-    command_bus::{CommandEnvelope, CommandSource, DomainCommand, TaskCommand},
+    command_bus::{CommandBus, CommandEnvelope, CommandSource, DomainCommand, TaskCommand},
+    merge_queue::has_pending_merge,
     supervise,
 };
 
@@ -207,7 +209,8 @@ where
         };
 
         // Create memory service
-        let memory_service = MemoryService::new(memory_repo.clone());
+        let memory_service = MemoryService::new(memory_repo.clone())
+            .with_memory_config(&self.core_deps.config.memory);
 
         // Check if we have any existing memories
         let stats = memory_service.get_stats().await?;
@@ -530,7 +533,10 @@ where
             return Ok(());
         };
 
-        let memory_service = Arc::new(MemoryService::new(memory_repo.clone()));
+        let memory_service = Arc::new(
+            MemoryService::new(memory_repo.clone())
+                .with_memory_config(&self.core_deps.config.memory),
+        );
         let maintenance_service =
             Arc::new(MemoryMaintenanceService::from_memory_service(memory_service));
         let daemon = MemoryDecayDaemon::new(maintenance_service, DecayDaemonConfig::default())
@@ -756,6 +762,12 @@ where
             return Ok(existing.path);
         }
 
+        if self.core_deps.config.reuse_chained_worktrees
+            && let Some(path) = self.reuse_predecessor_worktree(task_id).await
+        {
+            return Ok(path);
+        }
+
         // If this is a subtask, branch from the root ancestor's feature branch
         let parent_base_ref = if let Ok(Some(task)) = self.core_deps.task_repo.get(task_id).await {
             if let Some(parent_id) = task.parent_id {
@@ -804,6 +816,88 @@ where
         Ok(worktree.path)
     }
 
+    /// Take over the worktree of the most recently completed dependency, so a
+    /// chained task continues on its predecessor's branch instead of paying
+    /// for a fresh checkout.
+    ///
+    /// Only worktrees whose work has been released qualify: `Merged`, or
+    /// `Completed` with no merge request still queued, in progress or in
+    /// conflict. The handoff is an atomic compare-and-swap in the
+    /// repository, so when several dependents race for the same worktree
+    /// exactly one wins and the rest fall back to creating their own.
+    pub(super) async fn reuse_predecessor_worktree(&self, task_id: Uuid) -> Option<String> {
+        let task = self.core_deps.task_repo.get(task_id).await.ok()??;
+
+        let mut predecessors = Vec::new();
+        for dep_id in &task.depends_on {
+            if let Ok(Some(dep)) = self.core_deps.task_repo.get(*dep_id).await
+                && dep.status == TaskStatus::Complete
+            {
+                predecessors.push(dep);
+            }
+        }
+        predecessors.sort_by_key(|dep| std::cmp::Reverse(dep.completed_at));
+
+        for dep in predecessors {
+            let Ok(Some(wt)) = self.core_deps.worktree_repo.get_by_task(dep.id).await else {
+                continue;
+            };
+            if !matches!(wt.status, WorktreeStatus::Completed | WorktreeStatus::Merged)
+                || !std::path::Path::new(&wt.path).exists()
+            {
+                continue;
+            }
+            if let Some(ref merge_repo) = self.advanced_services.merge_request_repo {
+                match has_pending_merge(merge_repo.as_ref(), dep.id).await {
+                    Ok(false) => {}
+                    Ok(true) => {
+                        tracing::debug!(
+                            task_id = %task_id,
+                            predecessor = %dep.id,
+                            "Predecessor worktree has a pending merge; not reusing it"
+                        );
+                        continue;
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            task_id = %task_id,
+                            predecessor = %dep.id,
+                            "Failed to check predecessor merge state: {}",
+                            e
+                        );
+                        continue;
+                    }
+                }
+            }
+            match self
+                .core_deps
+                .worktree_repo
+                .reassign_completed(wt.id, dep.id, task_id)
+                .await
+            {
+                Ok(true) => {
+                    tracing::info!(
+                        task_id = %task_id,
+                        predecessor = %dep.id,
+                        path = %wt.path,
+                        "Reusing predecessor's worktree for chained task"
+                    );
+                    return Some(wt.path);
+                }
+                Ok(false) => continue,
+                Err(e) => {
+                    tracing::warn!(
+                        task_id = %task_id,
+                        predecessor = %dep.id,
+                        "Failed to reassign predecessor worktree: {}",
+                        e
+                    );
+                }
+            }
+        }
+        None
+    }
+
     /// Provision a workspace for task execution based on the workflow's `WorkspaceKind`.
     ///
     /// - `WorkspaceKind::Worktree` → create (or reuse) a git worktree via
@@ -903,10 +997,15 @@ where
             running_tasks: *task_counts.get(&TaskStatus::Running).unwrap_or(&0) as usize,
             completed_tasks: *task_counts.get(&TaskStatus::Complete).unwrap_or(&0) as usize,
             failed_tasks: *task_counts.get(&TaskStatus::Failed).unwrap_or(&0) as usize,
-            active_agents: self.core_deps.config.max_agents
-                - self.runtime_state.agent_semaphore.available_permits(),
+            active_agents: self.runtime_state.agent_slots.active_agents(),
             active_worktrees,
             total_tokens_used: self.runtime_state.total_tokens.load(Ordering::Relaxed),
+            half_open_circuits: self
+                .subsystem_services
+                .circuit_breaker
+                .get_half_open_circuits()
+                .await
+                .len(),
         };
 
         {
@@ -938,6 +1037,65 @@ where
         self.runtime_state.resume().await;
     }
 
+    /// Follow the persisted operator pause flag (`abathur swarm pause` /
+    /// `resume`). Only changes to the flag are applied, so a pause made
+    /// in-process is not undone by a flag nobody touched.
+    pub(super) async fn sync_pause_flag(
+        &self,
+        last_applied: &mut Option<bool>,
+        event_tx: &mpsc::Sender<SwarmEvent>,
+    ) {
+        let Some(ref repo) = self.advanced_services.swarm_control_repo else {
+            return;
+        };
+        let paused = match repo.is_paused().await {
+            Ok(paused) => paused,
+            Err(e) => {
+                tracing::warn!("Failed to read swarm pause flag: {}", e);
+                return;
+            }
+        };
+        if *last_applied == Some(paused) {
+            return;
+        }
+        *last_applied = Some(paused);
+
+        let before = self.status().await;
+        if paused {
+            self.pause().await;
+        } else {
+            self.resume().await;
+        }
+        let after = self.status().await;
+        if after == before {
+            return;
+        }
+
+        let (event, payload) = if after == OrchestratorStatus::Paused {
+            (
+                SwarmEvent::Paused,
+                crate::services::event_bus::EventPayload::OrchestratorPaused,
+            )
+        } else {
+            (
+                SwarmEvent::Resumed,
+                crate::services::event_bus::EventPayload::OrchestratorResumed,
+            )
+        };
+        tracing::info!(
+            "Orchestrator {} by operator request",
+            if paused { "paused" } else { "resumed" }
+        );
+        let _ = event_tx.send(event).await;
+        self.subsystem_services
+            .event_bus
+            .publish(crate::services::event_factory::orchestrator_event(
+                crate::services::event_bus::EventSeverity::Info,
+                payload,
+            ))
+            .await;
+    }
+
     /// Stop the orchestrator gracefully. Delegates to `RuntimeState` (T11).
     pub async fn stop(&self) {
         self.runtime_state.stop().await;
@@ -948,15 +1106,73 @@ where
         self.runtime_state.total_tokens()
     }
 
+    /// Apply one startup-reconciliation correction: dispatch `command` through
+    /// the command bus when available, falling back to writing
+    /// `fallback_status` directly. Returns whether the task was corrected.
+    async fn apply_startup_correction(
+        &self,
+        cb: Option<&Arc<CommandBus>>,
+        task: &Task,
+        command: TaskCommand,
+        fallback_status: TaskStatus,
+    ) -> bool {
+        if let Some(cb) = cb {
+            let envelope =
+                CommandEnvelope::new(CommandSource::System, DomainCommand::Task(command));
+            match cb.dispatch(envelope).await {
+                Ok(_) => return true,
+                Err(e) => tracing::warn!(
+                    "CommandBus failed to reconcile task {}, falling back to direct write: {}",
+                    task.id,
+                    e
+                ),
+            }
+        }
+
+        let mut task = task.clone();
+        task.status = fallback_status;
+        if let Err(e) = self.core_deps.task_repo.update(&task).await {
+            tracing::warn!("Failed to reconcile task {}: {}", task.id, e);
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Whether every dependency of `task` is complete. Dependencies that
+    /// cannot be loaded are ignored.
+    async fn startup_deps_complete(&self, task: &Task) -> bool {
+        for dep_id in &task.depends_on {
+            if let Ok(Some(dep)) = self.core_deps.task_repo.get(*dep_id).await
+                && dep.status != TaskStatus::Complete
+            {
+                return false;
+            }
+        }
+        true
+    }
+
     /// Run startup reconciliation to fix inconsistent state after a crash or restart.
     ///
     /// Checks for:
     /// - Tasks stuck in `Running` status (stale agents) -> fail them
     /// - Tasks in `Ready` status with incomplete dependencies -> move back to `Pending`
     /// - Tasks in `Pending` status with all dependencies complete -> transition to `Ready`
+    ///
+    /// The first three checks process up to
+    /// `polling.startup_reconciliation_concurrency` tasks at a time; each task
+    /// is still corrected by a single command or write.
     pub async fn run_startup_reconciliation(&self) -> DomainResult<u64> {
+        let started = std::time::Instant::now();
         let mut corrections: u64 = 0;
         let cb = self.advanced_services.command_bus.read().await.clone();
+        let cb = cb.as_ref();
+        let concurrency = self
+            .core_deps
+            .config
+            .polling
+            .startup_reconciliation_concurrency
+            .max(1);
 
         // 1. Fail stale Running tasks (started_at older than threshold).
         //    On restart, any task that was Running has lost its agent.
@@ -969,51 +1185,30 @@ where
             })
             .await?;
 
-        for task in &running_tasks {
-            tracing::info!(
-                "Startup reconciliation: failing stale running task {} ('{}')",
-                task.id,
-                task.title
-            );
-            if let Some(ref cb) = cb {
-                let envelope = CommandEnvelope::new(
-                    CommandSource::System,
-                    DomainCommand::Task(TaskCommand::Fail {
+        corrections += stream::iter(&running_tasks)
+            .map(|task| async move {
+                tracing::info!(
+                    "Startup reconciliation: failing stale running task {} ('{}')",
+                    task.id,
+                    task.title
+                );
+                self.apply_startup_correction(
+                    cb,
+                    task,
+                    TaskCommand::Fail {
                         task_id: task.id,
                         error: Some(
                             "Stale running task detected during startup reconciliation".to_string(),
                         ),
-                    }),
-                );
-                match cb.dispatch(envelope).await {
-                    Ok(_) => {
-                        corrections += 1;
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            "CommandBus failed to reconcile task {}, falling back to direct write: {}",
-                            task.id,
-                            e
-                        );
-                        let mut task = task.clone();
-                        task.status = TaskStatus::Failed;
-                        if let Err(e) = self.core_deps.task_repo.update(&task).await {
-                            tracing::warn!("Failed to reconcile task {}: {}", task.id, e);
-                        } else {
-                            corrections += 1;
-                        }
-                    }
-                }
-            } else {
-                let mut task = task.clone();
-                task.status = TaskStatus::Failed;
-                if let Err(e) = self.core_deps.task_repo.update(&task).await {
-                    tracing::warn!("Failed to reconcile task {}: {}", task.id, e);
-                } else {
-                    corrections += 1;
-                }
-            }
-        }
+                    },
+                    TaskStatus::Failed,
+                )
+                .await
+            })
+            .buffer_unordered(concurrency)
+            .filter(|corrected| std::future::ready(*corrected))
+            .count()
+            .await as u64;
 
         // 2. Check Ready tasks with incomplete dependencies -> move back to Pending
         let ready_tasks = self
@@ -1025,62 +1220,31 @@ where
             })
             .await?;
 
-        for task in &ready_tasks {
-            if !task.depends_on.is_empty() {
-                let mut all_deps_complete = true;
-                for dep_id in &task.depends_on {
-                    if let Ok(Some(dep)) = self.core_deps.task_repo.get(*dep_id).await
-                        && dep.status != TaskStatus::Complete
-                    {
-                        all_deps_complete = false;
-                        break;
-                    }
-                }
-                if !all_deps_complete {
-                    tracing::info!(
-                        "Startup reconciliation: moving task {} ('{}') back to Pending (incomplete deps)",
-                        task.id,
-                        task.title
-                    );
-                    if let Some(ref cb) = cb {
-                        let envelope = CommandEnvelope::new(
-                            CommandSource::System,
-                            DomainCommand::Task(TaskCommand::Transition {
-                                task_id: task.id,
-                                new_status: TaskStatus::Pending,
-                            }),
-                        );
-                        match cb.dispatch(envelope).await {
-                            Ok(_) => {
-                                corrections += 1;
-                            }
-                            Err(e) => {
-                                tracing::warn!(
-                                    "CommandBus failed to reconcile task {}, falling back to direct write: {}",
-                                    task.id,
-                                    e
-                                );
-                                let mut task = task.clone();
-                                task.status = TaskStatus::Pending;
-                                if let Err(e) = self.core_deps.task_repo.update(&task).await {
-                                    tracing::warn!("Failed to reconcile task {}: {}", task.id, e);
-                                } else {
-                                    corrections += 1;
-                                }
-                            }
-                        }
-                    } else {
-                        let mut task = task.clone();
-                        task.status = TaskStatus::Pending;
-                        if let Err(e) = self.core_deps.task_repo.update(&task).await {
-                            tracing::warn!("Failed to reconcile task {}: {}", task.id, e);
-                        } else {
-                            corrections += 1;
-                        }
-                    }
+        corrections += stream::iter(ready_tasks.iter().filter(|t| !t.depends_on.is_empty()))
+            .map(|task| async move {
+                if self.startup_deps_complete(task).await {
+                    return false;
                 }
-            }
-        }
+                tracing::info!(
+                    "Startup reconciliation: moving task {} ('{}') back to Pending (incomplete deps)",
+                    task.id,
+                    task.title
+                );
+                self.apply_startup_correction(
+                    cb,
+                    task,
+                    TaskCommand::Transition {
+                        task_id: task.id,
+                        new_status: TaskStatus::Pending,
+                    },
+                    TaskStatus::Pending,
+                )
+                .await
+            })
+            .buffer_unordered(concurrency)
+            .filter(|corrected| std::future::ready(*corrected))
+            .count()
+            .await as u64;
 
         // 3. Check Pending tasks with all dependencies complete -> transition to Ready
         let pending_tasks = self
@@ -1092,66 +1256,31 @@ where
             })
             .await?;
 
-        for task in &pending_tasks {
-            let should_promote = if task.depends_on.is_empty() {
-                true
-            } else {
-                let mut all_complete = true;
-                for dep_id in &task.depends_on {
-                    if let Ok(Some(dep)) = self.core_deps.task_repo.get(*dep_id).await
-                        && dep.status != TaskStatus::Complete
-                    {
-                        all_complete = false;
-                        break;
-                    }
+        corrections += stream::iter(&pending_tasks)
+            .map(|task| async move {
+                if !self.startup_deps_complete(task).await {
+                    return false;
                 }
-                all_complete
-            };
-
-            if should_promote {
                 tracing::info!(
                     "Startup reconciliation: promoting task {} ('{}') to Ready",
                     task.id,
                     task.title
                 );
-                if let Some(ref cb) = cb {
-                    let envelope = CommandEnvelope::new(
-                        CommandSource::System,
-                        DomainCommand::Task(TaskCommand::Transition {
-                            task_id: task.id,
-                            new_status: TaskStatus::Ready,
-                        }),
-                    );
-                    match cb.dispatch(envelope).await {
-                        Ok(_) => {
-                            corrections += 1;
-                        }
-                        Err(e) => {
-                            tracing::warn!(
-                                "CommandBus failed to reconcile task {}, falling back to direct write: {}",
-                                task.id,
-                                e
-                            );
-                            let mut task = task.clone();
-                            task.status = TaskStatus::Ready;
-                            if let Err(e) = self.core_deps.task_repo.update(&task).await {
-                                tracing::warn!("Failed to reconcile task {}: {}", task.id, e);
-                            } else {
-                                corrections += 1;
-                            }
-                        }
-                    }
-                } else {
-                    let mut task = task.clone();
-                    task.status = TaskStatus::Ready;
-                    if let Err(e) = self.core_deps.task_repo.update(&task).await {
-                        tracing::warn!("Failed to reconcile task {}: {}", task.id, e);
-                    } else {
-                        corrections += 1;
-                    }
-                }
-            }
-        }
+                self.apply_startup_correction(
+                    cb,
+                    task,
+                    TaskCommand::Transition {
+                        task_id: task.id,
+                        new_status: TaskStatus::Ready,
+                    },
+                    TaskStatus::Ready,
+                )
+                .await
+            })
+            .buffer_unordered(concurrency)
+            .filter(|corrected| std::future::ready(*corrected))
+            .count()
+            .await as u64;
 
         // 4. Fix stale Validating tasks based on workflow_state and staleness.
         //    Uses updated_at against stale_validating_timeout_secs (default 1800s / 30min).
@@ -1206,7 +1335,7 @@ where
                         ws,
                         target_status
                     );
-                    if let Some(ref cb) = cb {
+                    if let Some(cb) = cb {
                         let envelope = CommandEnvelope::new(
                             CommandSource::System,
                             DomainCommand::Task(TaskCommand::ForceTransition {
@@ -1256,7 +1385,7 @@ where
                         task.id,
                         task.title
                     );
-                    if let Some(ref cb) = cb {
+                    if let Some(cb) = cb {
                         let envelope = CommandEnvelope::new(
                             CommandSource::System,
                             DomainCommand::Task(TaskCommand::ForceTransition {
@@ -1303,7 +1432,7 @@ where
                         task.title,
                         elapsed.num_seconds()
                     );
-                    if let Some(ref cb) = cb {
+                    if let Some(cb) = cb {
                         let envelope = CommandEnvelope::new(
                             CommandSource::System,
                             DomainCommand::Task(TaskCommand::Fail {
@@ -1352,7 +1481,7 @@ where
                         ws_ref,
                         elapsed.num_seconds()
                     );
-                    if let Some(ref cb) = cb {
+                    if let Some(cb) = cb {
                         let envelope = CommandEnvelope::new(
                             CommandSource::System,
                             DomainCommand::Task(TaskCommand::ForceTransition {
@@ -1411,6 +1540,13 @@ where
         // Restore persisted template stats and version changes
         self.subsystem_services.evolution_loop.load_persisted_state().await;
 
+        tracing::info!(
+            corrections,
+            concurrency,
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "Startup reconciliation finished"
+        );
+
         Ok(corrections)
     }
 }
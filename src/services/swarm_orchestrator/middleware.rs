@@ -20,7 +20,6 @@
 
 use async_trait::async_trait;
 use std::sync::Arc;
-use tokio::sync::Semaphore;
 
 use crate::domain::errors::DomainResult;
 use crate::domain::models::Task;
@@ -34,6 +33,8 @@ use crate::services::{
     cost_window_service::CostWindowService,
 };
 
+use super::agent_slots::AgentSlots;
+
 // ============================================================================
 // Pre-spawn chain
 // ============================================================================
@@ -71,10 +72,13 @@ pub struct PreSpawnContext {
     pub audit_log: Arc<AuditLogService>,
     pub circuit_breaker: Arc<CircuitBreakerService>,
     pub guardrails: Arc<Guardrails>,
+    pub event_bus: Arc<EventBus>,
     pub cost_window_service: Option<Arc<CostWindowService>>,
     pub budget_tracker: Option<Arc<BudgetTracker>>,
-    pub agent_semaphore: Arc<Semaphore>,
+    pub agent_slots: Arc<AgentSlots>,
     pub max_agents: usize,
+    /// Orchestrator's running token counter at the time of the spawn attempt.
+    pub total_tokens: u64,
 
     // -- Optional extension points --
     /// Running count of "federation-priority" bumps applied by middleware.
@@ -82,6 +86,10 @@ pub struct PreSpawnContext {
     /// middleware can record when it bumps priority, without needing to
     /// mutate `Task` directly.
     pub federation_priority_bumps: u32,
+    /// Evaluate gates without side effects. Set by
+    /// `SwarmOrchestrator::spawn_decision`; middleware must not persist task
+    /// changes or write audit entries when this is true.
+    pub dry_run: bool,
 }
 
 impl PreSpawnContext {
@@ -136,21 +144,33 @@ impl PreSpawnChain {
     /// spawn; returns `Skip { reason }` from the first middleware that
     /// rejected it. Errors short-circuit and bubble up.
     pub async fn run(&self, ctx: &mut PreSpawnContext) -> DomainResult<PreSpawnDecision> {
+        Ok(match self.evaluate(ctx).await? {
+            Some((_, reason)) => PreSpawnDecision::Skip { reason },
+            None => PreSpawnDecision::Continue,
+        })
+    }
+
+    /// Like [`run`](Self::run), but also names the middleware that skipped.
+    /// Returns `None` when every middleware allowed the spawn.
+    pub async fn evaluate(
+        &self,
+        ctx: &mut PreSpawnContext,
+    ) -> DomainResult<Option<(&'static str, String)>> {
         for mw in &self.middleware {
             match mw.handle(ctx).await? {
                 PreSpawnDecision::Continue => {}
-                skip @ PreSpawnDecision::Skip { .. } => {
+                PreSpawnDecision::Skip { reason } => {
                     tracing::debug!(
                         middleware = mw.name(),
                         task_id = %ctx.task.id,
-                        decision = ?skip,
+                        %reason,
                         "PreSpawnChain: middleware requested skip"
                     );
-                    return Ok(skip);
+                    return Ok(Some((mw.name(), reason)));
                 }
             }
         }
-        Ok(PreSpawnDecision::Continue)
+        Ok(None)
     }
 }
 
@@ -297,7 +317,10 @@ pub mod subtask_merge;
 pub mod verification;
 
 pub use autoship::AutoshipMiddleware;
-pub use budget::{BudgetConcurrencyMiddleware, BudgetDispatchMiddleware};
+pub use budget::{
+    BudgetCeilingMiddleware, BudgetConcurrencyMiddleware, BudgetDispatchMiddleware,
+    GoalBudgetMiddleware,
+};
 pub use circuit_breaker::CircuitBreakerMiddleware;
 pub use federation_priority::FederationPriorityMiddleware;
 pub use guardrails_check::GuardrailsMiddleware;
@@ -342,11 +365,16 @@ mod tests {
             audit_log: Arc::new(AuditLogService::with_defaults()),
             circuit_breaker: Arc::new(CircuitBreakerService::with_defaults()),
             guardrails: Arc::new(Guardrails::with_defaults()),
+            event_bus: Arc::new(EventBus::new(
+                crate::services::event_bus::EventBusConfig::default(),
+            )),
             cost_window_service: None,
             budget_tracker: None,
-            agent_semaphore: Arc::new(Semaphore::new(4)),
+            agent_slots: Arc::new(AgentSlots::new(4, Default::default())),
             max_agents: 4,
+            total_tokens: 0,
             federation_priority_bumps: 0,
+            dry_run: false,
         }
     }
 
@@ -1,19 +1,83 @@
 //! Pre-spawn middleware: budget-pressure gates.
 //!
-//! Two related middlewares:
+//! Four related middlewares:
+//! - [`BudgetCeilingMiddleware`] stops all spawns once the swarm-wide token
+//!   ceiling is reached, and under the hard-stop policy triggers the
+//!   `budget-check` handler that cancels running tasks.
 //! - [`BudgetDispatchMiddleware`] defers low-priority tasks under elevated
 //!   budget pressure (matches the previous `should_dispatch_task` gate).
 //! - [`BudgetConcurrencyMiddleware`] enforces a budget-adjusted ceiling on
 //!   concurrent agents.
+//! - [`GoalBudgetMiddleware`] holds back tasks charged to a goal that has
+//!   spent its per-goal token budget.
 //!
-//! Both are no-ops when no `BudgetTracker` is attached to the orchestrator.
+//! All are no-ops when no `BudgetTracker` is attached to the orchestrator.
 
 use async_trait::async_trait;
 
 use crate::domain::errors::DomainResult;
+use crate::services::budget_tracker::BudgetPolicy;
+use crate::services::event_bus::{EventCategory, EventPayload, EventSeverity};
+use crate::services::event_factory;
+use crate::services::goal_context_service::GoalContextService;
 
 use super::{PreSpawnContext, PreSpawnDecision, PreSpawnMiddleware};
 
+pub struct BudgetCeilingMiddleware;
+
+impl BudgetCeilingMiddleware {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BudgetCeilingMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl PreSpawnMiddleware for BudgetCeilingMiddleware {
+    fn name(&self) -> &'static str {
+        "budget-ceiling"
+    }
+
+    async fn handle(&self, ctx: &mut PreSpawnContext) -> DomainResult<PreSpawnDecision> {
+        let Some(ref bt) = ctx.budget_tracker else {
+            return Ok(PreSpawnDecision::Continue);
+        };
+        if !bt.is_ceiling_reached(ctx.total_tokens) {
+            return Ok(PreSpawnDecision::Continue);
+        }
+
+        tracing::debug!(
+            task_id = %ctx.task.id,
+            total_tokens = ctx.total_tokens,
+            "spawn_task_agent: skipping — token ceiling reached"
+        );
+        // Run the hard stop now rather than waiting for the next tick.
+        if bt.policy() == BudgetPolicy::HardStop && !ctx.dry_run {
+            ctx.event_bus
+                .publish(event_factory::make_event(
+                    EventSeverity::Debug,
+                    EventCategory::Scheduler,
+                    None,
+                    None,
+                    EventPayload::ScheduledEventFired {
+                        schedule_id: uuid::Uuid::new_v4(),
+                        name: "budget-check".to_string(),
+                    },
+                ))
+                .await;
+        }
+
+        Ok(PreSpawnDecision::Skip {
+            reason: "budget-ceiling".to_string(),
+        })
+    }
+}
+
 pub struct BudgetDispatchMiddleware;
 
 impl BudgetDispatchMiddleware {
@@ -79,9 +143,7 @@ impl PreSpawnMiddleware for BudgetConcurrencyMiddleware {
             return Ok(PreSpawnDecision::Continue);
         };
 
-        let running = ctx
-            .max_agents
-            .saturating_sub(ctx.agent_semaphore.available_permits());
+        let running = ctx.agent_slots.in_use();
         let budget_max = bt.effective_max_agents(ctx.max_agents as u32).await as usize;
         if running >= budget_max {
             tracing::debug!(
@@ -98,3 +160,189 @@ impl PreSpawnMiddleware for BudgetConcurrencyMiddleware {
         Ok(PreSpawnDecision::Continue)
     }
 }
+
+pub struct GoalBudgetMiddleware;
+
+impl GoalBudgetMiddleware {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for GoalBudgetMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl PreSpawnMiddleware for GoalBudgetMiddleware {
+    fn name(&self) -> &'static str {
+        "goal-budget"
+    }
+
+    async fn handle(&self, ctx: &mut PreSpawnContext) -> DomainResult<PreSpawnDecision> {
+        let Some(ref bt) = ctx.budget_tracker else {
+            return Ok(PreSpawnDecision::Continue);
+        };
+        if !bt.any_goal_over_budget().await {
+            return Ok(PreSpawnDecision::Continue);
+        }
+
+        let goals = GoalContextService::new(ctx.goal_repo.clone())
+            .get_goals_charged_for_task(&ctx.task)
+            .await?;
+        for goal in goals {
+            if bt.is_goal_over_budget(goal.id).await {
+                if !ctx.dry_run {
+                    tracing::debug!(
+                        task_id = %ctx.task.id,
+                        goal_id = %goal.id,
+                        "spawn_task_agent: skipping — goal token budget exceeded"
+                    );
+                }
+                return Ok(PreSpawnDecision::Skip {
+                    reason: format!("goal-budget-exceeded:{}", goal.name),
+                });
+            }
+        }
+
+        Ok(PreSpawnDecision::Continue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use crate::adapters::sqlite::test_support;
+    use crate::domain::models::{Goal, Task};
+    use crate::domain::ports::{AgentRepository, GoalRepository, TaskRepository};
+    use crate::services::budget_tracker::{BudgetTracker, BudgetTrackerConfig};
+    use crate::services::event_bus::{EventBus, EventBusConfig, EventPayload};
+    use crate::services::swarm_orchestrator::SpawnGate;
+    use crate::services::swarm_orchestrator::agent_slots::AgentSlots;
+    use crate::services::{AuditLogService, CircuitBreakerService, Guardrails};
+
+    #[tokio::test]
+    async fn goal_at_budget_cap_stops_spawning_while_other_goal_runs() {
+        let (task_repo, agent_repo, goal_repo) = test_support::setup_task_agent_goal_repos().await;
+        let capped = Goal::new("capped", "runaway goal");
+        let healthy = Goal::new("healthy", "well-behaved goal");
+        goal_repo.create(&capped).await.unwrap();
+        goal_repo.create(&healthy).await.unwrap();
+
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let mut rx = bus.subscribe();
+        let tracker = Arc::new(BudgetTracker::new(
+            BudgetTrackerConfig {
+                default_goal_token_budget: Some(10_000),
+                goal_token_budgets: HashMap::from([("capped".to_string(), 1_000)]),
+                ..Default::default()
+            },
+            bus.clone(),
+        ));
+
+        let task_repo: Arc<dyn TaskRepository> = task_repo;
+        let agent_repo: Arc<dyn AgentRepository> = agent_repo;
+        let goal_repo: Arc<dyn GoalRepository> = goal_repo;
+        let ctx_for = |goal: &Goal| {
+            let mut task = Task::with_title("work", "goal-budget test");
+            task.set_goal_id(goal.id);
+            PreSpawnContext {
+                task,
+                agent_type: None,
+                task_repo: task_repo.clone(),
+                agent_repo: agent_repo.clone(),
+                goal_repo: goal_repo.clone(),
+                audit_log: Arc::new(AuditLogService::with_defaults()),
+                circuit_breaker: Arc::new(CircuitBreakerService::with_defaults()),
+                guardrails: Arc::new(Guardrails::with_defaults()),
+                event_bus: bus.clone(),
+                cost_window_service: None,
+                budget_tracker: Some(tracker.clone()),
+                agent_slots: Arc::new(AgentSlots::new(4, Default::default())),
+                max_agents: 4,
+                total_tokens: 0,
+                federation_priority_bumps: 0,
+                dry_run: false,
+            }
+        };
+        let mw = GoalBudgetMiddleware::new();
+
+        tracker.record_goal_tokens(&capped, 1_200).await;
+        tracker.record_goal_tokens(&healthy, 1_200).await;
+
+        let decision = mw.handle(&mut ctx_for(&capped)).await.unwrap();
+        assert!(
+            matches!(decision, PreSpawnDecision::Skip { ref reason } if reason.contains("capped")),
+            "capped goal's task must not spawn"
+        );
+        let decision = mw.handle(&mut ctx_for(&healthy)).await.unwrap();
+        assert!(matches!(decision, PreSpawnDecision::Continue));
+
+        // A dry run reports the same block, under its own gate.
+        let mut dry = ctx_for(&capped);
+        dry.dry_run = true;
+        let decision = mw.handle(&mut dry).await.unwrap();
+        assert!(matches!(decision, PreSpawnDecision::Skip { .. }));
+        assert_eq!(SpawnGate::from_middleware(mw.name()), SpawnGate::GoalBudget);
+
+        let event = rx.recv().await.unwrap();
+        assert!(matches!(
+            event.payload,
+            EventPayload::GoalBudgetExceeded { goal_id, budget: 1_000, .. } if goal_id == capped.id
+        ));
+    }
+
+    #[tokio::test]
+    async fn ceiling_skips_spawns_and_triggers_hard_stop_check() {
+        let (task_repo, agent_repo, goal_repo) = test_support::setup_task_agent_goal_repos().await;
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let mut rx = bus.subscribe();
+        let tracker = Arc::new(BudgetTracker::new(
+            BudgetTrackerConfig {
+                token_ceiling: Some(5_000),
+                policy: BudgetPolicy::HardStop,
+                ..Default::default()
+            },
+            bus.clone(),
+        ));
+
+        let mut ctx = PreSpawnContext {
+            task: Task::with_title("work", "ceiling test"),
+            agent_type: None,
+            task_repo,
+            agent_repo,
+            goal_repo,
+            audit_log: Arc::new(AuditLogService::with_defaults()),
+            circuit_breaker: Arc::new(CircuitBreakerService::with_defaults()),
+            guardrails: Arc::new(Guardrails::with_defaults()),
+            event_bus: bus.clone(),
+            cost_window_service: None,
+            budget_tracker: Some(tracker),
+            agent_slots: Arc::new(AgentSlots::new(4, Default::default())),
+            max_agents: 4,
+            total_tokens: 4_999,
+            federation_priority_bumps: 0,
+            dry_run: false,
+        };
+        let mw = BudgetCeilingMiddleware::new();
+
+        let decision = mw.handle(&mut ctx).await.unwrap();
+        assert!(matches!(decision, PreSpawnDecision::Continue));
+
+        ctx.total_tokens = 5_000;
+        let decision = mw.handle(&mut ctx).await.unwrap();
+        assert!(
+            matches!(decision, PreSpawnDecision::Skip { ref reason } if reason == "budget-ceiling")
+        );
+        let event = rx.recv().await.unwrap();
+        assert!(matches!(
+            event.payload,
+            EventPayload::ScheduledEventFired { ref name, .. } if name == "budget-check"
+        ));
+    }
+}
@@ -43,21 +43,23 @@ impl PreSpawnMiddleware for CircuitBreakerMiddleware {
         let check_result = ctx.circuit_breaker.check(scope).await;
 
         if check_result.is_blocked() {
-            ctx.audit_log
-                .log(
-                    AuditEntry::new(
-                        AuditLevel::Warning,
-                        AuditCategory::Execution,
-                        AuditAction::CircuitBreakerTriggered,
-                        AuditActor::System,
-                        format!(
-                            "Task {} blocked by circuit breaker for agent '{}'",
-                            ctx.task.id, agent_type
-                        ),
+            if !ctx.dry_run {
+                ctx.audit_log
+                    .log(
+                        AuditEntry::new(
+                            AuditLevel::Warning,
+                            AuditCategory::Execution,
+                            AuditAction::CircuitBreakerTriggered,
+                            AuditActor::System,
+                            format!(
+                                "Task {} blocked by circuit breaker for agent '{}'",
+                                ctx.task.id, agent_type
+                            ),
+                        )
+                        .with_entity(ctx.task.id, "task"),
                     )
-                    .with_entity(ctx.task.id, "task"),
-                )
-                .await;
+                    .await;
+            }
 
             return Ok(PreSpawnDecision::Skip {
                 reason: format!("circuit-breaker-blocked:{}", agent_type),
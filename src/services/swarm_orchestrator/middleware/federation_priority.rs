@@ -104,6 +104,9 @@ impl PreSpawnMiddleware for FederationPriorityMiddleware {
         let previous = ctx.task.priority;
         ctx.task.priority = desired;
         ctx.federation_priority_bumps = ctx.federation_priority_bumps.saturating_add(1);
+        if ctx.dry_run {
+            return Ok(PreSpawnDecision::Continue);
+        }
 
         tracing::info!(
             task_id = %ctx.task.id,
@@ -137,10 +140,11 @@ mod tests {
     use crate::adapters::sqlite::test_support;
     use crate::domain::models::{Goal, Task};
     use crate::domain::ports::{AgentRepository, GoalRepository, TaskRepository};
+    use crate::services::event_bus::{EventBus, EventBusConfig};
+    use crate::services::swarm_orchestrator::agent_slots::AgentSlots;
     use crate::services::swarm_orchestrator::middleware::PreSpawnContext;
     use crate::services::{AuditLogService, CircuitBreakerService, Guardrails};
     use std::sync::Arc;
-    use tokio::sync::Semaphore;
 
     async fn make_ctx_with_goal(
         goal: Option<Goal>,
@@ -173,11 +177,14 @@ mod tests {
             audit_log: Arc::new(AuditLogService::with_defaults()),
             circuit_breaker: Arc::new(CircuitBreakerService::with_defaults()),
             guardrails: Arc::new(Guardrails::with_defaults()),
+            event_bus: Arc::new(EventBus::new(EventBusConfig::default())),
             cost_window_service: None,
             budget_tracker: None,
-            agent_semaphore: Arc::new(Semaphore::new(4)),
+            agent_slots: Arc::new(AgentSlots::new(4, Default::default())),
             max_agents: 4,
+            total_tokens: 0,
             federation_priority_bumps: 0,
+            dry_run: false,
         }
     }
 
@@ -1,19 +1,33 @@
 //! Pre-spawn middleware: honour the orchestrator guardrails.
 //!
 //! Guardrails track per-agent spawn rate and global limits. If a spawn would
-//! breach a limit, skip and let the task retry on the next poll.
+//! breach a limit, skip and let the task retry on the next poll. Hitting the
+//! spawn-rate limit additionally escalates to a human once per episode, since
+//! a sustained spawn storm usually points at a bug rather than load.
+
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use async_trait::async_trait;
 
 use crate::domain::errors::DomainResult;
+use crate::services::event_bus::{
+    EventCategory, EventPayload, EventSeverity, HumanEscalationPayload,
+};
+use crate::services::event_factory;
+use crate::services::guardrails::GuardrailResult;
 
 use super::{PreSpawnContext, PreSpawnDecision, PreSpawnMiddleware};
 
-pub struct GuardrailsMiddleware;
+pub struct GuardrailsMiddleware {
+    /// Set while spawns are being rate limited; cleared on the next allowed spawn.
+    rate_limit_escalated: AtomicBool,
+}
 
 impl GuardrailsMiddleware {
     pub fn new() -> Self {
-        Self
+        Self {
+            rate_limit_escalated: AtomicBool::new(false),
+        }
     }
 }
 
@@ -34,7 +48,40 @@ impl PreSpawnMiddleware for GuardrailsMiddleware {
         // matches the previous inline behaviour. Registration happens after
         // atomic claim in the orchestrator, not here.
         let unique_id = ctx.task.id.to_string();
-        let spawn_check = ctx.guardrails.check_agent_spawn(&unique_id).await;
+        let agent_type = ctx
+            .agent_type
+            .as_deref()
+            .or(ctx.task.agent_type.as_deref())
+            .unwrap_or_default();
+        let spawn_check = ctx
+            .guardrails
+            .check_agent_spawn(agent_type, &unique_id)
+            .await;
+
+        if let GuardrailResult::RateLimited(ref reason) = spawn_check
+            && !ctx.dry_run
+            && !self.rate_limit_escalated.swap(true, Ordering::SeqCst)
+        {
+            ctx.event_bus
+                .publish(event_factory::make_event(
+                    EventSeverity::Warning,
+                    EventCategory::Escalation,
+                    None,
+                    Some(ctx.task.id),
+                    EventPayload::HumanEscalationRequired(HumanEscalationPayload {
+                        goal_id: None,
+                        task_id: Some(ctx.task.id),
+                        reason: format!("{}; agent spawning is paused", reason),
+                        urgency: "high".to_string(),
+                        questions: vec![
+                            "Is something creating or retrying tasks in a loop?".to_string(),
+                        ],
+                        is_blocking: false,
+                    }),
+                ))
+                .await;
+        }
+
         if spawn_check.is_blocked() {
             tracing::debug!(
                 task_id = %ctx.task.id,
@@ -46,6 +93,9 @@ impl PreSpawnMiddleware for GuardrailsMiddleware {
             });
         }
 
+        if !ctx.dry_run {
+            self.rate_limit_escalated.store(false, Ordering::SeqCst);
+        }
         Ok(PreSpawnDecision::Continue)
     }
 }
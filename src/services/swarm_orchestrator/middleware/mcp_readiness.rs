@@ -90,21 +90,23 @@ impl PreSpawnMiddleware for McpReadinessMiddleware {
             return Ok(PreSpawnDecision::Continue);
         }
 
-        ctx.audit_log
-            .log(
-                AuditEntry::new(
-                    AuditLevel::Warning,
-                    AuditCategory::Execution,
-                    AuditAction::TaskFailed,
-                    AuditActor::System,
-                    format!(
-                        "Skipping spawn for task {} - MCP servers not ready (will retry next cycle)",
-                        ctx.task.id
-                    ),
+        if !ctx.dry_run {
+            ctx.audit_log
+                .log(
+                    AuditEntry::new(
+                        AuditLevel::Warning,
+                        AuditCategory::Execution,
+                        AuditAction::TaskFailed,
+                        AuditActor::System,
+                        format!(
+                            "Skipping spawn for task {} - MCP servers not ready (will retry next cycle)",
+                            ctx.task.id
+                        ),
+                    )
+                    .with_entity(ctx.task.id, "task"),
                 )
-                .with_entity(ctx.task.id, "task"),
-            )
-            .await;
+                .await;
+        }
 
         Ok(PreSpawnDecision::Skip {
             reason: "mcp-not-ready".to_string(),
@@ -2,44 +2,133 @@
 //!
 //! Resolution priority matches the previous inline logic:
 //! 1. Explicit `task.agent_type` (user specified `--agent`)
-//! 2. `task.routing_hints.preferred_agent` (validated against the agent repo)
-//! 3. Capability matching against `task.routing_hints.required_tools`
-//! 4. Default to `"overmind"`
+//! 2. The configured [`TaskRouter`] policy (validated against the agent repo)
+//! 3. `task.routing_hints.preferred_agent` (validated against the agent repo)
+//! 4. Templates declaring every `task.routing_hints.required_capabilities`,
+//!    best success rate first (ties broken by name)
+//! 5. Capability matching against `task.routing_hints.required_tools`
+//! 6. Default to `"overmind"`
+//!
+//! When the resolved type has no template, the `[task_routing.fallbacks]`
+//! chain is walked and the first type that does have one is used instead,
+//! announced with an `AgentTypeSubstituted` event.
 //!
 //! The resolved value is stored on the context AND (when the task didn't
 //! previously have `agent_type` set) persisted back on the task record so
 //! audit logs and task queries reflect the routing decision.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 
 use crate::domain::errors::DomainResult;
 use crate::domain::models::Task;
-use crate::domain::ports::{AgentFilter, AgentRepository};
+use crate::domain::ports::{AgentFilter, AgentRepository, TaskFilter, TaskRepository};
+use crate::services::config::TaskRoutingConfig;
+use crate::services::event_bus::{EventCategory, EventPayload, EventSeverity};
+use crate::services::event_factory;
+use crate::services::task_router::{DefaultTaskRouter, TaskRouter};
+use crate::services::task_service::agent_type_metrics;
 
 use super::{PreSpawnContext, PreSpawnDecision, PreSpawnMiddleware};
 
 /// Resolve a task's agent_type.
-pub struct RouteTaskMiddleware;
+pub struct RouteTaskMiddleware {
+    router: Arc<dyn TaskRouter>,
+    routing_config: TaskRoutingConfig,
+}
 
 impl RouteTaskMiddleware {
     pub fn new() -> Self {
-        Self
+        Self {
+            router: Arc::new(DefaultTaskRouter),
+            routing_config: TaskRoutingConfig::default(),
+        }
     }
 
-    async fn route(task: &Task, agent_repo: &dyn AgentRepository) -> String {
+    /// Consult `router` before the built-in fallbacks.
+    pub fn with_router(mut self, router: Arc<dyn TaskRouter>) -> Self {
+        self.router = router;
+        self
+    }
+
+    /// Substitute along `config.fallbacks` for agent types without a template.
+    pub fn with_routing_config(mut self, config: TaskRoutingConfig) -> Self {
+        self.routing_config = config;
+        self
+    }
+
+    /// The first of `agent_type` and its fallback chain that has a template,
+    /// or `agent_type` itself when none does. Types without a chain are
+    /// returned unchecked.
+    async fn resolve_fallback(
+        &self,
+        agent_type: String,
+        agent_repo: &dyn AgentRepository,
+    ) -> String {
+        let chain = self.routing_config.fallback_chain(&agent_type);
+        if chain.is_empty() || Self::has_template(agent_repo, &agent_type).await {
+            return agent_type;
+        }
+        for candidate in chain {
+            if Self::has_template(agent_repo, candidate).await {
+                return candidate.to_string();
+            }
+        }
+        tracing::warn!(
+            %agent_type,
+            "route_task: no template for agent type or any of its fallbacks"
+        );
+        agent_type
+    }
+
+    async fn has_template(agent_repo: &dyn AgentRepository, name: &str) -> bool {
+        matches!(agent_repo.get_template_by_name(name).await, Ok(Some(_)))
+    }
+
+    async fn route(
+        &self,
+        task: &Task,
+        agent_repo: &dyn AgentRepository,
+        task_repo: &dyn TaskRepository,
+    ) -> String {
         // 1. Explicit assignment
         if let Some(ref agent) = task.agent_type {
             return agent.clone();
         }
 
-        // 2. Preferred agent (validate existence)
+        // 2. Routing policy (validate existence)
+        if let Some(agent) = self.router.route(task) {
+            if Self::has_template(agent_repo, &agent).await {
+                return agent;
+            }
+            tracing::warn!(
+                task_id = %task.id,
+                agent_type = %agent,
+                "route_task: router picked an agent type with no template; ignoring it"
+            );
+        }
+
+        // 3. Preferred agent (validate existence)
         if let Some(ref preferred) = task.routing_hints.preferred_agent
             && let Ok(Some(_)) = agent_repo.get_template_by_name(preferred).await
         {
             return preferred.clone();
         }
 
-        // 3. Capability matching
+        // 4. Declared capabilities
+        if !task.routing_hints.required_capabilities.is_empty()
+            && let Some(matched) = Self::match_agent_by_capabilities(
+                agent_repo,
+                task_repo,
+                &task.routing_hints.required_capabilities,
+            )
+            .await
+        {
+            return matched;
+        }
+
+        // 5. Capability matching
         if !task.routing_hints.required_tools.is_empty()
             && let Some(matched) =
                 Self::match_agent_by_tools(agent_repo, &task.routing_hints.required_tools).await
@@ -47,7 +136,7 @@ impl RouteTaskMiddleware {
             return matched;
         }
 
-        // 4. Default: route to overmind
+        // 6. Default: route to overmind
         if task.parent_id.is_some() {
             tracing::warn!(
                 task_id = %task.id,
@@ -58,6 +147,55 @@ impl RouteTaskMiddleware {
         "overmind".to_string()
     }
 
+    /// The active template declaring every capability in `required` with the
+    /// best success rate over its finished tasks. Templates without finished
+    /// tasks rank below any measured rate; remaining ties go to the first
+    /// name alphabetically, so the choice is stable across runs.
+    async fn match_agent_by_capabilities(
+        agent_repo: &dyn AgentRepository,
+        task_repo: &dyn TaskRepository,
+        required: &[String],
+    ) -> Option<String> {
+        let mut candidates: Option<Vec<String>> = None;
+        for capability in required {
+            let names: Vec<String> = agent_repo
+                .find_by_capability(capability)
+                .await
+                .ok()?
+                .into_iter()
+                .map(|t| t.name)
+                .collect();
+            candidates = Some(match candidates {
+                Some(prev) => prev.into_iter().filter(|n| names.contains(n)).collect(),
+                None => names,
+            });
+        }
+
+        let mut best: Option<(Option<f64>, String)> = None;
+        for name in candidates? {
+            let tasks = task_repo
+                .list(TaskFilter {
+                    agent_type: Some(name.clone()),
+                    ..Default::default()
+                })
+                .await
+                .ok()?;
+            let rate = agent_type_metrics(&tasks).first().map(|m| m.success_rate);
+            // Candidates arrive sorted by name, so only a strictly better
+            // rate displaces the current pick.
+            let better = match (&best, rate) {
+                (None, _) => true,
+                (Some((Some(best_rate), _)), Some(rate)) => rate > *best_rate,
+                (Some((None, _)), Some(_)) => true,
+                (Some(_), None) => false,
+            };
+            if better {
+                best = Some((rate, name));
+            }
+        }
+        best.map(|(_, name)| name)
+    }
+
     async fn match_agent_by_tools(
         agent_repo: &dyn AgentRepository,
         required_tools: &[String],
@@ -109,11 +247,39 @@ impl PreSpawnMiddleware for RouteTaskMiddleware {
     }
 
     async fn handle(&self, ctx: &mut PreSpawnContext) -> DomainResult<PreSpawnDecision> {
-        let agent_type = Self::route(&ctx.task, &*ctx.agent_repo).await;
+        let routed = self
+            .route(&ctx.task, &*ctx.agent_repo, &*ctx.task_repo)
+            .await;
+        let agent_type = self
+            .resolve_fallback(routed.clone(), &*ctx.agent_repo)
+            .await;
+
+        if agent_type != routed && !ctx.dry_run {
+            tracing::info!(
+                task_id = %ctx.task.id,
+                requested = %routed,
+                substitute = %agent_type,
+                "route_task: agent type has no template; using fallback"
+            );
+            ctx.event_bus
+                .publish(event_factory::make_event(
+                    EventSeverity::Warning,
+                    EventCategory::Agent,
+                    None,
+                    Some(ctx.task.id),
+                    EventPayload::AgentTypeSubstituted {
+                        task_id: ctx.task.id,
+                        requested: routed,
+                        substitute: agent_type.clone(),
+                    },
+                ))
+                .await;
+        }
 
         // Persist routing decision only when task.agent_type was None — same
         // condition the previous inline logic used.
         if ctx.task.agent_type.is_none()
+            && !ctx.dry_run
             && let Ok(Some(mut updated)) = ctx.task_repo.get(ctx.task.id).await
         {
             updated.agent_type = Some(agent_type.clone());
@@ -124,3 +290,190 @@ impl PreSpawnMiddleware for RouteTaskMiddleware {
         Ok(PreSpawnDecision::Continue)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    use crate::adapters::sqlite::test_support;
+    use crate::domain::models::{AgentTemplate, AgentTier, TaskStatus};
+    use crate::domain::ports::{GoalRepository, TaskRepository};
+    use crate::services::event_bus::{EventBus, EventBusConfig};
+    use crate::services::swarm_orchestrator::agent_slots::AgentSlots;
+    use crate::services::{AuditLogService, CircuitBreakerService, Guardrails};
+
+    fn context(
+        task: Task,
+        task_repo: Arc<dyn TaskRepository>,
+        agent_repo: Arc<dyn AgentRepository>,
+        goal_repo: Arc<dyn GoalRepository>,
+        event_bus: Arc<EventBus>,
+    ) -> PreSpawnContext {
+        PreSpawnContext {
+            task,
+            agent_type: None,
+            task_repo,
+            agent_repo,
+            goal_repo,
+            audit_log: Arc::new(AuditLogService::with_defaults()),
+            circuit_breaker: Arc::new(CircuitBreakerService::with_defaults()),
+            guardrails: Arc::new(Guardrails::with_defaults()),
+            event_bus,
+            cost_window_service: None,
+            budget_tracker: None,
+            agent_slots: Arc::new(AgentSlots::new(4, Default::default())),
+            max_agents: 4,
+            total_tokens: 0,
+            federation_priority_bumps: 0,
+            dry_run: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn missing_agent_type_falls_back_along_chain() {
+        let (task_repo, agent_repo, goal_repo) = test_support::setup_task_agent_goal_repos().await;
+        agent_repo
+            .create_template(&AgentTemplate::new("worker", AgentTier::Worker))
+            .await
+            .unwrap();
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let mut rx = bus.subscribe();
+
+        let task = Task::with_title("port parser", "rewrite the parser").with_agent("rust-expert");
+        let mut ctx = context(task.clone(), task_repo, agent_repo, goal_repo, bus.clone());
+
+        // Without a configured chain the requested type is kept as-is.
+        RouteTaskMiddleware::new().handle(&mut ctx).await.unwrap();
+        assert_eq!(ctx.agent_type.as_deref(), Some("rust-expert"));
+
+        let mw = RouteTaskMiddleware::new().with_routing_config(TaskRoutingConfig {
+            fallbacks: HashMap::from([
+                ("rust-expert".to_string(), "backend-dev".to_string()),
+                ("backend-dev".to_string(), "worker".to_string()),
+            ]),
+            ..Default::default()
+        });
+        let decision = mw.handle(&mut ctx).await.unwrap();
+        assert!(matches!(decision, PreSpawnDecision::Continue));
+        assert_eq!(ctx.agent_type.as_deref(), Some("worker"));
+
+        let event = rx.recv().await.unwrap();
+        assert!(matches!(
+            event.payload,
+            EventPayload::AgentTypeSubstituted { task_id, ref requested, ref substitute }
+                if task_id == task.id && requested == "rust-expert" && substitute == "worker"
+        ));
+    }
+
+    #[tokio::test]
+    async fn required_capabilities_pick_best_success_rate() {
+        let (task_repo, agent_repo, goal_repo) = test_support::setup_task_agent_goal_repos().await;
+        for (name, caps) in [
+            ("auditor", &["code-review", "security-audit"][..]),
+            ("critic", &["code-review"][..]),
+            ("reviewer", &["code-review"][..]),
+        ] {
+            let mut template = AgentTemplate::new(name, AgentTier::Specialist);
+            for cap in caps {
+                template = template.with_capability(*cap);
+            }
+            agent_repo.create_template(&template).await.unwrap();
+        }
+        // auditor: 1 of 2 succeeded; critic: 2 of 2; reviewer: 2 of 2.
+        for (agent, complete) in [
+            ("auditor", true),
+            ("auditor", false),
+            ("critic", true),
+            ("critic", true),
+            ("reviewer", true),
+            ("reviewer", true),
+        ] {
+            let mut task = Task::with_title("review", "review a diff").with_agent(agent);
+            if complete {
+                task.status = TaskStatus::Complete;
+            } else {
+                task.status = TaskStatus::Failed;
+                task.retry_count = task.max_retries;
+            }
+            task_repo.create(&task).await.unwrap();
+        }
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+
+        let route = |caps: &[&str]| {
+            let mut task = Task::with_title("review PR", "review the open PR");
+            task.routing_hints.required_capabilities = caps.iter().map(|c| c.to_string()).collect();
+            context(
+                task,
+                task_repo.clone(),
+                agent_repo.clone(),
+                goal_repo.clone(),
+                bus.clone(),
+            )
+        };
+
+        // critic and reviewer tie on success rate; the tie goes to critic by name.
+        let mut ctx = route(&["code-review"]);
+        RouteTaskMiddleware::new().handle(&mut ctx).await.unwrap();
+        assert_eq!(ctx.agent_type.as_deref(), Some("critic"));
+
+        // Every capability must be declared.
+        let mut ctx = route(&["code-review", "security-audit"]);
+        RouteTaskMiddleware::new().handle(&mut ctx).await.unwrap();
+        assert_eq!(ctx.agent_type.as_deref(), Some("auditor"));
+
+        // Nothing declares it: fall through to the default.
+        let mut ctx = route(&["deploy"]);
+        RouteTaskMiddleware::new().handle(&mut ctx).await.unwrap();
+        assert_eq!(ctx.agent_type.as_deref(), Some("overmind"));
+    }
+
+    #[tokio::test]
+    async fn router_pick_without_template_falls_through() {
+        use crate::services::config::KeywordRoutingRule;
+        use crate::services::task_router::KeywordTaskRouter;
+
+        let (task_repo, agent_repo, goal_repo) = test_support::setup_task_agent_goal_repos().await;
+        agent_repo
+            .create_template(&AgentTemplate::new(
+                "technical-writer",
+                AgentTier::Specialist,
+            ))
+            .await
+            .unwrap();
+        let bus = Arc::new(EventBus::new(EventBusConfig::default()));
+        let mw = RouteTaskMiddleware::new().with_router(Arc::new(KeywordTaskRouter::new(
+            &TaskRoutingConfig {
+                rules: vec![
+                    KeywordRoutingRule {
+                        agent_type: "qa-engineer".to_string(),
+                        keywords: vec!["flaky".to_string()],
+                    },
+                    KeywordRoutingRule {
+                        agent_type: "technical-writer".to_string(),
+                        keywords: vec!["readme".to_string()],
+                    },
+                ],
+                ..Default::default()
+            },
+        )));
+
+        let task = Task::with_title("Update README", "Describe the new flags");
+        let mut ctx = context(
+            task,
+            task_repo.clone(),
+            agent_repo.clone(),
+            goal_repo.clone(),
+            bus.clone(),
+        );
+        mw.handle(&mut ctx).await.unwrap();
+        assert_eq!(ctx.agent_type.as_deref(), Some("technical-writer"));
+
+        // qa-engineer has no template, so routing moves on to the preferred agent.
+        let mut task = Task::with_title("Fix flaky test", "The login test is flaky");
+        task.routing_hints.preferred_agent = Some("technical-writer".to_string());
+        let mut ctx = context(task, task_repo, agent_repo, goal_repo, bus);
+        mw.handle(&mut ctx).await.unwrap();
+        assert_eq!(ctx.agent_type.as_deref(), Some("technical-writer"));
+    }
+}
@@ -13,6 +13,7 @@
 mod advanced_services;
 mod agent_lifecycle;
 pub(crate) mod agent_prep;
+pub mod agent_slots;
 pub(crate) mod convergent_execution;
 mod core_deps;
 mod daemon_handles;
@@ -41,13 +42,14 @@ use subsystem_services::SubsystemServices;
 
 // Re-export public types
 pub use types::{
-    ConvergenceLoopConfig, McpServerConfig, OrchestratorStatus, PollingConfig, SwarmConfig,
-    SwarmEvent, SwarmStats, VerificationLevel,
+    ConvergenceLoopConfig, McpServerConfig, OrchestratorStatus, PollingConfig, SpawnDecision,
+    SpawnGate, SwarmConfig, SwarmEvent, SwarmStats, VerificationLevel,
 };
 
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
+use crate::adapters::substrates::{RoutingPolicy, SubstrateRegistry};
 use crate::domain::errors::DomainResult;
 use crate::domain::ports::{
     AgentRepository, GoalRepository, MemoryRepository, NullMemoryRepository, Substrate,
@@ -145,7 +147,7 @@ where
         event_reactor: Arc<EventReactor>,
         event_scheduler: Arc<EventScheduler>,
     ) -> Self {
-        let max_agents = config.max_agents;
+        let runtime_state = RuntimeState::new(config.max_agents, &config.agent_slot_weights);
         Self {
             // ---------------- Core dependencies (required) ----------------
             core_deps: CoreDeps {
@@ -154,11 +156,13 @@ where
                 worktree_repo,
                 agent_repo,
                 substrate,
+                failover_substrates: Vec::new(),
+                substrate_routing: None,
                 config,
             },
 
             // ---------------- Runtime state ----------------
-            runtime_state: RuntimeState::new(max_agents),
+            runtime_state,
 
             // ---------------- Subsystem services (always present) ----------------
             subsystem_services: SubsystemServices::new(event_bus, event_reactor, event_scheduler),
@@ -300,6 +304,7 @@ where
             include_artifacts: true,
             include_task_output: true,
             verifier_agent_type: "intent-verifier".to_string(),
+            ..IntentVerifierConfig::default()
         };
         self.advanced_services.intent_verifier = Some(Arc::new(IntentVerifierService::new(
             self.core_deps.goal_repo.clone(),
@@ -310,6 +315,24 @@ where
         self
     }
 
+    /// Fail tasks over to these substrates, in order, while the primary
+    /// substrate's circuit breaker is open.
+    pub fn with_failover_substrates(mut self, substrates: Vec<Arc<dyn Substrate>>) -> Self {
+        self.core_deps.failover_substrates = substrates;
+        self
+    }
+
+    /// Spread spawns over `registry`'s substrates under `policy`, skipping
+    /// any whose circuit breaker is open. Replaces primary/failover selection.
+    pub fn with_substrate_routing(
+        mut self,
+        registry: SubstrateRegistry,
+        policy: RoutingPolicy,
+    ) -> Self {
+        self.core_deps.substrate_routing = Some((Arc::new(registry), policy));
+        self
+    }
+
     /// Create orchestrator with custom guardrails configuration.
     pub fn with_guardrails(mut self, config: GuardrailsConfig) -> Self {
         self.subsystem_services.guardrails = Arc::new(Guardrails::new(config));
@@ -325,6 +348,24 @@ where
         self
     }
 
+    /// Use a custom policy to pick agent types for tasks submitted without one,
+    /// replacing the keyword router built from `[task_routing]`.
+    pub fn with_task_router(mut self, router: Arc<dyn crate::services::TaskRouter>) -> Self {
+        self.advanced_services.task_router = Some(router);
+        self
+    }
+
+    /// Routing policy for unassigned tasks: the custom router if one was
+    /// set, otherwise the configured keyword rules.
+    pub(crate) fn task_router(&self) -> Arc<dyn crate::services::TaskRouter> {
+        match self.advanced_services.task_router {
+            Some(ref router) => router.clone(),
+            None => Arc::new(crate::services::KeywordTaskRouter::new(
+                &self.core_deps.config.task_routing,
+            )),
+        }
+    }
+
     /// Create orchestrator with memory repository for cold start and decay daemon.
     pub fn with_memory_repo(mut self, memory_repo: Arc<M>) -> Self {
         self.advanced_services.memory_repo = Some(memory_repo);
@@ -333,7 +374,11 @@ where
 
     /// Create orchestrator with custom audit log configuration.
     pub fn with_audit_log(mut self, config: AuditLogConfig) -> Self {
-        self.subsystem_services.audit_log = Arc::new(AuditLogService::new(config));
+        let mut audit_log = AuditLogService::new(config);
+        if let Some(pool) = &self.advanced_services.pool {
+            audit_log = audit_log.with_pool(pool.clone());
+        }
+        self.subsystem_services.audit_log = Arc::new(audit_log);
         self
     }
 
@@ -344,10 +389,12 @@ where
     }
 
     /// Provide a DB pool for services that need persistence (absence timers, command dedup,
-    /// evolution loop refinement requests, and event outbox).
+    /// evolution loop refinement requests, event outbox, the audit log, and the operator
+    /// pause flag).
     pub fn with_pool(mut self, pool: sqlx::SqlitePool) -> Self {
         use crate::adapters::sqlite::{
             SqliteMergeRequestRepository, SqliteOutboxRepository, SqliteRefinementRepository,
+            SqliteSwarmControlRepository,
         };
         use crate::services::evolution_loop::EvolutionConfig;
 
@@ -361,6 +408,12 @@ where
             Some(Arc::new(SqliteOutboxRepository::new(pool.clone())));
         self.advanced_services.merge_request_repo =
             Some(Arc::new(SqliteMergeRequestRepository::new(pool.clone())));
+        self.advanced_services.swarm_control_repo =
+            Some(Arc::new(SqliteSwarmControlRepository::new(pool.clone())));
+        self.subsystem_services.audit_log = Arc::new(
+            AuditLogService::new(self.subsystem_services.audit_log.config().clone())
+                .with_pool(pool.clone()),
+        );
         self.advanced_services.pool = Some(pool);
         self
     }
@@ -447,6 +500,26 @@ where
         self
     }
 
+    /// Attach a result cache so cacheable tasks can reuse the output of an
+    /// identical earlier run instead of starting an agent.
+    pub fn with_result_cache(
+        mut self,
+        cache: Arc<crate::services::result_cache::ResultCache>,
+    ) -> Self {
+        self.advanced_services.result_cache = Some(cache);
+        self
+    }
+
+    /// Attach an escalation notifier so human escalations are announced
+    /// through the sinks routed for their urgency.
+    pub fn with_escalation_notifier(
+        mut self,
+        notifier: Arc<crate::services::escalation_notifier::EscalationNotifier>,
+    ) -> Self {
+        self.advanced_services.escalation_notifier = Some(notifier);
+        self
+    }
+
     // -- Service Accessors --
 
     /// Get the Overmind service if configured.
@@ -718,6 +791,16 @@ where
                     )
                     .await
             };
+            if let Ok(types) = agent_service
+                .unresolvable_fallbacks(&self.core_deps.config.task_routing)
+                .await
+                && !types.is_empty()
+            {
+                tracing::warn!(
+                    agent_types = ?types,
+                    "No template for these agent types or any of their [task_routing.fallbacks]"
+                );
+            }
             match seed_result {
                 Ok(seeded) if !seeded.is_empty() => {
                     self.subsystem_services.audit_log
@@ -949,6 +1032,7 @@ where
         };
         let mut tick_counter: u64 = 0;
         let mut idle_terminal_ticks: u64 = 0; // consecutive timer ticks with all terminal
+        let mut pause_flag: Option<bool> = None; // last operator pause flag applied
         let command_retention = std::time::Duration::from_secs(7 * 24 * 3600); // 7 days
 
         enum Wake {
@@ -959,6 +1043,7 @@ where
 
         // Main orchestration loop
         loop {
+            self.sync_pause_flag(&mut pause_flag, &event_tx).await;
             let current_status = self.runtime_state.status.read().await.clone();
 
             match current_status {
@@ -1138,6 +1223,14 @@ where
             }
         }
 
+        // Let running agents finish (their completions still flow through
+        // the reactor) before tearing anything down.
+        let drain_timeout =
+            std::time::Duration::from_secs(self.core_deps.config.shutdown_drain_timeout_secs);
+        if let Err(e) = self.drain(drain_timeout).await {
+            tracing::error!(error = %e, "drain on shutdown failed");
+        }
+
         // Flush pending watermarks before stopping the reactor
         self.subsystem_services.event_reactor.flush_watermarks().await;
 
@@ -1176,6 +1269,69 @@ where
         Ok(())
     }
 
+    /// Stop spawning agents and wait up to `timeout` for running ones to
+    /// finish. Agents of this process still running after that are stopped
+    /// and their tasks restarted to Ready with a hint explaining why,
+    /// instead of being left orphaned. Tasks other processes are running in
+    /// the same database are left alone. Returns the IDs of the restarted
+    /// tasks.
+    pub async fn drain(&self, timeout: std::time::Duration) -> DomainResult<Vec<uuid::Uuid>> {
+        self.stop().await;
+        let active = self.runtime_state.agent_slots.active_agents();
+        if self.runtime_state.agent_slots.wait_idle(timeout).await {
+            tracing::info!(agents = active, "drain: all running agents finished");
+            return Ok(Vec::new());
+        }
+
+        // Stop the stragglers before touching their tasks, so none of them
+        // reports back onto a task that has already been re-queued.
+        let stopped = self.runtime_state.agent_slots.stop_owned().await;
+
+        let task_service = crate::services::TaskService::new(self.core_deps.task_repo.clone())
+            .with_event_bus(self.subsystem_services.event_bus.clone());
+        let mut requeued = Vec::new();
+        for task_id in stopped {
+            let Some(task) = self.core_deps.task_repo.get(task_id).await? else {
+                continue;
+            };
+            if task.status != crate::domain::models::TaskStatus::Running {
+                continue;
+            }
+            let requeue = async {
+                task_service
+                    .restart_task(
+                        task.id,
+                        crate::domain::models::TaskStatus::Ready,
+                        "swarm shut down before the agent finished",
+                    )
+                    .await?;
+                task_service
+                    .merge_task_context(
+                        task.id,
+                        Vec::new(),
+                        vec![
+                            "The swarm shut down while a previous attempt was running; \
+                             check the worktree for partial work before starting over."
+                                .to_string(),
+                        ],
+                    )
+                    .await
+            };
+            match requeue.await {
+                Ok(_) => requeued.push(task.id),
+                Err(e) => {
+                    tracing::warn!(task_id = %task.id, error = %e, "drain: failed to requeue task")
+                }
+            }
+        }
+        tracing::warn!(
+            timeout_secs = timeout.as_secs(),
+            requeued = requeued.len(),
+            "drain: timed out waiting for agents; requeued their tasks"
+        );
+        Ok(requeued)
+    }
+
     /// Run a single iteration of the orchestration loop.
     pub async fn tick(&self) -> DomainResult<SwarmStats> {
         let (tx, _rx) = mpsc::channel(100);
@@ -1216,13 +1372,78 @@ where
         let mut rx = self.runtime_state.ready_task_rx.lock().await;
         let mut spawned_ids = std::collections::HashSet::new();
 
+        // The status check is a cheap pre-filter only; the claim below is
+        // what guarantees a task grabbed by another drainer is not spawned
+        // twice.
+        let mut ready = Vec::new();
         while let Ok(task_id) = rx.try_recv() {
-            // Fetch and validate task is still Ready
             if let Ok(Some(task)) = self.core_deps.task_repo.get(task_id).await
                 && task.status == crate::domain::models::TaskStatus::Ready
             {
-                self.spawn_task_agent(&task, event_tx).await?;
-                spawned_ids.insert(task_id);
+                spawned_ids.insert(task.id);
+                ready.push(task);
+            }
+        }
+        // Consider tasks in the same order the scheduler ranks them.
+        goal_processing::rank_by_aged_priority(
+            &mut ready,
+            crate::domain::models::Task::ready_key,
+            chrono::Utc::now(),
+            self.core_deps.config.priority_aging_coefficient,
+        );
+
+        // Gate and route every candidate before anything is claimed, so a
+        // task the middleware refuses is never moved to Running.
+        let mut admitted = Vec::new();
+        for task in ready {
+            if let Some(agent_type) = self.admit_spawn(&task).await? {
+                admitted.push((task, agent_type));
+            }
+        }
+
+        // Claim the best admitted task still Ready among those routed to the
+        // same agent type as the head of the queue, one statement per spawn.
+        while let Some((_, agent_type)) = admitted.first() {
+            let agent_type = agent_type.clone();
+            let Some(substrate) = self.select_substrate().await else {
+                tracing::debug!("drain_ready_tasks: all substrate circuits open, skipping spawns");
+                break;
+            };
+            let Some(permit) = self.runtime_state.agent_slots.try_acquire(&agent_type) else {
+                admitted.retain(|(_, t)| *t != agent_type);
+                continue;
+            };
+            let candidates: Vec<uuid::Uuid> = admitted
+                .iter()
+                .filter(|(_, t)| *t == agent_type)
+                .map(|(task, _)| task.id)
+                .collect();
+            match self
+                .core_deps
+                .task_repo
+                .claim_next_ready(&candidates, &agent_type)
+                .await
+            {
+                Ok(Some(claimed)) => {
+                    let task = match admitted.iter().position(|(t, _)| t.id == claimed.id) {
+                        Some(position) => admitted.remove(position).0,
+                        None => claimed.clone(),
+                    };
+                    self.launch_claimed_task(&task, claimed, agent_type, substrate, permit, event_tx)
+                        .await?;
+                }
+                Ok(None) => {
+                    // Every candidate of this type was taken by another drainer.
+                    admitted.retain(|(_, t)| *t != agent_type);
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        %agent_type,
+                        "drain_ready_tasks: failed to claim a ready task: {}",
+                        e
+                    );
+                    break;
+                }
             }
         }
 
@@ -1332,6 +1553,37 @@ mod tests {
         assert_eq!(orchestrator.status().await, OrchestratorStatus::Idle);
     }
 
+    #[tokio::test]
+    async fn test_sync_pause_flag_applies_operator_changes() {
+        use crate::adapters::sqlite::{SqliteSwarmControlRepository, create_migrated_test_pool};
+        use crate::domain::ports::SwarmControlRepository;
+
+        let control = Arc::new(SqliteSwarmControlRepository::new(
+            create_migrated_test_pool().await.unwrap(),
+        ));
+        let mut orchestrator = setup_orchestrator().await;
+        orchestrator.advanced_services.swarm_control_repo = Some(control.clone());
+        *orchestrator.runtime_state.status.write().await = OrchestratorStatus::Running;
+        let (tx, mut rx) = mpsc::channel(8);
+        let mut applied = None;
+
+        control.set_paused(true).await.unwrap();
+        orchestrator.sync_pause_flag(&mut applied, &tx).await;
+        assert_eq!(orchestrator.status().await, OrchestratorStatus::Paused);
+        assert!(matches!(rx.try_recv(), Ok(SwarmEvent::Paused)));
+
+        control.set_paused(false).await.unwrap();
+        orchestrator.sync_pause_flag(&mut applied, &tx).await;
+        assert_eq!(orchestrator.status().await, OrchestratorStatus::Running);
+        assert!(matches!(rx.try_recv(), Ok(SwarmEvent::Resumed)));
+
+        // An unchanged flag leaves an in-process pause alone.
+        orchestrator.pause().await;
+        orchestrator.sync_pause_flag(&mut applied, &tx).await;
+        assert_eq!(orchestrator.status().await, OrchestratorStatus::Paused);
+        assert!(rx.try_recv().is_err());
+    }
+
     #[tokio::test]
     async fn test_tick_empty() {
         let orchestrator = setup_orchestrator().await;
@@ -1340,12 +1592,650 @@ mod tests {
         assert_eq!(stats.pending_tasks, 0);
     }
 
+    #[tokio::test]
+    async fn test_drain_waits_for_agents_then_stops_and_requeues_own_stragglers() {
+        use crate::domain::models::{Task, TaskStatus};
+        use std::time::Duration;
+
+        let orchestrator = setup_orchestrator().await;
+        let task_repo = orchestrator.core_deps.task_repo.clone();
+        let running_task = || async {
+            let mut task = Task::new("Refactor the parser");
+            task.transition_to(TaskStatus::Ready).unwrap();
+            task.transition_to(TaskStatus::Running).unwrap();
+            task_repo.create(&task).await.unwrap();
+            task
+        };
+
+        // An agent that finishes within the timeout is waited for.
+        let quick = running_task().await;
+        let slots = orchestrator.runtime_state.agent_slots.clone();
+        let permit = slots.try_acquire("coder").unwrap();
+        let repo = task_repo.clone();
+        let quick_id = quick.id;
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let mut task = repo.get(quick_id).await.unwrap().unwrap();
+            task.transition_to(TaskStatus::Complete).unwrap();
+            repo.update(&task).await.unwrap();
+            drop(permit);
+        });
+        let requeued = orchestrator.drain(Duration::from_secs(5)).await.unwrap();
+        assert!(requeued.is_empty());
+        assert_eq!(
+            orchestrator.status().await,
+            OrchestratorStatus::ShuttingDown
+        );
+        assert_eq!(
+            task_repo.get(quick.id).await.unwrap().unwrap().status,
+            TaskStatus::Complete
+        );
+
+        // One still running at the timeout is stopped and its task put back
+        // to Ready; another process's Running task is not ours to touch.
+        let slow = running_task().await;
+        let foreign = running_task().await;
+        let mut permit = slots.try_acquire("coder").unwrap();
+        permit.bind_task(slow.id);
+        let repo = task_repo.clone();
+        let slow_id = slow.id;
+        let worker = tokio::spawn(async move {
+            let _permit = permit;
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            let mut task = repo.get(slow_id).await.unwrap().unwrap();
+            task.transition_to(TaskStatus::Complete).unwrap();
+            repo.update(&task).await.unwrap();
+        });
+        slots.attach_worker(slow.id, worker);
+
+        let requeued = orchestrator.drain(Duration::from_millis(50)).await.unwrap();
+        assert_eq!(requeued, vec![slow.id]);
+        assert_eq!(slots.active_agents(), 0);
+        let slow = task_repo.get(slow.id).await.unwrap().unwrap();
+        assert_eq!(slow.status, TaskStatus::Ready);
+        assert!(slow.context.hints.iter().any(|h| h.contains("shut down")));
+        assert_eq!(
+            task_repo.get(foreign.id).await.unwrap().unwrap().status,
+            TaskStatus::Running
+        );
+    }
+
     #[tokio::test]
     async fn test_token_tracking() {
         let orchestrator = setup_orchestrator().await;
         assert_eq!(orchestrator.total_tokens(), 0);
     }
 
+    #[tokio::test]
+    async fn test_spawn_decision_names_blocking_gate() {
+        use crate::domain::models::{Task, TaskStatus};
+
+        let orchestrator = setup_orchestrator()
+            .await
+            .with_guardrails(GuardrailsConfig {
+                max_concurrent_agents: 0,
+                ..Default::default()
+            });
+
+        // Register only the gates under test; the full built-in chain also
+        // probes local MCP infrastructure, which depends on the environment.
+        {
+            let mut chain = orchestrator.middleware.pre_spawn_chain.write().await;
+            chain.register(Arc::new(middleware::RouteTaskMiddleware::new()));
+            chain.register(Arc::new(middleware::GuardrailsMiddleware::new()));
+        }
+
+        let pending = Task::new("Not ready yet");
+        let decision = orchestrator.spawn_decision(&pending).await.unwrap();
+        assert_eq!(decision.blocked_by, Some(SpawnGate::NotReady));
+
+        let mut ready = Task::new("Ready but over the agent limit").with_agent("coder");
+        ready.status = TaskStatus::Ready;
+        let decision = orchestrator.spawn_decision(&ready).await.unwrap();
+        assert!(!decision.would_spawn());
+        assert_eq!(decision.blocked_by, Some(SpawnGate::Guardrails));
+        assert_eq!(decision.middleware.as_deref(), Some("guardrails"));
+        assert!(
+            decision.reason.contains("Maximum concurrent agents"),
+            "reason should carry the guardrail message; got: {}",
+            decision.reason
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chained_task_reuses_predecessor_worktree() {
+        use crate::domain::models::{Task, TaskStatus, Worktree, WorktreeStatus};
+
+        let orchestrator = setup_orchestrator_bare(SwarmConfig {
+            reuse_chained_worktrees: true,
+            ..disabled_feature_config()
+        })
+        .await;
+        let task_repo = &orchestrator.core_deps.task_repo;
+        let worktree_repo = &orchestrator.core_deps.worktree_repo;
+
+        let mut predecessor = Task::new("Add the parser");
+        predecessor.status = TaskStatus::Complete;
+        predecessor.completed_at = Some(chrono::Utc::now());
+        task_repo.create(&predecessor).await.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().to_string();
+        let mut wt = Worktree::new(predecessor.id, &path, "abathur/task-parser", "main");
+        wt.complete();
+        worktree_repo.create(&wt).await.unwrap();
+
+        let chained = Task::new("Wire the parser into the CLI").with_dependency(predecessor.id);
+        task_repo.create(&chained).await.unwrap();
+
+        let reused = orchestrator.create_worktree_for_task(chained.id).await.unwrap();
+        assert_eq!(reused, path);
+
+        let owned = worktree_repo.get_by_task(chained.id).await.unwrap().unwrap();
+        assert_eq!(owned.id, wt.id);
+        assert_eq!(owned.status, WorktreeStatus::Active);
+        assert!(worktree_repo.get_by_task(predecessor.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_chained_task_skips_predecessor_worktree_pending_merge() {
+        use crate::adapters::sqlite::{SqliteMergeRequestRepository, SqliteTaskRepository};
+        use crate::domain::models::{Task, TaskStatus, Worktree, WorktreeStatus};
+        use crate::domain::ports::MergeRequestRepository;
+        use crate::services::merge_queue::{MergeRequest, MergeStatus};
+
+        let mut orchestrator = setup_orchestrator_bare(SwarmConfig {
+            reuse_chained_worktrees: true,
+            ..disabled_feature_config()
+        })
+        .await;
+        let task_repo = orchestrator.core_deps.task_repo.clone();
+        let worktree_repo = orchestrator.core_deps.worktree_repo.clone();
+
+        let mut predecessor = Task::new("Add the parser");
+        predecessor.status = TaskStatus::Complete;
+        predecessor.completed_at = Some(chrono::Utc::now());
+        task_repo.create(&predecessor).await.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().to_string();
+        let mut wt = Worktree::new(predecessor.id, &path, "abathur/task-parser", "main");
+        wt.complete();
+        worktree_repo.create(&wt).await.unwrap();
+
+        // The predecessor's branch is still queued for merge.
+        let merge_pool = test_support::setup_pool().await;
+        SqliteTaskRepository::new(merge_pool.clone())
+            .create(&predecessor)
+            .await
+            .unwrap();
+        let merge_repo = Arc::new(SqliteMergeRequestRepository::new(merge_pool));
+        let mut request = MergeRequest::new_stage1(
+            predecessor.id,
+            "abathur/task-parser".to_string(),
+            "main".to_string(),
+            path.clone(),
+        );
+        merge_repo.create(&request).await.unwrap();
+        orchestrator.advanced_services.merge_request_repo = Some(merge_repo.clone());
+
+        let chained = Task::new("Wire the parser into the CLI").with_dependency(predecessor.id);
+        task_repo.create(&chained).await.unwrap();
+        let reused = orchestrator.reuse_predecessor_worktree(chained.id).await;
+        assert!(reused.is_none());
+        let kept = worktree_repo
+            .get_by_task(predecessor.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(kept.status, WorktreeStatus::Completed);
+
+        // Once merged, the worktree is released for reuse.
+        request.status = MergeStatus::Completed;
+        merge_repo.update(&request).await.unwrap();
+        let mut merged = kept;
+        merged.status = WorktreeStatus::Merged;
+        worktree_repo.update(&merged).await.unwrap();
+        assert_eq!(
+            orchestrator.reuse_predecessor_worktree(chained.id).await,
+            Some(path)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_task_max_turns_override_reaches_substrate() {
+        use crate::domain::models::workflow_template::{WorkflowTemplate, WorkspaceKind};
+        use crate::domain::models::{ExecutionParameters, Task, TaskStatus};
+
+        let mock = Arc::new(MockSubstrate::new());
+        let orchestrator = setup_orchestrator_with_substrate(
+            SwarmConfig {
+                verify_on_completion: false,
+                workflow_template: Some(WorkflowTemplate {
+                    name: "analysis".to_string(),
+                    description: String::new(),
+                    phases: vec![],
+                    workspace_kind: WorkspaceKind::None,
+                    tool_grants: vec![],
+                    output_delivery: Default::default(),
+                    max_verification_retries: 0,
+                }),
+                ..disabled_feature_config()
+            },
+            mock.clone(),
+        )
+        .await;
+        orchestrator
+            .middleware
+            .pre_spawn_chain
+            .write()
+            .await
+            .register(Arc::new(middleware::RouteTaskMiddleware::new()));
+
+        let mut task = Task::new("Explore the flaky test")
+            .with_agent("researcher")
+            .with_execution_params(ExecutionParameters {
+                max_turns: Some(7),
+                temperature: Some(0.9),
+                ..Default::default()
+            });
+        task.status = TaskStatus::Ready;
+        orchestrator.core_deps.task_repo.create(&task).await.unwrap();
+
+        let (event_tx, _event_rx) = tokio::sync::mpsc::channel(64);
+        orchestrator.spawn_task_agent(&task, &event_tx).await.unwrap();
+
+        let session = tokio::time::timeout(std::time::Duration::from_secs(10), async {
+            loop {
+                if let Some(session) = mock.get_all_sessions().await.pop() {
+                    return session;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .expect("substrate was never invoked");
+
+        // The researcher role default (51 turns) is replaced by the override.
+        assert_eq!(session.config.max_turns, 7);
+        assert_eq!(session.config.temperature, Some(0.9));
+    }
+
+    #[tokio::test]
+    async fn test_drain_claims_only_tasks_the_middleware_admits() {
+        use crate::domain::models::workflow_template::{WorkflowTemplate, WorkspaceKind};
+        use crate::domain::models::{Task, TaskStatus};
+
+        struct RefuseByTitle;
+
+        #[async_trait::async_trait]
+        impl middleware::PreSpawnMiddleware for RefuseByTitle {
+            fn name(&self) -> &'static str {
+                "refuse-by-title"
+            }
+
+            async fn handle(
+                &self,
+                ctx: &mut middleware::PreSpawnContext,
+            ) -> DomainResult<middleware::PreSpawnDecision> {
+                if ctx.task.title == "Refused" {
+                    return Ok(middleware::PreSpawnDecision::Skip {
+                        reason: "refused by test".to_string(),
+                    });
+                }
+                Ok(middleware::PreSpawnDecision::Continue)
+            }
+        }
+
+        let mock = Arc::new(MockSubstrate::new());
+        let orchestrator = setup_orchestrator_with_substrate(
+            SwarmConfig {
+                verify_on_completion: false,
+                workflow_template: Some(WorkflowTemplate {
+                    name: "analysis".to_string(),
+                    description: String::new(),
+                    phases: vec![],
+                    workspace_kind: WorkspaceKind::None,
+                    tool_grants: vec![],
+                    output_delivery: Default::default(),
+                    max_verification_retries: 0,
+                }),
+                ..disabled_feature_config()
+            },
+            mock.clone(),
+        )
+        .await;
+        {
+            let mut chain = orchestrator.middleware.pre_spawn_chain.write().await;
+            chain.register(Arc::new(middleware::RouteTaskMiddleware::new()));
+            chain.register(Arc::new(RefuseByTitle));
+        }
+
+        let mut ids = Vec::new();
+        for title in ["Refused", "Admitted"] {
+            let mut task = Task::new(title).with_agent("researcher");
+            task.status = TaskStatus::Ready;
+            orchestrator.core_deps.task_repo.create(&task).await.unwrap();
+            orchestrator
+                .runtime_state
+                .ready_task_tx
+                .send(task.id)
+                .await
+                .unwrap();
+            ids.push(task.id);
+        }
+
+        let (event_tx, _event_rx) = tokio::sync::mpsc::channel(64);
+        orchestrator.drain_ready_tasks(&event_tx).await.unwrap();
+
+        let task_repo = &orchestrator.core_deps.task_repo;
+        let refused = task_repo.get(ids[0]).await.unwrap().unwrap();
+        assert_eq!(refused.status, TaskStatus::Ready);
+        let admitted = task_repo.get(ids[1]).await.unwrap().unwrap();
+        assert_ne!(admitted.status, TaskStatus::Ready);
+        assert!(admitted.started_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_ephemeral_task_runs_in_repo_root_without_worktree() {
+        use crate::domain::models::workflow_template::{WorkflowTemplate, WorkspaceKind};
+        use crate::domain::models::{Task, TaskStatus, WorkspaceMode};
+
+        let repo = tempfile::TempDir::new().unwrap();
+        for args in [
+            &["init", "-b", "main"][..],
+            &["config", "user.email", "test@test.com"],
+            &["config", "user.name", "Test"],
+            &["commit", "--allow-empty", "-m", "initial"],
+        ] {
+            let out = std::process::Command::new("git")
+                .args(args)
+                .current_dir(repo.path())
+                .output()
+                .unwrap();
+            assert!(out.status.success(), "git {:?} failed", args);
+        }
+
+        let mock = Arc::new(MockSubstrate::new());
+        let orchestrator = setup_orchestrator_with_substrate(
+            SwarmConfig {
+                use_worktrees: true,
+                verify_on_completion: false,
+                fetch_on_sync: false,
+                repo_path: repo.path().to_path_buf(),
+                worktree_base_path: repo.path().join(".worktrees"),
+                default_base_ref: "main".to_string(),
+                workflow_template: Some(WorkflowTemplate {
+                    name: "code".to_string(),
+                    description: String::new(),
+                    phases: vec![],
+                    workspace_kind: WorkspaceKind::Worktree,
+                    tool_grants: vec![],
+                    output_delivery: Default::default(),
+                    max_verification_retries: 0,
+                }),
+                ..disabled_feature_config()
+            },
+            mock.clone(),
+        )
+        .await;
+        orchestrator
+            .middleware
+            .pre_spawn_chain
+            .write()
+            .await
+            .register(Arc::new(middleware::RouteTaskMiddleware::new()));
+
+        let mut ephemeral = Task::new("Summarise the README")
+            .with_agent("coder")
+            .with_workspace_mode(WorkspaceMode::Ephemeral);
+        ephemeral.status = TaskStatus::Ready;
+        let mut auto = Task::new("Fix the README typo").with_agent("coder");
+        auto.status = TaskStatus::Ready;
+        for task in [&ephemeral, &auto] {
+            orchestrator.core_deps.task_repo.create(task).await.unwrap();
+        }
+
+        let (event_tx, _event_rx) = tokio::sync::mpsc::channel(64);
+        orchestrator.spawn_task_agent(&ephemeral, &event_tx).await.unwrap();
+        orchestrator.spawn_task_agent(&auto, &event_tx).await.unwrap();
+
+        let sessions = tokio::time::timeout(std::time::Duration::from_secs(10), async {
+            loop {
+                let sessions = mock.get_all_sessions().await;
+                if sessions.len() == 2 {
+                    return sessions;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .expect("substrate was never invoked for both tasks");
+
+        let worktree_repo = &orchestrator.core_deps.worktree_repo;
+        assert!(worktree_repo.get_by_task(ephemeral.id).await.unwrap().is_none());
+        let auto_wt = worktree_repo.get_by_task(auto.id).await.unwrap().unwrap();
+
+        // Only the auto task's agent was pointed at a worktree.
+        let working_dirs: Vec<_> = sessions.iter().map(|s| s.config.working_dir.clone()).collect();
+        assert!(working_dirs.contains(&None));
+        assert!(working_dirs.contains(&Some(auto_wt.path)));
+    }
+
+    #[tokio::test]
+    async fn test_output_schema_mismatch_retries_then_accepts_valid_json() {
+        use crate::adapters::substrates::mock::MockResponse;
+        use crate::domain::models::workflow_template::{WorkflowTemplate, WorkspaceKind};
+        use crate::domain::models::{Task, TaskStatus};
+        use crate::services::builtin_handlers::TaskFailedRetryHandler;
+        use crate::services::event_bus::EventPayload;
+        use crate::services::event_reactor::{EventHandler, HandlerContext, Reaction};
+
+        let mock = Arc::new(MockSubstrate::new());
+        let orchestrator = setup_orchestrator_with_substrate(
+            SwarmConfig {
+                verify_on_completion: false,
+                workflow_template: Some(WorkflowTemplate {
+                    name: "analysis".to_string(),
+                    description: String::new(),
+                    phases: vec![],
+                    workspace_kind: WorkspaceKind::None,
+                    tool_grants: vec![],
+                    output_delivery: Default::default(),
+                    max_verification_retries: 0,
+                }),
+                ..disabled_feature_config()
+            },
+            mock.clone(),
+        )
+        .await;
+        orchestrator
+            .middleware
+            .pre_spawn_chain
+            .write()
+            .await
+            .register(Arc::new(middleware::RouteTaskMiddleware::new()));
+        let task_repo = orchestrator.core_deps.task_repo.clone();
+
+        let mut task = Task::new("Classify issue #12").with_agent("researcher");
+        task.set_output_schema(serde_json::json!({
+            "type": "object",
+            "properties": { "label": { "enum": ["bug", "feature"] } },
+            "required": ["label"]
+        }));
+        task.status = TaskStatus::Ready;
+        task_repo.create(&task).await.unwrap();
+        mock.queue_responses_for_task(
+            task.id,
+            [
+                MockResponse::success("I think this is a bug."),
+                MockResponse::success(r#"{"label": "bug"}"#),
+            ],
+        )
+        .await;
+
+        let wait_for = |status: TaskStatus| {
+            let task_repo = task_repo.clone();
+            async move {
+                tokio::time::timeout(std::time::Duration::from_secs(10), async {
+                    loop {
+                        let t = task_repo.get(task.id).await.unwrap().unwrap();
+                        if t.status == status {
+                            return t;
+                        }
+                        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                    }
+                })
+                .await
+                .unwrap_or_else(|_| panic!("task never reached {:?}", status))
+            }
+        };
+
+        // First attempt: free-form text fails the schema gate.
+        let mut events = orchestrator.subsystem_services.event_bus.subscribe();
+        let (event_tx, _event_rx) = tokio::sync::mpsc::channel(64);
+        orchestrator
+            .spawn_task_agent(&task, &event_tx)
+            .await
+            .unwrap();
+        wait_for(TaskStatus::Failed).await;
+        let failed = loop {
+            let event = events.recv().await.unwrap();
+            if let EventPayload::TaskFailed {
+                task_id, ref error, ..
+            } = event.payload
+                && task_id == task.id
+            {
+                assert!(error.starts_with("schema_validation_failed"), "{error}");
+                break event;
+            }
+        };
+
+        // The retry handler re-queues it immediately with the errors attached.
+        let handler = TaskFailedRetryHandler::new(task_repo.clone(), 3);
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+        let reaction = handler.handle(&failed, &ctx).await.unwrap();
+        assert!(matches!(reaction, Reaction::EmitEvents(_)));
+        let retried = task_repo.get(task.id).await.unwrap().unwrap();
+        assert_eq!(retried.status, TaskStatus::Ready);
+        assert!(
+            retried
+                .context
+                .hints
+                .contains(&"retry:schema_validation_failed".to_string())
+        );
+
+        // Second attempt returns valid JSON and is accepted.
+        orchestrator
+            .spawn_task_agent(&retried, &event_tx)
+            .await
+            .unwrap();
+        let done = wait_for(TaskStatus::Complete).await;
+        assert_eq!(done.retry_count, 1);
+        assert_eq!(done.result(), Some(r#"{"label": "bug"}"#));
+    }
+
+    #[tokio::test]
+    async fn test_identical_cacheable_task_reuses_result_without_substrate() {
+        use crate::domain::models::workflow_template::{WorkflowTemplate, WorkspaceKind};
+        use crate::domain::models::{Task, TaskStatus};
+        use crate::services::config::ResultCacheConfig;
+        use crate::services::event_bus::EventPayload;
+        use crate::services::result_cache::ResultCache;
+
+        let repo = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .current_dir(repo.path())
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+        git(&["init", "-q"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "init"]);
+
+        let mock = Arc::new(MockSubstrate::new());
+        let orchestrator = setup_orchestrator_with_substrate(
+            SwarmConfig {
+                repo_path: repo.path().to_path_buf(),
+                verify_on_completion: false,
+                workflow_template: Some(WorkflowTemplate {
+                    name: "analysis".to_string(),
+                    description: String::new(),
+                    phases: vec![],
+                    workspace_kind: WorkspaceKind::None,
+                    tool_grants: vec![],
+                    output_delivery: Default::default(),
+                    max_verification_retries: 0,
+                }),
+                ..disabled_feature_config()
+            },
+            mock.clone(),
+        )
+        .await
+        .with_result_cache(Arc::new(ResultCache::new(&ResultCacheConfig {
+            enabled: true,
+            ..Default::default()
+        })));
+        orchestrator
+            .middleware
+            .pre_spawn_chain
+            .write()
+            .await
+            .register(Arc::new(middleware::RouteTaskMiddleware::new()));
+        let task_repo = orchestrator.core_deps.task_repo.clone();
+        let mut events = orchestrator.subsystem_services.event_bus.subscribe();
+        let (event_tx, _event_rx) = tokio::sync::mpsc::channel(64);
+
+        let mut completed = Vec::new();
+        for _ in 0..2 {
+            let mut task = Task::new("Summarize the open issues").with_agent("researcher");
+            task.set_cacheable(true);
+            task.status = TaskStatus::Ready;
+            task_repo.create(&task).await.unwrap();
+            orchestrator
+                .spawn_task_agent(&task, &event_tx)
+                .await
+                .unwrap();
+            let done = tokio::time::timeout(std::time::Duration::from_secs(10), async {
+                loop {
+                    let t = task_repo.get(task.id).await.unwrap().unwrap();
+                    if t.status == TaskStatus::Complete {
+                        return t;
+                    }
+                    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                }
+            })
+            .await
+            .expect("task never completed");
+            completed.push(done);
+        }
+
+        assert_eq!(mock.get_all_sessions().await.len(), 1);
+        assert!(!completed[0].result_cached());
+        assert!(completed[1].result_cached());
+        assert!(completed[1].result().is_some());
+        assert_eq!(completed[1].result(), completed[0].result());
+
+        let cached = loop {
+            let event = events.recv().await.unwrap();
+            if let EventPayload::TaskResultCached {
+                task_id,
+                source_task_id,
+                ..
+            } = event.payload
+            {
+                break (task_id, source_task_id);
+            }
+        };
+        assert_eq!(cached, (completed[1].id, completed[0].id));
+    }
+
     // ------------------------------------------------------------------------
     // validate_dependencies() — startup validation
     // ------------------------------------------------------------------------
@@ -1361,6 +2251,19 @@ mod tests {
         TestWorktreeRepo,
         TestAgentRepo,
         TestMemoryRepo,
+    > {
+        setup_orchestrator_with_substrate(config, Arc::new(MockSubstrate::new())).await
+    }
+
+    async fn setup_orchestrator_with_substrate(
+        config: SwarmConfig,
+        substrate: Arc<dyn Substrate>,
+    ) -> SwarmOrchestrator<
+        TestGoalRepo,
+        TestTaskRepo,
+        TestWorktreeRepo,
+        TestAgentRepo,
+        TestMemoryRepo,
     > {
         use crate::services::event_bus::{EventBus, EventBusConfig};
         use crate::services::event_reactor::{EventReactor, ReactorConfig};
@@ -1368,7 +2271,6 @@ mod tests {
 
         let (goal_repo, task_repo, worktree_repo, agent_repo, _memory_repo) =
             test_support::setup_all_repos().await;
-        let substrate: Arc<dyn Substrate> = Arc::new(MockSubstrate::new());
 
         let event_bus = Arc::new(EventBus::new(EventBusConfig::default()));
         let event_reactor = Arc::new(EventReactor::new(
@@ -3,18 +3,19 @@
 //!
 //! Part of the T11 god-object decomposition (see
 //! `specs/T11-swarm-orchestrator-decomposition.md`). Holds the status, stats,
-//! semaphore, atomics, caches, escalation store, and the ready-task /
+//! agent slots, atomics, caches, escalation store, and the ready-task /
 //! specialist mpsc channels.
 
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use tokio::sync::{Mutex, RwLock, Semaphore, mpsc};
+use tokio::sync::{Mutex, RwLock, mpsc};
 use uuid::Uuid;
 
 use crate::domain::models::{Goal, HumanEscalationEvent};
 
+use super::agent_slots::AgentSlots;
 use super::types::{OrchestratorStatus, SwarmStats};
 
 /// Runtime state of a running swarm: status, counters, caches, and the
@@ -22,7 +23,7 @@ use super::types::{OrchestratorStatus, SwarmStats};
 pub(crate) struct RuntimeState {
     pub(crate) status: Arc<RwLock<OrchestratorStatus>>,
     pub(crate)stats: Arc<RwLock<SwarmStats>>,
-    pub(crate)agent_semaphore: Arc<Semaphore>,
+    pub(crate)agent_slots: Arc<AgentSlots>,
     pub(crate)total_tokens: Arc<AtomicU64>,
     pub(crate)active_goals_cache: Arc<RwLock<Vec<Goal>>>,
     pub(crate)escalation_store: Arc<RwLock<HashMap<Uuid, HumanEscalationEvent>>>,
@@ -37,13 +38,13 @@ impl RuntimeState {
     /// Construct fresh runtime state for an orchestrator that hasn't started
     /// yet. Capacities match the historical `mod.rs` constants (256 ready
     /// tasks, 64 specialist).
-    pub(crate) fn new(max_agents: usize) -> Self {
+    pub(crate) fn new(max_agents: usize, slot_weights: &HashMap<String, u32>) -> Self {
         let (ready_tx, ready_rx) = mpsc::channel(256);
         let (specialist_tx, specialist_rx) = mpsc::channel(64);
         Self {
             status: Arc::new(RwLock::new(OrchestratorStatus::Idle)),
             stats: Arc::new(RwLock::new(SwarmStats::default())),
-            agent_semaphore: Arc::new(Semaphore::new(max_agents)),
+            agent_slots: Arc::new(AgentSlots::new(max_agents, slot_weights.clone())),
             total_tokens: Arc::new(AtomicU64::new(0)),
             active_goals_cache: Arc::new(RwLock::new(Vec::new())),
             escalation_store: Arc::new(RwLock::new(HashMap::new())),
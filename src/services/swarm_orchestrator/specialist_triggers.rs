@@ -7,6 +7,7 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
+use crate::adapters::mcp::{DelegationError, RetryPolicy};
 use crate::domain::errors::DomainResult;
 use crate::domain::models::{Task, TaskPriority, TaskSource, TaskStatus};
 use crate::domain::ports::{
@@ -16,9 +17,7 @@ use crate::services::{
     AuditAction, AuditActor, AuditCategory, AuditEntry, AuditLevel, IntegrationVerifierService,
     MergeQueue, MergeQueueConfig, VerifierConfig,
     command_bus::{CommandEnvelope, CommandSource, DomainCommand, TaskCommand},
-    dag_restructure::{
-        RestructureContext, RestructureDecision, RestructureTrigger, TaskPriorityModifier,
-    },
+    dag_restructure::{RestructureContext, RestructureDecision, RestructureTrigger},
 };
 
 use super::SwarmOrchestrator;
@@ -51,7 +50,7 @@ where
 
         for task in permanently_failed {
             // First, try DAG restructuring before falling back to diagnostic analyst
-            let restructure_result = self.try_restructure_for_failure(task, event_tx).await;
+            let restructure_result = self.try_restructure_for_failure(task).await;
 
             match restructure_result {
                 Ok(true) => {
@@ -145,11 +144,7 @@ where
 
     /// Try to restructure the DAG for a permanently failed task.
     /// Returns Ok(true) if restructuring was applied, Ok(false) if not possible/exhausted.
-    async fn try_restructure_for_failure(
-        &self,
-        failed_task: &Task,
-        event_tx: &mpsc::Sender<SwarmEvent>,
-    ) -> DomainResult<bool> {
+    async fn try_restructure_for_failure(&self, failed_task: &Task) -> DomainResult<bool> {
         let trigger = RestructureTrigger::PermanentFailure {
             task_id: failed_task.id,
             retries_exhausted: failed_task.retry_count,
@@ -181,18 +176,35 @@ where
             time_since_last: None,
         };
 
-        // Get restructure decision
-        let decision = restructure_svc.analyze_and_decide(&context).await?;
+        // Plan the restructure against the failed task and its dependents,
+        // which get rewired when the task is replaced.
+        let mut task_set = self.core_deps.task_repo.get_dependents(failed_task.id).await?;
+        task_set.push(failed_task.clone());
+        let plan = restructure_svc.plan_for_failure(&context, &task_set).await?;
+        drop(restructure_svc);
+        let Some(decision) = plan.decisions.first().map(|d| d.decision.clone()) else {
+            return Ok(false);
+        };
 
-        // Log the decision
+        // Log the plan before applying it
         self.subsystem_services.audit_log
-            .info(
-                AuditCategory::Task,
-                AuditAction::TaskCreated,
-                format!(
-                    "DAG restructure decision for task {}: {:?}",
-                    failed_task.id, decision
-                ),
+            .log(
+                AuditEntry::new(
+                    AuditLevel::Info,
+                    AuditCategory::Task,
+                    AuditAction::DagRestructured,
+                    AuditActor::System,
+                    format!(
+                        "DAG restructure plan for task {}: {} new task(s), +{}/-{} edge(s), {} transition(s)",
+                        failed_task.id,
+                        plan.new_tasks.len(),
+                        plan.edges_added.len(),
+                        plan.edges_removed.len(),
+                        plan.transitions.len()
+                    ),
+                )
+                .with_entity(failed_task.id, "task")
+                .with_metadata("plan", serde_json::to_value(&plan).unwrap_or_default()),
             )
             .await;
 
@@ -213,79 +225,48 @@ where
 
         // Apply the decision
         match decision {
-            RestructureDecision::RetryDifferentApproach {
-                new_approach,
-                new_agent_type,
-            } => {
-                // Update description directly (no command for description updates)
-                let mut updated_task = failed_task.clone();
-                updated_task.description = format!(
-                    "{}\n\n## Restructure Note\nPrevious approach failed. Try: {}",
-                    updated_task.description, new_approach
-                );
-                if let Some(agent_type) = new_agent_type {
-                    updated_task.agent_type = Some(agent_type);
-                }
-                updated_task.retry_count = 0;
-                self.core_deps.task_repo.update(&updated_task).await?;
-
-                // Emit description update event via EventBus
-                self.subsystem_services.event_bus
-                    .publish(crate::services::event_factory::task_event(
-                        crate::services::event_bus::EventSeverity::Info,
-                        None,
-                        failed_task.id,
-                        crate::services::event_bus::EventPayload::TaskDescriptionUpdated {
-                            task_id: failed_task.id,
-                            reason: format!(
-                                "DAG restructure: retry with different approach — {}",
-                                new_approach
-                            ),
-                        },
-                    ))
-                    .await;
-
-                // Transition to Ready via CommandBus
-                if let Some(cb) = self.advanced_services.command_bus.read().await.as_ref() {
-                    let envelope = CommandEnvelope::new(
-                        CommandSource::System,
-                        DomainCommand::Task(TaskCommand::Transition {
-                            task_id: failed_task.id,
-                            new_status: TaskStatus::Ready,
-                        }),
+            RestructureDecision::RetryDifferentApproach { .. }
+            | RestructureDecision::DecomposeDifferently { .. }
+            | RestructureDecision::AlternativePath { .. } => {
+                let Some(cb) = self.advanced_services.command_bus.read().await.clone() else {
+                    tracing::warn!(
+                        "CommandBus not available — cannot apply restructure plan for task {}",
+                        failed_task.id
                     );
-                    let _ = cb.dispatch(envelope).await;
-                }
-                Ok(true)
-            }
-            RestructureDecision::DecomposeDifferently {
-                new_subtasks,
-                remove_original,
-            } => {
-                self.create_restructure_subtasks(
-                    failed_task,
-                    &new_subtasks,
-                    remove_original,
-                    event_tx,
-                )
-                .await?;
-                Ok(true)
-            }
-            RestructureDecision::AlternativePath {
-                description,
-                new_tasks,
-            } => {
-                self.create_restructure_subtasks(failed_task, &new_tasks, false, event_tx)
+                    return Ok(false);
+                };
+                let restructure_svc = self.subsystem_services.restructure_service.lock().await;
+                restructure_svc
+                    .apply(&plan, self.core_deps.task_repo.as_ref(), &cb)
                     .await?;
+                drop(restructure_svc);
 
-                self.subsystem_services.audit_log
-                    .info(
-                        AuditCategory::Task,
-                        AuditAction::TaskCreated,
-                        format!("Created alternative path: {}", description),
-                    )
-                    .await;
-
+                if let RestructureDecision::RetryDifferentApproach { new_approach, .. } = &decision
+                {
+                    self.subsystem_services.event_bus
+                        .publish(crate::services::event_factory::task_event(
+                            crate::services::event_bus::EventSeverity::Info,
+                            None,
+                            failed_task.id,
+                            crate::services::event_bus::EventPayload::TaskDescriptionUpdated {
+                                task_id: failed_task.id,
+                                reason: format!(
+                                    "DAG restructure: retry with different approach — {}",
+                                    new_approach
+                                ),
+                            },
+                        ))
+                        .await;
+                }
+                if let RestructureDecision::AlternativePath { description, .. } = &decision {
+                    self.subsystem_services.audit_log
+                        .info(
+                            AuditCategory::Task,
+                            AuditAction::TaskCreated,
+                            format!("Created alternative path: {}", description),
+                        )
+                        .await;
+                }
                 Ok(true)
             }
             RestructureDecision::WaitAndRetry { delay, reason } => {
@@ -306,12 +287,16 @@ where
                 // Try federation delegation before falling through
                 if let Some(ref federation) = self.advanced_services.federation_client {
                     let peers = federation.list_available_peers();
+                    let retry_policy = RetryPolicy::default();
                     for peer in peers {
                         let task_desc = format!(
                             "Delegated task: {}\nReason: {}\nContext: {}",
                             failed_task.title, reason, context
                         );
-                        match federation.delegate_task(&peer.id, &task_desc).await {
+                        match federation
+                            .delegate_with_retry(&peer.id, &task_desc, &retry_policy)
+                            .await
+                        {
                             Ok(a2a_task) => {
                                 self.subsystem_services.audit_log
                                     .info(
@@ -325,6 +310,9 @@ where
                                     .await;
                                 return Ok(true);
                             }
+                            Err(e @ DelegationError::CircuitOpen { .. }) => {
+                                tracing::debug!("Skipping federation peer '{}': {}", peer.name, e);
+                            }
                             Err(e) => {
                                 tracing::warn!(
                                     "Federation delegation to '{}' failed: {}",
@@ -371,106 +359,6 @@ where
         }
     }
 
-    /// Create subtasks from a restructure decision (shared between DecomposeDifferently and AlternativePath).
-    async fn create_restructure_subtasks(
-        &self,
-        failed_task: &Task,
-        new_tasks: &[crate::services::dag_restructure::NewTaskSpec],
-        remove_original: bool,
-        _event_tx: &mpsc::Sender<SwarmEvent>,
-    ) -> DomainResult<()> {
-        let cb = self.advanced_services.command_bus.read().await.clone();
-
-        let mut title_to_id: Vec<(String, Uuid)> = Vec::new();
-        for spec in new_tasks {
-            let priority = match spec.priority {
-                TaskPriorityModifier::Same => failed_task.priority,
-                TaskPriorityModifier::Higher => TaskPriority::High,
-                TaskPriorityModifier::Lower => TaskPriority::Low,
-            };
-
-            // Resolve depends_on titles to UUIDs from already-created tasks
-            let mut depends_on = Vec::new();
-            for dep_title in &spec.depends_on {
-                if let Some((_, dep_id)) = title_to_id.iter().find(|(t, _)| t == dep_title) {
-                    depends_on.push(*dep_id);
-                }
-            }
-
-            // Submit task via CommandBus (journals TaskSubmitted event)
-            if let Some(ref cb) = cb {
-                let envelope = CommandEnvelope::new(
-                    CommandSource::System,
-                    DomainCommand::Task(TaskCommand::Submit {
-                        title: Some(spec.title.clone()),
-                        description: spec.description.clone(),
-                        parent_id: None,
-                        priority,
-                        agent_type: spec.agent_type.clone(),
-                        depends_on: depends_on.clone(),
-                        context: Box::new(None),
-                        idempotency_key: None,
-                        source: TaskSource::System,
-                        deadline: None,
-                        task_type: None,
-                        execution_mode: None,
-                    }),
-                );
-                match cb.dispatch(envelope).await {
-                    Ok(crate::services::command_bus::CommandResult::Task(task)) => {
-                        title_to_id.push((spec.title.clone(), task.id));
-                    }
-                    Ok(other) => {
-                        tracing::warn!(
-                            "CommandBus returned unexpected result type for restructure subtask '{}': {:?}",
-                            spec.title,
-                            other
-                        );
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            "CommandBus submit failed for restructure subtask '{}': {}",
-                            spec.title,
-                            e
-                        );
-                    }
-                }
-            } else {
-                tracing::warn!(
-                    "CommandBus not available — cannot create restructure subtask '{}'",
-                    spec.title
-                );
-            }
-        }
-
-        // Cancel the original task via CommandBus
-        if remove_original {
-            if let Some(ref cb) = cb {
-                let envelope = CommandEnvelope::new(
-                    CommandSource::System,
-                    DomainCommand::Task(TaskCommand::Cancel {
-                        task_id: failed_task.id,
-                        reason: "Replaced by restructure subtasks".to_string(),
-                    }),
-                );
-                if let Err(e) = cb.dispatch(envelope).await {
-                    tracing::warn!(
-                        "CommandBus cancel failed for task {}: {}",
-                        failed_task.id,
-                        e
-                    );
-                }
-            } else {
-                tracing::warn!(
-                    "CommandBus not available — cannot cancel task {}",
-                    failed_task.id
-                );
-            }
-        }
-
-        Ok(())
-    }
-
     /// Spawn a diagnostic analyst for a permanently failed task.
     async fn spawn_specialist_for_failure(
         &self,
@@ -511,6 +399,10 @@ where
                     deadline: None,
                     task_type: None,
                     execution_mode: None,
+                    estimate_secs: None,
+                    execution_params: None,
+                    required_overseers: Vec::new(),
+                    workspace_mode: None,
                 }),
             );
             match cb.dispatch(envelope).await {
@@ -720,6 +612,10 @@ where
                     deadline: None,
                     task_type: None,
                     execution_mode: None,
+                    estimate_secs: None,
+                    execution_params: None,
+                    required_overseers: Vec::new(),
+                    workspace_mode: None,
                 }),
             );
             match cb.dispatch(envelope).await {
@@ -984,6 +880,10 @@ where
                             deadline: None,
                             task_type: None,
                             execution_mode: None,
+                            estimate_secs: None,
+                            execution_params: None,
+                            required_overseers: Vec::new(),
+                            workspace_mode: None,
                         }),
                     );
                     match cb.dispatch(envelope).await {
@@ -1,35 +1,43 @@
 //! Task context loader.
 //!
-//! Loads goal-context, memory-context, and intent-gap-context for a task and
-//! assembles the combined description that is passed to the substrate.
+//! Loads goal-context, memory-context, dependency results, and
+//! intent-gap-context for a task and assembles the combined description that
+//! is passed to the substrate.
 //!
 //! Extracted from `goal_processing::spawn_task_agent` per spec T10
 //! (`specs/T10-spawn-task-agent-extraction.md`).
 
 use std::sync::Arc;
 
-use crate::domain::errors::DomainResult;
-use crate::domain::models::{RelevanceWeights, ScoredMemory, Task};
-use crate::domain::ports::{GoalRepository, MemoryRepository};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::models::{AccessorId, MemoryRetrievalStrategy, ScoredMemory, Task, TaskStatus};
+use crate::domain::ports::{GoalRepository, MemoryRepository, TaskRepository};
 use crate::services::GoalContextService;
+use crate::services::context_truncation::{estimate_tokens, truncate_to_token_budget};
 use crate::services::memory_service::MemoryService;
+use crate::services::output_schema::SCHEMA_VALIDATION_FAILED;
+
+/// Headroom for the marker `truncate_to_token_budget` appends.
+const TRUNCATION_MARKER_TOKENS: usize = 16;
+
+/// Sections smaller than this after trimming are dropped outright.
+const MIN_TRIMMED_SECTION_TOKENS: usize = 64;
+
+/// Token budget for injected dependency results (25% of an 8000-token
+/// context budget, matching memory context).
+const DEPENDENCY_CONTEXT_TOKENS: usize = 2000;
 
 /// The fully-assembled context passed to the substrate.
 #[derive(Debug, Clone, Default)]
 pub struct TaskContext {
     pub goal_context: Option<String>,
-    /// Read by audit logging only; not load-bearing for substrate.
-    // reason: surfaced as part of the assembled context so audit log writes
-    // can persist the inputs verbatim alongside the substrate prompt.
-    #[allow(dead_code)]
     pub memory_context: Option<String>,
-    /// Read by audit logging only; not load-bearing for substrate.
-    // reason: surfaced as part of the assembled context so audit log writes
-    // can persist the inputs verbatim alongside the substrate prompt.
-    #[allow(dead_code)]
+    /// Results and artifacts of completed dependencies whose edges opted in
+    /// to result injection.
+    pub dependency_context: Option<String>,
     pub intent_gap_context: Option<String>,
-    /// Final task description: goal/memory/gap context joined with the original
-    /// task description.
+    /// Final task description: goal/memory/dependency/gap context joined with
+    /// the original task description.
     pub combined_description: String,
 }
 
@@ -46,6 +54,12 @@ where
 {
     goal_repo: Arc<G>,
     memory_repo: Option<Arc<M>>,
+    task_repo: Option<Arc<dyn TaskRepository>>,
+    memory_strategy: MemoryRetrievalStrategy,
+    /// Retrieved memories reinforced per task; 0 keeps retrieval read-only.
+    reinforce_limit: usize,
+    /// `[memory]` settings applied to the memory service used for retrieval.
+    memory_config: crate::services::config::MemoryConfig,
 }
 
 impl<G, M> TaskContextService<G, M>
@@ -57,29 +71,58 @@ where
         Self {
             goal_repo,
             memory_repo,
+            task_repo: None,
+            memory_strategy: MemoryRetrievalStrategy::default(),
+            reinforce_limit: 0,
+            memory_config: crate::services::config::MemoryConfig::default(),
         }
     }
 
-    /// Load goal/memory/intent-gap context for a task and assemble the
-    /// combined description used by the substrate.
+    /// Look up dependencies in `task_repo` so results of completed
+    /// dependencies can be injected. Without it no dependency context is
+    /// loaded.
+    pub fn with_task_repo(mut self, task_repo: Arc<dyn TaskRepository>) -> Self {
+        self.task_repo = Some(task_repo);
+        self
+    }
+
+    /// Select memory context with `strategy` instead of the default
+    /// semantic-biased blend.
+    pub fn with_memory_strategy(mut self, strategy: MemoryRetrievalStrategy) -> Self {
+        self.memory_strategy = strategy;
+        self
+    }
+
+    /// Reinforce up to `limit` of the best-ranked memories loaded into each
+    /// task's context, so memories agents actually use decay more slowly.
+    pub fn with_memory_reinforcement(mut self, limit: usize) -> Self {
+        self.reinforce_limit = limit;
+        self
+    }
+
+    /// Apply the swarm's `[memory]` settings to memory retrieval.
+    pub fn with_memory_config(mut self, config: crate::services::config::MemoryConfig) -> Self {
+        self.memory_config = config;
+        self
+    }
+
+    /// Load goal/memory/dependency/intent-gap context for a task and
+    /// assemble the combined description used by the substrate.
     pub async fn load_task_context(&self, task: &Task) -> DomainResult<TaskContext> {
         let goal_context = self.load_goal_context(task).await;
         let memory_context = self.load_memory_context(task).await;
+        let dependency_context = self.load_dependency_context(task).await;
         let intent_gap_context = task.intent_gap_context().map(|s| s.to_string());
 
-        let combined_description = assemble_description(
-            task,
-            goal_context.as_deref(),
-            memory_context.as_deref(),
-            intent_gap_context.as_deref(),
-        );
-
-        Ok(TaskContext {
+        let mut ctx = TaskContext {
             goal_context,
             memory_context,
+            dependency_context,
             intent_gap_context,
-            combined_description,
-        })
+            combined_description: String::new(),
+        };
+        ctx.reassemble(task);
+        Ok(ctx)
     }
 
     async fn load_goal_context(&self, task: &Task) -> Option<String> {
@@ -96,19 +139,32 @@ where
 
     async fn load_memory_context(&self, task: &Task) -> Option<String> {
         let mem_repo = self.memory_repo.as_ref()?;
-        let memory_service = MemoryService::new(mem_repo.clone());
+        let memory_service =
+            MemoryService::new(mem_repo.clone()).with_memory_config(&self.memory_config);
         let desc_preview: String = task.description.chars().take(500).collect();
         let query = format!("{} {}", task.title, desc_preview);
         match memory_service
-            .load_context_with_budget(
+            .load_context_with_strategy(
                 &query,
-                None,
+                &self.memory_strategy,
                 2000, // 25% of 8000-token context budget
-                RelevanceWeights::semantic_biased(),
             )
             .await
         {
-            Ok(memories) if !memories.is_empty() => Some(format_memory_context(&memories)),
+            Ok(memories) if !memories.is_empty() => {
+                if self.reinforce_limit > 0
+                    && let Err(e) = memory_service
+                        .reinforce_retrieved(
+                            memories.iter().map(|m| &m.memory),
+                            AccessorId::task(task.id),
+                            self.reinforce_limit,
+                        )
+                        .await
+                {
+                    tracing::debug!(task_id = %task.id, "Failed to reinforce memories: {}", e);
+                }
+                Some(format_memory_context(&memories))
+            }
             Ok(_) => None,
             Err(e) => {
                 tracing::debug!(task_id = %task.id, "Failed to load memory context: {}", e);
@@ -116,6 +172,114 @@ where
             }
         }
     }
+
+    async fn load_dependency_context(&self, task: &Task) -> Option<String> {
+        let task_repo = self.task_repo.as_ref()?;
+        let mut deps = Vec::new();
+        for dep_id in task.injected_dependencies() {
+            match task_repo.get(dep_id).await {
+                Ok(Some(dep)) if dep.status == TaskStatus::Complete => deps.push(dep),
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        task_id = %task.id,
+                        dependency = %dep_id,
+                        "Failed to load dependency: {}",
+                        e
+                    );
+                }
+            }
+        }
+        format_dependency_context(&deps)
+            .map(|ctx| truncate_to_token_budget(&ctx, DEPENDENCY_CONTEXT_TOKENS))
+    }
+}
+
+impl TaskContext {
+    /// Trim optional sections until `system_prompt` plus the combined
+    /// description fit in `max_context_tokens`.
+    ///
+    /// Sections are sacrificed lowest-priority first: memory, then
+    /// dependency results, then goal guidance, then intent-gap context. Each is truncated when a useful
+    /// remainder fits, otherwise dropped. Returns the names of the sections
+    /// that were trimmed. Fails when the system prompt and task description
+    /// alone exceed the window, since no amount of trimming can help.
+    pub fn fit_to_window(
+        &mut self,
+        task: &Task,
+        system_prompt: &str,
+        max_context_tokens: usize,
+    ) -> DomainResult<Vec<String>> {
+        let mandatory = estimate_tokens(system_prompt) + estimate_tokens(&task.description);
+        if mandatory > max_context_tokens {
+            return Err(DomainError::ValidationFailed(format!(
+                "prompt needs ~{} tokens without optional context, exceeding the {}-token context window",
+                mandatory, max_context_tokens
+            )));
+        }
+
+        let mut trimmed = Vec::new();
+        for name in [
+            "memory_context",
+            "dependency_context",
+            "goal_context",
+            "intent_gap_context",
+        ] {
+            let total =
+                estimate_tokens(system_prompt) + estimate_tokens(&self.combined_description);
+            if total <= max_context_tokens {
+                break;
+            }
+            let slot = match name {
+                "memory_context" => &mut self.memory_context,
+                "dependency_context" => &mut self.dependency_context,
+                "goal_context" => &mut self.goal_context,
+                _ => &mut self.intent_gap_context,
+            };
+            let Some(content) = slot.take() else {
+                continue;
+            };
+            let keep = estimate_tokens(&content)
+                .saturating_sub(total - max_context_tokens + TRUNCATION_MARKER_TOKENS);
+            if keep >= MIN_TRIMMED_SECTION_TOKENS {
+                *slot = Some(truncate_to_token_budget(&content, keep));
+                trimmed.push(format!("{} (truncated)", name));
+            } else {
+                trimmed.push(name.to_string());
+            }
+            self.reassemble(task);
+        }
+
+        // Separators and boundary rounding can leave a small overshoot; fall
+        // back to the mandatory parts, which are known to fit.
+        if estimate_tokens(system_prompt) + estimate_tokens(&self.combined_description)
+            > max_context_tokens
+        {
+            for (name, slot) in [
+                ("memory_context", &mut self.memory_context),
+                ("dependency_context", &mut self.dependency_context),
+                ("goal_context", &mut self.goal_context),
+                ("intent_gap_context", &mut self.intent_gap_context),
+            ] {
+                if slot.take().is_some() && !trimmed.iter().any(|t| t.starts_with(name)) {
+                    trimmed.push(name.to_string());
+                }
+            }
+            self.reassemble(task);
+        }
+
+        Ok(trimmed)
+    }
+
+    fn reassemble(&mut self, task: &Task) {
+        self.combined_description = assemble_description(
+            task,
+            self.goal_context.as_deref(),
+            self.memory_context.as_deref(),
+            self.dependency_context.as_deref(),
+            self.intent_gap_context.as_deref(),
+        );
+    }
 }
 
 /// Format scored memories as contextual guidance text for agent task prompts.
@@ -136,12 +300,63 @@ pub(crate) fn format_memory_context(memories: &[ScoredMemory]) -> String {
     output
 }
 
+/// Format the results and artifacts of completed dependencies as a prompt
+/// section. Returns `None` when no dependency has anything to pass on.
+pub(crate) fn format_dependency_context(deps: &[Task]) -> Option<String> {
+    let mut output = String::from(
+        "## Results from Dependencies\nThe following tasks this task depends on have completed:\n\n",
+    );
+    let mut any = false;
+    for dep in deps {
+        let result = dep.result().filter(|r| !r.trim().is_empty());
+        if result.is_none() && dep.artifacts.is_empty() {
+            continue;
+        }
+        any = true;
+        output.push_str(&format!("### {} ({})\n", dep.title, dep.id));
+        if let Some(result) = result {
+            output.push_str(result.trim());
+            output.push('\n');
+        }
+        for artifact in &dep.artifacts {
+            output.push_str(&format!(
+                "- **{:?}**: `{}`\n",
+                artifact.artifact_type, artifact.uri
+            ));
+        }
+        output.push('\n');
+    }
+    any.then_some(output)
+}
+
+/// Format the output contract for a task with an output schema, including
+/// the validation errors from the previous attempt when it was rejected.
+pub(crate) fn format_output_contract(task: &Task) -> Option<String> {
+    let schema = task.output_schema()?;
+    let mut output = format!(
+        "## Required Output Format\nYour final response must be a single JSON value matching this JSON Schema:\n\n```json\n{}\n```\n",
+        serde_json::to_string_pretty(schema).unwrap_or_default()
+    );
+    if let Some(reason) = task
+        .last_failure_reason()
+        .and_then(|r| r.strip_prefix(SCHEMA_VALIDATION_FAILED))
+    {
+        output.push_str(&format!(
+            "\nYour previous response was rejected ({}). Reply with only the corrected JSON.\n",
+            reason.trim_start_matches(':').trim()
+        ));
+    }
+    Some(output)
+}
+
 /// Assemble the final task description in priority order:
-/// goal_context > memory_context > intent_gap_context > task.description.
+/// goal_context > memory_context > dependency_context > intent_gap_context >
+/// task.description, followed by the output contract when the task has one.
 pub(crate) fn assemble_description(
     task: &Task,
     goal_context: Option<&str>,
     memory_context: Option<&str>,
+    dependency_context: Option<&str>,
     intent_gap_context: Option<&str>,
 ) -> String {
     let mut parts: Vec<&str> = Vec::new();
@@ -151,13 +366,20 @@ pub(crate) fn assemble_description(
     if let Some(m) = memory_context {
         parts.push(m);
     }
+    if let Some(d) = dependency_context {
+        parts.push(d);
+    }
     if let Some(g) = intent_gap_context {
         parts.push(g);
     }
+    let body = match format_output_contract(task) {
+        Some(contract) => format!("{}\n\n{}", task.description, contract),
+        None => task.description.clone(),
+    };
     if parts.is_empty() {
-        task.description.clone()
+        body
     } else {
-        format!("{}\n\n---\n\n{}", parts.join("\n\n---\n\n"), task.description)
+        format!("{}\n\n---\n\n{}", parts.join("\n\n---\n\n"), body)
     }
 }
 
@@ -182,25 +404,46 @@ mod tests {
             &task,
             Some("[goal]"),
             Some("[memory]"),
+            Some("[deps]"),
             Some("[gap]"),
         );
-        // Goal first, then memory, then gap, then the task description last.
+        // Goal first, then memory, dependencies, gap, and the task description last.
         let goal_idx = out.find("[goal]").unwrap();
         let mem_idx = out.find("[memory]").unwrap();
+        let dep_idx = out.find("[deps]").unwrap();
         let gap_idx = out.find("[gap]").unwrap();
         let body_idx = out.find("Hello task body").unwrap();
         assert!(goal_idx < mem_idx);
-        assert!(mem_idx < gap_idx);
+        assert!(mem_idx < dep_idx);
+        assert!(dep_idx < gap_idx);
         assert!(gap_idx < body_idx);
     }
 
     #[test]
     fn test_assemble_description_no_context_returns_body() {
         let task = Task::new("body only");
-        let out = assemble_description(&task, None, None, None);
+        let out = assemble_description(&task, None, None, None, None);
         assert_eq!(out, "body only");
     }
 
+    #[test]
+    fn test_output_contract_follows_body_and_carries_rejection() {
+        let mut task = Task::new("Classify the issue");
+        task.set_output_schema(serde_json::json!({"type": "object", "required": ["label"]}));
+        let out = assemble_description(&task, None, None, None, None);
+        assert!(out.starts_with("Classify the issue\n\n## Required Output Format"));
+        assert!(out.contains("\"required\""));
+        assert!(!out.contains("previous response was rejected"));
+
+        task.set_last_failure_reason(
+            "schema_validation_failed: $: missing required property 'label'",
+        );
+        let out = assemble_description(&task, None, None, None, None);
+        assert!(
+            out.contains("previous response was rejected ($: missing required property 'label')")
+        );
+    }
+
     #[tokio::test]
     async fn test_context_loads_goal_guidance_returns_none_when_no_goals() {
         // With no goals in repo, loader returns None gracefully.
@@ -224,6 +467,102 @@ mod tests {
         assert!(ctx.memory_context.is_none());
     }
 
+    #[tokio::test]
+    async fn test_dependent_prompt_includes_dependency_result() {
+        let (goal_repo, task_repo, _wt_repo, _agent_repo, mem_repo) =
+            test_support::setup_all_repos().await;
+
+        let mut schema = Task::new("Design the schema");
+        schema.transition_to(TaskStatus::Ready).unwrap();
+        schema.transition_to(TaskStatus::Running).unwrap();
+        schema.set_result("Tables: users(id, email), orders(id, user_id)");
+        schema.transition_to(TaskStatus::Complete).unwrap();
+        task_repo.create(&schema).await.unwrap();
+
+        let mut notes = Task::new("Collect notes");
+        notes.transition_to(TaskStatus::Ready).unwrap();
+        notes.transition_to(TaskStatus::Running).unwrap();
+        notes.set_result("unrelated notes");
+        notes.transition_to(TaskStatus::Complete).unwrap();
+        task_repo.create(&notes).await.unwrap();
+
+        // Only the schema edge opts in to result injection.
+        let task = Task::new("Write the migration")
+            .with_result_dependency(schema.id)
+            .with_dependency(notes.id);
+        let svc = TaskContextService::new(goal_repo, Some(mem_repo)).with_task_repo(task_repo);
+        let ctx = svc.load_task_context(&task).await.unwrap();
+
+        let deps = ctx.dependency_context.as_deref().unwrap();
+        assert!(deps.contains("Design the schema"));
+        assert!(
+            ctx.combined_description
+                .contains("Tables: users(id, email), orders(id, user_id)")
+        );
+        assert!(!ctx.combined_description.contains("unrelated notes"));
+        assert!(ctx.combined_description.ends_with("Write the migration"));
+    }
+
+    #[tokio::test]
+    async fn test_incomplete_dependency_result_not_injected() {
+        let (goal_repo, task_repo, _wt_repo, _agent_repo, mem_repo) =
+            test_support::setup_all_repos().await;
+        let mut dep = Task::new("Still running");
+        dep.set_result("stale output from a previous attempt");
+        task_repo.create(&dep).await.unwrap();
+
+        let task = Task::new("Downstream").with_result_dependency(dep.id);
+        let svc = TaskContextService::new(goal_repo, Some(mem_repo)).with_task_repo(task_repo);
+        let ctx = svc.load_task_context(&task).await.unwrap();
+
+        assert!(ctx.dependency_context.is_none());
+        assert_eq!(ctx.combined_description, "Downstream");
+    }
+
+    #[tokio::test]
+    async fn test_retrieved_memory_is_reinforced_and_outlives_unretrieved() {
+        use crate::domain::ports::MemoryRepository;
+
+        let (goal_repo, _task_repo, _wt_repo, _agent_repo, mem_repo) =
+            test_support::setup_all_repos().await;
+        let two_hours_ago = chrono::Utc::now() - chrono::Duration::hours(2);
+        let mut used = Memory::working("retry-policy", "Retries back off exponentially")
+            .with_namespace("infra");
+        let mut unused =
+            Memory::working("style-guide", "Prefer early returns").with_namespace("style");
+        for mem in [&mut used, &mut unused] {
+            mem.last_accessed = two_hours_ago;
+            mem_repo.store(mem).await.unwrap();
+        }
+
+        let svc = TaskContextService::new(goal_repo, Some(mem_repo.clone()))
+            .with_memory_strategy(MemoryRetrievalStrategy::Namespace {
+                namespace: "infra".to_string(),
+            })
+            .with_memory_reinforcement(5);
+        let task = Task::new("Tune the retry policy");
+        let ctx = svc.load_task_context(&task).await.unwrap();
+        assert!(ctx.memory_context.unwrap().contains("retry-policy"));
+
+        let reinforced = mem_repo.get(used.id).await.unwrap().unwrap();
+        assert_eq!(reinforced.access_count, 1);
+        assert_eq!(mem_repo.get(unused.id).await.unwrap().unwrap().access_count, 0);
+
+        // Both were equally stale; only the unretrieved one has decayed away.
+        let decayed: Vec<_> = mem_repo
+            .get_decayed(0.1)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(decayed, vec![unused.id]);
+
+        // A retry of the same task does not reinforce the memory again.
+        svc.load_task_context(&task).await.unwrap();
+        assert_eq!(mem_repo.get(used.id).await.unwrap().unwrap().access_count, 1);
+    }
+
     #[test]
     fn test_format_memory_context_renders_entries() {
         let entries = vec![
@@ -236,4 +575,75 @@ mod tests {
         assert!(s.contains("0.90"));
         assert!(s.contains("0.70"));
     }
+
+    fn context_with(task: &Task, goal: &str, memory: &str, gap: &str) -> TaskContext {
+        let mut ctx = TaskContext {
+            goal_context: Some(goal.to_string()),
+            memory_context: Some(memory.to_string()),
+            dependency_context: None,
+            intent_gap_context: Some(gap.to_string()),
+            combined_description: String::new(),
+        };
+        ctx.reassemble(task);
+        ctx
+    }
+
+    #[test]
+    fn test_fit_to_window_trims_memory_first() {
+        let task = Task::new("Implement the widget");
+        let system_prompt = "You are a careful engineer.";
+        let goal = "goal guidance\n".repeat(20);
+        let memory = "remembered fact\n".repeat(500);
+        let gap = "intent gap\n".repeat(10);
+        let mut ctx = context_with(&task, &goal, &memory, &gap);
+        let max = 1_000;
+        assert!(estimate_tokens(&ctx.combined_description) > max);
+
+        let dropped = ctx.fit_to_window(&task, system_prompt, max).unwrap();
+
+        assert!(estimate_tokens(system_prompt) + estimate_tokens(&ctx.combined_description) <= max);
+        assert_eq!(dropped, vec!["memory_context (truncated)".to_string()]);
+        assert_eq!(ctx.goal_context.as_deref(), Some(goal.as_str()));
+        assert_eq!(ctx.intent_gap_context.as_deref(), Some(gap.as_str()));
+        assert!(ctx.combined_description.ends_with("Implement the widget"));
+    }
+
+    #[test]
+    fn test_fit_to_window_drops_sections_that_cannot_fit() {
+        let task = Task::new("Implement the widget");
+        let goal = "goal guidance\n".repeat(200);
+        let memory = "remembered fact\n".repeat(100);
+        let mut ctx = context_with(&task, &goal, &memory, "gap");
+        let max = 80;
+
+        let dropped = ctx.fit_to_window(&task, "prompt", max).unwrap();
+
+        assert!(estimate_tokens("prompt") + estimate_tokens(&ctx.combined_description) <= max);
+        assert_eq!(dropped, vec!["memory_context", "goal_context"]);
+        assert!(ctx.memory_context.is_none());
+        assert!(ctx.goal_context.is_none());
+        assert_eq!(ctx.intent_gap_context.as_deref(), Some("gap"));
+    }
+
+    #[test]
+    fn test_fit_to_window_leaves_fitting_context_alone() {
+        let task = Task::new("Implement the widget");
+        let mut ctx = context_with(&task, "goal", "memory", "gap");
+        let before = ctx.combined_description.clone();
+
+        let dropped = ctx.fit_to_window(&task, "prompt", 10_000).unwrap();
+
+        assert!(dropped.is_empty());
+        assert_eq!(ctx.combined_description, before);
+    }
+
+    #[test]
+    fn test_fit_to_window_rejects_oversized_mandatory_parts() {
+        let task = Task::new("x".repeat(1_000));
+        let mut ctx = context_with(&task, "goal", "memory", "gap");
+
+        let err = ctx.fit_to_window(&task, "prompt", 100).unwrap_err();
+
+        assert!(matches!(err, DomainError::ValidationFailed(_)));
+    }
 }
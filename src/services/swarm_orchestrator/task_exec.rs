@@ -29,12 +29,12 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use tokio::sync::{RwLock, Semaphore, mpsc};
+use tokio::sync::{RwLock, mpsc};
 
 use crate::domain::models::workflow_template::WorkflowTemplate;
 use crate::domain::models::{
-    AgentTier, ExecutionMode, OutputDelivery, SessionStatus, SubstrateConfig, SubstrateRequest,
-    Task, TaskStatus,
+    AgentTier, ExecutionMode, OutputDelivery, SessionStatus, SubstrateConfig, SubstrateOutput,
+    SubstrateRequest, SubstrateSession, Task, TaskStatus,
 };
 use crate::domain::models::convergence::ConvergenceEngineConfig;
 use crate::domain::ports::{
@@ -45,12 +45,16 @@ use crate::services::command_bus::{CommandBus, CommandEnvelope, CommandSource, D
 use crate::services::event_bus::EventBus;
 use crate::services::evolution_loop::EvolutionLoop;
 use crate::services::guardrails::Guardrails;
+use crate::services::output_schema::{SCHEMA_VALIDATION_FAILED, validate_output};
+use crate::services::partial_output::{OutputBuffer, record_attempt_output};
+use crate::services::result_cache::{CachedResult, ResultCache};
 use crate::services::{
     AgentTierHint, AuditAction, AuditActor, AuditCategory, AuditEntry, AuditLevel, CircuitScope,
     ModelRouter, TaskExecution, TaskOutcome,
 };
 
 use super::agent_prep::AgentMetadata;
+use super::agent_slots::{AgentSlotPermit, AgentSlots};
 use super::convergent_execution::{ConvergentIntentVerifier, ConvergentOutcome};
 use super::goal_processing::{
     can_safely_auto_complete, is_max_turns_auto_completable, replay_gate_rejection_event,
@@ -69,7 +73,7 @@ use super::types::SwarmEvent;
 pub struct ExecutionConfig {
     pub repo_path: PathBuf,
     pub default_base_ref: String,
-    /// The orchestrator owns the semaphore; permits are acquired before
+    /// The orchestrator owns the slot budget; permits are acquired before
     /// `execute_task` is called and travel via `TaskExecutionParams::permit`.
     /// Kept here so the orchestrator can pass through any future construction
     /// helpers without rewiring callsites.
@@ -77,7 +81,7 @@ pub struct ExecutionConfig {
     // `TaskExecutionParams::permit`. Kept on `ExecutionConfig` so the
     // orchestrator can hand it to future helpers without rewiring callsites.
     #[allow(dead_code)]
-    pub agent_semaphore: Arc<Semaphore>,
+    pub agent_slots: Arc<AgentSlots>,
     pub guardrails: Arc<Guardrails>,
     pub require_commits: bool,
     pub verify_on_completion: bool,
@@ -89,6 +93,9 @@ pub struct ExecutionConfig {
     pub output_delivery: OutputDelivery,
     pub merge_request_repo: Option<Arc<dyn MergeRequestRepository>>,
     pub post_completion_chain: Arc<RwLock<PostCompletionChain>>,
+    /// Present when `[result_cache]` is enabled.
+    pub result_cache: Option<Arc<ResultCache>>,
+    pub model_router: ModelRouter,
 }
 
 /// Parameters captured for a single task execution. Owns every Arc/clone the
@@ -122,7 +129,7 @@ pub struct TaskExecutionParams {
     pub circuit_breaker: Arc<crate::services::CircuitBreakerService>,
     pub command_bus: Option<Arc<CommandBus>>,
     pub total_tokens: Arc<AtomicU64>,
-    pub permit: tokio::sync::OwnedSemaphorePermit,
+    pub permit: AgentSlotPermit,
 
     // Convergence infrastructure (None when not configured)
     pub overseer_cluster: Option<Arc<crate::services::overseers::OverseerClusterService>>,
@@ -185,6 +192,7 @@ pub async fn execute_task(params: TaskExecutionParams) {
     let post_goal_repo: Arc<dyn GoalRepository> = goal_repo;
     let post_worktree_repo: Arc<dyn WorktreeRepository> = worktree_repo.clone();
     let post_completion_chain = config.post_completion_chain.clone();
+    let model_router = config.model_router.clone();
     let repo_path = config.repo_path;
     let default_base_ref = config.default_base_ref;
     let verify_on_completion = config.verify_on_completion;
@@ -197,6 +205,7 @@ pub async fn execute_task(params: TaskExecutionParams) {
     let output_delivery = config.output_delivery;
     let merge_request_repo = config.merge_request_repo;
     let guardrails = config.guardrails;
+    let result_cache = config.result_cache;
 
     // Task is already Running (claimed atomically before spawn).
 
@@ -797,30 +806,32 @@ pub async fn execute_task(params: TaskExecutionParams) {
     if let Some(ref wt_path) = worktree_path {
         substrate_config = substrate_config.with_working_dir(wt_path);
     }
-
-    if let Some(ref model) = template_preferred_model {
-        substrate_config.model = Some(model.clone());
-    } else {
-        let tier_hint = match template_tier {
-            AgentTier::Architect => AgentTierHint::Architect,
-            AgentTier::Specialist => AgentTierHint::Specialist,
-            AgentTier::Worker => AgentTierHint::Worker,
-        };
-        let selection = ModelRouter::with_defaults().select_model(
-            task_clone.routing_hints.complexity,
-            Some(tier_hint),
-            task_clone.retry_count,
-        );
-        tracing::debug!(
-            task_id = %task_id,
-            %agent_type,
-            model = %selection.model,
-            reason = %selection.reason,
-            "ModelRouter selected model for direct execution"
-        );
-        substrate_config.model = Some(selection.model);
+    if let Some(ref params) = task_clone.execution_params {
+        params.apply_sampling(&mut substrate_config);
     }
 
+    let tier_hint = match template_tier {
+        AgentTier::Architect => AgentTierHint::Architect,
+        AgentTier::Specialist => AgentTierHint::Specialist,
+        AgentTier::Worker => AgentTierHint::Worker,
+    };
+    let selection = model_router.select_for_agent(
+        &agent_type,
+        template_preferred_model.as_deref(),
+        task_clone.routing_hints.complexity,
+        Some(tier_hint),
+        task_clone.retry_count,
+    );
+    tracing::debug!(
+        task_id = %task_id,
+        %agent_type,
+        model = %selection.model,
+        reason = %selection.reason,
+        ladder_position = ?selection.ladder_position,
+        "ModelRouter selected model for direct execution"
+    );
+    substrate_config.model = Some(selection.model);
+
     if !cli_tools.is_empty() {
         substrate_config = substrate_config.with_allowed_tools(cli_tools);
     }
@@ -857,14 +868,147 @@ pub async fn execute_task(params: TaskExecutionParams) {
         SubstrateRequest::new(task_id, &agent_type, &system_prompt, &task_description)
             .with_config(substrate_config);
 
-    let result = substrate.execute(request).await;
+    // A cacheable task run by a read-only agent may reuse the result of an
+    // identical run against the same repository state. Write-capable agents
+    // are excluded: a cached result cannot reproduce their file changes.
+    let cache_slot = match result_cache {
+        Some(cache) if task_clone.is_cacheable() && !require_commits => {
+            let work_dir = worktree_path
+                .as_deref()
+                .map(std::path::Path::new)
+                .unwrap_or(&repo_path);
+            crate::services::result_cache::repo_state(work_dir)
+                .await
+                .map(|state| {
+                    let key =
+                        ResultCache::cache_key(&agent_type, &system_prompt, &task_description);
+                    (cache, key, state)
+                })
+        }
+        _ => None,
+    };
+    let cache_hit = match &cache_slot {
+        Some((cache, key, state)) => cache.lookup(key, state).await,
+        None => None,
+    };
+    let served_from_cache = cache_hit.is_some();
+
+    let result = if let Some(hit) = cache_hit {
+        tracing::info!(
+            task_id = %task_id,
+            source_task_id = %hit.source_task_id,
+            tokens_saved = hit.tokens,
+            "Reusing cached result; agent not started"
+        );
+        if let Ok(Some(mut t)) = task_repo.get(task_id).await {
+            t.set_result_cached();
+            let _ = task_repo.update(&t).await;
+        }
+        event_bus
+            .publish(crate::services::event_factory::task_event(
+                crate::services::event_bus::EventSeverity::Info,
+                None,
+                task_id,
+                crate::services::event_bus::EventPayload::TaskResultCached {
+                    task_id,
+                    source_task_id: hit.source_task_id,
+                    tokens_saved: hit.tokens,
+                },
+            ))
+            .await;
+        let mut session = SubstrateSession::new(task_id, &agent_type, request.config);
+        session.complete(hit.result.unwrap_or_default());
+        Ok(session)
+    } else {
+        let (output_tx, output_forwarder) =
+            spawn_output_forwarder(event_bus.clone(), task_id, agent_type.clone());
+        let result = substrate.execute_with_output(request, output_tx).await;
+        let streamed = output_forwarder.await.unwrap_or_default();
+
+        // Keep what the agent streamed when the attempt did not complete,
+        // matching the DAG executor, and drop stale output when it did.
+        let completed = matches!(&result, Ok(s) if s.status == SessionStatus::Completed);
+        if let Ok(Some(mut t)) = task_repo.get(task_id).await
+            && record_attempt_output(&mut t, completed, streamed.as_str())
+            && let Err(e) = task_repo.update(&t).await
+        {
+            tracing::warn!(task_id = %task_id, error = %e, "failed to persist partial agent output");
+        }
+
+        // Only execution errors count against the substrate: an agent that ran
+        // and then failed its task says nothing about the backend's health.
+        let substrate_scope = CircuitScope::substrate(substrate.name());
+        match &result {
+            Ok(_) => circuit_breaker.record_success(substrate_scope).await,
+            Err(e) => {
+                circuit_breaker
+                    .record_failure(substrate_scope, e.to_string())
+                    .await
+            }
+        }
+        result
+    };
 
     if let Some(ref wt_path) = worktree_path {
         let _ = auto_commit_worktree(wt_path, task_id).await;
     }
 
     if let Ok(Some(mut completed_task)) = task_repo.get(task_id).await {
+        let gate_error = match &result {
+            Ok(session)
+                if session.status == SessionStatus::Completed
+                    && !completed_task.status.is_terminal() =>
+            {
+                let work_dir = worktree_path
+                    .as_deref()
+                    .map(std::path::Path::new)
+                    .unwrap_or(&repo_path);
+                match enforce_verification_gate(
+                    &completed_task,
+                    overseer_cluster.as_deref(),
+                    work_dir,
+                    &task_repo,
+                    command_bus.as_ref(),
+                    &event_bus,
+                )
+                .await
+                {
+                    Some(error) => Some(error),
+                    None => {
+                        enforce_output_schema(
+                            &completed_task,
+                            session.result.as_deref(),
+                            &task_repo,
+                            command_bus.as_ref(),
+                            &event_bus,
+                        )
+                        .await
+                    }
+                }
+            }
+            _ => None,
+        };
+
         match result {
+            Ok(session) if gate_error.is_some() => {
+                let error_msg = gate_error.unwrap_or_default();
+                total_tokens.fetch_add(session.total_tokens(), Ordering::Relaxed);
+                circuit_breaker
+                    .record_failure(circuit_scope.clone(), &error_msg)
+                    .await;
+                audit_log
+                    .log(
+                        AuditEntry::new(
+                            AuditLevel::Warning,
+                            AuditCategory::Task,
+                            AuditAction::TaskFailed,
+                            AuditActor::System,
+                            format!("Task failed verification gate: {}", error_msg),
+                        )
+                        .with_entity(task_id, "task"),
+                    )
+                    .await;
+            }
             Ok(session) if session.status == SessionStatus::Completed => {
                 let tokens = session.total_tokens();
                 let turns = session.turns_completed;
@@ -951,6 +1095,29 @@ pub async fn execute_task(params: TaskExecutionParams) {
 
                 circuit_breaker.record_success(circuit_scope.clone()).await;
 
+                if !served_from_cache && let Some((cache, key, repo_state)) = cache_slot {
+                    cache
+                        .store(
+                            key,
+                            CachedResult {
+                                repo_state,
+                                result: session.result.clone(),
+                                source_task_id: task_id,
+                                tokens,
+                            },
+                        )
+                        .await;
+                }
+
+                // Keep the agent's final output so dependents that opted in
+                // can have it injected into their prompts.
+                if let Some(output) = session.result.as_deref().filter(|o| !o.trim().is_empty())
+                    && let Ok(Some(mut t)) = task_repo.get(task_id).await
+                {
+                    t.set_result(output);
+                    let _ = task_repo.update(&t).await;
+                }
+
                 audit_log
                     .log(
                         AuditEntry::new(
@@ -959,8 +1126,10 @@ pub async fn execute_task(params: TaskExecutionParams) {
                             AuditAction::TaskCompleted,
                             AuditActor::System,
                             format!(
-                                "Task completed: {} tokens used, {} turns",
-                                tokens, turns
+                                "Task completed on {}: {} tokens used, {} turns",
+                                substrate.name(),
+                                tokens,
+                                turns
                             ),
                         )
                         .with_entity(task_id, "task"),
@@ -1390,6 +1559,162 @@ pub async fn execute_task(params: TaskExecutionParams) {
     guardrails.register_agent_end(&agent_unique_id).await;
 }
 
+/// Publish each piece of agent output as an `AgentOutputChunk` event until
+/// the substrate drops the returned sender. The events are persisted with the
+/// task, which is what `task logs` replays and follows. The handle resolves to
+/// the buffered text so a failed attempt can keep it as partial output.
+fn spawn_output_forwarder(
+    event_bus: Arc<EventBus>,
+    task_id: uuid::Uuid,
+    agent_type: String,
+) -> (
+    mpsc::Sender<SubstrateOutput>,
+    tokio::task::JoinHandle<OutputBuffer>,
+) {
+    let (tx, mut rx) = mpsc::channel(100);
+    let handle = tokio::spawn(async move {
+        let mut buffer = OutputBuffer::new();
+        while let Some(output) = rx.recv().await {
+            buffer.record(&output);
+            let (kind, content) = match output {
+                SubstrateOutput::AssistantText { content } => ("text", content),
+                SubstrateOutput::ToolStart { name, .. } => ("tool_start", name),
+                SubstrateOutput::ToolResult {
+                    result,
+                    is_error: false,
+                    ..
+                } => ("tool_result", result),
+                SubstrateOutput::ToolResult {
+                    result,
+                    is_error: true,
+                    ..
+                } => ("tool_error", result),
+                _ => continue,
+            };
+            event_bus
+                .publish(crate::services::event_factory::task_event(
+                    crate::services::event_bus::EventSeverity::Debug,
+                    None,
+                    task_id,
+                    crate::services::event_bus::EventPayload::AgentOutputChunk {
+                        task_id,
+                        agent_type: agent_type.clone(),
+                        kind: kind.to_string(),
+                        content,
+                    },
+                ))
+                .await;
+        }
+        buffer
+    });
+    (tx, handle)
+}
+
+/// Run the overseers a direct-mode task declared via `task submit --verify`
+/// once the agent has completed. When any blocking overseer fails, the task is
+/// marked Failed (so the retry handlers pick it up) instead of Complete, and
+/// the failure message is returned. Returns `None` when the task declares no
+/// overseers or every one passed.
+async fn enforce_verification_gate(
+    task: &Task,
+    overseer_cluster: Option<&crate::services::overseers::OverseerClusterService>,
+    work_dir: &std::path::Path,
+    task_repo: &Arc<dyn TaskRepository>,
+    command_bus: Option<&Arc<CommandBus>>,
+    event_bus: &Arc<EventBus>,
+) -> Option<String> {
+    if task.required_overseers.is_empty() {
+        return None;
+    }
+
+    let failures = match overseer_cluster {
+        Some(cluster) => {
+            let artifact = crate::domain::models::convergence::ArtifactReference::new(
+                work_dir.to_string_lossy(),
+                "",
+            );
+            cluster.verify(&task.required_overseers, &artifact).await
+        }
+        None => vec!["no overseer cluster configured".to_string()],
+    };
+    if failures.is_empty() {
+        return None;
+    }
+
+    let error_msg = format!("verification gate failed: {}", failures.join("; "));
+    tracing::warn!(task_id = %task.id, error = %error_msg, "Task failed its verification gate");
+    Some(fail_at_completion_gate(task, error_msg, task_repo, command_bus, event_bus).await)
+}
+
+/// Check the agent's final output against the task's output schema. On a
+/// mismatch the task is marked Failed with a `schema_validation_failed`
+/// error, which the retry handler feeds back to the next attempt as a
+/// corrective hint. Returns `None` when the task declares no schema or the
+/// output matches it.
+async fn enforce_output_schema(
+    task: &Task,
+    output: Option<&str>,
+    task_repo: &Arc<dyn TaskRepository>,
+    command_bus: Option<&Arc<CommandBus>>,
+    event_bus: &Arc<EventBus>,
+) -> Option<String> {
+    let schema = task.output_schema()?;
+    let reason = validate_output(schema, output.unwrap_or("")).err()?;
+
+    let error_msg = format!("{}: {}", SCHEMA_VALIDATION_FAILED, reason);
+    tracing::warn!(task_id = %task.id, error = %error_msg, "Task output failed schema validation");
+    Some(fail_at_completion_gate(task, error_msg, task_repo, command_bus, event_bus).await)
+}
+
+/// Mark a task that completed but failed a completion gate as Failed, so the
+/// retry handlers pick it up, and return the failure message.
+async fn fail_at_completion_gate(
+    task: &Task,
+    error_msg: String,
+    task_repo: &Arc<dyn TaskRepository>,
+    command_bus: Option<&Arc<CommandBus>>,
+    event_bus: &Arc<EventBus>,
+) -> String {
+    let task_id = task.id;
+    if let Some(cb) = command_bus {
+        let envelope = CommandEnvelope::new(
+            CommandSource::System,
+            DomainCommand::Task(TaskCommand::Fail {
+                task_id,
+                error: Some(error_msg.clone()),
+            }),
+        );
+        if cb.dispatch(envelope).await.is_ok() {
+            return error_msg;
+        }
+        tracing::warn!(
+            "Failed to fail task {} via CommandBus, using non-atomic fallback",
+            task_id
+        );
+    }
+
+    if let Ok(Some(mut t)) = task_repo.get(task_id).await
+        && !t.status.is_terminal()
+    {
+        let _ = t.transition_to(TaskStatus::Failed);
+        let _ = task_repo.update(&t).await;
+    }
+    event_bus
+        .publish(crate::services::event_factory::task_event(
+            crate::services::event_bus::EventSeverity::Warning,
+            None,
+            task_id,
+            crate::services::event_bus::EventPayload::TaskFailed {
+                task_id,
+                error: error_msg.clone(),
+                retry_count: task.retry_count,
+            },
+        ))
+        .await;
+
+    error_msg
+}
+
 /// Handle the `IntentGapsFound` outcome from convergent execution: store
 /// structured gap context on the failing task, transition it to Failed, and
 /// for standalone tasks (no parent workflow) create an explicit retry task
@@ -1558,6 +1883,79 @@ mod tests {
     use crate::adapters::substrates::MockSubstrate;
     use crate::services::guardrails::Guardrails;
 
+    #[tokio::test]
+    async fn test_verification_gate_fails_task_when_compilation_fails() {
+        use crate::domain::models::convergence::{
+            ArtifactReference, BuildResult, Overseer, OverseerCost, OverseerResult,
+            OverseerSignalUpdate,
+        };
+
+        struct BrokenCompile;
+
+        #[async_trait::async_trait]
+        impl Overseer for BrokenCompile {
+            fn name(&self) -> &str {
+                "compilation"
+            }
+            async fn measure(&self, _: &ArtifactReference) -> anyhow::Result<OverseerResult> {
+                Ok(OverseerResult {
+                    pass: false,
+                    signal: OverseerSignalUpdate::BuildResult(BuildResult {
+                        success: false,
+                        error_count: 1,
+                        errors: vec!["error[E0425]: cannot find value `x`".to_string()],
+                    }),
+                })
+            }
+            fn cost(&self) -> OverseerCost {
+                OverseerCost::Cheap
+            }
+        }
+
+        let task_repo: Arc<dyn TaskRepository> = test_support::setup_task_repo().await;
+        let mut task = Task::new("Gated change");
+        task.required_overseers = vec!["compilation".to_string()];
+        task.transition_to(TaskStatus::Ready).unwrap();
+        task.transition_to(TaskStatus::Running).unwrap();
+        task_repo.create(&task).await.unwrap();
+
+        let mut cluster = crate::services::overseers::OverseerClusterService::new();
+        cluster.add(Box::new(BrokenCompile));
+        let event_bus = Arc::new(EventBus::new(
+            crate::services::event_bus::EventBusConfig::default(),
+        ));
+
+        let error = enforce_verification_gate(
+            &task,
+            Some(&cluster),
+            std::path::Path::new("."),
+            &task_repo,
+            None,
+            &event_bus,
+        )
+        .await
+        .expect("gate must fail");
+        assert!(error.contains("compilation"), "{error}");
+
+        let stored = task_repo.get(task.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, TaskStatus::Failed);
+
+        // Tasks without a gate are left for the normal completion path.
+        let ungated = Task::new("Ungated");
+        assert!(
+            enforce_verification_gate(
+                &ungated,
+                Some(&cluster),
+                std::path::Path::new("."),
+                &task_repo,
+                None,
+                &event_bus,
+            )
+            .await
+            .is_none()
+        );
+    }
+
     /// Risk 3 mitigation test: a minimal post-completion middleware fires
     /// when the spawn block calls `run_post_completion_workflow` for a
     /// completed direct-mode task.
@@ -11,8 +11,11 @@ use crate::domain::models::workflow_template::WorkflowTemplate;
 /// Configuration for the swarm orchestrator.
 #[derive(Debug, Clone)]
 pub struct SwarmConfig {
-    /// Maximum concurrent agents.
+    /// Maximum concurrent agents — the slot budget when agent types carry
+    /// slot weights.
     pub max_agents: usize,
+    /// Slots each agent type occupies out of `max_agents` (default 1).
+    pub agent_slot_weights: std::collections::HashMap<String, u32>,
     /// Default max turns per agent invocation.
     pub default_max_turns: u32,
     /// Whether to use worktrees for task isolation.
@@ -23,9 +26,26 @@ pub struct SwarmConfig {
     pub auto_retry: bool,
     /// Maximum retries per task.
     pub max_task_retries: u32,
+    /// Backoff before a failed task is retried.
+    pub retry_backoff: crate::services::config::RetryBackoffConfig,
     /// Maximum review loop-back iterations (plan → implement → review) before
     /// falling through to normal failure handling.
     pub max_review_iterations: u32,
+    /// Maximum total tasks the review loop may create under a single root
+    /// task, across all review chains and daemon restarts.
+    pub max_review_loop_tasks_per_root: u64,
+    /// Per-agent-type model escalation ladders applied on retries.
+    pub model_escalation: crate::services::config::ModelEscalationConfig,
+    /// Per-agent-type memory retrieval strategies for prompt assembly.
+    pub memory_retrieval: crate::services::config::MemoryRetrievalConfig,
+    /// `[memory]` settings applied to every memory service the swarm builds.
+    pub memory: crate::services::config::MemoryConfig,
+    /// Keyword rules that choose an agent type for unassigned tasks.
+    pub task_routing: crate::services::config::TaskRoutingConfig,
+    /// Token budget for assembled agent prompts, keyed by substrate name
+    /// (`[substrates.<name>] max_context_tokens`). Substrates without an
+    /// entry use the model's context window.
+    pub max_context_tokens: std::collections::HashMap<String, usize>,
     /// Base path for worktrees.
     pub worktree_base_path: PathBuf,
     /// Repository path.
@@ -54,6 +74,9 @@ pub struct SwarmConfig {
     pub convergence: ConvergenceLoopConfig,
     /// Interval in seconds for the reconciliation safety-net loop (default: 30).
     pub reconciliation_interval_secs: Option<u64>,
+    /// How long shutdown waits for running agents to finish before putting
+    /// their tasks back to Ready (see `SwarmOrchestrator::drain`).
+    pub shutdown_drain_timeout_secs: u64,
     /// Configurable polling intervals for all scheduled handlers.
     pub polling: PollingConfig,
     /// Retention period for events in days (default: 30). Events older than
@@ -102,6 +125,24 @@ pub struct SwarmConfig {
     /// and push to remote after auto-ship merges to the base branch.
     /// Default: true. Set to false for local-only / offline development.
     pub fetch_on_sync: bool,
+
+    /// Whether a task reuses the completed worktree of a dependency that has
+    /// not yet been merged or cleaned up, instead of creating a fresh one.
+    /// Default: false.
+    pub reuse_chained_worktrees: bool,
+
+    /// Whether reconciliation destroys orphaned worktrees that have
+    /// uncommitted changes instead of escalating them. Default: false.
+    pub force_worktree_cleanup: bool,
+
+    /// Probability that each spawn slot picks a random ready task instead of
+    /// the highest-priority one. Default: 0.0 (strict priority order).
+    pub exploration_epsilon: f64,
+
+    /// Weight of wait time when ranking ready tasks (see
+    /// [`Task::aged_priority`](crate::domain::models::Task::aged_priority)).
+    /// Default: 0.0 (no aging).
+    pub priority_aging_coefficient: f64,
 }
 
 /// Configurable polling intervals (seconds) for all scheduled handlers.
@@ -142,6 +183,19 @@ pub struct PollingConfig {
     /// Interval for pruning old events (default: 21600s = 6 hours).
     pub event_pruning_interval_secs: u64,
 
+    // --- Artifact pruning ---
+    /// Whether artifact pruning is enabled (default: true).
+    pub artifact_pruning_enabled: bool,
+    /// Interval for pruning old task artifacts (default: 21600s = 6 hours).
+    pub artifact_pruning_interval_secs: u64,
+    /// Age in days after a task finishes before its older artifacts are pruned (default: 30).
+    pub artifact_retention_days: u64,
+    /// Number of most recent artifacts always kept per task (default: 1).
+    pub artifact_keep_per_task: usize,
+    /// Cap on artifacts held across terminal tasks; the oldest are pruned
+    /// first once it is exceeded (default: 10000).
+    pub artifact_max_total: usize,
+
     // --- SLA enforcement ---
     /// Interval for SLA check (default: 60s).
     pub sla_check_interval_secs: u64,
@@ -152,6 +206,10 @@ pub struct PollingConfig {
     /// Whether to auto-escalate on SLA breach (default: true).
     pub sla_auto_escalate_on_breach: bool,
 
+    // --- Budget ceiling ---
+    /// Interval for the hard-stop token ceiling check (default: 30s).
+    pub budget_check_interval_secs: u64,
+
     // --- Priority aging ---
     /// Whether priority aging is enabled (default: false, opt-in).
     pub priority_aging_enabled: bool,
@@ -177,6 +235,11 @@ pub struct PollingConfig {
     pub task_learning_min_retries: u32,
     /// Whether to store efficiency patterns for fast completions (default: true).
     pub task_learning_store_efficiency: bool,
+    /// Whether to condense task output via the substrate before storing it as a learning;
+    /// output is truncated instead when disabled or the substrate fails (default: false).
+    pub task_learning_summarize: bool,
+    /// Maximum characters of task output kept in a stored learning (default: 400).
+    pub task_learning_max_result_chars: usize,
 
     // --- Diagnostic/remediation task creation ---
     /// Whether to auto-create diagnostic tasks from drift detection (default: true).
@@ -193,6 +256,10 @@ pub struct PollingConfig {
     pub startup_max_replay_events: u64,
     /// Stale task threshold in seconds for startup orphan detection (default: 300).
     pub startup_stale_task_threshold_secs: u64,
+    /// Maximum number of tasks reconciled concurrently at startup (default: 8).
+    pub startup_reconciliation_concurrency: usize,
+    /// Events loaded per page when the reactor replays from the event store (default: 500).
+    pub event_replay_page_size: usize,
 
     // --- Obstacle escalation ---
     /// Whether obstacle escalation is enabled (default: true).
@@ -201,6 +268,12 @@ pub struct PollingConfig {
     pub obstacle_escalation_threshold: u32,
     /// Sliding window in seconds for counting failures (default: 86400 = 24 hours).
     pub obstacle_escalation_window_secs: u64,
+
+    // --- Adapter health ---
+    /// Interval for adapter connectivity checks (default: 300s).
+    pub adapter_health_check_interval_secs: u64,
+    /// Seconds an adapter may stay unhealthy before escalating (default: 1800).
+    pub adapter_unhealthy_escalation_secs: u64,
 }
 
 impl Default for PollingConfig {
@@ -232,12 +305,22 @@ impl Default for PollingConfig {
             dead_letter_retry_interval_secs: 60,
             event_pruning_interval_secs: 21600, // 6 hours
 
+            // Artifact pruning
+            artifact_pruning_enabled: true,
+            artifact_pruning_interval_secs: 21600, // 6 hours
+            artifact_retention_days: 30,
+            artifact_keep_per_task: 1,
+            artifact_max_total: 10_000,
+
             // SLA enforcement
             sla_check_interval_secs: 60,
             sla_warning_threshold_pct: 0.25,
             sla_critical_threshold_pct: 0.10,
             sla_auto_escalate_on_breach: true,
 
+            // Budget ceiling
+            budget_check_interval_secs: 30,
+
             // Priority aging (opt-in)
             priority_aging_enabled: false,
             priority_aging_interval_secs: 300,
@@ -253,6 +336,8 @@ impl Default for PollingConfig {
             task_learning_enabled: true,
             task_learning_min_retries: 1,
             task_learning_store_efficiency: true,
+            task_learning_summarize: false,
+            task_learning_max_result_chars: 400,
 
             // Diagnostic/remediation task creation
             auto_create_diagnostic_tasks: true,
@@ -263,11 +348,17 @@ impl Default for PollingConfig {
             startup_catchup_enabled: true,
             startup_max_replay_events: 10000,
             startup_stale_task_threshold_secs: 300,
+            startup_reconciliation_concurrency: 8,
+            event_replay_page_size: 500,
 
             // Obstacle escalation
             obstacle_escalation_enabled: true,
             obstacle_escalation_threshold: 3,
             obstacle_escalation_window_secs: 86400,
+
+            // Adapter health
+            adapter_health_check_interval_secs: 300,
+            adapter_unhealthy_escalation_secs: 1800,
         }
     }
 }
@@ -287,6 +378,9 @@ pub struct ConvergenceLoopConfig {
     pub convergence_timeout_secs: u64,
     /// Verification level: "goal" for goal-level, "wave" for per-wave, "task" for per-task.
     pub verification_level: VerificationLevel,
+    /// Stop plateaued trajectories early and extend steeply improving ones
+    /// (default: `None`, a fixed iteration cap).
+    pub adaptive_budget: Option<crate::domain::models::convergence::AdaptiveBudgetConfig>,
 }
 
 /// Level at which intent verification is performed.
@@ -320,6 +414,7 @@ impl Default for ConvergenceLoopConfig {
             auto_retry_partial: true,
             convergence_timeout_secs: 7200, // 2 hours
             verification_level: VerificationLevel::default(),
+            adaptive_budget: None,
         }
     }
 }
@@ -360,12 +455,21 @@ impl Default for SwarmConfig {
     fn default() -> Self {
         Self {
             max_agents: 4,
+            agent_slot_weights: std::collections::HashMap::new(),
             default_max_turns: 25,
             use_worktrees: true,
             goal_timeout_secs: 3600,
             auto_retry: true,
             max_task_retries: 3,
+            retry_backoff: crate::services::config::RetryBackoffConfig::default(),
             max_review_iterations: 3,
+            max_review_loop_tasks_per_root:
+                crate::services::builtin_handlers::DEFAULT_MAX_LOOP_TASKS_PER_ROOT,
+            model_escalation: crate::services::config::ModelEscalationConfig::default(),
+            memory_retrieval: crate::services::config::MemoryRetrievalConfig::default(),
+            memory: crate::services::config::MemoryConfig::default(),
+            task_routing: crate::services::config::TaskRoutingConfig::default(),
+            max_context_tokens: std::collections::HashMap::new(),
             worktree_base_path: PathBuf::from(".abathur/worktrees"),
             repo_path: PathBuf::from("."),
             default_base_ref: "main".to_string(),
@@ -379,6 +483,7 @@ impl Default for SwarmConfig {
             enable_intent_verification: true,
             convergence: ConvergenceLoopConfig::default(),
             reconciliation_interval_secs: None,
+            shutdown_drain_timeout_secs: 60,
             polling: PollingConfig::default(),
             event_retention_days: 30,
             convergence_enabled: true,
@@ -391,6 +496,10 @@ impl Default for SwarmConfig {
             overmind_max_turns: None,
             max_pending_ingestion_tasks: 1,
             fetch_on_sync: true,
+            reuse_chained_worktrees: false,
+            force_worktree_cleanup: false,
+            exploration_epsilon: 0.0,
+            priority_aging_coefficient: 0.0,
         }
     }
 }
@@ -653,6 +762,75 @@ pub struct SwarmStats {
     pub active_agents: usize,
     pub active_worktrees: usize,
     pub total_tokens_used: u64,
+    /// Circuit breakers currently probing for recovery.
+    pub half_open_circuits: usize,
+}
+
+/// Gate that prevented a ready task from spawning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpawnGate {
+    /// Task is not in the Ready state.
+    NotReady,
+    /// MCP servers / A2A gateway did not pass the readiness probe.
+    InfrastructureUnready,
+    /// Circuit breaker for the routed agent type is open.
+    CircuitBreaker,
+    /// Inside a configured quiet window.
+    QuietWindow,
+    /// Swarm-wide token ceiling has been reached.
+    BudgetCeiling,
+    /// A goal the task is charged to has spent its token budget.
+    GoalBudget,
+    /// Budget pressure defers tasks of this priority.
+    BudgetPressure,
+    /// Running agents are at the budget-adjusted ceiling.
+    BudgetConcurrency,
+    /// Guardrails deny another agent spawn.
+    Guardrails,
+    /// All `max_agents` permits are in use.
+    AgentCapacity,
+    /// A custom pre-spawn middleware skipped the task.
+    Middleware,
+}
+
+impl SpawnGate {
+    /// Map a built-in pre-spawn middleware name to its gate.
+    pub fn from_middleware(name: &str) -> Self {
+        match name {
+            "mcp-readiness" => Self::InfrastructureUnready,
+            "circuit-breaker" => Self::CircuitBreaker,
+            "quiet-window" => Self::QuietWindow,
+            "budget-ceiling" => Self::BudgetCeiling,
+            "goal-budget" => Self::GoalBudget,
+            "budget-dispatch" => Self::BudgetPressure,
+            "budget-concurrency" => Self::BudgetConcurrency,
+            "guardrails" => Self::Guardrails,
+            _ => Self::Middleware,
+        }
+    }
+}
+
+/// Why the scheduler would (or would not) spawn an agent for a task right now.
+///
+/// Produced by `SwarmOrchestrator::spawn_decision` without side effects.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SpawnDecision {
+    pub task_id: Uuid,
+    /// Agent type routing resolved to, if routing ran.
+    pub agent_type: Option<String>,
+    /// First gate that blocked the spawn; `None` means the task would spawn.
+    pub blocked_by: Option<SpawnGate>,
+    /// Name of the pre-spawn middleware that skipped, when applicable.
+    pub middleware: Option<String>,
+    /// Human-readable detail (skip reason or status).
+    pub reason: String,
+}
+
+impl SpawnDecision {
+    pub fn would_spawn(&self) -> bool {
+        self.blocked_by.is_none()
+    }
 }
 
 /// Try to convert an EventPayload into a SwarmEvent.
@@ -0,0 +1,102 @@
+//! Pluggable agent-type selection for tasks submitted without one.
+//!
+//! A [`TaskRouter`] gets the first say on a task whose `agent_type` is unset,
+//! both at submit time (`TaskService::submit_task`) and again before spawn
+//! (`RouteTaskMiddleware`). Returning `None` defers to the built-in fallback
+//! chain: preferred agent, tool matching, then `overmind`.
+
+use crate::domain::models::Task;
+use crate::services::config::{KeywordRoutingRule, TaskRoutingConfig};
+
+/// Chooses an agent type for an unassigned task.
+pub trait TaskRouter: Send + Sync {
+    /// Agent type for `task`, or `None` to leave the decision to the
+    /// built-in fallback chain.
+    fn route(&self, task: &Task) -> Option<String>;
+}
+
+/// Router that never decides, preserving the built-in routing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultTaskRouter;
+
+impl TaskRouter for DefaultTaskRouter {
+    fn route(&self, _task: &Task) -> Option<String> {
+        None
+    }
+}
+
+/// Router driven by `[[task_routing.rules]]`: the first rule with a keyword
+/// in the task's title or description wins.
+#[derive(Debug, Clone, Default)]
+pub struct KeywordTaskRouter {
+    rules: Vec<KeywordRoutingRule>,
+}
+
+impl KeywordTaskRouter {
+    pub fn new(config: &TaskRoutingConfig) -> Self {
+        let rules = config
+            .rules
+            .iter()
+            .map(|rule| KeywordRoutingRule {
+                agent_type: rule.agent_type.clone(),
+                keywords: rule
+                    .keywords
+                    .iter()
+                    .map(|k| k.trim().to_lowercase())
+                    .filter(|k| !k.is_empty())
+                    .collect(),
+            })
+            .collect();
+        Self { rules }
+    }
+}
+
+impl TaskRouter for KeywordTaskRouter {
+    fn route(&self, task: &Task) -> Option<String> {
+        if self.rules.is_empty() {
+            return None;
+        }
+        let text = format!("{}\n{}", task.title, task.description).to_lowercase();
+        self.rules
+            .iter()
+            .find(|rule| rule.keywords.iter().any(|k| text.contains(k.as_str())))
+            .map(|rule| rule.agent_type.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn router(rules: &[(&str, &[&str])]) -> KeywordTaskRouter {
+        KeywordTaskRouter::new(&TaskRoutingConfig {
+            rules: rules
+                .iter()
+                .map(|(agent, keywords)| KeywordRoutingRule {
+                    agent_type: agent.to_string(),
+                    keywords: keywords.iter().map(|k| k.to_string()).collect(),
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_keyword_rules_route_in_order() {
+        let router = router(&[
+            ("qa-engineer", &["Failing Test", "flaky"]),
+            ("technical-writer", &["docs", "readme"]),
+            ("catch-all", &["fix"]),
+        ]);
+
+        let qa = Task::new("Fix failing tests in the auth module");
+        assert_eq!(router.route(&qa).as_deref(), Some("qa-engineer"));
+
+        let docs = Task::with_title("Update README", "Describe the new flags");
+        assert_eq!(router.route(&docs).as_deref(), Some("technical-writer"));
+
+        let unmatched = Task::new("Implement OAuth login");
+        assert_eq!(router.route(&unmatched), None);
+        assert_eq!(DefaultTaskRouter.route(&qa), None);
+    }
+}
@@ -130,6 +130,7 @@ impl<R: TaskScheduleRepository> TaskScheduleService<R> {
             },
             TaskScheduleType::Cron { expression } => ScheduleType::Cron {
                 expression: expression.clone(),
+                timezone: None,
             },
         }
     }
@@ -145,7 +146,9 @@ impl<R: TaskScheduleRepository> TaskScheduleService<R> {
             goal_id: None,
             task_id: None,
             active: schedule.status == TaskScheduleStatus::Active,
-            created_at: Utc::now(),
+            // Anchor on the schedule's own creation time so a window missed
+            // before the first registration still gets its catch-up fire.
+            created_at: schedule.created_at,
             last_fired: schedule.last_fired_at,
             fire_count: schedule.fire_count,
         }
@@ -1,15 +1,16 @@
-//! Task lifecycle transitions: claim, complete, fail, retry, cancel,
+//! Task lifecycle transitions: claim, complete, fail, retry, rerun, cancel,
 //! transition_to_*, force_transition, and workflow/context state mutators.
 
 use uuid::Uuid;
 
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::models::workflow_state::WorkflowState;
-use crate::domain::models::{Task, TaskStatus};
+use crate::domain::models::{Task, TaskStatus, WorktreeStatus};
 use crate::domain::ports::TaskRepository;
 use crate::services::event_bus::{EventCategory, EventPayload, EventSeverity, UnifiedEvent};
+use crate::services::merge_queue::has_pending_merge;
 
-use super::TaskService;
+use super::{SubmitExtras, TaskService};
 
 impl<T: TaskRepository> TaskService<T> {
     /// Transition task to Running state (claim it).
@@ -418,6 +419,90 @@ impl<T: TaskRepository> TaskService<T> {
         Ok((task, events))
     }
 
+    /// Submit a fresh copy of a completed task, e.g. to verify its result
+    /// still holds. Unlike `retry_task`, the original is left untouched; the
+    /// new task carries the same description, agent and context and links
+    /// back to the original via `supersedes`. Unless `fresh` is set, the
+    /// original's worktree is handed to the copy before it becomes Ready.
+    pub async fn rerun_task(
+        &self,
+        task_id: Uuid,
+        fresh: bool,
+    ) -> DomainResult<(Task, Vec<UnifiedEvent>)> {
+        let original = self
+            .task_repo
+            .get(task_id)
+            .await?
+            .ok_or(DomainError::TaskNotFound(task_id))?;
+
+        if original.status != TaskStatus::Complete {
+            return Err(DomainError::ValidationFailed(format!(
+                "Task cannot be rerun: only completed tasks can be rerun (status is {}); use retry for failed tasks",
+                original.status.as_str()
+            )));
+        }
+
+        let (task, events) = self
+            .submit_task_with_extras(
+                Some(original.title.clone()),
+                original.description.clone(),
+                None,
+                original.priority,
+                original.agent_type.clone(),
+                vec![],
+                // Only durable context, so the copy starts from scratch.
+                Some(original.context.durable()),
+                None,
+                original.source.clone(),
+                None,
+                Some(original.task_type),
+                Some(original.execution_mode.clone()),
+                SubmitExtras {
+                    estimate_secs: original.estimate_secs,
+                    execution_params: original.execution_params.clone(),
+                    supersedes: Some(original.id),
+                    inherit_worktree_from: (!fresh).then_some(original.id),
+                    required_overseers: original.required_overseers.clone(),
+                    workspace_mode: Some(original.workspace_mode),
+                },
+            )
+            .await?;
+        tracing::info!(%task_id, rerun_task_id = %task.id, "completed task rerun submitted");
+
+        Ok((task, events))
+    }
+
+    /// Hand `from_task`'s worktree to `to_task` once its work is released:
+    /// merged, or completed with no merge queued, in progress or in conflict.
+    /// Returns the path handed over, or `None` when there is nothing to reuse.
+    pub(super) async fn inherit_worktree(
+        &self,
+        from_task: Uuid,
+        to_task: Uuid,
+    ) -> DomainResult<Option<String>> {
+        let Some(ref worktree_repo) = self.worktree_repo else {
+            return Ok(None);
+        };
+        let Some(wt) = worktree_repo.get_by_task(from_task).await? else {
+            return Ok(None);
+        };
+        if !matches!(wt.status, WorktreeStatus::Completed | WorktreeStatus::Merged)
+            || !std::path::Path::new(&wt.path).exists()
+        {
+            return Ok(None);
+        }
+        if let Some(ref merge_repo) = self.merge_request_repo
+            && has_pending_merge(merge_repo.as_ref(), from_task).await?
+        {
+            tracing::info!(%from_task, path = %wt.path, "worktree has a pending merge; not reusing it");
+            return Ok(None);
+        }
+        let reassigned = worktree_repo
+            .reassign_completed(wt.id, from_task, to_task)
+            .await?;
+        Ok(reassigned.then_some(wt.path))
+    }
+
     /// Update workflow state stored in task.context.custom["workflow_state"].
     ///
     /// This is the **only** sanctioned path for writing workflow state. It
@@ -464,6 +549,21 @@ impl<T: TaskRepository> TaskService<T> {
         task_id: Uuid,
         updates: Vec<(String, serde_json::Value)>,
     ) -> DomainResult<()> {
+        self.merge_task_context(task_id, updates, Vec::new())
+            .await
+            .map(|_| ())
+    }
+
+    /// Merge keys into task.context.custom and append hints, with
+    /// retry-on-conflict. Keys not named in `updates` and existing hints are
+    /// kept; hints stay capped by
+    /// [`TaskContext::push_hint_bounded`](crate::domain::models::TaskContext::push_hint_bounded).
+    pub async fn merge_task_context(
+        &self,
+        task_id: Uuid,
+        updates: Vec<(String, serde_json::Value)>,
+        hints: Vec<String>,
+    ) -> DomainResult<Task> {
         for attempt in 0..3u32 {
             let mut task = self
                 .task_repo
@@ -473,11 +573,14 @@ impl<T: TaskRepository> TaskService<T> {
             for (key, val) in &updates {
                 task.context.custom.insert(key.clone(), val.clone());
             }
+            for hint in &hints {
+                task.context.push_hint_bounded(hint.clone());
+            }
             task.updated_at = chrono::Utc::now();
             match self.task_repo.update(&task).await {
-                Ok(()) => return Ok(()),
+                Ok(()) => return Ok(task),
                 Err(DomainError::ConcurrencyConflict { .. }) if attempt < 2 => {
-                    tracing::debug!(%task_id, attempt, "update_task_context: conflict, retrying");
+                    tracing::debug!(%task_id, attempt, "merge_task_context: conflict, retrying");
                     continue;
                 }
                 Err(e) => return Err(e),
@@ -759,4 +862,79 @@ impl<T: TaskRepository> TaskService<T> {
         self.publish_events(&events).await;
         Ok((task, events))
     }
+
+    /// Force-reset a stuck task back to Ready or Pending for another attempt.
+    ///
+    /// Unlike `force_transition`, this counts as a retry (bumps
+    /// `retry_count`) and clears verification/workflow state the same way
+    /// `retry_task` does. The emitted `TaskRestarted` event is what tells
+    /// `AgentTerminationHandler` to kill any agent still working the task.
+    pub async fn restart_task(
+        &self,
+        task_id: Uuid,
+        target: TaskStatus,
+        reason: &str,
+    ) -> DomainResult<(Task, Vec<UnifiedEvent>)> {
+        let mut task = self
+            .task_repo
+            .get(task_id)
+            .await?
+            .ok_or(DomainError::TaskNotFound(task_id))?;
+
+        let old_status = task.status;
+        task.restart(target)
+            .map_err(DomainError::ValidationFailed)?;
+
+        task.clear_verification_retry_count();
+        task.clear_verification_feedback();
+        task.clear_verification_idempotency_key();
+        task.clear_verification_phase_context();
+        task.clear_verification_aggregation_summary();
+        if let Some(ref wf_name) = task.routing_hints.workflow_name {
+            let wf_state = WorkflowState::Pending {
+                workflow_name: wf_name.clone(),
+            };
+            let _ = task.set_workflow_state(&wf_state);
+        }
+
+        self.task_repo.update(&task).await?;
+
+        tracing::info!(
+            %task_id,
+            old_status = old_status.as_str(),
+            new_status = target.as_str(),
+            retry_count = task.retry_count,
+            reason,
+            "restarted task"
+        );
+
+        let goal_id = Self::extract_goal_id(&task);
+        let mut events = vec![Self::make_event(
+            EventSeverity::Warning,
+            EventCategory::Task,
+            goal_id,
+            Some(task_id),
+            EventPayload::TaskRestarted {
+                task_id,
+                from_status: old_status.as_str().to_string(),
+                to_status: target.as_str().to_string(),
+                retry_count: task.retry_count,
+            },
+        )];
+        if target == TaskStatus::Ready {
+            events.push(Self::make_event(
+                EventSeverity::Info,
+                EventCategory::Task,
+                goal_id,
+                Some(task_id),
+                EventPayload::TaskReady {
+                    task_id,
+                    task_title: task.title.clone(),
+                },
+            ));
+        }
+
+        self.publish_events(&events).await;
+        Ok((task, events))
+    }
 }
@@ -7,13 +7,15 @@ use async_trait::async_trait;
 use crate::domain::errors::DomainError;
 use crate::domain::models::workflow_state::WorkflowState;
 use crate::domain::models::{ExecutionMode, Task, TaskSource, TaskStatus, TaskType};
-use crate::domain::ports::TaskRepository;
+use crate::domain::ports::{MergeRequestRepository, TaskRepository, WorktreeRepository};
 use crate::services::command_bus::{
     CommandError, CommandOutcome, CommandResult, TaskCommand, TaskCommandHandler,
 };
 use crate::services::event_bus::EventBus;
 use crate::services::event_bus::{EventCategory, EventPayload, EventSeverity, UnifiedEvent};
 use crate::services::event_factory;
+use crate::services::task_router::TaskRouter;
+use crate::services::task_validation::TaskValidatorChain;
 
 mod lifecycle;
 mod queries;
@@ -23,8 +25,9 @@ mod submit;
 #[cfg(test)]
 mod tests;
 
-pub use queries::{PruneResult, PruneSkipped};
+pub use queries::{AgentTypeMetrics, PruneResult, PruneSkipped, agent_type_metrics};
 pub use spawn_limits::{SpawnLimitConfig, SpawnLimitResult, SpawnLimitType};
+pub use submit::SubmitExtras;
 
 #[derive(Clone)]
 pub struct TaskService<T: TaskRepository> {
@@ -41,6 +44,18 @@ pub struct TaskService<T: TaskRepository> {
     /// the caller, closing the persist-then-publish gap (S7). When `None`,
     /// events are only returned (backward-compatible behavior for tests).
     event_bus: Option<Arc<EventBus>>,
+    /// Picks an agent type for tasks submitted without one. When `None`,
+    /// routing is left entirely to the pre-spawn `RouteTaskMiddleware`.
+    router: Option<Arc<dyn TaskRouter>>,
+    /// Policies checked before a task is persisted. When `None`, the chain
+    /// is built from `[task_validation]` in the loaded config.
+    validators: Option<Arc<TaskValidatorChain>>,
+    /// Lets `rerun_task` hand the original's worktree to the rerun. When
+    /// `None`, reruns always start from a fresh worktree.
+    worktree_repo: Option<Arc<dyn WorktreeRepository>>,
+    /// Consulted so a worktree whose branch is still queued for merge is
+    /// never handed on.
+    merge_request_repo: Option<Arc<dyn MergeRequestRepository>>,
 }
 
 impl<T: TaskRepository> TaskService<T> {
@@ -50,6 +65,10 @@ impl<T: TaskRepository> TaskService<T> {
             spawn_limits: SpawnLimitConfig::default(),
             default_execution_mode: None,
             event_bus: None,
+            router: None,
+            validators: None,
+            worktree_repo: None,
+            merge_request_repo: None,
         }
     }
 
@@ -77,6 +96,30 @@ impl<T: TaskRepository> TaskService<T> {
         self
     }
 
+    /// Set the router consulted for tasks submitted without an agent type.
+    pub fn with_router(mut self, router: Arc<dyn TaskRouter>) -> Self {
+        self.router = Some(router);
+        self
+    }
+
+    /// Replace the configured pre-submission validators.
+    pub fn with_validators(mut self, validators: TaskValidatorChain) -> Self {
+        self.validators = Some(Arc::new(validators));
+        self
+    }
+
+    /// Set the worktree repository used to hand worktrees to reruns.
+    pub fn with_worktree_repo(mut self, repo: Arc<dyn WorktreeRepository>) -> Self {
+        self.worktree_repo = Some(repo);
+        self
+    }
+
+    /// Set the merge request repository checked before a worktree handoff.
+    pub fn with_merge_request_repo(mut self, repo: Arc<dyn MergeRequestRepository>) -> Self {
+        self.merge_request_repo = Some(repo);
+        self
+    }
+
     /// Access the underlying task repository.
     pub fn repo(&self) -> &Arc<T> {
         &self.task_repo
@@ -90,8 +133,11 @@ impl<T: TaskRepository> TaskService<T> {
     /// `default_workflow` is the workflow name used for root tasks without an
     /// explicit routing hint (typically `config.default_workflow`).
     fn infer_workflow_name(task: &Task, default_workflow: &str) -> Option<String> {
-        // Adapter-sourced tasks -> "external" (triage-first)
-        if let TaskSource::Adapter(_) = &task.source {
+        // Adapter- and webhook-sourced tasks -> "external" (triage-first)
+        if matches!(
+            task.source,
+            TaskSource::Adapter(_) | TaskSource::Webhook { .. }
+        ) {
             return Some("external".to_string());
         }
 
@@ -178,9 +224,13 @@ impl<T: TaskRepository + 'static> TaskCommandHandler for TaskService<T> {
                 deadline,
                 task_type,
                 execution_mode,
+                estimate_secs,
+                execution_params,
+                required_overseers,
+                workspace_mode,
             } => {
                 let (task, events) = self
-                    .submit_task(
+                    .submit_task_with_extras(
                         title,
                         description,
                         parent_id,
@@ -193,6 +243,14 @@ impl<T: TaskRepository + 'static> TaskCommandHandler for TaskService<T> {
                         deadline,
                         task_type,
                         execution_mode,
+                        SubmitExtras {
+                            estimate_secs,
+                            execution_params: execution_params.map(|p| *p),
+                            supersedes: None,
+                            inherit_worktree_from: None,
+                            required_overseers,
+                            workspace_mode,
+                        },
                     )
                     .await?;
                 Ok(CommandOutcome {
@@ -231,6 +289,13 @@ impl<T: TaskRepository + 'static> TaskCommandHandler for TaskService<T> {
                     events,
                 })
             }
+            TaskCommand::Rerun { task_id, fresh } => {
+                let (task, events) = self.rerun_task(task_id, fresh).await?;
+                Ok(CommandOutcome {
+                    result: CommandResult::Task(task),
+                    events,
+                })
+            }
             TaskCommand::Cancel { task_id, reason } => {
                 let (task, events) = self.cancel_task(task_id, &reason).await?;
                 Ok(CommandOutcome {
@@ -361,6 +426,17 @@ impl<T: TaskRepository + 'static> TaskCommandHandler for TaskService<T> {
                     events,
                 })
             }
+            TaskCommand::Restart {
+                task_id,
+                to,
+                reason,
+            } => {
+                let (task, events) = self.restart_task(task_id, to, &reason).await?;
+                Ok(CommandOutcome {
+                    result: CommandResult::Task(task),
+                    events,
+                })
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Read-only and prune queries: get, list, ready_tasks, status counts, prune.
 
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::errors::DomainResult;
@@ -29,6 +30,79 @@ pub struct PruneSkipped {
     pub reason: String,
 }
 
+/// Outcome metrics for one agent type over its finished tasks.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct AgentTypeMetrics {
+    pub agent_type: String,
+    /// Completed plus failed tasks.
+    pub total: u32,
+    pub completed: u32,
+    pub failed: u32,
+    pub avg_retries: f64,
+    /// Mean start-to-finish time of tasks with both timestamps, if any.
+    pub avg_duration_secs: Option<f64>,
+    pub success_rate: f64,
+}
+
+/// Group finished tasks by agent type and compute their outcome metrics,
+/// sorted by agent type. Tasks without an agent type count as "unknown".
+///
+/// Only completed tasks and failed tasks with no retries left are counted:
+/// a failed task that may still be retried has no outcome yet. The
+/// evolution evaluation handler uses these numbers to decide when an agent
+/// template needs refinement.
+pub fn agent_type_metrics<'a>(tasks: impl IntoIterator<Item = &'a Task>) -> Vec<AgentTypeMetrics> {
+    use std::collections::BTreeMap;
+
+    #[derive(Default)]
+    struct Acc {
+        completed: u32,
+        failed: u32,
+        retries: u64,
+        duration_secs: f64,
+        timed: u32,
+    }
+
+    let mut by_agent: BTreeMap<&str, Acc> = BTreeMap::new();
+    for task in tasks {
+        let succeeded = match task.status {
+            TaskStatus::Complete => true,
+            TaskStatus::Failed if task.retry_count >= task.max_retries => false,
+            _ => continue,
+        };
+        let acc = by_agent
+            .entry(task.agent_type.as_deref().unwrap_or("unknown"))
+            .or_default();
+        if succeeded {
+            acc.completed += 1;
+        } else {
+            acc.failed += 1;
+        }
+        acc.retries += u64::from(task.retry_count);
+        if let (Some(start), Some(end)) = (task.started_at, task.completed_at) {
+            acc.duration_secs += (end - start).num_milliseconds().max(0) as f64 / 1000.0;
+            acc.timed += 1;
+        }
+    }
+
+    by_agent
+        .into_iter()
+        .map(|(agent_type, acc)| {
+            let total = acc.completed + acc.failed;
+            AgentTypeMetrics {
+                agent_type: agent_type.to_string(),
+                total,
+                completed: acc.completed,
+                failed: acc.failed,
+                avg_retries: acc.retries as f64 / f64::from(total),
+                avg_duration_secs: (acc.timed > 0)
+                    .then(|| acc.duration_secs / f64::from(acc.timed)),
+                success_rate: f64::from(acc.completed) / f64::from(total),
+            }
+        })
+        .collect()
+}
+
 impl<T: TaskRepository> TaskService<T> {
     /// Get a task by ID.
     pub async fn get_task(&self, id: Uuid) -> DomainResult<Option<Task>> {
@@ -40,6 +114,11 @@ impl<T: TaskRepository> TaskService<T> {
         self.task_repo.list(filter).await
     }
 
+    /// Search task titles and descriptions, best match first, within `filter`.
+    pub async fn search_tasks(&self, query: &str, filter: TaskFilter) -> DomainResult<Vec<Task>> {
+        self.task_repo.search_by_text(query, filter).await
+    }
+
     /// Get ready tasks ordered by priority.
     pub async fn get_ready_tasks(&self, limit: usize) -> DomainResult<Vec<Task>> {
         self.task_repo.get_ready_tasks(limit).await
@@ -49,6 +128,22 @@ impl<T: TaskRepository> TaskService<T> {
         self.task_repo.delete(task_id).await
     }
 
+    /// Per-agent-type outcome metrics (see [`agent_type_metrics`]), optionally
+    /// limited to one agent type and to tasks that finished at or after `since`.
+    pub async fn get_agent_type_metrics(
+        &self,
+        agent_type: Option<&str>,
+        since: Option<DateTime<Utc>>,
+    ) -> DomainResult<Vec<AgentTypeMetrics>> {
+        let mut tasks = self.task_repo.list_by_status(TaskStatus::Complete).await?;
+        tasks.extend(self.task_repo.list_by_status(TaskStatus::Failed).await?);
+
+        Ok(agent_type_metrics(tasks.iter().filter(|t| {
+            agent_type.is_none_or(|a| t.agent_type.as_deref().unwrap_or("unknown") == a)
+                && since.is_none_or(|s| t.completed_at.is_some_and(|c| c >= s))
+        })))
+    }
+
     /// Get task status counts.
     pub async fn get_status_counts(
         &self,
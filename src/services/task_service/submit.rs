@@ -5,13 +5,35 @@ use uuid::Uuid;
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::models::workflow_state::WorkflowState;
 use crate::domain::models::{
-    Complexity, ExecutionMode, Task, TaskContext, TaskPriority, TaskSource, TaskStatus, TaskType,
+    Complexity, ExecutionMode, ExecutionParameters, Task, TaskContext, TaskPriority, TaskSource,
+    TaskStatus, TaskType, WorkspaceMode,
 };
 use crate::domain::ports::TaskRepository;
 use crate::services::event_bus::{EventCategory, EventPayload, EventSeverity, UnifiedEvent};
+use crate::services::task_validation::TaskValidatorChain;
 
 use super::TaskService;
 
+/// Optional submission attributes carried alongside the positional
+/// `submit_task` arguments. New optional fields go here so the 60+ existing
+/// `submit_task` call sites stay untouched.
+#[derive(Debug, Clone, Default)]
+pub struct SubmitExtras {
+    /// Effort estimate in seconds, used for velocity reporting.
+    pub estimate_secs: Option<u64>,
+    /// Substrate parameter overrides applied at spawn time.
+    pub execution_params: Option<ExecutionParameters>,
+    /// The completed task this submission re-runs.
+    pub supersedes: Option<Uuid>,
+    /// Take over this task's released worktree before the new task can
+    /// become Ready, so the orchestrator never spawns it without one.
+    pub inherit_worktree_from: Option<Uuid>,
+    /// Overseers that must pass once the agent completes.
+    pub required_overseers: Vec<String>,
+    /// Where the agent runs; `None` keeps [`WorkspaceMode::Auto`].
+    pub workspace_mode: Option<WorkspaceMode>,
+}
+
 impl<T: TaskRepository> TaskService<T> {
     // --- Scoring weights for classify_execution_mode ---
     //
@@ -189,6 +211,43 @@ impl<T: TaskRepository> TaskService<T> {
         deadline: Option<chrono::DateTime<chrono::Utc>>,
         task_type: Option<TaskType>,
         execution_mode: Option<ExecutionMode>,
+    ) -> DomainResult<(Task, Vec<UnifiedEvent>)> {
+        self.submit_task_with_extras(
+            title,
+            description,
+            parent_id,
+            priority,
+            agent_type,
+            depends_on,
+            context,
+            idempotency_key,
+            source,
+            deadline,
+            task_type,
+            execution_mode,
+            SubmitExtras::default(),
+        )
+        .await
+    }
+
+    /// Submit a new task with [`SubmitExtras`] attributes that are not part of
+    /// the positional `submit_task` signature.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn submit_task_with_extras(
+        &self,
+        title: Option<String>,
+        description: String,
+        parent_id: Option<Uuid>,
+        priority: TaskPriority,
+        agent_type: Option<String>,
+        depends_on: Vec<Uuid>,
+        context: Option<TaskContext>,
+        idempotency_key: Option<String>,
+        source: TaskSource,
+        deadline: Option<chrono::DateTime<chrono::Utc>>,
+        task_type: Option<TaskType>,
+        execution_mode: Option<ExecutionMode>,
+        extras: SubmitExtras,
     ) -> DomainResult<(Task, Vec<UnifiedEvent>)> {
         let mut events = Vec::new();
 
@@ -256,7 +315,18 @@ impl<T: TaskRepository> TaskService<T> {
         if let Some(key) = idempotency_key {
             task = task.with_idempotency_key(key);
         }
+        let inherit_worktree_from = extras.inherit_worktree_from;
         task.deadline = deadline;
+        task.estimate_secs = extras.estimate_secs;
+        task.supersedes = extras.supersedes;
+        task.required_overseers = extras.required_overseers;
+        if let Some(mode) = extras.workspace_mode {
+            task.workspace_mode = mode;
+        }
+        if let Some(params) = extras.execution_params {
+            params.validate().map_err(DomainError::ValidationFailed)?;
+            task = task.with_execution_params(params);
+        }
         if let Some(tt) = task_type {
             task = task.with_task_type(tt);
         }
@@ -273,6 +343,23 @@ impl<T: TaskRepository> TaskService<T> {
             task.context = ctx;
         }
 
+        // Let the configured router pick an agent type the caller left unset.
+        if task.agent_type.is_none()
+            && let Some(ref router) = self.router
+            && let Some(agent) = router.route(&task)
+        {
+            tracing::debug!(agent_type = %agent, "task routed by policy");
+            task.agent_type = Some(agent);
+        }
+
+        // Enforce submission policies before anything is persisted.
+        let config = crate::services::config::Config::load().unwrap_or_default();
+        match self.validators {
+            Some(ref validators) => validators.validate(&task),
+            None => TaskValidatorChain::from_config(&config.task_validation).validate(&task),
+        }
+        .inspect_err(|e| tracing::warn!(error = %e, "task submission rejected"))?;
+
         // --- Execution mode classification heuristic (Part 1.2) ---
         // If the caller explicitly requested an execution mode, use it directly.
         // Otherwise, if the task has the default Direct mode, run the heuristic to
@@ -294,7 +381,6 @@ impl<T: TaskRepository> TaskService<T> {
         // loaded from `workflows_dir`. There are no hardcoded fallbacks — if the
         // default workflow can't be resolved, reject the task with a remediation
         // hint rather than creating it unenrolled.
-        let config = crate::services::config::Config::load().unwrap_or_default();
         if let Some(wf_name) = Self::infer_workflow_name(&task, &config.default_workflow) {
             if config.resolve_workflow(&wf_name).is_none() {
                 return Err(DomainError::ValidationFailed(format!(
@@ -315,6 +401,14 @@ impl<T: TaskRepository> TaskService<T> {
         self.task_repo.create(&task).await?;
         tracing::info!(task_id = %task.id, status = ?task.status, execution_mode = ?task.execution_mode, "task submitted successfully");
 
+        // The task is still Pending here, so the handoff lands before any
+        // scheduler can claim it.
+        if let Some(from_task) = inherit_worktree_from
+            && let Some(path) = self.inherit_worktree(from_task, task.id).await?
+        {
+            tracing::info!(task_id = %task.id, %from_task, %path, "inherited worktree");
+        }
+
         // Check if task is ready
         self.check_and_update_readiness(&mut task).await?;
         self.task_repo.update(&task).await?;
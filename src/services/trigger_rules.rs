@@ -277,6 +277,41 @@ impl TriggerRule {
         self
     }
 
+    /// Check that the rule's condition is well-formed.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("rule name cannot be empty".to_string());
+        }
+        match &self.condition {
+            TriggerCondition::Always => {}
+            TriggerCondition::CountThreshold { count, window_secs } => {
+                if *count == 0 || *window_secs == 0 {
+                    return Err(format!(
+                        "rule '{}': count threshold needs a non-zero count and window",
+                        self.name
+                    ));
+                }
+            }
+            TriggerCondition::Absence {
+                expected_type,
+                deadline_secs,
+                ..
+            } => {
+                if expected_type.is_empty() || *deadline_secs == 0 {
+                    return Err(format!(
+                        "rule '{}': absence condition needs an expected event type and a non-zero deadline",
+                        self.name
+                    ));
+                }
+            }
+            TriggerCondition::Cron { expression } => {
+                validate_cron_expression(expression)
+                    .map_err(|e| format!("rule '{}': {}", self.name, e))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the EventScheduler name for cron-conditioned triggers.
     /// Convention: `trigger-cron:{rule_uuid}`
     pub fn cron_schedule_name(&self) -> String {
@@ -410,6 +445,7 @@ impl TriggerRuleEngine {
             name: rule.cron_schedule_name(),
             schedule: crate::services::event_scheduler::ScheduleType::Cron {
                 expression: expression.to_string(),
+                timezone: None,
             },
             category: EventCategory::Scheduler,
             severity: EventSeverity::Info,
@@ -461,7 +497,10 @@ impl TriggerRuleEngine {
                 let sched_event = crate::services::event_scheduler::ScheduledEvent {
                     id: Uuid::new_v4(),
                     name: rule.cron_schedule_name(),
-                    schedule: crate::services::event_scheduler::ScheduleType::Cron { expression },
+                    schedule: crate::services::event_scheduler::ScheduleType::Cron {
+                        expression,
+                        timezone: None,
+                    },
                     category: EventCategory::Scheduler,
                     severity: EventSeverity::Info,
                     goal_id: None,
@@ -597,6 +636,65 @@ impl TriggerRuleEngine {
         *store = rules;
     }
 
+    /// Reload rules from the rule repository and swap them in.
+    ///
+    /// Built-in rules missing from the repository are kept, as at startup.
+    /// Every rule is validated first; on any error the current rules stay in
+    /// place. Fire state, count windows and absence timers carry over for
+    /// rules that survive the reload, and cron schedules are re-registered.
+    /// Returns the number of rules now loaded.
+    pub async fn reload_rules(&self) -> Result<usize, String> {
+        let repo = self
+            .rule_repo
+            .as_ref()
+            .ok_or_else(|| "no trigger rule repository configured".to_string())?;
+        let mut rules = repo
+            .list()
+            .await
+            .map_err(|e| format!("failed to list trigger rules: {}", e))?;
+        for builtin in builtin_trigger_rules() {
+            if !rules.iter().any(|r| r.name == builtin.name) {
+                rules.push(builtin);
+            }
+        }
+
+        let errors: Vec<String> = rules.iter().filter_map(|r| r.validate().err()).collect();
+        if !errors.is_empty() {
+            return Err(format!("invalid trigger rules: {}", errors.join("; ")));
+        }
+
+        let old_rules = self.list_rules().await;
+        for old in old_rules.iter().filter(|r| r.is_cron()) {
+            self.deregister_cron_schedule(old).await;
+        }
+
+        // The in-memory fire state is at least as fresh as the persisted one.
+        for rule in &mut rules {
+            if let Some(old) = old_rules.iter().find(|o| o.id == rule.id)
+                && old.last_fired > rule.last_fired
+            {
+                rule.last_fired = old.last_fired;
+                rule.fire_count = rule.fire_count.max(old.fire_count);
+            }
+        }
+
+        let count = rules.len();
+        let ids: std::collections::HashSet<Uuid> = rules.iter().map(|r| r.id).collect();
+        *self.rules.write().await = rules;
+        self.event_windows
+            .write()
+            .await
+            .retain(|id, _| ids.contains(id));
+        self.absence_timers
+            .write()
+            .await
+            .retain(|id, _| ids.contains(id));
+        self.register_cron_triggers().await;
+
+        tracing::info!("Reloaded {} trigger rules", count);
+        Ok(count)
+    }
+
     /// Add a single rule.
     pub async fn add_rule(&self, rule: TriggerRule) {
         let rule_id = rule.id;
@@ -1108,6 +1206,10 @@ impl TriggerRuleEngine {
                     deadline: None,
                     task_type: None,
                     execution_mode: None,
+                    estimate_secs: None,
+                    execution_params: None,
+                    required_overseers: Vec::new(),
+                    workspace_mode: None,
                 })
             }
             SerializableDomainCommand::PauseGoal { goal_id } => {
@@ -1149,6 +1251,7 @@ impl EventHandler for TriggerRuleEngine {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -1157,7 +1260,28 @@ impl EventHandler for TriggerRuleEngine {
         event: &UnifiedEvent,
         _ctx: &HandlerContext,
     ) -> Result<Reaction, String> {
-        let events = self.evaluate(event).await;
+        let mut events = Vec::new();
+        if matches!(event.payload, EventPayload::TriggerRulesReloadRequested) {
+            match self.reload_rules().await {
+                Ok(count) => events.push(UnifiedEvent {
+                    id: EventId::new(),
+                    sequence: SequenceNumber(0),
+                    timestamp: Utc::now(),
+                    severity: EventSeverity::Info,
+                    category: EventCategory::Orchestrator,
+                    goal_id: None,
+                    task_id: None,
+                    correlation_id: event.correlation_id,
+                    source_process_id: None,
+                    payload: EventPayload::TriggerRulesReloaded {
+                        rule_count: count as u32,
+                    },
+                }),
+                Err(e) => tracing::warn!("Trigger rule reload rejected: {}", e),
+            }
+        }
+
+        events.extend(self.evaluate(event).await);
         if events.is_empty() {
             Ok(Reaction::None)
         } else {
@@ -1708,4 +1832,95 @@ mod tests {
             "Task claimed but not completed within 30 minutes (agent_type: unknown) — timed out"
         );
     }
+
+    fn hook_fired(reaction: &Reaction) -> bool {
+        match reaction {
+            Reaction::EmitEvents(events) => events.iter().any(|e| {
+                matches!(
+                    &e.payload,
+                    EventPayload::ScheduledEventFired { name, .. } if name == "paused-hook"
+                )
+            }),
+            Reaction::None => false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reload_picks_up_new_rule_without_restart() {
+        use crate::adapters::sqlite::{SqliteTriggerRuleRepository, test_support};
+        use crate::domain::ports::TriggerRuleRepository;
+
+        let pool = test_support::setup_pool().await;
+        let (task_repo, goal_repo, memory_repo) =
+            test_support::setup_task_goal_memory_repos().await;
+        let repo = Arc::new(SqliteTriggerRuleRepository::new(pool));
+        let engine = TriggerRuleEngine::new(test_support::make_command_bus(
+            &task_repo,
+            &goal_repo,
+            &memory_repo,
+        ))
+        .with_rule_repo(repo.clone());
+        let ctx = HandlerContext {
+            chain_depth: 0,
+            correlation_id: None,
+        };
+        let paused = make_test_event(
+            EventPayload::OrchestratorPaused,
+            EventCategory::Orchestrator,
+        );
+
+        let rule = TriggerRule::new(
+            "paused-hook",
+            SerializableEventFilter {
+                categories: vec![],
+                min_severity: None,
+                payload_types: vec!["OrchestratorPaused".to_string()],
+                goal_id: None,
+                task_id: None,
+            },
+            TriggerAction::EmitEvent {
+                payload: TriggerEventPayload::ScheduledEventFired {
+                    name: "paused-hook".to_string(),
+                },
+                category: EventCategory::Scheduler,
+                severity: EventSeverity::Info,
+            },
+        );
+        repo.create(&rule).await.unwrap();
+
+        // Added to the repository after startup: not active yet.
+        assert!(!hook_fired(&engine.handle(&paused, &ctx).await.unwrap()));
+
+        let reload = make_test_event(
+            EventPayload::TriggerRulesReloadRequested,
+            EventCategory::Orchestrator,
+        );
+        match engine.handle(&reload, &ctx).await.unwrap() {
+            Reaction::EmitEvents(events) => assert!(
+                events
+                    .iter()
+                    .any(|e| matches!(e.payload, EventPayload::TriggerRulesReloaded { .. }))
+            ),
+            Reaction::None => panic!("reload should report the new rule set"),
+        }
+        assert!(hook_fired(&engine.handle(&paused, &ctx).await.unwrap()));
+
+        // An invalid rule rejects the whole reload and keeps the current rules.
+        let mut bad = rule.clone();
+        bad.id = Uuid::new_v4();
+        bad.name = "bad-cron".to_string();
+        bad.condition = TriggerCondition::Cron {
+            expression: "not a cron".to_string(),
+        };
+        repo.create(&bad).await.unwrap();
+        assert!(engine.reload_rules().await.is_err());
+        assert!(
+            engine
+                .list_rules()
+                .await
+                .iter()
+                .all(|r| r.name != "bad-cron")
+        );
+        assert!(hook_fired(&engine.handle(&paused, &ctx).await.unwrap()));
+    }
 }
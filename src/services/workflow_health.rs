@@ -0,0 +1,307 @@
+//! Detection and repair of orphaned workflow subtasks.
+//!
+//! A workflow fans subtasks out under a parent task and fans their results
+//! back in. When the parent dies (fails, is canceled, or is deleted) while
+//! subtasks are still live, nothing will ever collect their results.
+//! [`WorkflowHealthMonitor::diagnose`] reports such workflows without changing
+//! anything; [`WorkflowHealthMonitor::remediate_orphaned_workflow`] hands each
+//! live subtask to the nearest live ancestor of the dead parent, or fails it
+//! when there is none.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::models::{Task, TaskStatus};
+use crate::domain::ports::{TaskFilter, TaskRepository};
+use crate::services::task_service::TaskService;
+
+/// A dead parent task with live subtasks still under it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkflowAnomaly {
+    pub parent_id: Uuid,
+    /// Status of the parent; `None` when it no longer exists.
+    pub parent_status: Option<TaskStatus>,
+    /// Non-terminal subtasks of the parent.
+    pub orphaned_subtasks: Vec<Uuid>,
+    /// Nearest live ancestor of the parent, which remediation reassigns the
+    /// subtasks to. `None` means they will be failed instead.
+    pub adopter: Option<Uuid>,
+}
+
+impl WorkflowAnomaly {
+    fn parent_state(&self) -> &'static str {
+        self.parent_status.map_or("missing", |s| s.as_str())
+    }
+}
+
+impl std::fmt::Display for WorkflowAnomaly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parent {} is {} with {} live subtask(s)",
+            self.parent_id,
+            self.parent_state(),
+            self.orphaned_subtasks.len()
+        )
+    }
+}
+
+/// What [`WorkflowHealthMonitor::remediate_orphaned_workflow`] changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RemediationReport {
+    /// Subtasks moved under a live ancestor of their dead parent.
+    pub reassigned: Vec<Uuid>,
+    /// Subtasks failed, or left as they were because the repair errored.
+    pub failed: Vec<Uuid>,
+    /// Why each subtask above ended up where it did.
+    pub reasons: HashMap<Uuid, String>,
+}
+
+impl RemediationReport {
+    pub fn is_empty(&self) -> bool {
+        self.reassigned.is_empty() && self.failed.is_empty()
+    }
+}
+
+/// Finds and repairs workflows whose parent task died under live subtasks.
+pub struct WorkflowHealthMonitor<T: TaskRepository> {
+    task_repo: Arc<T>,
+}
+
+impl<T: TaskRepository> WorkflowHealthMonitor<T> {
+    pub fn new(task_repo: Arc<T>) -> Self {
+        Self { task_repo }
+    }
+
+    /// Report orphaned workflows without changing anything.
+    pub async fn diagnose(&self) -> DomainResult<Vec<WorkflowAnomaly>> {
+        let tasks = self.task_repo.list(TaskFilter::default()).await?;
+        let by_id: HashMap<Uuid, &Task> = tasks.iter().map(|t| (t.id, t)).collect();
+
+        let mut orphans: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for task in tasks.iter().filter(|t| !t.is_terminal()) {
+            let Some(parent_id) = task.parent_id else {
+                continue;
+            };
+            if by_id.get(&parent_id).is_none_or(|p| p.is_terminal()) {
+                orphans.entry(parent_id).or_default().push(task.id);
+            }
+        }
+
+        let mut anomalies: Vec<_> = orphans
+            .into_iter()
+            .map(|(parent_id, mut orphaned_subtasks)| {
+                orphaned_subtasks.sort();
+                let parent = by_id.get(&parent_id);
+                WorkflowAnomaly {
+                    parent_id,
+                    parent_status: parent.map(|p| p.status),
+                    orphaned_subtasks,
+                    adopter: parent.and_then(|p| live_ancestor(p, &by_id)),
+                }
+            })
+            .collect();
+        anomalies.sort_by_key(|a| a.parent_id);
+        Ok(anomalies)
+    }
+
+    /// Repair the workflow under `parent_id`: its live subtasks move to the
+    /// parent's nearest live ancestor, or are failed when it has none. A live
+    /// parent has nothing to repair, so the report comes back empty.
+    pub async fn remediate_orphaned_workflow(
+        &self,
+        parent_id: Uuid,
+    ) -> DomainResult<RemediationReport> {
+        let mut report = RemediationReport::default();
+        let Some(anomaly) = self
+            .diagnose()
+            .await?
+            .into_iter()
+            .find(|a| a.parent_id == parent_id)
+        else {
+            return Ok(report);
+        };
+
+        let task_service = TaskService::new(self.task_repo.clone());
+        for subtask_id in &anomaly.orphaned_subtasks {
+            let subtask_id = *subtask_id;
+            let outcome = match anomaly.adopter {
+                Some(adopter) => self
+                    .reassign(subtask_id, adopter)
+                    .await
+                    .map(|()| format!("reassigned to {}", adopter)),
+                None => task_service
+                    .force_transition(
+                        subtask_id,
+                        TaskStatus::Failed,
+                        &format!(
+                            "workflow parent {} is {}",
+                            parent_id,
+                            anomaly.parent_state()
+                        ),
+                    )
+                    .await
+                    .map(|_| "no live ancestor to take it over".to_string()),
+            };
+            let reason = match outcome {
+                Ok(detail) => {
+                    if anomaly.adopter.is_some() {
+                        report.reassigned.push(subtask_id);
+                    } else {
+                        report.failed.push(subtask_id);
+                    }
+                    format!(
+                        "parent {} is {}; {}",
+                        parent_id,
+                        anomaly.parent_state(),
+                        detail
+                    )
+                }
+                Err(e) => {
+                    report.failed.push(subtask_id);
+                    format!("repair failed: {}", e)
+                }
+            };
+            report.reasons.insert(subtask_id, reason);
+        }
+
+        tracing::info!(
+            %parent_id,
+            reassigned = report.reassigned.len(),
+            failed = report.failed.len(),
+            "remediated orphaned workflow"
+        );
+        Ok(report)
+    }
+
+    async fn reassign(&self, subtask_id: Uuid, adopter: Uuid) -> DomainResult<()> {
+        let mut task = self
+            .task_repo
+            .get(subtask_id)
+            .await?
+            .ok_or(DomainError::TaskNotFound(subtask_id))?;
+        task.parent_id = Some(adopter);
+        task.updated_at = chrono::Utc::now();
+        task.version += 1;
+        self.task_repo.update(&task).await
+    }
+}
+
+/// The first non-terminal task above `task`, if the chain reaches one before
+/// running out (or looping).
+fn live_ancestor(task: &Task, by_id: &HashMap<Uuid, &Task>) -> Option<Uuid> {
+    let mut seen = HashSet::from([task.id]);
+    let mut next = task.parent_id;
+    while let Some(id) = next {
+        if !seen.insert(id) {
+            return None;
+        }
+        let ancestor = by_id.get(&id)?;
+        if !ancestor.is_terminal() {
+            return Some(id);
+        }
+        next = ancestor.parent_id;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::test_support::setup_task_repo;
+
+    async fn create(
+        repo: &impl TaskRepository,
+        title: &str,
+        parent: Option<&Task>,
+        status: TaskStatus,
+    ) -> Task {
+        let mut task = Task::new(title);
+        task.parent_id = parent.map(|p| p.id);
+        task.status = status;
+        repo.create(&task).await.unwrap();
+        task
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_and_remediate_orphaned_workflow() {
+        let repo = setup_task_repo().await;
+        let root = create(&*repo, "Ship the feature", None, TaskStatus::Running).await;
+        // A workflow whose parent failed, under a still-live root.
+        let dead = create(&*repo, "Implement phase", Some(&root), TaskStatus::Failed).await;
+        let ready = create(&*repo, "Write the parser", Some(&dead), TaskStatus::Ready).await;
+        let running = create(&*repo, "Write the tests", Some(&dead), TaskStatus::Running).await;
+        let done = create(&*repo, "Sketch the API", Some(&dead), TaskStatus::Complete).await;
+        // A workflow with no live ancestor at all.
+        let lone = create(&*repo, "Research", None, TaskStatus::Canceled).await;
+        let stranded = create(&*repo, "Read the papers", Some(&lone), TaskStatus::Pending).await;
+        // A healthy workflow.
+        create(&*repo, "Review", Some(&root), TaskStatus::Ready).await;
+
+        let monitor = WorkflowHealthMonitor::new(repo.clone());
+        let mut expected = vec![
+            WorkflowAnomaly {
+                parent_id: dead.id,
+                parent_status: Some(TaskStatus::Failed),
+                orphaned_subtasks: {
+                    let mut ids = vec![ready.id, running.id];
+                    ids.sort();
+                    ids
+                },
+                adopter: Some(root.id),
+            },
+            WorkflowAnomaly {
+                parent_id: lone.id,
+                parent_status: Some(TaskStatus::Canceled),
+                orphaned_subtasks: vec![stranded.id],
+                adopter: None,
+            },
+        ];
+        expected.sort_by_key(|a| a.parent_id);
+        assert_eq!(monitor.diagnose().await.unwrap(), expected);
+        // Diagnosing changes nothing.
+        assert_eq!(
+            repo.get(ready.id).await.unwrap().unwrap().parent_id,
+            Some(dead.id)
+        );
+
+        let report = monitor.remediate_orphaned_workflow(dead.id).await.unwrap();
+        assert_eq!(report.reassigned.len(), 2);
+        assert!(report.reassigned.contains(&ready.id));
+        assert!(report.reassigned.contains(&running.id));
+        assert!(report.failed.is_empty());
+        assert!(report.reasons[&ready.id].contains(&root.id.to_string()));
+        for id in [ready.id, running.id] {
+            let task = repo.get(id).await.unwrap().unwrap();
+            assert_eq!(task.parent_id, Some(root.id));
+        }
+        // Finished subtasks are left where they were.
+        assert_eq!(
+            repo.get(done.id).await.unwrap().unwrap().parent_id,
+            Some(dead.id)
+        );
+
+        let report = monitor.remediate_orphaned_workflow(lone.id).await.unwrap();
+        assert!(report.reassigned.is_empty());
+        assert_eq!(report.failed, vec![stranded.id]);
+        assert!(report.reasons[&stranded.id].contains("canceled"));
+        assert_eq!(
+            repo.get(stranded.id).await.unwrap().unwrap().status,
+            TaskStatus::Failed
+        );
+
+        // Repairs are idempotent: nothing is left to report or fix.
+        assert!(monitor.diagnose().await.unwrap().is_empty());
+        assert!(
+            monitor
+                .remediate_orphaned_workflow(dead.id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Tests for `abathur audit ...`.
+
+use super::{AssertExt, abathur_cmd, init_project, run_json};
+use tempfile::TempDir;
+
+#[test]
+fn audit_list_empty() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args(["audit", "list"])
+        .assert()
+        .success_without_warnings()
+        .stdout(predicates::str::contains("No audit events found."));
+}
+
+#[test]
+fn audit_list_json_with_filters() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    let json = run_json(
+        dir,
+        &[
+            "audit",
+            "list",
+            "--category",
+            "agent",
+            "--level",
+            "warning",
+            "--since",
+            "1h",
+            "--json",
+        ],
+    );
+
+    assert!(json["entries"].as_array().is_some());
+    assert_eq!(json["total"].as_u64(), Some(0));
+}
+
+#[test]
+fn audit_list_rejects_unknown_category() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args(["audit", "list", "--category", "bogus"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Unknown audit category"));
+}
@@ -0,0 +1,50 @@
+//! Tests for `abathur cron ...`.
+
+use super::{AssertExt, abathur_cmd, init_project, run_json};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn cron_create_accepts_known_overlap_policy() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args([
+            "cron",
+            "create",
+            "0 9 * * 1",
+            "Summarize the week",
+            "--overlap",
+            "allow",
+        ])
+        .assert()
+        .success_without_warnings();
+
+    let json = run_json(dir, &["cron", "list", "--json"]);
+    assert_eq!(json["total"], 1);
+}
+
+#[test]
+fn cron_create_rejects_unknown_overlap_policy() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args([
+            "cron",
+            "create",
+            "0 9 * * 1",
+            "Summarize the week",
+            "--overlap",
+            "queue",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid overlap policy: queue"));
+
+    let json = run_json(dir, &["cron", "list", "--json"]);
+    assert_eq!(json["total"], 0);
+}
@@ -29,6 +29,21 @@ fn event_stats_json_output() {
     assert!(json["total_events"].as_u64().is_some());
 }
 
+#[test]
+fn event_stats_json_reports_volumes() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    let json = run_json(dir, &["event", "stats", "--since", "1h", "--json"]);
+
+    assert!(json["since"].is_string(), "--since should set the window");
+    assert!(json["window_events"].as_u64().is_some());
+    assert!(json["by_category"].as_array().is_some());
+    assert!(json["by_payload_type"].as_array().is_some());
+    assert!(json["handler_lag"].as_array().is_some());
+}
+
 #[test]
 fn event_list_empty() {
     let tmp = TempDir::new().unwrap();
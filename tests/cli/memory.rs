@@ -37,6 +37,39 @@ fn memory_store_json_output() {
     assert_eq!(json_str(memory, "memory_type"), "fact");
 }
 
+#[test]
+fn memory_store_with_decay_floor() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    let json = run_json(
+        dir,
+        &[
+            "memory",
+            "store",
+            "floored-key",
+            "baseline fact",
+            "--decay-floor",
+            "0.3",
+            "--json",
+        ],
+    );
+    assert_eq!(json["success"], true);
+
+    abathur_cmd(dir)
+        .args([
+            "memory",
+            "store",
+            "bad-floor-key",
+            "content",
+            "--decay-floor",
+            "1.5",
+        ])
+        .assert()
+        .failure();
+}
+
 #[test]
 fn memory_recall_by_id() {
     let tmp = TempDir::new().unwrap();
@@ -280,6 +313,19 @@ fn memory_prune_expired_only() {
     );
 }
 
+#[test]
+fn memory_gc_dry_run_reports_without_pruning() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    let json = run_json(dir, &["memory", "gc", "--dry-run", "--json"]);
+
+    assert_eq!(json["dry_run"].as_bool(), Some(true));
+    assert!(json.get("expired_pruned").is_some());
+    assert!(json.get("conflicts_resolved").is_some());
+}
+
 #[test]
 fn memory_stats_shows_statistics() {
     let tmp = TempDir::new().unwrap();
@@ -10,12 +10,15 @@ use serde_json::Value;
 use std::path::Path;
 
 pub mod agent;
+pub mod audit;
+pub mod cron;
 pub mod event;
 pub mod goal;
 pub mod init;
 pub mod mcp;
 pub mod memory;
 pub mod misc;
+pub mod schedule;
 pub mod swarm;
 pub mod task;
 pub mod trigger;
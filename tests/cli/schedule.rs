@@ -0,0 +1,53 @@
+//! Tests for `abathur schedule ...`.
+
+use super::{AssertExt, abathur_cmd, init_project, run_json};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn create_args<'a>(name: &'a str, overlap: &'a str) -> [&'a str; 12] {
+    [
+        "schedule",
+        "create",
+        "--name",
+        name,
+        "--interval",
+        "3600",
+        "--task-title",
+        "Weekly report",
+        "--task-description",
+        "Summarize the week",
+        "--overlap",
+        overlap,
+    ]
+}
+
+#[test]
+fn schedule_create_accepts_known_overlap_policy() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args(create_args("weekly-report", "cancel_previous"))
+        .assert()
+        .success_without_warnings();
+
+    let json = run_json(dir, &["schedule", "list", "--json"]);
+    assert_eq!(json["total"], 1);
+}
+
+#[test]
+fn schedule_create_rejects_unknown_overlap_policy() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args(create_args("weekly-report", "queue"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid overlap policy: queue"));
+
+    let json = run_json(dir, &["schedule", "list", "--json"]);
+    assert_eq!(json["total"], 0);
+}
@@ -148,6 +148,32 @@ fn swarm_stop_json_when_not_running() {
     assert!(json["message"].as_str().is_some());
 }
 
+#[test]
+fn swarm_pause_and_resume_when_not_running() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    let json = run_json(dir, &["swarm", "pause", "--json"]);
+    assert_eq!(json["status"].as_str().unwrap(), "not_running");
+    assert_eq!(json["paused"], true);
+    assert_eq!(json["changed"], true);
+
+    // The flag persists and shows up in status.
+    let status = run_json(dir, &["swarm", "status", "--json"]);
+    assert_eq!(status["paused"], true);
+
+    abathur_cmd(dir)
+        .args(["swarm", "resume"])
+        .assert()
+        .success_without_warnings()
+        .stdout(predicates::str::contains("start unpaused"));
+
+    let json = run_json(dir, &["swarm", "resume", "--json"]);
+    assert_eq!(json["paused"], false);
+    assert_eq!(json["changed"], false);
+}
+
 #[test]
 fn swarm_tick_runs_successfully() {
     let tmp = TempDir::new().unwrap();
@@ -161,6 +187,19 @@ fn swarm_tick_runs_successfully() {
         .stdout(predicates::str::contains("Tick completed"));
 }
 
+#[test]
+fn swarm_tick_explain_without_ready_tasks() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args(["swarm", "tick", "--explain"])
+        .assert()
+        .success_without_warnings()
+        .stdout(predicates::str::contains("No ready tasks."));
+}
+
 #[test]
 fn swarm_tick_json_output() {
     let tmp = TempDir::new().unwrap();
@@ -260,6 +299,182 @@ fn swarm_active_with_goals() {
     );
 }
 
+/// Creates a goal plus tasks A and B where B depends on A; returns their IDs.
+fn seed_goal_with_dependent_tasks(dir: &std::path::Path) -> (String, String, String) {
+    let goal = run_json(dir, &["goal", "set", "Graph Goal", "--json"]);
+    let goal_id = json_str(&goal["goal"], "id");
+    let a = run_json(
+        dir,
+        &[
+            "task",
+            "submit",
+            "Build the parser",
+            "-t",
+            "Parser",
+            "--json",
+        ],
+    );
+    let a_id = json_str(&a["task"], "id");
+    let b = run_json(
+        dir,
+        &[
+            "task",
+            "submit",
+            "Build the evaluator",
+            "-t",
+            "Evaluator",
+            "--depends-on",
+            &a_id,
+            "--json",
+        ],
+    );
+    let b_id = json_str(&b["task"], "id");
+    (goal_id, a_id, b_id)
+}
+
+#[test]
+fn swarm_export_graph_dot_has_goal_clusters_and_edges() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+    let (goal_id, a_id, b_id) = seed_goal_with_dependent_tasks(dir);
+
+    abathur_cmd(dir)
+        .args(["swarm", "export-graph", "--format", "dot"])
+        .assert()
+        .success_without_warnings()
+        .stdout(
+            predicates::str::starts_with("digraph swarm {")
+                .and(predicates::str::contains(format!(
+                    "subgraph \"cluster_{}\"",
+                    goal_id
+                )))
+                .and(predicates::str::contains("label=\"Graph Goal (normal)\""))
+                .and(predicates::str::contains(format!(
+                    "\"{}\" -> \"{}\";",
+                    a_id, b_id
+                ))),
+        );
+}
+
+#[test]
+fn swarm_export_graph_json_filtered_by_goal() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+    let (goal_id, a_id, b_id) = seed_goal_with_dependent_tasks(dir);
+    run_json(dir, &["goal", "set", "Other Goal", "--json"]);
+
+    let json = run_json(
+        dir,
+        &[
+            "swarm",
+            "export-graph",
+            "--format",
+            "json",
+            "--goal",
+            &goal_id,
+        ],
+    );
+
+    let goals = json["goals"].as_array().expect("goals should be an array");
+    assert_eq!(goals.len(), 1);
+    assert_eq!(json_str(&goals[0], "id"), goal_id);
+    assert_eq!(json["tasks"].as_array().unwrap().len(), 2);
+    let edges = json["edges"].as_array().expect("edges should be an array");
+    assert!(
+        edges
+            .iter()
+            .any(|e| json_str(e, "from") == a_id && json_str(e, "to") == b_id),
+        "Expected a dependency edge from A to B, got {:?}",
+        edges
+    );
+}
+
+#[test]
+fn swarm_restart_task_resets_running_task() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    let submitted = run_json(dir, &["task", "submit", "Wedged work", "--json"]);
+    let id = json_str(&submitted["task"], "id");
+    abathur_cmd(dir)
+        .args([
+            "task",
+            "force-transition",
+            &id,
+            "--status",
+            "running",
+            "--reason",
+            "simulate hung agent",
+        ])
+        .assert()
+        .success();
+
+    let json = run_json(dir, &["swarm", "restart-task", &id, "--json"]);
+    assert_eq!(json["success"], true);
+    assert_eq!(json["task"]["status"], "ready");
+    assert_eq!(json["task"]["retry_count"], 1);
+
+    // A Ready task is not stuck; restarting it again is rejected.
+    abathur_cmd(dir)
+        .args(["swarm", "restart-task", &id, "--to", "pending"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Cannot restart a ready task"));
+}
+
+#[test]
+fn swarm_drain_report_lists_running_and_unstarted_tasks() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    let submitted = run_json(
+        dir,
+        &[
+            "task",
+            "submit",
+            "Long migration",
+            "--estimate",
+            "2h",
+            "--json",
+        ],
+    );
+    let running_id = json_str(&submitted["task"], "id");
+    abathur_cmd(dir)
+        .args([
+            "task",
+            "force-transition",
+            &running_id,
+            "--status",
+            "running",
+            "--reason",
+            "simulate in-flight agent",
+        ])
+        .assert()
+        .success();
+    run_json(dir, &["task", "submit", "Queued one", "--json"]);
+    run_json(dir, &["task", "submit", "Queued two", "--json"]);
+
+    let json = run_json(dir, &["swarm", "drain-report", "--json"]);
+    let running = json["running"].as_array().unwrap();
+    assert_eq!(running.len(), 1);
+    assert_eq!(json_str(&running[0], "id"), running_id);
+    assert_eq!(running[0]["estimate_secs"], 7200);
+    assert_eq!(running[0]["remaining_source"], "estimate");
+    assert_eq!(json["ready_not_started"], 2);
+    assert_eq!(json["unestimated"], 0);
+
+    abathur_cmd(dir)
+        .args(["swarm", "drain-report"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Ready tasks that won't start: 2"))
+        .stdout(predicates::str::contains("Long migration"));
+}
+
 #[test]
 fn swarm_start_dry_run_and_stop() {
     let tmp = TempDir::new().unwrap();
@@ -160,6 +160,65 @@ fn task_show_json_output() {
     assert_eq!(json_str(&json["task"], "title"), "Show JSON Task");
 }
 
+#[test]
+fn task_submit_with_execution_overrides() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    let create = run_json(
+        dir,
+        &[
+            "task",
+            "submit",
+            "Deep investigation",
+            "--max-turns",
+            "90",
+            "--temperature",
+            "0.4",
+            "--json",
+        ],
+    );
+    let id = json_str(&create["task"], "id");
+
+    let json = run_json(dir, &["task", "show", &id, "--json"]);
+
+    assert_eq!(json["execution_params"]["max_turns"].as_u64(), Some(90));
+    assert!((json["execution_params"]["temperature"].as_f64().unwrap() - 0.4).abs() < 1e-6);
+}
+
+#[test]
+fn task_submit_with_verify_gate() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    let create = run_json(
+        dir,
+        &[
+            "task",
+            "submit",
+            "Gated change",
+            "--verify",
+            "compilation,tests",
+            "--json",
+        ],
+    );
+    let id = json_str(&create["task"], "id");
+
+    let json = run_json(dir, &["task", "show", &id, "--json"]);
+    assert_eq!(
+        json["required_overseers"],
+        serde_json::json!(["compilation", "test-suite"])
+    );
+
+    abathur_cmd(dir)
+        .args(["task", "submit", "Bad gate", "--verify", "vibes"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("unknown overseer 'vibes'"));
+}
+
 #[test]
 fn task_cancel() {
     let tmp = TempDir::new().unwrap();
@@ -844,7 +903,20 @@ fn task_show_nonexistent_fails() {
     abathur_cmd(dir)
         .args(["task", "show", "00000000-0000-0000-0000-000000000000"])
         .assert()
-        .failure();
+        .code(3);
+}
+
+#[test]
+fn task_show_unknown_prefix_exits_not_found() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args(["--json", "task", "show", "deadbeef"])
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("\"exit_code\": 3"));
 }
 
 #[test]
@@ -1471,3 +1543,104 @@ fn task_list_human_output_with_limit() {
         stdout
     );
 }
+
+#[test]
+fn task_schedule_add_list_remove() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args([
+            "agent",
+            "register",
+            "dep-updater",
+            "-p",
+            "Update dependencies",
+        ])
+        .assert()
+        .success_without_warnings();
+
+    let json = run_json(
+        dir,
+        &[
+            "task",
+            "schedule",
+            "add",
+            "--cron",
+            "0 9 * * 1",
+            "--template",
+            "dep-updater",
+            "--json",
+        ],
+    );
+    assert_eq!(json["success"], true);
+    assert_eq!(json_str(&json, "name"), "dep-updater-schedule");
+
+    let json = run_json(dir, &["task", "schedule", "list", "--json"]);
+    assert_eq!(json["total"], 1);
+
+    abathur_cmd(dir)
+        .args(["task", "schedule", "remove", "dep-updater-schedule"])
+        .assert()
+        .success_without_warnings();
+    let json = run_json(dir, &["task", "schedule", "list", "--json"]);
+    assert_eq!(json["total"], 0);
+}
+
+#[test]
+fn task_schedule_add_rejects_unknown_template() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args([
+            "task",
+            "schedule",
+            "add",
+            "--cron",
+            "0 9 * * 1",
+            "--template",
+            "no-such-agent",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found"));
+}
+
+#[test]
+fn task_schedule_add_rejects_unknown_overlap_policy() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    init_project(dir);
+
+    abathur_cmd(dir)
+        .args([
+            "agent",
+            "register",
+            "dep-updater",
+            "-p",
+            "Update dependencies",
+        ])
+        .assert()
+        .success_without_warnings();
+
+    abathur_cmd(dir)
+        .args([
+            "task",
+            "schedule",
+            "add",
+            "--cron",
+            "0 9 * * 1",
+            "--template",
+            "dep-updater",
+            "--overlap",
+            "queue",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid overlap policy: queue"));
+    let json = run_json(dir, &["task", "schedule", "list", "--json"]);
+    assert_eq!(json["total"], 0);
+}
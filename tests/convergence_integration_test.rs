@@ -93,6 +93,16 @@ impl TrajectoryRepository for InMemoryTrajectoryRepo {
         Ok(guard.iter().rev().take(limit).cloned().collect())
     }
 
+    async fn get_active(&self) -> DomainResult<Vec<Trajectory>> {
+        let guard = self.saved.lock().await;
+        Ok(guard
+            .iter()
+            .rev()
+            .filter(|t| !t.phase.is_terminal())
+            .cloned()
+            .collect())
+    }
+
     async fn get_successful_strategies(
         &self,
         _attractor_type: &AttractorType,
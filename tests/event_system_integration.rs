@@ -77,6 +77,7 @@ impl EventHandler for CountingHandler {
             priority: HandlerPriority::NORMAL,
             error_strategy: ErrorStrategy::LogAndContinue,
             critical: false,
+            singleton: false,
         }
     }
 
@@ -438,6 +439,10 @@ async fn test_command_bus_routes_and_emits() {
             deadline: None,
             task_type: None,
             execution_mode: None,
+            estimate_secs: None,
+            execution_params: None,
+            required_overseers: Vec::new(),
+            workspace_mode: None,
         }),
     );
 
@@ -523,6 +528,10 @@ async fn test_e2e_mutation_persist_react() {
             deadline: None,
             task_type: None,
             execution_mode: None,
+            estimate_secs: None,
+            execution_params: None,
+            required_overseers: Vec::new(),
+            workspace_mode: None,
         }),
     );
     command_bus.dispatch(envelope).await.expect("dispatch");
@@ -0,0 +1,104 @@
+//! MCP HTTP servers over TLS.
+//!
+//! Starts the agents MCP server with the self-signed certificate in
+//! `tests/fixtures/tls` and talks to it over HTTPS, trusting only that
+//! certificate.
+
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::time::Duration;
+
+use abathur::adapters::mcp::{AgentsHttpConfig, AgentsHttpServer, TlsConfig};
+use abathur::adapters::sqlite::{SqliteAgentRepository, create_migrated_test_pool};
+use abathur::services::AgentService;
+use abathur::services::event_bus::{EventBus, EventBusConfig};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/tls");
+
+fn fixture(name: &str) -> String {
+    format!("{}/{}", FIXTURES, name)
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+async fn agents_server(tls: TlsConfig) -> (AgentsHttpServer<SqliteAgentRepository>, u16) {
+    let pool = create_migrated_test_pool().await.unwrap();
+    let event_bus = Arc::new(EventBus::new(EventBusConfig {
+        persist_events: false,
+        ..Default::default()
+    }));
+    let service = AgentService::new(Arc::new(SqliteAgentRepository::new(pool)), event_bus);
+    let port = free_port();
+    let config = AgentsHttpConfig {
+        port,
+        tls: Some(tls),
+        ..Default::default()
+    };
+    (AgentsHttpServer::new(service, config), port)
+}
+
+#[tokio::test]
+async fn test_agents_server_serves_https() {
+    let (server, port) =
+        agents_server(TlsConfig::new(fixture("cert.pem"), fixture("key.pem"))).await;
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let handle = tokio::spawn(server.serve_with_shutdown(async {
+        let _ = shutdown_rx.await;
+    }));
+
+    let cert =
+        reqwest::Certificate::from_pem(&std::fs::read(fixture("cert.pem")).unwrap()).unwrap();
+    let client = reqwest::Client::builder()
+        .tls_certs_only([cert])
+        .build()
+        .unwrap();
+
+    let url = format!("https://localhost:{}/health", port);
+    let mut response = None;
+    for _ in 0..50 {
+        match client.get(&url).send().await {
+            Ok(r) => {
+                response = Some(r);
+                break;
+            }
+            Err(_) => tokio::time::sleep(Duration::from_millis(100)).await,
+        }
+    }
+    let response = response.expect("server never answered over HTTPS");
+    assert!(response.status().is_success());
+
+    // The same port does not speak plain HTTP.
+    let plain = reqwest::get(format!("http://localhost:{}/health", port)).await;
+    assert!(plain.map_or(true, |r| !r.status().is_success()));
+
+    shutdown_tx.send(()).unwrap();
+    tokio::time::timeout(Duration::from_secs(10), handle)
+        .await
+        .expect("server did not shut down")
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_missing_certificate_fails_startup() {
+    let (server, port) = agents_server(TlsConfig::new(
+        fixture("missing-cert.pem"),
+        fixture("key.pem"),
+    ))
+    .await;
+
+    let err = tokio::time::timeout(Duration::from_secs(10), server.serve())
+        .await
+        .expect("startup should fail instead of serving")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("missing-cert.pem"), "{}", err);
+    // Nothing was left listening on the port.
+    assert!(std::net::TcpStream::connect(("127.0.0.1", port)).is_err());
+}